@@ -1606,6 +1606,160 @@ const_ordinary! { PBST: u32;
 	PAUSED 0x0003
 }
 
+const_ordinary! { PSBTN: u32;
+	/// [`PSM_PRESSBUTTON`](https://learn.microsoft.com/en-us/windows/win32/controls/psm-pressbutton)
+	/// buttons (`u32`).
+	=>
+	=>
+	BACK 0
+	NEXT 1
+	FINISH 2
+	OK 3
+	APPLYNOW 4
+	CANCEL 5
+	HELP 6
+}
+
+const_bitflag! { PSH: u32;
+	/// [`PROPSHEETHEADER`](crate::PROPSHEETHEADER) `dwFlags` (`u32`).
+	=>
+	=>
+	DEFAULT 0x0000_0000
+	PROPTITLE 0x0000_0001
+	USEHICON 0x0000_0002
+	USEICONID 0x0000_0004
+	PROPSHEETPAGE 0x0000_0008
+	WIZARDHASFINISH 0x0000_0010
+	WIZARD 0x0000_0020
+	USEPSTARTPAGE 0x0000_0040
+	NOAPPLYNOW 0x0000_0080
+	USECALLBACK 0x0000_0100
+	HASHELP 0x0000_0200
+	MODELESS 0x0000_0400
+	RTLREADING 0x0000_0800
+	WIZARDCONTEXTHELP 0x0000_1000
+	AEROWIZARD 0x0000_4000
+	WATERMARK 0x0000_8000
+	USEHBMWATERMARK 0x0001_0000
+	USEHPLWATERMARK 0x0002_0000
+	STRETCHWATERMARK 0x0004_0000
+	HEADER 0x0008_0000
+	USEHBMHEADER 0x0010_0000
+	USEPAGELANG 0x0020_0000
+	WIZARD_LITE 0x0040_0000
+	WIZARD97 0x0100_0000
+	NOCONTEXTHELP 0x0200_0000
+	RESIZABLE 0x0400_0000
+	HEADERBITMAP 0x0800_0000
+	NOMARGIN 0x1000_0000
+}
+
+const_wm! { PSM;
+	/// Property sheet
+	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-property-sheets-reference-messages)
+	/// (`u32`).
+	=>
+	=>
+	SETCURSEL WM::USER.0 + 101
+	REMOVEPAGE WM::USER.0 + 102
+	ADDPAGE WM::USER.0 + 103
+	CHANGED WM::USER.0 + 104
+	RESTARTWINDOWS WM::USER.0 + 105
+	REBOOTSYSTEM WM::USER.0 + 106
+	CANCELTOCLOSE WM::USER.0 + 107
+	QUERYSIBLINGS WM::USER.0 + 108
+	UNCHANGED WM::USER.0 + 109
+	APPLY WM::USER.0 + 110
+	SETWIZBUTTONS WM::USER.0 + 112
+	PRESSBUTTON WM::USER.0 + 113
+	SETCURSELID WM::USER.0 + 114
+	GETTABCONTROL WM::USER.0 + 116
+	ISDIALOGMESSAGE WM::USER.0 + 117
+	GETCURRENTPAGEHWND WM::USER.0 + 118
+	INSERTPAGE WM::USER.0 + 119
+	SETTITLE WM::USER.0 + 120
+	SETFINISHTEXT WM::USER.0 + 121
+	SETHEADERTITLE WM::USER.0 + 126
+	SETHEADERSUBTITLE WM::USER.0 + 128
+	HWNDTOINDEX WM::USER.0 + 129
+	INDEXTOHWND WM::USER.0 + 130
+	PAGETOINDEX WM::USER.0 + 131
+	INDEXTOPAGE WM::USER.0 + 132
+	IDTOINDEX WM::USER.0 + 133
+	INDEXTOID WM::USER.0 + 134
+	GETRESULT WM::USER.0 + 135
+	RECALCPAGESIZES WM::USER.0 + 136
+	SETNEXTTEXT WM::USER.0 + 137
+	SHOWWIZBUTTONS WM::USER.0 + 138
+	ENABLEWIZBUTTONS WM::USER.0 + 139
+	SETBUTTONTEXT WM::USER.0 + 140
+}
+
+const_nm! { PSN;
+	/// Property sheet `WM_NOTIFY`
+	/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-property-sheets-reference-notifications)
+	/// (`i32`).
+	=>
+	FIRST -200
+	=>
+	SETACTIVE Self::FIRST.0 - 0
+	KILLACTIVE Self::FIRST.0 - 1
+	APPLY Self::FIRST.0 - 2
+	RESET Self::FIRST.0 - 3
+	HELP Self::FIRST.0 - 5
+	WIZBACK Self::FIRST.0 - 6
+	WIZNEXT Self::FIRST.0 - 7
+	WIZFINISH Self::FIRST.0 - 8
+	QUERYCANCEL Self::FIRST.0 - 9
+	GETOBJECT Self::FIRST.0 - 10
+	TRANSLATEACCELERATOR Self::FIRST.0 - 12
+	QUERYINITIALFOCUS Self::FIRST.0 - 13
+}
+
+const_ordinary! { PSNRET: isize;
+	/// [`PSN_APPLY`](https://learn.microsoft.com/en-us/windows/win32/controls/psn-apply)
+	/// and [`PSN_KILLACTIVE`](https://learn.microsoft.com/en-us/windows/win32/controls/psn-killactive)
+	/// results (`isize`).
+	=>
+	=>
+	NOERROR 0
+	INVALID 1
+	INVALID_NOCHANGEPAGE 2
+	MESSAGEHANDLED 3
+}
+
+const_bitflag! { PSP: u32;
+	/// [`PROPSHEETPAGE`](crate::PROPSHEETPAGE) `dwFlags` (`u32`).
+	=>
+	=>
+	DEFAULT 0x0000_0000
+	DLGINDIRECT 0x0000_0001
+	USEHICON 0x0000_0002
+	USEICONID 0x0000_0004
+	USETITLE 0x0000_0008
+	RTLREADING 0x0000_0010
+	HASHELP 0x0000_0020
+	USEREFPARENT 0x0000_0040
+	USECALLBACK 0x0000_0080
+	PREMATURE 0x0000_0400
+	HIDEHEADER 0x0000_0800
+	USEHEADERTITLE 0x0000_1000
+	USEHEADERSUBTITLE 0x0000_2000
+	USEFUSIONCONTEXT 0x0000_4000
+}
+
+const_bitflag! { PSWIZB: u32;
+	/// [`PSM_SETWIZBUTTONS`](https://learn.microsoft.com/en-us/windows/win32/controls/psm-setwizbuttons)
+	/// buttons (`u32`).
+	=>
+	=>
+	BACK 0x0000_0001
+	NEXT 0x0000_0002
+	FINISH 0x0000_0004
+	DISABLEDFINISH 0x0000_0008
+	CANCEL 0x0000_0010
+}
+
 const_wm! { RB;
 	/// Rebar control
 	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-rebar-control-reference-messages)
@@ -2189,6 +2343,18 @@ const_bitflag! { TDCBF: i32;
 	CLOSE 0x0020
 }
 
+const_ordinary! { TDE: u32;
+	/// Task dialog
+	/// [elements](https://learn.microsoft.com/en-us/windows/win32/controls/tdm-set-element-text)
+	/// (`u32`).
+	=>
+	=>
+	CONTENT 0
+	EXPANDED_INFORMATION 1
+	FOOTER 2
+	MAIN_INSTRUCTION 3
+}
+
 const_bitflag! { TDF: i32;
 	/// [`TASKDIALOGCONFIG`](crate::TASKDIALOGCONFIG) `dwFlags` (`i32`).
 	=>
@@ -2213,6 +2379,49 @@ const_bitflag! { TDF: i32;
 	SIZE_TO_CONTENT 0x0100_0000
 }
 
+const_wm! { TDM;
+	/// Task dialog
+	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-task-dialogs-reference-messages)
+	/// (`u32`).
+	=>
+	=>
+	NAVIGATE_PAGE WM::USER.0 + 101
+	CLICK_BUTTON WM::USER.0 + 102
+	SET_MARQUEE_PROGRESS_BAR WM::USER.0 + 103
+	SET_PROGRESS_BAR_STATE WM::USER.0 + 104
+	SET_PROGRESS_BAR_RANGE WM::USER.0 + 105
+	SET_PROGRESS_BAR_POS WM::USER.0 + 106
+	SET_PROGRESS_BAR_MARQUEE WM::USER.0 + 107
+	SET_ELEMENT_TEXT WM::USER.0 + 108
+	CLICK_RADIO_BUTTON WM::USER.0 + 110
+	ENABLE_BUTTON WM::USER.0 + 111
+	ENABLE_RADIO_BUTTON WM::USER.0 + 112
+	CLICK_VERIFICATION WM::USER.0 + 113
+	UPDATE_ELEMENT_TEXT WM::USER.0 + 114
+	SET_BUTTON_ELEVATION_REQUIRED_STATE WM::USER.0 + 115
+	UPDATE_ICON WM::USER.0 + 116
+}
+
+const_ordinary! { TDN: u32;
+	/// Task dialog
+	/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-task-dialogs-reference-notifications),
+	/// received by the [`PFTASKDIALOGCALLBACK`](crate::PFTASKDIALOGCALLBACK)
+	/// callback (`u32`).
+	=>
+	=>
+	CREATED 0
+	NAVIGATED 1
+	BUTTON_CLICKED 2
+	HYPERLINK_CLICKED 3
+	TIMER 4
+	DESTROYED 5
+	RADIO_BUTTON_CLICKED 6
+	DIALOG_CONSTRUCTED 7
+	VERIFICATION_CLICKED 8
+	HELP 9
+	EXPANDO_BUTTON_CLICKED 10
+}
+
 const_wm! { TRBM;
 	/// Trackbar control
 	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-trackbar-control-reference-messages)
@@ -2269,8 +2478,34 @@ const_nm! { TRBN;
 	THUMBPOSCHANGING Self::FIRST.0 - 1
 }
 
+const_ordinary! { TTDT: u32;
+	/// [`TTM_SETDELAYTIME`](crate::msg::ttm::SetDelayTime) duration (`u32`).
+	=>
+	=>
+	AUTOMATIC 0
+	RESHOW 1
+	AUTOPOP 2
+	INITIAL 3
+}
+
+const_bitflag! { TTF: u32;
+	/// [`TTTOOLINFO`](crate::TTTOOLINFO) `uFlags` (`u32`).
+	=>
+	=>
+	IDISHWND 0x0001
+	CENTERTIP 0x0002
+	RTLREADING 0x0004
+	SUBCLASS 0x0010
+	TRACK 0x0020
+	ABSOLUTE 0x0080
+	TRANSPARENT 0x0100
+	PARSELINKS 0x1000
+	DI_SETITEM 0x8000
+}
+
 const_ordinary! { TTI: i32;
-	/// [`EDITBALLOONTIP`](crate::EDITBALLOONTIP) ttiIcon (`i32`).
+	/// [`EDITBALLOONTIP`](crate::EDITBALLOONTIP) `ttiIcon` and
+	/// [`TTM_SETTITLE`](crate::msg::ttm::SetTitle) icon (`i32`).
 	=>
 	=>
 	ERROR 3
@@ -2282,6 +2517,88 @@ const_ordinary! { TTI: i32;
 	ERROR_LARGE 6
 }
 
+const_wm! { TTM;
+	/// Tooltip control
+	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tooltip-control-reference-messages)
+	/// (`u32`).
+	=>
+	=>
+	ACTIVATE WM::USER.0 + 1
+	SETDELAYTIME WM::USER.0 + 3
+	RELAYEVENT WM::USER.0 + 7
+	GETTOOLCOUNT WM::USER.0 + 13
+	WINDOWFROMPOINT WM::USER.0 + 16
+	TRACKACTIVATE WM::USER.0 + 17
+	TRACKPOSITION WM::USER.0 + 18
+	SETTIPBKCOLOR WM::USER.0 + 19
+	SETTIPTEXTCOLOR WM::USER.0 + 20
+	GETDELAYTIME WM::USER.0 + 21
+	GETTIPBKCOLOR WM::USER.0 + 22
+	GETTIPTEXTCOLOR WM::USER.0 + 23
+	SETMAXTIPWIDTH WM::USER.0 + 24
+	GETMAXTIPWIDTH WM::USER.0 + 25
+	SETMARGIN WM::USER.0 + 26
+	GETMARGIN WM::USER.0 + 27
+	POP WM::USER.0 + 28
+	UPDATE WM::USER.0 + 29
+	GETBUBBLESIZE WM::USER.0 + 30
+	ADJUSTRECT WM::USER.0 + 31
+	SETTITLE WM::USER.0 + 33
+	POPUP WM::USER.0 + 34
+	GETTITLE WM::USER.0 + 35
+	ADDTOOL WM::USER.0 + 50
+	DELTOOL WM::USER.0 + 51
+	NEWTOOLRECT WM::USER.0 + 52
+	GETTOOLINFO WM::USER.0 + 53
+	SETTOOLINFO WM::USER.0 + 54
+	HITTEST WM::USER.0 + 55
+	GETTEXT WM::USER.0 + 56
+	UPDATETIPTEXT WM::USER.0 + 57
+	ENUMTOOLS WM::USER.0 + 58
+	GETCURRENTTOOL WM::USER.0 + 59
+	SETWINDOWTHEME CCM::SETWINDOWTHEME.0
+}
+
+const_nm! { TTN;
+	/// Tooltip control `WM_NOTIFY`
+	/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tooltip-control-reference-notifications)
+	/// (`i32`).
+	=>
+	FIRST -520
+	=>
+	GETDISPINFO Self::FIRST.0 - 10
+	SHOW Self::FIRST.0 - 1
+	POP Self::FIRST.0 - 2
+	LINKCLICK Self::FIRST.0 - 3
+}
+
+const_ws! { TTS: u32;
+	/// Tooltip control
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/tooltip-styles)
+	/// (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	/// Indicates that the tooltip control appears when the cursor is on a tool,
+	/// even if the tooltip control's owner window is inactive.
+	ALWAYSTIP 0x01
+	/// Prevents the system from stripping ampersand characters from a string
+	/// or terminating a string at a tab character.
+	NOPREFIX 0x02
+	/// Disables sliding tooltip animation.
+	NOANIMATE 0x10
+	/// Disables fading tooltip animation.
+	NOFADE 0x20
+	/// Indicates that the tooltip control has the appearance of a cartoon
+	/// "balloon", with rounded corners and a stem pointing to the item.
+	BALLOON 0x40
+	/// Displays a Close button on the tooltip.
+	CLOSE 0x80
+	/// Uses themed hyperlinks.
+	USEVISUALSTYLE 0x100
+}
+
 const_ordinary! { TVC: u32;
 	/// [`NMTREEVIEW`](crate::NMTREEVIEW) `action` (`u32`).
 	=>
@@ -1,7 +1,8 @@
-use crate::kernel::ffi_types::{BOOL, HANDLE, PFUNC, PVOID};
+use crate::kernel::ffi_types::{BOOL, HANDLE, PCVOID, PFUNC, PVOID};
 
 extern_sys! { "comctl32";
 	DefSubclassProc(HANDLE, u32, usize, isize) -> isize
+	GetWindowSubclass(HANDLE, PFUNC, usize, *mut usize) -> BOOL
 	ImageList_Add(HANDLE, HANDLE, HANDLE) -> i32
 	ImageList_AddMasked(HANDLE, HANDLE, u32) -> i32
 	ImageList_BeginDrag(HANDLE, i32, i32, i32) -> BOOL
@@ -18,6 +19,7 @@ extern_sys! { "comctl32";
 	InitCommonControls()
 	InitCommonControlsEx(PVOID) -> BOOL
 	InitMUILanguage(u16)
+	PropertySheetW(PCVOID) -> isize
 	RemoveWindowSubclass(HANDLE, PFUNC, usize) -> BOOL
 	SetWindowSubclass(HANDLE, PFUNC, usize, usize) -> BOOL
 }
@@ -1,8 +1,8 @@
 #![allow(non_snake_case)]
 
 use crate::comctl;
-use crate::comctl::decl::INITCOMMONCONTROLSEX;
-use crate::kernel::decl::{LANGID, SysResult};
+use crate::comctl::decl::{INITCOMMONCONTROLSEX, PROPSHEETHEADER};
+use crate::kernel::decl::{GetLastError, LANGID, SysResult};
 use crate::kernel::privs::bool_to_sysresult;
 
 /// [`InitCommonControls`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-initcommoncontrols)
@@ -24,3 +24,15 @@ pub fn InitCommonControlsEx(icce: &INITCOMMONCONTROLSEX) -> SysResult<()> {
 pub fn InitMUILanguage(ui_lang: LANGID) {
 	unsafe { comctl::ffi::InitMUILanguage(ui_lang.0) }
 }
+
+/// [`PropertySheet`](https://learn.microsoft.com/en-us/windows/win32/api/prsht/nf-prsht-propertysheetw)
+/// function.
+///
+/// For a modal property sheet, returns a positive value if any changes were
+/// saved, or zero otherwise. For a modeless one, returns the window handle.
+pub fn PropertySheet(psh: &PROPSHEETHEADER) -> SysResult<isize> {
+	match unsafe { comctl::ffi::PropertySheetW(psh as *const _ as _) } {
+		-1 => Err(GetLastError()),
+		ret => Ok(ret),
+	}
+}
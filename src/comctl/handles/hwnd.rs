@@ -1,9 +1,15 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
-use crate::comctl;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::{co, comctl};
 use crate::comctl::decl::SUBCLASSPROC;
 use crate::kernel::decl::SysResult;
 use crate::kernel::privs::bool_to_sysresult;
+use crate::msg::WndMsg;
 use crate::prelude::{Handle, MsgSend};
 use crate::user::decl::HWND;
 
@@ -18,6 +24,72 @@ impl comctl_Hwnd for HWND {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait comctl_Hwnd: Handle {
+	/// Installs a subclass on the window, which receives the messages before
+	/// the window procedure. Returns an ID which can be passed to
+	/// [`HWND::remove_subclass`](crate::prelude::comctl_Hwnd::remove_subclass).
+	///
+	/// Several independent subclasses can be installed on the same window.
+	/// They are called in order of `priority`, the highest first; subclasses
+	/// with the same priority are called from the most recently installed.
+	/// If the closure returns `Some`, the message is considered handled, and
+	/// the value is returned to the system; if it returns `None`, the message
+	/// goes to the next subclass and, eventually, to
+	/// [`HWND::DefSubclassProc`](crate::prelude::comctl_Hwnd::DefSubclassProc).
+	///
+	/// Any data captured by the closure lives as long as the subclass. Upon
+	/// [`WM_NCDESTROY`](crate::msg::wm::NcDestroy), which is delivered to all
+	/// subclasses regardless of their return values, all subclasses are
+	/// removed and their closures are dropped.
+	///
+	/// A panic cannot unwind across the subclass procedure, which is called by
+	/// the system, so a panicking closure is caught, and the message goes to
+	/// `DefSubclassProc`.
+	///
+	/// Subclasses must be installed from the thread which created the window.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HWND};
+	///
+	/// let hedit: HWND; // initialized somewhere
+	/// # let hedit = HWND::NULL;
+	///
+	/// let id = hedit.add_subclass(0, |_, p| {
+	///     if p.msg_id == co::WM::CHAR && p.wparam == ' ' as usize {
+	///         Some(0) // swallow spaces
+	///     } else {
+	///         None // go on
+	///     }
+	/// })?;
+	///
+	/// hedit.remove_subclass(id)?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	fn add_subclass<F>(&self, priority: i32, func: F) -> SysResult<u32>
+		where F: Fn(&HWND, WndMsg) -> Option<isize> + 'static,
+	{
+		let chain = match SubclassChain::get(self.as_ptr()) {
+			Some(chain) => chain,
+			None => SubclassChain::install(self.as_ptr())?,
+		};
+		Ok(chain.add(priority, Rc::new(func)))
+	}
+
+	/// Removes a subclass installed with
+	/// [`HWND::add_subclass`](crate::prelude::comctl_Hwnd::add_subclass),
+	/// dropping its closure.
+	///
+	/// Fails with [`co::ERROR::NOT_FOUND`](crate::co::ERROR::NOT_FOUND) if
+	/// there is no subclass with the given ID.
+	fn remove_subclass(&self, id: u32) -> SysResult<()> {
+		match SubclassChain::get(self.as_ptr()) {
+			Some(chain) if chain.remove(id) => Ok(()),
+			_ => Err(co::ERROR::NOT_FOUND),
+		}
+	}
+
 	/// [`DefSubclassProc`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-defsubclassproc)
 	/// method.
 	///
@@ -38,6 +110,29 @@ pub trait comctl_Hwnd: Handle {
 		)
 	}
 
+	/// [`GetWindowSubclass`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-getwindowsubclass)
+	/// method.
+	///
+	/// Returns the reference data of the subclass, or `None` if the subclass
+	/// is not installed.
+	#[must_use]
+	fn GetWindowSubclass(&self,
+		subclass_func: SUBCLASSPROC, subclass_id: usize) -> Option<usize>
+	{
+		let mut ref_data = usize::default();
+		match unsafe {
+			comctl::ffi::GetWindowSubclass(
+				self.as_ptr(),
+				subclass_func as _,
+				subclass_id,
+				&mut ref_data,
+			)
+		} {
+			0 => None,
+			_ => Some(ref_data),
+		}
+	}
+
 	/// [`RemoveWindowSubclass`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/nf-commctrl-removewindowsubclass)
 	/// method.
 	fn RemoveWindowSubclass(&self,
@@ -78,3 +173,109 @@ pub trait comctl_Hwnd: Handle {
 		)
 	}
 }
+
+//------------------------------------------------------------------------------
+
+/// Unique ID of the subclass which dispatches to the subclass chain.
+const SUBCLASS_CHAIN_ID: usize = 0x5753_4342;
+
+/// Source of the IDs returned by `add_subclass`.
+static NEXT_SUBCLASS_ID: AtomicU32 = AtomicU32::new(1);
+
+type SubclassFunc = Rc<dyn Fn(&HWND, WndMsg) -> Option<isize>>;
+
+struct SubclassEntry {
+	id: u32,
+	priority: i32,
+	func: SubclassFunc,
+}
+
+/// Subclasses installed with `add_subclass`, ordered by priority. A single
+/// native subclass is installed per window, whose reference data points to
+/// this struct.
+struct SubclassChain {
+	entries: RefCell<Vec<SubclassEntry>>,
+}
+
+impl SubclassChain {
+	fn get<'a>(hwnd: *mut std::ffi::c_void) -> Option<&'a Self> {
+		let hwnd = unsafe { HWND::from_ptr(hwnd) };
+		hwnd.GetWindowSubclass(Self::subclass_proc, SUBCLASS_CHAIN_ID)
+			.map(|ref_data| unsafe { &*(ref_data as *const Self) })
+	}
+
+	fn install<'a>(hwnd: *mut std::ffi::c_void) -> SysResult<&'a Self> {
+		let hwnd = unsafe { HWND::from_ptr(hwnd) };
+		let ptr_self = Box::into_raw(
+			Box::new(Self { entries: RefCell::new(Vec::default()) }),
+		);
+		match unsafe {
+			hwnd.SetWindowSubclass(
+				Self::subclass_proc, SUBCLASS_CHAIN_ID, ptr_self as _)
+		} {
+			Ok(_) => Ok(unsafe { &*ptr_self }),
+			Err(e) => {
+				let _ = unsafe { Box::from_raw(ptr_self) };
+				Err(e)
+			},
+		}
+	}
+
+	fn add(&self, priority: i32, func: SubclassFunc) -> u32 {
+		let id = NEXT_SUBCLASS_ID.fetch_add(1, Ordering::Relaxed);
+		let mut entries = self.entries.borrow_mut();
+		let pos = entries.iter()
+			.position(|entry| entry.priority <= priority) // before the ones with same priority
+			.unwrap_or(entries.len());
+		entries.insert(pos, SubclassEntry { id, priority, func });
+		id
+	}
+
+	fn remove(&self, id: u32) -> bool {
+		let mut entries = self.entries.borrow_mut();
+		match entries.iter().position(|entry| entry.id == id) {
+			Some(pos) => {
+				entries.remove(pos);
+				true
+			},
+			None => false,
+		}
+	}
+
+	extern "system" fn subclass_proc(
+		hwnd: HWND,
+		msg: co::WM,
+		wparam: usize,
+		lparam: isize,
+		subclass_id: usize,
+		ref_data: usize,
+	) -> isize
+	{
+		let wm_any = WndMsg::new(msg, wparam, lparam);
+		let ptr_self = ref_data as *mut Self;
+
+		// Closures are cloned, so they can add or remove subclasses, or
+		// reenter this procedure by sending messages to the window.
+		let funcs = unsafe { &*ptr_self }.entries.borrow().iter()
+			.map(|entry| entry.func.clone())
+			.collect::<Vec<_>>();
+
+		// A panic cannot unwind across the subclass procedure, so it's caught,
+		// and the message goes to DefSubclassProc.
+		let mut ret = None;
+		if msg == co::WM::NCDESTROY {
+			funcs.iter().for_each(|func| { // all of them must know
+				let _ = panic::catch_unwind(AssertUnwindSafe(|| func(&hwnd, wm_any)));
+			});
+			drop(funcs);
+			hwnd.RemoveWindowSubclass(Self::subclass_proc, subclass_id).ok(); // ignore errors
+			let _ = unsafe { Box::from_raw(ptr_self) }; // drop all closures
+		} else {
+			ret = panic::catch_unwind(AssertUnwindSafe(
+				|| funcs.iter().find_map(|func| func(&hwnd, wm_any))))
+				.unwrap_or(None);
+		}
+
+		ret.unwrap_or_else(|| hwnd.DefSubclassProc(wm_any))
+	}
+}
@@ -0,0 +1,126 @@
+use crate::co;
+use crate::comctl::decl::{LHITTESTINFO, LITEM};
+use crate::kernel::decl::SysResult;
+use crate::msg::WndMsg;
+use crate::prelude::MsgSend;
+use crate::user::decl::SIZE;
+use crate::user::privs::zero_as_badargs;
+
+/// [`LM_GETIDEALHEIGHT`](https://learn.microsoft.com/en-us/windows/win32/controls/lm-getidealheight)
+/// message, which has no parameters.
+///
+/// Return type: `u32`.
+pub struct GetIdealHeight {}
+
+unsafe impl MsgSend for GetIdealHeight {
+	type RetType = u32;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::LM::GETIDEALHEIGHT.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`LM_GETIDEALSIZE`](https://learn.microsoft.com/en-us/windows/win32/controls/lm-getidealsize)
+/// message parameters.
+///
+/// Return type: `u32`.
+pub struct GetIdealSize<'a> {
+	pub max_width: u32,
+	pub size: &'a mut SIZE,
+}
+
+unsafe impl<'a> MsgSend for GetIdealSize<'a> {
+	type RetType = u32;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::LM::GETIDEALSIZE.into(),
+			wparam: self.max_width as _,
+			lparam: self.size as *mut _ as _,
+		}
+	}
+}
+
+/// [`LM_GETITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/lm-getitem)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct GetItem<'a> {
+	pub item: &'a mut LITEM,
+}
+
+unsafe impl<'a> MsgSend for GetItem<'a> {
+	type RetType = SysResult<()>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::LM::GETITEM.into(),
+			wparam: 0,
+			lparam: self.item as *mut _ as _,
+		}
+	}
+}
+
+/// [`LM_HITTEST`](https://learn.microsoft.com/en-us/windows/win32/controls/lm-hittest)
+/// message parameters.
+///
+/// Return type: `bool`.
+pub struct HitTest<'a> {
+	pub info: &'a mut LHITTESTINFO,
+}
+
+unsafe impl<'a> MsgSend for HitTest<'a> {
+	type RetType = bool;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v != 0
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::LM::HITTEST.into(),
+			wparam: 0,
+			lparam: self.info as *mut _ as _,
+		}
+	}
+}
+
+/// [`LM_SETITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/lm-setitem)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct SetItem<'a> {
+	pub item: &'a LITEM,
+}
+
+unsafe impl<'a> MsgSend for SetItem<'a> {
+	type RetType = SysResult<()>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::LM::SETITEM.into(),
+			wparam: 0,
+			lparam: self.item as *const _ as _,
+		}
+	}
+}
@@ -3,6 +3,7 @@ pub mod cb;
 pub mod dtm;
 pub mod em;
 pub mod hdm;
+pub mod lm;
 pub mod lvm;
 pub mod mcm;
 pub mod pbm;
@@ -11,6 +12,7 @@ pub mod stm;
 pub mod tbm;
 pub mod tcm;
 pub mod trbm;
+pub mod ttm;
 pub mod tvm;
 pub mod udm;
 pub mod wm;
@@ -0,0 +1,475 @@
+use crate::co;
+use crate::comctl::decl::TTTOOLINFO;
+use crate::kernel::decl::{MAKEDWORD, SysResult, WString};
+use crate::msg::WndMsg;
+use crate::prelude::MsgSend;
+use crate::user::decl::COLORREF;
+use crate::user::privs::zero_as_badargs;
+
+/// [`TTM_ACTIVATE`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-activate)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct Activate {
+	pub activate: bool,
+}
+
+unsafe impl MsgSend for Activate {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::ACTIVATE.into(),
+			wparam: self.activate as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_ADDTOOL`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-addtool)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct AddTool<'a, 'b> {
+	pub info: &'b TTTOOLINFO<'a>,
+}
+
+unsafe impl<'a, 'b> MsgSend for AddTool<'a, 'b> {
+	type RetType = SysResult<()>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::ADDTOOL.into(),
+			wparam: 0,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
+
+/// [`TTM_DELTOOL`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-deltool)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct DelTool<'a, 'b> {
+	pub info: &'b TTTOOLINFO<'a>,
+}
+
+unsafe impl<'a, 'b> MsgSend for DelTool<'a, 'b> {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::DELTOOL.into(),
+			wparam: 0,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
+
+/// [`TTM_GETMAXTIPWIDTH`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-getmaxtipwidth)
+/// message, which has no parameters.
+///
+/// Return type: `i32`.
+pub struct GetMaxTipWidth {}
+
+unsafe impl MsgSend for GetMaxTipWidth {
+	type RetType = i32;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::GETMAXTIPWIDTH.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_GETTIPBKCOLOR`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-gettipbkcolor)
+/// message, which has no parameters.
+///
+/// Return type: `COLORREF`.
+pub struct GetTipBkColor {}
+
+unsafe impl MsgSend for GetTipBkColor {
+	type RetType = COLORREF;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		COLORREF(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::GETTIPBKCOLOR.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_GETTIPTEXTCOLOR`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-gettiptextcolor)
+/// message, which has no parameters.
+///
+/// Return type: `COLORREF`.
+pub struct GetTipTextColor {}
+
+unsafe impl MsgSend for GetTipTextColor {
+	type RetType = COLORREF;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		COLORREF(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::GETTIPTEXTCOLOR.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_GETTOOLCOUNT`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-gettoolcount)
+/// message, which has no parameters.
+///
+/// Return type: `u32`.
+pub struct GetToolCount {}
+
+unsafe impl MsgSend for GetToolCount {
+	type RetType = u32;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::GETTOOLCOUNT.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_GETTOOLINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-gettoolinfo)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct GetToolInfo<'a, 'b> {
+	pub info: &'b mut TTTOOLINFO<'a>,
+}
+
+unsafe impl<'a, 'b> MsgSend for GetToolInfo<'a, 'b> {
+	type RetType = SysResult<()>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::GETTOOLINFO.into(),
+			wparam: 0,
+			lparam: self.info as *mut _ as _,
+		}
+	}
+}
+
+/// [`TTM_NEWTOOLRECT`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-newtoolrect)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct NewToolRect<'a, 'b> {
+	pub info: &'b TTTOOLINFO<'a>,
+}
+
+unsafe impl<'a, 'b> MsgSend for NewToolRect<'a, 'b> {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::NEWTOOLRECT.into(),
+			wparam: 0,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
+
+/// [`TTM_POP`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-pop)
+/// message, which has no parameters.
+///
+/// Return type: `()`.
+pub struct Pop {}
+
+unsafe impl MsgSend for Pop {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::POP.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_POPUP`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-popup)
+/// message, which has no parameters.
+///
+/// Return type: `()`.
+pub struct Popup {}
+
+unsafe impl MsgSend for Popup {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::POPUP.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_SETDELAYTIME`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-setdelaytime)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct SetDelayTime {
+	pub which: co::TTDT,
+	pub milliseconds: Option<u16>,
+}
+
+unsafe impl MsgSend for SetDelayTime {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::SETDELAYTIME.into(),
+			wparam: self.which.0 as _,
+			lparam: self.milliseconds.map_or(-1, |ms| ms as _),
+		}
+	}
+}
+
+/// [`TTM_SETMAXTIPWIDTH`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-setmaxtipwidth)
+/// message parameters.
+///
+/// Return type: `i32`.
+pub struct SetMaxTipWidth {
+	pub width: Option<u32>,
+}
+
+unsafe impl MsgSend for SetMaxTipWidth {
+	type RetType = i32;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::SETMAXTIPWIDTH.into(),
+			wparam: 0,
+			lparam: self.width.map_or(-1, |w| w as _),
+		}
+	}
+}
+
+/// [`TTM_SETTIPBKCOLOR`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-settipbkcolor)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct SetTipBkColor {
+	pub color: COLORREF,
+}
+
+unsafe impl MsgSend for SetTipBkColor {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::SETTIPBKCOLOR.into(),
+			wparam: self.color.0 as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_SETTIPTEXTCOLOR`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-settiptextcolor)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct SetTipTextColor {
+	pub color: COLORREF,
+}
+
+unsafe impl MsgSend for SetTipTextColor {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::SETTIPTEXTCOLOR.into(),
+			wparam: self.color.0 as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_SETTITLE`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-settitle)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct SetTitle {
+	pub icon: co::TTI,
+	pub title: WString,
+}
+
+unsafe impl MsgSend for SetTitle {
+	type RetType = SysResult<()>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::SETTITLE.into(),
+			wparam: self.icon.0 as _,
+			lparam: self.title.as_ptr() as _,
+		}
+	}
+}
+
+/// [`TTM_SETTOOLINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-settoolinfo)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct SetToolInfo<'a, 'b> {
+	pub info: &'b TTTOOLINFO<'a>,
+}
+
+unsafe impl<'a, 'b> MsgSend for SetToolInfo<'a, 'b> {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::SETTOOLINFO.into(),
+			wparam: 0,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
+
+/// [`TTM_TRACKACTIVATE`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-trackactivate)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct TrackActivate<'a, 'b> {
+	pub activate: bool,
+	pub info: &'b TTTOOLINFO<'a>,
+}
+
+unsafe impl<'a, 'b> MsgSend for TrackActivate<'a, 'b> {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::TRACKACTIVATE.into(),
+			wparam: self.activate as _,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
+
+/// [`TTM_TRACKPOSITION`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-trackposition)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct TrackPosition {
+	pub x: i16,
+	pub y: i16,
+}
+
+unsafe impl MsgSend for TrackPosition {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::TRACKPOSITION.into(),
+			wparam: 0,
+			lparam: MAKEDWORD(self.x as _, self.y as _) as _,
+		}
+	}
+}
+
+/// [`TTM_UPDATE`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-update)
+/// message, which has no parameters.
+///
+/// Return type: `()`.
+pub struct Update {}
+
+unsafe impl MsgSend for Update {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::UPDATE.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_UPDATETIPTEXT`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-updatetiptext)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct UpdateTipText<'a, 'b> {
+	pub info: &'b TTTOOLINFO<'a>,
+}
+
+unsafe impl<'a, 'b> MsgSend for UpdateTipText<'a, 'b> {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::UPDATETIPTEXT.into(),
+			wparam: 0,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
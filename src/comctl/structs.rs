@@ -13,7 +13,7 @@ use crate::kernel::ffi_types::BOOL;
 use crate::kernel::privs::IS_INTRESOURCE;
 use crate::prelude::Handle;
 use crate::user::decl::{
-	COLORREF, HBITMAP, HDC, HWND, POINT, RECT, SIZE, WINDOWPOS,
+	COLORREF, DLGPROC, HBITMAP, HDC, HICON, HWND, POINT, RECT, SIZE, WINDOWPOS,
 };
 
 /// [`BUTTON_IMAGELIST`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-button_imagelist)
@@ -146,6 +146,15 @@ pub struct INITCOMMONCONTROLSEX {
 
 impl_default_with_size!(INITCOMMONCONTROLSEX, dwSize);
 
+/// [`LHITTESTINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-lhittestinfo)
+/// struct.
+#[repr(C)]
+#[derive(Default)]
+pub struct LHITTESTINFO {
+	pub pt: POINT,
+	pub item: LITEM,
+}
+
 /// [`LITEM`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-litem)
 /// struct.
 #[repr(C)]
@@ -1028,6 +1037,94 @@ pub struct NMVIEWCHANGE {
 	pub dwNewView: co::MCMV,
 }
 
+/// [`PROPSHEETHEADER`](https://learn.microsoft.com/en-us/windows/win32/api/prsht/ns-prsht-propsheetheaderw_v2)
+/// struct.
+///
+/// Only the `ppsp` variant of the pages union is supported, so `dwFlags` must
+/// include `co::PSH::PROPSHEETPAGE`.
+#[repr(C)]
+pub struct PROPSHEETHEADER<'a, 'b> {
+	dwSize: u32,
+	pub dwFlags: co::PSH,
+	pub hwndParent: HWND,
+	pub hInstance: HINSTANCE,
+	pub hIcon: HICON,
+	pszCaption: *mut u16,
+	nPages: u32,
+	pub nStartPage: u32,
+	ppsp: *mut PROPSHEETPAGE<'b, 'b, 'b, 'b>,
+	pfnCallback: usize,
+	pub hbmWatermark: HBITMAP,
+	hplWatermark: usize,
+	pub hbmHeader: HBITMAP,
+
+	_pszCaption: PhantomData<&'a mut u16>,
+	_ppsp: PhantomData<&'b mut PROPSHEETPAGE<'b, 'b, 'b, 'b>>,
+}
+
+impl_default_with_size!(PROPSHEETHEADER, dwSize, 'a, 'b);
+
+impl<'a, 'b> PROPSHEETHEADER<'a, 'b> {
+	pub_fn_string_ptr_get_set!('a, pszCaption, set_pszCaption);
+	pub_fn_array_buf_get_set!('b, ppsp, set_ppsp, nPages, PROPSHEETPAGE<'b, 'b, 'b, 'b>);
+}
+
+/// [`PROPSHEETPAGE`](https://learn.microsoft.com/en-us/windows/win32/api/prsht/ns-prsht-propsheetpagew)
+/// struct.
+#[repr(C)]
+pub struct PROPSHEETPAGE<'a, 'b, 'c, 'd> {
+	dwSize: u32,
+	pub dwFlags: co::PSP,
+	pub hInstance: HINSTANCE,
+	pszTemplate: *const u16,
+	pub hIcon: HICON,
+	pszTitle: *mut u16,
+	pub pfnDlgProc: Option<DLGPROC>,
+	pub lParam: isize,
+	pfnCallback: usize,
+	pcRefParent: *mut u32,
+	pszHeaderTitle: *mut u16,
+	pszHeaderSubTitle: *mut u16,
+	hActCtx: usize,
+	pub hbmHeader: HBITMAP,
+
+	_pszTemplate: PhantomData<&'a IdStr>,
+	_pszTitle: PhantomData<&'b mut u16>,
+	_pszHeaderTitle: PhantomData<&'c mut u16>,
+	_pszHeaderSubTitle: PhantomData<&'d mut u16>,
+}
+
+impl_default_with_size!(PROPSHEETPAGE, dwSize, 'a, 'b, 'c, 'd);
+
+impl<'a, 'b, 'c, 'd> PROPSHEETPAGE<'a, 'b, 'c, 'd> {
+	/// Returns the `pszTemplate` field.
+	#[must_use]
+	pub fn pszTemplate(&self) -> Option<IdStr> {
+		if self.pszTemplate.is_null() {
+			None
+		} else {
+			Some(IdStr::from_ptr(self.pszTemplate))
+		}
+	}
+
+	/// Sets the `pszTemplate` field.
+	pub fn set_pszTemplate(&mut self, val: Option<&'a IdStr>) {
+		self.pszTemplate = val.map_or(std::ptr::null(), |val| val.as_ptr());
+	}
+
+	pub_fn_string_ptr_get_set!('b, pszTitle, set_pszTitle);
+	pub_fn_string_ptr_get_set!('c, pszHeaderTitle, set_pszHeaderTitle);
+	pub_fn_string_ptr_get_set!('d, pszHeaderSubTitle, set_pszHeaderSubTitle);
+}
+
+/// [`PSHNOTIFY`](https://learn.microsoft.com/en-us/windows/win32/api/prsht/ns-prsht-pshnotify)
+/// struct.
+#[repr(C)]
+pub struct PSHNOTIFY {
+	pub hdr: NMHDR,
+	pub lParam: isize,
+}
+
 /// [`TBADDBITMAP`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-tbaddbitmap)
 /// struct.
 #[repr(C)]
@@ -1278,6 +1375,29 @@ impl<'a> TCITEM<'a> {
 	pub_fn_string_buf_get_set!('a, pszText, set_pszText, cchTextMax);
 }
 
+/// [`TTTOOLINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-tttoolinfow)
+/// struct.
+#[repr(C)]
+pub struct TTTOOLINFO<'a> {
+	cbSize: u32,
+	pub uFlags: co::TTF,
+	pub hwnd: HWND,
+	pub uId: usize,
+	pub rect: RECT,
+	pub hinst: HINSTANCE,
+	lpszText: *mut u16,
+	pub lParam: isize,
+	lpReserved: *mut std::ffi::c_void,
+
+	_lpszText: PhantomData<&'a mut u16>,
+}
+
+impl_default_with_size!(TTTOOLINFO, cbSize, 'a);
+
+impl<'a> TTTOOLINFO<'a> {
+	pub_fn_string_ptr_get_set!('a, lpszText, set_lpszText);
+}
+
 /// [`TVHITTESTINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-tvhittestinfo)
 /// struct.
 #[repr(C)]
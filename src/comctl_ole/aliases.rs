@@ -7,8 +7,8 @@ use crate::user::decl::HWND;
 pub type PFTASKDIALOGCALLBACK =
 	extern "system" fn(
 		hWnd: HWND,
-		msg: co::WM,
+		msg: co::TDN,
 		wParam: usize,
 		lParam: isize,
 		lpRefData: isize,
-	);
+	) -> co::HRESULT;
@@ -1,14 +1,21 @@
 //! Provides high-level abstractions to
 //! [`TaskDialogIndirect`](crate::TaskDialogIndirect) and
-//! [`HWND::TaskDialog`](crate::prelude::comctl_ole_Hwnd::TaskDialog) functions.
+//! [`HWND::TaskDialog`](crate::prelude::comctl_ole_Hwnd::TaskDialog) functions,
+//! including the fully customizable
+//! [`TaskDialogBuilder`](crate::task_dlg::TaskDialogBuilder).
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
 
 use crate::co;
 use crate::comctl_ole::decl::{
-	IconIdTdicon, TASKDIALOG_BUTTON, TASKDIALOGCONFIG, TaskDialogIndirect,
+	IconId, IconIdTdicon, TASKDIALOG_BUTTON, TASKDIALOGCONFIG,
+	TaskDialogIndirect,
 };
-use crate::kernel::decl::WString;
+use crate::kernel::decl::{AnyResult, MAKEDWORD, WString};
+use crate::msg::WndMsg;
 use crate::ole::decl::HrResult;
-use crate::prelude::Handle;
+use crate::prelude::{Handle, user_Hwnd};
 use crate::user::decl::HWND;
 
 /// Displays an error modal window with an OK button.
@@ -261,3 +268,664 @@ fn generate(
 	TaskDialogIndirect(&tdc, None)
 		.map(|(dlg_id, _)| dlg_id)
 }
+
+//------------------------------------------------------------------------------
+
+/// Result of a task dialog shown with
+/// [`TaskDialogBuilder::show`](crate::task_dlg::TaskDialogBuilder::show).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TaskDialogResult {
+	/// The button clicked by the user. Custom buttons return the ID they were
+	/// added with.
+	pub button: co::DLGID,
+	/// The radio button selected when the dialog was closed, or zero if there
+	/// are no radio buttons.
+	pub radio_button: u16,
+	/// Whether the verification checkbox was checked when the dialog was
+	/// closed.
+	pub verification_checked: bool,
+}
+
+/// The task dialog being shown, passed to the callbacks of
+/// [`TaskDialogBuilder`](crate::task_dlg::TaskDialogBuilder), which can be used
+/// to update it while it's open.
+pub struct ActiveTaskDialog {
+	hwnd: HWND,
+}
+
+impl ActiveTaskDialog {
+	/// Returns the handle to the task dialog window.
+	#[must_use]
+	pub const fn hwnd(&self) -> &HWND {
+		&self.hwnd
+	}
+
+	fn send(&self, msg: co::TDM, wparam: usize, lparam: isize) -> isize {
+		self.hwnd.SendMessage(WndMsg { msg_id: msg.into(), wparam, lparam })
+	}
+
+	/// Simulates a click on the given button, with
+	/// [`TDM_CLICK_BUTTON`](https://learn.microsoft.com/en-us/windows/win32/controls/tdm-click-button).
+	pub fn click_button(&self, id: co::DLGID) {
+		self.send(co::TDM::CLICK_BUTTON, u16::from(id) as _, 0);
+	}
+
+	/// Simulates a click on the given radio button, with
+	/// [`TDM_CLICK_RADIO_BUTTON`](https://learn.microsoft.com/en-us/windows/win32/controls/tdm-click-radio-button).
+	pub fn click_radio_button(&self, id: u16) {
+		self.send(co::TDM::CLICK_RADIO_BUTTON, id as _, 0);
+	}
+
+	/// Closes the dialog as if the user clicked Cancel.
+	pub fn close(&self) {
+		self.hwnd.EndDialog(u16::from(co::DLGID::CANCEL) as _).ok(); // ignore errors
+	}
+
+	/// Enables or disables the given button, with
+	/// [`TDM_ENABLE_BUTTON`](https://learn.microsoft.com/en-us/windows/win32/controls/tdm-enable-button).
+	pub fn enable_button(&self, id: co::DLGID, enable: bool) {
+		self.send(co::TDM::ENABLE_BUTTON, u16::from(id) as _, enable as _);
+	}
+
+	/// Enables or disables the given radio button, with
+	/// [`TDM_ENABLE_RADIO_BUTTON`](https://learn.microsoft.com/en-us/windows/win32/controls/tdm-enable-radio-button).
+	pub fn enable_radio_button(&self, id: u16, enable: bool) {
+		self.send(co::TDM::ENABLE_RADIO_BUTTON, id as _, enable as _);
+	}
+
+	/// Sets the text of one of the dialog elements, with
+	/// [`TDM_SET_ELEMENT_TEXT`](https://learn.microsoft.com/en-us/windows/win32/controls/tdm-set-element-text).
+	pub fn set_element_text(&self, element: co::TDE, text: &str) {
+		let text_buf = WString::from_str(text);
+		self.send(co::TDM::SET_ELEMENT_TEXT, element.0 as _, text_buf.as_ptr() as _);
+	}
+
+	/// Turns the progress bar into a marquee progress bar, or back, with
+	/// [`TDM_SET_MARQUEE_PROGRESS_BAR`](https://learn.microsoft.com/en-us/windows/win32/controls/tdm-set-marquee-progress-bar).
+	pub fn set_marquee_mode(&self, marquee: bool) {
+		self.send(co::TDM::SET_MARQUEE_PROGRESS_BAR, marquee as _, 0);
+	}
+
+	/// Starts or stops the marquee animation, with
+	/// [`TDM_SET_PROGRESS_BAR_MARQUEE`](https://learn.microsoft.com/en-us/windows/win32/controls/tdm-set-progress-bar-marquee).
+	/// The speed is the time between updates, in milliseconds; zero means the
+	/// default speed.
+	pub fn set_marquee(&self, running: bool, speed_ms: u32) {
+		self.send(co::TDM::SET_PROGRESS_BAR_MARQUEE, running as _, speed_ms as _);
+	}
+
+	/// Sets the position of the progress bar, with
+	/// [`TDM_SET_PROGRESS_BAR_POS`](https://learn.microsoft.com/en-us/windows/win32/controls/tdm-set-progress-bar-pos).
+	/// Returns the previous position.
+	pub fn set_progress_pos(&self, pos: u32) -> u32 {
+		self.send(co::TDM::SET_PROGRESS_BAR_POS, pos as _, 0) as _
+	}
+
+	/// Sets the range of the progress bar, with
+	/// [`TDM_SET_PROGRESS_BAR_RANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/tdm-set-progress-bar-range).
+	/// The default range is 0 to 100.
+	pub fn set_progress_range(&self, min: u16, max: u16) {
+		self.send(co::TDM::SET_PROGRESS_BAR_RANGE, 0, MAKEDWORD(min, max) as _);
+	}
+
+	/// Sets the state of the progress bar, with
+	/// [`TDM_SET_PROGRESS_BAR_STATE`](https://learn.microsoft.com/en-us/windows/win32/controls/tdm-set-progress-bar-state).
+	pub fn set_progress_state(&self, state: co::PBST) {
+		self.send(co::TDM::SET_PROGRESS_BAR_STATE, state.0 as _, 0);
+	}
+
+	/// Shows or hides the UAC shield icon on the given button, with
+	/// [`TDM_SET_BUTTON_ELEVATION_REQUIRED_STATE`](https://learn.microsoft.com/en-us/windows/win32/controls/tdm-set-button-elevation-required-state).
+	pub fn set_shield(&self, id: co::DLGID, shield: bool) {
+		self.send(co::TDM::SET_BUTTON_ELEVATION_REQUIRED_STATE,
+			u16::from(id) as _, shield as _);
+	}
+}
+
+/// A user closure which receives the dialog and a notification argument.
+type Callback<A, R = ()> = Option<Box<dyn Fn(&ActiveTaskDialog, A) -> AnyResult<R>>>;
+
+/// User closures called by the task dialog callback.
+#[derive(Default)]
+struct Callbacks {
+	created: Callback<()>,
+	button_clicked: Callback<co::DLGID, bool>,
+	radio_button_clicked: Callback<u16>,
+	hyperlink_clicked: Callback<String>,
+	verification_clicked: Callback<bool>,
+	expando_clicked: Callback<bool>,
+	timer: Callback<u32>,
+}
+
+impl Callbacks {
+	fn process(&self,
+		dlg: &ActiveTaskDialog,
+		msg: co::TDN,
+		wparam: usize,
+		lparam: isize,
+	) -> AnyResult<co::HRESULT>
+	{
+		match msg {
+			co::TDN::CREATED => if let Some(func) = &self.created {
+				func(dlg, ())?;
+			},
+			co::TDN::BUTTON_CLICKED => if let Some(func) = &self.button_clicked {
+				if !func(dlg, co::DLGID::from(wparam as u16))? {
+					return Ok(co::HRESULT::S_FALSE); // keep the dialog open
+				}
+			},
+			co::TDN::RADIO_BUTTON_CLICKED => if let Some(func) = &self.radio_button_clicked {
+				func(dlg, wparam as _)?;
+			},
+			co::TDN::HYPERLINK_CLICKED => if let Some(func) = &self.hyperlink_clicked {
+				func(dlg, WString::from_wchars_nullt(lparam as _).to_string())?;
+			},
+			co::TDN::VERIFICATION_CLICKED => if let Some(func) = &self.verification_clicked {
+				func(dlg, wparam != 0)?;
+			},
+			co::TDN::EXPANDO_BUTTON_CLICKED => if let Some(func) = &self.expando_clicked {
+				func(dlg, wparam != 0)?;
+			},
+			co::TDN::TIMER => if let Some(func) = &self.timer {
+				func(dlg, wparam as _)?;
+			},
+			_ => {},
+		}
+		Ok(co::HRESULT::S_OK)
+	}
+}
+
+/// State shared with the task dialog callback while the dialog is shown.
+struct CallbackState<'a> {
+	callbacks: &'a Callbacks,
+	error: Option<Box<dyn std::error::Error + Send + Sync>>,
+	panic: Option<Box<dyn Any + Send>>,
+}
+
+extern "system" fn task_dialog_proc(
+	hwnd: HWND,
+	msg: co::TDN,
+	wparam: usize,
+	lparam: isize,
+	ref_data: isize,
+) -> co::HRESULT
+{
+	let state = unsafe { &mut *(ref_data as *mut CallbackState) };
+	let dlg = ActiveTaskDialog { hwnd };
+	if state.error.is_some() || state.panic.is_some() {
+		return co::HRESULT::S_OK; // dialog is already being closed
+	}
+
+	// A panic cannot unwind across the callback, so it's resumed after the
+	// dialog is closed.
+	let callbacks = state.callbacks;
+	match panic::catch_unwind(AssertUnwindSafe(
+		|| callbacks.process(&dlg, msg, wparam, lparam)))
+	{
+		Ok(Ok(hr)) => hr,
+		Ok(Err(err)) => {
+			state.error = Some(err);
+			dlg.close();
+			co::HRESULT::S_OK
+		},
+		Err(payload) => {
+			state.panic = Some(payload);
+			dlg.close();
+			co::HRESULT::S_OK
+		},
+	}
+}
+
+/// Builds and shows a task dialog with
+/// [`TaskDialogIndirect`](crate::TaskDialogIndirect), a modern replacement
+/// for the message box, with custom buttons, radio buttons, a verification
+/// checkbox, an expandable area, hyperlinks and a progress bar.
+///
+/// The callbacks receive an
+/// [`ActiveTaskDialog`](crate::task_dlg::ActiveTaskDialog), which can update
+/// the dialog while it's open. If a callback returns an error, the dialog is
+/// closed, and the error is returned by
+/// [`show`](crate::task_dlg::TaskDialogBuilder::show).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, HWND, task_dlg};
+///
+/// let hparent: HWND; // initialized somewhere
+/// # let hparent = HWND::NULL;
+///
+/// let res = task_dlg::TaskDialogBuilder::new("Backup")
+///     .main_instruction("Backup in progress")
+///     .content("Files are being copied. See <a href=\"log\">the log</a>.")
+///     .common_buttons(co::TDCBF::CANCEL)
+///     .button(100, "Run in background")
+///     .verification("Close when finished", true)
+///     .expanded_information("Destination: D:\\Backup")
+///     .progress_bar(false)
+///     .on_created(|dlg| {
+///         dlg.set_progress_pos(25);
+///         Ok(())
+///     })
+///     .on_hyperlink_clicked(|_, href| {
+///         println!("Clicked: {}", href);
+///         Ok(())
+///     })
+///     .show(&hparent)?;
+///
+/// if res.button == co::DLGID::from(100) {
+///     println!("Running in background.");
+/// }
+/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+pub struct TaskDialogBuilder {
+	title: String,
+	main_instruction: Option<String>,
+	content: Option<String>,
+	main_icon: IconIdTdicon,
+	common_buttons: co::TDCBF,
+	buttons: Vec<(u16, String)>,
+	command_links: bool,
+	default_button: Option<co::DLGID>,
+	radio_buttons: Vec<(u16, String)>,
+	default_radio_button: Option<u16>,
+	verification: Option<(String, bool)>,
+	expanded_information: Option<String>,
+	expanded_control_text: Option<String>,
+	collapsed_control_text: Option<String>,
+	expanded_by_default: bool,
+	expand_in_footer: bool,
+	footer: Option<String>,
+	footer_icon: IconId,
+	progress_bar: Option<bool>, // marquee?
+	allow_cancellation: bool,
+	width: u32,
+	callbacks: Callbacks,
+}
+
+impl TaskDialogBuilder {
+	/// Creates a new builder with the given window title.
+	#[must_use]
+	pub fn new(title: &str) -> Self {
+		Self {
+			title: title.to_owned(),
+			main_instruction: None,
+			content: None,
+			main_icon: IconIdTdicon::None,
+			common_buttons: co::TDCBF::OK,
+			buttons: Vec::new(),
+			command_links: false,
+			default_button: None,
+			radio_buttons: Vec::new(),
+			default_radio_button: None,
+			verification: None,
+			expanded_information: None,
+			expanded_control_text: None,
+			collapsed_control_text: None,
+			expanded_by_default: false,
+			expand_in_footer: false,
+			footer: None,
+			footer_icon: IconId::None,
+			progress_bar: None,
+			allow_cancellation: true,
+			width: 0,
+			callbacks: Callbacks::default(),
+		}
+	}
+
+	/// Sets the main instruction, displayed in a larger font above the content.
+	#[must_use]
+	pub fn main_instruction(mut self, text: &str) -> Self {
+		self.main_instruction = Some(text.to_owned());
+		self
+	}
+
+	/// Sets the content text. It may contain `<a href="...">` hyperlinks,
+	/// which are enabled if
+	/// [`on_hyperlink_clicked`](crate::task_dlg::TaskDialogBuilder::on_hyperlink_clicked)
+	/// is set.
+	#[must_use]
+	pub fn content(mut self, text: &str) -> Self {
+		self.content = Some(text.to_owned());
+		self
+	}
+
+	/// Sets the main icon.
+	#[must_use]
+	pub fn main_icon(mut self, icon: IconIdTdicon) -> Self {
+		self.main_icon = icon;
+		self
+	}
+
+	/// Sets the common buttons. Defaults to `co::TDCBF::OK`.
+	#[must_use]
+	pub fn common_buttons(mut self, btns: co::TDCBF) -> Self {
+		self.common_buttons = btns;
+		self
+	}
+
+	/// Adds a custom button, whose ID is returned if the user clicks it. Custom
+	/// buttons appear before the common buttons.
+	///
+	/// If no common buttons are wanted, call
+	/// [`common_buttons`](crate::task_dlg::TaskDialogBuilder::common_buttons)
+	/// with `co::TDCBF::NoValue`.
+	#[must_use]
+	pub fn button(mut self, id: u16, text: &str) -> Self {
+		self.buttons.push((id, text.to_owned()));
+		self
+	}
+
+	/// Displays the custom buttons as command links. The text after the first
+	/// new line of each button is displayed as a note, in a smaller font.
+	#[must_use]
+	pub fn command_links(mut self, command_links: bool) -> Self {
+		self.command_links = command_links;
+		self
+	}
+
+	/// Sets the button which initially has the focus.
+	#[must_use]
+	pub fn default_button(mut self, id: co::DLGID) -> Self {
+		self.default_button = Some(id);
+		self
+	}
+
+	/// Adds a radio button, whose ID is returned in
+	/// [`TaskDialogResult::radio_button`](crate::task_dlg::TaskDialogResult::radio_button).
+	#[must_use]
+	pub fn radio_button(mut self, id: u16, text: &str) -> Self {
+		self.radio_buttons.push((id, text.to_owned()));
+		self
+	}
+
+	/// Sets the radio button initially selected. Defaults to the first one.
+	#[must_use]
+	pub fn default_radio_button(mut self, id: u16) -> Self {
+		self.default_radio_button = Some(id);
+		self
+	}
+
+	/// Displays a verification checkbox with the given text, and its initial
+	/// state.
+	#[must_use]
+	pub fn verification(mut self, text: &str, checked: bool) -> Self {
+		self.verification = Some((text.to_owned(), checked));
+		self
+	}
+
+	/// Sets the additional information, hidden until the user clicks the
+	/// expando button.
+	#[must_use]
+	pub fn expanded_information(mut self, text: &str) -> Self {
+		self.expanded_information = Some(text.to_owned());
+		self
+	}
+
+	/// Sets the texts of the expando button, when the information is expanded
+	/// and collapsed.
+	#[must_use]
+	pub fn expando_texts(mut self, expanded: &str, collapsed: &str) -> Self {
+		self.expanded_control_text = Some(expanded.to_owned());
+		self.collapsed_control_text = Some(collapsed.to_owned());
+		self
+	}
+
+	/// Displays the additional information initially expanded.
+	#[must_use]
+	pub fn expanded_by_default(mut self, expanded: bool) -> Self {
+		self.expanded_by_default = expanded;
+		self
+	}
+
+	/// Displays the additional information in the footer area, instead of
+	/// right after the content.
+	#[must_use]
+	pub fn expand_in_footer(mut self, in_footer: bool) -> Self {
+		self.expand_in_footer = in_footer;
+		self
+	}
+
+	/// Sets the footer text.
+	#[must_use]
+	pub fn footer(mut self, text: &str) -> Self {
+		self.footer = Some(text.to_owned());
+		self
+	}
+
+	/// Sets the footer icon.
+	#[must_use]
+	pub fn footer_icon(mut self, icon: IconId) -> Self {
+		self.footer_icon = icon;
+		self
+	}
+
+	/// Displays a progress bar, which can be updated with
+	/// [`ActiveTaskDialog::set_progress_pos`](crate::task_dlg::ActiveTaskDialog::set_progress_pos).
+	#[must_use]
+	pub fn progress_bar(mut self, marquee: bool) -> Self {
+		self.progress_bar = Some(marquee);
+		self
+	}
+
+	/// Allows the dialog to be closed with Alt+F4, Esc and the title bar close
+	/// button, even without a Cancel button. Defaults to `true`.
+	#[must_use]
+	pub fn allow_cancellation(mut self, allow: bool) -> Self {
+		self.allow_cancellation = allow;
+		self
+	}
+
+	/// Sets the width of the client area, in dialog units. Defaults to zero,
+	/// which means the ideal width is calculated by the system.
+	#[must_use]
+	pub fn width(mut self, width: u32) -> Self {
+		self.width = width;
+		self
+	}
+
+	/// Sets the closure called when the dialog is created.
+	#[must_use]
+	pub fn on_created<F>(mut self, func: F) -> Self
+		where F: Fn(&ActiveTaskDialog) -> AnyResult<()> + 'static,
+	{
+		self.callbacks.created = Some(Box::new(move |dlg, _| func(dlg)));
+		self
+	}
+
+	/// Sets the closure called when the user clicks a button. Return `false`
+	/// to keep the dialog open.
+	#[must_use]
+	pub fn on_button_clicked<F>(mut self, func: F) -> Self
+		where F: Fn(&ActiveTaskDialog, co::DLGID) -> AnyResult<bool> + 'static,
+	{
+		self.callbacks.button_clicked = Some(Box::new(func));
+		self
+	}
+
+	/// Sets the closure called when the user selects a radio button.
+	#[must_use]
+	pub fn on_radio_button_clicked<F>(mut self, func: F) -> Self
+		where F: Fn(&ActiveTaskDialog, u16) -> AnyResult<()> + 'static,
+	{
+		self.callbacks.radio_button_clicked = Some(Box::new(func));
+		self
+	}
+
+	/// Sets the closure called when the user clicks a hyperlink, receiving its
+	/// `href`. Hyperlinks are enabled only if this closure is set.
+	#[must_use]
+	pub fn on_hyperlink_clicked<F>(mut self, func: F) -> Self
+		where F: Fn(&ActiveTaskDialog, &str) -> AnyResult<()> + 'static,
+	{
+		self.callbacks.hyperlink_clicked = Some(Box::new(move |dlg, href| func(dlg, &href)));
+		self
+	}
+
+	/// Sets the closure called when the user toggles the verification
+	/// checkbox, receiving its new state.
+	#[must_use]
+	pub fn on_verification_clicked<F>(mut self, func: F) -> Self
+		where F: Fn(&ActiveTaskDialog, bool) -> AnyResult<()> + 'static,
+	{
+		self.callbacks.verification_clicked = Some(Box::new(func));
+		self
+	}
+
+	/// Sets the closure called when the user clicks the expando button,
+	/// receiving whether the information is now expanded.
+	#[must_use]
+	pub fn on_expando_clicked<F>(mut self, func: F) -> Self
+		where F: Fn(&ActiveTaskDialog, bool) -> AnyResult<()> + 'static,
+	{
+		self.callbacks.expando_clicked = Some(Box::new(func));
+		self
+	}
+
+	/// Sets the closure called approximately every 200 milliseconds, receiving
+	/// the milliseconds since the dialog was created. Useful to update the
+	/// progress bar.
+	#[must_use]
+	pub fn on_timer<F>(mut self, func: F) -> Self
+		where F: Fn(&ActiveTaskDialog, u32) -> AnyResult<()> + 'static,
+	{
+		self.callbacks.timer = Some(Box::new(func));
+		self
+	}
+
+	/// Shows the modal task dialog, blocking until it's closed.
+	///
+	/// If a callback panics, the panic is resumed after the dialog is closed.
+	pub fn show(mut self, hparent: &HWND) -> AnyResult<TaskDialogResult> {
+		let mut tdc = TASKDIALOGCONFIG::default();
+		tdc.hwndParent = unsafe { hparent.raw_copy() };
+		tdc.dwCommonButtons = self.common_buttons;
+		tdc.set_pszMainIcon(std::mem::replace(&mut self.main_icon, IconIdTdicon::None));
+		tdc.set_pszFooterIcon(std::mem::replace(&mut self.footer_icon, IconId::None));
+		tdc.cxWidth = self.width;
+
+		let mut flags = co::TDF::POSITION_RELATIVE_TO_WINDOW;
+		if self.allow_cancellation {
+			flags |= co::TDF::ALLOW_DIALOG_CANCELLATION;
+		}
+		if self.command_links {
+			flags |= co::TDF::USE_COMMAND_LINKS;
+		}
+		if self.expanded_by_default {
+			flags |= co::TDF::EXPANDED_BY_DEFAULT;
+		}
+		if self.expand_in_footer {
+			flags |= co::TDF::EXPAND_FOOTER_AREA;
+		}
+		if let Some((_, true)) = self.verification {
+			flags |= co::TDF::VERIFICATION_FLAG_CHECKED;
+		}
+		match self.progress_bar {
+			Some(true) => flags |= co::TDF::SHOW_MARQUEE_PROGRESS_BAR,
+			Some(false) => flags |= co::TDF::SHOW_PROGRESS_BAR,
+			None => {},
+		}
+		if self.callbacks.hyperlink_clicked.is_some() {
+			flags |= co::TDF::ENABLE_HYPERLINKS;
+		}
+		if self.callbacks.timer.is_some() {
+			flags |= co::TDF::CALLBACK_TIMER;
+		}
+		tdc.dwFlags = flags;
+
+		let mut title_buf = WString::from_str(&self.title);
+		tdc.set_pszWindowTitle(Some(&mut title_buf));
+
+		let mut main_instruction_buf = WString::from_opt_str(self.main_instruction.as_deref());
+		if self.main_instruction.is_some() {
+			tdc.set_pszMainInstruction(Some(&mut main_instruction_buf));
+		}
+
+		let mut content_buf = WString::from_opt_str(self.content.as_deref());
+		if self.content.is_some() {
+			tdc.set_pszContent(Some(&mut content_buf));
+		}
+
+		let mut btn_bufs = self.buttons.iter()
+			.map(|(_, text)| WString::from_str(text))
+			.collect::<Vec<_>>();
+		let mut btns = self.buttons.iter().zip(btn_bufs.iter_mut())
+			.map(|((id, _), buf)| {
+				let mut btn = TASKDIALOG_BUTTON::default();
+				btn.set_nButtonID(*id);
+				btn.set_pszButtonText(Some(buf));
+				btn
+			})
+			.collect::<Vec<_>>();
+		if !btns.is_empty() {
+			tdc.set_pButtons(Some(&mut btns));
+		}
+		if let Some(id) = self.default_button {
+			tdc.nDefaultButton = u16::from(id) as _;
+		}
+
+		let mut radio_bufs = self.radio_buttons.iter()
+			.map(|(_, text)| WString::from_str(text))
+			.collect::<Vec<_>>();
+		let mut radios = self.radio_buttons.iter().zip(radio_bufs.iter_mut())
+			.map(|((id, _), buf)| {
+				let mut btn = TASKDIALOG_BUTTON::default();
+				btn.set_nButtonID(*id);
+				btn.set_pszButtonText(Some(buf));
+				btn
+			})
+			.collect::<Vec<_>>();
+		if !radios.is_empty() {
+			tdc.set_pRadioButtons(Some(&mut radios));
+		}
+		if let Some(id) = self.default_radio_button {
+			tdc.nDefaultRadioButton = id as _;
+		}
+
+		let mut verification_buf = WString::from_opt_str(
+			self.verification.as_ref().map(|(text, _)| text.as_str()));
+		if self.verification.is_some() {
+			tdc.set_pszVerificationText(Some(&mut verification_buf));
+		}
+
+		let mut expanded_info_buf = WString::from_opt_str(self.expanded_information.as_deref());
+		if self.expanded_information.is_some() {
+			tdc.set_pszExpandedInformation(Some(&mut expanded_info_buf));
+		}
+
+		let mut expanded_ctrl_buf = WString::from_opt_str(self.expanded_control_text.as_deref());
+		let mut collapsed_ctrl_buf = WString::from_opt_str(self.collapsed_control_text.as_deref());
+		if self.expanded_control_text.is_some() {
+			tdc.set_pszExpandedControlText(Some(&mut expanded_ctrl_buf));
+			tdc.set_pszCollapsedControlText(Some(&mut collapsed_ctrl_buf));
+		}
+
+		let mut footer_buf = WString::from_opt_str(self.footer.as_deref());
+		if self.footer.is_some() {
+			tdc.set_pszFooter(Some(&mut footer_buf));
+		}
+
+		let mut state = CallbackState {
+			callbacks: &self.callbacks,
+			error: None,
+			panic: None,
+		};
+		tdc.pfCallback = Some(task_dialog_proc);
+		tdc.lpCallbackData = &mut state as *mut _ as _;
+
+		let mut verification_checked = false;
+		let res = TaskDialogIndirect(&tdc,
+			self.verification.as_ref().map(|_| &mut verification_checked));
+
+		if let Some(payload) = state.panic {
+			panic::resume_unwind(payload);
+		}
+		if let Some(err) = state.error {
+			return Err(err);
+		}
+
+		let (button, radio_button) = res?;
+		Ok(TaskDialogResult { button, radio_button, verification_checked })
+	}
+}
@@ -0,0 +1,5 @@
+use crate::kernel::ffi_types::{HANDLE, HRES, PCVOID};
+
+extern_sys! { "uxtheme";
+	DrawThemeIcon(HANDLE, HANDLE, i32, i32, PCVOID, HANDLE, i32) -> HRES
+}
@@ -0,0 +1,46 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::{co, comctl_uxtheme};
+use crate::comctl::decl::HIMAGELIST;
+use crate::ole::decl::HrResult;
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::{Handle, uxtheme_Htheme};
+use crate::user::decl::{HDC, RECT};
+use crate::uxtheme::decl::HTHEME;
+
+impl comctl_uxtheme_Htheme for HTHEME {}
+
+/// This trait is enabled with `comctl` and `uxtheme` features, and provides
+/// methods for [`HTHEME`](crate::HTHEME).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait comctl_uxtheme_Htheme: uxtheme_Htheme {
+	/// [`DrawThemeIcon`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-drawthemeicon)
+	/// method.
+	fn DrawThemeIcon(&self,
+		hdc: &HDC,
+		part_state: co::VS,
+		rc: RECT,
+		himagelist: &HIMAGELIST,
+		image_index: u32,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				comctl_uxtheme::ffi::DrawThemeIcon(
+					self.as_ptr(),
+					hdc.as_ptr(),
+					part_state.part,
+					part_state.state,
+					&rc as *const _ as _,
+					himagelist.as_ptr(),
+					image_index as _,
+				)
+			},
+		)
+	}
+}
@@ -0,0 +1,5 @@
+mod htheme;
+
+pub mod traits {
+	pub use super::htheme::comctl_uxtheme_Htheme;
+}
@@ -0,0 +1,9 @@
+#![cfg_attr(docsrs, doc(cfg(all(feature = "comctl", feature = "uxtheme"))))]
+
+pub(in crate::comctl_uxtheme) mod ffi;
+
+mod handles;
+
+pub mod traits {
+	pub use super::handles::traits::*;
+}
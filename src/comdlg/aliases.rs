@@ -10,3 +10,14 @@ pub type CCHOOKPROC =
 		wParam: usize,
 		lParam: isize,
 	) -> usize;
+
+/// Type alias to
+/// [`CFHOOKPROC`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/nc-commdlg-lpcfhookproc)
+/// callback function.
+pub type CFHOOKPROC =
+	extern "system" fn(
+		hWnd: HWND,
+		uMsg: u32,
+		wParam: usize,
+		lParam: isize,
+	) -> usize;
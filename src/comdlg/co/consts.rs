@@ -1,3 +1,5 @@
+#![allow(non_camel_case_types)]
+
 const_bitflag! { CC: u32;
 	/// [`CHOOSECOLOR`](crate::CHOOSECOLOR) `Flags` (`u32`).
 	=>
@@ -35,3 +37,92 @@ const_bitflag! { CC: u32;
 	/// basic colors.
 	ANYCOLOR 0x0000_0100
 }
+
+const_bitflag! { CF_FONT: u32;
+	/// [`CHOOSEFONT`](crate::CHOOSEFONT) `Flags` (`u32`).
+	///
+	/// Originally has `CF` prefix, which collides with the clipboard formats.
+	=>
+	=>
+	/// Causes the dialog box to display the Apply button.
+	APPLY 0x0000_0200
+	/// Obsolete, the font list is always based on the screen fonts.
+	BOTH 0x0000_0003
+	/// Causes the dialog box to display the controls that allow the user to
+	/// specify strikeout, underline, and text color options. If this flag is
+	/// set, you can use the `rgbColors` member to specify the initial text
+	/// color.
+	EFFECTS 0x0000_0100
+	/// Enables the hook procedure specified in the `lpfnHook` member.
+	ENABLEHOOK 0x0000_0008
+	/// Indicates that the `hInstance` and `lpTemplateName` members specify a
+	/// dialog box template to use in place of the default template.
+	ENABLETEMPLATE 0x0000_0010
+	/// Indicates that the `hInstance` member identifies a data block that
+	/// contains a preloaded dialog box template.
+	ENABLETEMPLATEHANDLE 0x0000_0020
+	/// Causes the dialog box to list only fixed-pitch fonts.
+	FIXEDPITCHONLY 0x0000_4000
+	/// Causes the dialog box to display an error message if the user attempts
+	/// to select a font or style that is not listed in the dialog box.
+	FORCEFONTEXIST 0x0001_0000
+	/// Causes the dialog box to display all fonts.
+	INACTIVEFONTS 0x0200_0000
+	/// Causes the dialog box to use the structure pointed to by the
+	/// `lpLogFont` member to initialize the dialog box controls.
+	INITTOLOGFONTSTRUCT 0x0000_0040
+	/// Causes the dialog box to select only font sizes within the range
+	/// specified by the `nSizeMin` and `nSizeMax` members.
+	LIMITSIZE 0x0000_2000
+	/// Causes the dialog box to allow only the selection of fonts that use
+	/// the ANSI character set.
+	NOOEMFONTS 0x0000_0800
+	/// Causes the dialog box to select no face name.
+	NOFACESEL 0x0008_0000
+	/// Disables the Script combo box.
+	NOSCRIPTSEL 0x0080_0000
+	/// Causes the dialog box to not display or allow selection of font
+	/// simulations.
+	NOSIMULATIONS 0x0000_1000
+	/// Causes the dialog box to select no font size.
+	NOSIZESEL 0x0020_0000
+	/// Causes the dialog box to select no font style.
+	NOSTYLESEL 0x0010_0000
+	/// Causes the dialog box to not allow vector font selections.
+	NOVECTORFONTS 0x0000_0800
+	/// Causes the dialog box to display only horizontally oriented fonts.
+	NOVERTFONTS 0x0100_0000
+	/// Obsolete, the font list is always based on the screen fonts.
+	PRINTERFONTS 0x0000_0002
+	/// Causes the dialog box to allow only the selection of scalable fonts.
+	SCALABLEONLY 0x0002_0000
+	/// Obsolete, the font list is always based on the screen fonts.
+	SCREENFONTS 0x0000_0001
+	/// Causes the dialog box to allow selection of fonts for all non-OEM and
+	/// Symbol character sets, as well as the ANSI character set.
+	SCRIPTSONLY 0x0000_0400
+	/// Allows only the selection of fonts with the character set identified in
+	/// the `lfCharSet` member of the `LOGFONT` structure.
+	SELECTSCRIPT 0x0040_0000
+	/// Causes the dialog box to display the Help button.
+	SHOWHELP 0x0000_0004
+	/// Causes the dialog box to use the `lpszStyle` member to initialize the
+	/// font style combo box.
+	USESTYLE 0x0000_0080
+	/// Obsolete.
+	WYSIWYG 0x0000_8000
+	/// Causes the dialog box to list only TrueType fonts.
+	TTONLY 0x0004_0000
+}
+
+const_bitflag! { FONTTYPE: u16;
+	/// [`CHOOSEFONT`](crate::CHOOSEFONT) `nFontType` (`u16`).
+	=>
+	=>
+	BOLD 0x0100
+	ITALIC 0x0200
+	PRINTER 0x4000
+	REGULAR 0x0400
+	SCREEN 0x2000
+	SIMULATED 0x8000
+}
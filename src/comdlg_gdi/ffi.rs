@@ -0,0 +1,5 @@
+use crate::kernel::ffi_types::{BOOL, PVOID};
+
+extern_sys! { "comdlg32";
+	ChooseFontW(PVOID) -> BOOL
+}
@@ -0,0 +1,42 @@
+#![allow(non_snake_case)]
+
+use crate::{co, comdlg_gdi};
+use crate::comdlg::decl::CommDlgExtendedError;
+use crate::comdlg_gdi::decl::CHOOSEFONT;
+
+/// [`ChooseFont`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-choosefontw)
+/// function.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, ChooseFont, CHOOSEFONT, HWND, LOGFONT};
+///
+/// let parent_hwnd: HWND; // initialized somewhere
+/// # let parent_hwnd = HWND::NULL;
+///
+/// let mut cf = CHOOSEFONT::default();
+/// let mut lf = LOGFONT::default();
+///
+/// cf.hwndOwner = parent_hwnd;
+/// cf.Flags = co::CF_FONT::EFFECTS | co::CF_FONT::INITTOLOGFONTSTRUCT;
+/// cf.set_lpLogFont(Some(&mut lf));
+///
+/// if ChooseFont(&mut cf)? {
+///     println!("The font: {}, {} pt",
+///         cf.lpLogFont().unwrap().lfFaceName(),
+///         cf.iPointSize / 10,
+///     );
+/// }
+/// # Ok::<_, co::CDERR>(())
+/// ```
+pub fn ChooseFont(cf: &mut CHOOSEFONT) -> Result<bool, co::CDERR> {
+	match unsafe { comdlg_gdi::ffi::ChooseFontW(cf as *mut _ as _) } {
+		0 => match CommDlgExtendedError() {
+			co::CDERR::NoValue => Ok(false),
+			err => Err(err),
+		},
+		_ => Ok(true),
+	}
+}
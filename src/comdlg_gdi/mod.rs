@@ -0,0 +1,11 @@
+#![cfg_attr(docsrs, doc(cfg(all(feature = "comdlg", feature = "gdi"))))]
+
+pub(in crate::comdlg_gdi) mod ffi;
+
+mod funcs;
+mod structs;
+
+pub mod decl {
+	pub use super::funcs::*;
+	pub use super::structs::*;
+}
@@ -0,0 +1,42 @@
+#![allow(non_snake_case)]
+
+use std::marker::PhantomData;
+
+use crate::co;
+use crate::comdlg::decl::CFHOOKPROC;
+use crate::gdi::decl::LOGFONT;
+use crate::kernel::decl::{HINSTANCE, WString};
+use crate::user::decl::{COLORREF, HDC, HWND};
+
+/// [`CHOOSEFONT`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/ns-commdlg-choosefontw)
+/// struct.
+#[repr(C)]
+pub struct CHOOSEFONT<'a, 'b> {
+	lStructSize: u32,
+	pub hwndOwner: HWND,
+	pub hDC: HDC,
+	lpLogFont: *mut LOGFONT,
+	pub iPointSize: i32,
+	pub Flags: co::CF_FONT,
+	pub rgbColors: COLORREF,
+	pub lCustData: isize,
+	pub lpfnHook: Option<CFHOOKPROC>,
+	lpTemplateName: *mut u16, // u16 resource ID
+	pub hInstance: HINSTANCE,
+	lpszStyle: *mut u16,
+	pub nFontType: co::FONTTYPE,
+	___MISSING_ALIGNMENT__: u16,
+	pub nSizeMin: i32,
+	pub nSizeMax: i32,
+
+	_lpLogFont: PhantomData<&'a mut LOGFONT>,
+	_lpszStyle: PhantomData<&'b mut u16>,
+}
+
+impl_default_with_size!(CHOOSEFONT, lStructSize, 'a, 'b);
+
+impl<'a, 'b> CHOOSEFONT<'a, 'b> {
+	pub_fn_ptr_get_set!('a, lpLogFont, set_lpLogFont, LOGFONT);
+	pub_fn_resource_id_get_set!(lpTemplateName, set_lpTemplateName);
+	pub_fn_string_ptr_get_set!('b, lpszStyle, set_lpszStyle);
+}
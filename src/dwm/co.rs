@@ -0,0 +1,43 @@
+#![allow(non_camel_case_types)]
+
+const_ordinary! { DWMWA: u32;
+	/// [`DWMWINDOWATTRIBUTE`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/ne-dwmapi-dwmwindowattribute)
+	/// enumeration (`u32`).
+	=>
+	=>
+	NCRENDERING_ENABLED 1
+	NCRENDERING_POLICY 2
+	TRANSITIONS_FORCEDISABLED 3
+	ALLOW_NCPAINT 4
+	CAPTION_BUTTON_BOUNDS 5
+	NONCLIENT_RTL_LAYOUT 6
+	FORCE_ICONIC_REPRESENTATION 7
+	FLIP3D_POLICY 8
+	EXTENDED_FRAME_BOUNDS 9
+	HAS_ICONIC_BITMAP 10
+	DISALLOW_PEEK 11
+	EXCLUDED_FROM_PEEK 12
+	CLOAK 13
+	CLOAKED 14
+	FREEZE_REPRESENTATION 15
+	PASSIVE_UPDATE_MODE 16
+	USE_HOSTBACKDROPBRUSH 17
+	USE_IMMERSIVE_DARK_MODE 20
+	WINDOW_CORNER_PREFERENCE 33
+	BORDER_COLOR 34
+	CAPTION_COLOR 35
+	TEXT_COLOR 36
+	VISIBLE_FRAME_BORDER_THICKNESS 37
+	SYSTEMBACKDROP_TYPE 38
+}
+
+const_ordinary! { DWMWCP: u32;
+	/// [`DWM_WINDOW_CORNER_PREFERENCE`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/ne-dwmapi-dwm_window_corner_preference)
+	/// enumeration (`u32`).
+	=>
+	=>
+	DEFAULT 0
+	DONOTROUND 1
+	ROUND 2
+	ROUNDSMALL 3
+}
@@ -0,0 +1,38 @@
+use crate::co;
+use crate::user::decl::COLORREF;
+
+/// Variant parameter for:
+///
+/// * [`HWND::DwmSetWindowAttribute`](crate::prelude::dwm_Hwnd::DwmSetWindowAttribute).
+#[derive(Clone, Copy)]
+pub enum DwmAttr {
+	/// [`co::DWMWA::USE_IMMERSIVE_DARK_MODE`](crate::co::DWMWA::USE_IMMERSIVE_DARK_MODE),
+	/// available since Windows 10 build 18985.
+	UseImmersiveDarkMode(bool),
+	/// [`co::DWMWA::WINDOW_CORNER_PREFERENCE`](crate::co::DWMWA::WINDOW_CORNER_PREFERENCE),
+	/// available since Windows 11.
+	WindowCornerPreference(co::DWMWCP),
+	/// [`co::DWMWA::BORDER_COLOR`](crate::co::DWMWA::BORDER_COLOR), available
+	/// since Windows 11.
+	BorderColor(COLORREF),
+	/// [`co::DWMWA::CAPTION_COLOR`](crate::co::DWMWA::CAPTION_COLOR),
+	/// available since Windows 11.
+	CaptionColor(COLORREF),
+	/// [`co::DWMWA::TEXT_COLOR`](crate::co::DWMWA::TEXT_COLOR), available
+	/// since Windows 11.
+	TextColor(COLORREF),
+}
+
+impl DwmAttr {
+	/// Returns the attribute identifier and its 32-bit value.
+	#[must_use]
+	pub(in crate::dwm) fn id_val(&self) -> (co::DWMWA, u32) {
+		match self {
+			Self::UseImmersiveDarkMode(b) => (co::DWMWA::USE_IMMERSIVE_DARK_MODE, *b as _),
+			Self::WindowCornerPreference(c) => (co::DWMWA::WINDOW_CORNER_PREFERENCE, c.0),
+			Self::BorderColor(c) => (co::DWMWA::BORDER_COLOR, c.0),
+			Self::CaptionColor(c) => (co::DWMWA::CAPTION_COLOR, c.0),
+			Self::TextColor(c) => (co::DWMWA::TEXT_COLOR, c.0),
+		}
+	}
+}
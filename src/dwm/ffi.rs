@@ -0,0 +1,5 @@
+use crate::kernel::ffi_types::{HANDLE, HRES, PCVOID};
+
+extern_sys! { "dwmapi";
+	DwmSetWindowAttribute(HANDLE, u32, PCVOID, u32) -> HRES
+}
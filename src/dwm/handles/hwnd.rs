@@ -0,0 +1,49 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::dwm;
+use crate::dwm::decl::DwmAttr;
+use crate::ole::decl::HrResult;
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::Handle;
+use crate::user::decl::HWND;
+
+impl dwm_Hwnd for HWND {}
+
+/// This trait is enabled with the `dwm` feature, and provides methods for
+/// [`HWND`](crate::HWND).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait dwm_Hwnd: Handle {
+	/// [`DwmSetWindowAttribute`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmsetwindowattribute)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{DwmAttr, HWND};
+	///
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	///
+	/// hwnd.DwmSetWindowAttribute(DwmAttr::UseImmersiveDarkMode(true))?;
+	/// # Ok::<_, winsafe::co::HRESULT>(())
+	/// ```
+	fn DwmSetWindowAttribute(&self, attr: DwmAttr) -> HrResult<()> {
+		let (id, val) = attr.id_val();
+		ok_to_hrresult(
+			unsafe {
+				dwm::ffi::DwmSetWindowAttribute(
+					self.as_ptr(),
+					id.0,
+					&val as *const _ as _,
+					std::mem::size_of::<u32>() as _,
+				)
+			},
+		)
+	}
+}
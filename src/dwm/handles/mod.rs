@@ -0,0 +1,5 @@
+mod hwnd;
+
+pub mod traits {
+	pub use super::hwnd::dwm_Hwnd;
+}
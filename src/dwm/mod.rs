@@ -0,0 +1,15 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "dwm")))]
+
+pub(in crate::dwm) mod ffi;
+pub mod co;
+
+mod enums;
+mod handles;
+
+pub mod decl {
+	pub use super::enums::*;
+}
+
+pub mod traits {
+	pub use super::handles::traits::*;
+}
@@ -91,6 +91,14 @@ const_ordinary! { CLIP: u8;
 	EMBEDDED 8 << 4
 }
 
+const_bitflag! { DI: u32;
+	/// [`DOCINFO`](crate::DOCINFO) `fwType` (`u32`).
+	=>
+	=>
+	APPBANDING 0x0000_0001
+	ROPS_READ_DESTINATION 0x0000_0002
+}
+
 const_ordinary! { DIB: u32;
 	/// [`LOGBRUSH`](crate::LOGBRUSH) `lbColor` (`u32`).
 	=>
@@ -219,7 +227,10 @@ const_bitflag! { LR: u32;
 	/// [`HINSTANCE::LoadImageCursor`](crate::prelude::gdi_Hinstance::LoadImageCursor)
 	/// and
 	/// [`HINSTANCE::LoadImageIcon`](crate::prelude::gdi_Hinstance::LoadImageIcon)
-	/// `load`.
+	/// `load`; also
+	/// [`HICON::CreateIconFromResourceEx`](crate::prelude::gdi_Hicon::CreateIconFromResourceEx)
+	/// and [`LookupIconIdFromDirectoryEx`](crate::LookupIconIdFromDirectoryEx)
+	/// `flags`.
 	=>
 	=>
 	DEFAULTCOLOR 0x0000_0000
@@ -284,8 +295,106 @@ impl PITCH {
 	}
 }
 
-const_ordinary! { PS: i32;
-	/// [`HPEN::CreatePen`](crate::prelude::gdi_Hpen::CreatePen) `style`
+const_ordinary! { POLY_FILL: i32;
+	/// [`HRGN::CreatePolygonRgn`](crate::prelude::gdi_Hrgn::CreatePolygonRgn)
+	/// `mode` (`i32`).
+	=>
+	=>
+	ALTERNATE 1
+	WINDING 2
+}
+
+const_bitflag! { PRINTER_ACCESS: u32;
+	/// [`PRINTER_DEFAULTS`](crate::PRINTER_DEFAULTS) `DesiredAccess` (`u32`).
+	=>
+	=>
+	ADMINISTER 0x0000_0004
+	USE 0x0000_0008
+	MANAGE_LIMITED 0x0000_0040
+	ALL_ACCESS 0x000f_000c
+	READ 0x0002_0008
+	WRITE 0x0002_0008
+	EXECUTE 0x0002_0008
+}
+
+const_bitflag! { PRINTER_ATTRIBUTE: u32;
+	/// [`PRINTER_INFO_2`](crate::PRINTER_INFO_2) `Attributes` (`u32`).
+	=>
+	=>
+	QUEUED 0x0000_0001
+	DIRECT 0x0000_0002
+	DEFAULT 0x0000_0004
+	SHARED 0x0000_0008
+	NETWORK 0x0000_0010
+	HIDDEN 0x0000_0020
+	LOCAL 0x0000_0040
+	ENABLE_DEVQ 0x0000_0080
+	KEEPPRINTEDJOBS 0x0000_0100
+	DO_COMPLETE_FIRST 0x0000_0200
+	WORK_OFFLINE 0x0000_0400
+	ENABLE_BIDI 0x0000_0800
+	RAW_ONLY 0x0000_1000
+	PUBLISHED 0x0000_2000
+	FAX 0x0000_4000
+	TS 0x0000_8000
+	PUSHED_USER 0x0002_0000
+	PUSHED_MACHINE 0x0004_0000
+	MACHINE 0x0008_0000
+	FRIENDLY_NAME 0x0010_0000
+	TS_GENERIC_DRIVER 0x0020_0000
+}
+
+const_bitflag! { PRINTER_ENUM: u32;
+	/// [`EnumPrinters`](crate::EnumPrinters) `flags` (`u32`).
+	=>
+	=>
+	DEFAULT 0x0000_0001
+	LOCAL 0x0000_0002
+	CONNECTIONS 0x0000_0004
+	FAVORITE 0x0000_0004
+	NAME 0x0000_0008
+	REMOTE 0x0000_0010
+	SHARED 0x0000_0020
+	NETWORK 0x0000_0040
+	EXPAND 0x0000_4000
+	CONTAINER 0x0000_8000
+}
+
+const_bitflag! { PRINTER_STATUS: u32;
+	/// [`PRINTER_INFO_2`](crate::PRINTER_INFO_2) `Status` (`u32`).
+	=>
+	=>
+	READY 0
+	PAUSED 0x0000_0001
+	ERROR 0x0000_0002
+	PENDING_DELETION 0x0000_0004
+	PAPER_JAM 0x0000_0008
+	PAPER_OUT 0x0000_0010
+	MANUAL_FEED 0x0000_0020
+	PAPER_PROBLEM 0x0000_0040
+	OFFLINE 0x0000_0080
+	IO_ACTIVE 0x0000_0100
+	BUSY 0x0000_0200
+	PRINTING 0x0000_0400
+	OUTPUT_BIN_FULL 0x0000_0800
+	NOT_AVAILABLE 0x0000_1000
+	WAITING 0x0000_2000
+	PROCESSING 0x0000_4000
+	INITIALIZING 0x0000_8000
+	WARMING_UP 0x0001_0000
+	TONER_LOW 0x0002_0000
+	NO_TONER 0x0004_0000
+	PAGE_PUNT 0x0008_0000
+	USER_INTERVENTION 0x0010_0000
+	OUT_OF_MEMORY 0x0020_0000
+	DOOR_OPEN 0x0040_0000
+	SERVER_UNKNOWN 0x0080_0000
+	POWER_SAVE 0x0100_0000
+}
+
+const_bitflag! { PS: i32;
+	/// [`HPEN::CreatePen`](crate::prelude::gdi_Hpen::CreatePen) and
+	/// [`HPEN::ExtCreatePen`](crate::prelude::gdi_Hpen::ExtCreatePen) `style`
 	/// (`i32`).
 	=>
 	=>
@@ -296,6 +405,19 @@ const_ordinary! { PS: i32;
 	DASHDOTDOT 4
 	NULL 5
 	INSIDEFRAME 6
+	USERSTYLE 7
+	ALTERNATE 8
+
+	ENDCAP_ROUND 0x0000_0000
+	ENDCAP_SQUARE 0x0000_0100
+	ENDCAP_FLAT 0x0000_0200
+
+	JOIN_ROUND 0x0000_0000
+	JOIN_BEVEL 0x0000_1000
+	JOIN_MITER 0x0000_2000
+
+	COSMETIC 0x0000_0000
+	GEOMETRIC 0x0001_0000
 }
 
 const_ordinary! { QUALITY: u8;
@@ -312,8 +434,54 @@ const_ordinary! { QUALITY: u8;
 	CLEARTYPE_NATURAL 6
 }
 
+const_ordinary! { R2: i32;
+	/// [`HDC::SetROP2`](crate::prelude::gdi_Hdc::SetROP2) `mode` (`i32`).
+	=>
+	=>
+	/// Pixel is always black.
+	BLACK 1
+	/// Pixel is the inverse of the `MERGEPEN` color.
+	NOTMERGEPEN 2
+	/// Pixel is a combination of the colors common to both the screen and the
+	/// inverse of the pen.
+	MASKNOTPEN 3
+	/// Pixel is the inverse of the pen color.
+	NOTCOPYPEN 4
+	/// Pixel is a combination of the colors common to both the pen and the
+	/// inverse of the screen.
+	MASKPENNOT 5
+	/// Pixel is the inverse of the screen color.
+	NOT 6
+	/// Pixel is a combination of the colors in the pen and in the screen, but
+	/// not in both. Drawing twice restores the original pixels, which is useful
+	/// for rubber-band rectangles.
+	XORPEN 7
+	/// Pixel is the inverse of the `MASKPEN` color.
+	NOTMASKPEN 8
+	/// Pixel is a combination of the colors common to both the pen and the
+	/// screen.
+	MASKPEN 9
+	/// Pixel is the inverse of the `XORPEN` color.
+	NOTXORPEN 10
+	/// Pixel remains unchanged.
+	NOP 11
+	/// Pixel is a combination of the screen color and the inverse of the pen
+	/// color.
+	MERGENOTPEN 12
+	/// Pixel is the pen color.
+	COPYPEN 13
+	/// Pixel is a combination of the pen color and the inverse of the screen
+	/// color.
+	MERGEPENNOT 14
+	/// Pixel is a combination of the pen color and the screen color.
+	MERGEPEN 15
+	/// Pixel is always white.
+	WHITE 16
+}
+
 const_ordinary! { RGN: i32;
-	/// [`HDC::SelectClipPath`](crate::prelude::gdi_Hdc::SelectClipPath) `mode`
+	/// [`HDC::SelectClipPath`](crate::prelude::gdi_Hdc::SelectClipPath) and
+	/// [`HRGN::CombineRgn`](crate::prelude::gdi_Hrgn::CombineRgn) `mode`
 	/// (`i32`).
 	=>
 	=>
@@ -327,6 +495,8 @@ const_ordinary! { RGN: i32;
 const_ordinary! { ROP: u32;
 	/// Raster operation code (`u32`).
 	/// [`HDC::BitBlt`](crate::prelude::gdi_Hdc::BitBlt) `rop`,
+	/// [`HDC::MaskBlt`](crate::prelude::gdi_Hdc::MaskBlt) `fore_rop` and
+	/// `back_rop`,
 	/// [`HDC::PatBlt`](crate::prelude::gdi_Hdc::PatBlt) `rop` and
 	/// [`IMAGELISTDRAWPARAMS`](crate::IMAGELISTDRAWPARAMS) `dwRop`.
 	///
@@ -1,6 +1,7 @@
 use crate::kernel::ffi_types::{BOOL, HANDLE, PCSTR, PCVOID, PSTR, PVOID};
 
 extern_sys! { "gdi32";
+	AbortDoc(HANDLE) -> i32
 	AbortPath(HANDLE) -> BOOL
 	AngleArc(HANDLE, i32, i32, u32, f32, f32) -> BOOL
 	Arc(HANDLE, i32, i32, i32, i32, i32, i32, i32, i32) -> BOOL
@@ -10,18 +11,23 @@ extern_sys! { "gdi32";
 	CancelDC(HANDLE) -> BOOL
 	Chord(HANDLE, i32, i32, i32, i32, i32, i32, i32, i32) -> BOOL
 	CloseFigure(HANDLE) -> BOOL
+	CombineRgn(HANDLE, HANDLE, HANDLE, i32) -> i32
 	CreateBitmap(i32, i32, u32, u32, PVOID) -> HANDLE
 	CreateBrushIndirect(PCVOID) -> HANDLE
 	CreateCompatibleBitmap(HANDLE, i32, i32) -> HANDLE
 	CreateCompatibleDC(HANDLE) -> HANDLE
+	CreateDCW(PCSTR, PCSTR, PCSTR, PCVOID) -> HANDLE
+	CreateFontIndirectExW(PCVOID) -> HANDLE
 	CreateFontIndirectW(PCVOID) -> HANDLE
 	CreateFontW(i32, i32, i32, i32, i32, u32, u32, u32, u32, u32, u32, u32, u32, PCSTR) -> HANDLE
 	CreateHalftonePalette(HANDLE) -> HANDLE
+	CreateEllipticRgn(i32, i32, i32, i32) -> HANDLE
 	CreateHatchBrush(i32, u32) -> HANDLE
 	CreatePalette(PCVOID) -> HANDLE
 	CreatePatternBrush(HANDLE) -> HANDLE
 	CreatePen(i32, i32, u32) -> HANDLE
 	CreatePenIndirect(PCVOID) -> HANDLE
+	CreatePolygonRgn(PCVOID, i32, i32) -> HANDLE
 	CreateRectRgn(i32, i32, i32, i32) -> HANDLE
 	CreateRectRgnIndirect(PVOID) -> HANDLE
 	CreateRoundRectRgn(i32, i32, i32, i32, i32, i32) -> HANDLE
@@ -29,7 +35,11 @@ extern_sys! { "gdi32";
 	DeleteDC(HANDLE) -> BOOL
 	DeleteObject(HANDLE) -> BOOL
 	Ellipse(HANDLE, i32, i32, i32, i32) -> BOOL
+	EndDoc(HANDLE) -> i32
+	EndPage(HANDLE) -> i32
 	EndPath(HANDLE) -> BOOL
+	EqualRgn(HANDLE, HANDLE) -> BOOL
+	ExtCreatePen(u32, u32, PCVOID, u32, PCVOID) -> HANDLE
 	FillPath(HANDLE) -> BOOL
 	FillRect(HANDLE, PCVOID, HANDLE) -> i32
 	FillRgn(HANDLE, HANDLE, HANDLE) -> BOOL
@@ -45,6 +55,8 @@ extern_sys! { "gdi32";
 	GetDeviceCaps(HANDLE, i32) -> i32
 	GetDIBits(HANDLE, HANDLE, u32, u32, PVOID, PVOID, u32) -> i32
 	GetObjectW(HANDLE, i32, PVOID) -> i32
+	GetRegionData(HANDLE, u32, PVOID) -> u32
+	GetROP2(HANDLE) -> i32
 	GetStockObject(i32) -> HANDLE
 	GetStretchBltMode(HANDLE) -> i32
 	GetSysColorBrush(i32) -> HANDLE
@@ -57,12 +69,14 @@ extern_sys! { "gdi32";
 	GetWindowExtEx(HANDLE, PVOID) -> BOOL
 	GetWindowOrgEx(HANDLE, PVOID) -> BOOL
 	LineTo(HANDLE, i32, i32) -> BOOL
+	MaskBlt(HANDLE, i32, i32, i32, i32, HANDLE, i32, i32, HANDLE, i32, i32, u32) -> BOOL
 	MoveToEx(HANDLE, i32, i32, PVOID) -> BOOL
 	OffsetClipRgn(HANDLE, i32, i32) -> i32
 	OffsetRgn(HANDLE, i32, i32) -> i32
 	PatBlt(HANDLE, i32, i32, i32, i32, u32) -> BOOL
 	PathToRegion(HANDLE) -> HANDLE
 	Pie(HANDLE, i32, i32, i32, i32, i32, i32, i32, i32) -> BOOL
+	PlgBlt(HANDLE, PCVOID, HANDLE, i32, i32, i32, i32, HANDLE, i32, i32) -> BOOL
 	PolyBezier(HANDLE, PCVOID, u32) -> BOOL
 	PolyBezierTo(HANDLE, PCVOID, u32) -> BOOL
 	Polyline(HANDLE, PCVOID, u32) -> BOOL
@@ -86,6 +100,7 @@ extern_sys! { "gdi32";
 	SetDCBrushColor(HANDLE, u32) -> u32
 	SetDCPenColor(HANDLE, u32) -> u32
 	SetGraphicsMode(HANDLE, i32) -> i32
+	SetROP2(HANDLE, i32) -> i32
 	SetStretchBltMode(HANDLE, i32) -> i32
 	SetTextAlign(HANDLE, u32) -> u32
 	SetTextColor(HANDLE, u32) -> u32
@@ -94,6 +109,8 @@ extern_sys! { "gdi32";
 	SetViewportOrgEx(HANDLE, i32, i32, PVOID) -> BOOL
 	SetWindowExtEx(HANDLE, i32, i32, PVOID) -> BOOL
 	SetWindowOrgEx(HANDLE, i32, i32, PVOID) -> BOOL
+	StartDocW(HANDLE, PCVOID) -> i32
+	StartPage(HANDLE) -> i32
 	StretchBlt(HANDLE, i32, i32, i32, i32, HANDLE, i32, i32, i32, i32, u32) -> BOOL
 	StrokeAndFillPath(HANDLE) -> BOOL
 	StrokePath(HANDLE) -> BOOL
@@ -104,5 +121,18 @@ extern_sys! { "gdi32";
 }
 
 extern_sys! { "user32";
+	CreateIconFromResourceEx(PCVOID, u32, BOOL, u32, i32, i32, u32) -> HANDLE
+	CreateIconIndirect(PCVOID) -> HANDLE
+	GetIconInfo(HANDLE, PVOID) -> BOOL // returns ICONINFO with HBITMAP, so needs gdi feature
 	LoadImageW(HANDLE, PCSTR, u32, i32, i32, u32) -> HANDLE // returns GdiObjectGuard, so needs gdi feature
+	LookupIconIdFromDirectoryEx(PCVOID, BOOL, i32, i32, u32) -> i32
+}
+
+extern_sys! { "winspool";
+	ClosePrinter(HANDLE) -> BOOL
+	EnumPrintersW(u32, PCSTR, u32, PVOID, u32, *mut u32, *mut u32) -> BOOL
+	GetDefaultPrinterW(PSTR, *mut u32) -> BOOL
+	GetPrinterW(HANDLE, u32, PVOID, u32, *mut u32) -> BOOL
+	OpenPrinterW(PCSTR, *mut HANDLE, PCVOID) -> BOOL
+	SetDefaultPrinterW(PCSTR) -> BOOL
 }
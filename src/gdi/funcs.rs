@@ -1,8 +1,80 @@
 #![allow(non_snake_case)]
 
-use crate::gdi;
-use crate::kernel::decl::{GetLastError, SysResult};
+use crate::{co, gdi};
+use crate::gdi::guard::PrinterInfoGuard;
+use crate::kernel::decl::{GetLastError, SysResult, WString};
 use crate::kernel::privs::bool_to_sysresult;
+use crate::prelude::PrinterInfo;
+use crate::user::decl::SIZE;
+
+/// [`EnumPrinters`](https://learn.microsoft.com/en-us/windows/win32/printdocs/enumprinters)
+/// function.
+///
+/// The information level is given by the type of the returned structs.
+///
+/// # Examples
+///
+/// Listing the local and connected printers:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, EnumPrinters, PRINTER_INFO_4};
+///
+/// let printers = EnumPrinters::<PRINTER_INFO_4>(
+///     co::PRINTER_ENUM::LOCAL | co::PRINTER_ENUM::CONNECTIONS,
+///     None,
+/// )?;
+///
+/// for printer in printers.iter() {
+///     println!("{}", printer.pPrinterName().unwrap_or_default());
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[must_use]
+pub fn EnumPrinters<T>(
+	flags: co::PRINTER_ENUM,
+	name: Option<&str>,
+) -> SysResult<Vec<PrinterInfoGuard<T>>>
+	where T: PrinterInfo,
+{
+	let wname = WString::from_opt_str(name);
+	let mut needed = u32::default();
+	let mut count = u32::default();
+
+	unsafe {
+		gdi::ffi::EnumPrintersW( // retrieve needed buffer size
+			flags.0,
+			wname.as_ptr(),
+			T::LEVEL,
+			std::ptr::null_mut(),
+			0,
+			&mut needed,
+			&mut count,
+		);
+	}
+	if needed == 0 {
+		return Ok(Vec::default()); // no printers
+	}
+	let get_size_err = GetLastError();
+	if get_size_err != co::ERROR::INSUFFICIENT_BUFFER {
+		return Err(get_size_err);
+	}
+
+	let mut buf = vec![0u64; (needed as usize).div_ceil(8)];
+	bool_to_sysresult(
+		unsafe {
+			gdi::ffi::EnumPrintersW(
+				flags.0,
+				wname.as_ptr(),
+				T::LEVEL,
+				buf.as_mut_ptr() as _,
+				needed,
+				&mut needed,
+				&mut count,
+			)
+		},
+	).map(|_| PrinterInfoGuard::from_buf(buf, count as _))
+}
 
 /// [`GdiFlush`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-gdiflush)
 /// function.
@@ -25,3 +97,57 @@ pub fn GdiSetBatchLimit(limit: u32) -> SysResult<u32> {
 		n => Ok(n),
 	}
 }
+
+/// [`GetDefaultPrinter`](https://learn.microsoft.com/en-us/windows/win32/printdocs/getdefaultprinter)
+/// function.
+#[must_use]
+pub fn GetDefaultPrinter() -> SysResult<String> {
+	let mut name_sz = u32::default();
+
+	unsafe { gdi::ffi::GetDefaultPrinterW(std::ptr::null_mut(), &mut name_sz); }
+	let get_size_err = GetLastError();
+	if get_size_err != co::ERROR::INSUFFICIENT_BUFFER {
+		return Err(get_size_err);
+	}
+
+	let mut name_buf = WString::new_alloc_buf(name_sz as _);
+
+	bool_to_sysresult(
+		unsafe { gdi::ffi::GetDefaultPrinterW(name_buf.as_mut_ptr(), &mut name_sz) },
+	).map(|_| name_buf.to_string())
+}
+
+/// [`LookupIconIdFromDirectoryEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-lookupiconidfromdirectoryex)
+/// function.
+///
+/// The `res_dir` must be in the resource format (`GRPICONDIR`), not in the
+/// `.ico` file format; [`IconFile`](crate::IconFile) takes care of this
+/// conversion. Returns the ID of the image which best fits the given size.
+#[must_use]
+pub fn LookupIconIdFromDirectoryEx(
+	res_dir: &[u8],
+	is_icon: bool,
+	sz: SIZE,
+	flags: co::LR,
+) -> SysResult<u16>
+{
+	match unsafe {
+		gdi::ffi::LookupIconIdFromDirectoryEx(
+			res_dir.as_ptr() as _, is_icon as _, sz.cx, sz.cy, flags.0)
+	} {
+		0 => Err(GetLastError()),
+		id => Ok(id as _),
+	}
+}
+
+/// [`SetDefaultPrinter`](https://learn.microsoft.com/en-us/windows/win32/printdocs/setdefaultprinter)
+/// function.
+///
+/// If `name` is `None`, the first available printer is set as the default.
+pub fn SetDefaultPrinter(name: Option<&str>) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe {
+			gdi::ffi::SetDefaultPrinterW(WString::from_opt_str(name).as_ptr())
+		},
+	)
+}
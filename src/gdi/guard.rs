@@ -1,10 +1,19 @@
 use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
 
 use crate::{co, gdi};
-use crate::gdi::decl::{LOGPALETTE, PALETTEENTRY};
-use crate::prelude::{gdi_Hdc, GdiObject, Handle};
+use crate::gdi::decl::{HPRINTER, ICONINFO, LOGPALETTE, PALETTEENTRY};
+use crate::prelude::{gdi_Hdc, GdiObject, Handle, PrinterInfo};
 use crate::user::decl::HDC;
 
+handle_guard! { ClosePrinterGuard: HPRINTER;
+	gdi::ffi::ClosePrinter;
+	/// RAII implementation for [`HPRINTER`](crate::HPRINTER) which
+	/// automatically calls
+	/// [`ClosePrinter`](https://learn.microsoft.com/en-us/windows/win32/printdocs/closeprinter)
+	/// when the object goes out of scope.
+}
+
 handle_guard! { DeleteDCGuard: HDC;
 	gdi::ffi::DeleteDC;
 	/// RAII implementation for [`HDC`](crate::HDC) which automatically calls
@@ -84,6 +93,69 @@ impl<T> DeleteObjectGuard<T>
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation for [`ICONINFO`](crate::ICONINFO) which automatically
+/// calls
+/// [`DeleteObject`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-deleteobject)
+/// on `hbmMask` and `hbmColor` fields when the object goes out of scope.
+pub struct DeleteObjectIconInfoGuard {
+	ii: ICONINFO,
+}
+
+impl Drop for DeleteObjectIconInfoGuard {
+	fn drop(&mut self) {
+		if let Some(h) = self.ii.hbmMask.as_opt() {
+			let _ = unsafe { DeleteObjectGuard::new(h.raw_copy()) };
+		}
+		if let Some(h) = self.ii.hbmColor.as_opt() {
+			let _ = unsafe { DeleteObjectGuard::new(h.raw_copy()) };
+		}
+	}
+}
+
+impl Deref for DeleteObjectIconInfoGuard {
+	type Target = ICONINFO;
+
+	fn deref(&self) -> &Self::Target {
+		&self.ii
+	}
+}
+
+impl DerefMut for DeleteObjectIconInfoGuard {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.ii
+	}
+}
+
+impl DeleteObjectIconInfoGuard {
+	/// Constructs the guard by taking ownership of the struct.
+	/// 
+	/// # Safety
+	/// 
+	/// Be sure the handles must be freed with
+	/// [`DeleteObject`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-deleteobject)
+	/// at the end of scope.
+	/// 
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(ii: ICONINFO) -> Self {
+		Self { ii }
+	}
+
+	/// Ejects the underlying struct, leaving
+	/// [`ICONINFO::default`](crate::ICONINFO::default) in its place.
+	///
+	/// Since the internal handles will be invalidated, the destructor will not
+	/// run. It's your responsibility to run it, otherwise you'll cause a
+	/// resource leak.
+	#[must_use]
+	pub fn leak(&mut self) -> ICONINFO {
+		std::mem::take(&mut self.ii)
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation for [`LOGPALETTE`](crate::LOGPALETTE) which manages the
 /// allocated memory.
 pub struct LogpaletteGuard {
@@ -122,6 +194,47 @@ impl LogpaletteGuard {
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation for a [`PrinterInfo`](crate::prelude::PrinterInfo)
+/// struct, which keeps alive the memory block where the struct and its strings
+/// were written.
+///
+/// Returned by [`EnumPrinters`](crate::EnumPrinters) and
+/// [`HPRINTER::GetPrinter`](crate::prelude::gdi_Hprinter::GetPrinter).
+pub struct PrinterInfoGuard<T>
+	where T: PrinterInfo,
+{
+	_buf: Rc<Vec<u64>>, // u64 for alignment
+	ptr: *const T,
+}
+
+impl<T> Deref for PrinterInfoGuard<T>
+	where T: PrinterInfo,
+{
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { &*self.ptr }
+	}
+}
+
+impl<T> PrinterInfoGuard<T>
+	where T: PrinterInfo,
+{
+	/// Returns one guard for each struct written at the beginning of the
+	/// buffer, all sharing the same buffer.
+	pub(in crate::gdi) fn from_buf(buf: Vec<u64>, count: usize) -> Vec<Self> {
+		let buf = Rc::new(buf);
+		(0..count)
+			.map(|i| Self {
+				_buf: buf.clone(),
+				ptr: unsafe { (buf.as_ptr() as *const T).add(i) },
+			})
+			.collect()
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation for
 /// [`HDC::SelectObject`](crate::prelude::gdi_Hdc::SelectObject) calls, which
 /// automatically selects the previous GDI object at the end of the scope.
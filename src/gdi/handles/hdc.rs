@@ -3,7 +3,7 @@
 use std::any::TypeId;
 
 use crate::{co, gdi};
-use crate::gdi::decl::{BITMAPINFO, HPALETTE, TEXTMETRIC};
+use crate::gdi::decl::{BITMAPINFO, DOCINFO, HPALETTE, TEXTMETRIC};
 use crate::gdi::guard::{DeleteDCGuard, DeleteObjectGuard, SelectObjectGuard};
 use crate::gdi::privs::{CLR_INVALID, GDI_ERROR, LF_FACESIZE};
 use crate::kernel::decl::{GetLastError, SysResult, WString};
@@ -12,7 +12,7 @@ use crate::kernel::privs::{
 };
 use crate::prelude::{GdiObjectSelect, Handle};
 use crate::user::decl::{
-	COLORREF, HBITMAP, HBRUSH, HDC, HRGN, POINT, RECT, SIZE,
+	COLORREF, DEVMODE, HBITMAP, HBRUSH, HDC, HRGN, POINT, RECT, SIZE,
 };
 
 impl gdi_Hdc for HDC {}
@@ -26,6 +26,15 @@ impl gdi_Hdc for HDC {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait gdi_Hdc: Handle {
+	/// [`AbortDoc`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-abortdoc)
+	/// method.
+	fn AbortDoc(&self) -> SysResult<()> {
+		match unsafe { gdi::ffi::AbortDoc(self.as_ptr()) } {
+			v if v <= 0 => Err(GetLastError()),
+			_ => Ok(()),
+		}
+	}
+
 	/// [`AborthPath`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-abortpath)
 	/// method.
 	fn AbortPath(&self) -> SysResult<()> {
@@ -170,6 +179,43 @@ pub trait gdi_Hdc: Handle {
 		}
 	}
 
+	/// [`CreateDC`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createdcw)
+	/// static method.
+	///
+	/// To create a device context for a printer, pass `None` as `driver` and
+	/// the printer name as `device`.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, GetDefaultPrinter, HDC};
+	///
+	/// let printer = GetDefaultPrinter()?;
+	/// let hdc = HDC::CreateDC(None, Some(&printer), None)?;
+	///
+	/// let dpi = hdc.GetDeviceCaps(co::GDC::LOGPIXELSX);
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn CreateDC(
+		driver: Option<&str>,
+		device: Option<&str>,
+		dm: Option<&DEVMODE>,
+	) -> SysResult<DeleteDCGuard>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				gdi::ffi::CreateDCW(
+					WString::from_opt_str(driver).as_ptr(),
+					WString::from_opt_str(device).as_ptr(),
+					std::ptr::null(),
+					dm.map_or(std::ptr::null(), |dm| dm as *const _ as _),
+				),
+			).map(|h| DeleteDCGuard::new(h))
+		}
+	}
+
 	/// [`CreateHalftonePalette`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createhalftonepalette)
 	/// method.
 	#[must_use]
@@ -194,6 +240,24 @@ pub trait gdi_Hdc: Handle {
 		)
 	}
 
+	/// [`EndDoc`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-enddoc)
+	/// method.
+	fn EndDoc(&self) -> SysResult<()> {
+		match unsafe { gdi::ffi::EndDoc(self.as_ptr()) } {
+			v if v <= 0 => Err(GetLastError()),
+			_ => Ok(()),
+		}
+	}
+
+	/// [`EndPage`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-endpage)
+	/// method.
+	fn EndPage(&self) -> SysResult<()> {
+		match unsafe { gdi::ffi::EndPage(self.as_ptr()) } {
+			v if v <= 0 => Err(GetLastError()),
+			_ => Ok(()),
+		}
+	}
+
 	/// [`EndPath`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-endpath)
 	/// method.
 	fn EndPath(&self) -> SysResult<()> {
@@ -382,6 +446,16 @@ pub trait gdi_Hdc: Handle {
 		unsafe { gdi::ffi::GetDeviceCaps(self.as_ptr(), index.0) }
 	}
 
+	/// [`GetROP2`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getrop2)
+	/// method.
+	#[must_use]
+	fn GetROP2(&self) -> SysResult<co::R2> {
+		match unsafe { gdi::ffi::GetROP2(self.as_ptr()) } {
+			0 => Err(GetLastError()),
+			v => Ok(co::R2(v)),
+		}
+	}
+
 	/// [`GetStretchBltMode`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getstretchbltmode)
 	/// method.
 	#[must_use]
@@ -512,6 +586,38 @@ pub trait gdi_Hdc: Handle {
 		)
 	}
 
+	/// [`MaskBlt`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-maskblt)
+	/// method.
+	///
+	/// The `fore_rop` is applied where the mask is 1, and the `back_rop` where
+	/// the mask is 0; both are combined as the `MAKEROP4` macro does.
+	fn MaskBlt(&self,
+		dest_pos: POINT,
+		sz: SIZE,
+		hdc_src: &HDC,
+		src_pos: POINT,
+		hbm_mask: &HBITMAP,
+		mask_pos: POINT,
+		fore_rop: co::ROP,
+		back_rop: co::ROP,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				gdi::ffi::MaskBlt(
+					self.as_ptr(),
+					dest_pos.x, dest_pos.y,
+					sz.cx, sz.cy,
+					hdc_src.as_ptr(),
+					src_pos.x, src_pos.y,
+					hbm_mask.as_ptr(),
+					mask_pos.x, mask_pos.y,
+					((back_rop.0 << 8) & 0xff00_0000) | fore_rop.0,
+				)
+			},
+		)
+	}
+
 	/// [`PatBlt`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-patblt)
 	/// method.
 	fn PatBlt(&self, top_left: POINT, sz: SIZE, rop: co::ROP) -> SysResult<()> {
@@ -551,6 +657,35 @@ pub trait gdi_Hdc: Handle {
 		)
 	}
 
+	/// [`PlgBlt`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-plgblt)
+	/// method.
+	///
+	/// The `dest_pts` are the upper-left, upper-right and lower-left corners of
+	/// the destination parallelogram.
+	fn PlgBlt(&self,
+		dest_pts: &[POINT; 3],
+		hdc_src: &HDC,
+		src_pos: POINT,
+		sz: SIZE,
+		mask: Option<(&HBITMAP, POINT)>,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				gdi::ffi::PlgBlt(
+					self.as_ptr(),
+					dest_pts.as_ptr() as _,
+					hdc_src.as_ptr(),
+					src_pos.x, src_pos.y,
+					sz.cx, sz.cy,
+					mask.map_or(std::ptr::null_mut(), |(hbm, _)| hbm.as_ptr()),
+					mask.map_or(0, |(_, pt)| pt.x),
+					mask.map_or(0, |(_, pt)| pt.y),
+				)
+			},
+		)
+	}
+
 	/// [`PolyBezier`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-polybezier)
 	/// method.
 	fn PolyBezier(&self, pts: &[POINT]) -> SysResult<()> {
@@ -824,6 +959,36 @@ pub trait gdi_Hdc: Handle {
 		}
 	}
 
+	/// [`SetROP2`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-setrop2)
+	/// method.
+	///
+	/// Returns the previous mode.
+	///
+	/// # Examples
+	///
+	/// Drawing a rubber-band selection rectangle, which is erased by drawing it
+	/// again:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HBRUSH, HDC, RECT};
+	///
+	/// let hdc: HDC; // initialized somewhere
+	/// # let hdc = HDC::NULL;
+	///
+	/// let prev_rop = hdc.SetROP2(co::R2::NOT)?;
+	/// let _brush = hdc.SelectObject(&HBRUSH::GetStockObject(co::STOCK_BRUSH::NULL)?)?;
+	/// hdc.Rectangle(RECT { left: 10, top: 10, right: 120, bottom: 80 })?;
+	/// hdc.SetROP2(prev_rop)?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn SetROP2(&self, mode: co::R2) -> SysResult<co::R2> {
+		match unsafe { gdi::ffi::SetROP2(self.as_ptr(), mode.0) } {
+			0 => Err(GetLastError()),
+			v => Ok(co::R2(v)),
+		}
+	}
+
 	/// [`SetStretchBltMode`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-setstretchbltmode)
 	/// method.
 	fn SetStretchBltMode(&self,
@@ -915,6 +1080,26 @@ pub trait gdi_Hdc: Handle {
 		).map(|_| pt)
 	}
 
+	/// [`StartDoc`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-startdocw)
+	/// method.
+	///
+	/// Returns the print job identifier.
+	fn StartDoc(&self, di: &DOCINFO) -> SysResult<i32> {
+		match unsafe { gdi::ffi::StartDocW(self.as_ptr(), di as *const _ as _) } {
+			v if v <= 0 => Err(GetLastError()),
+			job_id => Ok(job_id),
+		}
+	}
+
+	/// [`StartPage`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-startpage)
+	/// method.
+	fn StartPage(&self) -> SysResult<()> {
+		match unsafe { gdi::ffi::StartPage(self.as_ptr()) } {
+			v if v <= 0 => Err(GetLastError()),
+			_ => Ok(()),
+		}
+	}
+
 	/// [`StretchBlt`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-stretchblt)
 	/// method.
 	fn StretchBlt(&self,
@@ -1,7 +1,7 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
 use crate::{co, gdi};
-use crate::gdi::decl::LOGFONT;
+use crate::gdi::decl::{ENUMLOGFONTEXDV, LOGFONT};
 use crate::gdi::guard::DeleteObjectGuard;
 use crate::kernel::decl::{SysResult, WString};
 use crate::kernel::privs::{bool_to_sysresult, ptr_to_sysresult_handle};
@@ -71,6 +71,20 @@ pub trait gdi_Hfont: Handle {
 		}
 	}
 
+	/// [`CreateFontIndirectEx`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createfontindirectexw)
+	/// static method.
+	#[must_use]
+	fn CreateFontIndirectEx(
+		elfdv: &ENUMLOGFONTEXDV,
+	) -> SysResult<DeleteObjectGuard<HFONT>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				gdi::ffi::CreateFontIndirectExW(elfdv as *const _ as _),
+			).map(|h| DeleteObjectGuard::new(h))
+		}
+	}
+
 	/// [`GetObject`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getobjectw)
 	/// method.
 	fn GetObject(&self, lf: &mut LOGFONT) -> SysResult<()> {
@@ -0,0 +1,75 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::{co, gdi};
+use crate::gdi::decl::ICONINFO;
+use crate::gdi::guard::DeleteObjectIconInfoGuard;
+use crate::kernel::decl::SysResult;
+use crate::kernel::privs::{bool_to_sysresult, ptr_to_sysresult_handle};
+use crate::prelude::Handle;
+use crate::user::decl::{HICON, SIZE};
+use crate::user::guard::DestroyIconGuard;
+
+impl gdi_Hicon for HICON {}
+
+/// This trait is enabled with the `gdi` feature, and provides methods for
+/// [`HICON`](crate::HICON).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_Hicon: Handle {
+	/// [`CreateIconFromResourceEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createiconfromresourceex)
+	/// static method.
+	///
+	/// The `res_bits` is the data of a single image, usually located with
+	/// [`LookupIconIdFromDirectoryEx`](crate::LookupIconIdFromDirectoryEx). If
+	/// `sz` is zero, the system metrics are used.
+	#[must_use]
+	fn CreateIconFromResourceEx(
+		res_bits: &[u8],
+		is_icon: bool,
+		sz: SIZE,
+		flags: co::LR,
+	) -> SysResult<DestroyIconGuard>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				gdi::ffi::CreateIconFromResourceEx(
+					res_bits.as_ptr() as _,
+					res_bits.len() as _,
+					is_icon as _,
+					0x0003_0000,
+					sz.cx, sz.cy,
+					flags.0,
+				),
+			).map(|h| DestroyIconGuard::new(h))
+		}
+	}
+
+	/// [`CreateIconIndirect`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createiconindirect)
+	/// static method.
+	///
+	/// The bitmaps are copied, so they still must be deleted.
+	#[must_use]
+	fn CreateIconIndirect(info: &ICONINFO) -> SysResult<DestroyIconGuard> {
+		unsafe {
+			ptr_to_sysresult_handle(
+				gdi::ffi::CreateIconIndirect(info as *const _ as _),
+			).map(|h| DestroyIconGuard::new(h))
+		}
+	}
+
+	/// [`GetIconInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-geticoninfo)
+	/// method.
+	#[must_use]
+	fn GetIconInfo(&self) -> SysResult<DeleteObjectIconInfoGuard> {
+		let mut ii = ICONINFO::default();
+		unsafe {
+			bool_to_sysresult(
+				gdi::ffi::GetIconInfo(self.as_ptr(), &mut ii as *mut _ as _),
+			).map(|_| DeleteObjectIconInfoGuard::new(ii))
+		}
+	}
+}
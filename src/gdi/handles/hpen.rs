@@ -1,7 +1,7 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
 use crate::{co, gdi};
-use crate::gdi::decl::LOGPEN;
+use crate::gdi::decl::{LOGBRUSH, LOGPEN};
 use crate::gdi::guard::DeleteObjectGuard;
 use crate::kernel::decl::SysResult;
 use crate::kernel::privs::ptr_to_sysresult_handle;
@@ -54,6 +54,54 @@ pub trait gdi_Hpen: Handle {
 		}
 	}
 
+	/// [`ExtCreatePen`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-extcreatepen)
+	/// static method.
+	///
+	/// The `style_lengths` are used only with `co::PS::USERSTYLE`.
+	///
+	/// # Examples
+	///
+	/// A thick red pen with square ends:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, COLORREF, HPEN, LOGBRUSH};
+	///
+	/// let lb = LOGBRUSH {
+	///     lbStyle: co::BSS::SOLID,
+	///     lbColor: COLORREF::new(255, 0, 0),
+	///     lbHatch: 0,
+	/// };
+	///
+	/// let hpen = HPEN::ExtCreatePen(
+	///     co::PS::GEOMETRIC | co::PS::SOLID | co::PS::ENDCAP_SQUARE,
+	///     8,
+	///     &lb,
+	///     None,
+	/// )?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn ExtCreatePen(
+		style: co::PS,
+		width: u32,
+		brush: &LOGBRUSH,
+		style_lengths: Option<&[u32]>,
+	) -> SysResult<DeleteObjectGuard<HPEN>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				gdi::ffi::ExtCreatePen(
+					style.0 as _,
+					width,
+					brush as *const _ as _,
+					style_lengths.map_or(0, |s| s.len() as _),
+					style_lengths.map_or(std::ptr::null(), |s| s.as_ptr() as _),
+				),
+			).map(|h| DeleteObjectGuard::new(h))
+		}
+	}
+
 	/// [`GetStockObject`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getstockobject)
 	/// static method.
 	#[must_use]
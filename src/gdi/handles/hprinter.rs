@@ -0,0 +1,98 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::{co, gdi};
+use crate::gdi::decl::PRINTER_DEFAULTS;
+use crate::gdi::guard::{ClosePrinterGuard, PrinterInfoGuard};
+use crate::kernel::decl::{GetLastError, SysResult, WString};
+use crate::kernel::privs::bool_to_sysresult;
+use crate::prelude::{Handle, PrinterInfo};
+
+impl_handle! { HPRINTER;
+	/// Handle to a
+	/// [printer](https://learn.microsoft.com/en-us/windows/win32/printdocs/openprinter).
+}
+
+impl gdi_Hprinter for HPRINTER {}
+
+/// This trait is enabled with the `gdi` feature, and provides methods for
+/// [`HPRINTER`](crate::HPRINTER).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_Hprinter: Handle {
+	/// [`GetPrinter`](https://learn.microsoft.com/en-us/windows/win32/printdocs/getprinter)
+	/// method.
+	///
+	/// The information level is given by the type of the returned struct.
+	///
+	/// # Examples
+	///
+	/// Checking the status of the default printer:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, GetDefaultPrinter, HPRINTER, PRINTER_INFO_2};
+	///
+	/// let name = GetDefaultPrinter()?;
+	/// let hprinter = HPRINTER::OpenPrinter(Some(&name), None)?;
+	///
+	/// let info = hprinter.GetPrinter::<PRINTER_INFO_2>()?;
+	/// if info.Status.has(co::PRINTER_STATUS::OFFLINE) {
+	///     println!("{} is offline, {} jobs queued.", name, info.cJobs);
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn GetPrinter<T>(&self) -> SysResult<PrinterInfoGuard<T>>
+		where T: PrinterInfo,
+	{
+		let mut needed = u32::default();
+		unsafe {
+			gdi::ffi::GetPrinterW( // retrieve needed buffer size
+				self.as_ptr(), T::LEVEL, std::ptr::null_mut(), 0, &mut needed);
+		}
+		let get_size_err = GetLastError();
+		if get_size_err != co::ERROR::INSUFFICIENT_BUFFER {
+			return Err(get_size_err);
+		}
+
+		let mut buf = vec![0u64; (needed as usize).div_ceil(8)];
+		bool_to_sysresult(
+			unsafe {
+				gdi::ffi::GetPrinterW(
+					self.as_ptr(),
+					T::LEVEL,
+					buf.as_mut_ptr() as _,
+					needed,
+					&mut needed,
+				)
+			},
+		).map(|_| PrinterInfoGuard::from_buf(buf, 1).pop().unwrap())
+	}
+
+	/// [`OpenPrinter`](https://learn.microsoft.com/en-us/windows/win32/printdocs/openprinter)
+	/// static method.
+	///
+	/// If `printer_name` is `None`, the handle refers to the local print
+	/// server.
+	#[must_use]
+	fn OpenPrinter(
+		printer_name: Option<&str>,
+		defaults: Option<&PRINTER_DEFAULTS>,
+	) -> SysResult<ClosePrinterGuard>
+	{
+		let mut hprinter = HPRINTER::NULL;
+		unsafe {
+			bool_to_sysresult(
+				gdi::ffi::OpenPrinterW(
+					WString::from_opt_str(printer_name).as_ptr(),
+					hprinter.as_mut(),
+					defaults.map_or(std::ptr::null(), |d| d as *const _ as _),
+				),
+			).map(|_| ClosePrinterGuard::new(hprinter))
+		}
+	}
+}
@@ -5,7 +5,7 @@ use crate::gdi::guard::DeleteObjectGuard;
 use crate::kernel::decl::{GetLastError, SysResult};
 use crate::kernel::privs::ptr_to_sysresult_handle;
 use crate::prelude::{GdiObject, GdiObjectSelect, Handle};
-use crate::user::decl::{HRGN, RECT, SIZE};
+use crate::user::decl::{HRGN, POINT, RECT, SIZE};
 
 impl GdiObject for HRGN {}
 impl GdiObjectSelect for HRGN {}
@@ -20,6 +20,69 @@ impl gdi_Hrgn for HRGN {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait gdi_Hrgn: Handle {
+	/// [`CombineRgn`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-combinergn)
+	/// method.
+	///
+	/// Combines `src1` and `src2` according to `mode`, storing the result in
+	/// this region, which must already exist.
+	///
+	/// The function doesn't set the last error, so
+	/// [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// is returned if it fails.
+	///
+	/// # Examples
+	///
+	/// Creating a rectangular region with a hole:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HRGN, RECT};
+	///
+	/// let outer = HRGN::CreateRectRgn(RECT { left: 0, top: 0, right: 100, bottom: 100 })?;
+	/// let hole = HRGN::CreateEllipticRgn(RECT { left: 25, top: 25, right: 75, bottom: 75 })?;
+	/// let result = HRGN::CreateRectRgn(RECT::default())?;
+	///
+	/// result.CombineRgn(&outer, &hole, co::RGN::DIFF)?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	fn CombineRgn(&self,
+		src1: &HRGN, src2: &HRGN, mode: co::RGN) -> SysResult<co::REGION>
+	{
+		match unsafe {
+			gdi::ffi::CombineRgn(
+				self.as_ptr(), src1.as_ptr(), src2.as_ptr(), mode.0)
+		} {
+			0 => Err(co::ERROR::INVALID_PARAMETER), // REGION::ERROR
+			ret => Ok(co::REGION(ret)),
+		}
+	}
+
+	/// [`CreateEllipticRgn`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createellipticrgn)
+	/// static method.
+	#[must_use]
+	fn CreateEllipticRgn(bounds: RECT) -> SysResult<DeleteObjectGuard<HRGN>> {
+		unsafe {
+			ptr_to_sysresult_handle(
+				gdi::ffi::CreateEllipticRgn(
+					bounds.left, bounds.top, bounds.right, bounds.bottom),
+			).map(|h| DeleteObjectGuard::new(h))
+		}
+	}
+
+	/// [`CreatePolygonRgn`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createpolygonrgn)
+	/// static method.
+	#[must_use]
+	fn CreatePolygonRgn(
+		points: &[POINT], mode: co::POLY_FILL) -> SysResult<DeleteObjectGuard<HRGN>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				gdi::ffi::CreatePolygonRgn(
+					points.as_ptr() as _, points.len() as _, mode.0),
+			).map(|h| DeleteObjectGuard::new(h))
+		}
+	}
+
 	/// [`CreateRectRgn`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createrectrgn)
 	/// static method.
 	#[must_use]
@@ -52,13 +115,53 @@ pub trait gdi_Hrgn: Handle {
 		unsafe {
 			ptr_to_sysresult_handle(
 				gdi::ffi::CreateRoundRectRgn(
-					bounds.left, bounds.top, bounds.right, bounds.top,
+					bounds.left, bounds.top, bounds.right, bounds.bottom,
 					size.cx, size.cy,
 				),
 			).map(|h| DeleteObjectGuard::new(h))
 		}
 	}
 
+	/// [`EqualRgn`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-equalrgn)
+	/// method.
+	#[must_use]
+	fn EqualRgn(&self, other: &HRGN) -> bool {
+		unsafe { gdi::ffi::EqualRgn(self.as_ptr(), other.as_ptr()) != 0 }
+	}
+
+	/// [`GetRegionData`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-getregiondata)
+	/// method.
+	///
+	/// Returns the bounding rectangle of the region, and the rectangles which
+	/// compose it.
+	#[must_use]
+	fn GetRegionData(&self) -> SysResult<(RECT, Vec<RECT>)> {
+		const HEADER_SIZE: usize = 32; // RGNDATAHEADER
+
+		let num_bytes = match unsafe {
+			gdi::ffi::GetRegionData(self.as_ptr(), 0, std::ptr::null_mut())
+		} {
+			0 => return Err(GetLastError()),
+			n => n as usize,
+		};
+
+		let mut buf = vec![0u32; num_bytes.div_ceil(4)]; // 4-byte aligned
+		if unsafe {
+			gdi::ffi::GetRegionData(
+				self.as_ptr(), num_bytes as _, buf.as_mut_ptr() as _)
+		} == 0 {
+			return Err(GetLastError());
+		}
+
+		let count = buf[2] as usize; // nCount
+		let rect_ptr = unsafe {
+			(buf.as_ptr() as *const u8).add(HEADER_SIZE) as *const RECT
+		};
+		let bound = unsafe { *(buf.as_ptr().add(4) as *const RECT) }; // rcBound
+		let rects = unsafe { std::slice::from_raw_parts(rect_ptr, count) }.to_vec();
+		Ok((bound, rects))
+	}
+
 	/// [`OffsetClipRgn`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-offsetcliprgn)
 	/// method.
 	fn OffsetClipRgn(&self, x: i32, y: i32) -> SysResult<co::REGION> {
@@ -3,15 +3,18 @@ mod hbitmap;
 mod hbrush;
 mod hdc;
 mod hfont;
+mod hicon;
 mod hinstance;
 mod hpalette;
 mod hpen;
+mod hprinter;
 mod hrgn;
 
 pub mod decl {
 	pub use super::hfont::HFONT;
 	pub use super::hpalette::HPALETTE;
 	pub use super::hpen::HPEN;
+	pub use super::hprinter::HPRINTER;
 }
 
 pub mod traits {
@@ -20,8 +23,10 @@ pub mod traits {
 	pub use super::hbrush::gdi_Hbrush;
 	pub use super::hdc::gdi_Hdc;
 	pub use super::hfont::gdi_Hfont;
+	pub use super::hicon::gdi_Hicon;
 	pub use super::hinstance::gdi_Hinstance;
 	pub use super::hpalette::gdi_Hpalette;
 	pub use super::hpen::gdi_Hpen;
+	pub use super::hprinter::gdi_Hprinter;
 	pub use super::hrgn::gdi_Hrgn;
 }
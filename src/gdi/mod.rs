@@ -9,15 +9,19 @@ pub mod messages;
 mod enums;
 mod funcs;
 mod handles;
+mod printer_traits;
 mod structs;
+mod utilities;
 
 pub mod decl {
 	pub use super::enums::*;
 	pub use super::funcs::*;
 	pub use super::handles::decl::*;
 	pub use super::structs::*;
+	pub use super::utilities::*;
 }
 
 pub mod traits {
 	pub use super::handles::traits::*;
+	pub use super::printer_traits::*;
 }
@@ -0,0 +1,22 @@
+/// This trait is enabled with the `gdi` feature, and is implemented by the
+/// `PRINTER_INFO` structs, identifying their information level:
+///
+/// * [`PRINTER_INFO_1`](crate::PRINTER_INFO_1);
+/// * [`PRINTER_INFO_2`](crate::PRINTER_INFO_2);
+/// * [`PRINTER_INFO_4`](crate::PRINTER_INFO_4);
+/// * [`PRINTER_INFO_5`](crate::PRINTER_INFO_5).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+///
+/// # Safety
+///
+/// The implementor must have the exact memory layout of the struct of the
+/// given level, which is written by the system.
+pub unsafe trait PrinterInfo {
+	/// The information level passed to the system.
+	const LEVEL: u32;
+}
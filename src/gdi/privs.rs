@@ -1,3 +1,6 @@
 pub(crate) const CLR_INVALID: u32 = 0xffff_ffff;
 pub(crate) const GDI_ERROR: u32 = 0xffff_ffff;
 pub(crate) const LF_FACESIZE: usize = 32;
+pub(crate) const LF_FULLFACESIZE: usize = 64;
+pub(crate) const MM_MAX_NUMAXES: usize = 16;
+pub(crate) const STAMP_DESIGNVECTOR: u32 = 0x0800_0000 + b'd' as u32 + ((b'v' as u32) << 8);
@@ -1,10 +1,16 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use std::marker::PhantomData;
+
 use crate::co;
 use crate::gdi::guard::LogpaletteGuard;
-use crate::gdi::privs::LF_FACESIZE;
-use crate::kernel::decl::IsWindowsVistaOrGreater;
-use crate::user::decl::{COLORREF, POINT};
+use crate::gdi::privs::{
+	LF_FACESIZE, LF_FULLFACESIZE, MM_MAX_NUMAXES, STAMP_DESIGNVECTOR,
+};
+use crate::kernel::decl::{IsWindowsVistaOrGreater, WString};
+use crate::kernel::ffi_types::{BOOL, PVOID};
+use crate::prelude::PrinterInfo;
+use crate::user::decl::{COLORREF, DEVMODE, HBITMAP, POINT};
 
 /// [`BITMAP`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-bitmap)
 /// struct.
@@ -84,6 +90,116 @@ impl BITMAPINFOHEADER {
 	pub_fn_serialize!();
 }
 
+/// [`DESIGNVECTOR`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-designvector)
+/// struct.
+#[repr(C)]
+#[derive(Clone, Eq, PartialEq)]
+pub struct DESIGNVECTOR {
+	dvReserved: u32,
+	dvNumAxes: u32,
+	dvValues: [i32; MM_MAX_NUMAXES],
+}
+
+impl Default for DESIGNVECTOR {
+	fn default() -> Self {
+		Self {
+			dvReserved: STAMP_DESIGNVECTOR,
+			dvNumAxes: 0,
+			dvValues: [0; MM_MAX_NUMAXES],
+		}
+	}
+}
+
+impl DESIGNVECTOR {
+	/// Returns the `dvValues` field, limited to `dvNumAxes`.
+	#[must_use]
+	pub fn dvValues(&self) -> &[i32] {
+		&self.dvValues[..self.dvNumAxes as usize]
+	}
+
+	/// Sets the `dvValues` field, also setting `dvNumAxes`.
+	///
+	/// # Panics
+	///
+	/// Panics if `values` has more than 16 elements.
+	pub fn set_dvValues(&mut self, values: &[i32]) {
+		if values.len() > MM_MAX_NUMAXES {
+			panic!("DESIGNVECTOR supports up to {} axes.", MM_MAX_NUMAXES);
+		}
+		self.dvValues = [0; MM_MAX_NUMAXES];
+		self.dvValues[..values.len()].copy_from_slice(values);
+		self.dvNumAxes = values.len() as _;
+	}
+}
+
+/// [`DOCINFO`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-docinfow)
+/// struct.
+#[repr(C)]
+pub struct DOCINFO<'a, 'b, 'c> {
+	cbSize: i32,
+	lpszDocName: *mut u16,
+	lpszOutput: *mut u16,
+	lpszDatatype: *mut u16,
+	pub fwType: co::DI,
+
+	_lpszDocName: PhantomData<&'a mut u16>,
+	_lpszOutput: PhantomData<&'b mut u16>,
+	_lpszDatatype: PhantomData<&'c mut u16>,
+}
+
+impl_default_with_size!(DOCINFO, cbSize, 'a, 'b, 'c);
+
+impl<'a, 'b, 'c> DOCINFO<'a, 'b, 'c> {
+	pub_fn_string_ptr_get_set!('a, lpszDocName, set_lpszDocName);
+	pub_fn_string_ptr_get_set!('b, lpszOutput, set_lpszOutput);
+	pub_fn_string_ptr_get_set!('c, lpszDatatype, set_lpszDatatype);
+}
+
+/// [`ENUMLOGFONTEX`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-enumlogfontexw)
+/// struct.
+#[repr(C)]
+#[derive(Clone, Eq, PartialEq)]
+pub struct ENUMLOGFONTEX {
+	pub elfLogFont: LOGFONT,
+	elfFullName: [u16; LF_FULLFACESIZE],
+	elfStyle: [u16; LF_FACESIZE],
+	elfScript: [u16; LF_FACESIZE],
+}
+
+impl_default!(ENUMLOGFONTEX);
+
+impl ENUMLOGFONTEX {
+	pub_fn_string_arr_get_set!(elfFullName, set_elfFullName);
+	pub_fn_string_arr_get_set!(elfStyle, set_elfStyle);
+	pub_fn_string_arr_get_set!(elfScript, set_elfScript);
+}
+
+/// [`ENUMLOGFONTEXDV`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-enumlogfontexdvw)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Eq, PartialEq)]
+pub struct ENUMLOGFONTEXDV {
+	pub elfEnumLogfontEx: ENUMLOGFONTEX,
+	pub elfDesignVector: DESIGNVECTOR,
+}
+
+/// [`ICONINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-iconinfo)
+/// struct.
+#[repr(C)]
+pub struct ICONINFO {
+	fIcon: BOOL,
+	pub xHotspot: u32,
+	pub yHotspot: u32,
+	pub hbmMask: HBITMAP,
+	pub hbmColor: HBITMAP,
+}
+
+impl_default!(ICONINFO);
+
+impl ICONINFO {
+	pub_fn_bool_get_set!(fIcon, set_fIcon);
+}
+
 /// [`LOGBRUSH`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-logbrush)
 /// struct.
 #[repr(C)]
@@ -239,6 +355,144 @@ pub struct PALETTEENTRY {
 	pub peFlags: co::PC,
 }
 
+/// [`PRINTER_DEFAULTS`](https://learn.microsoft.com/en-us/windows/win32/printdocs/printer-defaults)
+/// struct.
+#[repr(C)]
+pub struct PRINTER_DEFAULTS<'a, 'b> {
+	pDatatype: *mut u16,
+	pDevMode: *mut DEVMODE,
+	pub DesiredAccess: co::PRINTER_ACCESS,
+
+	_pDatatype: PhantomData<&'a mut u16>,
+	_pDevMode: PhantomData<&'b mut DEVMODE>,
+}
+
+impl_default!(PRINTER_DEFAULTS, 'a, 'b);
+
+impl<'a, 'b> PRINTER_DEFAULTS<'a, 'b> {
+	pub_fn_string_ptr_get_set!('a, pDatatype, set_pDatatype);
+	pub_fn_ptr_get_set!('b, pDevMode, set_pDevMode, DEVMODE);
+}
+
+/// [`PRINTER_INFO_1`](https://learn.microsoft.com/en-us/windows/win32/printdocs/printer-info-1)
+/// struct.
+#[repr(C)]
+pub struct PRINTER_INFO_1<'a> {
+	pub Flags: u32,
+	pDescription: *mut u16,
+	pName: *mut u16,
+	pComment: *mut u16,
+
+	_p: PhantomData<&'a mut u16>,
+}
+
+impl_default!(PRINTER_INFO_1, 'a);
+
+unsafe impl<'a> PrinterInfo for PRINTER_INFO_1<'a> {
+	const LEVEL: u32 = 1;
+}
+
+impl<'a> PRINTER_INFO_1<'a> {
+	pub_fn_string_ptr_get_set!('a, pDescription, set_pDescription);
+	pub_fn_string_ptr_get_set!('a, pName, set_pName);
+	pub_fn_string_ptr_get_set!('a, pComment, set_pComment);
+}
+
+/// [`PRINTER_INFO_2`](https://learn.microsoft.com/en-us/windows/win32/printdocs/printer-info-2)
+/// struct.
+#[repr(C)]
+pub struct PRINTER_INFO_2<'a> {
+	pServerName: *mut u16,
+	pPrinterName: *mut u16,
+	pShareName: *mut u16,
+	pPortName: *mut u16,
+	pDriverName: *mut u16,
+	pComment: *mut u16,
+	pLocation: *mut u16,
+	pDevMode: *mut DEVMODE,
+	pSepFile: *mut u16,
+	pPrintProcessor: *mut u16,
+	pDatatype: *mut u16,
+	pParameters: *mut u16,
+	pSecurityDescriptor: PVOID,
+	pub Attributes: co::PRINTER_ATTRIBUTE,
+	pub Priority: u32,
+	pub DefaultPriority: u32,
+	pub StartTime: u32,
+	pub UntilTime: u32,
+	pub Status: co::PRINTER_STATUS,
+	pub cJobs: u32,
+	pub AveragePPM: u32,
+
+	_p: PhantomData<&'a mut u16>,
+}
+
+impl_default!(PRINTER_INFO_2, 'a);
+
+unsafe impl<'a> PrinterInfo for PRINTER_INFO_2<'a> {
+	const LEVEL: u32 = 2;
+}
+
+impl<'a> PRINTER_INFO_2<'a> {
+	pub_fn_string_ptr_get_set!('a, pServerName, set_pServerName);
+	pub_fn_string_ptr_get_set!('a, pPrinterName, set_pPrinterName);
+	pub_fn_string_ptr_get_set!('a, pShareName, set_pShareName);
+	pub_fn_string_ptr_get_set!('a, pPortName, set_pPortName);
+	pub_fn_string_ptr_get_set!('a, pDriverName, set_pDriverName);
+	pub_fn_string_ptr_get_set!('a, pComment, set_pComment);
+	pub_fn_string_ptr_get_set!('a, pLocation, set_pLocation);
+	pub_fn_string_ptr_get_set!('a, pSepFile, set_pSepFile);
+	pub_fn_string_ptr_get_set!('a, pPrintProcessor, set_pPrintProcessor);
+	pub_fn_string_ptr_get_set!('a, pDatatype, set_pDatatype);
+	pub_fn_string_ptr_get_set!('a, pParameters, set_pParameters);
+}
+
+/// [`PRINTER_INFO_4`](https://learn.microsoft.com/en-us/windows/win32/printdocs/printer-info-4)
+/// struct.
+#[repr(C)]
+pub struct PRINTER_INFO_4<'a> {
+	pPrinterName: *mut u16,
+	pServerName: *mut u16,
+	pub Attributes: co::PRINTER_ATTRIBUTE,
+
+	_p: PhantomData<&'a mut u16>,
+}
+
+impl_default!(PRINTER_INFO_4, 'a);
+
+unsafe impl<'a> PrinterInfo for PRINTER_INFO_4<'a> {
+	const LEVEL: u32 = 4;
+}
+
+impl<'a> PRINTER_INFO_4<'a> {
+	pub_fn_string_ptr_get_set!('a, pPrinterName, set_pPrinterName);
+	pub_fn_string_ptr_get_set!('a, pServerName, set_pServerName);
+}
+
+/// [`PRINTER_INFO_5`](https://learn.microsoft.com/en-us/windows/win32/printdocs/printer-info-5)
+/// struct.
+#[repr(C)]
+pub struct PRINTER_INFO_5<'a> {
+	pPrinterName: *mut u16,
+	pPortName: *mut u16,
+	pub Attributes: co::PRINTER_ATTRIBUTE,
+	pub DeviceNotSelectedTimeout: u32,
+	pub TransmissionRetryTimeout: u32,
+
+	_p: PhantomData<&'a mut u16>,
+}
+
+impl_default!(PRINTER_INFO_5, 'a);
+
+unsafe impl<'a> PrinterInfo for PRINTER_INFO_5<'a> {
+	const LEVEL: u32 = 5;
+}
+
+impl<'a> PRINTER_INFO_5<'a> {
+	pub_fn_string_ptr_get_set!('a, pPrinterName, set_pPrinterName);
+	pub_fn_string_ptr_get_set!('a, pPortName, set_pPortName);
+}
+
 /// [`RGBQUAD`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-rgbquad)
 /// struct.
 #[repr(C)]
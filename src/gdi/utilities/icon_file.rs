@@ -0,0 +1,297 @@
+use crate::co;
+use crate::gdi::decl::{BITMAP, BITMAPINFO, BITMAPINFOHEADER, LookupIconIdFromDirectoryEx};
+use crate::kernel::decl::{File, FileAccess, FileMapped, SysResult};
+use crate::prelude::{gdi_Hbitmap, gdi_Hdc, gdi_Hicon, Handle, user_Hwnd};
+use crate::user::decl::{HBITMAP, HICON, HWND, SIZE};
+use crate::user::guard::DestroyIconGuard;
+
+/// Size of the `ICONDIR` header, both in file and resource formats.
+const DIR_SZ: usize = 6;
+/// Size of the `ICONDIRENTRY` struct, in file format.
+const FILE_ENTRY_SZ: usize = 16;
+/// Size of the `GRPICONDIRENTRY` struct, in resource format.
+const RES_ENTRY_SZ: usize = 14;
+
+/// A single image of an [`IconFile`](crate::IconFile), which can be either a
+/// DIB or a PNG.
+struct IconImage {
+	width: u8, // zero means 256
+	height: u8,
+	color_count: u8,
+	planes: u16,
+	bit_count: u16,
+	data: Vec<u8>,
+}
+
+//------------------------------------------------------------------------------
+
+/// The images of a multi-resolution icon, which can be written to and read
+/// from the `.ico` file format.
+///
+/// Images added from [`HICON`](crate::HICON) or [`HBITMAP`](crate::HBITMAP)
+/// handles are stored as 32-bit DIBs, with alpha channel; images read from a
+/// file are kept untouched.
+///
+/// # Examples
+///
+/// Composing an `.ico` file from two icons:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{HICON, IconFile};
+///
+/// let hicon16: HICON; // initialized somewhere
+/// # let hicon16 = HICON::NULL;
+/// let hicon32: HICON;
+/// # let hicon32 = HICON::NULL;
+///
+/// let mut ico = IconFile::new();
+/// ico.add_hicon(&hicon16)?;
+/// ico.add_hicon(&hicon32)?;
+/// ico.save("C:\\Temp\\foo.ico")?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+///
+/// Loading the 48x48 image from an `.ico` file:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{IconFile, SIZE};
+///
+/// let ico = IconFile::open("C:\\Temp\\foo.ico")?;
+/// let hicon = ico.hicon(SIZE::new(48, 48))?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Default)]
+pub struct IconFile {
+	images: Vec<IconImage>,
+}
+
+impl IconFile {
+	/// Creates a new, empty icon.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Parses the contents of an `.ico` file.
+	///
+	/// Fails with [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the data is not a valid icon.
+	#[must_use]
+	pub fn from_bytes(data: &[u8]) -> SysResult<Self> {
+		let read_u16 = |off: usize| u16::from_le_bytes([data[off], data[off + 1]]);
+		let read_u32 = |off: usize| u32::from_le_bytes(
+			[data[off], data[off + 1], data[off + 2], data[off + 3]]);
+
+		if data.len() < DIR_SZ || read_u16(0) != 0 || read_u16(2) != 1 {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let count = read_u16(4) as usize;
+		if data.len() < DIR_SZ + count * FILE_ENTRY_SZ {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let images = (0..count)
+			.map(|i| {
+				let entry = DIR_SZ + i * FILE_ENTRY_SZ;
+				let sz = read_u32(entry + 8) as usize;
+				let off = read_u32(entry + 12) as usize;
+				match off.checked_add(sz) {
+					Some(end) if end <= data.len() => Ok(IconImage {
+						width: data[entry],
+						height: data[entry + 1],
+						color_count: data[entry + 2],
+						planes: read_u16(entry + 4),
+						bit_count: read_u16(entry + 6),
+						data: data[off..end].to_vec(),
+					}),
+					_ => Err(co::ERROR::INVALID_DATA),
+				}
+			})
+			.collect::<SysResult<Vec<_>>>()?;
+		Ok(Self { images })
+	}
+
+	/// Reads and parses an `.ico` file.
+	#[must_use]
+	pub fn open(file_path: &str) -> SysResult<Self> {
+		let fin = FileMapped::open(file_path, FileAccess::ExistingReadOnly)?;
+		Self::from_bytes(fin.as_slice())
+	}
+
+	/// Adds an image from a color bitmap, which can be up to 256x256 pixels.
+	///
+	/// If the bitmap has no alpha channel, transparency is taken from the
+	/// monochrome mask bitmap, if any; otherwise, the mask is computed from
+	/// the alpha channel.
+	pub fn add_bitmap(&mut self,
+		hbmp_color: &HBITMAP,
+		hbmp_mask: Option<&HBITMAP>,
+	) -> SysResult<()>
+	{
+		let mut bmp = BITMAP::default();
+		hbmp_color.GetObject(&mut bmp)?;
+		if bmp.bmWidth < 1 || bmp.bmWidth > 256 || bmp.bmHeight < 1 || bmp.bmHeight > 256 {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		let (cx, cy) = (bmp.bmWidth as usize, bmp.bmHeight as usize);
+
+		let mut pixels = Self::dib_32bpp(hbmp_color, cx, cy)?; // bottom-up BGRA rows
+		let mask_pixels = match hbmp_mask {
+			Some(hbmp_mask) => Some(Self::dib_32bpp(hbmp_mask, cx, cy)?),
+			None => None,
+		};
+
+		let has_alpha = pixels.chunks_exact(4).any(|px| px[3] != 0);
+		if !has_alpha {
+			pixels.chunks_exact_mut(4)
+				.enumerate()
+				.for_each(|(i, px)| {
+					let transparent = mask_pixels.as_ref()
+						.is_some_and(|mask| mask[i * 4..i * 4 + 3] != [0, 0, 0]);
+					px[3] = if transparent { 0 } else { 0xff };
+				});
+		}
+
+		let mask_stride = cx.div_ceil(32) * 4; // 1-bit rows aligned to DWORD
+		let mut mask = vec![0u8; mask_stride * cy];
+		for y in 0..cy {
+			for x in 0..cx {
+				if pixels[(y * cx + x) * 4 + 3] == 0 {
+					mask[y * mask_stride + x / 8] |= 0x80 >> (x % 8);
+				}
+			}
+		}
+
+		let mut bih = BITMAPINFOHEADER::default();
+		bih.biWidth = cx as _;
+		bih.biHeight = (cy * 2) as _; // color and mask
+		bih.biPlanes = 1;
+		bih.biBitCount = 32;
+		bih.biCompression = co::BI::RGB;
+		bih.biSizeImage = (pixels.len() + mask.len()) as _;
+
+		let mut data = bih.serialize().to_vec();
+		data.extend_from_slice(&pixels);
+		data.extend_from_slice(&mask);
+
+		self.images.push(IconImage {
+			width: cx as u8, // 256 becomes zero
+			height: cy as u8,
+			color_count: 0,
+			planes: 1,
+			bit_count: 32,
+			data,
+		});
+		Ok(())
+	}
+
+	/// Adds an image from an icon, with
+	/// [`HICON::GetIconInfo`](crate::prelude::gdi_Hicon::GetIconInfo).
+	///
+	/// Fails with [`ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED) if
+	/// the icon is monochrome.
+	pub fn add_hicon(&mut self, hicon: &HICON) -> SysResult<()> {
+		let ii = hicon.GetIconInfo()?;
+		if ii.hbmColor == HBITMAP::NULL {
+			return Err(co::ERROR::NOT_SUPPORTED);
+		}
+		self.add_bitmap(&ii.hbmColor, Some(&ii.hbmMask))
+	}
+
+	/// Retrieves the pixels of the bitmap as 32-bit bottom-up rows.
+	fn dib_32bpp(hbmp: &HBITMAP, cx: usize, cy: usize) -> SysResult<Vec<u8>> {
+		let mut bi = BITMAPINFO::default();
+		bi.bmiHeader.biWidth = cx as _;
+		bi.bmiHeader.biHeight = cy as _;
+		bi.bmiHeader.biPlanes = 1;
+		bi.bmiHeader.biBitCount = 32;
+		bi.bmiHeader.biCompression = co::BI::RGB;
+
+		let mut buf = vec![0u8; cx * cy * 4];
+		let hdc_screen = HWND::DESKTOP.GetDC()?;
+		unsafe {
+			hdc_screen.GetDIBits(hbmp, 0, cy as _,
+				Some(&mut buf), &mut bi, co::DIB::RGB_COLORS)?;
+		}
+		Ok(buf)
+	}
+
+	/// Creates an icon from the image which best fits the given size, chosen
+	/// by [`LookupIconIdFromDirectoryEx`](crate::LookupIconIdFromDirectoryEx).
+	///
+	/// Fails with [`ERROR::NOT_FOUND`](crate::co::ERROR::NOT_FOUND) if there
+	/// are no images.
+	#[must_use]
+	pub fn hicon(&self, sz: SIZE) -> SysResult<DestroyIconGuard> {
+		if self.images.is_empty() {
+			return Err(co::ERROR::NOT_FOUND);
+		}
+
+		let mut res_dir = Vec::with_capacity(DIR_SZ + self.images.len() * RES_ENTRY_SZ);
+		self.serialize_dir(&mut res_dir);
+		self.images.iter()
+			.enumerate()
+			.for_each(|(i, img)| {
+				Self::serialize_entry(&mut res_dir, img);
+				res_dir.extend_from_slice(&(i as u16 + 1).to_le_bytes()); // nID, 1-based index
+			});
+
+		let id = LookupIconIdFromDirectoryEx(&res_dir, true, sz, co::LR::DEFAULTCOLOR)?;
+		let img = self.images.get(id as usize - 1)
+			.ok_or(co::ERROR::NOT_FOUND)?;
+		HICON::CreateIconFromResourceEx(&img.data, true, sz, co::LR::DEFAULTCOLOR)
+	}
+
+	/// Returns the sizes of the images, in the order they are stored.
+	#[must_use]
+	pub fn sizes(&self) -> Vec<SIZE> {
+		self.images.iter()
+			.map(|img| SIZE::new(
+				if img.width == 0 { 256 } else { img.width as _ },
+				if img.height == 0 { 256 } else { img.height as _ },
+			))
+			.collect()
+	}
+
+	/// Writes the icon to an `.ico` file, replacing its contents.
+	pub fn save(&self, file_path: &str) -> SysResult<()> {
+		let fout = File::open(file_path, FileAccess::OpenOrCreateRW)?;
+		fout.erase_and_write(&self.to_bytes())
+	}
+
+	/// Serializes the icon in the `.ico` file format.
+	#[must_use]
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let headers_sz = DIR_SZ + self.images.len() * FILE_ENTRY_SZ;
+		let mut buf = Vec::with_capacity(
+			headers_sz + self.images.iter().map(|img| img.data.len()).sum::<usize>());
+
+		self.serialize_dir(&mut buf);
+		let mut off = headers_sz;
+		for img in self.images.iter() {
+			Self::serialize_entry(&mut buf, img);
+			buf.extend_from_slice(&(off as u32).to_le_bytes()); // dwImageOffset
+			off += img.data.len();
+		}
+		for img in self.images.iter() {
+			buf.extend_from_slice(&img.data);
+		}
+		buf
+	}
+
+	fn serialize_dir(&self, buf: &mut Vec<u8>) {
+		buf.extend_from_slice(&0u16.to_le_bytes()); // idReserved
+		buf.extend_from_slice(&1u16.to_le_bytes()); // idType, icon
+		buf.extend_from_slice(&(self.images.len() as u16).to_le_bytes());
+	}
+
+	/// Writes the fields shared by both file and resource formats.
+	fn serialize_entry(buf: &mut Vec<u8>, img: &IconImage) {
+		buf.extend_from_slice(&[img.width, img.height, img.color_count, 0]);
+		buf.extend_from_slice(&img.planes.to_le_bytes());
+		buf.extend_from_slice(&img.bit_count.to_le_bytes());
+		buf.extend_from_slice(&(img.data.len() as u32).to_le_bytes()); // dwBytesInRes
+	}
+}
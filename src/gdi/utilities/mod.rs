@@ -0,0 +1,5 @@
+mod icon_file;
+mod print_to_file;
+
+pub use icon_file::IconFile;
+pub use print_to_file::PrintToFile;
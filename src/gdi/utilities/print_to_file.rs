@@ -0,0 +1,110 @@
+use crate::gdi::decl::DOCINFO;
+use crate::kernel::decl::{SysResult, WString};
+use crate::prelude::gdi_Hdc;
+use crate::user::decl::HDC;
+
+/// Renders a document, painted page by page, to a file through a printer
+/// driver which writes to disk, like "Microsoft Print to PDF" or "Microsoft
+/// XPS Document Writer".
+///
+/// Each page is painted by a closure which receives the printer
+/// [`HDC`](crate::HDC). The page dimensions, in device units, can be
+/// retrieved with
+/// [`HDC::GetDeviceCaps`](crate::prelude::gdi_Hdc::GetDeviceCaps) using
+/// [`co::GDC::HORZRES`](crate::co::GDC::HORZRES) and
+/// [`co::GDC::VERTRES`](crate::co::GDC::VERTRES).
+///
+/// # Examples
+///
+/// Exporting a two-page PDF:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, PrintToFile};
+///
+/// PrintToFile::pdf("C:\\Temp\\report.pdf")
+///     .doc_name("Monthly report")
+///     .print(2, |hdc, page_index| {
+///         let dpi = hdc.GetDeviceCaps(co::GDC::LOGPIXELSY);
+///         hdc.TextOut(dpi, dpi, &format!("Page {}", page_index + 1))?;
+///         Ok(())
+///     })?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct PrintToFile {
+	printer: String,
+	output: String,
+	doc_name: String,
+}
+
+impl PrintToFile {
+	/// Name of the PDF printer which ships with Windows 10 and later.
+	pub const PDF_PRINTER: &'static str = "Microsoft Print to PDF";
+	/// Name of the XPS printer which ships with Windows Vista and later.
+	pub const XPS_PRINTER: &'static str = "Microsoft XPS Document Writer";
+
+	/// Creates a new object, which will print through the given printer,
+	/// writing to the given output file.
+	#[must_use]
+	pub fn new(printer: &str, output_file: &str) -> Self {
+		Self {
+			printer: printer.to_owned(),
+			output: output_file.to_owned(),
+			doc_name: "Document".to_owned(),
+		}
+	}
+
+	/// Creates a new object which prints to a PDF file, using
+	/// [`PDF_PRINTER`](crate::PrintToFile::PDF_PRINTER).
+	#[must_use]
+	pub fn pdf(output_file: &str) -> Self {
+		Self::new(Self::PDF_PRINTER, output_file)
+	}
+
+	/// Creates a new object which prints to an XPS file, using
+	/// [`XPS_PRINTER`](crate::PrintToFile::XPS_PRINTER).
+	#[must_use]
+	pub fn xps(output_file: &str) -> Self {
+		Self::new(Self::XPS_PRINTER, output_file)
+	}
+
+	/// Sets the document name, which is shown in the print queue. Defaults to
+	/// "Document".
+	#[must_use]
+	pub fn doc_name(mut self, name: &str) -> Self {
+		self.doc_name = name.to_owned();
+		self
+	}
+
+	/// Prints the given number of pages, calling the closure once for each
+	/// page, with the zero-based page index.
+	///
+	/// If the closure returns an error, the document is aborted and the error
+	/// is returned.
+	pub fn print<F>(&self, num_pages: u32, mut paint: F) -> SysResult<()>
+		where F: FnMut(&HDC, u32) -> SysResult<()>,
+	{
+		let hdc = HDC::CreateDC(None, Some(&self.printer), None)?;
+
+		let mut doc_name = WString::from_str(&self.doc_name);
+		let mut output = WString::from_str(&self.output);
+		let mut di = DOCINFO::default();
+		di.set_lpszDocName(Some(&mut doc_name));
+		di.set_lpszOutput(Some(&mut output));
+		hdc.StartDoc(&di)?;
+
+		let res = (0..num_pages).try_for_each(|page_index| {
+			hdc.StartPage()?;
+			paint(&hdc, page_index)?;
+			hdc.EndPage()
+		});
+
+		match res {
+			Ok(_) => hdc.EndDoc(),
+			Err(e) => {
+				hdc.AbortDoc().ok(); // ignore errors, we already have one
+				Err(e)
+			},
+		}
+	}
+}
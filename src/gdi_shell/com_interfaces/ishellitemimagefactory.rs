@@ -0,0 +1,83 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::gdi::guard::DeleteObjectGuard;
+use crate::kernel::decl::MAKEQWORD;
+use crate::kernel::ffi_types::{HANDLE, HRES};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::{Handle, ole_IUnknown};
+use crate::user::decl::{HBITMAP, SIZE};
+use crate::vt::IUnknownVT;
+
+/// [`IShellItemImageFactory`](crate::IShellItemImageFactory) virtual table.
+#[repr(C)]
+pub struct IShellItemImageFactoryVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetImage: fn(ComPtr, u64, u32, *mut HANDLE) -> HRES,
+}
+
+com_interface! { IShellItemImageFactory: "bcc18b79-ba16-442f-80c4-8a59c30c463b";
+	/// [`IShellItemImageFactory`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ishellitemimagefactory)
+	/// COM interface over
+	/// [`IShellItemImageFactoryVT`](crate::vt::IShellItemImageFactoryVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// Usually created with
+	/// [`SHCreateItemFromParsingName`](crate::SHCreateItemFromParsingName)
+	/// function.
+}
+
+impl gdi_shell_IShellItemImageFactory for IShellItemImageFactory {}
+
+/// This trait is enabled with `gdi` and `shell` features, and provides methods
+/// for [`IShellItemImageFactory`](crate::IShellItemImageFactory).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_shell_IShellItemImageFactory: ole_IUnknown {
+	/// [`IShellItemImageFactory::GetImage`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitemimagefactory-getimage)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Retrieving the thumbnail of a picture, or its icon, if there's no
+	/// thumbnail:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IBindCtx, IShellItem, IShellItemImageFactory, SIZE,
+	///     SHCreateItemFromParsingName};
+	///
+	/// let item = SHCreateItemFromParsingName::<IShellItem>(
+	///     "C:\\Temp\\foo.jpg",
+	///     None::<&IBindCtx>,
+	/// )?;
+	/// let factory = item.QueryInterface::<IShellItemImageFactory>()?;
+	/// let hbmp = factory.GetImage(SIZE::new(256, 256), co::SIIGBF::RESIZETOFIT)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn GetImage(&self,
+		size: SIZE, flags: co::SIIGBF) -> HrResult<DeleteObjectGuard<HBITMAP>>
+	{
+		let mut hbmp = HBITMAP::NULL;
+		unsafe {
+			let vt = self.vt_ref::<IShellItemImageFactoryVT>();
+			ok_to_hrresult(
+				(vt.GetImage)(
+					self.ptr(),
+					MAKEQWORD(size.cx as _, size.cy as _),
+					flags.0,
+					hbmp.as_mut(),
+				),
+			).map(|_| DeleteObjectGuard::new(hbmp))
+		}
+	}
+}
@@ -0,0 +1,13 @@
+mod ishellitemimagefactory;
+
+pub mod decl {
+	pub use super::ishellitemimagefactory::IShellItemImageFactory;
+}
+
+pub mod traits {
+	pub use super::ishellitemimagefactory::gdi_shell_IShellItemImageFactory;
+}
+
+pub mod vt {
+	pub use super::ishellitemimagefactory::IShellItemImageFactoryVT;
+}
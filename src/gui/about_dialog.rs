@@ -0,0 +1,206 @@
+use crate::co;
+use crate::gui::{
+	Button, ButtonOpts, Icon, Label, LabelOpts, SysLink, SysLinkOpts,
+	WindowModal, WindowModalOpts,
+};
+use crate::kernel::decl::{HINSTANCE, SysResult};
+use crate::msg::stm;
+use crate::prelude::{
+	GuiEvents, GuiNativeControlEvents, GuiParent, GuiWindow, Handle,
+	kernel_Hinstance, shell_Hwnd, user_Hwnd,
+};
+use crate::version::decl::ResourceInfo;
+
+/// A prebuilt "About" box, showing the application icon, name, version and
+/// copyright, and an optional link to the application website.
+///
+/// Any text left empty in [`AboutDialogOpts`](crate::gui::AboutDialogOpts) is
+/// read from the `VERSIONINFO` resource of the current executable, with
+/// [`ResourceInfo`](crate::ResourceInfo).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let about = gui::AboutDialog::new(&wnd, gui::AboutDialogOpts {
+///     website: "https://github.com/rodrigocfd/winsafe".to_owned(),
+///     ..Default::default()
+/// });
+/// about.show_modal()?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone)]
+pub struct AboutDialog {
+	wnd: WindowModal,
+}
+
+unsafe impl Send for AboutDialog {}
+
+impl AboutDialog {
+	/// Instantiates a new `AboutDialog` object, with all its controls.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: AboutDialogOpts) -> Self {
+		let opts = opts.fill_from_version_info();
+
+		let wnd = WindowModal::new(parent, WindowModalOpts {
+			title: opts.title,
+			size: (360, 180),
+			..Default::default()
+		});
+
+		let lbl_icon = Label::new(&wnd, LabelOpts {
+			text: "".to_owned(),
+			position: (14, 14),
+			size: (32, 32),
+			label_style: co::SS::ICON | co::SS::REALSIZEIMAGE,
+			..Default::default()
+		});
+
+		let _lbl_name = Label::new(&wnd, LabelOpts {
+			text: opts.app_name,
+			position: (60, 14),
+			size: (286, 20),
+			..Default::default()
+		});
+
+		let _lbl_version = Label::new(&wnd, LabelOpts {
+			text: opts.version,
+			position: (60, 38),
+			size: (286, 20),
+			..Default::default()
+		});
+
+		let _lbl_copyright = Label::new(&wnd, LabelOpts {
+			text: opts.copyright,
+			position: (60, 62),
+			size: (286, 36),
+			..Default::default()
+		});
+
+		if !opts.website.is_empty() {
+			let lnk_website = SysLink::new(&wnd, SysLinkOpts {
+				text: format!("<a href=\"{}\">{}</a>", opts.website, opts.website),
+				position: (60, 104),
+				..Default::default()
+			});
+
+			let wnd2 = wnd.clone();
+			lnk_website.on_click(move |url| {
+				wnd2.hwnd().ShellExecute("open", url, None, None, co::SW::SHOWNORMAL)
+					.map_err(|e| e.to_string())?;
+				Ok(())
+			});
+		}
+
+		let btn_ok = Button::new(&wnd, ButtonOpts {
+			text: "&OK".to_owned(),
+			position: (258, 140),
+			button_style: co::BS::DEFPUSHBUTTON,
+			..Default::default()
+		});
+
+		let wnd2 = wnd.clone();
+		btn_ok.on().bn_clicked(move || {
+			wnd2.close(co::DLGID::OK.0 as _)?;
+			Ok(())
+		});
+
+		let icon = opts.icon;
+		let wnd2 = wnd.clone();
+		wnd.on().wm_create(move |_| { // controls are created by now
+			let hicon = icon.as_hicon(&wnd2.hwnd().hinstance())?;
+			lbl_icon.hwnd().SendMessage(stm::SetIcon { icon: &hicon }).ok(); // no previous icon
+			Ok(0)
+		});
+
+		Self { wnd }
+	}
+
+	/// Physically creates the dialog, then runs the modal loop. This method
+	/// will block until the dialog is closed.
+	///
+	/// # Panics
+	///
+	/// Panics if the dialog is already created.
+	pub fn show_modal(&self) -> SysResult<()> {
+		self.wnd.show_modal().map(|_| ())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create an [`AboutDialog`](crate::gui::AboutDialog) with
+/// [`AboutDialog::new`](crate::gui::AboutDialog::new).
+pub struct AboutDialogOpts {
+	/// Title of the dialog.
+	///
+	/// Defaults to `"About"`.
+	pub title: String,
+	/// Icon displayed at the left of the texts.
+	///
+	/// Defaults to `Icon::Idi(co::IDI::APPLICATION)`.
+	pub icon: Icon,
+	/// Name of the application.
+	///
+	/// Defaults to empty string, which reads `ProductName` from the
+	/// `VERSIONINFO` resource.
+	pub app_name: String,
+	/// Version of the application.
+	///
+	/// Defaults to empty string, which reads the file version from the
+	/// `VERSIONINFO` resource.
+	pub version: String,
+	/// Copyright notice.
+	///
+	/// Defaults to empty string, which reads `LegalCopyright` from the
+	/// `VERSIONINFO` resource.
+	pub copyright: String,
+	/// Website URL, displayed as a link which opens the default browser.
+	///
+	/// Defaults to empty string, which displays no link.
+	pub website: String,
+}
+
+impl Default for AboutDialogOpts {
+	fn default() -> Self {
+		Self {
+			title: "About".to_owned(),
+			icon: Icon::Idi(co::IDI::APPLICATION),
+			app_name: "".to_owned(),
+			version: "".to_owned(),
+			copyright: "".to_owned(),
+			website: "".to_owned(),
+		}
+	}
+}
+
+impl AboutDialogOpts {
+	fn fill_from_version_info(mut self) -> Self {
+		let res_info = HINSTANCE::NULL.GetModuleFileName()
+			.and_then(|exe_name| ResourceInfo::read_from(&exe_name));
+
+		if let Ok(res_info) = res_info { // the executable may have no VERSIONINFO
+			if let Some(block) = res_info.blocks().next() {
+				if self.app_name.is_empty() {
+					self.app_name = block.product_name().unwrap_or_default();
+				}
+				if self.copyright.is_empty() {
+					self.copyright = block.legal_copyright().unwrap_or_default();
+				}
+			}
+			if self.version.is_empty() {
+				if let Some(ver_info) = res_info.version_info() {
+					let ver = ver_info.dwFileVersion();
+					self.version = format!("Version {}.{}.{}.{}",
+						ver[0], ver[1], ver[2], ver[3]);
+				}
+			}
+		}
+		self
+	}
+}
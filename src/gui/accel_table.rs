@@ -0,0 +1,85 @@
+use crate::co;
+use crate::kernel::decl::SysResult;
+use crate::prelude::user_Haccel;
+use crate::user::decl::{ACCEL, HACCEL};
+use crate::user::guard::DestroyAcceleratorTableGuard;
+
+/// Builder of an accelerator table, whose keyboard shortcuts are translated
+/// into [`WM_COMMAND`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-command)
+/// messages.
+///
+/// The created table is meant to be passed to
+/// [`WindowMainOpts`](crate::gui::WindowMainOpts), so the main loop will
+/// translate the shortcuts automatically. The commands are handled by the
+/// [`wm_command_accel_menu`](crate::prelude::GuiEventsAll::wm_command_accel_menu)
+/// closures, thus shared with the menu items of the same command IDs.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, gui, seq_ids};
+///
+/// seq_ids! {
+///     ID_FILE_OPEN = 2001;
+///     ID_FILE_SAVE
+/// }
+///
+/// let wnd = gui::WindowMain::new(
+///     gui::WindowMainOpts {
+///         accel_table: Some(
+///             gui::AccelTable::new()
+///                 .add(co::ACCELF::CONTROL, co::VK::CHAR_O, ID_FILE_OPEN)
+///                 .add(co::ACCELF::CONTROL, co::VK::CHAR_S, ID_FILE_SAVE)
+///                 .build()?,
+///         ),
+///         ..Default::default()
+///     },
+/// );
+///
+/// wnd.on().wm_command_accel_menu(ID_FILE_OPEN, move || {
+///     println!("Open.");
+///     Ok(())
+/// });
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Default)]
+pub struct AccelTable {
+	entries: Vec<ACCEL>,
+}
+
+impl AccelTable {
+	/// Creates a new, empty accelerator table builder.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds a shortcut, with modifier keys like `ACCELF::CONTROL`,
+	/// `ACCELF::SHIFT` and `ACCELF::ALT`, which sends the given command ID.
+	///
+	/// Since `key` is a virtual key code, `ACCELF::VIRTKEY` is always added.
+	#[must_use]
+	pub fn add(mut self, modifiers: co::ACCELF, key: co::VK, cmd_id: u16) -> Self {
+		self.entries.push(ACCEL {
+			fVirt: modifiers | co::ACCELF::VIRTKEY,
+			key,
+			cmd: cmd_id,
+		});
+		self
+	}
+
+	/// Adds entries which were already filled, like the ones returned by
+	/// [`UndoStack::accelerators`](crate::gui::UndoStack::accelerators).
+	#[must_use]
+	pub fn add_entries(mut self, entries: &[ACCEL]) -> Self {
+		self.entries.extend_from_slice(entries);
+		self
+	}
+
+	/// Creates the accelerator table with
+	/// [`HACCEL::CreateAcceleratorTable`](crate::prelude::user_Haccel::CreateAcceleratorTable).
+	pub fn build(mut self) -> SysResult<DestroyAcceleratorTableGuard> {
+		HACCEL::CreateAcceleratorTable(&mut self.entries)
+	}
+}
@@ -1,15 +1,32 @@
+use std::cell::{Cell, UnsafeCell};
 use std::ptr::NonNull;
+use std::sync::Mutex;
 
 use crate::co;
-use crate::gui::events::{ProcessResult, WindowEventsAll};
+use crate::gui::dark_mode::{apply_dark_mode, dark_mode, DarkMode, is_dark, is_top_level};
+use crate::gui::drop_target::DropTarget;
+use crate::gui::events::{CloseDecision, CloseReason, ProcessResult, WindowEventsAll};
 use crate::gui::layout_arranger::{Horz, LayoutArranger, Vert};
-use crate::gui::privs::{post_quit_error, QUIT_ERROR};
-use crate::kernel::decl::{AnyResult, HINSTANCE, SysResult};
-use crate::msg::WndMsg;
-use crate::prelude::{GuiEvents, GuiParent, Handle, kernel_Hinstance, user_Hwnd};
-use crate::user::decl::{
-	DispatchMessage, GetMessage, HACCEL, HWND, MSG, TranslateMessage,
+use crate::gui::privs::{is_ui_font, post_quit_error, ui_font_for_dpi};
+use crate::gui::ui_task::drop_ui_futures;
+use crate::kernel::decl::{AnyResult, HINSTANCE, SysResult, WString};
+use crate::msg::{wm, WndMsg};
+use crate::ole::decl::{IDropTarget, OleInitialize};
+use crate::ole::guard::OleUninitializeGuard;
+use crate::prelude::{
+	GuiEvents, GuiParent, Handle, kernel_Hinstance, MsgSendRecv, ole_Hwnd,
+	shell_Hwnd, user_Hwnd,
 };
+use crate::user::decl::{HWND, HwndPlace, POINT, RECT, SIZE};
+
+/// A closure to be run in the UI thread.
+type UiPack = Box<dyn FnOnce() -> AnyResult<()> + Send>;
+
+/// Closures posted with `Base::post_ui_thread`, pending for each window. A
+/// window has an entry from its creation to its destruction, so closures posted
+/// to a window which is gone are dropped right away, and the ones still pending
+/// are dropped when the window is destroyed.
+static POSTED_PACKS: Mutex<Vec<(usize, Vec<UiPack>)>> = Mutex::new(Vec::new());
 
 /// Base to `RawBase` and `DlgBase`, which means all container windows.
 pub(in crate::gui) struct Base {
@@ -18,7 +35,9 @@ pub(in crate::gui) struct Base {
 	parent_ptr: Option<NonNull<Self>>,
 	user_events: WindowEventsAll, // ordinary window events, inserted by user: only last added is executed (overwrite previous)
 	privileged_events: WindowEventsAll, // inserted internally to automate tasks: all will be executed
+	privileged_ret: Cell<Option<isize>>, // result set by a privileged closure, if any
 	layout_arranger: LayoutArranger,
+	drop_target: UnsafeCell<Option<(IDropTarget, OleUninitializeGuard)>>, // registered if user added drop events
 }
 
 impl Base {
@@ -40,7 +59,9 @@ impl Base {
 			parent_ptr: parent.map(|parent| NonNull::from(parent)),
 			user_events: WindowEventsAll::new(),
 			privileged_events: WindowEventsAll::new(),
+			privileged_ret: Cell::new(None),
 			layout_arranger: LayoutArranger::new(),
+			drop_target: UnsafeCell::new(None),
 		};
 		new_self.default_message_handlers();
 		new_self
@@ -96,11 +117,163 @@ impl Base {
 		&self.privileged_events
 	}
 
-	/// If the library added a closure to the given message, run it.
+	/// If the library added a closure to the given message, run it. Returns
+	/// the result set by the closure with `set_privileged_ret`, if any, which
+	/// takes precedence over the user closure.
 	pub(in crate::gui) fn process_privileged_messages(&self,
-		wm_any: WndMsg) -> AnyResult<()>
+		wm_any: WndMsg) -> AnyResult<Option<isize>>
 	{
-		self.privileged_events.process_all_messages(wm_any)
+		self.privileged_ret.set(None);
+		self.privileged_events.process_all_messages(wm_any)?;
+		let ret = self.privileged_ret.take(); // before any nested message
+
+		// The drop target needs the window events, which are not available
+		// when the privileged closures are added, so it's handled here.
+		if wm_any.msg_id == self.creation_msg() {
+			POSTED_PACKS.lock().unwrap().push((self.hwnd.as_ptr() as _, Vec::new()));
+			self.layout_arranger.set_dpi(self.hwnd.GetDpiForWindow());
+			if self.user_events.has_drop_events() {
+				self.register_drop_target()?;
+			}
+			if self.user_events.has_message(co::WM::DROPFILES) {
+				self.hwnd.DragAcceptFiles(true);
+			}
+			if self.user_events.has_message(co::WM::CLIPBOARDUPDATE) {
+				let listener_guard = self.hwnd.AddClipboardFormatListener()?;
+				std::mem::forget(listener_guard); // the listener is removed when the window is destroyed
+			}
+			if dark_mode() != DarkMode::Off && is_top_level(&self.hwnd) {
+				apply_dark_mode(&self.hwnd, is_dark()); // child controls are already created
+			}
+		} else if wm_any.msg_id == co::WM::SETTINGCHANGE {
+			if dark_mode() == DarkMode::FollowSystem && is_top_level(&self.hwnd) {
+				let p = wm::SettingChange::from_generic_wm(wm_any);
+				if !p.section.is_null()
+					&& WString::from_wchars_nullt(p.section).to_string() == "ImmersiveColorSet"
+				{
+					apply_dark_mode(&self.hwnd, is_dark());
+				}
+			}
+		} else if wm_any.msg_id == co::WM::SYSCOLORCHANGE {
+			// Only top-level windows receive this message, and they must
+			// forward it to the common controls.
+			if is_top_level(&self.hwnd) {
+				self.hwnd.EnumChildWindows(|hchild| {
+					hchild.SendMessage(wm::SysColorChange {});
+					true
+				});
+			}
+		} else if wm_any.msg_id == co::WM::DPICHANGED {
+			// Dialogs are automatically scaled by the system, so only the
+			// stored coordinates are updated.
+			let p = wm::DpiChanged::from_generic_wm(wm_any);
+			self.layout_arranger.rescale_dpi(&self.hwnd, p.new_dpi_x as _, !self.is_dialog)?;
+			if !self.is_dialog {
+				let rc = p.suggested_rect;
+				self.hwnd.SetWindowPos(
+					HwndPlace::None,
+					POINT::new(rc.left, rc.top),
+					SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+					co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+				)?;
+			}
+			self.replace_ui_fonts(p.new_dpi_x as _)?;
+		} else if wm_any.msg_id == co::WM::DPICHANGED_AFTERPARENT { // child windows
+			self.layout_arranger.rescale_dpi(
+				&self.hwnd, self.hwnd.GetDpiForWindow(), !self.is_dialog)?;
+		} else if wm_any.msg_id == co::WM::DESTROY {
+			self.revoke_drop_target();
+			drop_ui_futures(&self.hwnd);
+		} else if wm_any.msg_id == co::WM::NCDESTROY {
+			let pending = {
+				let mut posted = POSTED_PACKS.lock().unwrap();
+				posted.iter()
+					.position(|(hwnd, _)| *hwnd == self.hwnd.as_ptr() as usize)
+					.map(|idx| posted.swap_remove(idx).1)
+			};
+			drop(pending); // closures dropped outside the lock, they may post again
+		} else if wm_any.msg_id == Self::WM_UI_THREAD {
+			self.process_ui_thread(wm_any);
+		}
+		Ok(ret)
+	}
+
+	/// Sets the result of the message being processed by a privileged closure.
+	pub(in crate::gui) fn set_privileged_ret(&self, ret: isize) {
+		self.privileged_ret.set(Some(ret));
+	}
+
+	/// Runs the user closure of the close pipeline, if any.
+	pub(in crate::gui) fn process_closing(&self,
+		reason: CloseReason) -> AnyResult<CloseDecision>
+	{
+		self.user_events.process_closing(reason)
+	}
+
+	/// Sets the UI font scaled to the new DPI on the window and its
+	/// descendants, which are still using the old DPI one. Fonts set by the
+	/// user are kept.
+	fn replace_ui_fonts(&self, dpi: u32) -> SysResult<()> {
+		let hfont = ui_font_for_dpi(dpi)?;
+		let replace = |hwnd: &HWND| {
+			if hwnd.SendMessage(wm::GetFont {}).is_some_and(|cur| is_ui_font(&cur)) {
+				hwnd.SendMessage(wm::SetFont {
+					hfont: unsafe { hfont.raw_copy() },
+					redraw: true,
+				});
+			}
+		};
+		replace(&self.hwnd);
+		self.hwnd.EnumChildWindows(|hchild| { // all descendants, not only the direct children
+			replace(&hchild);
+			true
+		});
+		Ok(())
+	}
+
+	/// Runs the closure sent by `send_ui_thread_pack`, whose slot is in
+	/// `lparam`, or the closures posted by `post_ui_thread`, when `lparam` is
+	/// zero.
+	fn process_ui_thread(&self, wm_any: WndMsg) {
+		if co::WM(wm_any.wparam as _) != Self::WM_UI_THREAD { // additional safety check
+			return;
+		}
+
+		if wm_any.lparam == 0 {
+			let packs = {
+				let mut posted = POSTED_PACKS.lock().unwrap();
+				posted.iter_mut()
+					.find(|(hwnd, _)| *hwnd == self.hwnd.as_ptr() as usize)
+					.map(|(_, packs)| std::mem::take(packs))
+					.unwrap_or_default()
+			}; // closures run outside the lock, they may post again
+			for pack in packs {
+				if let Err(err) = pack() {
+					post_quit_error(wm_any, err);
+					break;
+				}
+			}
+		} else {
+			let slot = unsafe { &mut *(wm_any.lparam as *mut Option<UiPack>) };
+			if let Some(pack) = slot.take() {
+				pack().unwrap_or_else(|err| post_quit_error(wm_any, err));
+			}
+		}
+	}
+
+	fn register_drop_target(&self) -> AnyResult<()> {
+		let ole_guard = OleInitialize()?;
+		let drop_target = DropTarget::new_obj(&self.user_events);
+		self.hwnd.RegisterDragDrop(&drop_target)?;
+		*unsafe { &mut *self.drop_target.get() } = Some((drop_target, ole_guard));
+		Ok(())
+	}
+
+	fn revoke_drop_target(&self) {
+		if let Some(drop_target) = unsafe { &mut *self.drop_target.get() }.take() {
+			self.hwnd.RevokeDragDrop().ok(); // ignore errors
+			drop(drop_target); // COM object released before OleUninitialize
+		}
 	}
 
 	/// Removes all user and privileged events.
@@ -116,22 +289,30 @@ impl Base {
 		Ok(())
 	}
 
+	pub(in crate::gui) fn add_to_layout_arranger_constrained(&self,
+		hchild: &HWND, horz: Horz, vert: Vert,
+		margins: RECT, min_size: SIZE, max_size: Option<SIZE>) -> SysResult<()>
+	{
+		self.layout_arranger.add_child_constrained(
+			&self.hwnd, hchild, horz, vert, margins, min_size, max_size)
+	}
+
+	/// Immediately rearranges the controls to the current client area.
+	pub(in crate::gui) fn rearrange_layout(&self) -> SysResult<()> {
+		let rc = self.hwnd.GetClientRect()?;
+		self.layout_arranger.rearrange(&wm::Size {
+			request: co::SIZE_R::RESTORED,
+			client_area: SIZE::new(rc.right, rc.bottom),
+		})
+	}
+
 	pub(in crate::gui) fn spawn_new_thread<F>(&self, func: F)
 		where F: FnOnce() -> AnyResult<()> + Send + 'static,
 	{
 		let hwnd = unsafe { self.hwnd.raw_copy() };
 		std::thread::spawn(move || {
 			func().unwrap_or_else(|err| {
-				let pack: Box<Box<dyn FnOnce() -> AnyResult<()>>> = Box::new(Box::new(|| Err(err)));
-				let ptr_pack = Box::into_raw(pack);
-				hwnd.GetAncestor(co::GA::ROOTOWNER)
-					.map(|hwnd| {
-						hwnd.SendMessage(WndMsg {
-							msg_id: Self::WM_UI_THREAD,
-							wparam: Self::WM_UI_THREAD.0 as _,
-							lparam: ptr_pack as _,
-						});
-					});
+				Self::send_ui_thread_pack(&hwnd, Box::new(|| Err(err)));
 			});
 		});
 	}
@@ -144,21 +325,52 @@ impl Base {
 		// by wndproc, run in the original thread of the window, thus allowing
 		// GUI updates. With this, the user doesn't have to deal with a custom
 		// WM_ message.
+		Self::send_ui_thread_pack(&self.hwnd, Box::new(func));
+	}
 
-		// https://users.rust-lang.org/t/sending-a-boxed-trait-over-ffi/21708/2
-		let pack: Box<Box<dyn FnOnce() -> AnyResult<()>>> = Box::new(Box::new(func));
-		let ptr_pack = Box::into_raw(pack);
+	/// Asynchronous counterpart of `run_ui_thread`, which doesn't wait for the
+	/// closure to run. Can be called from any thread.
+	pub(in crate::gui) fn post_ui_thread<F>(hwnd: &HWND, func: F)
+		where F: FnOnce() -> AnyResult<()> + Send + 'static,
+	{
+		// The closure is queued in the window entry, and a message with a zero
+		// lparam wakes the window up to run it.
+		let mut pack: Option<UiPack> = Some(Box::new(func));
 
-		// Bypass any modals and send straight to main window. This avoids any
-		// blind spots of unhandled messages by a modal being created/destroyed.
-		self.hwnd.GetAncestor(co::GA::ROOTOWNER)
-			.map(|hwnd| {
-				hwnd.SendMessage(WndMsg {
+		if let Some(hwnd_root) = hwnd.GetAncestor(co::GA::ROOTOWNER) {
+			let mut posted = POSTED_PACKS.lock().unwrap();
+			if let Some((_, packs)) = posted.iter_mut()
+				.find(|(hwnd, _)| *hwnd == hwnd_root.as_ptr() as usize)
+			{
+				packs.extend(pack.take());
+				hwnd_root.PostMessage(WndMsg {
 					msg_id: Self::WM_UI_THREAD,
 					wparam: Self::WM_UI_THREAD.0 as _,
-					lparam: ptr_pack as _,
-				});
+					lparam: 0,
+				}).ok(); // if it fails, the closure is dropped when the window is destroyed
+			}
+		}
+
+		drop(pack); // window is gone, the closure won't run, so just drop it
+	}
+
+	fn send_ui_thread_pack(hwnd: &HWND, pack: UiPack) {
+		// The closure is passed through a slot, which the window procedure
+		// clears when it takes the closure. SendMessage blocks until the
+		// message is processed, so the slot outlives it. If the window is gone
+		// and the message isn't processed, the closure is still in the slot,
+		// and it's dropped here.
+		let mut slot = Some(pack);
+
+		// Bypass any modals and send straight to main window. This avoids any
+		// blind spots of unhandled messages by a modal being created/destroyed.
+		if let Some(hwnd_root) = hwnd.GetAncestor(co::GA::ROOTOWNER) {
+			hwnd_root.SendMessage(WndMsg {
+				msg_id: Self::WM_UI_THREAD,
+				wparam: Self::WM_UI_THREAD.0 as _,
+				lparam: &mut slot as *mut Option<UiPack> as _,
 			});
+		}
 	}
 
 	fn default_message_handlers(&self) {
@@ -170,53 +382,5 @@ impl Base {
 			layout_arranger.rearrange(&p)?;
 			Ok(()) // not meaningful
 		});
-
-		self.privileged_events.wm(Self::WM_UI_THREAD, |p| {
-			if co::WM(p.wparam as _) == Self::WM_UI_THREAD { // additional safety check
-				let ptr_pack = p.lparam as *mut Box<dyn FnOnce() -> AnyResult<()>>;
-				let pack: Box<Box<dyn FnOnce() -> AnyResult<()>>> = unsafe { Box::from_raw(ptr_pack) };
-				pack().unwrap_or_else(|err| post_quit_error(p, err));
-			}
-			Ok(None) // not meaningful
-		});
-	}
-
-	pub(in crate::gui) fn run_main_loop(
-		haccel: Option<&HACCEL>) -> AnyResult<i32>
-	{
-		let mut msg = MSG::default();
-
-		loop {
-			if !GetMessage(&mut msg, None, 0, 0)? {
-				// WM_QUIT was sent, gracefully terminate the program.
-				// wParam has the program exit code.
-				// https://learn.microsoft.com/en-us/windows/win32/winmsg/using-messages-and-message-queues
-				// PostQuitMessage() may have been called internally, so check QUIT_ERROR.
-				return match unsafe { QUIT_ERROR.take() } {
-					Some(msg_err) => Err(msg_err.into()), // MsgError wrapped into AnyResult
-					None => Ok(msg.wParam as _), // successfull exit with ret code
-				};
-			}
-
-			// If a child window, will retrieve its top-level parent.
-			// If a top-level, use itself.
-			let hwnd_top_level = msg.hwnd.GetAncestor(co::GA::ROOT)
-					.unwrap_or(unsafe { msg.hwnd.raw_copy() });
-
-			// If we have an accelerator table, try to translate the message.
-			if let Some(haccel) = haccel {
-				if hwnd_top_level.TranslateAccelerator(haccel, &mut msg).is_ok() {
-					continue; // message translated
-				}
-			}
-
-			// Try to process keyboard actions for child controls.
-			if hwnd_top_level.IsDialogMessage(&mut msg) {
-				continue;
-			}
-
-			TranslateMessage(&msg);
-			unsafe { DispatchMessage(&msg); }
-		}
 	}
 }
@@ -0,0 +1,159 @@
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+use crate::co;
+use crate::comdlg::decl::{ChooseColor, CHOOSECOLOR};
+use crate::comdlg_gdi::decl::{ChooseFont, CHOOSEFONT};
+use crate::gdi::decl::LOGFONT;
+use crate::ole::decl::{CoCreateInstance, CoInitializeEx, HrResult, IBindCtx};
+use crate::prelude::{
+	GuiParent, Handle, shell_IFileDialog, shell_IModalWindow, shell_IShellItem,
+};
+use crate::shell::decl::{
+	IFileOpenDialog, IShellItem, SHCreateItemFromParsingName,
+};
+use crate::user::decl::COLORREF;
+
+thread_local! {
+	/// Custom colors of the color dialog, kept between the calls.
+	static CUSTOM_COLORS: RefCell<[COLORREF; 16]> =
+		const { RefCell::new([COLORREF::new(255, 255, 255); 16]) };
+}
+
+/// Shows the system color dialog with
+/// [`ChooseColor`](crate::ChooseColor), returning the color chosen by the
+/// user, or `None` if the user cancelled.
+///
+/// The custom colors defined by the user are kept until the UI thread ends.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{gui, COLORREF};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// if let Some(color) = gui::choose_color(&wnd, Some(COLORREF::new(255, 0, 0)))? {
+///     println!("Color: {} {} {}",
+///         color.GetRValue(), color.GetGValue(), color.GetBValue());
+/// }
+/// # Ok::<_, winsafe::co::CDERR>(())
+/// ```
+pub fn choose_color(
+	parent: &impl GuiParent,
+	initial: Option<COLORREF>,
+) -> Result<Option<COLORREF>, co::CDERR>
+{
+	CUSTOM_COLORS.with(|custom_colors| {
+		let mut custom_colors = custom_colors.borrow_mut();
+
+		let mut cc = CHOOSECOLOR::default();
+		cc.hwndOwner = unsafe { parent.hwnd().raw_copy() };
+		cc.Flags = co::CC::ANYCOLOR | co::CC::FULLOPEN;
+		if let Some(initial) = initial {
+			cc.Flags |= co::CC::RGBINIT;
+			cc.rgbResult = initial;
+		}
+		cc.set_lpCustColors(Some(&mut custom_colors));
+
+		Ok(if ChooseColor(&mut cc)? { Some(cc.rgbResult) } else { None })
+	})
+}
+
+/// Shows the system font dialog with [`ChooseFont`](crate::ChooseFont),
+/// returning the font chosen by the user, or `None` if the user cancelled.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// if let Some(lf) = gui::choose_font(&wnd, None)? {
+///     println!("Font: {}", lf.lfFaceName());
+/// }
+/// # Ok::<_, winsafe::co::CDERR>(())
+/// ```
+pub fn choose_font(
+	parent: &impl GuiParent,
+	initial: Option<&LOGFONT>,
+) -> Result<Option<LOGFONT>, co::CDERR>
+{
+	let mut lf = initial.cloned().unwrap_or_default();
+
+	let mut cf = CHOOSEFONT::default();
+	cf.hwndOwner = unsafe { parent.hwnd().raw_copy() };
+	cf.Flags = co::CF_FONT::FORCEFONTEXIST | co::CF_FONT::NOVERTFONTS;
+	if initial.is_some() {
+		cf.Flags |= co::CF_FONT::INITTOLOGFONTSTRUCT;
+	}
+	cf.set_lpLogFont(Some(&mut lf));
+
+	let chosen = ChooseFont(&mut cf)?;
+	Ok(if chosen { Some(lf) } else { None })
+}
+
+/// Shows the system folder picker with
+/// [`IFileOpenDialog`](crate::IFileOpenDialog), returning the folder chosen
+/// by the user, or `None` if the user cancelled.
+///
+/// COM is initialized for the current thread, if it isn't already.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// if let Some(folder) = gui::pick_folder(&wnd, Some("C:\\Temp"))? {
+///     println!("Folder: {}", folder.display());
+/// }
+/// # Ok::<_, winsafe::co::HRESULT>(())
+/// ```
+pub fn pick_folder(
+	parent: &impl GuiParent,
+	initial: Option<&str>,
+) -> HrResult<Option<PathBuf>>
+{
+	let _com_guard = CoInitializeEx( // must outlive the COM objects
+		co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+
+	let file_open = CoCreateInstance::<IFileOpenDialog>(
+		&co::CLSID::FileOpenDialog,
+		None,
+		co::CLSCTX::INPROC_SERVER,
+	)?;
+
+	file_open.SetOptions(
+		file_open.GetOptions()?
+		| co::FOS::PICKFOLDERS
+		| co::FOS::FORCEFILESYSTEM,
+	)?;
+
+	if let Some(initial) = initial {
+		// A folder which doesn't exist is simply ignored.
+		if let Ok(folder) = SHCreateItemFromParsingName::<IShellItem>(
+			initial, None::<&IBindCtx>)
+		{
+			file_open.SetFolder(&folder)?;
+		}
+	}
+
+	Ok(
+		if file_open.Show(parent.hwnd())? {
+			Some(PathBuf::from(
+				file_open.GetResult()?.GetDisplayName(co::SIGDN::FILESYSPATH)?,
+			))
+		} else {
+			None
+		},
+	)
+}
@@ -0,0 +1,93 @@
+use crate::co;
+use crate::dwm::decl::DwmAttr;
+use crate::kernel::decl::{HKEY, RegistryValue};
+use crate::prelude::{
+	dwm_Hwnd, kernel_Hkey, NativeBitflag, user_Hwnd, uxtheme_Hwnd,
+};
+use crate::user::decl::HWND;
+
+/// Dark mode behavior of the windows, set with
+/// [`gui::set_dark_mode`](crate::gui::set_dark_mode).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DarkMode {
+	/// Windows are always light. This is the default.
+	Off,
+	/// Windows are always dark.
+	On,
+	/// Windows follow the system setting, switching automatically when the
+	/// user changes it.
+	FollowSystem,
+}
+
+static mut DARK_MODE: DarkMode = DarkMode::Off;
+
+/// Sets the dark mode behavior of all the windows created afterwards.
+///
+/// When dark, top-level windows have a dark title bar, set with
+/// [`HWND::DwmSetWindowAttribute`](crate::prelude::dwm_Hwnd::DwmSetWindowAttribute),
+/// and native controls have the `"DarkMode_Explorer"` theme, set with
+/// [`HWND::SetWindowTheme`](crate::prelude::uxtheme_Hwnd::SetWindowTheme).
+/// The client area colors are still up to the application.
+///
+/// With [`DarkMode::FollowSystem`](crate::gui::DarkMode::FollowSystem), the
+/// windows are updated when a
+/// [`WM_SETTINGCHANGE`](crate::msg::wm::SettingChange) with the
+/// `"ImmersiveColorSet"` section is received.
+///
+/// Must be called in the UI thread, before creating the windows. The dark
+/// title bar requires Windows 10 build 18985; on older systems, it's ignored.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// gui::set_dark_mode(gui::DarkMode::FollowSystem);
+///
+/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// ```
+pub fn set_dark_mode(mode: DarkMode) {
+	unsafe { DARK_MODE = mode; }
+}
+
+/// Returns the current dark mode behavior.
+pub(in crate::gui) fn dark_mode() -> DarkMode {
+	unsafe { DARK_MODE }
+}
+
+/// Tells whether the windows must be dark right now.
+pub(in crate::gui) fn is_dark() -> bool {
+	match dark_mode() {
+		DarkMode::Off => false,
+		DarkMode::On => true,
+		DarkMode::FollowSystem => matches!(
+			HKEY::CURRENT_USER.RegGetValue(
+				Some("Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize"),
+				Some("AppsUseLightTheme"),
+			),
+			Ok(RegistryValue::Dword(0)),
+		),
+	}
+}
+
+/// Applies dark mode to a top-level window and all its descendant controls.
+pub(in crate::gui) fn apply_dark_mode(hwnd: &HWND, dark: bool) {
+	hwnd.DwmSetWindowAttribute(DwmAttr::UseImmersiveDarkMode(dark)).ok(); // unsupported on older systems
+	hwnd.EnumChildWindows(|hchild| {
+		apply_dark_theme(&hchild, dark);
+		true
+	});
+	hwnd.InvalidateRect(None, true).ok();
+}
+
+/// Applies the dark theme to a native control.
+pub(in crate::gui) fn apply_dark_theme(hctrl: &HWND, dark: bool) {
+	hctrl.SetWindowTheme(if dark { Some("DarkMode_Explorer") } else { None }, None)
+		.ok(); // ignore errors, the control keeps its current theme
+}
+
+/// Tells whether the window is a top-level one, which has its own title bar.
+pub(in crate::gui) fn is_top_level(hwnd: &HWND) -> bool {
+	!co::WS(hwnd.GetWindowLongPtr(co::GWLP::STYLE) as _).has(co::WS::CHILD)
+}
@@ -1,7 +1,9 @@
 use crate::co;
+use crate::comctl::decl::PROPSHEETPAGE;
 use crate::gui::base::Base;
 use crate::gui::events::{ProcessResult, WindowEventsAll};
-use crate::gui::privs::{post_quit_error, ui_font};
+use crate::gui::panic_handler::catch_panic;
+use crate::gui::privs::{post_quit_error, ui_font_for_dpi};
 use crate::kernel::decl::{AnyResult, IdStr, SysResult};
 use crate::msg::{wm, WndMsg};
 use crate::prelude::{Handle, MsgSendRecv, user_Hinstance, user_Hwnd};
@@ -94,6 +96,27 @@ impl DlgBase {
 		Ok(ret as _)
 	}
 
+	pub(in crate::gui) const fn dialog_id(&self) -> u16 {
+		self.dialog_id
+	}
+
+	/// Sets the fields of a property sheet page which will host this dialog;
+	/// the template must be set by the caller.
+	pub(in crate::gui) fn fill_property_sheet_page(&self,
+		psp: &mut PROPSHEETPAGE) -> SysResult<()>
+	{
+		if *self.base.hwnd() != HWND::NULL {
+			panic!("Cannot create dialog twice.");
+		}
+
+		psp.hInstance = self.base.parent_hinstance()?;
+		psp.pfnDlgProc = Some(Self::page_proc);
+		// Pass pointer to Self.
+		// At this moment, the parent struct is already created and pinned.
+		psp.lParam = self as *const _ as _;
+		Ok(())
+	}
+
 	pub(in crate::gui) fn spawn_new_thread<F>(&self, func: F)
 		where F: FnOnce() -> AnyResult<()> + Send + 'static,
 	{
@@ -110,10 +133,23 @@ impl DlgBase {
 		hwnd: HWND, msg: co::WM, wparam: usize, lparam: isize) -> isize
 	{
 		let wm_any = WndMsg::new(msg, wparam, lparam);
-		Self::dialog_proc_proc(hwnd, wm_any)
+		catch_panic(wm_any, || Self::dialog_proc_proc(hwnd, wm_any))
 			.unwrap_or_else(|err| { post_quit_error(wm_any, err); true as _ })
 	}
 
+	extern "system" fn page_proc(
+		hwnd: HWND, msg: co::WM, wparam: usize, lparam: isize) -> isize
+	{
+		// The property sheet passes a copy of the PROPSHEETPAGE, whose lParam
+		// holds our pointer.
+		let lparam = if msg == co::WM::INITDIALOG {
+			unsafe { &*(lparam as *const PROPSHEETPAGE) }.lParam
+		} else {
+			lparam
+		};
+		Self::dialog_proc(hwnd, msg, wparam, lparam)
+	}
+
 	fn dialog_proc_proc(hwnd: HWND, wm_any: WndMsg) -> AnyResult<isize> {
 		let ptr_self = match wm_any.msg_id {
 			co::WM::INITDIALOG => { // first message being handled
@@ -133,20 +169,21 @@ impl DlgBase {
 			return Ok(hwnd.DefWindowProc(wm_any));
 		}
 
-		// Execute privileged closures.
+		// Execute privileged closures, which may set the result.
 		let ref_self = unsafe { &mut *ptr_self };
-		ref_self.base.process_privileged_messages(wm_any)?;
+		let privileged_ret = ref_self.base.process_privileged_messages(wm_any)?;
 
 		if wm_any.msg_id == co::WM::INITDIALOG {
 			// Child controls are created in privileged closures, so we set the
 			// system font only now.
+			let hfont = ui_font_for_dpi(ref_self.base.hwnd().GetDpiForWindow())?;
 			ref_self.base.hwnd().SendMessage(wm::SetFont { // on the window itself
-				hfont: unsafe { ui_font().raw_copy() },
+				hfont: unsafe { hfont.raw_copy() },
 				redraw: false,
 			});
 			ref_self.base.hwnd().EnumChildWindows(|hchild| {
 				hchild.SendMessage(wm::SetFont { // on each child control
-					hfont: unsafe { ui_font().raw_copy() },
+					hfont: unsafe { hfont.raw_copy() },
 					redraw: false,
 				});
 				true
@@ -154,7 +191,10 @@ impl DlgBase {
 		}
 
 		// Execute user closure, if any.
-		let process_result = ref_self.base.process_user_message(wm_any)?;
+		let process_result = match (privileged_ret, ref_self.base.process_user_message(wm_any)?) {
+			(Some(ret), _) => ProcessResult::HandledWithRet(ret),
+			(None, process_result) => process_result,
+		};
 
 		if wm_any.msg_id == co::WM::NCDESTROY { // always check
 			hwnd.SetWindowLongPtr(co::GWLP::DWLP_USER, 0); // clear passed pointer
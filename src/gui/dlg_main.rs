@@ -3,16 +3,15 @@ use std::pin::Pin;
 use std::sync::Arc;
 
 use crate::co;
-use crate::gdi::decl::IdOicStr;
-use crate::gui::base::Base;
 use crate::gui::dlg_base::DlgBase;
 use crate::gui::events::WindowEventsAll;
+use crate::gui::message_loop::MessageLoop;
+use crate::gui::raw_base::Icon;
 use crate::kernel::decl::{AnyResult, HINSTANCE, IdStr, SysResult};
-use crate::msg::wm;
 use crate::prelude::{
-	gdi_Hinstance, GuiEvents, kernel_Hinstance, user_Hinstance, user_Hwnd,
+	GuiEvents, kernel_Hinstance, user_Hinstance, user_Hwnd,
 };
-use crate::user::decl::{HWND, PostQuitMessage, SIZE};
+use crate::user::decl::{HWND, PostQuitMessage};
 
 struct Obj { // actual fields of DlgMain
 	dlg_base: DlgBase,
@@ -73,7 +72,7 @@ impl DlgMain {
 	}
 
 	pub(in crate::gui) fn run_main(&self,
-		cmd_show: Option<co::SW>) -> AnyResult<i32>
+		cmd_show: Option<co::SW>, msg_loop: MessageLoop) -> AnyResult<i32>
 	{
 		self.0.dlg_base.create_dialog_param().unwrap();
 		let hinst = HINSTANCE::GetModuleHandle(None).unwrap();
@@ -82,40 +81,28 @@ impl DlgMain {
 			.transpose()
 			.unwrap();
 
-		self.set_icon_if_any(&hinst).unwrap();
+		self.set_icon_if_any().unwrap();
 		self.hwnd().ShowWindow(cmd_show.unwrap_or(co::SW::SHOW));
 
-		Base::run_main_loop(haccel.as_ref()) // blocks until window is closed
+		msg_loop.run_with(haccel.as_ref(), None) // blocks until window is closed
 	}
 
 	fn default_message_handlers(&self) {
-		let self2 = self.clone();
-		self.on().wm_close(move || {
-			self2.hwnd().DestroyWindow().ok(); // ignore errors
-			Ok(())
-		});
-
+		// WM_CLOSE is handled by the close pipeline of WindowMain.
 		self.on().wm_nc_destroy(|| {
 			PostQuitMessage(0);
 			Ok(())
 		});
 	}
 
-	fn set_icon_if_any(&self, hinst: &HINSTANCE) -> SysResult<()> {
-		// If an icon ID was specified, load it from the resources.
+	fn set_icon_if_any(&self) -> SysResult<()> {
+		// If an icon ID was specified, load it from the resources, with the
+		// sizes for the current DPI.
 		// Resource icons are automatically released by the system.
 		if let Some(id) = self.0.icon_id {
-			self.hwnd().SendMessage(wm::SetIcon {
-				hicon: hinst.LoadImageIcon(
-					IdOicStr::Id(id), SIZE::new(16, 16), co::LR::DEFAULTCOLOR)?.leak(),
-				size: co::ICON_SZ::SMALL,
-			});
-
-			self.hwnd().SendMessage(wm::SetIcon {
-				hicon: hinst.LoadImageIcon(
-					IdOicStr::Id(id), SIZE::new(32, 32), co::LR::DEFAULTCOLOR)?.leak(),
-				size: co::ICON_SZ::BIG,
-			});
+			for mut hicon in Icon::Id(id).set_to_window(self.hwnd())? {
+				let _ = hicon.leak();
+			}
 		}
 		Ok(())
 	}
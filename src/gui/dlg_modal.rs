@@ -63,6 +63,10 @@ impl DlgModal {
 		self.0.dlg_base.dialog_box_param()
 	}
 
+	pub(in crate::gui) fn close(&self, result: i32) -> SysResult<()> {
+		self.hwnd().EndDialog(result as _)
+	}
+
 	fn default_message_handlers(&self) {
 		let self2 = self.clone();
 		self.0.dlg_base.privileged_on().wm_init_dialog(move |_| {
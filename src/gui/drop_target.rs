@@ -0,0 +1,202 @@
+#![allow(non_snake_case)]
+
+use std::cell::Cell;
+use std::mem::ManuallyDrop;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicU32, fence, Ordering};
+
+use crate::co;
+use crate::gui::events::WindowEventsAll;
+use crate::gui::panic_handler::catch_panic;
+use crate::gui::privs::post_quit_error;
+use crate::kernel::decl::{AnyResult, WString};
+use crate::kernel::ffi_types::{HRES, PCVOID};
+use crate::msg::WndMsg;
+use crate::ole::decl::{ComPtr, FORMATETC, IDataObject, IDropTarget, IUnknown};
+use crate::prelude::{Handle, kernel_Hglobal, ole_IDataObject, ole_IUnknown, shell_Hdrop};
+use crate::shell::decl::HDROP;
+use crate::vt::{IDropTargetVT, IUnknownVT};
+
+/// Virtual table shared by all [`DropTarget`](crate::gui::drop_target::DropTarget)
+/// objects.
+static DROP_TARGET_VT: IDropTargetVT = IDropTargetVT {
+	IUnknownVT: IUnknownVT {
+		QueryInterface: DropTarget::QueryInterface,
+		AddRef: DropTarget::AddRef,
+		Release: DropTarget::Release,
+	},
+	DragEnter: DropTarget::DragEnter,
+	DragOver: DropTarget::DragOver,
+	DragLeave: DropTarget::DragLeave,
+	Drop: DropTarget::Drop,
+};
+
+/// Implementation of the
+/// [`IDropTarget`](https://learn.microsoft.com/en-us/windows/win32/api/oleidl/nn-oleidl-idroptarget)
+/// COM interface, which forwards the dropped files and text to the
+/// `drop_files` and `drop_text` events of a window.
+#[repr(C)]
+pub(in crate::gui) struct DropTarget {
+	vt: *const IDropTargetVT, // must be the first field, as in any COM object
+	ref_count: AtomicU32,
+	events_ptr: NonNull<WindowEventsAll>,
+	effect: Cell<co::DROPEFFECT>, // decided when the drag enters the window
+}
+
+impl DropTarget {
+	/// Creates a new COM object, with a reference count of 1.
+	///
+	/// The events must outlive the object, which is granted by revoking the
+	/// drop target before the window is destroyed.
+	#[must_use]
+	pub(in crate::gui) fn new_obj(events: &WindowEventsAll) -> IDropTarget {
+		let obj = Box::new(Self {
+			vt: &DROP_TARGET_VT,
+			ref_count: AtomicU32::new(1),
+			events_ptr: NonNull::from(events),
+			effect: Cell::new(co::DROPEFFECT::NONE),
+		});
+		IDropTarget::from(ComPtr(Box::into_raw(obj) as _))
+	}
+
+	fn from_com_ptr<'a>(p: ComPtr) -> &'a Self {
+		unsafe { &*(p.0 as *const Self) }
+	}
+
+	fn events(&self) -> &WindowEventsAll {
+		unsafe { self.events_ptr.as_ref() }
+	}
+
+	fn QueryInterface(p: ComPtr, riid: PCVOID, ppv: *mut ComPtr) -> HRES {
+		let riid = unsafe { &*(riid as *const co::IID) };
+		if *riid == IUnknown::IID || *riid == IDropTarget::IID {
+			Self::AddRef(p);
+			unsafe { *ppv = p; }
+			co::HRESULT::S_OK.0
+		} else {
+			unsafe { *ppv = ComPtr::null(); }
+			co::HRESULT::E_NOINTERFACE.0
+		}
+	}
+
+	fn AddRef(p: ComPtr) -> u32 {
+		Self::from_com_ptr(p).ref_count.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn Release(p: ComPtr) -> u32 {
+		let count = Self::from_com_ptr(p).ref_count.fetch_sub(1, Ordering::Release) - 1;
+		if count == 0 {
+			fence(Ordering::Acquire); // see the drop of std::sync::Arc
+			let _ = unsafe { Box::from_raw(p.0 as *mut Self) };
+		}
+		count
+	}
+
+	fn DragEnter(p: ComPtr,
+		data_obj: ComPtr, _key_state: u32, _pt: u64, effect: *mut u32) -> HRES
+	{
+		let obj = Self::from_com_ptr(p);
+		let data_obj = ManuallyDrop::new(IDataObject::from(data_obj)); // not owned by us
+
+		let accepts = (obj.events().has_drop_files() && Self::has_format(&data_obj, co::CF::HDROP))
+			|| (obj.events().has_drop_text() && Self::has_format(&data_obj, co::CF::UNICODETEXT));
+		obj.effect.set(if accepts { co::DROPEFFECT::COPY } else { co::DROPEFFECT::NONE });
+
+		unsafe { *effect = obj.effect.get().0; }
+		co::HRESULT::S_OK.0
+	}
+
+	fn DragOver(p: ComPtr, _key_state: u32, _pt: u64, effect: *mut u32) -> HRES {
+		unsafe { *effect = Self::from_com_ptr(p).effect.get().0; }
+		co::HRESULT::S_OK.0
+	}
+
+	fn DragLeave(p: ComPtr) -> HRES {
+		Self::from_com_ptr(p).effect.set(co::DROPEFFECT::NONE);
+		co::HRESULT::S_OK.0
+	}
+
+	fn Drop(p: ComPtr,
+		data_obj: ComPtr, _key_state: u32, _pt: u64, effect: *mut u32) -> HRES
+	{
+		let obj = Self::from_com_ptr(p);
+		let data_obj = ManuallyDrop::new(IDataObject::from(data_obj)); // not owned by us
+
+		unsafe { *effect = obj.effect.get().0; }
+		if obj.effect.get() != co::DROPEFFECT::NONE {
+			obj.effect.set(co::DROPEFFECT::NONE);
+			let src_msg = WndMsg::new(co::WM::NULL, 0, 0);
+			catch_panic(src_msg, || obj.process_drop(&data_obj).map(|_| 0))
+				.unwrap_or_else(|err| { post_quit_error(src_msg, err); 0 });
+		}
+		co::HRESULT::S_OK.0
+	}
+
+	fn process_drop(&self, data_obj: &IDataObject) -> AnyResult<()> {
+		if self.events().has_drop_files() {
+			if let Some(files) = Self::get_files(data_obj)? {
+				return self.events().process_drop_files(files);
+			}
+		}
+		if self.events().has_drop_text() {
+			if let Some(text) = Self::get_text(data_obj)? {
+				return self.events().process_drop_text(text);
+			}
+		}
+		Ok(())
+	}
+
+	fn format_etc(cf: co::CF) -> FORMATETC<'static> {
+		let mut fmt = FORMATETC::default();
+		fmt.set_cfFormat(cf);
+		fmt.dwAspect = 1; // DVASPECT_CONTENT
+		fmt.lindex = -1;
+		fmt.tymed = co::TYMED::HGLOBAL;
+		fmt
+	}
+
+	fn has_format(data_obj: &IDataObject, cf: co::CF) -> bool {
+		data_obj.QueryGetData(&Self::format_etc(cf)).unwrap_or(false)
+	}
+
+	fn get_files(data_obj: &IDataObject) -> AnyResult<Option<Vec<String>>> {
+		let stgm = match data_obj.GetData(&Self::format_etc(co::CF::HDROP)) {
+			Ok(stgm) => stgm,
+			Err(_) => return Ok(None), // format not available
+		};
+		let hdrop = match stgm.hGlobal() {
+			Some(hglobal) => unsafe { HDROP::from_ptr(hglobal.as_ptr()) },
+			None => return Ok(None),
+		};
+
+		// We can't use HDROP::iter(), because it calls DragFinish(), and the
+		// handle is freed by ReleaseStgMedium().
+		let count = unsafe { hdrop.DragQueryFile(None, None)? };
+		let mut files = Vec::with_capacity(count as _);
+		for i in 0..count {
+			let len = unsafe { hdrop.DragQueryFile(Some(i), None)? };
+			let mut buf = WString::new_alloc_buf(len as usize + 1);
+			unsafe { hdrop.DragQueryFile(Some(i), Some(&mut buf))?; }
+			files.push(buf.to_string());
+		}
+		Ok(Some(files))
+	}
+
+	fn get_text(data_obj: &IDataObject) -> AnyResult<Option<String>> {
+		let stgm = match data_obj.GetData(&Self::format_etc(co::CF::UNICODETEXT)) {
+			Ok(stgm) => stgm,
+			Err(_) => return Ok(None), // format not available
+		};
+		let hglobal = match stgm.hGlobal() {
+			Some(hglobal) => hglobal,
+			None => return Ok(None),
+		};
+
+		let (bytes, _unlock_guard) = hglobal.GlobalLock()?;
+		let wchars = unsafe {
+			std::slice::from_raw_parts(bytes.as_ptr() as *const u16, bytes.len() / 2)
+		};
+		let len = wchars.iter().position(|ch| *ch == 0).unwrap_or(wchars.len());
+		Ok(Some(WString::from_wchars_slice(&wchars[..len]).to_string()))
+	}
+}
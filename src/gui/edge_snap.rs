@@ -0,0 +1,243 @@
+use std::cell::{Cell, RefCell};
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::kernel::decl::{AnyResult, GetCurrentProcessId};
+use crate::prelude::{GuiEvents, GuiParent, user_Hmonitor, user_Hwnd};
+use crate::user::decl::{EnumWindows, HMONITOR, MONITORINFOEX, RECT};
+
+struct Obj { // actual fields of EdgeSnap
+	parent_ptr: NonNull<Base>,
+	opts: EdgeSnapOpts,
+	enabled: Cell<bool>,
+}
+
+//------------------------------------------------------------------------------
+
+/// Makes a window "magnetic": while it's being moved or resized by the user,
+/// its edges snap to the edges of the monitor work area and of the other
+/// top-level windows of the current process, when closer than a threshold.
+///
+/// The snapping is done by adjusting the rectangle of
+/// [`WM_MOVING`](crate::msg::wm::Moving) and
+/// [`WM_SIZING`](crate::msg::wm::Sizing) messages.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let snap = gui::EdgeSnap::new(&wnd, gui::EdgeSnapOpts {
+///     threshold: 16,
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Clone)]
+pub struct EdgeSnap(Rc<Obj>);
+
+unsafe impl Send for EdgeSnap {}
+
+impl EdgeSnap {
+	/// Instantiates a new `EdgeSnap` object, which will act upon the parent
+	/// window.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create an `EdgeSnap` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: EdgeSnapOpts) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+
+		let new_self = Self(
+			Rc::new(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					opts,
+					enabled: Cell::new(true),
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm_moving(move |p| {
+			if self2.is_enabled() {
+				self2.snap_moving(p.window_pos)?;
+			}
+			Ok(())
+		});
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm_sizing(move |p| {
+			if self2.is_enabled() {
+				self2.snap_sizing(p.window_edge, p.coords)?;
+			}
+			Ok(())
+		});
+
+		new_self
+	}
+
+	fn parent(&self) -> &Base {
+		unsafe { self.0.parent_ptr.as_ref() }
+	}
+
+	/// Tells whether the snapping is currently enabled.
+	#[must_use]
+	pub fn is_enabled(&self) -> bool {
+		self.0.enabled.get()
+	}
+
+	/// Enables or disables the snapping. It's initially enabled.
+	pub fn set_enabled(&self, enabled: bool) {
+		self.0.enabled.set(enabled);
+	}
+
+	fn snap_moving(&self, rc: &mut RECT) -> AnyResult<()> {
+		let (vert_lines, horz_lines) = self.snap_lines(rc)?;
+		let threshold = self.0.opts.threshold;
+
+		if let Some(dx) = nearest_delta(&[rc.left, rc.right], &vert_lines, threshold) {
+			rc.left += dx;
+			rc.right += dx;
+		}
+		if let Some(dy) = nearest_delta(&[rc.top, rc.bottom], &horz_lines, threshold) {
+			rc.top += dy;
+			rc.bottom += dy;
+		}
+		Ok(())
+	}
+
+	fn snap_sizing(&self, edge: co::WMSZ, rc: &mut RECT) -> AnyResult<()> {
+		let (vert_lines, horz_lines) = self.snap_lines(rc)?;
+		let threshold = self.0.opts.threshold;
+
+		// Only the edges being dragged are snapped.
+		let horz_edge = match edge {
+			co::WMSZ::LEFT | co::WMSZ::TOPLEFT | co::WMSZ::BOTTOMLEFT => Some(&mut rc.left),
+			co::WMSZ::RIGHT | co::WMSZ::TOPRIGHT | co::WMSZ::BOTTOMRIGHT => Some(&mut rc.right),
+			_ => None,
+		};
+		if let Some(x) = horz_edge {
+			if let Some(dx) = nearest_delta(&[*x], &vert_lines, threshold) {
+				*x += dx;
+			}
+		}
+
+		let vert_edge = match edge {
+			co::WMSZ::TOP | co::WMSZ::TOPLEFT | co::WMSZ::TOPRIGHT => Some(&mut rc.top),
+			co::WMSZ::BOTTOM | co::WMSZ::BOTTOMLEFT | co::WMSZ::BOTTOMRIGHT => Some(&mut rc.bottom),
+			_ => None,
+		};
+		if let Some(y) = vert_edge {
+			if let Some(dy) = nearest_delta(&[*y], &horz_lines, threshold) {
+				*y += dy;
+			}
+		}
+		Ok(())
+	}
+
+	/// Returns the vertical and horizontal lines the window edges can snap to.
+	fn snap_lines(&self, rc: &RECT) -> AnyResult<(Vec<i32>, Vec<i32>)> {
+		let opts = &self.0.opts;
+		let mut vert_lines = Vec::<i32>::new();
+		let mut horz_lines = Vec::<i32>::new();
+
+		if opts.screen_edges {
+			let hmon = HMONITOR::MonitorFromRect(*rc, co::MONITOR::DEFAULTTONEAREST);
+			let mut mi = MONITORINFOEX::default();
+			hmon.GetMonitorInfo(&mut mi)?;
+			vert_lines.extend_from_slice(&[mi.rcWork.left, mi.rcWork.right]);
+			horz_lines.extend_from_slice(&[mi.rcWork.top, mi.rcWork.bottom]);
+		}
+
+		if opts.other_windows {
+			let hwnd_self = self.parent().hwnd();
+			let proc_id = GetCurrentProcessId();
+			let others = RefCell::new(Vec::<RECT>::new());
+
+			EnumWindows(|hwnd| {
+				if hwnd != *hwnd_self
+					&& hwnd.IsWindowVisible()
+					&& !hwnd.IsIconic()
+					&& hwnd.GetWindowThreadProcessId().1 == proc_id
+				{
+					if let Ok(rc_other) = hwnd.GetWindowRect() {
+						others.borrow_mut().push(rc_other);
+					}
+				}
+				true
+			})?;
+
+			// An edge of another window is only considered if the windows are
+			// side by side along that edge.
+			let th = opts.threshold;
+			for other in others.into_inner().iter() {
+				if rc.top < other.bottom + th && rc.bottom > other.top - th {
+					vert_lines.extend_from_slice(&[other.left, other.right]);
+				}
+				if rc.left < other.right + th && rc.right > other.left - th {
+					horz_lines.extend_from_slice(&[other.top, other.bottom]);
+				}
+			}
+		}
+
+		Ok((vert_lines, horz_lines))
+	}
+}
+
+/// Returns the smallest offset, within the threshold, which makes any of the
+/// edges coincide with any of the lines.
+fn nearest_delta(edges: &[i32], lines: &[i32], threshold: i32) -> Option<i32> {
+	edges.iter()
+		.flat_map(|edge| lines.iter().map(move |line| line - edge))
+		.filter(|delta| delta.abs() <= threshold)
+		.min_by_key(|delta| delta.abs())
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create an [`EdgeSnap`](crate::gui::EdgeSnap) with
+/// [`EdgeSnap::new`](crate::gui::EdgeSnap::new).
+pub struct EdgeSnapOpts {
+	/// Maximum distance, in pixels, at which an edge snaps.
+	///
+	/// Defaults to `10`.
+	pub threshold: i32,
+	/// Snap to the edges of the monitor work area.
+	///
+	/// Defaults to `true`.
+	pub screen_edges: bool,
+	/// Snap to the edges of other visible top-level windows of the current
+	/// process.
+	///
+	/// Defaults to `true`.
+	pub other_windows: bool,
+}
+
+impl Default for EdgeSnapOpts {
+	fn default() -> Self {
+		Self {
+			threshold: 10,
+			screen_edges: true,
+			other_windows: true,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn nearest() {
+		assert_eq!(nearest_delta(&[0, 100], &[5, 103, 200], 10), Some(3));
+		assert_eq!(nearest_delta(&[0, 100], &[5, 103, 200], 2), None);
+		assert_eq!(nearest_delta(&[0, 100], &[], 10), None);
+	}
+}
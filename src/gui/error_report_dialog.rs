@@ -0,0 +1,220 @@
+use crate::co;
+use crate::gui::{
+	Button, ButtonOpts, Edit, EditOpts, Icon, Label, LabelOpts, WindowModal,
+	WindowModalOpts,
+};
+use crate::kernel::decl::SysResult;
+use crate::msg::stm;
+use crate::prelude::{
+	GuiEvents, GuiNativeControlEvents, GuiParent, GuiWindow, GuiWindowText,
+	user_Hwnd,
+};
+use crate::user::decl::{Clipboard, HwndPlace, POINT, SIZE};
+
+/// A prebuilt dialog to report an error to the user, showing a message and an
+/// expandable area with the details, which can also be copied to the
+/// clipboard.
+///
+/// # Examples
+///
+/// Reporting an error returned by an operation:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// let err: Box<dyn std::error::Error + Send + Sync>; // returned somewhere
+/// # let err: Box<dyn std::error::Error + Send + Sync> = "".into();
+///
+/// let dlg = gui::ErrorReportDialog::new(&wnd,
+///     gui::ErrorReportDialogOpts::from_error(err.as_ref()));
+/// dlg.show_modal()?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[derive(Clone)]
+pub struct ErrorReportDialog {
+	wnd: WindowModal,
+}
+
+unsafe impl Send for ErrorReportDialog {}
+
+impl ErrorReportDialog {
+	/// Instantiates a new `ErrorReportDialog` object, with all its controls.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: ErrorReportDialogOpts) -> Self {
+		let wnd = WindowModal::new(parent, WindowModalOpts {
+			title: opts.title,
+			size: (440, 128), // details area is initially hidden below
+			..Default::default()
+		});
+
+		let lbl_icon = Label::new(&wnd, LabelOpts {
+			text: "".to_owned(),
+			position: (14, 14),
+			size: (32, 32),
+			label_style: co::SS::ICON | co::SS::REALSIZEIMAGE,
+			..Default::default()
+		});
+
+		let _lbl_message = Label::new(&wnd, LabelOpts {
+			text: opts.message.clone(),
+			position: (60, 14),
+			size: (366, 64),
+			..Default::default()
+		});
+
+		let btn_details = Button::new(&wnd, ButtonOpts {
+			text: "&Details >>".to_owned(),
+			position: (14, 88),
+			width: 100,
+			window_style: if opts.details.is_empty() {
+				co::WS::CHILD | co::WS::TABSTOP | co::WS::GROUP
+			} else {
+				co::WS::CHILD | co::WS::VISIBLE | co::WS::TABSTOP | co::WS::GROUP
+			},
+			..Default::default()
+		});
+
+		let btn_copy = Button::new(&wnd, ButtonOpts {
+			text: "&Copy".to_owned(),
+			position: (122, 88),
+			..Default::default()
+		});
+
+		let btn_close = Button::new(&wnd, ButtonOpts {
+			text: "Close".to_owned(),
+			position: (338, 88),
+			button_style: co::BS::DEFPUSHBUTTON,
+			..Default::default()
+		});
+
+		let txt_details = Edit::new(&wnd, EditOpts {
+			text: opts.details.lines().collect::<Vec<_>>().join("\r\n"),
+			position: (14, 128),
+			width: 412,
+			height: 150,
+			edit_style: co::ES::MULTILINE | co::ES::READONLY | co::ES::AUTOVSCROLL,
+			window_style: co::WS::CHILD | co::WS::VSCROLL | co::WS::TABSTOP | co::WS::GROUP,
+			..Default::default()
+		});
+
+		let (wnd2, btn2) = (wnd.clone(), btn_details.clone());
+		btn_details.on().bn_clicked(move || {
+			let expanding = !txt_details.hwnd().IsWindowVisible();
+			let rc_txt = txt_details.hwnd().GetWindowRect()?;
+			let delta = (rc_txt.bottom - rc_txt.top) * 164 / 150; // plus bottom margin, DPI-scaled
+			let rc_wnd = wnd2.hwnd().GetWindowRect()?;
+
+			wnd2.hwnd().SetWindowPos(
+				HwndPlace::None,
+				POINT::default(),
+				SIZE::new(
+					rc_wnd.right - rc_wnd.left,
+					rc_wnd.bottom - rc_wnd.top + if expanding { delta } else { -delta },
+				),
+				co::SWP::NOZORDER | co::SWP::NOMOVE,
+			)?;
+			txt_details.hwnd().ShowWindow(if expanding { co::SW::SHOW } else { co::SW::HIDE });
+			btn2.set_text(if expanding { "&Details <<" } else { "&Details >>" });
+			Ok(())
+		});
+
+		let report = if opts.details.is_empty() {
+			opts.message
+		} else {
+			format!("{}\r\n\r\n{}", opts.message, opts.details)
+		};
+		let wnd2 = wnd.clone();
+		btn_copy.on().bn_clicked(move || {
+			let clip = Clipboard::open(wnd2.hwnd())?;
+			clip.empty()?;
+			clip.set_text(&report)?;
+			Ok(())
+		});
+
+		let wnd2 = wnd.clone();
+		btn_close.on().bn_clicked(move || {
+			wnd2.close(co::DLGID::CANCEL.0 as _)?;
+			Ok(())
+		});
+
+		let icon = opts.icon;
+		let wnd2 = wnd.clone();
+		wnd.on().wm_create(move |_| { // controls are created by now
+			let hicon = icon.as_hicon(&wnd2.hwnd().hinstance())?;
+			lbl_icon.hwnd().SendMessage(stm::SetIcon { icon: &hicon }).ok(); // no previous icon
+			Ok(0)
+		});
+
+		Self { wnd }
+	}
+
+	/// Physically creates the dialog, then runs the modal loop. This method
+	/// will block until the dialog is closed.
+	///
+	/// # Panics
+	///
+	/// Panics if the dialog is already created.
+	pub fn show_modal(&self) -> SysResult<()> {
+		self.wnd.show_modal().map(|_| ())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create an [`ErrorReportDialog`](crate::gui::ErrorReportDialog)
+/// with [`ErrorReportDialog::new`](crate::gui::ErrorReportDialog::new).
+pub struct ErrorReportDialogOpts {
+	/// Title of the dialog.
+	///
+	/// Defaults to `"Error"`.
+	pub title: String,
+	/// Icon displayed at the left of the message.
+	///
+	/// Defaults to `Icon::Idi(co::IDI::ERROR)`.
+	pub icon: Icon,
+	/// Message displayed to the user.
+	///
+	/// Defaults to empty string.
+	pub message: String,
+	/// Details displayed in the expandable area.
+	///
+	/// Defaults to empty string, which hides the "Details" button.
+	pub details: String,
+}
+
+impl Default for ErrorReportDialogOpts {
+	fn default() -> Self {
+		Self {
+			title: "Error".to_owned(),
+			icon: Icon::Idi(co::IDI::ERROR),
+			message: "".to_owned(),
+			details: "".to_owned(),
+		}
+	}
+}
+
+impl ErrorReportDialogOpts {
+	/// Creates the options from an error: the message is the error itself,
+	/// and the details are the chain of its
+	/// [sources](https://doc.rust-lang.org/std/error/trait.Error.html#method.source),
+	/// followed by its debug representation.
+	#[must_use]
+	pub fn from_error(err: &(dyn std::error::Error + 'static)) -> Self {
+		let mut details = String::new();
+		let mut source = err.source();
+		while let Some(src) = source {
+			details.push_str(&format!("Caused by: {}\n", src));
+			source = src.source();
+		}
+		details.push_str(&format!("{:?}", err));
+
+		Self {
+			message: err.to_string(),
+			details,
+			..Default::default()
+		}
+	}
+}
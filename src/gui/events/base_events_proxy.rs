@@ -37,4 +37,20 @@ impl BaseEventsProxy {
 		let parent_ref = unsafe { self.parent_ptr.as_ref() };
 		parent_ref.on().wm_notify(self.ctrl_id as _, code, func);
 	}
+
+	/// Adds a `WM_DRAWITEM` event to the parent window.
+	pub(in crate::gui) fn wm_draw_item<F>(&self, func: F)
+		where F: Fn(wm::DrawItem) -> AnyResult<()> + 'static,
+	{
+		let parent_ref = unsafe { self.parent_ptr.as_ref() };
+		parent_ref.on().wm_draw_item_ctrl(self.ctrl_id, func);
+	}
+
+	/// Adds a `WM_MEASUREITEM` event to the parent window.
+	pub(in crate::gui) fn wm_measure_item<F>(&self, func: F)
+		where F: Fn(wm::MeasureItem) -> AnyResult<()> + 'static,
+	{
+		let parent_ref = unsafe { self.parent_ptr.as_ref() };
+		parent_ref.on().wm_measure_item_ctrl(self.ctrl_id, func);
+	}
 }
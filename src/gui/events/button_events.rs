@@ -3,6 +3,7 @@ use crate::comctl::decl::{NMBCDROPDOWN, NMBCHOTITEM, NMCUSTOMDRAW};
 use crate::gui::base::Base;
 use crate::gui::events::base_events_proxy::BaseEventsProxy;
 use crate::kernel::decl::AnyResult;
+use crate::msg::wm;
 
 /// Exposes button control
 /// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-button-control-reference-notifications).
@@ -77,4 +78,41 @@ impl ButtonEvents {
 		self.0.wm_notify(co::NM::CUSTOMDRAW,
 			move |p| Ok(Some(func(unsafe { p.cast_nmhdr::<NMCUSTOMDRAW>() })?.0 as _)));
 	}
+
+	/// [`WM_DRAWITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-drawitem)
+	/// message, sent to the parent of buttons created with the
+	/// [`BS::OWNERDRAW`](crate::co::BS::OWNERDRAW) style.
+	///
+	/// The whole button must be painted with the `hDC` of the
+	/// [`DRAWITEMSTRUCT`](crate::DRAWITEMSTRUCT), using `itemState` to
+	/// render the pressed, focused and disabled states.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui, msg, AnyResult, HBRUSH};
+	///
+	/// let btn: gui::Button; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let btn = gui::Button::new(&wnd, gui::ButtonOpts::default());
+	///
+	/// btn.on().wm_draw_item(
+	///     move |p: msg::wm::DrawItem| -> AnyResult<()> {
+	///         let dis = p.drawitemstruct;
+	///         let color = if dis.itemState.has(co::ODS::SELECTED) {
+	///             co::COLOR::BTNSHADOW
+	///         } else {
+	///             co::COLOR::BTNFACE
+	///         };
+	///         dis.hDC.FillRect(dis.rcItem, &HBRUSH::from_sys_color(color))?;
+	///         Ok(())
+	///     },
+	/// );
+	/// ```
+	pub fn wm_draw_item<F>(&self, func: F)
+		where F: Fn(wm::DrawItem) -> AnyResult<()> + 'static,
+	{
+		self.0.wm_draw_item(func);
+	}
 }
@@ -52,3 +52,36 @@ impl<K: Copy + Eq, F> FuncStore<K, F> {
 		self.elems.clear();
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn find() {
+		let mut store = FuncStore::<u32, &str>::new();
+		assert!(store.is_empty());
+		store.push(1, "a");
+		store.push(2, "b");
+		store.push(1, "c");
+
+		assert_eq!(store.find(1), Some(&"c")); // last added
+		assert_eq!(store.find(3), None);
+
+		store.clear();
+		assert!(store.is_empty());
+		assert_eq!(store.find(1), None);
+	}
+
+	#[test]
+	fn find_all() {
+		let mut store = FuncStore::<u32, &str>::new();
+		store.push(1, "a");
+		store.push(2, "b");
+		store.push(1, "c");
+
+		assert_eq!(store.find_all(1).copied().collect::<Vec<_>>(), ["a", "c"]); // in order
+		assert_eq!(store.find_all(2).count(), 1);
+		assert!(store.find_all(3).next().is_none());
+	}
+}
@@ -7,6 +7,7 @@ use crate::comctl::decl::{
 use crate::gui::base::Base;
 use crate::gui::events::base_events_proxy::BaseEventsProxy;
 use crate::kernel::decl::AnyResult;
+use crate::msg::wm;
 
 /// Exposes list view control
 /// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-list-view-control-reference-notifications).
@@ -302,4 +303,30 @@ impl ListViewEvents {
 		/// [`NM_SETFOCUS`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-setfocus-list-view-)
 		/// notification.
 	}
+
+	/// [`WM_DRAWITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-drawitem)
+	/// message, sent to the parent of report list views created with the
+	/// [`LVS::OWNERDRAWFIXED`](crate::co::LVS::OWNERDRAWFIXED) style, once
+	/// for each item.
+	///
+	/// The whole item, including all subitems, must be painted with the `hDC`
+	/// of the [`DRAWITEMSTRUCT`](crate::DRAWITEMSTRUCT), within `rcItem`.
+	pub fn wm_draw_item<F>(&self, func: F)
+		where F: Fn(wm::DrawItem) -> AnyResult<()> + 'static,
+	{
+		self.0.wm_draw_item(func);
+	}
+
+	/// [`WM_MEASUREITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-measureitem)
+	/// message, sent to the parent of report list views created with the
+	/// [`LVS::OWNERDRAWFIXED`](crate::co::LVS::OWNERDRAWFIXED) style, to set
+	/// the height of the items.
+	///
+	/// Since this message is sent when the control is created, this event must
+	/// be added before the parent window is created.
+	pub fn wm_measure_item<F>(&self, func: F)
+		where F: Fn(wm::MeasureItem) -> AnyResult<()> + 'static,
+	{
+		self.0.wm_measure_item(func);
+	}
 }
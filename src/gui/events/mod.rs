@@ -13,12 +13,14 @@ mod list_view_events;
 mod month_calendar_events;
 mod radio_group_events;
 mod status_bar_events;
+mod sys_link_events;
 mod tab_events;
 mod trackbar_events;
 mod tree_view_events;
 mod up_down_events;
 mod window_events_all;
 mod window_events;
+mod wizard_page_events;
 
 pub use button_events::ButtonEvents;
 pub use combo_box_events::ComboBoxEvents;
@@ -30,12 +32,14 @@ pub use list_view_events::ListViewEvents;
 pub use month_calendar_events::MonthCalendarEvents;
 pub use radio_group_events::RadioGroupEvents;
 pub use status_bar_events::StatusBarEvents;
+pub use sys_link_events::SysLinkEvents;
 pub use tab_events::TabEvents;
 pub use trackbar_events::TrackbarEvents;
 pub use tree_view_events::TreeViewEvents;
 pub use up_down_events::UpDownEvents;
-pub use window_events_all::WindowEventsAll;
-pub use window_events::WindowEvents;
+pub use window_events_all::{CloseDecision, CloseReason, WindowEventsAll};
+pub use window_events::{CtlColors, SystemColors, WindowEvents};
+pub use wizard_page_events::WizardPageEvents;
 pub(in crate::gui) use window_events::ProcessResult;
 
 pub(in crate::gui) mod traits {
@@ -0,0 +1,27 @@
+use crate::co;
+use crate::comctl::decl::NMLINK;
+use crate::gui::base::Base;
+use crate::gui::events::base_events_proxy::BaseEventsProxy;
+use crate::kernel::decl::AnyResult;
+
+/// Exposes SysLink control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-syslink-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window,
+/// who is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by
+/// the control.
+pub struct SysLinkEvents(BaseEventsProxy);
+
+impl SysLinkEvents {
+	pub(in crate::gui) fn new(parent_base: &Base, ctrl_id: u16) -> Self {
+		Self(BaseEventsProxy::new(parent_base, ctrl_id))
+	}
+
+	pub_fn_nfy_withparm_noret! { nm_click, co::NM::CLICK, NMLINK;
+		/// [`NM_CLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-click-syslink)
+		/// notification.
+	}
+}
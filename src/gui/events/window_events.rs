@@ -1,12 +1,19 @@
 use std::cell::UnsafeCell;
+use std::path::PathBuf;
+use std::rc::Rc;
 
 use crate::co;
 use crate::gdi::decl::HFONT;
 use crate::gui::events::func_store::FuncStore;
-use crate::kernel::decl::AnyResult;
+use crate::kernel::decl::{AnyResult, SysResult, WString};
 use crate::msg::{wm, WndMsg};
-use crate::prelude::{Handle, MsgSendRecv};
-use crate::user::decl::{HICON, HMENU};
+use crate::prelude::{
+	gdi_Hbrush, gdi_Hdc, Handle, MsgSendRecv, NativeBitflag, shell_Hdrop,
+};
+use crate::user::decl::{
+	COLORREF, GetSysColor, HICON, HIGHCONTRAST, HMENU, POINT,
+	SystemParametersInfo,
+};
 
 /// The result of processing a message.
 pub(in crate::gui) enum ProcessResult {
@@ -18,6 +25,76 @@ pub(in crate::gui) enum ProcessResult {
 	HandledWithoutRet,
 }
 
+/// Text and background colors returned by the `wm_ctl_color_*_colors` events of
+/// [`GuiEvents`](crate::prelude::GuiEvents).
+///
+/// The background brush is created internally, and cached until the window is
+/// destroyed, so you don't have to manage its lifetime.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CtlColors {
+	/// Color of the text.
+	pub text: COLORREF,
+	/// Color of the text background and of the control background.
+	pub background: COLORREF,
+}
+
+/// Snapshot of the system colors, passed to the `system_appearance_changed`
+/// event of [`GuiEvents`](crate::prelude::GuiEvents).
+///
+/// Custom-drawn controls should paint themselves with these colors, so they
+/// follow the high contrast themes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SystemColors {
+	/// Whether a high contrast theme is active.
+	pub high_contrast: bool,
+	/// [`co::COLOR::WINDOW`](crate::co::COLOR::WINDOW).
+	pub window: COLORREF,
+	/// [`co::COLOR::WINDOWTEXT`](crate::co::COLOR::WINDOWTEXT).
+	pub window_text: COLORREF,
+	/// [`co::COLOR::BTNFACE`](crate::co::COLOR::BTNFACE).
+	pub btn_face: COLORREF,
+	/// [`co::COLOR::BTNTEXT`](crate::co::COLOR::BTNTEXT).
+	pub btn_text: COLORREF,
+	/// [`co::COLOR::HIGHLIGHT`](crate::co::COLOR::HIGHLIGHT).
+	pub highlight: COLORREF,
+	/// [`co::COLOR::HIGHLIGHTTEXT`](crate::co::COLOR::HIGHLIGHTTEXT).
+	pub highlight_text: COLORREF,
+	/// [`co::COLOR::GRAYTEXT`](crate::co::COLOR::GRAYTEXT).
+	pub gray_text: COLORREF,
+	/// [`co::COLOR::HOTLIGHT`](crate::co::COLOR::HOTLIGHT).
+	pub hot_light: COLORREF,
+}
+
+impl SystemColors {
+	/// Reads the current system colors with
+	/// [`GetSysColor`](crate::GetSysColor), and the high contrast state with
+	/// [`SystemParametersInfo`](crate::SystemParametersInfo).
+	#[must_use]
+	pub fn current() -> Self {
+		let mut hc = HIGHCONTRAST::default();
+		let high_contrast = unsafe {
+			SystemParametersInfo(
+				co::SPI::GETHIGHCONTRAST,
+				std::mem::size_of::<HIGHCONTRAST>() as _,
+				&mut hc,
+				co::SPIF::NoValue,
+			)
+		}.is_ok_and(|_| hc.dwFlags.has(co::HCF::HIGHCONTRASTON));
+
+		Self {
+			high_contrast,
+			window: GetSysColor(co::COLOR::WINDOW),
+			window_text: GetSysColor(co::COLOR::WINDOWTEXT),
+			btn_face: GetSysColor(co::COLOR::BTNFACE),
+			btn_text: GetSysColor(co::COLOR::BTNTEXT),
+			highlight: GetSysColor(co::COLOR::HIGHLIGHT),
+			highlight_text: GetSysColor(co::COLOR::HIGHLIGHTTEXT),
+			gray_text: GetSysColor(co::COLOR::GRAYTEXT),
+			hot_light: GetSysColor(co::COLOR::HOTLIGHT),
+		}
+	}
+}
+
 //------------------------------------------------------------------------------
 
 /// Exposes window
@@ -43,6 +120,11 @@ impl WindowEvents {
 		unsafe { &mut *self.msgs.get() }.is_empty()
 	}
 
+	/// Tells whether a user function was added to the given message.
+	pub(in crate::gui) fn has_message(&self, ident: co::WM) -> bool {
+		unsafe { &*self.msgs.get() }.find(ident).is_some()
+	}
+
 	/// Removes all stored events.
 	pub(in crate::gui) fn clear(&self) {
 		unsafe { &mut *self.msgs.get() }.clear();
@@ -167,6 +249,34 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	fn_wm_noparm_noret! { wm_clipboard_update, co::WM::CLIPBOARDUPDATE;
+		/// [`WM_CLIPBOARDUPDATE`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-clipboardupdate)
+		/// message.
+		///
+		/// This message is sent only to windows which were registered with
+		/// [`HWND::AddClipboardFormatListener`](crate::prelude::user_Hwnd::AddClipboardFormatListener).
+		/// Upon creation, windows which handle `WM_CLIPBOARDUPDATE` are
+		/// automatically registered, and the listener is removed when the
+		/// window is destroyed.
+		///
+		/// # Examples
+		///
+		/// ```rust,no_run
+		/// use winsafe::prelude::*;
+		/// use winsafe::{gui, AnyResult};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_clipboard_update(
+		///     move || -> AnyResult<()> {
+		///         println!("Clipboard contents changed.");
+		///         Ok(())
+		///     },
+		/// );
+		/// ```
+	}
+
 	fn_wm_noparm_noret! { wm_close, co::WM::CLOSE;
 		/// [`WM_CLOSE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-close)
 		/// message.
@@ -221,6 +331,15 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	fn_wm_ctlcolor_colors! { wm_ctl_color_btn_colors, co::WM::CTLCOLORBTN, wm::CtlColorBtn;
+		/// [`WM_CTLCOLORBTN`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorbtn)
+		/// message, returning the desired text and background colors.
+		///
+		/// The text and background colors are set on the `HDC`, and a solid brush
+		/// with the background color is returned. The brushes are cached, and
+		/// destroyed along with the window.
+	}
+
 	fn_wm_ctlcolor! { wm_ctl_color_dlg, co::WM::CTLCOLORDLG, wm::CtlColorDlg;
 		/// [`WM_CTLCOLORDLG`](https://learn.microsoft.com/en-us/windows/win32/dlgbox/wm-ctlcolordlg)
 		/// message.
@@ -231,11 +350,29 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	fn_wm_ctlcolor_colors! { wm_ctl_color_edit_colors, co::WM::CTLCOLOREDIT, wm::CtlColorEdit;
+		/// [`WM_CTLCOLOREDIT`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcoloredit)
+		/// message, returning the desired text and background colors.
+		///
+		/// The text and background colors are set on the `HDC`, and a solid brush
+		/// with the background color is returned. The brushes are cached, and
+		/// destroyed along with the window.
+	}
+
 	fn_wm_ctlcolor! { wm_ctl_color_list_box, co::WM::CTLCOLORLISTBOX, wm::CtlColorListBox;
 		/// [`WM_CTLCOLORLISTBOX`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorlistbox)
 		/// message.
 	}
 
+	fn_wm_ctlcolor_colors! { wm_ctl_color_list_box_colors, co::WM::CTLCOLORLISTBOX, wm::CtlColorListBox;
+		/// [`WM_CTLCOLORLISTBOX`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorlistbox)
+		/// message, returning the desired text and background colors.
+		///
+		/// The text and background colors are set on the `HDC`, and a solid brush
+		/// with the background color is returned. The brushes are cached, and
+		/// destroyed along with the window.
+	}
+
 	fn_wm_ctlcolor! { wm_ctl_color_scroll_bar, co::WM::CTLCOLORSCROLLBAR, wm::CtlColorScrollBar;
 		/// [`WM_CTLCOLORSCROLLBAR`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorscrollbar)
 		/// message.
@@ -246,6 +383,33 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	fn_wm_ctlcolor_colors! { wm_ctl_color_static_colors, co::WM::CTLCOLORSTATIC, wm::CtlColorStatic;
+		/// [`WM_CTLCOLORSTATIC`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-ctlcolorstatic)
+		/// message, returning the desired text and background colors.
+		///
+		/// The text and background colors are set on the `HDC`, and a solid brush
+		/// with the background color is returned. The brushes are cached, and
+		/// destroyed along with the window.
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, gui};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_ctl_color_static_colors(
+		///     move |_: w::msg::wm::CtlColorStatic| -> w::AnyResult<gui::events::CtlColors> {
+		///         Ok(gui::events::CtlColors {
+		///             text: w::COLORREF::new(0xff, 0xff, 0xff),
+		///             background: w::COLORREF::new(0x20, 0x20, 0x20),
+		///         })
+		///     },
+		/// );
+		/// ```
+	}
+
 	fn_wm_withparm_noret! { wm_dead_char, co::WM::DEADCHAR, wm::DeadChar;
 		/// [`WM_DEADCHAR`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-deadchar)
 		/// message.
@@ -277,11 +441,65 @@ pub trait GuiEvents {
 		/// ```
 	}
 
+	fn_wm_noparm_noret! { wm_destroy_clipboard, co::WM::DESTROYCLIPBOARD;
+		/// [`WM_DESTROYCLIPBOARD`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-destroyclipboard)
+		/// message.
+	}
+
 	fn_wm_withparm_noret! { wm_display_change, co::WM::DISPLAYCHANGE, wm::DisplayChange;
 		/// [`WM_DISPLAYCHANGE`](https://learn.microsoft.com/en-us/windows/win32/gdi/wm-displaychange)
 		/// message.
 	}
 
+	fn_wm_withparm_noret! { wm_dpi_changed, co::WM::DPICHANGED, wm::DpiChanged;
+		/// [`WM_DPICHANGED`](https://learn.microsoft.com/en-us/windows/win32/hidpi/wm-dpichanged)
+		/// message.
+		///
+		/// The window itself is automatically resized to the suggested
+		/// rectangle, and its child controls are rescaled, before this closure
+		/// runs.
+	}
+
+	/// [`WM_DRAWITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-drawitem)
+	/// message.
+	///
+	/// Sent to the parent of owner-drawn controls and to the owner of
+	/// owner-drawn menu items, whose `control_id` is zero. The item must be
+	/// painted with the `hDC` of the
+	/// [`DRAWITEMSTRUCT`](crate::DRAWITEMSTRUCT), within `rcItem`. The `TRUE`
+	/// return value required by the message is handled automatically.
+	///
+	/// To handle the items of a single control, prefer the `wm_draw_item` event
+	/// of the control itself.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui, msg, AnyResult};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.on().wm_draw_item(
+	///     move |p: msg::wm::DrawItem| -> AnyResult<()> {
+	///         let dis = p.drawitemstruct;
+	///         if dis.CtlType == co::ODT::MENU {
+	///             dis.hDC.TextOut(dis.rcItem.left, dis.rcItem.top, "Item")?;
+	///         }
+	///         Ok(())
+	///     },
+	/// );
+	/// ```
+	fn wm_draw_item<F>(&self, func: F)
+		where F: Fn(wm::DrawItem) -> AnyResult<()> + 'static,
+	{
+		self.wm(co::WM::DRAWITEM, move |p| {
+			func(wm::DrawItem::from_generic_wm(p))?;
+			Ok(Some(1)) // TRUE
+		});
+	}
+
 	fn_wm_withparm_noret! { wm_drop_files, co::WM::DROPFILES, wm::DropFiles;
 		/// [`WM_DROPFILES`](https://learn.microsoft.com/en-us/windows/win32/shell/wm-dropfiles)
 		/// message.
@@ -307,6 +525,48 @@ pub trait GuiEvents {
 		/// ```
 	}
 
+	/// [`WM_DROPFILES`](https://learn.microsoft.com/en-us/windows/win32/shell/wm-dropfiles)
+	/// message, with the dropped paths already retrieved with
+	/// [`HDROP::iter_paths`](crate::prelude::shell_Hdrop::iter_paths), and the
+	/// drop point with
+	/// [`HDROP::DragQueryPoint`](crate::prelude::shell_Hdrop::DragQueryPoint).
+	///
+	/// Upon creation, windows which handle `WM_DROPFILES` are automatically
+	/// registered with
+	/// [`HWND::DragAcceptFiles`](crate::prelude::shell_Hwnd::DragAcceptFiles).
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{gui, AnyResult, POINT};
+	/// use std::path::PathBuf;
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.on().wm_drop_files_paths(
+	///     move |paths: Vec<PathBuf>, pt: POINT| -> AnyResult<()> {
+	///         for path in paths.iter() {
+	///             println!("Dropped at {}: {}", pt, path.display());
+	///         }
+	///         Ok(())
+	///     },
+	/// );
+	/// ```
+	fn wm_drop_files_paths<F>(&self, func: F)
+		where F: Fn(Vec<PathBuf>, POINT) -> AnyResult<()> + 'static,
+	{
+		self.wm(co::WM::DROPFILES, move |p| {
+			let mut hdrop = wm::DropFiles::from_generic_wm(p).hdrop;
+			let (pt, _) = hdrop.DragQueryPoint();
+			let paths = hdrop.iter_paths()?
+				.collect::<SysResult<Vec<_>>>()?;
+			func(paths, pt)?;
+			Ok(None) // not meaningful
+		});
+	}
+
 	fn_wm_withparm_noret! { wm_enable, co::WM::ENABLE, wm::Enable;
 		/// [`WM_ENABLE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-enable)
 		/// message.
@@ -520,6 +780,32 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	fn_wm_withparm_noret! { wm_mdi_activate, co::WM::MDIACTIVATE, wm::MdiActivate;
+		/// [`WM_MDIACTIVATE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-mdiactivate)
+		/// message, sent to the MDI child windows being activated and
+		/// deactivated.
+	}
+
+	/// [`WM_MEASUREITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-measureitem)
+	/// message.
+	///
+	/// Sent to the parent of owner-drawn controls and to the owner of
+	/// owner-drawn menu items, whose `control_id` is zero. The closure must
+	/// fill `itemWidth` and `itemHeight` of the
+	/// [`MEASUREITEMSTRUCT`](crate::MEASUREITEMSTRUCT). The `TRUE` return value
+	/// required by the message is handled automatically.
+	///
+	/// To handle the items of a single control, prefer the `wm_measure_item`
+	/// event of the control itself.
+	fn wm_measure_item<F>(&self, func: F)
+		where F: Fn(wm::MeasureItem) -> AnyResult<()> + 'static,
+	{
+		self.wm(co::WM::MEASUREITEM, move |p| {
+			func(wm::MeasureItem::from_generic_wm(p))?;
+			Ok(Some(1)) // TRUE
+		});
+	}
+
 	fn_wm_withparm_noret! { wm_menu_command, co::WM::MENUCOMMAND, wm::MenuCommand;
 		/// [`WM_MENUCOMMAND`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-menucommand)
 		/// message.
@@ -555,9 +841,43 @@ pub trait GuiEvents {
 		/// message.
 	}
 
-	fn_wm_withparm_noret! { wm_moving, co::WM::MOVING, wm::Moving;
-		/// [`WM_MOVING`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-moving)
-		/// message.
+	/// [`WM_MOVING`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-moving)
+	/// message.
+	///
+	/// The closure can modify the window rectangle to constrain the position
+	/// of the window being dragged. The `TRUE` return value required by the
+	/// message is handled automatically.
+	///
+	/// # Examples
+	///
+	/// Snapping the window position to a 20-pixel grid:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{gui, msg, AnyResult};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.on().wm_moving(
+	///     move |p: msg::wm::Moving| -> AnyResult<()> {
+	///         let rc = p.window_pos;
+	///         let (cx, cy) = (rc.right - rc.left, rc.bottom - rc.top);
+	///         rc.left = (rc.left + 10) / 20 * 20;
+	///         rc.top = (rc.top + 10) / 20 * 20;
+	///         rc.right = rc.left + cx;
+	///         rc.bottom = rc.top + cy;
+	///         Ok(())
+	///     },
+	/// );
+	/// ```
+	fn wm_moving<F>(&self, func: F)
+		where F: Fn(wm::Moving) -> AnyResult<()> + 'static,
+	{
+		self.wm(co::WM::MOVING, move |p| {
+			func(wm::Moving::from_generic_wm(p))?;
+			Ok(Some(1)) // TRUE
+		});
 	}
 
 	fn_wm_withparm_coret! { wm_nc_calc_size, co::WM::NCCALCSIZE, wm::NcCalcSize, co::WVR;
@@ -642,6 +962,37 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	fn_wm_withparm_boolret! { wm_query_end_session, co::WM::QUERYENDSESSION, wm::QueryEndSession;
+		/// [`WM_QUERYENDSESSION`](https://learn.microsoft.com/en-us/windows/win32/shutdown/wm-queryendsession)
+		/// message.
+		///
+		/// Return true to allow the session to end. The state should be saved
+		/// when [`wm_end_session`](crate::prelude::GuiEvents::wm_end_session)
+		/// is received.
+		///
+		/// # Examples
+		///
+		/// ```rust,no_run
+		/// use winsafe::prelude::*;
+		/// use winsafe::{gui, msg, AnyResult};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		/// let has_unsaved_changes = true;
+		///
+		/// let wnd2 = wnd.clone(); // to pass into the closure
+		///
+		/// wnd.on().wm_query_end_session(
+		///     move |_: msg::wm::QueryEndSession| -> AnyResult<bool> {
+		///         if has_unsaved_changes {
+		///             wnd2.hwnd().ShutdownBlockReasonCreate("Saving document...")?;
+		///         }
+		///         Ok(true)
+		///     },
+		/// );
+		/// ```
+	}
+
 	fn_wm_noparm_boolret! { wm_query_open, co::WM::QUERYOPEN;
 		/// [`WM_QUERYOPEN`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-queryopen)
 		/// message.
@@ -661,6 +1012,47 @@ pub trait GuiEvents {
 		/// [`WM_RBUTTONUP`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-rbuttonup)
 	}
 
+	fn_wm_noparm_noret! { wm_render_all_formats, co::WM::RENDERALLFORMATS;
+		/// [`WM_RENDERALLFORMATS`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-renderallformats)
+		/// message.
+		///
+		/// Sent to the clipboard owner before it is destroyed, if it performed
+		/// delayed rendering of one or more formats. The handler must open the
+		/// clipboard, check whether it's still the owner, and render all the
+		/// delayed formats with
+		/// [`SetClipboardData`](crate::SetClipboardData).
+	}
+
+	fn_wm_withparm_noret! { wm_render_format, co::WM::RENDERFORMAT, wm::RenderFormat;
+		/// [`WM_RENDERFORMAT`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-renderformat)
+		/// message.
+		///
+		/// Sent to the clipboard owner when a format which was set with
+		/// [delayed rendering](crate::SetClipboardData) is requested. The
+		/// handler must render the data with
+		/// [`SetClipboardData`](crate::SetClipboardData), without opening the
+		/// clipboard.
+		///
+		/// # Examples
+		///
+		/// ```rust,no_run
+		/// use winsafe::prelude::*;
+		/// use winsafe::{co, gui, msg, AnyResult};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_render_format(
+		///     move |p: msg::wm::RenderFormat| -> AnyResult<()> {
+		///         if p.clipboard_format == co::CF::UNICODETEXT {
+		///             // allocate the data and call SetClipboardData()
+		///         }
+		///         Ok(())
+		///     },
+		/// );
+		/// ```
+	}
+
 	fn_wm_withparm_boolret! { wm_set_cursor, co::WM::SETCURSOR, wm::SetCursor;
 		/// [`WM_SETCURSOR`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-setcursor)
 		/// message.
@@ -705,6 +1097,11 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	fn_wm_withparm_noret! { wm_setting_change, co::WM::SETTINGCHANGE, wm::SettingChange;
+		/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+		/// message.
+	}
+
 	fn_wm_withparm_noret! { wm_show_window, co::WM::SHOWWINDOW, wm::ShowWindow;
 		/// [`WM_SHOWWINDOW`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-showwindow)
 		/// message.
@@ -735,9 +1132,49 @@ pub trait GuiEvents {
 		/// ```
 	}
 
-	fn_wm_withparm_noret! { wm_sizing, co::WM::SIZING, wm::Sizing;
-		/// [`WM_SIZING`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-sizing)
-		/// message.
+	/// [`WM_SIZING`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-sizing)
+	/// message.
+	///
+	/// The closure can modify the window rectangle to constrain the size of
+	/// the window being resized, considering the edge being dragged. The
+	/// `TRUE` return value required by the message is handled automatically.
+	///
+	/// # Examples
+	///
+	/// Locking the window to a 16:9 aspect ratio:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui, msg, AnyResult};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.on().wm_sizing(
+	///     move |p: msg::wm::Sizing| -> AnyResult<()> {
+	///         let rc = p.coords;
+	///         match p.window_edge {
+	///             co::WMSZ::LEFT | co::WMSZ::RIGHT => { // width rules
+	///                 rc.bottom = rc.top + (rc.right - rc.left) * 9 / 16;
+	///             },
+	///             co::WMSZ::TOPLEFT | co::WMSZ::TOPRIGHT => {
+	///                 rc.top = rc.bottom - (rc.right - rc.left) * 9 / 16;
+	///             },
+	///             _ => { // height rules
+	///                 rc.right = rc.left + (rc.bottom - rc.top) * 16 / 9;
+	///             },
+	///         }
+	///         Ok(())
+	///     },
+	/// );
+	/// ```
+	fn wm_sizing<F>(&self, func: F)
+		where F: Fn(wm::Sizing) -> AnyResult<()> + 'static,
+	{
+		self.wm(co::WM::SIZING, move |p| {
+			func(wm::Sizing::from_generic_wm(p))?;
+			Ok(Some(1)) // TRUE
+		});
 	}
 
 	fn_wm_withparm_noret! { wm_style_changed, co::WM::STYLECHANGED, wm::StyleChanged;
@@ -760,6 +1197,77 @@ pub trait GuiEvents {
 		/// message.
 	}
 
+	/// Handles
+	/// [`WM_SYSCOLORCHANGE`](https://learn.microsoft.com/en-us/windows/win32/gdi/wm-syscolorchange),
+	/// [`WM_THEMECHANGED`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-themechanged)
+	/// and
+	/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+	/// messages at once, calling the closure with the new
+	/// [`SystemColors`](crate::gui::events::SystemColors) whenever the system
+	/// colors, the visual theme or the high contrast mode change.
+	///
+	/// `WM_SETTINGCHANGE` is only handled when the high contrast mode or the
+	/// color set changes.
+	///
+	/// **Note:** This event takes over the three messages, so you shouldn't
+	/// also use [`wm_sys_color_change`](crate::prelude::GuiEvents::wm_sys_color_change),
+	/// [`wm_theme_changed`](crate::prelude::GuiEvents::wm_theme_changed) or
+	/// [`wm_setting_change`](crate::prelude::GuiEvents::wm_setting_change).
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::gui;
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.on().system_appearance_changed({
+	///     let wnd = wnd.clone();
+	///     move |colors: gui::events::SystemColors| -> winsafe::AnyResult<()> {
+	///         if colors.high_contrast {
+	///             println!("High contrast is on.");
+	///         }
+	///         wnd.hwnd().InvalidateRect(None, true)?;
+	///         Ok(())
+	///     }
+	/// });
+	/// ```
+	fn system_appearance_changed<F>(&self, func: F)
+		where F: Fn(SystemColors) -> AnyResult<()> + 'static,
+	{
+		let func = Rc::new(func);
+
+		let func2 = func.clone();
+		self.wm(co::WM::SYSCOLORCHANGE, move |_| {
+			func2(SystemColors::current())?;
+			Ok(None) // not meaningful
+		});
+
+		let func2 = func.clone();
+		self.wm(co::WM::THEMECHANGED, move |_| {
+			func2(SystemColors::current())?;
+			Ok(None) // not meaningful
+		});
+
+		self.wm(co::WM::SETTINGCHANGE, move |p| {
+			let p = wm::SettingChange::from_generic_wm(p);
+			if p.parameter == co::SPI::SETHIGHCONTRAST
+				|| (!p.section.is_null()
+					&& WString::from_wchars_nullt(p.section).to_string() == "ImmersiveColorSet")
+			{
+				func(SystemColors::current())?;
+			}
+			Ok(None) // not meaningful
+		});
+	}
+
+	fn_wm_noparm_noret! { wm_sys_color_change, co::WM::SYSCOLORCHANGE;
+		/// [`WM_SYSCOLORCHANGE`](https://learn.microsoft.com/en-us/windows/win32/gdi/wm-syscolorchange)
+		/// message.
+	}
+
 	fn_wm_withparm_noret! { wm_sys_command, co::WM::SYSCOMMAND, wm::SysCommand;
 		/// [`WM_SYSCOMMAND`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-syscommand)
 		/// message.
@@ -8,6 +8,39 @@ use crate::kernel::decl::AnyResult;
 use crate::msg::{wm, WndMsg};
 use crate::prelude::{GuiEvents, MsgSendRecv};
 
+/// Why the window is about to be closed, passed to the
+/// [`closing`](crate::prelude::GuiEventsAll::closing) event.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CloseReason {
+	/// The user clicked the X button, pressed Alt+F4, or chose Close from the
+	/// system menu.
+	User,
+	/// The window was closed with
+	/// [`WindowMain::close`](crate::gui::WindowMain::close).
+	Programmatic,
+	/// The user session is ending, due to a logoff or a system shutdown
+	/// (`WM_QUERYENDSESSION`).
+	SessionEnd(co::ENDSESSION),
+}
+
+/// What to do with a close request, returned by the
+/// [`closing`](crate::prelude::GuiEventsAll::closing) event.
+#[derive(Clone, PartialEq, Eq)]
+pub enum CloseDecision {
+	/// Proceed: the window is destroyed, or the session is allowed to end.
+	Close,
+	/// Keep the window open. If the session is ending, the system is asked to
+	/// not end it, although it may do so anyway.
+	Cancel,
+	/// Keep the window open. If the session is ending, the given reason is
+	/// shown to the user with
+	/// [`ShutdownBlockReasonCreate`](crate::prelude::user_Hwnd::ShutdownBlockReasonCreate),
+	/// until the session end is confirmed or cancelled.
+	BlockShutdown(String),
+}
+
+//------------------------------------------------------------------------------
+
 /// Exposes window
 /// [messages](https://learn.microsoft.com/en-us/windows/win32/winmsg/about-messages-and-message-queues),
 /// plus timer and native control notifications.
@@ -34,6 +67,15 @@ pub struct WindowEventsAll {
 			Box<dyn Fn(wm::Notify) -> AnyResult<Option<isize>>>, // return value may be meaningful
 		>,
 	>,
+	itms: UnsafeCell<
+		FuncStore< // WM_DRAWITEM and WM_MEASUREITEM messages
+			(co::WM, u16), // message, control ID
+			Box<dyn Fn(WndMsg) -> AnyResult<()>>, // return value is always TRUE
+		>,
+	>,
+	drop_files_fn: UnsafeCell<Option<Box<dyn Fn(Vec<String>) -> AnyResult<()>>>>, // OLE drag and drop
+	drop_text_fn: UnsafeCell<Option<Box<dyn Fn(String) -> AnyResult<()>>>>,
+	closing_fn: UnsafeCell<Option<Box<dyn Fn(CloseReason) -> AnyResult<CloseDecision>>>>,
 }
 
 impl WindowEventsAll {
@@ -43,6 +85,10 @@ impl WindowEventsAll {
 			tmrs: UnsafeCell::new(FuncStore::new()),
 			cmds: UnsafeCell::new(FuncStore::new()),
 			nfys: UnsafeCell::new(FuncStore::new()),
+			itms: UnsafeCell::new(FuncStore::new()),
+			drop_files_fn: UnsafeCell::new(None),
+			drop_text_fn: UnsafeCell::new(None),
+			closing_fn: UnsafeCell::new(None),
 		}
 	}
 
@@ -52,10 +98,62 @@ impl WindowEventsAll {
 			{ &mut *self.tmrs.get() }.clear();
 			{ &mut *self.cmds.get() }.clear();
 			{ &mut *self.nfys.get() }.clear();
+			{ &mut *self.itms.get() }.clear();
+			*self.drop_files_fn.get() = None;
+			*self.drop_text_fn.get() = None;
+			*self.closing_fn.get() = None;
 		}
 		self.window_events.clear();
 	}
 
+	/// Tells whether a user function was added to the given message.
+	pub(in crate::gui) fn has_message(&self, ident: co::WM) -> bool {
+		self.window_events.has_message(ident)
+	}
+
+	/// Tells whether any of the OLE drag and drop events was added, so the
+	/// window must be registered as a drop target.
+	pub(in crate::gui) fn has_drop_events(&self) -> bool {
+		self.has_drop_files() || self.has_drop_text()
+	}
+
+	pub(in crate::gui) fn has_drop_files(&self) -> bool {
+		unsafe { &*self.drop_files_fn.get() }.is_some()
+	}
+
+	pub(in crate::gui) fn has_drop_text(&self) -> bool {
+		unsafe { &*self.drop_text_fn.get() }.is_some()
+	}
+
+	/// Runs the user function for dropped files, if any.
+	pub(in crate::gui) fn process_drop_files(&self,
+		files: Vec<String>) -> AnyResult<()>
+	{
+		match unsafe { &*self.drop_files_fn.get() } {
+			Some(func) => func(files),
+			None => Ok(()),
+		}
+	}
+
+	/// Runs the user function for dropped text, if any.
+	pub(in crate::gui) fn process_drop_text(&self, text: String) -> AnyResult<()> {
+		match unsafe { &*self.drop_text_fn.get() } {
+			Some(func) => func(text),
+			None => Ok(()),
+		}
+	}
+
+	/// Runs the user function for the close pipeline, if any; by default, the
+	/// window is closed.
+	pub(in crate::gui) fn process_closing(&self,
+		reason: CloseReason) -> AnyResult<CloseDecision>
+	{
+		match unsafe { &*self.closing_fn.get() } {
+			Some(func) => func(reason),
+			None => Ok(CloseDecision::Close),
+		}
+	}
+
 	/// Searches for the last added user function for the given message, and
 	/// runs if it exists, returning the result.
 	pub(in crate::gui) fn process_one_message(&self,
@@ -99,6 +197,17 @@ impl WindowEventsAll {
 					None => ProcessResult::NotHandled, // no stored WM_TIMER message
 				}
 			}
+			co::WM::DRAWITEM | co::WM::MEASUREITEM => {
+				let key = (wm_any.msg_id, wm_any.wparam as u16);
+				let itms = unsafe { &mut *self.itms.get() };
+				match itms.find(key) {
+					Some(func) => { // we have a stored function to handle this control item
+						func(wm_any)?; // execute user function
+						ProcessResult::HandledWithRet(1) // TRUE
+					},
+					None => self.window_events.process_one_message(wm_any)?, // maybe a generic handler
+				}
+			},
 			_ => self.window_events.process_one_message(wm_any)?,
 		})
 	}
@@ -132,6 +241,14 @@ impl WindowEventsAll {
 					func()?; // execute stored function
 				}
 			},
+			co::WM::DRAWITEM | co::WM::MEASUREITEM => {
+				let key = (wm_any.msg_id, wm_any.wparam as u16);
+				let itms = unsafe { &mut *self.itms.get() };
+				for func in itms.find_all(key) {
+					func(wm_any)?; // execute stored function
+				}
+				self.window_events.process_all_messages(wm_any)?;
+			},
 			_ => self.window_events.process_all_messages(wm_any)?,
 		})
 	}
@@ -146,6 +263,24 @@ impl GuiEvents for WindowEventsAll {
 }
 
 impl GuiEventsAll for WindowEventsAll {
+	fn closing<F>(&self, func: F)
+		where F: Fn(CloseReason) -> AnyResult<CloseDecision> + 'static,
+	{
+		*unsafe { &mut *self.closing_fn.get() } = Some(Box::new(func));
+	}
+
+	fn drop_files<F>(&self, func: F)
+		where F: Fn(Vec<String>) -> AnyResult<()> + 'static,
+	{
+		*unsafe { &mut *self.drop_files_fn.get() } = Some(Box::new(func));
+	}
+
+	fn drop_text<F>(&self, func: F)
+		where F: Fn(String) -> AnyResult<()> + 'static,
+	{
+		*unsafe { &mut *self.drop_text_fn.get() } = Some(Box::new(func));
+	}
+
 	fn wm_timer<F>(&self, timer_id: u32, func: F)
 		where F: Fn() -> AnyResult<()> + 'static,
 	{
@@ -165,6 +300,20 @@ impl GuiEventsAll for WindowEventsAll {
 		let code: co::NM = code.into();
 		unsafe { &mut *self.nfys.get() }.push((id_from, code), Box::new(func));
 	}
+
+	fn wm_draw_item_ctrl<F>(&self, ctrl_id: u16, func: F)
+		where F: Fn(wm::DrawItem) -> AnyResult<()> + 'static,
+	{
+		unsafe { &mut *self.itms.get() }.push((co::WM::DRAWITEM, ctrl_id),
+			Box::new(move |p| func(wm::DrawItem::from_generic_wm(p))));
+	}
+
+	fn wm_measure_item_ctrl<F>(&self, ctrl_id: u16, func: F)
+		where F: Fn(wm::MeasureItem) -> AnyResult<()> + 'static,
+	{
+		unsafe { &mut *self.itms.get() }.push((co::WM::MEASUREITEM, ctrl_id),
+			Box::new(move |p| func(wm::MeasureItem::from_generic_wm(p))));
+	}
 }
 
 //------------------------------------------------------------------------------
@@ -172,6 +321,90 @@ impl GuiEventsAll for WindowEventsAll {
 /// Exposes methods to handle the basic window messages, plus timer and native
 /// control notifications.
 pub trait GuiEventsAll: GuiEvents {
+	/// Close pipeline of a [`WindowMain`](crate::gui::WindowMain), called
+	/// when the window is about to be closed, either by the user, by
+	/// [`WindowMain::close`](crate::gui::WindowMain::close), or because the
+	/// user session is ending. Only the last closure is kept.
+	///
+	/// This is the right place to save the application state. The returned
+	/// [`CloseDecision`](crate::gui::events::CloseDecision) is translated into
+	/// the correct results of `WM_CLOSE`, `WM_QUERYENDSESSION` and
+	/// `WM_ENDSESSION` messages, so these don't need to be handled.
+	///
+	/// Other windows don't run this closure. Closures added to
+	/// [`wm_close`](crate::prelude::GuiEvents::wm_close),
+	/// [`wm_query_end_session`](crate::prelude::GuiEvents::wm_query_end_session)
+	/// or [`wm_end_session`](crate::prelude::GuiEvents::wm_end_session) are
+	/// still run after the pipeline, unless it destroyed the window, but the
+	/// results of `WM_CLOSE` and `WM_QUERYENDSESSION` come from the pipeline.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{gui, AnyResult};
+	/// use winsafe::gui::events::{CloseDecision, CloseReason};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.on().closing(
+	///     move |reason: CloseReason| -> AnyResult<CloseDecision> {
+	///         match reason {
+	///             CloseReason::SessionEnd(_) => {
+	///                 // save the document somewhere safe...
+	///                 Ok(CloseDecision::Close)
+	///             },
+	///             _ => Ok(CloseDecision::Close),
+	///         }
+	///     },
+	/// );
+	/// ```
+	fn closing<F>(&self, func: F)
+		where F: Fn(CloseReason) -> AnyResult<CloseDecision> + 'static;
+
+	/// Files dropped over the window, through OLE drag and drop, with the
+	/// paths of the files. Only the last closure is kept.
+	///
+	/// When this event is added, the window is registered as a drop target
+	/// with [`RegisterDragDrop`](crate::prelude::ole_Hwnd::RegisterDragDrop)
+	/// upon creation, after calling [`OleInitialize`](crate::OleInitialize).
+	/// Unlike [`wm_drop_files`](crate::prelude::GuiEvents::wm_drop_files), the
+	/// `WS_EX_ACCEPTFILES` style is not needed.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{gui, AnyResult};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.on().drop_files(
+	///     move |files: Vec<String>| -> AnyResult<()> {
+	///         for file in files.iter() {
+	///             println!("Dropped: {}", file);
+	///         }
+	///         Ok(())
+	///     },
+	/// );
+	/// ```
+	fn drop_files<F>(&self, func: F)
+		where F: Fn(Vec<String>) -> AnyResult<()> + 'static;
+
+	/// Text dropped over the window, through OLE drag and drop. Only the last
+	/// closure is kept.
+	///
+	/// If [`drop_files`](crate::prelude::GuiEventsAll::drop_files) is also
+	/// added, it has precedence when the dropped data has both formats.
+	///
+	/// When this event is added, the window is registered as a drop target
+	/// with [`RegisterDragDrop`](crate::prelude::ole_Hwnd::RegisterDragDrop)
+	/// upon creation, after calling [`OleInitialize`](crate::OleInitialize).
+	fn drop_text<F>(&self, func: F)
+		where F: Fn(String) -> AnyResult<()> + 'static;
+
 	/// [`WM_TIMER`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-timer)
 	/// message, narrowed to a specific timer ID.
 	fn wm_timer<F>(&self, timer_id: u32, func: F)
@@ -230,6 +463,24 @@ pub trait GuiEventsAll: GuiEvents {
 		});
 	}
 
+	/// [`WM_DRAWITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-drawitem)
+	/// message, narrowed to a specific control ID. The `TRUE` return value
+	/// required by the message is handled automatically.
+	///
+	/// If no closure was added to the control ID, the message falls back to
+	/// [`wm_draw_item`](crate::prelude::GuiEvents::wm_draw_item).
+	fn wm_draw_item_ctrl<F>(&self, ctrl_id: u16, func: F)
+		where F: Fn(wm::DrawItem) -> AnyResult<()> + 'static;
+
+	/// [`WM_MEASUREITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-measureitem)
+	/// message, narrowed to a specific control ID. The `TRUE` return value
+	/// required by the message is handled automatically.
+	///
+	/// If no closure was added to the control ID, the message falls back to
+	/// [`wm_measure_item`](crate::prelude::GuiEvents::wm_measure_item).
+	fn wm_measure_item_ctrl<F>(&self, ctrl_id: u16, func: F)
+		where F: Fn(wm::MeasureItem) -> AnyResult<()> + 'static;
+
 	/// [`WM_NOTIFY`](crate::msg::wm::Notify) message, for specific ID and
 	/// notification code.
 	///
@@ -0,0 +1,185 @@
+use std::cell::Cell;
+use std::ptr::NonNull;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::gui::wizard_page::WizardNav;
+use crate::kernel::decl::AnyResult;
+use crate::prelude::{GuiEventsAll, user_Hwnd};
+
+/// Exposes property sheet page
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-property-sheets-reference-notifications),
+/// sent by the property sheet to the
+/// [`WizardPage`](crate::gui::WizardPage).
+///
+/// The return values of the closures are set as the dialog message results, as
+/// required by the property sheet.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// page.
+pub struct WizardPageEvents {
+	page_ptr: Cell<NonNull<Base>>,
+}
+
+impl WizardPageEvents {
+	pub(in crate::gui) fn new() -> Self {
+		Self { page_ptr: Cell::new(NonNull::dangling()) }
+	}
+
+	/// Sets the page, once its object is pinned.
+	pub(in crate::gui) fn set_page(&self, page_base: &Base) {
+		self.page_ptr.set(NonNull::from(page_base));
+	}
+
+	/// Adds a `WM_NOTIFY` handler to the page, whose returned value is set as
+	/// the dialog message result.
+	fn psn<F>(&self, code: co::PSN, func: F)
+		where F: Fn() -> AnyResult<isize> + 'static,
+	{
+		let page_ptr = self.page_ptr.get();
+		let page_ref = unsafe { page_ptr.as_ref() };
+		page_ref.on().wm_notify(0, code, move |_| { // property sheet has no ID
+			let ret = func()?;
+			let page_ref = unsafe { page_ptr.as_ref() };
+			page_ref.hwnd().SetWindowLongPtr(co::GWLP::DWLP_MSGRESULT, ret);
+			Ok(Some(1)) // TRUE: result was set
+		});
+	}
+
+	/// [`PSN_APPLY`](https://learn.microsoft.com/en-us/windows/win32/controls/psn-apply)
+	/// notification.
+	///
+	/// Sent when the user clicks OK, Close or Apply. Return
+	/// `co::PSNRET::INVALID` to keep the sheet open and activate this page.
+	pub fn psn_apply<F>(&self, func: F)
+		where F: Fn() -> AnyResult<co::PSNRET> + 'static,
+	{
+		self.psn(co::PSN::APPLY, move || Ok(func()?.0));
+	}
+
+	/// [`PSN_HELP`](https://learn.microsoft.com/en-us/windows/win32/controls/psn-help)
+	/// notification.
+	pub fn psn_help<F>(&self, func: F)
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		self.psn(co::PSN::HELP, move || { func()?; Ok(0) });
+	}
+
+	/// [`PSN_KILLACTIVE`](https://learn.microsoft.com/en-us/windows/win32/controls/psn-killactive)
+	/// notification.
+	///
+	/// Sent when the page is about to lose the activation, which is the place to
+	/// validate the user input. Return `false` to keep the page active.
+	pub fn psn_kill_active<F>(&self, func: F)
+		where F: Fn() -> AnyResult<bool> + 'static,
+	{
+		self.psn(co::PSN::KILLACTIVE, move || Ok(!func()? as _));
+	}
+
+	/// [`PSN_QUERYCANCEL`](https://learn.microsoft.com/en-us/windows/win32/controls/psn-querycancel)
+	/// notification.
+	///
+	/// Return `false` to prevent the sheet from being cancelled.
+	pub fn psn_query_cancel<F>(&self, func: F)
+		where F: Fn() -> AnyResult<bool> + 'static,
+	{
+		self.psn(co::PSN::QUERYCANCEL, move || Ok(!func()? as _));
+	}
+
+	/// [`PSN_RESET`](https://learn.microsoft.com/en-us/windows/win32/controls/psn-reset)
+	/// notification.
+	///
+	/// Sent when the sheet is being cancelled.
+	pub fn psn_reset<F>(&self, func: F)
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		self.psn(co::PSN::RESET, move || { func()?; Ok(0) });
+	}
+
+	/// [`PSN_SETACTIVE`](https://learn.microsoft.com/en-us/windows/win32/controls/psn-setactive)
+	/// notification.
+	///
+	/// Sent when the page is about to be activated, which is the place to set
+	/// the wizard buttons with
+	/// [`WizardPage::set_wizard_buttons`](crate::gui::WizardPage::set_wizard_buttons).
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui, AnyResult};
+	///
+	/// let page: gui::WizardPage; // initialized somewhere
+	/// # let page = gui::WizardPage::new(gui::WizardPageOpts::default());
+	///
+	/// page.on_page().psn_set_active({
+	///     let page = page.clone();
+	///     move || -> AnyResult<()> {
+	///         page.set_wizard_buttons(co::PSWIZB::BACK | co::PSWIZB::NEXT)?;
+	///         Ok(())
+	///     }
+	/// });
+	/// ```
+	pub fn psn_set_active<F>(&self, func: F)
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		self.psn(co::PSN::SETACTIVE, move || { func()?; Ok(0) });
+	}
+
+	/// [`PSN_WIZBACK`](https://learn.microsoft.com/en-us/windows/win32/controls/psn-wizback)
+	/// notification.
+	///
+	/// Sent when the user clicks the Back button of a wizard.
+	pub fn psn_wiz_back<F>(&self, func: F)
+		where F: Fn() -> AnyResult<WizardNav> + 'static,
+	{
+		self.psn(co::PSN::WIZBACK, move || Ok(func()?.into()));
+	}
+
+	/// [`PSN_WIZFINISH`](https://learn.microsoft.com/en-us/windows/win32/controls/psn-wizfinish)
+	/// notification.
+	///
+	/// Sent when the user clicks the Finish button of a wizard. Return `false`
+	/// to keep the wizard open.
+	pub fn psn_wiz_finish<F>(&self, func: F)
+		where F: Fn() -> AnyResult<bool> + 'static,
+	{
+		self.psn(co::PSN::WIZFINISH, move || Ok(!func()? as _));
+	}
+
+	/// [`PSN_WIZNEXT`](https://learn.microsoft.com/en-us/windows/win32/controls/psn-wiznext)
+	/// notification.
+	///
+	/// Sent when the user clicks the Next button of a wizard.
+	///
+	/// # Examples
+	///
+	/// Skipping a page when a check box is not checked:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{gui, AnyResult};
+	///
+	/// const DLG_PAGE_SUMMARY: u16 = 103;
+	///
+	/// let page: gui::WizardPage; // initialized somewhere
+	/// let chk_advanced: gui::CheckBox;
+	/// # let page = gui::WizardPage::new(gui::WizardPageOpts::default());
+	/// # let chk_advanced = gui::CheckBox::new_dlg(&page, 1001, (gui::Horz::None, gui::Vert::None));
+	///
+	/// page.on_page().psn_wiz_next(
+	///     move || -> AnyResult<gui::WizardNav> {
+	///         Ok(if chk_advanced.is_checked() {
+	///             gui::WizardNav::Default
+	///         } else {
+	///             gui::WizardNav::GoTo(DLG_PAGE_SUMMARY)
+	///         })
+	///     },
+	/// );
+	/// ```
+	pub fn psn_wiz_next<F>(&self, func: F)
+		where F: Fn() -> AnyResult<WizardNav> + 'static,
+	{
+		self.psn(co::PSN::WIZNEXT, move || Ok(func()?.into()));
+	}
+}
@@ -0,0 +1,177 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::co;
+use crate::gdi::decl::{HFONT, LOGFONT, NONCLIENTMETRICS};
+use crate::gdi::guard::DeleteObjectGuard;
+use crate::kernel::decl::{MulDiv, SysResult};
+use crate::prelude::gdi_Hfont;
+use crate::user::decl::{GetDpiForSystem, SystemParametersInfo};
+
+thread_local! {
+	/// Fonts which were set to controls, kept alive until the UI thread ends.
+	static FONTS_IN_USE: RefCell<Vec<Font>> = RefCell::new(Vec::new());
+}
+
+/// A font which can be set to the controls with
+/// [`GuiChild::set_font`](crate::prelude::GuiChild::set_font), created with a
+/// [`FontBuilder`](crate::gui::FontBuilder).
+///
+/// The font is reference-counted, so cloning it is cheap. The underlying
+/// `HFONT` is deleted when the last clone is dropped – but a font set to a
+/// control is kept alive until the UI thread ends.
+#[derive(Clone)]
+pub struct Font(Rc<DeleteObjectGuard<HFONT>>);
+
+impl Font {
+	/// Returns the underlying font handle.
+	#[must_use]
+	pub fn hfont(&self) -> &HFONT {
+		&self.0
+	}
+
+	/// Keeps a clone of the font until the UI thread ends, because it's being
+	/// used by a control.
+	pub(in crate::gui) fn keep_alive(&self) {
+		FONTS_IN_USE.with(|fonts| {
+			let mut fonts = fonts.borrow_mut();
+			if !fonts.iter().any(|f| Rc::ptr_eq(&f.0, &self.0)) {
+				fonts.push(self.clone());
+			}
+		});
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Builds a [`Font`](crate::gui::Font) with
+/// [`CreateFontIndirect`](crate::prelude::gdi_Hfont::CreateFontIndirect).
+///
+/// The builder starts with the same font used by the controls, which is the
+/// system UI font.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let lbl: gui::Label; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// # let lbl = gui::Label::new(&wnd, gui::LabelOpts::default());
+///
+/// let font = gui::FontBuilder::new()
+///     .face("Consolas")
+///     .size(12)
+///     .bold(true)
+///     .build()?;
+///
+/// lbl.set_font(&font);
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub struct FontBuilder {
+	lf: LOGFONT,
+	size: Option<u32>,
+	dpi: Option<u32>,
+}
+
+impl Default for FontBuilder {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl FontBuilder {
+	/// Creates a new builder, starting with the system UI font.
+	#[must_use]
+	pub fn new() -> Self {
+		let mut ncm = NONCLIENTMETRICS::default();
+		let lf = match unsafe {
+			SystemParametersInfo(
+				co::SPI::GETNONCLIENTMETRICS,
+				std::mem::size_of::<NONCLIENTMETRICS>() as _,
+				&mut ncm,
+				co::SPIF::NoValue,
+			)
+		} {
+			Ok(_) => ncm.lfMenuFont, // same as the global UI font
+			Err(_) => {
+				let mut lf = LOGFONT::default();
+				lf.set_lfFaceName("Segoe UI");
+				lf
+			},
+		};
+
+		Self { lf, size: None, dpi: None }
+	}
+
+	/// Sets the typeface name.
+	#[must_use]
+	pub fn face(mut self, face: &str) -> Self {
+		self.lf.set_lfFaceName(face);
+		self
+	}
+
+	/// Sets the size, in points.
+	#[must_use]
+	pub fn size(mut self, points: u32) -> Self {
+		self.size = Some(points);
+		self
+	}
+
+	/// Sets the DPI used to convert the size into pixels. If not set, the
+	/// system DPI is used.
+	#[must_use]
+	pub fn dpi(mut self, dpi: u32) -> Self {
+		self.dpi = Some(dpi);
+		self
+	}
+
+	/// Sets the weight.
+	#[must_use]
+	pub fn weight(mut self, weight: co::FW) -> Self {
+		self.lf.lfWeight = weight;
+		self
+	}
+
+	/// Sets the weight to bold or normal.
+	#[must_use]
+	pub fn bold(self, bold: bool) -> Self {
+		self.weight(if bold { co::FW::BOLD } else { co::FW::NORMAL })
+	}
+
+	/// Sets the italic attribute.
+	#[must_use]
+	pub fn italic(mut self, italic: bool) -> Self {
+		self.lf.lfItalic = italic as _;
+		self
+	}
+
+	/// Sets the underline attribute.
+	#[must_use]
+	pub fn underline(mut self, underline: bool) -> Self {
+		self.lf.lfUnderline = underline as _;
+		self
+	}
+
+	/// Sets the strikeout attribute.
+	#[must_use]
+	pub fn strikeout(mut self, strikeout: bool) -> Self {
+		self.lf.lfStrikeOut = strikeout as _;
+		self
+	}
+
+	/// Creates the font.
+	pub fn build(mut self) -> SysResult<Font> {
+		let sys_dpi = GetDpiForSystem() as i32;
+		let dpi = self.dpi.map_or(sys_dpi, |dpi| dpi as _);
+
+		self.lf.lfHeight = match self.size {
+			Some(points) => -MulDiv(points as _, dpi, 72), // negative: character height
+			None => MulDiv(self.lf.lfHeight, dpi, sys_dpi), // system font is in system DPI
+		};
+		self.lf.lfWidth = 0;
+
+		Ok(Font(Rc::new(HFONT::CreateFontIndirect(&self.lf)?)))
+	}
+}
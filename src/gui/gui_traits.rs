@@ -1,12 +1,18 @@
 use std::any::Any;
+use std::future::Future;
 
 use crate::co;
 use crate::gui::events::{WindowEvents, WindowEventsAll};
+use crate::gui::font::Font;
+use crate::gui::ui_task::spawn_ui_future;
 use crate::gui::window_control::WindowControl;
-use crate::kernel::decl::AnyResult;
+use crate::gui::window_data::{
+	get_window_data, set_window_data, take_window_data,
+};
+use crate::kernel::decl::{AnyResult, SysResult};
 use crate::msg::wm;
 use crate::prelude::{Handle, user_Hwnd};
-use crate::user::decl::{HWND, HwndFocus};
+use crate::user::decl::{HWND, HwndFocus, HwndPlace, POINT, SIZE};
 use crate::user::privs::WC_DIALOG;
 
 /// Any window. Exposes the underlying window handle.
@@ -50,6 +56,64 @@ pub trait GuiWindow {
 	/// ```
 	#[must_use]
 	fn as_any(&self) -> &dyn Any;
+
+	/// Associates a value with the window, keyed by its type, returning the
+	/// value of the same type which was previously stored, if any. This is a
+	/// typed alternative to
+	/// [`GWLP::USERDATA`](crate::co::GWLP::USERDATA).
+	///
+	/// All values are dropped when the window receives
+	/// [`WM_NCDESTROY`](crate::msg::wm::NcDestroy).
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet, or if called from a thread
+	/// other than the window's UI thread.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use std::rc::Rc;
+	/// use winsafe::prelude::*;
+	/// use winsafe::gui;
+	///
+	/// struct Document {
+	///     path: String,
+	/// }
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.set_data(Rc::new(Document { path: "C:\\Temp\\foo.txt".to_owned() }));
+	///
+	/// if let Some(doc) = wnd.get_data::<Rc<Document>>() {
+	///     println!("{}", doc.path);
+	/// }
+	/// ```
+	fn set_data<T: 'static>(&self, value: T) -> Option<T>
+		where Self: Sized,
+	{
+		set_window_data(self.hwnd(), value)
+	}
+
+	/// Returns a clone of the value of type `T` associated with the window by
+	/// [`set_data`](crate::prelude::GuiWindow::set_data), if any.
+	///
+	/// To share mutable state, store an `Rc<RefCell<T>>`.
+	#[must_use]
+	fn get_data<T: Clone + 'static>(&self) -> Option<T>
+		where Self: Sized,
+	{
+		get_window_data(self.hwnd())
+	}
+
+	/// Removes and returns the value of type `T` associated with the window by
+	/// [`set_data`](crate::prelude::GuiWindow::set_data), if any.
+	fn take_data<T: 'static>(&self) -> Option<T>
+		where Self: Sized,
+	{
+		take_window_data(self.hwnd())
+	}
 }
 
 /// Any window which can get/set text.
@@ -91,6 +155,44 @@ pub trait GuiParent: GuiWindow {
 	/// used externally.
 	#[must_use]
 	unsafe fn as_base(&self) -> *mut std::ffi::c_void;
+
+	/// Sets the tab order of the given child controls – which is their Z order
+	/// –, so the focus moves among them in the given sequence with Tab and
+	/// Shift+Tab. The first control is placed at the top of the Z order, and
+	/// the controls not listed come after the last one.
+	///
+	/// By default, the tab order is the creation order of the controls.
+	///
+	/// Must be called after the window is created, like in the
+	/// [`wm_create`](crate::prelude::GuiEvents::wm_create) event.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{gui, AnyResult};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// let (txt, btn): (gui::Edit, gui::Button);
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let txt = gui::Edit::new(&wnd, gui::EditOpts::default());
+	/// # let btn = gui::Button::new(&wnd, gui::ButtonOpts::default());
+	///
+	/// let wnd2 = wnd.clone();
+	/// wnd.on().wm_create(move |_| -> AnyResult<i32> {
+	///     wnd2.set_tab_order(&[&btn, &txt])?;
+	///     Ok(0)
+	/// });
+	/// ```
+	fn set_tab_order(&self, ctrls: &[&dyn GuiChild]) -> SysResult<()> {
+		let mut insert_after = HwndPlace::Place(co::HWND_PLACE::TOP);
+		for ctrl in ctrls.iter() {
+			ctrl.hwnd().SetWindowPos(insert_after, POINT::default(), SIZE::default(),
+				co::SWP::NOMOVE | co::SWP::NOSIZE | co::SWP::NOACTIVATE)?;
+			insert_after = HwndPlace::Hwnd(unsafe { ctrl.hwnd().raw_copy() });
+		}
+		Ok(())
+	}
 }
 
 /// Allows a window to spawn new threads which can return errors, and run
@@ -167,6 +269,9 @@ pub trait GuiThread: GuiParent {
 	/// When working in a parallel thread, you **must** call `run_ui_thread` to
 	/// update the UI.
 	///
+	/// If the window has already been destroyed, the closure is dropped
+	/// without being run.
+	///
 	/// # Examples
 	///
 	/// The example below shows the event of a
@@ -223,6 +328,58 @@ pub trait GuiThread: GuiParent {
 	/// ```
 	fn run_ui_thread<F>(&self, func: F)
 		where F: FnOnce() -> AnyResult<()> + Send + 'static;
+
+	/// Spawns a
+	/// [`Future`](https://doc.rust-lang.org/std/future/trait.Future.html)
+	/// which is polled in the window's original UI thread, driven by the
+	/// ordinary message loop. Every time the future is woken – from any
+	/// thread – it's resumed in the UI thread, so it can freely update the UI
+	/// across `.await` points.
+	///
+	/// Blocking work should be moved to another thread with
+	/// [`gui::spawn_blocking`](crate::gui::spawn_blocking), and awaited.
+	///
+	/// If the future returns an error, it will be forwarded to the UI thread
+	/// like any other event error. Pending futures are dropped when the root
+	/// window is destroyed.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet, or if called from a thread
+	/// other than the window's UI thread.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{gui, AnyResult, Sleep};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// let btn: gui::Button;
+	/// # let btn = gui::Button::new(&wnd, gui::ButtonOpts::default());
+	///
+	/// btn.on().bn_clicked({
+	///     let wnd = wnd.clone();
+	///     move || -> AnyResult<()> {
+	///         wnd.spawn_ui_future({
+	///             let wnd = wnd.clone();
+	///             async move {
+	///                 wnd.hwnd().SetWindowText("Working...")?;
+	///                 gui::spawn_blocking(|| Sleep(3000)).await;
+	///                 wnd.hwnd().SetWindowText("Done")?;
+	///                 Ok(())
+	///             }
+	///         });
+	///         Ok(())
+	///     }
+	/// });
+	/// ```
+	fn spawn_ui_future<F>(&self, future: F)
+		where F: Future<Output = AnyResult<()>> + 'static,
+	{
+		spawn_ui_future(self.hwnd(), future);
+	}
 }
 
 /// Any child window.
@@ -232,6 +389,19 @@ pub trait GuiChild: GuiWindow {
 	/// The control ID should be unique within a parent.
 	#[must_use]
 	fn ctrl_id(&self) -> u16;
+
+	/// Sets the font of the control, replacing the default UI font, by sending
+	/// a [`wm::SetFont`](crate::msg::wm::SetFont) message.
+	///
+	/// The font is kept alive until the UI thread ends. Must be called after
+	/// the control is created.
+	fn set_font(&self, font: &Font) {
+		font.keep_alive();
+		self.hwnd().SendMessage(wm::SetFont {
+			hfont: unsafe { font.hfont().raw_copy() },
+			redraw: true,
+		});
+	}
 }
 
 /// Any child window which can be focused.
@@ -293,3 +463,14 @@ pub trait GuiTab {
 	#[must_use]
 	fn as_ctrl(&self) -> &WindowControl;
 }
+
+/// An undoable operation, to be stored in an
+/// [`UndoStack`](crate::gui::UndoStack).
+pub trait GuiUndoCommand {
+	/// Performs the operation. Called when the command is first executed, and
+	/// again each time it is redone.
+	fn execute(&self) -> AnyResult<()>;
+
+	/// Reverts the changes made by [`execute`](crate::prelude::GuiUndoCommand::execute).
+	fn undo(&self) -> AnyResult<()>;
+}
@@ -0,0 +1,554 @@
+use std::cell::RefCell;
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::kernel::decl::{MulDiv, SysResult};
+use crate::prelude::{GuiChild, GuiEvents, GuiParent, Handle, user_Hdwp, user_Hwnd};
+use crate::user::decl::{HDWP, HWND, HwndPlace, POINT, RECT, SIZE};
+
+struct Obj { // actual fields of Layout
+	parent_ptr: NonNull<Base>,
+	root: RefCell<LayoutItem>,
+}
+
+//------------------------------------------------------------------------------
+
+/// Declarative layout manager, which computes the positions and sizes of the
+/// child controls from a tree of vertical boxes, horizontal boxes and grids,
+/// whenever the parent window is resized.
+///
+/// It's an alternative to the absolute positions given in the options of each
+/// control, and to their `horz_resize` and `vert_resize` rules, which should
+/// not be used for the controls placed in a `Layout`.
+///
+/// All sizes given in the layout are in 96 DPI pixels, and are scaled to the
+/// DPI of the parent window.
+///
+/// # Examples
+///
+/// A multi-line text box filling the window, with two buttons aligned at the
+/// bottom right:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// let txt = gui::Edit::new(&wnd, gui::EditOpts::default());
+/// let btn_ok = gui::Button::new(&wnd, gui::ButtonOpts::default());
+/// let btn_cancel = gui::Button::new(&wnd, gui::ButtonOpts::default());
+///
+/// let stretch = gui::LayoutHints { stretch: 1, ..Default::default() };
+///
+/// let layout = gui::Layout::new(&wnd, gui::LayoutItem::VBox(gui::LayoutBox {
+///     items: vec![
+///         gui::LayoutItem::ctrl(&txt, stretch),
+///         gui::LayoutItem::HBox(gui::LayoutBox {
+///             items: vec![
+///                 gui::LayoutItem::Spacer(stretch),
+///                 gui::LayoutItem::ctrl(&btn_ok, gui::LayoutHints::default()),
+///                 gui::LayoutItem::ctrl(&btn_cancel, gui::LayoutHints::default()),
+///             ],
+///             spacing: 8,
+///             ..Default::default()
+///         }),
+///     ],
+///     spacing: 8,
+///     padding: 10,
+///     ..Default::default()
+/// }));
+/// ```
+#[derive(Clone)]
+pub struct Layout(Rc<Obj>);
+
+unsafe impl Send for Layout {}
+
+impl Layout {
+	/// Instantiates a new `Layout` object, which will arrange the controls of
+	/// the given tree within the client area of the parent window.
+	///
+	/// The controls must be created before the `Layout`.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Layout` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, root: LayoutItem) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+		if *parent_ref.hwnd() != HWND::NULL {
+			panic!("Cannot create Layout after parent window creation.");
+		}
+
+		let new_self = Self(
+			Rc::new(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					root: RefCell::new(root),
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(parent_ref.creation_msg(), move |_| {
+			let dpi = self2.dpi();
+			self2.0.root.borrow_mut().store_natural_sizes(dpi)?;
+			let rc = self2.parent().hwnd().GetClientRect()?;
+			self2.arrange(SIZE::new(rc.right, rc.bottom))?;
+			Ok(None) // not meaningful
+		});
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm_size(move |p| {
+			if p.request != co::SIZE_R::MINIMIZED {
+				self2.arrange(p.client_area)?;
+			}
+			Ok(())
+		});
+
+		new_self
+	}
+
+	fn parent(&self) -> &Base {
+		unsafe { self.0.parent_ptr.as_ref() }
+	}
+
+	fn dpi(&self) -> i32 {
+		match self.parent().hwnd().GetDpiForWindow() {
+			0 => 96, // USER_DEFAULT_SCREEN_DPI
+			dpi => dpi as _,
+		}
+	}
+
+	/// Immediately arranges the controls to fill the given client area size.
+	fn arrange(&self, client_area: SIZE) -> SysResult<()> {
+		let root = self.0.root.borrow();
+		let num_ctrls = root.count_ctrls();
+		if num_ctrls == 0 {
+			return Ok(());
+		}
+
+		let dpi = self.dpi();
+		let mut hdwp = HDWP::BeginDeferWindowPos(num_ctrls as _)?;
+		root.place(
+			&mut hdwp,
+			RECT { left: 0, top: 0, right: client_area.cx, bottom: client_area.cy },
+			&|v| MulDiv(v, dpi, 96),
+		)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Alignment of an item of a [`Layout`](crate::gui::Layout) within the area
+/// reserved to it.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum LayoutAlign {
+	/// The item fills the whole area.
+	Fill,
+	/// The item keeps its size, at the left or top of the area.
+	Start,
+	/// The item keeps its size, centered within the area.
+	Center,
+	/// The item keeps its size, at the right or bottom of the area.
+	End,
+}
+
+/// Size hints of an item of a [`Layout`](crate::gui::Layout).
+#[derive(Clone, Copy)]
+pub struct LayoutHints {
+	/// Preferred size of the item.
+	///
+	/// Defaults to none, which means the size of the control when the parent
+	/// window is created, zero for spacers, and the sum of the children for
+	/// boxes and grids.
+	pub size: Option<SIZE>,
+	/// Stretch factor of the item within a box. The extra space of the box is
+	/// distributed among the items proportionally to their factors; items with
+	/// zero keep their preferred size.
+	///
+	/// Defaults to zero.
+	pub stretch: u32,
+	/// Alignment of the item within its area.
+	///
+	/// Defaults to `LayoutAlign::Fill`.
+	pub align: LayoutAlign,
+}
+
+impl Default for LayoutHints {
+	fn default() -> Self {
+		Self {
+			size: None,
+			stretch: 0,
+			align: LayoutAlign::Fill,
+		}
+	}
+}
+
+/// Contents of a vertical or horizontal box of a
+/// [`Layout`](crate::gui::Layout).
+#[derive(Default)]
+pub struct LayoutBox {
+	/// Items of the box, stacked in the given order.
+	pub items: Vec<LayoutItem>,
+	/// Space between the items.
+	///
+	/// Defaults to zero.
+	pub spacing: i32,
+	/// Space between the items and the edges of the box.
+	///
+	/// Defaults to zero.
+	pub padding: i32,
+	/// Size hints of the box itself.
+	pub hints: LayoutHints,
+}
+
+/// Contents of a grid of a [`Layout`](crate::gui::Layout).
+#[derive(Default)]
+pub struct LayoutGrid {
+	/// Number of columns. The items fill the grid row by row.
+	///
+	/// Defaults to zero, which is treated as 1.
+	pub cols: usize,
+	/// Items of the grid.
+	pub items: Vec<LayoutItem>,
+	/// Stretch factors of each column, like
+	/// [`LayoutHints::stretch`](crate::gui::LayoutHints::stretch). Missing
+	/// values are zero.
+	pub col_stretch: Vec<u32>,
+	/// Stretch factors of each row, like
+	/// [`LayoutHints::stretch`](crate::gui::LayoutHints::stretch). Missing
+	/// values are zero.
+	pub row_stretch: Vec<u32>,
+	/// Space between the columns and rows.
+	///
+	/// Defaults to zero.
+	pub spacing: i32,
+	/// Space between the items and the edges of the grid.
+	///
+	/// Defaults to zero.
+	pub padding: i32,
+	/// Size hints of the grid itself.
+	pub hints: LayoutHints,
+}
+
+/// An item of a [`Layout`](crate::gui::Layout) tree.
+pub enum LayoutItem {
+	/// A child control. Prefer creating it with
+	/// [`LayoutItem::ctrl`](crate::gui::LayoutItem::ctrl).
+	Ctrl(Box<dyn GuiChild>, LayoutHints),
+	/// Empty space.
+	Spacer(LayoutHints),
+	/// Items stacked from top to bottom.
+	VBox(LayoutBox),
+	/// Items stacked from left to right.
+	HBox(LayoutBox),
+	/// Items arranged in rows and columns.
+	Grid(LayoutGrid),
+}
+
+impl LayoutItem {
+	/// Creates an item with a child control.
+	#[must_use]
+	pub fn ctrl<C>(ctrl: &C, hints: LayoutHints) -> Self
+		where C: GuiChild + Clone + 'static,
+	{
+		Self::Ctrl(Box::new(ctrl.clone()), hints)
+	}
+
+	const fn hints(&self) -> &LayoutHints {
+		match self {
+			Self::Ctrl(_, hints) | Self::Spacer(hints) => hints,
+			Self::VBox(b) | Self::HBox(b) => &b.hints,
+			Self::Grid(g) => &g.hints,
+		}
+	}
+
+	fn children(&self) -> &[LayoutItem] {
+		match self {
+			Self::Ctrl(_, _) | Self::Spacer(_) => &[],
+			Self::VBox(b) | Self::HBox(b) => &b.items,
+			Self::Grid(g) => &g.items,
+		}
+	}
+
+	fn count_ctrls(&self) -> usize {
+		match self {
+			Self::Ctrl(_, _) => 1,
+			_ => self.children().iter().map(|item| item.count_ctrls()).sum(),
+		}
+	}
+
+	/// Stores the current size of the controls without a preferred size, in 96
+	/// DPI pixels.
+	fn store_natural_sizes(&mut self, dpi: i32) -> SysResult<()> {
+		match self {
+			Self::Ctrl(ctrl, hints) => if hints.size.is_none() {
+				let rc = ctrl.hwnd().GetWindowRect()?;
+				hints.size = Some(SIZE::new(
+					MulDiv(rc.right - rc.left, 96, dpi),
+					MulDiv(rc.bottom - rc.top, 96, dpi),
+				));
+			},
+			Self::Spacer(_) => {},
+			Self::VBox(b) | Self::HBox(b) =>
+				for item in b.items.iter_mut() {
+					item.store_natural_sizes(dpi)?;
+				},
+			Self::Grid(g) =>
+				for item in g.items.iter_mut() {
+					item.store_natural_sizes(dpi)?;
+				},
+		}
+		Ok(())
+	}
+
+	/// Computes the preferred size of the item, already scaled.
+	fn preferred(&self, scale: &dyn Fn(i32) -> i32) -> SIZE {
+		if let Some(sz) = self.hints().size {
+			return SIZE::new(scale(sz.cx), scale(sz.cy));
+		}
+
+		match self {
+			Self::Ctrl(_, _) | Self::Spacer(_) => SIZE::default(),
+			Self::VBox(b) | Self::HBox(b) => {
+				let vert = matches!(self, Self::VBox(_));
+				let gaps = scale(b.spacing) * (b.items.len().max(1) as i32 - 1)
+					+ scale(b.padding) * 2;
+				let (mut along, mut across) = (gaps, 0);
+				for item in b.items.iter() {
+					let sz = item.preferred(scale);
+					let (a, c) = if vert { (sz.cy, sz.cx) } else { (sz.cx, sz.cy) };
+					along += a;
+					across = across.max(c);
+				}
+				across += scale(b.padding) * 2;
+				if vert { SIZE::new(across, along) } else { SIZE::new(along, across) }
+			},
+			Self::Grid(g) => {
+				let (widths, heights) = g.preferred_tracks(scale);
+				let sum = |tracks: &[i32]| tracks.iter().sum::<i32>()
+					+ scale(g.spacing) * (tracks.len().max(1) as i32 - 1)
+					+ scale(g.padding) * 2;
+				SIZE::new(sum(&widths), sum(&heights))
+			},
+		}
+	}
+
+	/// Positions the item within the given area.
+	fn place(&self,
+		hdwp: &mut HDWP, area: RECT, scale: &dyn Fn(i32) -> i32) -> SysResult<()>
+	{
+		let rc = align_in(area, self.hints().align, || self.preferred(scale));
+
+		match self {
+			Self::Ctrl(ctrl, _) => hdwp.DeferWindowPos(
+				ctrl.hwnd(),
+				HwndPlace::None,
+				POINT::new(rc.left, rc.top),
+				SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+				co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+			),
+			Self::Spacer(_) => Ok(()),
+			Self::VBox(b) | Self::HBox(b) => {
+				let vert = matches!(self, Self::VBox(_));
+				let (pad, spacing) = (scale(b.padding), scale(b.spacing));
+				let inner = RECT {
+					left: rc.left + pad,
+					top: rc.top + pad,
+					right: rc.right - pad,
+					bottom: rc.bottom - pad,
+				};
+				let avail = if vert { inner.bottom - inner.top } else { inner.right - inner.left }
+					- spacing * (b.items.len().max(1) as i32 - 1);
+
+				let lens = distribute(avail,
+					&b.items.iter()
+						.map(|item| {
+							let sz = item.preferred(scale);
+							(if vert { sz.cy } else { sz.cx }, item.hints().stretch)
+						})
+						.collect::<Vec<_>>(),
+				);
+
+				let mut pos = if vert { inner.top } else { inner.left };
+				for (item, len) in b.items.iter().zip(lens) {
+					let cell = if vert {
+						RECT { left: inner.left, top: pos, right: inner.right, bottom: pos + len }
+					} else {
+						RECT { left: pos, top: inner.top, right: pos + len, bottom: inner.bottom }
+					};
+					item.place(hdwp, cell, scale)?;
+					pos += len + spacing;
+				}
+				Ok(())
+			},
+			Self::Grid(g) => {
+				let (pad, spacing) = (scale(g.padding), scale(g.spacing));
+				let (pref_widths, pref_heights) = g.preferred_tracks(scale);
+				let tracks = |prefs: &[i32], stretch: &[u32], total: i32| distribute(
+					total - pad * 2 - spacing * (prefs.len().max(1) as i32 - 1),
+					&prefs.iter().enumerate()
+						.map(|(i, pref)| (*pref, stretch.get(i).copied().unwrap_or(0)))
+						.collect::<Vec<_>>(),
+				);
+				let widths = tracks(&pref_widths, &g.col_stretch, rc.right - rc.left);
+				let heights = tracks(&pref_heights, &g.row_stretch, rc.bottom - rc.top);
+
+				let cols = g.cols.max(1);
+				let mut top = rc.top + pad;
+				for (row, items) in g.items.chunks(cols).enumerate() {
+					let mut left = rc.left + pad;
+					for (col, item) in items.iter().enumerate() {
+						item.place(hdwp, RECT {
+							left,
+							top,
+							right: left + widths[col],
+							bottom: top + heights[row],
+						}, scale)?;
+						left += widths[col] + spacing;
+					}
+					top += heights[row] + spacing;
+				}
+				Ok(())
+			},
+		}
+	}
+}
+
+impl LayoutGrid {
+	/// Computes the preferred widths of the columns and heights of the rows,
+	/// already scaled.
+	fn preferred_tracks(&self, scale: &dyn Fn(i32) -> i32) -> (Vec<i32>, Vec<i32>) {
+		let cols = self.cols.max(1);
+		let rows = self.items.len().div_ceil(cols);
+		let (mut widths, mut heights) = (vec![0; cols], vec![0; rows]);
+
+		for (i, item) in self.items.iter().enumerate() {
+			let sz = item.preferred(scale);
+			widths[i % cols] = widths[i % cols].max(sz.cx);
+			heights[i / cols] = heights[i / cols].max(sz.cy);
+		}
+		(widths, heights)
+	}
+}
+
+/// Distributes the available length among the items, given their preferred
+/// lengths and stretch factors. The difference to the preferred lengths, which
+/// can be negative, goes to the stretchable items.
+fn distribute(avail: i32, items: &[(i32, u32)]) -> Vec<i32> {
+	let total_stretch = items.iter().map(|(_, stretch)| *stretch as i32).sum::<i32>();
+	let mut extra = avail - items.iter().map(|(pref, _)| *pref).sum::<i32>();
+	let mut stretch_left = total_stretch;
+
+	items.iter()
+		.map(|(pref, stretch)| {
+			if *stretch == 0 || stretch_left == 0 {
+				return *pref;
+			}
+			let share = MulDiv(extra, *stretch as _, stretch_left); // last one takes the remainder
+			extra -= share;
+			stretch_left -= *stretch as i32;
+			(*pref + share).max(0)
+		})
+		.collect()
+}
+
+/// Computes the rectangle of an item within its area, according to its
+/// alignment; the preferred size is only computed if needed.
+fn align_in(area: RECT, align: LayoutAlign, preferred: impl FnOnce() -> SIZE) -> RECT {
+	if align == LayoutAlign::Fill {
+		return area;
+	}
+
+	let sz = preferred();
+	let (cx, cy) = ((area.right - area.left).min(sz.cx), (area.bottom - area.top).min(sz.cy));
+	let (left, top) = match align {
+		LayoutAlign::Start => (area.left, area.top),
+		LayoutAlign::Center => (
+			area.left + (area.right - area.left - cx) / 2,
+			area.top + (area.bottom - area.top - cy) / 2,
+		),
+		_ => (area.right - cx, area.bottom - cy),
+	};
+	RECT { left, top, right: left + cx, bottom: top + cy }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn spacer(cx: i32, cy: i32) -> LayoutItem {
+		LayoutItem::Spacer(LayoutHints { size: Some(SIZE::new(cx, cy)), ..Default::default() })
+	}
+
+	fn rect(left: i32, top: i32, right: i32, bottom: i32) -> RECT {
+		RECT { left, top, right, bottom }
+	}
+
+	#[test]
+	fn distribute_extra_space() {
+		assert_eq!(distribute(100, &[(10, 0), (20, 1), (30, 3)]), [10, 30, 60]);
+		assert_eq!(distribute(10, &[(0, 1), (0, 1), (0, 1)]), [3, 4, 3]); // remainder is kept
+		assert_eq!(distribute(100, &[(10, 0), (20, 0)]), [10, 20]);
+	}
+
+	#[test]
+	fn distribute_missing_space() {
+		assert_eq!(distribute(50, &[(40, 0), (30, 1)]), [40, 10]);
+		assert_eq!(distribute(10, &[(40, 0), (30, 1)]), [40, 0]);
+	}
+
+	#[test]
+	fn preferred_boxes() {
+		let items = || vec![spacer(10, 20), spacer(30, 5)];
+		let vbox = LayoutItem::VBox(LayoutBox { items: items(), spacing: 4, padding: 2, ..Default::default() });
+		let hbox = LayoutItem::HBox(LayoutBox { items: items(), spacing: 4, padding: 2, ..Default::default() });
+
+		let same = |v: i32| v;
+		assert!(vbox.preferred(&same) == SIZE::new(34, 33));
+		assert!(hbox.preferred(&same) == SIZE::new(48, 24));
+
+		let dpi144 = |v: i32| MulDiv(v, 144, 96);
+		assert!(vbox.preferred(&dpi144) == SIZE::new(51, 50));
+	}
+
+	#[test]
+	fn preferred_size_hint_wins() {
+		let vbox = LayoutItem::VBox(LayoutBox {
+			items: vec![spacer(500, 500)],
+			hints: LayoutHints { size: Some(SIZE::new(100, 50)), ..Default::default() },
+			..Default::default()
+		});
+		assert!(vbox.preferred(&|v| MulDiv(v, 144, 96)) == SIZE::new(150, 75));
+	}
+
+	#[test]
+	fn preferred_grid() {
+		let grid = LayoutGrid {
+			cols: 2,
+			items: vec![spacer(10, 10), spacer(20, 5), spacer(5, 30)],
+			spacing: 1,
+			..Default::default()
+		};
+		let (widths, heights) = grid.preferred_tracks(&|v| v);
+		assert_eq!((widths, heights), (vec![10, 20], vec![10, 30]));
+		assert!(LayoutItem::Grid(grid).preferred(&|v| v) == SIZE::new(31, 41));
+
+		let one_col = LayoutGrid { items: vec![spacer(10, 10), spacer(20, 5)], ..Default::default() };
+		assert_eq!(one_col.preferred_tracks(&|v| v), (vec![20], vec![10, 5]));
+	}
+
+	#[test]
+	fn align_within_area() {
+		let area = rect(0, 0, 100, 50);
+		let pref = || SIZE::new(20, 10);
+		assert!(align_in(area, LayoutAlign::Fill, || unreachable!()) == area);
+		assert!(align_in(area, LayoutAlign::Start, pref) == rect(0, 0, 20, 10));
+		assert!(align_in(area, LayoutAlign::Center, pref) == rect(40, 20, 60, 30));
+		assert!(align_in(area, LayoutAlign::End, pref) == rect(80, 40, 100, 50));
+		assert!(align_in(area, LayoutAlign::Center, || SIZE::new(200, 10)) == rect(0, 20, 100, 30));
+	}
+}
@@ -1,10 +1,10 @@
-use std::cell::UnsafeCell;
+use std::cell::{RefCell, UnsafeCell};
 use std::marker::PhantomPinned;
 use std::pin::Pin;
 use std::sync::Arc;
 
 use crate::co;
-use crate::kernel::decl::SysResult;
+use crate::kernel::decl::{MulDiv, SysResult};
 use crate::msg::wm;
 use crate::prelude::{Handle, user_Hdwp, user_Hwnd};
 use crate::user::decl::{HDWP, HWND, HwndPlace, POINT, RECT, SIZE};
@@ -23,6 +23,10 @@ pub enum Horz {
 	/// When parent window resizes, the control width will stretch/shrink
 	/// accordingly. Position will remain fixed.
 	Resize,
+	/// When parent window resizes, the control will be kept at the given
+	/// percentages of the parent width: `left` is the position of its left
+	/// edge, and `width` is its width. Values above 100 are treated as 100.
+	Percent { left: u8, width: u8 },
 }
 
 /// Specifies the vertical behavior of the control when the parent window is
@@ -39,6 +43,77 @@ pub enum Vert {
 	/// When parent window resizes, the control height will stretch/shrink
 	/// accordingly. Position will remain fixed.
 	Resize,
+	/// When parent window resizes, the control will be kept at the given
+	/// percentages of the parent height: `top` is the position of its top
+	/// edge, and `height` is its height. Values above 100 are treated as 100.
+	Percent { top: u8, height: u8 },
+}
+
+/// Behavior of the control in a single axis, common to `Horz` and `Vert`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Rule {
+	None,
+	Repos,
+	Resize,
+	Percent(u8, u8),
+}
+
+impl From<Horz> for Rule {
+	fn from(v: Horz) -> Self {
+		match v {
+			Horz::None => Self::None,
+			Horz::Repos => Self::Repos,
+			Horz::Resize => Self::Resize,
+			Horz::Percent { left, width } => Self::Percent(left, width),
+		}
+	}
+}
+
+impl From<Vert> for Rule {
+	fn from(v: Vert) -> Self {
+		match v {
+			Vert::None => Self::None,
+			Vert::Repos => Self::Repos,
+			Vert::Resize => Self::Resize,
+			Vert::Percent { top, height } => Self::Percent(top, height),
+		}
+	}
+}
+
+impl Rule {
+	/// Calculates the new position and length of the control in this axis.
+	/// `margins` are the distances to the lower and upper edges of the parent,
+	/// and `limits` are the minimum and maximum lengths.
+	fn arrange(self,
+		pos: i32, len: i32, parent_orig: i32, parent_now: i32,
+		margins: (i32, i32), limits: (i32, Option<i32>)) -> (i32, i32)
+	{
+		let (mut pos, mut len) = match self {
+			Self::None => return (pos, len),
+			Self::Repos => (parent_now - parent_orig + pos, len),
+			Self::Resize => (pos, parent_now - parent_orig + len),
+			Self::Percent(pos_pct, len_pct) => ( // margins are inside the proportional cell
+				MulDiv(parent_now, pos_pct.min(100) as _, 100) + margins.0,
+				MulDiv(parent_now, len_pct.min(100) as _, 100) - margins.0 - margins.1,
+			),
+		};
+
+		match self {
+			Self::Repos => { // keep it within the margins, lower edge has priority
+				pos = pos.min(parent_now - margins.1 - len).max(margins.0);
+			},
+			_ => {
+				if self == Self::Resize {
+					len = len.min(parent_now - margins.1 - pos);
+				}
+				if let Some(max) = limits.1 {
+					len = len.min(max);
+				}
+				len = len.max(limits.0); // minimum has priority
+			},
+		}
+		(pos, len)
+	}
 }
 
 struct ChildInfo {
@@ -46,11 +121,15 @@ struct ChildInfo {
 	rc_orig: RECT, // original coordinates relative to parent
 	horz: Horz,
 	vert: Vert,
+	margins: RECT, // distances to the parent edges
+	min_size: SIZE,
+	max_size: Option<SIZE>,
 }
 
 struct Obj { // actual fields of LayoutArranger
 	ctrls: UnsafeCell<Vec<ChildInfo>>,
 	sz_parent_orig: UnsafeCell<SIZE>, // original parent client area
+	dpi: UnsafeCell<u32>, // DPI the stored coordinates refer to
 	_pin: PhantomPinned,
 }
 
@@ -67,6 +146,7 @@ impl LayoutArranger {
 				Obj {
 					ctrls: UnsafeCell::new(Vec::with_capacity(10)), // arbitrary
 					sz_parent_orig: UnsafeCell::new(SIZE::default()),
+					dpi: UnsafeCell::new(0),
 					_pin: PhantomPinned,
 				},
 			),
@@ -77,16 +157,28 @@ impl LayoutArranger {
 	/// its position and size rearranged when requested.
 	pub(in crate::gui) fn add_child(&self,
 		hparent: &HWND, hchild: &HWND, horz: Horz, vert: Vert) -> SysResult<()>
+	{
+		self.add_child_constrained(hparent, hchild, horz, vert,
+			RECT::default(), SIZE::default(), None)
+	}
+
+	/// Adds a new child control to the internal list, also with margins and
+	/// size limits. If the control was already added, its entry is replaced.
+	pub(in crate::gui) fn add_child_constrained(&self,
+		hparent: &HWND, hchild: &HWND, horz: Horz, vert: Vert,
+		margins: RECT, min_size: SIZE, max_size: Option<SIZE>) -> SysResult<()>
 	{
 		if *hparent == HWND::NULL || *hchild == HWND::NULL {
 			panic!("Cannot add resizer entries before window/control creation.");
 		}
 
+		let ctrls = unsafe { &mut *self.0.ctrls.get() };
+		ctrls.retain(|ctrl| ctrl.hchild != *hchild);
+
 		if horz == Horz::None && vert == Vert::None {
 			return Ok(()); // nothing to do, don't even add it
 		}
 
-		let ctrls = unsafe { &mut *self.0.ctrls.get() };
 		if ctrls.is_empty() { // first control being added?
 			let rc_parent = hparent.GetClientRect()?;
 			*unsafe { &mut *self.0.sz_parent_orig.get() } =
@@ -102,11 +194,78 @@ impl LayoutArranger {
 				rc_orig,
 				horz,
 				vert,
+				margins,
+				min_size,
+				max_size,
 			},
 		);
 		Ok(())
 	}
 
+	/// Sets the DPI of the parent window, when it's created.
+	pub(in crate::gui) fn set_dpi(&self, dpi: u32) {
+		*unsafe { &mut *self.0.dpi.get() } = dpi;
+	}
+
+	/// Scales the stored coordinates to the new DPI of the parent window. If
+	/// `move_children` is set, all direct children of the parent are also
+	/// repositioned and resized.
+	pub(in crate::gui) fn rescale_dpi(&self,
+		hparent: &HWND, new_dpi: u32, move_children: bool) -> SysResult<()>
+	{
+		let dpi = unsafe { &mut *self.0.dpi.get() };
+		let old_dpi = *dpi;
+		*dpi = new_dpi;
+		if old_dpi == 0 || new_dpi == 0 || old_dpi == new_dpi {
+			return Ok(());
+		}
+		let scale = |v: i32| MulDiv(v, new_dpi as _, old_dpi as _);
+
+		if move_children {
+			let children = RefCell::new(Vec::<HWND>::new());
+			hparent.EnumChildWindows(|hchild| {
+				if hchild.GetParent().map_or(false, |h| h == *hparent) { // direct children only
+					children.borrow_mut().push(hchild);
+				}
+				true
+			});
+			let children = children.into_inner();
+
+			if !children.is_empty() {
+				let mut hdwp = HDWP::BeginDeferWindowPos(children.len() as _)?;
+				for hchild in children.iter() {
+					let mut rc = hchild.GetWindowRect()?;
+					hparent.ScreenToClientRc(&mut rc)?;
+					hdwp.DeferWindowPos(
+						hchild,
+						HwndPlace::None,
+						POINT::new(scale(rc.left), scale(rc.top)),
+						SIZE::new(scale(rc.right - rc.left), scale(rc.bottom - rc.top)),
+						co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+					)?;
+				}
+			}
+		}
+
+		let scale_rc = |rc: &RECT| RECT {
+			left: scale(rc.left),
+			top: scale(rc.top),
+			right: scale(rc.right),
+			bottom: scale(rc.bottom),
+		};
+		let scale_sz = |sz: &SIZE| SIZE::new(scale(sz.cx), scale(sz.cy));
+
+		for ctrl in unsafe { &mut *self.0.ctrls.get() }.iter_mut() {
+			ctrl.rc_orig = scale_rc(&ctrl.rc_orig);
+			ctrl.margins = scale_rc(&ctrl.margins);
+			ctrl.min_size = scale_sz(&ctrl.min_size);
+			ctrl.max_size = ctrl.max_size.as_ref().map(scale_sz);
+		}
+		let sz_parent_orig = unsafe { &mut *self.0.sz_parent_orig.get() };
+		*sz_parent_orig = scale_sz(sz_parent_orig);
+		Ok(())
+	}
+
 	/// Rearranges all child controls to fit the new width/height of parent
 	/// window.
 	pub(in crate::gui) fn rearrange(&self, p: &wm::Size) -> SysResult<()> {
@@ -117,6 +276,7 @@ impl LayoutArranger {
 		}
 
 		let mut hdwp = HDWP::BeginDeferWindowPos(ctrls.len() as _)?;
+		let sz_parent_orig = unsafe { &*self.0.sz_parent_orig.get() };
 
 		for ctrl in ctrls.iter() {
 			let mut uflags = co::SWP::NOZORDER;
@@ -126,31 +286,24 @@ impl LayoutArranger {
 				uflags |= co::SWP::NOMOVE;
 			}
 
-			let sz_parent_orig = unsafe { &mut *self.0.sz_parent_orig.get() };
+			let (x, cx) = Rule::from(ctrl.horz).arrange(
+				ctrl.rc_orig.left, ctrl.rc_orig.right - ctrl.rc_orig.left,
+				sz_parent_orig.cx, p.client_area.cx,
+				(ctrl.margins.left, ctrl.margins.right),
+				(ctrl.min_size.cx, ctrl.max_size.map(|sz| sz.cx)),
+			);
+			let (y, cy) = Rule::from(ctrl.vert).arrange(
+				ctrl.rc_orig.top, ctrl.rc_orig.bottom - ctrl.rc_orig.top,
+				sz_parent_orig.cy, p.client_area.cy,
+				(ctrl.margins.top, ctrl.margins.bottom),
+				(ctrl.min_size.cy, ctrl.max_size.map(|sz| sz.cy)),
+			);
 
 			hdwp.DeferWindowPos(
 				&ctrl.hchild,
 				HwndPlace::None,
-				POINT::new(
-					match ctrl.horz {
-						Horz::Repos => p.client_area.cx - sz_parent_orig.cx + ctrl.rc_orig.left,
-						_ => ctrl.rc_orig.left // keep original x pos
-					},
-					match ctrl.vert {
-						Vert::Repos => p.client_area.cy - sz_parent_orig.cy + ctrl.rc_orig.top,
-						_ => ctrl.rc_orig.top // keep original y pos
-					},
-				),
-				SIZE::new(
-					match ctrl.horz {
-						Horz::Resize => p.client_area.cx - sz_parent_orig.cx + ctrl.rc_orig.right - ctrl.rc_orig.left,
-						_ => ctrl.rc_orig.right - ctrl.rc_orig.left // keep original width
-					},
-					match ctrl.vert {
-						Vert::Resize => p.client_area.cy - sz_parent_orig.cy + ctrl.rc_orig.bottom - ctrl.rc_orig.top,
-						_ =>ctrl.rc_orig.bottom - ctrl.rc_orig.top // keep original height
-					},
-				),
+				POINT::new(x, y),
+				SIZE::new(cx, cy),
 				uflags,
 			)?;
 		}
@@ -158,3 +311,33 @@ impl LayoutArranger {
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn none() {
+		assert_eq!(Rule::None.arrange(10, 20, 100, 150, (10, 70), (0, None)), (10, 20));
+	}
+
+	#[test]
+	fn repos() {
+		assert_eq!(Rule::Repos.arrange(10, 20, 100, 150, (10, 70), (0, None)), (60, 20));
+		assert_eq!(Rule::Repos.arrange(10, 20, 100, 50, (10, 70), (0, None)), (10, 20)); // lower edge wins
+	}
+
+	#[test]
+	fn resize() {
+		assert_eq!(Rule::Resize.arrange(10, 20, 100, 150, (10, 70), (0, None)), (10, 70));
+		assert_eq!(Rule::Resize.arrange(10, 20, 100, 150, (10, 70), (0, Some(50))), (10, 50));
+		assert_eq!(Rule::Resize.arrange(10, 20, 100, 80, (10, 70), (5, None)), (10, 5)); // minimum wins
+	}
+
+	#[test]
+	fn percent() {
+		assert_eq!(Rule::Percent(50, 50).arrange(0, 0, 100, 200, (2, 3), (0, None)), (102, 95));
+		assert_eq!(Rule::Percent(150, 10).arrange(0, 0, 100, 200, (2, 3), (0, None)), (202, 15));
+		assert!(Rule::from(Horz::Percent { left: 10, width: 20 }) == Rule::Percent(10, 20));
+	}
+}
@@ -0,0 +1,251 @@
+use std::rc::Rc;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::gui::privs::auto_ctrl_id;
+use crate::kernel::decl::{AnyResult, SysResult};
+use crate::prelude::{
+	GuiEvents, GuiEventsAll, GuiParent, Handle, NativeBitflag, user_Hmenu,
+	user_Hwnd,
+};
+use crate::user::decl::{BmpPtrStr, HMENU, IdMenu, IdPos, MenuEnum};
+
+enum Entry {
+	Check {
+		text: String,
+		checked: bool,
+		func: Box<dyn Fn(bool) -> AnyResult<()>>,
+	},
+	Item {
+		text: String,
+		func: Box<dyn Fn() -> AnyResult<()>>,
+	},
+	Radio {
+		texts: Vec<String>,
+		selected: usize,
+		func: Box<dyn Fn(usize) -> AnyResult<()>>,
+	},
+	Separator,
+	Submenu {
+		text: String,
+		menu: Menu,
+	},
+}
+
+//------------------------------------------------------------------------------
+
+/// Builder of a menu bar, whose items have their
+/// [`WM_COMMAND`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-command)
+/// messages routed to the given closures.
+///
+/// The command IDs of the items are auto-generated. Check items and radio
+/// groups have their check marks automatically updated when clicked.
+///
+/// The actual [`HMENU`](crate::HMENU) is created by
+/// [`attach`](crate::gui::Menu::attach).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let wnd2 = wnd.clone();
+/// gui::Menu::new()
+///     .add_submenu("&File", gui::Menu::new()
+///         .add_item("&Open", move || {
+///             println!("Open clicked.");
+///             Ok(())
+///         })
+///         .add_separator()
+///         .add_item("E&xit", move || {
+///             wnd2.hwnd().DestroyWindow()?;
+///             Ok(())
+///         }),
+///     )
+///     .add_submenu("&View", gui::Menu::new()
+///         .add_check_item("&Word wrap", true, move |checked| {
+///             println!("Word wrap: {}", checked);
+///             Ok(())
+///         })
+///         .add_separator()
+///         .add_radio_group(&["&Small", "&Medium", "&Large"], 1, move |idx| {
+///             println!("Size index: {}", idx);
+///             Ok(())
+///         }),
+///     )
+///     .attach(&wnd)?;
+///
+/// wnd.run_main(None)?;
+/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[derive(Default)]
+pub struct Menu {
+	entries: Vec<Entry>,
+}
+
+impl Menu {
+	/// Creates a new, empty menu builder.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds an item with a check mark, which is toggled when the item is
+	/// clicked. The closure receives the new check state.
+	#[must_use]
+	pub fn add_check_item<F>(mut self, text: &str, checked: bool, func: F) -> Self
+		where F: Fn(bool) -> AnyResult<()> + 'static,
+	{
+		self.entries.push(Entry::Check {
+			text: text.to_owned(),
+			checked,
+			func: Box::new(func),
+		});
+		self
+	}
+
+	/// Adds an ordinary item.
+	#[must_use]
+	pub fn add_item<F>(mut self, text: &str, func: F) -> Self
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		self.entries.push(Entry::Item {
+			text: text.to_owned(),
+			func: Box::new(func),
+		});
+		self
+	}
+
+	/// Adds a group of consecutive items where only one is selected, marked
+	/// with a radio bullet. The closure receives the zero-based index of the
+	/// clicked item.
+	///
+	/// If `selected` is out of bounds, no item is initially selected.
+	#[must_use]
+	pub fn add_radio_group<F>(mut self,
+		texts: &[&str], selected: usize, func: F) -> Self
+		where F: Fn(usize) -> AnyResult<()> + 'static,
+	{
+		self.entries.push(Entry::Radio {
+			texts: texts.iter().map(|t| (*t).to_owned()).collect(),
+			selected,
+			func: Box::new(func),
+		});
+		self
+	}
+
+	/// Adds a separator.
+	#[must_use]
+	pub fn add_separator(mut self) -> Self {
+		self.entries.push(Entry::Separator);
+		self
+	}
+
+	/// Adds a submenu, which will be created as a popup menu.
+	#[must_use]
+	pub fn add_submenu(mut self, text: &str, submenu: Menu) -> Self {
+		self.entries.push(Entry::Submenu {
+			text: text.to_owned(),
+			menu: submenu,
+		});
+		self
+	}
+
+	/// Creates the menu bar and sets it to the given top-level window, like a
+	/// [`WindowMain`](crate::gui::WindowMain), when the window is created.
+	/// Returns the menu handle, which is owned by the window.
+	///
+	/// Since the menu bar is set after the window is created, it takes room
+	/// from the client area. Do not set a menu in the window options.
+	///
+	/// # Panics
+	///
+	/// Panics if the window was already created – that is, you cannot attach
+	/// a menu in an event closure.
+	pub fn attach(self, wnd: &impl GuiParent) -> SysResult<HMENU> {
+		let parent_ref = unsafe { Base::from_guiparent(wnd) };
+		let mut hmenu = HMENU::CreateMenu()?;
+		if let Err(e) = self.build(&hmenu, parent_ref) {
+			hmenu.DestroyMenu()?;
+			return Err(e);
+		}
+
+		let parent_ptr = parent_ref as *const Base; // Base is pinned and outlives its events
+		let hmenu2 = unsafe { hmenu.raw_copy() };
+		parent_ref.privileged_on().wm(parent_ref.creation_msg(), move |_| {
+			unsafe { &*parent_ptr }.hwnd().SetMenu(&hmenu2)?;
+			Ok(None) // not meaningful
+		});
+
+		Ok(hmenu)
+	}
+
+	fn build(self, hmenu: &HMENU, parent_ref: &Base) -> SysResult<()> {
+		for entry in self.entries.into_iter() {
+			match entry {
+				Entry::Check { text, checked, func } => {
+					let cmd_id = auto_ctrl_id();
+					hmenu.AppendMenu(
+						co::MF::STRING | if checked { co::MF::CHECKED } else { co::MF::UNCHECKED },
+						IdMenu::Id(cmd_id),
+						BmpPtrStr::from_str(&text),
+					)?;
+
+					let hmenu2 = unsafe { hmenu.raw_copy() };
+					parent_ref.privileged_on().wm_command_accel_menu(cmd_id, move || {
+						let checked = !hmenu2.GetMenuState(IdPos::Id(cmd_id))?.has(co::MF::CHECKED);
+						hmenu2.CheckMenuItem(IdPos::Id(cmd_id), checked)?;
+						func(checked)
+					});
+				},
+				Entry::Item { text, func } => {
+					let cmd_id = auto_ctrl_id();
+					hmenu.AppendMenuEnum(&[MenuEnum::Entry(cmd_id, &text)])?;
+					parent_ref.privileged_on().wm_command_accel_menu(cmd_id, func);
+				},
+				Entry::Radio { texts, selected, func } => {
+					if texts.is_empty() {
+						continue;
+					}
+
+					let cmd_ids = texts.iter().map(|_| auto_ctrl_id()).collect::<Vec<_>>();
+					let (first, last) = (cmd_ids[0], cmd_ids[cmd_ids.len() - 1]);
+					for (text, cmd_id) in texts.iter().zip(cmd_ids.iter()) {
+						hmenu.AppendMenuEnum(&[MenuEnum::Entry(*cmd_id, text)])?;
+					}
+					if let Some(cmd_id) = cmd_ids.get(selected) {
+						hmenu.CheckMenuRadioItem(
+							IdPos::Id(first), IdPos::Id(last), IdPos::Id(*cmd_id))?;
+					}
+
+					let shared_func = Rc::new(func);
+					for (idx, cmd_id) in cmd_ids.iter().enumerate() {
+						let cmd_id = *cmd_id;
+						let hmenu2 = unsafe { hmenu.raw_copy() };
+						let shared_func = shared_func.clone();
+						parent_ref.privileged_on().wm_command_accel_menu(cmd_id, move || {
+							hmenu2.CheckMenuRadioItem(
+								IdPos::Id(first), IdPos::Id(last), IdPos::Id(cmd_id))?;
+							shared_func(idx)
+						});
+					}
+				},
+				Entry::Separator => {
+					hmenu.AppendMenuEnum(&[MenuEnum::Separator])?;
+				},
+				Entry::Submenu { text, menu } => {
+					let mut hsubmenu = HMENU::CreatePopupMenu()?;
+					if let Err(e) = menu.build(&hsubmenu, parent_ref) {
+						hsubmenu.DestroyMenu()?;
+						return Err(e);
+					}
+					hmenu.AppendMenuEnum(&[MenuEnum::Submenu(&hsubmenu, &text)])?; // now owned by the parent menu
+				},
+			}
+		}
+		Ok(())
+	}
+}
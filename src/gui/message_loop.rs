@@ -0,0 +1,254 @@
+use crate::co;
+use crate::gui::privs::QUIT_ERROR;
+use crate::kernel::decl::AnyResult;
+use crate::prelude::{Handle, user_Hwnd};
+use crate::user::decl::{
+	DispatchMessage, HACCEL, HWND, MSG, MsgWaitForMultipleObjectsEx,
+	PeekMessage, TranslateMessage,
+};
+
+impl_handle! { HWAITABLE;
+	/// Any kernel object handle which can be waited for, regardless of its
+	/// actual type.
+}
+
+/// The message loop which pumps the messages of the UI thread, used by
+/// [`WindowMain`](crate::gui::WindowMain) and
+/// [`WindowMessageOnly`](crate::gui::WindowMessageOnly).
+///
+/// By default, each message goes through `TranslateMDISysAccel`,
+/// `TranslateAccelerator` and `IsDialogMessage` before being dispatched. The
+/// loop can be customized with:
+///
+/// * pre-translate filters, which can consume messages before the default
+///   processing;
+/// * idle handlers, called when the message queue becomes empty;
+/// * wait handles, whose closures are called when the handles are signaled;
+/// * quit handlers, called when `WM_QUIT` is received, right before the loop
+///   returns.
+///
+/// The loop ends when `WM_QUIT` is posted, usually by
+/// [`PostQuitMessage`](crate::PostQuitMessage), or when any closure returns an
+/// error.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, gui, HEVENT};
+///
+/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// let hevent = HEVENT::CreateEvent(None, false, false, None)?;
+///
+/// let msg_loop = gui::MessageLoop::new()
+///     .pre_translate(|msg| {
+///         Ok(msg.message == co::WM::KEYDOWN
+///             && co::VK::from(msg.wParam as u16) == co::VK::F12) // swallow F12
+///     })
+///     .idle(|| {
+///         println!("Queue is empty.");
+///         Ok(false) // no more idle processing until the next message
+///     })
+///     .wait_handle(&*hevent, || {
+///         println!("Event signaled.");
+///         Ok(())
+///     });
+///
+/// wnd.run_main_with_loop(None, msg_loop)?;
+/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[derive(Default)]
+pub struct MessageLoop {
+	filter_fns: Vec<Box<dyn FnMut(&mut MSG) -> AnyResult<bool>>>,
+	idle_fns: Vec<Box<dyn FnMut() -> AnyResult<bool>>>,
+	wait_handles: Vec<HWAITABLE>,
+	wait_fns: Vec<Box<dyn FnMut() -> AnyResult<()>>>,
+	quit_fns: Vec<Box<dyn FnMut(i32) -> AnyResult<()>>>,
+}
+
+impl MessageLoop {
+	/// Creates a new message loop, with no customizations.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds a filter which is called for each message, before the default
+	/// processing. Filters are called in the order they were added.
+	///
+	/// If the closure returns `true`, the message is considered consumed, and
+	/// it won't be translated nor dispatched.
+	#[must_use]
+	pub fn pre_translate<F>(mut self, func: F) -> Self
+		where F: FnMut(&mut MSG) -> AnyResult<bool> + 'static,
+	{
+		self.filter_fns.push(Box::new(func));
+		self
+	}
+
+	/// Adds a handler which is called when the message queue becomes empty.
+	///
+	/// If any of the idle handlers returns `true`, they will be called again
+	/// as long as the queue remains empty; otherwise, they will be called
+	/// only after the next message is processed.
+	#[must_use]
+	pub fn idle<F>(mut self, func: F) -> Self
+		where F: FnMut() -> AnyResult<bool> + 'static,
+	{
+		self.idle_fns.push(Box::new(func));
+		self
+	}
+
+	/// Adds a kernel object handle – an event, a process, a thread, etc. –
+	/// whose closure is called, in the UI thread, whenever the handle is
+	/// signaled. The waiting is performed with
+	/// [`MsgWaitForMultipleObjectsEx`](crate::MsgWaitForMultipleObjectsEx).
+	///
+	/// The handle must remain valid while the loop is running; if it's closed,
+	/// the loop will end with an error.
+	///
+	/// Note that a handle which stays signaled, like a manual-reset event,
+	/// will have its closure called repeatedly.
+	///
+	/// # Panics
+	///
+	/// Panics if more than 63 handles are added.
+	#[must_use]
+	pub fn wait_handle<H, F>(mut self, handle: &H, func: F) -> Self
+		where H: Handle,
+			F: FnMut() -> AnyResult<()> + 'static,
+	{
+		if self.wait_handles.len() == 63 { // MAXIMUM_WAIT_OBJECTS - 1
+			panic!("Cannot wait for more than 63 handles.");
+		}
+		self.wait_handles.push(unsafe { HWAITABLE::from_ptr(handle.as_ptr()) });
+		self.wait_fns.push(Box::new(func));
+		self
+	}
+
+	/// Adds a handler which is called when `WM_QUIT` is received, right
+	/// before the loop returns, receiving the exit code. Handlers are called
+	/// in the order they were added, and are not called if the loop ends with
+	/// an error.
+	#[must_use]
+	pub fn on_quit<F>(mut self, func: F) -> Self
+		where F: FnMut(i32) -> AnyResult<()> + 'static,
+	{
+		self.quit_fns.push(Box::new(func));
+		self
+	}
+
+	/// Runs the message loop, blocking until `WM_QUIT` is received. Returns
+	/// the exit code passed to
+	/// [`PostQuitMessage`](crate::PostQuitMessage).
+	///
+	/// Only needed when the windows are created manually; otherwise, use
+	/// [`WindowMain::run_main_with_loop`](crate::gui::WindowMain::run_main_with_loop).
+	pub fn run(self) -> AnyResult<i32> {
+		self.run_with(None, None)
+	}
+
+	pub(in crate::gui) fn run_with(mut self,
+		haccel: Option<&HACCEL>,
+		hwnd_mdi_client: Option<&HWND>,
+	) -> AnyResult<i32>
+	{
+		let mut msg = MSG::default();
+		let mut idle_pending = !self.idle_fns.is_empty();
+
+		loop {
+			while PeekMessage(&mut msg, None, 0, 0, co::PM::REMOVE) {
+				if msg.message == co::WM::QUIT {
+					return self.quit(msg.wParam as _);
+				}
+				self.process(&mut msg, haccel, hwnd_mdi_client)?;
+				idle_pending = !self.idle_fns.is_empty();
+			}
+
+			if idle_pending {
+				idle_pending = false;
+				for idle_fn in self.idle_fns.iter_mut() {
+					if idle_fn()? {
+						idle_pending = true; // call the idle handlers again
+					}
+				}
+			}
+
+			let wait = MsgWaitForMultipleObjectsEx(
+				&self.wait_handles.iter().collect::<Vec<_>>(),
+				if idle_pending { Some(0) } else { None },
+				co::QS::ALLINPUT,
+				co::MWMO::INPUTAVAILABLE,
+			)?;
+
+			let num_handles = self.wait_handles.len() as u32;
+			if wait.0 < co::WAIT::OBJECT_0.0 + num_handles {
+				let idx = (wait.0 - co::WAIT::OBJECT_0.0) as usize;
+				self.wait_fns[idx]()?;
+			} else if wait.0 >= co::WAIT::ABANDONED.0
+				&& wait.0 < co::WAIT::ABANDONED.0 + num_handles
+			{
+				let idx = (wait.0 - co::WAIT::ABANDONED.0) as usize;
+				self.wait_fns[idx]()?; // an abandoned mutex is acquired too
+			}
+			// Otherwise, there are messages in the queue, or the idle timeout expired.
+		}
+	}
+
+	fn quit(&mut self, exit_code: i32) -> AnyResult<i32> {
+		// WM_QUIT was sent, gracefully terminate the program.
+		// wParam has the program exit code.
+		// https://learn.microsoft.com/en-us/windows/win32/winmsg/using-messages-and-message-queues
+		// PostQuitMessage() may have been called internally, so check QUIT_ERROR.
+		match unsafe { QUIT_ERROR.take() } {
+			Some(msg_err) => Err(msg_err.into()), // MsgError wrapped into AnyResult
+			None => {
+				for quit_fn in self.quit_fns.iter_mut() {
+					quit_fn(exit_code)?;
+				}
+				Ok(exit_code) // successfull exit with ret code
+			},
+		}
+	}
+
+	fn process(&mut self,
+		msg: &mut MSG,
+		haccel: Option<&HACCEL>,
+		hwnd_mdi_client: Option<&HWND>,
+	) -> AnyResult<()>
+	{
+		for filter_fn in self.filter_fns.iter_mut() {
+			if filter_fn(msg)? {
+				return Ok(()); // message consumed by user filter
+			}
+		}
+
+		// If a child window, will retrieve its top-level parent.
+		// If a top-level, use itself.
+		let hwnd_top_level = msg.hwnd.GetAncestor(co::GA::ROOT)
+			.unwrap_or(unsafe { msg.hwnd.raw_copy() });
+
+		// If we have an MDI client, try to translate the MDI child shortcuts.
+		if let Some(hwnd_mdi_client) = hwnd_mdi_client {
+			if hwnd_mdi_client.TranslateMDISysAccel(msg) {
+				return Ok(()); // message translated
+			}
+		}
+
+		// If we have an accelerator table, try to translate the message.
+		if let Some(haccel) = haccel {
+			if hwnd_top_level.TranslateAccelerator(haccel, msg).is_ok() {
+				return Ok(()); // message translated
+			}
+		}
+
+		// Try to process keyboard actions for child controls.
+		if hwnd_top_level.IsDialogMessage(msg) {
+			return Ok(());
+		}
+
+		TranslateMessage(msg);
+		unsafe { DispatchMessage(msg); }
+		Ok(())
+	}
+}
@@ -8,41 +8,94 @@
 
 #![cfg_attr(docsrs, doc(cfg(feature = "gui")))]
 
+mod about_dialog;
+mod accel_table;
 mod base;
+mod common_dialogs;
+mod dark_mode;
 mod dlg_base;
 mod dlg_control;
 mod dlg_main;
 mod dlg_modal;
 mod dlg_modeless;
+mod drop_target;
+mod edge_snap;
+mod error_report_dialog;
+mod font;
 mod gui_traits;
+mod layout;
 mod layout_arranger;
+mod menu;
+mod message_loop;
 mod msg_error;
 mod native_controls;
+mod panic_handler;
+mod popup_menu;
 mod privs;
+mod property_sheet;
 mod raw_base;
 mod raw_control;
 mod raw_main;
 mod raw_modal;
 mod raw_modeless;
+mod resizer;
+mod shell_change_notify;
+mod size_constraint;
+mod splitter;
+mod taskbar_progress;
+mod timer;
+mod tray_icon;
+mod ui_task;
+mod undo_stack;
 mod window_control;
+mod window_data;
 mod window_main;
+mod window_mdi_child;
+mod window_mdi_frame;
+mod window_message_only;
 mod window_modal;
 mod window_modeless;
+mod wizard_page;
 
 pub mod events;
 
+pub use about_dialog::{AboutDialog, AboutDialogOpts};
+pub use accel_table::AccelTable;
+pub use common_dialogs::{choose_color, choose_font, pick_folder};
+pub use dark_mode::{DarkMode, set_dark_mode};
+pub use edge_snap::{EdgeSnap, EdgeSnapOpts};
+pub use error_report_dialog::{ErrorReportDialog, ErrorReportDialogOpts};
+pub use font::{Font, FontBuilder};
+pub use layout::{Layout, LayoutAlign, LayoutBox, LayoutGrid, LayoutHints, LayoutItem};
 pub use layout_arranger::{Horz, Vert};
+pub use menu::Menu;
+pub use message_loop::MessageLoop;
 pub use msg_error::MsgError;
 pub use native_controls::*;
+pub use panic_handler::set_panic_handler;
+pub use popup_menu::PopupMenu;
+pub use property_sheet::{PropertySheet, PropertySheetOpts};
 pub use raw_base::{Brush, Cursor, Icon};
 pub use raw_control::WindowControlOpts;
 pub use raw_main::WindowMainOpts;
 pub use raw_modal::WindowModalOpts;
 pub use raw_modeless::WindowModelessOpts;
+pub use resizer::{Resizer, ResizerOpts};
+pub use shell_change_notify::{ShellChange, ShellChangeNotify, ShellChangeNotifyOpts};
+pub use size_constraint::{SizeConstraint, SizeConstraintOpts};
+pub use splitter::{Splitter, SplitterOpts, SplitterPanes};
+pub use timer::Timer;
+pub use tray_icon::{NotificationOpts, TrayIcon, TrayIconOpts};
+pub use ui_task::{BlockingTask, spawn_blocking};
+pub use undo_stack::{UndoStack, UndoStackOpts};
 pub use window_control::WindowControl;
 pub use window_main::WindowMain;
+pub use window_mdi_child::{WindowMdiChild, WindowMdiChildOpts};
+pub use window_mdi_frame::{WindowMdiFrame, WindowMdiFrameOpts};
+pub use window_message_only::{WindowMessageOnly, WindowMessageOnlyOpts};
 pub use window_modal::WindowModal;
 pub use window_modeless::WindowModeless;
+pub use wizard_page::{WizardNav, WizardPage, WizardPageOpts};
 
 pub(crate) mod traits {
 	pub use super::events::traits::*;
@@ -3,15 +3,15 @@ use std::ptr::NonNull;
 
 use crate::co;
 use crate::gui::base::Base;
+use crate::gui::dark_mode::{apply_dark_theme, is_dark};
 use crate::gui::events::{ProcessResult, WindowEvents};
+use crate::gui::panic_handler::catch_panic;
 use crate::gui::privs::post_quit_error;
 use crate::kernel::decl::{AnyResult, SysResult};
 use crate::msg::WndMsg;
 use crate::prelude::{comctl_Hwnd, Handle, user_Hwnd};
 use crate::user::decl::{AtomStr, HWND, IdMenu, POINT, SIZE};
 
-static mut BASE_SUBCLASS_ID: usize = 0;
-
 /// Variant field for child controls: creation options or just a control ID.
 pub enum OptsId<P> {
 	/// The control will be created with
@@ -96,6 +96,9 @@ impl BaseNativeControl {
 			)?;
 		}
 
+		if is_dark() {
+			apply_dark_theme(self.hwnd(), true);
+		}
 		self.install_subclass_if_needed()?;
 		Ok(())
 	}
@@ -120,64 +123,30 @@ impl BaseNativeControl {
 
 	fn install_subclass_if_needed(&self) -> SysResult<()> {
 		if !self.subclass_events.is_empty() {
-			let subclass_id = unsafe {
-				BASE_SUBCLASS_ID += 1;
-				BASE_SUBCLASS_ID
-			};
-
-			unsafe {
-				self.hwnd().SetWindowSubclass(
-					Self::subclass_proc, subclass_id,
-					self as *const _ as _, // pass pointer to self
-				)?;
-			}
+			let ptr_self = self as *const Self; // controls are pinned, so the pointer is stable
+			self.hwnd().add_subclass(0, move |_, wm_any| {
+				let ref_self = unsafe { &*ptr_self };
+				catch_panic(wm_any, || Self::subclass_proc_proc(ref_self, wm_any))
+					.unwrap_or_else(|err| { post_quit_error(wm_any, err); Some(0) })
+			})?;
 		}
 		Ok(())
 	}
 
-	extern "system" fn subclass_proc(
-		hwnd: HWND,
-		msg: co::WM,
-		wparam: usize,
-		lparam: isize,
-		subclass_id: usize,
-		ref_data: usize,
-	) -> isize
-	{
-		let wm_any = WndMsg::new(msg, wparam, lparam);
-		Self::subclass_proc_proc(hwnd, wm_any, subclass_id, ref_data)
-			.unwrap_or_else(|err| { post_quit_error(wm_any, err); 0 })
-	}
-
-	fn subclass_proc_proc(
-		hwnd: HWND,
-		wm_any: WndMsg,
-		subclass_id: usize,
-		ref_data: usize,
-	) -> AnyResult<isize>
-	{
-		let ptr_self = ref_data as *mut Self; // retrieve
+	fn subclass_proc_proc(&self, wm_any: WndMsg) -> AnyResult<Option<isize>> {
 		let mut process_result = ProcessResult::NotHandled;
-
-		if !ptr_self.is_null() {
-			let ref_self = unsafe { &mut *ptr_self };
-			if *ref_self.hwnd() != HWND::NULL {
-				process_result = ref_self.subclass_events.process_one_message(wm_any)?;
-			}
+		if *self.hwnd() != HWND::NULL {
+			process_result = self.subclass_events.process_one_message(wm_any)?;
 		}
 
 		if wm_any.msg_id == co::WM::NCDESTROY { // always check
-			hwnd.RemoveWindowSubclass(Self::subclass_proc, subclass_id)?;
-			if !ptr_self.is_null() {
-				let ref_self = unsafe { &mut *ptr_self };
-				ref_self.subclass_events.clear(); // prevents circular references
-			}
+			self.subclass_events.clear(); // prevents circular references
 		}
 
 		Ok(match process_result {
-			ProcessResult::HandledWithRet(res) => res,
-			ProcessResult::HandledWithoutRet => 0,
-			ProcessResult::NotHandled => hwnd.DefSubclassProc(wm_any).into(),
+			ProcessResult::HandledWithRet(res) => Some(res),
+			ProcessResult::HandledWithoutRet => Some(0),
+			ProcessResult::NotHandled => None, // next subclass or DefSubclassProc
 		})
 	}
 }
@@ -4,21 +4,22 @@ use std::pin::Pin;
 use std::sync::Arc;
 
 use crate::co;
+use crate::comctl::decl::{BUTTON_SPLITINFO, HIMAGELIST};
 use crate::gui::base::Base;
 use crate::gui::events::{ButtonEvents, WindowEvents};
 use crate::gui::layout_arranger::{Horz, Vert};
 use crate::gui::native_controls::base_native_control::{
 	BaseNativeControl, OptsId,
 };
-use crate::gui::privs::{auto_ctrl_id, multiply_dpi_or_dtu, ui_font};
-use crate::kernel::decl::SysResult;
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_or_dtu, ui_font_for_dpi};
+use crate::kernel::decl::{SysResult, WString};
 use crate::msg::{bm, wm};
 use crate::prelude::{
 	GuiChild, GuiChildFocus, GuiEvents, GuiNativeControl,
 	GuiNativeControlEvents, GuiParent, GuiWindow, GuiWindowText, Handle,
-	user_Hwnd,
+	NativeBitflag, user_Hwnd,
 };
-use crate::user::decl::{HWND, POINT, SIZE};
+use crate::user::decl::{BmpIcon, HWND, HwndPlace, POINT, SIZE};
 
 struct Obj { // actual fields of Button
 	base: BaseNativeControl,
@@ -183,9 +184,13 @@ impl Button {
 				)?;
 
 				self.hwnd().SendMessage(wm::SetFont {
-					hfont: unsafe { ui_font().raw_copy() },
+					hfont: ui_font_for_dpi(self.hwnd().GetDpiForWindow())?,
 					redraw: true,
 				});
+
+				if opts.auto_size {
+					self.fit_text()?; // after the font is set
+				}
 			},
 			OptsId::Dlg(ctrl_id) => self.0.base.create_dlg(*ctrl_id)?,
 		}
@@ -193,6 +198,114 @@ impl Button {
 		self.0.base.parent().add_to_layout_arranger(self.hwnd(), horz, vert)
 	}
 
+	/// Enlarges the button, if needed, so its text and image fit, using the
+	/// size given by a [`bm::GetIdealSize`](crate::msg::bm::GetIdealSize)
+	/// message.
+	///
+	/// Single-line buttons have their width increased. Multi-line buttons,
+	/// created with [`BS::MULTILINE`](crate::co::BS::MULTILINE), keep their
+	/// width, with the text wrapped, and have their height increased.
+	pub fn fit_text(&self) -> SysResult<()> {
+		let rc = self.hwnd().GetWindowRect()?;
+		let cur_sz = SIZE::new(rc.right - rc.left, rc.bottom - rc.top);
+		let is_multiline = co::BS(self.hwnd().GetWindowLongPtr(co::GWLP::STYLE) as _)
+			.has(co::BS::MULTILINE);
+
+		let mut ideal_sz = SIZE::new(if is_multiline { cur_sz.cx } else { 0 }, 0);
+		self.hwnd().SendMessage(bm::GetIdealSize { size: &mut ideal_sz })?;
+
+		let new_sz = if is_multiline {
+			SIZE::new(cur_sz.cx, cur_sz.cy.max(ideal_sz.cy))
+		} else {
+			SIZE::new(cur_sz.cx.max(ideal_sz.cx), cur_sz.cy)
+		};
+		if new_sz != cur_sz {
+			self.hwnd().SetWindowPos(HwndPlace::None, POINT::default(), new_sz,
+				co::SWP::NOMOVE | co::SWP::NOZORDER | co::SWP::NOACTIVATE)?;
+		}
+		Ok(())
+	}
+
+	/// Retrieves the size which best fits the text and image of the button by
+	/// sending a [`bm::GetIdealSize`](crate::msg::bm::GetIdealSize) message.
+	#[must_use]
+	pub fn ideal_size(&self) -> SysResult<SIZE> {
+		let mut size = SIZE::default();
+		self.hwnd().SendMessage(bm::GetIdealSize { size: &mut size })?;
+		Ok(size)
+	}
+
+	/// Sets the image displayed by the button, along with its text, by sending a
+	/// [`bm::SetImage`](crate::msg::bm::SetImage) message. Returns the previous
+	/// image, if any.
+	///
+	/// The image is not owned by the button, so it must remain valid while it's
+	/// displayed. To display the image only, create the button with
+	/// [`BS::ICON`](crate::co::BS::ICON) or
+	/// [`BS::BITMAP`](crate::co::BS::BITMAP) style.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui, BmpIcon, HICON};
+	///
+	/// let btn: gui::Button; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let btn = gui::Button::new(&wnd, gui::ButtonOpts::default());
+	///
+	/// let hicon = HICON::NULL; // loaded somewhere
+	/// btn.set_image(BmpIcon::Icon(hicon));
+	/// btn.fit_text()?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	pub fn set_image(&self, image: BmpIcon) -> Option<BmpIcon> {
+		self.hwnd().SendMessage(bm::SetImage { image }).ok() // zero means no previous image
+	}
+
+	/// Sets the drop-down state of a split button, created with
+	/// [`BS::SPLITBUTTON`](crate::co::BS::SPLITBUTTON), by sending a
+	/// [`bm::SetDropDownState`](crate::msg::bm::SetDropDownState) message.
+	///
+	/// Usually called while handling
+	/// [`bcn_drop_down`](crate::gui::events::ButtonEvents::bcn_drop_down).
+	pub fn set_drop_down_state(&self, is_pushed: bool) -> SysResult<()> {
+		self.hwnd().SendMessage(bm::SetDropDownState { is_pushed })
+	}
+
+	/// Sets the note displayed below the text of a command link button, created
+	/// with [`BS::COMMANDLINK`](crate::co::BS::COMMANDLINK), by sending a
+	/// [`bm::SetNote`](crate::msg::bm::SetNote) message.
+	pub fn set_note(&self, text: &str) -> SysResult<()> {
+		self.hwnd().SendMessage(bm::SetNote { text: WString::from_str(text) })
+	}
+
+	/// Shows or hides the UAC shield icon, which tells the action requires
+	/// elevation, by sending a [`bm::SetShield`](crate::msg::bm::SetShield)
+	/// message.
+	pub fn set_shield(&self, has_elevated_icon: bool) -> SysResult<()> {
+		self.hwnd().SendMessage(bm::SetShield { has_elevated_icon })
+	}
+
+	/// Sets the split style and the size of the drop-down area of a split button,
+	/// created with [`BS::SPLITBUTTON`](crate::co::BS::SPLITBUTTON), by sending
+	/// a [`bm::SetSplitInfo`](crate::msg::bm::SetSplitInfo) message.
+	pub fn set_split_style(&self,
+		style: co::BCSS, size: Option<SIZE>) -> SysResult<()>
+	{
+		let mut mask = co::BCSIF::STYLE;
+		if size.is_some() {
+			mask |= co::BCSIF::SIZE;
+		}
+		let info = BUTTON_SPLITINFO {
+			mask,
+			himlGlyph: HIMAGELIST::NULL,
+			uSplitStyle: style,
+			size: size.unwrap_or_default(),
+		};
+		self.hwnd().SendMessage(bm::SetSplitInfo { splitinfo: &info })
+	}
+
 	/// Fires the click event for the button by sending a
 	/// [`bm::Click`](crate::msg::bm::Click) message.
 	pub fn trigger_click(&self) {
@@ -245,6 +358,10 @@ pub struct ButtonOpts {
 	///
 	/// Suggestions:
 	/// * replace with `BS::DEFPUSHBUTTON` for the default button of the window;
+	/// * replace with `BS::SPLITBUTTON` for a button with a drop-down arrow;
+	/// * replace with `BS::COMMANDLINK` for a command link, whose note is set
+	/// with [`Button::set_note`](crate::gui::Button::set_note);
+	/// * add `BS::MULTILINE` to wrap long texts in more than one line;
 	/// * add `BS::NOTIFY` to receive notifications other than the simple click.
 	pub button_style: co::BS,
 	/// Window styles to be
@@ -258,6 +375,14 @@ pub struct ButtonOpts {
 	/// Defaults to `WS_EX::LEFT`.
 	pub window_ex_style: co::WS_EX,
 
+	/// If `true`, the button is enlarged right after its creation, if needed,
+	/// so its text fits, as done by
+	/// [`Button::fit_text`](crate::gui::Button::fit_text). Then `width` and
+	/// `height` act as the minimum size.
+	///
+	/// Defaults to `false`.
+	pub auto_size: bool,
+
 	/// The control ID.
 	///
 	/// Defaults to an auto-generated ID.
@@ -282,6 +407,7 @@ impl Default for ButtonOpts {
 			button_style: co::BS::PUSHBUTTON,
 			window_style: co::WS::CHILD | co::WS::VISIBLE | co::WS::TABSTOP | co::WS::GROUP,
 			window_ex_style: co::WS_EX::LEFT,
+			auto_size: false,
 			ctrl_id: 0,
 			horz_resize: Horz::None,
 			vert_resize: Vert::None,
@@ -11,7 +11,8 @@ use crate::gui::native_controls::base_native_control::{
 	BaseNativeControl, OptsId,
 };
 use crate::gui::privs::{
-	auto_ctrl_id, calc_text_bound_box_check, multiply_dpi_or_dtu, ui_font,
+	auto_ctrl_id, calc_text_bound_box_check, multiply_dpi_or_dtu,
+	ui_font_for_dpi,
 };
 use crate::kernel::decl::SysResult;
 use crate::msg::{bm, wm};
@@ -189,7 +190,7 @@ impl CheckBox {
 				)?;
 
 				self.hwnd().SendMessage(wm::SetFont {
-					hfont: unsafe { ui_font().raw_copy() },
+					hfont: ui_font_for_dpi(self.hwnd().GetDpiForWindow())?,
 					redraw: true,
 				});
 				if opts.check_state != CheckState::Unchecked {
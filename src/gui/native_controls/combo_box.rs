@@ -11,7 +11,7 @@ use crate::gui::native_controls::base_native_control::{
 	BaseNativeControl, OptsId,
 };
 use crate::gui::native_controls::combo_box_items::ComboBoxItems;
-use crate::gui::privs::{auto_ctrl_id, multiply_dpi_or_dtu, ui_font};
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_or_dtu, ui_font_for_dpi};
 use crate::kernel::decl::SysResult;
 use crate::msg::wm;
 use crate::prelude::{
@@ -193,7 +193,7 @@ impl ComboBox {
 				)?;
 
 				self.hwnd().SendMessage(wm::SetFont {
-					hfont: unsafe { ui_font().raw_copy() },
+					hfont: ui_font_for_dpi(self.hwnd().GetDpiForWindow())?,
 					redraw: true,
 				});
 				self.items().add(&opts.items);
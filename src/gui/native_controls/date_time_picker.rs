@@ -10,7 +10,7 @@ use crate::gui::layout_arranger::{Horz, Vert};
 use crate::gui::native_controls::base_native_control::{
 	BaseNativeControl, OptsId,
 };
-use crate::gui::privs::{auto_ctrl_id, multiply_dpi_or_dtu, ui_font};
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_or_dtu, ui_font_for_dpi};
 use crate::kernel::decl::{SysResult, SYSTEMTIME};
 use crate::msg::{dtm, wm};
 use crate::prelude::{
@@ -178,7 +178,7 @@ impl DateTimePicker {
 				}
 
 				self.hwnd().SendMessage(wm::SetFont {
-					hfont: unsafe { ui_font().raw_copy() },
+					hfont: ui_font_for_dpi(self.hwnd().GetDpiForWindow())?,
 					redraw: true,
 				});
 			},
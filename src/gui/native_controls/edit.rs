@@ -11,7 +11,7 @@ use crate::gui::layout_arranger::{Horz, Vert};
 use crate::gui::native_controls::base_native_control::{
 	BaseNativeControl, OptsId,
 };
-use crate::gui::privs::{auto_ctrl_id, multiply_dpi_or_dtu, ui_font};
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_or_dtu, ui_font_for_dpi};
 use crate::kernel::decl::{SysResult, WString};
 use crate::msg::{em, wm};
 use crate::prelude::{
@@ -184,7 +184,7 @@ impl Edit {
 				)?;
 
 				self.hwnd().SendMessage(wm::SetFont {
-					hfont: unsafe { ui_font().raw_copy() },
+					hfont: ui_font_for_dpi(self.hwnd().GetDpiForWindow())?,
 					redraw: true,
 				});
 			},
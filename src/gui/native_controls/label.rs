@@ -11,7 +11,7 @@ use crate::gui::native_controls::base_native_control::{
 	BaseNativeControl, OptsId,
 };
 use crate::gui::privs::{
-	auto_ctrl_id, calc_text_bound_box, multiply_dpi_or_dtu, ui_font,
+	auto_ctrl_id, calc_text_bound_box, multiply_dpi_or_dtu, ui_font_for_dpi,
 };
 use crate::kernel::decl::SysResult;
 use crate::msg::wm;
@@ -171,7 +171,7 @@ impl Label {
 				)?;
 
 				self.hwnd().SendMessage(wm::SetFont {
-					hfont: unsafe { ui_font().raw_copy() },
+					hfont: ui_font_for_dpi(self.hwnd().GetDpiForWindow())?,
 					redraw: true,
 				});
 			},
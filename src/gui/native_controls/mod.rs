@@ -20,9 +20,11 @@ mod radio_button;
 mod radio_group;
 mod status_bar_parts;
 mod status_bar;
+mod sys_link;
 mod tab_item;
 mod tab_items;
 mod tab;
+mod tooltip;
 mod trackbar;
 mod tree_view_item;
 mod tree_view_items;
@@ -42,7 +44,9 @@ pub use progress_bar::{ProgressBar, ProgressBarOpts};
 pub use radio_button::{RadioButton, RadioButtonOpts};
 pub use radio_group::RadioGroup;
 pub use status_bar::{StatusBar, StatusBarPart};
+pub use sys_link::{SysLink, SysLinkOpts};
 pub use tab::{Tab, TabOpts};
+pub use tooltip::{Tooltip, TooltipOpts};
 pub use trackbar::{Trackbar, TrackbarOpts};
 pub use tree_view::{TreeView, TreeViewOpts};
 pub use up_down::{UpDown, UpDownOpts};
@@ -8,7 +8,8 @@ use crate::gui::native_controls::base_native_control::{
 	BaseNativeControl, OptsId,
 };
 use crate::gui::privs::{
-	auto_ctrl_id, calc_text_bound_box_check, multiply_dpi_or_dtu, ui_font,
+	auto_ctrl_id, calc_text_bound_box_check, multiply_dpi_or_dtu,
+	ui_font_for_dpi,
 };
 use crate::kernel::decl::SysResult;
 use crate::msg::{bm, wm};
@@ -124,7 +125,7 @@ impl RadioButton {
 				)?;
 
 				self.hwnd().SendMessage(wm::SetFont {
-					hfont: unsafe { ui_font().raw_copy() },
+					hfont: ui_font_for_dpi(self.hwnd().GetDpiForWindow())?,
 					redraw: true,
 				});
 				if opts.selected { self.select(true); }
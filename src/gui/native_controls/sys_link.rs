@@ -0,0 +1,357 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::comctl::decl::{INITCOMMONCONTROLSEX, InitCommonControlsEx, LITEM};
+use crate::gui::base::Base;
+use crate::gui::events::{SysLinkEvents, WindowEvents};
+use crate::gui::layout_arranger::{Horz, Vert};
+use crate::gui::native_controls::base_native_control::{
+	BaseNativeControl, OptsId,
+};
+use crate::gui::privs::{auto_ctrl_id, multiply_dpi_or_dtu, ui_font_for_dpi};
+use crate::kernel::decl::{AnyResult, SysResult};
+use crate::msg::{lm, wm};
+use crate::prelude::{
+	GuiChild, GuiEvents, GuiNativeControl, GuiNativeControlEvents, GuiParent,
+	GuiWindow, GuiWindowText, Handle, user_Hwnd,
+};
+use crate::user::decl::{HWND, HwndPlace, POINT, SIZE};
+
+struct Obj { // actual fields of SysLink
+	base: BaseNativeControl,
+	opts_id: OptsId<SysLinkOpts>,
+	events: SysLinkEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [SysLink](https://learn.microsoft.com/en-us/windows/win32/controls/syslink-overview)
+/// control.
+///
+/// The text of the control may contain `<a>` markup, which will be parsed by
+/// the control and rendered as clickable hyperlinks:
+///
+/// ```text
+/// Visit <a href="https://github.com/rodrigocfd/winsafe">WinSafe</a> repository.
+/// ```
+///
+/// Note that the SysLink control requires ComCtl32.dll version 6, so your
+/// application must embed a manifest which enables it.
+#[derive(Clone)]
+pub struct SysLink(Pin<Arc<Obj>>);
+
+unsafe impl Send for SysLink {}
+
+impl GuiWindow for SysLink {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiWindowText for SysLink {}
+
+impl GuiChild for SysLink {
+	fn ctrl_id(&self) -> u16 {
+		match &self.0.opts_id {
+			OptsId::Wnd(opts) => opts.ctrl_id,
+			OptsId::Dlg(ctrl_id) => *ctrl_id,
+		}
+	}
+}
+
+impl GuiNativeControl for SysLink {
+	fn on_subclass(&self) -> &WindowEvents {
+		self.0.base.on_subclass()
+	}
+}
+
+impl GuiNativeControlEvents<SysLinkEvents> for SysLink {
+	fn on(&self) -> &SysLinkEvents {
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot add events after the control creation.");
+		} else if *self.0.base.parent().hwnd() != HWND::NULL {
+			panic!("Cannot add events after the parent window creation.");
+		}
+		&self.0.events
+	}
+}
+
+impl SysLink {
+	/// Instantiates a new `SysLink` object, to be created on the parent window
+	/// with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `SysLink` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: SysLinkOpts) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+		let opts = SysLinkOpts::define_ctrl_id(opts);
+		let (ctrl_id, horz, vert) = (opts.ctrl_id, opts.horz_resize, opts.vert_resize);
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent_ref),
+					opts_id: OptsId::Wnd(opts),
+					events: SysLinkEvents::new(parent_ref, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(parent_ref.creation_msg(), move |_| {
+			self2.create(horz, vert)?;
+			Ok(None) // not meaningful
+		});
+
+		new_self
+	}
+
+	/// Instantiates a new `SysLink` object, to be loaded from a dialog
+	/// resource with
+	/// [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create a `SysLink` in an event closure.
+	#[must_use]
+	pub fn new_dlg(
+		parent: &impl GuiParent,
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+	) -> Self
+	{
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent_ref),
+					opts_id: OptsId::Dlg(ctrl_id),
+					events: SysLinkEvents::new(parent_ref, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm_init_dialog(move |_| {
+			self2.create(resize_behavior.0, resize_behavior.1)?;
+			Ok(true) // not meaningful
+		});
+
+		new_self
+	}
+
+	fn create(&self, horz: Horz, vert: Vert) -> SysResult<()> {
+		match &self.0.opts_id {
+			OptsId::Wnd(opts) => {
+				let mut icce = INITCOMMONCONTROLSEX::default();
+				icce.icc = co::ICC::LINK_CLASS;
+				InitCommonControlsEx(&icce)?; // SysLink class is not registered by InitCommonControls()
+
+				let mut pos = POINT::new(opts.position.0, opts.position.1);
+				let mut sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
+				let fit_text = sz.cx == -1 && sz.cy == -1;
+				if fit_text {
+					multiply_dpi_or_dtu(self.0.base.parent(), Some(&mut pos), None)?;
+					sz = SIZE::default(); // will be resized after the font is set
+				} else {
+					multiply_dpi_or_dtu(
+						self.0.base.parent(), Some(&mut pos), Some(&mut sz))?;
+				}
+
+				self.0.base.create_window(
+					"SysLink", Some(&opts.text), pos, sz,
+					opts.ctrl_id,
+					opts.window_ex_style,
+					opts.window_style | opts.sys_link_style.into(),
+				)?;
+
+				self.hwnd().SendMessage(wm::SetFont {
+					hfont: ui_font_for_dpi(self.hwnd().GetDpiForWindow())?,
+					redraw: true,
+				});
+
+				if fit_text {
+					self.resize_to_fit()?;
+				}
+			},
+			OptsId::Dlg(ctrl_id) => self.0.base.create_dlg(*ctrl_id)?,
+		}
+
+		self.0.base.parent().add_to_layout_arranger(self.hwnd(), horz, vert)
+	}
+
+	fn resize_to_fit(&self) -> SysResult<()> {
+		let mut ideal_sz = SIZE::default();
+		self.hwnd().SendMessage(lm::GetIdealSize {
+			max_width: i32::MAX as _,
+			size: &mut ideal_sz,
+		});
+		self.hwnd().SetWindowPos(
+			HwndPlace::None, POINT::default(), ideal_sz,
+			co::SWP::NOZORDER | co::SWP::NOMOVE)
+	}
+
+	/// Adds a handler to [`NM_CLICK`](crate::gui::events::SysLinkEvents::nm_click)
+	/// notification, which receives the URL of the clicked link, as declared in
+	/// the `href` attribute of the `<a>` tag.
+	///
+	/// This notification is also sent when the user presses Enter on a focused
+	/// link.
+	///
+	/// # Panics
+	///
+	/// Panics if the control or the parent window are already created. Events
+	/// must be set before control and parent window creation.
+	///
+	/// # Examples
+	///
+	/// Opening the clicked link in the default browser with
+	/// [`HWND::ShellExecute`](crate::prelude::shell_Hwnd::ShellExecute):
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui, HWND};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// let link: gui::SysLink;
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let link = gui::SysLink::new(&wnd, gui::SysLinkOpts::default());
+	///
+	/// link.on_click({
+	///     let wnd = wnd.clone();
+	///     move |url| {
+	///         wnd.hwnd().ShellExecute("open", url, None, None, co::SW::SHOWNORMAL)
+	///             .map_err(|e| e.to_string())?;
+	///         Ok(())
+	///     }
+	/// });
+	/// ```
+	pub fn on_click<F>(&self, func: F)
+		where F: Fn(&str) -> AnyResult<()> + 'static,
+	{
+		self.on().nm_click(move |p| func(&p.item.szUrl()));
+	}
+
+	/// Calls [`set_text`](crate::prelude::GuiWindowText::set_text) and resizes
+	/// the control to exactly fit the new text, which may contain `<a>` markup.
+	pub fn set_text_and_resize(&self, text: &str) {
+		self.set_text(text);
+		self.resize_to_fit().unwrap();
+	}
+
+	/// Retrieves the ID and the URL of the link at the given index by sending
+	/// an [`lm::GetItem`](crate::msg::lm::GetItem) message.
+	///
+	/// The ID is declared in the `id` attribute of the `<a>` tag, and the URL
+	/// in the `href` attribute.
+	pub fn link(&self, index: u32) -> SysResult<(String, String)> {
+		let mut li = LITEM::default();
+		li.mask = co::LIF::ITEMINDEX | co::LIF::ITEMID | co::LIF::URL;
+		li.iLink = index as _;
+
+		self.hwnd().SendMessage(lm::GetItem { item: &mut li })?;
+		Ok((li.szID(), li.szUrl()))
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`SysLink`](crate::gui::SysLink) programmatically with
+/// [`SysLink::new`](crate::gui::SysLink::new).
+pub struct SysLinkOpts {
+	/// Text of the control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw),
+	/// which may contain `<a>` markup.
+	///
+	/// Defaults to `<a>X</a>`.
+	pub text: String,
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to the size needed to fit the text.
+	pub size: (u32, u32),
+	/// SysLink styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `LWS::TRANSPARENT`.
+	pub sys_link_style: co::LWS,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE | WS::TABSTOP | WS::GROUP`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal behavior when the parent is resized.
+	///
+	/// Defaults to `Horz::None`.
+	pub horz_resize: Horz,
+	/// Vertical behavior when the parent is resized.
+	///
+	/// Defaults to `Vert::None`.
+	pub vert_resize: Vert,
+}
+
+impl Default for SysLinkOpts {
+	fn default() -> Self {
+		Self {
+			text: "<a>X</a>".to_owned(),
+			position: (0, 0),
+			size: (-1i32 as _, -1i32 as _), // will resize to fit the text
+			sys_link_style: co::LWS::TRANSPARENT,
+			window_style: co::WS::CHILD | co::WS::VISIBLE | co::WS::TABSTOP | co::WS::GROUP,
+			window_ex_style: co::WS_EX::LEFT,
+			ctrl_id: 0,
+			horz_resize: Horz::None,
+			vert_resize: Vert::None,
+		}
+	}
+}
+
+impl SysLinkOpts {
+	fn define_ctrl_id(mut self) -> Self {
+		if self.ctrl_id == 0 {
+			self.ctrl_id = auto_ctrl_id();
+		}
+		self
+	}
+}
@@ -0,0 +1,261 @@
+use std::any::Any;
+use std::cell::UnsafeCell;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::comctl::decl::TTTOOLINFO;
+use crate::gui::base::Base;
+use crate::gui::native_controls::base_native_control::BaseNativeControl;
+use crate::kernel::decl::{SysResult, WString};
+use crate::msg::ttm;
+use crate::prelude::{GuiEvents, GuiParent, GuiWindow, Handle, user_Hwnd};
+use crate::user::decl::{HWND, POINT, SIZE};
+
+/// Tool ID of the single tracking tool, which is not attached to any control.
+/// Control tools use their `HWND` as ID, so this value never clashes.
+const TRACKING_TOOL_ID: usize = 0;
+
+struct Obj { // actual fields of Tooltip
+	base: BaseNativeControl,
+	opts: TooltipOpts,
+	pending_tips: UnsafeCell<Vec<(Box<dyn GuiWindow>, String)>>, // tips set before creation
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [tooltip](https://learn.microsoft.com/en-us/windows/win32/controls/tooltip-controls)
+/// control.
+///
+/// A single `Tooltip` object manages the tips of any number of controls within
+/// the same parent window.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// let btn: gui::Button;
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// # let btn = gui::Button::new(&wnd, gui::ButtonOpts::default());
+///
+/// let tips = gui::Tooltip::new(&wnd, gui::TooltipOpts::default());
+/// tips.set_tip(&btn, "Click here to save the file.\nThe file will be overwritten.");
+/// ```
+#[derive(Clone)]
+pub struct Tooltip(Pin<Arc<Obj>>);
+
+unsafe impl Send for Tooltip {}
+
+impl GuiWindow for Tooltip {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl Tooltip {
+	/// Instantiates a new `Tooltip` object, to be created along with the
+	/// parent window with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Tooltip` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: TooltipOpts) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent_ref),
+					opts,
+					pending_tips: UnsafeCell::new(Vec::new()),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(parent_ref.creation_msg(), move |_| {
+			self2.create()?;
+			Ok(None) // not meaningful
+		});
+
+		new_self
+	}
+
+	fn create(&self) -> SysResult<()> {
+		let opts = &self.0.opts;
+
+		self.0.base.create_window(
+			"tooltips_class32", None, POINT::default(), SIZE::default(),
+			0, // popup windows have no control ID
+			opts.window_ex_style,
+			co::WS::POPUP | opts.tooltip_style.into(),
+		)?;
+
+		if opts.max_width > 0 { // multiline text is enabled only with a max width
+			self.hwnd().SendMessage(ttm::SetMaxTipWidth {
+				width: Some(opts.max_width),
+			});
+		}
+
+		let pending_tips = unsafe { &mut *self.0.pending_tips.get() };
+		for (ctrl, text) in pending_tips.drain(..) {
+			self.add_tool(ctrl.hwnd(), text.as_str())?;
+		}
+		Ok(())
+	}
+
+	fn add_tool(&self, hctrl: &HWND, text: &str) -> SysResult<()> {
+		let mut wtext = WString::from_str(text);
+		let mut ti = self.tool_info(co::TTF::IDISHWND | co::TTF::SUBCLASS,
+			hctrl.as_ptr() as _);
+		ti.set_lpszText(Some(&mut wtext));
+
+		self.hwnd().SendMessage(ttm::DelTool { info: &ti }); // replace any previous tip
+		self.hwnd().SendMessage(ttm::AddTool { info: &ti })
+	}
+
+	fn tool_info<'a>(&self, flags: co::TTF, id: usize) -> TTTOOLINFO<'a> {
+		let mut ti = TTTOOLINFO::default();
+		ti.uFlags = flags;
+		ti.hwnd = unsafe { self.0.base.parent().hwnd().raw_copy() };
+		ti.uId = id;
+		ti
+	}
+
+	/// Sets the tip text which will be displayed when the mouse hovers the
+	/// given control. If the control already has a tip, it will be replaced.
+	///
+	/// Line breaks can be inserted with `\n`, as long as
+	/// [`TooltipOpts::max_width`](crate::gui::TooltipOpts::max_width) is not
+	/// zero.
+	///
+	/// This method can be called before the parent window is created, in which
+	/// case the tip will be set right after the creation.
+	pub fn set_tip<C>(&self, ctrl: &C, text: &str)
+		where C: GuiWindow + Clone + 'static,
+	{
+		if *self.hwnd() == HWND::NULL {
+			let pending_tips = unsafe { &mut *self.0.pending_tips.get() };
+			pending_tips.push((Box::new(ctrl.clone()), text.to_owned()));
+		} else {
+			self.add_tool(ctrl.hwnd(), text).unwrap();
+		}
+	}
+
+	/// Removes the tip previously set to the given control with
+	/// [`set_tip`](crate::gui::Tooltip::set_tip).
+	///
+	/// # Panics
+	///
+	/// Panics if the tooltip is not created yet.
+	pub fn remove_tip(&self, ctrl: &impl GuiWindow) {
+		let ti = self.tool_info(co::TTF::IDISHWND, ctrl.hwnd().as_ptr() as _);
+		self.hwnd().SendMessage(ttm::DelTool { info: &ti });
+	}
+
+	/// Sets the title and the icon of the tooltip, which are displayed above
+	/// the tip text. An empty title removes both.
+	///
+	/// # Panics
+	///
+	/// Panics if the tooltip is not created yet.
+	pub fn set_title(&self, icon: co::TTI, title: &str) {
+		self.hwnd().SendMessage(ttm::SetTitle {
+			icon,
+			title: WString::from_str(title),
+		}).unwrap();
+	}
+
+	/// Displays a tracking tip with the given text at the given position, in
+	/// screen coordinates. The tip stays visible until
+	/// [`track_hide`](crate::gui::Tooltip::track_hide) is called.
+	///
+	/// Tracking tips are not attached to any control, and are useful to
+	/// display arbitrary information, like the value being dragged.
+	///
+	/// # Panics
+	///
+	/// Panics if the tooltip is not created yet.
+	pub fn track_show(&self, text: &str, screen_pos: POINT) {
+		let mut wtext = WString::from_str(text);
+		let mut ti = self.tool_info(co::TTF::TRACK | co::TTF::ABSOLUTE,
+			TRACKING_TOOL_ID);
+		ti.set_lpszText(Some(&mut wtext));
+
+		let mut ti_cur = self.tool_info(co::TTF::TRACK | co::TTF::ABSOLUTE,
+			TRACKING_TOOL_ID);
+		if self.hwnd().SendMessage(ttm::GetToolInfo { info: &mut ti_cur }).is_ok() {
+			self.hwnd().SendMessage(ttm::UpdateTipText { info: &ti }); // tool already added
+		} else {
+			self.hwnd().SendMessage(ttm::AddTool { info: &ti }).unwrap();
+		}
+
+		self.hwnd().SendMessage(ttm::TrackPosition {
+			x: screen_pos.x as _,
+			y: screen_pos.y as _,
+		});
+		self.hwnd().SendMessage(ttm::TrackActivate { activate: true, info: &ti });
+	}
+
+	/// Hides the tracking tip displayed with
+	/// [`track_show`](crate::gui::Tooltip::track_show).
+	///
+	/// # Panics
+	///
+	/// Panics if the tooltip is not created yet.
+	pub fn track_hide(&self) {
+		let ti = self.tool_info(co::TTF::TRACK | co::TTF::ABSOLUTE,
+			TRACKING_TOOL_ID);
+		self.hwnd().SendMessage(ttm::TrackActivate { activate: false, info: &ti });
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`Tooltip`](crate::gui::Tooltip) programmatically with
+/// [`Tooltip::new`](crate::gui::Tooltip::new).
+pub struct TooltipOpts {
+	/// Maximum width of the tip, in pixels. Text wider than that will be
+	/// broken into multiple lines; explicit line breaks with `\n` are also
+	/// respected. If zero, the text is always displayed in a single line.
+	///
+	/// Defaults to `300`.
+	pub max_width: u32,
+	/// Tooltip styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Use [`TTS::BALLOON`](crate::co::TTS::BALLOON) to display the tips as
+	/// balloons.
+	///
+	/// Defaults to `TTS::ALWAYSTIP | TTS::NOPREFIX`.
+	pub tooltip_style: co::TTS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::TOPMOST`.
+	pub window_ex_style: co::WS_EX,
+}
+
+impl Default for TooltipOpts {
+	fn default() -> Self {
+		Self {
+			max_width: 300,
+			tooltip_style: co::TTS::ALWAYSTIP | co::TTS::NOPREFIX,
+			window_ex_style: co::WS_EX::TOPMOST,
+		}
+	}
+}
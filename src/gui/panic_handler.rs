@@ -0,0 +1,156 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::AssertUnwindSafe;
+use std::rc::Rc;
+
+use crate::gui::msg_error::MsgError;
+use crate::kernel::decl::AnyResult;
+use crate::msg::WndMsg;
+
+type PanicHandler = Rc<dyn Fn(&MsgError) -> bool>;
+
+thread_local! {
+	// Closure called when a panic is caught in an event closure.
+	static PANIC_HANDLER: RefCell<Option<PanicHandler>> = RefCell::new(None);
+}
+
+/// Sets the closure to be called when a panic happens inside an event
+/// closure – or any other closure called by a window procedure.
+///
+/// A panic cannot unwind across the window procedure, because it's called by
+/// the system, so the library catches it there. Then the handler is called
+/// with a [`MsgError`](crate::gui::MsgError) describing the panic, and it can
+/// show a message box, write a log, etc. If the handler returns `true`, the
+/// main loop is terminated, returning the error, and the windows are
+/// destroyed; if it returns `false`, the message is considered processed, and
+/// the program goes on.
+///
+/// If no handler is set, the default behavior is to terminate the main loop,
+/// like when an event closure returns an error.
+///
+/// The handler belongs to the thread which sets it, so it must be set in the
+/// UI thread, before running the main loop. Windows running in other threads
+/// use the handler set in their own thread, if any.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// gui::set_panic_handler(|err: &gui::MsgError| -> bool {
+///     eprintln!("Panic caught: {}", err);
+///     false // keep running
+/// });
+/// ```
+pub fn set_panic_handler<F>(func: F)
+	where F: Fn(&MsgError) -> bool + 'static,
+{
+	PANIC_HANDLER.with(|h| *h.borrow_mut() = Some(Rc::new(func)));
+}
+
+/// Runs the closure, catching any panic. If the panic handler decides to
+/// terminate, the panic is returned as an ordinary error; otherwise returns
+/// the default value.
+pub(in crate::gui) fn catch_panic<F, T>(src_msg: WndMsg, func: F) -> AnyResult<T>
+	where F: FnOnce() -> AnyResult<T>,
+		T: Default,
+{
+	match std::panic::catch_unwind(AssertUnwindSafe(func)) {
+		Ok(res) => res,
+		Err(payload) => {
+			let text = format!("Panic in event closure: {}", payload_text(&*payload));
+			// Cloned, so the handler stays alive even if it replaces itself.
+			let handler = PANIC_HANDLER.with(|h| h.borrow().clone());
+			let quit = match handler {
+				Some(handler) => handler(&MsgError::new(src_msg, text.clone().into())),
+				None => true,
+			};
+			if quit { Err(text.into()) } else { Ok(T::default()) }
+		},
+	}
+}
+
+/// Extracts the panic message, which is usually a `&str` or a `String`.
+fn payload_text(payload: &(dyn Any + Send)) -> &str {
+	if let Some(s) = payload.downcast_ref::<&str>() {
+		s
+	} else if let Some(s) = payload.downcast_ref::<String>() {
+		s
+	} else {
+		"unknown panic payload"
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::cell::Cell;
+
+	use super::*;
+	use crate::co;
+
+	fn src_msg() -> WndMsg {
+		WndMsg::new(co::WM::COMMAND, 1, 2)
+	}
+
+	#[test]
+	fn no_panic() {
+		set_panic_handler(|_| panic!("Handler called without a panic."));
+		assert_eq!(catch_panic(src_msg(), || Ok(42)).unwrap(), 42);
+		assert!(catch_panic::<_, i32>(src_msg(), || Err("err".into())).is_err());
+	}
+
+	#[test]
+	fn handler_invoked() {
+		let texts = Rc::new(RefCell::new(Vec::<String>::new()));
+		let texts2 = texts.clone();
+		set_panic_handler(move |err| {
+			assert_eq!(err.src_msg().msg_id, co::WM::COMMAND);
+			texts2.borrow_mut().push(err.to_string());
+			false
+		});
+
+		catch_panic::<_, ()>(src_msg(), || panic!("static text")).unwrap();
+		catch_panic::<_, ()>(src_msg(), || panic!("formatted {}", 10)).unwrap();
+
+		let texts = texts.borrow();
+		assert_eq!(texts.len(), 2);
+		assert!(texts[0].contains("static text"));
+		assert!(texts[1].contains("formatted 10"));
+	}
+
+	#[test]
+	fn handler_return_honored() {
+		let quit = Rc::new(Cell::new(false));
+		let quit2 = quit.clone();
+		set_panic_handler(move |_| quit2.get());
+
+		assert_eq!(catch_panic(src_msg(), || -> AnyResult<i32> { panic!("keep") }).unwrap(), 0);
+
+		quit.set(true);
+		let err = catch_panic(src_msg(), || -> AnyResult<i32> { panic!("quit") }).unwrap_err();
+		assert!(err.to_string().contains("quit"));
+	}
+
+	#[test]
+	fn default_quits() {
+		let err = catch_panic(src_msg(), || -> AnyResult<()> { panic!("no handler") }).unwrap_err();
+		assert!(err.to_string().contains("no handler"));
+	}
+
+	#[test]
+	fn subclass_proc_panic() {
+		// A native control subclass returns None for messages it didn't
+		// handle, which go on to DefSubclassProc; so does a kept panic.
+		set_panic_handler(|_| false);
+		let ret = catch_panic(src_msg(), || -> AnyResult<Option<isize>> {
+			panic!("in subclass")
+		}).unwrap();
+		assert_eq!(ret, None);
+
+		set_panic_handler(|_| true);
+		assert!(catch_panic(src_msg(), || -> AnyResult<Option<isize>> {
+			panic!("in subclass")
+		}).is_err());
+	}
+}
@@ -0,0 +1,159 @@
+use crate::co;
+use crate::kernel::decl::{AnyResult, SysResult};
+use crate::msg::wm;
+use crate::prelude::{GuiWindow, user_Hmenu, user_Hwnd};
+use crate::user::decl::{GetCursorPos, HMENU, HWND, MenuEnum, POINT};
+
+enum Entry {
+	Item {
+		text: String,
+		func: Box<dyn Fn() -> AnyResult<()>>,
+	},
+	Separator,
+	Submenu {
+		text: String,
+		menu: PopupMenu,
+	},
+}
+
+//------------------------------------------------------------------------------
+
+/// A context menu, whose items are mapped to closures.
+///
+/// The menu is created each time it's shown, with
+/// [`TrackPopupMenu`](crate::prelude::user_Hmenu::TrackPopupMenu) returning
+/// the chosen item, whose closure is then called – so no
+/// [`WM_COMMAND`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-command)
+/// messages are involved, and the menu can be built and shown at any time.
+///
+/// # Examples
+///
+/// Showing a context menu when the window is right-clicked:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let menu = gui::PopupMenu::new()
+///     .add_item("&Copy", move || {
+///         println!("Copy clicked.");
+///         Ok(())
+///     })
+///     .add_separator()
+///     .add_submenu("&More", gui::PopupMenu::new()
+///         .add_item("&Properties", move || {
+///             println!("Properties clicked.");
+///             Ok(())
+///         }),
+///     );
+///
+/// let wnd2 = wnd.clone();
+/// wnd.on().wm_context_menu(move || {
+///     menu.show(&wnd2)?;
+///     Ok(())
+/// });
+/// ```
+#[derive(Default)]
+pub struct PopupMenu {
+	entries: Vec<Entry>,
+}
+
+impl PopupMenu {
+	/// Creates a new, empty popup menu.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds an item, whose closure is called when the item is chosen.
+	#[must_use]
+	pub fn add_item<F>(mut self, text: &str, func: F) -> Self
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		self.entries.push(Entry::Item {
+			text: text.to_owned(),
+			func: Box::new(func),
+		});
+		self
+	}
+
+	/// Adds a separator.
+	#[must_use]
+	pub fn add_separator(mut self) -> Self {
+		self.entries.push(Entry::Separator);
+		self
+	}
+
+	/// Adds a submenu.
+	#[must_use]
+	pub fn add_submenu(mut self, text: &str, submenu: PopupMenu) -> Self {
+		self.entries.push(Entry::Submenu {
+			text: text.to_owned(),
+			menu: submenu,
+		});
+		self
+	}
+
+	/// Shows the menu at the current mouse cursor position, then calls the
+	/// closure of the chosen item, if any.
+	///
+	/// This method will block until the menu disappears.
+	pub fn show(&self, owner: &impl GuiWindow) -> AnyResult<()> {
+		self.show_at(owner, GetCursorPos()?)
+	}
+
+	/// Shows the menu at the given position, in screen coordinates, then calls
+	/// the closure of the chosen item, if any.
+	///
+	/// This method will block until the menu disappears.
+	pub fn show_at(&self, owner: &impl GuiWindow, pos: POINT) -> AnyResult<()> {
+		self.show_at_hwnd(owner.hwnd(), pos)
+	}
+
+	pub(in crate::gui) fn show_at_hwnd(&self,
+		hwnd_owner: &HWND, pos: POINT) -> AnyResult<()>
+	{
+		let mut funcs = Vec::<&dyn Fn() -> AnyResult<()>>::new();
+		let mut hmenu = HMENU::CreatePopupMenu()?;
+		let chosen = self.build(&hmenu, &mut funcs)
+			.and_then(|_| Self::track(&hmenu, hwnd_owner, pos));
+		hmenu.DestroyMenu()?; // also destroys the submenus
+
+		match chosen? {
+			Some(cmd_id) => funcs[cmd_id as usize - 1](), // IDs are 1-based indexes
+			None => Ok(()), // menu was cancelled
+		}
+	}
+
+	fn build<'a>(&'a self,
+		hmenu: &HMENU, funcs: &mut Vec<&'a dyn Fn() -> AnyResult<()>>) -> SysResult<()>
+	{
+		for entry in self.entries.iter() {
+			match entry {
+				Entry::Item { text, func } => {
+					funcs.push(func.as_ref());
+					hmenu.AppendMenuEnum(&[MenuEnum::Entry(funcs.len() as _, text)])?;
+				},
+				Entry::Separator => {
+					hmenu.AppendMenuEnum(&[MenuEnum::Separator])?;
+				},
+				Entry::Submenu { text, menu } => {
+					let hsubmenu = HMENU::CreatePopupMenu()?;
+					hmenu.AppendMenuEnum(&[MenuEnum::Submenu(&hsubmenu, text)])?; // now owned by the parent menu
+					menu.build(&hsubmenu, funcs)?;
+				},
+			}
+		}
+		Ok(())
+	}
+
+	fn track(hmenu: &HMENU, hwnd_owner: &HWND, pos: POINT) -> SysResult<Option<i32>> {
+		hwnd_owner.SetForegroundWindow();
+		let chosen = hmenu.TrackPopupMenu(
+			co::TPM::RETURNCMD | co::TPM::RIGHTBUTTON, pos, hwnd_owner)?;
+		hwnd_owner.PostMessage(wm::Null {})?; // necessary according to TrackPopupMenu docs
+		Ok(chosen)
+	}
+}
@@ -1,9 +1,11 @@
 //! Global objects used within `gui` module.
 
+use std::cell::Cell;
 use std::error::Error;
+use std::ptr::{addr_of, addr_of_mut};
 
 use crate::co;
-use crate::gdi::decl::{HFONT, NONCLIENTMETRICS};
+use crate::gdi::decl::{HFONT, LOGFONT, NONCLIENTMETRICS};
 use crate::gdi::guard::DeleteObjectGuard;
 use crate::gui::base::Base;
 use crate::gui::msg_error::MsgError;
@@ -14,7 +16,8 @@ use crate::prelude::{
 	uxtheme_Hwnd,
 };
 use crate::user::decl::{
-	GetSystemMetrics, HWND, POINT, PostQuitMessage, RECT, SIZE,
+	GetDpiForSystem, GetSystemMetrics, HWND, POINT, PostQuitMessage, RECT,
+	SetProcessDPIAware, SetProcessDpiAwarenessContext, SIZE,
 	SystemParametersInfo,
 };
 use crate::uxtheme::decl::{IsAppThemed, IsThemeActive};
@@ -27,7 +30,7 @@ pub(in crate::gui) static mut QUIT_ERROR: Option<MsgError> = None;
 pub(in crate::gui) fn post_quit_error(
 	src_msg: WndMsg, err: Box<dyn Error + Send + Sync>)
 {
-	unsafe { QUIT_ERROR = Some(MsgError::new(src_msg, err)); } // store the error, so Base::run_main_loop() can grab it
+	unsafe { QUIT_ERROR = Some(MsgError::new(src_msg, err)); } // store the error, so MessageLoop can grab it
 	PostQuitMessage(-1); // this -1 will be discarded in the main loop, anyway
 }
 
@@ -36,8 +39,12 @@ pub(in crate::gui) fn post_quit_error(
 /// Global UI font object.
 static mut UI_HFONT: Option<DeleteObjectGuard<HFONT>> = None;
 
-/// Creates the global UI font object.
-pub(in crate::gui) fn create_ui_font() -> SysResult<()> {
+/// UI font objects scaled to DPIs other than the system one, created as
+/// windows are moved across monitors.
+static mut UI_HFONTS_DPI: Vec<(u32, DeleteObjectGuard<HFONT>)> = Vec::new();
+
+/// Retrieves the system UI font, at system DPI.
+fn ui_logfont() -> SysResult<LOGFONT> {
 	let mut ncm = NONCLIENTMETRICS::default();
 	unsafe {
 		SystemParametersInfo(
@@ -46,14 +53,23 @@ pub(in crate::gui) fn create_ui_font() -> SysResult<()> {
 			&mut ncm,
 			co::SPIF::NoValue,
 		)?;
-		UI_HFONT = Some(HFONT::CreateFontIndirect(&ncm.lfMenuFont)?);
 	}
+	Ok(ncm.lfMenuFont)
+}
+
+/// Creates the global UI font object.
+pub(in crate::gui) fn create_ui_font() -> SysResult<()> {
+	let hfont = HFONT::CreateFontIndirect(&ui_logfont()?)?;
+	unsafe { UI_HFONT = Some(hfont); }
 	Ok(())
 }
 
-/// Frees the global UI font object.
+/// Frees the global UI font object, and the ones scaled to other DPIs.
 pub(in crate::gui) fn delete_ui_font() {
-	unsafe { UI_HFONT = None; } // https://users.rust-lang.org/t/why-drop-trait-not-called-when-use-global-static
+	unsafe {
+		UI_HFONT = None; // https://users.rust-lang.org/t/why-drop-trait-not-called-when-use-global-static
+		UI_HFONTS_DPI = Vec::new();
+	}
 }
 
 /// Retrieves the global UI font object, or panics if not created yet.
@@ -66,6 +82,36 @@ pub(in crate::gui) fn ui_font() -> &'static HFONT {
 	}
 }
 
+/// Retrieves the UI font object scaled to the given DPI, creating it if not
+/// cached yet. The handle is owned by the cache, so it must not be deleted.
+pub(in crate::gui) fn ui_font_for_dpi(dpi: u32) -> SysResult<HFONT> {
+	let sys_dpi = GetDpiForSystem();
+	if dpi == 0 || dpi == sys_dpi {
+		return Ok(unsafe { ui_font().raw_copy() });
+	}
+
+	unsafe {
+		let cache = &mut *addr_of_mut!(UI_HFONTS_DPI);
+		if let Some((_, hfont)) = cache.iter().find(|(d, _)| *d == dpi) {
+			return Ok(hfont.raw_copy());
+		}
+		let mut lf = ui_logfont()?;
+		lf.lfHeight = MulDiv(lf.lfHeight, dpi as _, sys_dpi as _);
+		let hfont = HFONT::CreateFontIndirect(&lf)?;
+		let ret = hfont.raw_copy();
+		cache.push((dpi, hfont));
+		Ok(ret)
+	}
+}
+
+/// Tells whether the font is one of the UI font objects, at any DPI.
+pub(in crate::gui) fn is_ui_font(hfont: &HFONT) -> bool {
+	unsafe {
+		(*addr_of!(UI_HFONT)).as_ref().is_some_and(|ui| **ui == *hfont)
+			|| (*addr_of!(UI_HFONTS_DPI)).iter().any(|(_, ui)| **ui == *hfont)
+	}
+}
+
 //------------------------------------------------------------------------------
 
 static mut BASE_CTRL_ID: u16 = 20_000; // in-between Visual Studio Resource Editor values
@@ -81,6 +127,14 @@ pub(in crate::gui) fn auto_ctrl_id() -> u16 {
 
 //------------------------------------------------------------------------------
 
+/// Sets the process DPI awareness, preferring per-monitor awareness, which
+/// makes windows receive `WM_DPICHANGED`.
+pub(in crate::gui) fn set_dpi_awareness() {
+	if SetProcessDpiAwarenessContext(co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2).is_err() {
+		SetProcessDPIAware().ok(); // older systems, or already set by the manifest
+	}
+}
+
 static mut DPI: POINT = POINT::new(0, 0);
 
 /// Multiplies the given coordinates by current system DPI.
@@ -93,21 +147,40 @@ pub(in crate::gui) fn multiply_dpi(
 			DPI.x = screen_dc.GetDeviceCaps(co::GDC::LOGPIXELSX); // cache
 			DPI.y = screen_dc.GetDeviceCaps(co::GDC::LOGPIXELSY);
 		}
-
-		if let Some(pt) = pt {
-			pt.x = MulDiv(pt.x, DPI.x, 96);
-			pt.y = MulDiv(pt.y, DPI.y, 96);
-		}
-		if let Some(sz) = sz {
-			sz.cx = MulDiv(sz.cx, DPI.x, 96);
-			sz.cy = MulDiv(sz.cy, DPI.y, 96);
-		}
+		scale_dpi(pt, sz, DPI.x, DPI.y);
 	}
 	Ok(())
 }
 
+/// Multiplies the given coordinates by the DPI of the monitor where the window
+/// is; falls back to current system DPI.
+pub(in crate::gui) fn multiply_dpi_for(
+	hwnd: &HWND, pt: Option<&mut POINT>, sz: Option<&mut SIZE>) -> SysResult<()>
+{
+	match hwnd.GetDpiForWindow() {
+		0 => multiply_dpi(pt, sz),
+		dpi => {
+			scale_dpi(pt, sz, dpi as _, dpi as _);
+			Ok(())
+		},
+	}
+}
+
+fn scale_dpi(
+	pt: Option<&mut POINT>, sz: Option<&mut SIZE>, dpi_x: i32, dpi_y: i32)
+{
+	if let Some(pt) = pt {
+		pt.x = MulDiv(pt.x, dpi_x, 96);
+		pt.y = MulDiv(pt.y, dpi_y, 96);
+	}
+	if let Some(sz) = sz {
+		sz.cx = MulDiv(sz.cx, dpi_x, 96);
+		sz.cy = MulDiv(sz.cy, dpi_y, 96);
+	}
+}
+
 /// If parent is a dialog, converts Dialog Template Units to pixels; otherwise
-/// multiplies by current DPI factor.
+/// multiplies by the DPI factor of the parent window.
 pub(in crate::gui) fn multiply_dpi_or_dtu(
 	parent_base: &Base,
 	pt: Option<&mut POINT>,
@@ -137,7 +210,7 @@ pub(in crate::gui) fn multiply_dpi_or_dtu(
 		});
 
 	} else {
-		multiply_dpi(pt, sz)?;
+		multiply_dpi_for(parent_base.hwnd(), pt, sz)?;
 	}
 
 	Ok(())
@@ -212,6 +285,26 @@ pub(in crate::gui) fn adjust_modeless_pos(
 	Ok(user_pos)
 }
 
+/// If the window has any child with `WS_TABSTOP`, adds `WS_EX_CONTROLPARENT` to
+/// it, so the dialog manager moves the focus into its children with Tab,
+/// Shift+Tab and mnemonics.
+pub(in crate::gui) fn set_control_parent_if_tab_stops(hwnd: &HWND) {
+	let has_tab_stops = Cell::new(false);
+	hwnd.EnumChildWindows(|hchild| {
+		if co::WS(hchild.GetWindowLongPtr(co::GWLP::STYLE) as _).has(co::WS::TABSTOP) {
+			has_tab_stops.set(true);
+			return false; // halt enumeration
+		}
+		true
+	});
+
+	if has_tab_stops.get() {
+		let ex_style = co::WS_EX(hwnd.GetWindowLongPtr(co::GWLP::EXSTYLE) as _);
+		hwnd.SetWindowLongPtr(co::GWLP::EXSTYLE,
+			(ex_style | co::WS_EX::CONTROLPARENT).0 as _);
+	}
+}
+
 /// Paints the themed border of an user control, if it has the proper styles.
 pub(in crate::gui) fn paint_control_borders(
 	hwnd: &HWND, wm_ncp: wm::NcPaint) -> AnyResult<()>
@@ -0,0 +1,135 @@
+use crate::co;
+use crate::comctl::decl::{PROPSHEETHEADER, PROPSHEETPAGE, PropertySheet as PropertySheetFn};
+use crate::gui::wizard_page::WizardPage;
+use crate::kernel::decl::{SysResult, WString};
+use crate::prelude::{GuiParent, Handle, user_Hwnd};
+
+/// A modal property sheet or wizard, which hosts
+/// [`WizardPage`](crate::gui::WizardPage) objects, shown with
+/// [`PropertySheet`](crate::PropertySheet).
+///
+/// # Examples
+///
+/// An Aero wizard with two pages, loaded from dialog resources:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, gui, AnyResult};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let page1 = gui::WizardPage::new(gui::WizardPageOpts {
+///     dialog_id: 101,
+///     header_title: Some("Choose the destination".to_owned()),
+///     ..Default::default()
+/// });
+/// let page2 = gui::WizardPage::new(gui::WizardPageOpts {
+///     dialog_id: 102,
+///     header_title: Some("Ready to install".to_owned()),
+///     ..Default::default()
+/// });
+///
+/// page2.on_page().psn_set_active({
+///     let page2 = page2.clone();
+///     move || -> AnyResult<()> {
+///         page2.set_wizard_buttons(co::PSWIZB::BACK | co::PSWIZB::FINISH)?;
+///         Ok(())
+///     }
+/// });
+///
+/// let wizard = gui::PropertySheet::new(
+///     gui::PropertySheetOpts {
+///         title: "Setup".to_owned(),
+///         sheet_style: co::PSH::WIZARD | co::PSH::AEROWIZARD,
+///         ..Default::default()
+///     },
+///     &[&page1, &page2],
+/// );
+///
+/// if wizard.show_modal(&wnd)? {
+///     println!("Finished.");
+/// }
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct PropertySheet {
+	opts: PropertySheetOpts,
+	pages: Vec<WizardPage>,
+}
+
+impl PropertySheet {
+	/// Instantiates a new `PropertySheet` object with the given pages, in
+	/// order.
+	#[must_use]
+	pub fn new(opts: PropertySheetOpts, pages: &[&WizardPage]) -> Self {
+		Self {
+			opts,
+			pages: pages.iter().map(|page| (*page).clone()).collect(),
+		}
+	}
+
+	/// Shows the modal property sheet, blocking until it's closed.
+	///
+	/// Returns `true` if the user saved the changes or finished the wizard.
+	///
+	/// # Panics
+	///
+	/// Panics if the property sheet is already being shown.
+	pub fn show_modal(&self, parent: &impl GuiParent) -> SysResult<bool> {
+		let mut page_bufs = self.pages.iter()
+			.map(|page| page.new_bufs())
+			.collect::<Vec<_>>();
+		let mut psps = Vec::with_capacity(self.pages.len());
+		for (page, bufs) in self.pages.iter().zip(page_bufs.iter_mut()) {
+			let mut psp = PROPSHEETPAGE::default();
+			page.fill_property_sheet_page(&mut psp, bufs)?;
+			psps.push(psp);
+		}
+
+		let mut title_buf = WString::from_str(&self.opts.title);
+
+		let mut psh = PROPSHEETHEADER::default();
+		psh.dwFlags = (self.opts.sheet_style | co::PSH::PROPSHEETPAGE)
+			& !co::PSH::MODELESS; // modeless sheets are not supported
+		psh.hwndParent = unsafe { parent.hwnd().raw_copy() };
+		psh.hInstance = parent.hwnd().hinstance();
+		psh.nStartPage = self.opts.start_page;
+		psh.set_pszCaption(Some(&mut title_buf));
+		psh.set_ppsp(Some(&mut psps));
+
+		PropertySheetFn(&psh).map(|ret| ret > 0)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`PropertySheet`](crate::gui::PropertySheet) with
+/// [`PropertySheet::new`](crate::gui::PropertySheet::new).
+pub struct PropertySheetOpts {
+	/// Title of the property sheet.
+	///
+	/// Defaults to empty string.
+	pub title: String,
+	/// Property sheet styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/prsht/ns-prsht-propsheetheaderw_v2).
+	///
+	/// For a wizard, use `co::PSH::WIZARD`, optionally combined with
+	/// `co::PSH::WIZARD97` or `co::PSH::AEROWIZARD`.
+	///
+	/// Defaults to `co::PSH::NOAPPLYNOW`.
+	pub sheet_style: co::PSH,
+	/// Zero-based index of the page initially shown.
+	///
+	/// Defaults to `0`.
+	pub start_page: u32,
+}
+
+impl Default for PropertySheetOpts {
+	fn default() -> Self {
+		Self {
+			title: "".to_owned(),
+			sheet_style: co::PSH::NOAPPLYNOW,
+			start_page: 0,
+		}
+	}
+}
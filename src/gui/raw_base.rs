@@ -1,18 +1,25 @@
+use std::cell::UnsafeCell;
+
 use crate::co;
+use crate::gdi::decl::IdOicStr;
 use crate::gui::base::Base;
 use crate::gui::events::{ProcessResult, WindowEventsAll};
+use crate::gui::panic_handler::catch_panic;
 use crate::gui::privs::post_quit_error;
 use crate::kernel::decl::{
 	AnyResult, HINSTANCE, SetLastError, SysResult, WString,
 };
 use crate::msg::{wm, WndMsg};
 use crate::prelude::{
-	gdi_Hbrush, Handle, MsgSendRecv, user_Hinstance, user_Hwnd,
+	gdi_Hbrush, gdi_Hinstance, Handle, MsgSendRecv, NativeBitflag,
+	user_Hinstance, user_Hwnd,
 };
 use crate::user::decl::{
-	ATOM, AtomStr, HBRUSH, HCURSOR, HICON, HWND, IdIdcStr, IdIdiStr, IdMenu,
-	POINT, RegisterClassEx, SIZE, WNDCLASSEX,
+	ATOM, AtomStr, GetSystemMetrics, GetSystemMetricsForDpi, HBRUSH, HCURSOR, HICON, HWND,
+	IdIdcStr, IdIdiStr, IdMenu, MDICREATESTRUCT, POINT, RegisterClassEx, SIZE,
+	WNDCLASSEX,
 };
+use crate::user::guard::DestroyIconGuard;
 
 /// The class background brush to be loaded for
 /// [`WindowMainOpts`](crate::gui::WindowMainOpts),
@@ -94,6 +101,75 @@ impl Icon {
 			})
 		}
 	}
+
+	/// Sets the big and small icons of the window. Resource icons are loaded
+	/// with the sizes for the current DPI of the window, and returned, so they
+	/// can be kept alive while the window uses them.
+	pub(in crate::gui) fn set_to_window(&self,
+		hwnd: &HWND) -> SysResult<Vec<DestroyIconGuard>>
+	{
+		Self::set_pair_to_window(self, self, hwnd)
+	}
+
+	/// Sets the big and small icons of the window, which may come from
+	/// different sources. Resource icons are loaded with the sizes for the
+	/// current DPI of the window, and returned, so they can be kept alive while
+	/// the window uses them.
+	pub(in crate::gui) fn set_pair_to_window(
+		big: &Icon, small: &Icon, hwnd: &HWND) -> SysResult<Vec<DestroyIconGuard>>
+	{
+		let mut loaded = Vec::with_capacity(2);
+		let hbig = big.load_for_dpi(hwnd, co::SM::CXICON, co::SM::CYICON, &mut loaded)?;
+		let hsmall = small.load_for_dpi(hwnd, co::SM::CXSMICON, co::SM::CYSMICON, &mut loaded)?;
+		if hbig != HICON::NULL || hsmall != HICON::NULL {
+			hwnd.set_icon(&hbig, &hsmall);
+		}
+		Ok(loaded)
+	}
+
+	fn load_for_dpi(&self,
+		hwnd: &HWND,
+		cx: co::SM,
+		cy: co::SM,
+		loaded: &mut Vec<DestroyIconGuard>,
+	) -> SysResult<HICON>
+	{
+		let name = match self {
+			Icon::Handle(h) => return Ok(unsafe { h.raw_copy() }),
+			Icon::Id(id) => IdOicStr::Id(*id),
+			Icon::Idi(idi) => {
+				// Stock icons are shared, so they must not be destroyed.
+				return Ok(HINSTANCE::NULL.LoadIcon(IdIdiStr::Idi(*idi))?.leak());
+			},
+			Icon::None => return Ok(HICON::NULL),
+			Icon::Str(s) => IdOicStr::Str(s.clone()),
+		};
+
+		let dpi = hwnd.GetDpiForWindow();
+		let metric = |index: co::SM| match GetSystemMetricsForDpi(index, dpi) {
+			Err(co::ERROR::CALL_NOT_IMPLEMENTED) => Ok(GetSystemMetrics(index)), // older systems
+			res => res,
+		};
+		let hicon = hwnd.hinstance().LoadImageIcon(name,
+			SIZE::new(metric(cx)?, metric(cy)?), co::LR::DEFAULTCOLOR)?;
+		let raw = unsafe { hicon.raw_copy() };
+		loaded.push(hicon);
+		Ok(raw)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Default window procedure called by [`RawBase`](crate::gui::raw_base::RawBase)
+/// for the messages not handled by the user.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(in crate::gui) enum DefProc {
+	/// [`DefWindowProc`](crate::prelude::user_Hwnd::DefWindowProc).
+	Window,
+	/// [`DefFrameProc`](crate::prelude::user_Hwnd::DefFrameProc).
+	MdiFrame,
+	/// [`DefMDIChildProc`](crate::prelude::user_Hwnd::DefMDIChildProc).
+	MdiChild,
 }
 
 //------------------------------------------------------------------------------
@@ -103,6 +179,8 @@ impl Icon {
 /// Owns the window procedure for all ordinary windows.
 pub(in crate::gui) struct RawBase {
 	base: Base,
+	def_proc: DefProc,
+	hwnd_mdi_client: UnsafeCell<HWND>, // MDI frames only
 }
 
 impl Drop for RawBase {
@@ -115,7 +193,17 @@ impl Drop for RawBase {
 
 impl RawBase {
 	pub(in crate::gui) fn new(parent: Option<&Base>) -> Self {
-		Self { base: Base::new(false, parent) }
+		Self::new_with_def_proc(parent, DefProc::Window)
+	}
+
+	pub(in crate::gui) fn new_with_def_proc(
+		parent: Option<&Base>, def_proc: DefProc) -> Self
+	{
+		Self {
+			base: Base::new(false, parent),
+			def_proc,
+			hwnd_mdi_client: UnsafeCell::new(HWND::NULL),
+		}
 	}
 
 	pub(in crate::gui) unsafe fn as_base(&self) -> *mut std::ffi::c_void {
@@ -127,13 +215,22 @@ impl RawBase {
 		self.base.hwnd()
 	}
 
+	/// Returns the MDI client window of an MDI frame, or `HWND::NULL`.
+	pub(in crate::gui) fn hwnd_mdi_client(&self) -> &HWND {
+		unsafe { &*self.hwnd_mdi_client.get() }
+	}
+
+	pub(in crate::gui) fn set_hwnd_mdi_client(&self, hwnd: HWND) {
+		unsafe { *self.hwnd_mdi_client.get() = hwnd; }
+	}
+
 	pub(in crate::gui) fn on(&self) -> &WindowEventsAll {
 		self.base.on()
 	}
 
-	// pub(in crate::gui) fn privileged_on(&self) -> &WindowEventsAll {
-	// 	self.base.privileged_on()
-	// }
+	pub(in crate::gui) fn privileged_on(&self) -> &WindowEventsAll {
+		self.base.privileged_on()
+	}
 
 	pub(in crate::gui) fn parent(&self) -> Option<&Base> {
 		self.base.parent()
@@ -247,6 +344,64 @@ impl RawBase {
 		Ok(())
 	}
 
+	pub(in crate::gui) fn create_message_only_window(
+		&self,
+		class_name: ATOM,
+		title: Option<&str>,
+	) -> SysResult<()>
+	{
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot create window twice.");
+		}
+
+		unsafe {
+			HWND::CreateWindowEx(
+				co::WS_EX::NoValue,
+				AtomStr::Atom(class_name),
+				title, co::WS::NoValue,
+				POINT::default(), SIZE::default(),
+				Some(&HWND::MESSAGE),
+				IdMenu::None,
+				&self.base.parent_hinstance()?,
+				Some(self as *const _ as _), // pass pointer to Self
+			)?;
+		}
+
+		Ok(())
+	}
+
+	pub(in crate::gui) fn create_mdi_child_window(
+		&self,
+		class_name: ATOM,
+		title: Option<&str>,
+		pos: POINT,
+		sz: SIZE,
+		styles: co::WS,
+		hwnd_mdi_client: &HWND,
+	) -> SysResult<()>
+	{
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot create window twice.");
+		}
+
+		// The MDI client wraps our parameter in a MDICREATESTRUCT, which is
+		// unwrapped during WM_NCCREATE processing.
+		unsafe {
+			HWND::CreateWindowEx(
+				co::WS_EX::MDICHILD,
+				AtomStr::Atom(class_name),
+				title, styles,
+				pos, sz,
+				Some(hwnd_mdi_client),
+				IdMenu::None,
+				&self.base.parent_hinstance()?,
+				Some(self as *const _ as _), // pass pointer to Self
+			)?;
+		}
+
+		Ok(())
+	}
+
 	pub(in crate::gui) fn spawn_new_thread<F>(&self, func: F)
 		where F: FnOnce() -> AnyResult<()> + Send + 'static,
 	{
@@ -263,7 +418,7 @@ impl RawBase {
 		hwnd: HWND, msg: co::WM, wparam: usize, lparam: isize) -> isize
 	{
 		let wm_any = WndMsg::new(msg, wparam, lparam);
-		Self::window_proc_proc(hwnd, wm_any)
+		catch_panic(wm_any, || Self::window_proc_proc(hwnd, wm_any))
 			.unwrap_or_else(|err| { post_quit_error(wm_any, err); 0 })
 	}
 
@@ -271,7 +426,14 @@ impl RawBase {
 		let ptr_self = match wm_any.msg_id {
 			co::WM::NCCREATE => { // first message being handled
 				let wm_ncc = wm::NcCreate::from_generic_wm(wm_any);
-				let ptr_self = wm_ncc.createstruct.lpCreateParams as *mut Self;
+				let ptr_self = if wm_ncc.createstruct.dwExStyle.has(co::WS_EX::MDICHILD) {
+					let mdics = unsafe {
+						&*(wm_ncc.createstruct.lpCreateParams as *const MDICREATESTRUCT)
+					};
+					mdics.lParam as *mut Self
+				} else {
+					wm_ncc.createstruct.lpCreateParams as *mut Self
+				};
 				hwnd.SetWindowLongPtr(co::GWLP::USERDATA, ptr_self as _); // store
 				let ref_self = unsafe { &mut *ptr_self };
 				ref_self.base.set_hwnd(unsafe { hwnd.raw_copy() }); // store HWND in struct field
@@ -286,12 +448,40 @@ impl RawBase {
 			return Ok(hwnd.DefWindowProc(wm_any));
 		}
 
-		// Execute privileged closures, discard results.
+		// Execute privileged closures, which may set the result.
 		let ref_self = unsafe { &mut *ptr_self };
-		ref_self.base.process_privileged_messages(wm_any)?;
+		let privileged_ret = ref_self.base.process_privileged_messages(wm_any)?;
 
 		// Execute user closure, if any.
-		let process_result = ref_self.base.process_user_message(wm_any)?;
+		let process_result = match (privileged_ret, ref_self.base.process_user_message(wm_any)?) {
+			(Some(ret), _) => ProcessResult::HandledWithRet(ret),
+			(None, process_result) => process_result,
+		};
+
+		// Copied before the events are cleared.
+		let def_proc = ref_self.def_proc;
+		let hwnd_mdi_client = unsafe { ref_self.hwnd_mdi_client().raw_copy() };
+		let call_def_proc = |wm_any: WndMsg| match def_proc {
+			DefProc::Window => hwnd.DefWindowProc(wm_any),
+			DefProc::MdiFrame => hwnd.DefFrameProc(
+				if hwnd_mdi_client == HWND::NULL { None } else { Some(&hwnd_mdi_client) },
+				wm_any),
+			DefProc::MdiChild => hwnd.DefMDIChildProc(wm_any),
+		};
+
+		// Some messages must reach the MDI procedures even if handled.
+		// https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-defmdichildprocw
+		let must_call_def_proc = match def_proc {
+			DefProc::Window => false,
+			DefProc::MdiFrame => matches!(wm_any.msg_id,
+				co::WM::SETFOCUS | co::WM::SIZE),
+			DefProc::MdiChild => matches!(wm_any.msg_id,
+				co::WM::CHILDACTIVATE | co::WM::GETMINMAXINFO | co::WM::MENUCHAR
+				| co::WM::MOVE | co::WM::SETFOCUS | co::WM::SIZE | co::WM::SYSCOMMAND),
+		};
+		if must_call_def_proc && !matches!(process_result, ProcessResult::NotHandled) {
+			call_def_proc(wm_any);
+		}
 
 		if wm_any.msg_id == co::WM::NCDESTROY { // always check
 			hwnd.SetWindowLongPtr(co::GWLP::USERDATA, 0); // clear passed pointer
@@ -302,7 +492,7 @@ impl RawBase {
 		Ok(match process_result {
 			ProcessResult::HandledWithRet(res) => res,
 			ProcessResult::HandledWithoutRet => 0,
-			ProcessResult::NotHandled => hwnd.DefWindowProc(wm_any).into(),
+			ProcessResult::NotHandled => call_def_proc(wm_any),
 		})
 	}
 }
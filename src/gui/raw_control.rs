@@ -8,6 +8,7 @@ use crate::gui::events::WindowEventsAll;
 use crate::gui::layout_arranger::{Horz, Vert};
 use crate::gui::privs::{
 	auto_ctrl_id, multiply_dpi_or_dtu, paint_control_borders,
+	set_control_parent_if_tab_stops,
 };
 use crate::gui::raw_base::{Brush, Cursor, Icon, RawBase};
 use crate::kernel::decl::{AnyResult, WString};
@@ -98,6 +99,7 @@ impl RawControl {
 				wnd_pos, wnd_sz,
 				opts.ex_style, opts.style,
 			)?;
+			set_control_parent_if_tab_stops(self2.hwnd()); // children are created by now
 
 			self2.0.raw_base.parent().unwrap()
 				.add_to_layout_arranger(self2.hwnd(), horz, vert)?;
@@ -4,22 +4,29 @@ use std::pin::Pin;
 use std::sync::Arc;
 
 use crate::co;
-use crate::gui::base::Base;
 use crate::gui::events::WindowEventsAll;
+use crate::gui::message_loop::MessageLoop;
 use crate::gui::privs::multiply_dpi;
-use crate::gui::raw_base::{Brush, Cursor, Icon, RawBase};
-use crate::kernel::decl::{AnyResult, HINSTANCE, WString};
-use crate::prelude::{GuiEvents, Handle, kernel_Hinstance, user_Hwnd};
+use crate::gui::raw_base::{Brush, Cursor, DefProc, Icon, RawBase};
+use crate::kernel::decl::{
+	AnyResult, HINSTANCE, HKEY, MulDiv, RegistryValue, SysResult, WString,
+};
+use crate::prelude::{
+	GuiEvents, Handle, kernel_Hinstance, kernel_Hkey, NativeBitflag, user_Hwnd,
+};
 use crate::user::decl::{
-	AdjustWindowRectEx, GetSystemMetrics, HMENU, HWND, IdMenu, POINT,
-	PostQuitMessage, RECT, SIZE, WNDCLASSEX,
+	AdjustWindowRectEx, AtomStr, CLIENTCREATESTRUCT, GetSystemMetrics, HMENU,
+	HWND, IdMenu, MINMAXINFO, POINT, PostQuitMessage, RECT, SIZE,
+	WINDOWPLACEMENT, WNDCLASSEX,
 };
-use crate::user::guard::DestroyAcceleratorTableGuard;
+use crate::user::guard::{DestroyAcceleratorTableGuard, DestroyIconGuard};
 
 struct Obj { // actual fields of RawMain
 	raw_base: RawBase,
 	opts: WindowMainOpts,
+	mdi_client: Option<CLIENTCREATESTRUCT>, // MDI frames only
 	hchild_prev_focus: UnsafeCell<HWND>, // WM_ACTIVATE woes
+	icons: UnsafeCell<Vec<DestroyIconGuard>>, // loaded from opts.icon and opts.small_icon
 	_pin: PhantomPinned,
 }
 
@@ -36,12 +43,37 @@ impl RawMain {
 				Obj {
 					raw_base: RawBase::new(None),
 					opts,
+					mdi_client: None,
+					hchild_prev_focus: UnsafeCell::new(HWND::NULL),
+					icons: UnsafeCell::new(Vec::new()),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+		new_self.default_message_handlers();
+		new_self
+	}
+
+	/// Creates a main window which will host an MDI client window.
+	pub(in crate::gui) fn new_mdi_frame(
+		opts: WindowMainOpts,
+		mdi_client: CLIENTCREATESTRUCT,
+	) -> Self
+	{
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					raw_base: RawBase::new_with_def_proc(None, DefProc::MdiFrame),
+					opts,
+					mdi_client: Some(mdi_client),
 					hchild_prev_focus: UnsafeCell::new(HWND::NULL),
+					icons: UnsafeCell::new(Vec::new()),
 					_pin: PhantomPinned,
 				},
 			),
 		);
 		new_self.default_message_handlers();
+		new_self.mdi_frame_message_handlers();
 		new_self
 	}
 
@@ -53,6 +85,10 @@ impl RawMain {
 		self.0.raw_base.hwnd()
 	}
 
+	pub(in crate::gui) fn hwnd_mdi_client(&self) -> &HWND {
+		self.0.raw_base.hwnd_mdi_client()
+	}
+
 	pub(in crate::gui) fn on(&self) -> &WindowEventsAll {
 		self.0.raw_base.on()
 	}
@@ -70,7 +106,7 @@ impl RawMain {
 	}
 
 	pub(in crate::gui) fn run_main(&self,
-		cmd_show: Option<co::SW>) -> AnyResult<i32>
+		cmd_show: Option<co::SW>, msg_loop: MessageLoop) -> AnyResult<i32>
 	{
 		let opts = &self.0.opts;
 
@@ -120,13 +156,55 @@ impl RawMain {
 			opts.ex_style, opts.style,
 		).unwrap();
 
-		self.hwnd().ShowWindow(cmd_show.unwrap_or(co::SW::SHOW));
+		match self.load_placement() {
+			Some(mut wp) => { // position and state saved in a previous run
+				if let Some(cmd_show) = cmd_show {
+					wp.showCmd = cmd_show;
+				}
+				self.hwnd().SetWindowPlacement(&wp).unwrap();
+			},
+			None => {
+				self.hwnd().ShowWindow(cmd_show.unwrap_or(co::SW::SHOW));
+			},
+		}
 		self.hwnd().UpdateWindow().unwrap();
 
-		Base::run_main_loop(opts.accel_table.as_deref()) // blocks until window is closed
+		msg_loop.run_with( // blocks until window is closed
+			opts.accel_table.as_deref(),
+			self.0.mdi_client.as_ref().map(|_| self.hwnd_mdi_client()),
+		)
 	}
 
 	fn default_message_handlers(&self) {
+		let self2 = self.clone();
+		self.0.raw_base.privileged_on().wm(co::WM::CREATE, move |_| {
+			self2.set_icon_for_dpi()?;
+			Ok(None) // not meaningful
+		});
+
+		let self2 = self.clone();
+		self.0.raw_base.privileged_on().wm_dpi_changed(move |_| {
+			self2.set_icon_for_dpi()?; // reload icons with the new sizes
+			Ok(())
+		});
+
+		let opts = &self.0.opts;
+		if opts.min_size != (0, 0) || opts.max_size != (0, 0) {
+			let self2 = self.clone();
+			self.0.raw_base.privileged_on().wm_get_min_max_info(move |p| {
+				self2.limit_tracking_size(p.info)?;
+				Ok(())
+			});
+		}
+
+		if opts.placement_key.is_some() {
+			let self2 = self.clone();
+			self.0.raw_base.privileged_on().wm(co::WM::DESTROY, move |_| {
+				self2.save_placement()?;
+				Ok(None) // not meaningful
+			});
+		}
+
 		let self2 = self.clone();
 		self.on().wm_activate(move |p| {
 			if !p.is_minimized {
@@ -155,6 +233,133 @@ impl RawMain {
 			Ok(())
 		});
 	}
+
+	fn set_icon_for_dpi(&self) -> SysResult<()> {
+		let opts = &self.0.opts;
+		let small = match opts.small_icon {
+			Icon::None => &opts.icon,
+			_ => &opts.small_icon,
+		};
+		let icons = Icon::set_pair_to_window(&opts.icon, small, self.hwnd())?;
+		if !icons.is_empty() {
+			*unsafe { &mut *self.0.icons.get() } = icons; // previous ones are destroyed
+		}
+		Ok(())
+	}
+
+	/// Applies `min_size` and `max_size`, which refer to the client area, to
+	/// the tracking sizes, which refer to the whole window.
+	fn limit_tracking_size(&self, info: &mut MINMAXINFO) -> SysResult<()> {
+		let opts = &self.0.opts;
+		let dpi = match self.hwnd().GetDpiForWindow() {
+			0 => 96,
+			dpi => dpi as i32,
+		};
+		let has_menu = opts.menu != HMENU::NULL;
+
+		let to_window_size = |client: (u32, u32)| -> SysResult<POINT> {
+			let mut rc = RECT {
+				left: 0,
+				top: 0,
+				right: MulDiv(client.0 as _, dpi, 96),
+				bottom: MulDiv(client.1 as _, dpi, 96),
+			};
+			AdjustWindowRectEx(&mut rc, opts.style, has_menu, opts.ex_style)?;
+			Ok(POINT::new(rc.right - rc.left, rc.bottom - rc.top))
+		};
+
+		if opts.min_size != (0, 0) {
+			let pt = to_window_size(opts.min_size)?;
+			info.ptMinTrackSize.x = info.ptMinTrackSize.x.max(pt.x);
+			info.ptMinTrackSize.y = info.ptMinTrackSize.y.max(pt.y);
+		}
+		if opts.max_size != (0, 0) {
+			let pt = to_window_size(opts.max_size)?;
+			if opts.max_size.0 != 0 {
+				info.ptMaxTrackSize.x = pt.x;
+			}
+			if opts.max_size.1 != 0 {
+				info.ptMaxTrackSize.y = pt.y;
+			}
+		}
+		Ok(())
+	}
+
+	const PLACEMENT_VALUE: &'static str = "WindowPlacement";
+
+	/// Reads the placement saved in the registry by a previous run, if any.
+	fn load_placement(&self) -> Option<WINDOWPLACEMENT> {
+		let key = self.0.opts.placement_key.as_ref()?;
+		let data = match HKEY::CURRENT_USER.RegGetValue(Some(key), Some(Self::PLACEMENT_VALUE)) {
+			Ok(RegistryValue::Binary(data)) if data.len() == 5 * 4 => data,
+			_ => return None, // not saved yet, or unknown format
+		};
+		let vals = data.chunks_exact(4)
+			.map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+			.collect::<Vec<_>>();
+
+		let mut wp = WINDOWPLACEMENT::default();
+		wp.showCmd = match co::SW(vals[0]) {
+			co::SW::SHOWMAXIMIZED => co::SW::SHOWMAXIMIZED,
+			_ => co::SW::SHOWNORMAL, // never start minimized
+		};
+		wp.rcNormalPosition = RECT {
+			left: vals[1],
+			top: vals[2],
+			right: vals[3],
+			bottom: vals[4],
+		};
+		Some(wp)
+	}
+
+	/// Writes the current placement to the registry, so it can be restored in
+	/// the next run.
+	fn save_placement(&self) -> SysResult<()> {
+		if let Some(key) = self.0.opts.placement_key.as_ref() {
+			let mut wp = WINDOWPLACEMENT::default();
+			self.hwnd().GetWindowPlacement(&mut wp)?;
+
+			let show_cmd = if wp.showCmd == co::SW::SHOWMINIMIZED
+				&& wp.flags.has(co::WPF::RESTORETOMAXIMIZED)
+			{
+				co::SW::SHOWMAXIMIZED
+			} else {
+				wp.showCmd
+			};
+			let rc = wp.rcNormalPosition;
+			let data = [show_cmd.0, rc.left, rc.top, rc.right, rc.bottom].iter()
+				.flat_map(|v| v.to_le_bytes())
+				.collect::<Vec<_>>();
+
+			HKEY::CURRENT_USER.RegSetKeyValue(
+				Some(key), Some(Self::PLACEMENT_VALUE), RegistryValue::Binary(data))?;
+		}
+		Ok(())
+	}
+
+	fn mdi_frame_message_handlers(&self) {
+		let self2 = self.clone();
+		self.0.raw_base.privileged_on().wm(co::WM::CREATE, move |_| {
+			// Created before any child control, so user closures will see it.
+			let ccs = self2.0.mdi_client.as_ref().unwrap();
+			let hwnd_mdi_client = unsafe {
+				HWND::CreateWindowEx(
+					co::WS_EX::CLIENTEDGE,
+					AtomStr::Str(WString::from_str("MDICLIENT")),
+					None,
+					co::WS::CHILD | co::WS::CLIPCHILDREN | co::WS::VSCROLL
+						| co::WS::HSCROLL | co::WS::VISIBLE,
+					POINT::default(), SIZE::default(),
+					Some(self2.hwnd()),
+					IdMenu::None,
+					&self2.hwnd().hinstance(),
+					Some(ccs as *const _ as _),
+				)?
+			};
+			self2.0.raw_base.set_hwnd_mdi_client(hwnd_mdi_client);
+			Ok(None) // not meaningful
+		});
+	}
 }
 
 //------------------------------------------------------------------------------
@@ -177,6 +382,22 @@ pub struct WindowMainOpts {
 	///
 	/// Defaults to `Icon::None`.
 	pub class_icon: Icon,
+	/// Big and small icons to be
+	/// [set](crate::prelude::user_Hwnd::set_icon) right after the window is
+	/// created.
+	///
+	/// Icons from resources are loaded with the sizes matching the window DPI,
+	/// and reloaded when the window moves to a monitor with a different DPI.
+	///
+	/// Defaults to `Icon::None`.
+	pub icon: Icon,
+	/// Small icon to be
+	/// [set](crate::prelude::user_Hwnd::set_icon) right after the window is
+	/// created, replacing the small version of `icon`. Useful when the small
+	/// icon is a different resource, drawn for small sizes.
+	///
+	/// Defaults to `Icon::None`, which means the small icon comes from `icon`.
+	pub small_icon: Icon,
 	/// Window cursor to be
 	/// [registered](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
 	///
@@ -201,6 +422,22 @@ pub struct WindowMainOpts {
 	///
 	/// Defaults to `(600, 500)`.
 	pub size: (u32, u32),
+	/// Minimum width and height of window client area, in pixels, when the
+	/// user resizes the window. Handled internally with
+	/// [`WM_GETMINMAXINFO`](crate::msg::wm::GetMinMaxInfo).
+	///
+	/// Will be adjusted to match the window DPI.
+	///
+	/// Defaults to `(0, 0)`, which means no limit.
+	pub min_size: (u32, u32),
+	/// Maximum width and height of window client area, in pixels, when the
+	/// user resizes the window. A zero dimension is not limited. Handled
+	/// internally with [`WM_GETMINMAXINFO`](crate::msg::wm::GetMinMaxInfo).
+	///
+	/// Will be adjusted to match the window DPI.
+	///
+	/// Defaults to `(0, 0)`, which means no limit.
+	pub max_size: (u32, u32),
 	/// Window styles to be
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
@@ -232,6 +469,14 @@ pub struct WindowMainOpts {
 	///
 	/// Defaults to `None`.
 	pub accel_table: Option<DestroyAcceleratorTableGuard>,
+	/// Registry key, under `HKEY_CURRENT_USER`, where the window placement –
+	/// position, size and maximized state – is saved when the window is
+	/// destroyed, and restored when it's created again, in the next run.
+	///
+	/// If a saved placement is found, it takes precedence over `size`.
+	///
+	/// Defaults to `None`.
+	pub placement_key: Option<String>,
 }
 
 impl Default for WindowMainOpts {
@@ -240,14 +485,19 @@ impl Default for WindowMainOpts {
 			class_name: "".to_owned(),
 			class_style: co::CS::DBLCLKS,
 			class_icon: Icon::None,
+			icon: Icon::None,
+			small_icon: Icon::None,
 			class_cursor: Cursor::Idc(co::IDC::ARROW),
 			class_bg_brush: Brush::Color(co::COLOR::BTNFACE),
 			title: "".to_owned(),
 			size: (600, 500),
+			min_size: (0, 0),
+			max_size: (0, 0),
 			style: co::WS::CAPTION | co::WS::SYSMENU | co::WS::CLIPCHILDREN | co::WS::BORDER | co::WS::VISIBLE,
 			ex_style: co::WS_EX::LEFT,
 			menu: HMENU::NULL,
 			accel_table: None,
+			placement_key: None,
 		}
 	}
 }
@@ -19,6 +19,7 @@ struct Obj { // actual fields of RawModal
 	raw_base: RawBase,
 	opts: WindowModalOpts,
 	hchild_prev_focus_parent: UnsafeCell<HWND>,
+	modal_result: UnsafeCell<i32>, // returned by show_modal()
 	_pin: PhantomPinned,
 }
 
@@ -36,6 +37,7 @@ impl RawModal {
 					raw_base: RawBase::new(Some(parent)),
 					opts,
 					hchild_prev_focus_parent: UnsafeCell::new(HWND::NULL),
+					modal_result: UnsafeCell::new(0),
 					_pin: PhantomPinned,
 				},
 			),
@@ -82,6 +84,7 @@ impl RawModal {
 			&mut class_name_buf)?;
 		let atom = self.0.raw_base.register_class(&mut wcx)?;
 
+		*unsafe { &mut *self.0.modal_result.get() } = 0; // a previous show_modal() may have left a result
 		*unsafe { &mut *self.0.hchild_prev_focus_parent.get() } =
 			HWND::GetFocus().unwrap_or(HWND::NULL);
 		hparent.EnableWindow(false); // https://devblogs.microsoft.com/oldnewthing/20040227-00/?p=40463
@@ -116,6 +119,23 @@ impl RawModal {
 		self.run_modal_loop()
 	}
 
+	pub(in crate::gui) fn close(&self, result: i32) -> SysResult<()> {
+		*unsafe { &mut *self.0.modal_result.get() } = result;
+		self.destroy_modal()
+	}
+
+	fn destroy_modal(&self) -> SysResult<()> {
+		if let Ok(hparent) = self.hwnd().GetWindow(co::GW::OWNER) {
+			hparent.EnableWindow(true); // re-enable parent
+			self.hwnd().DestroyWindow()?; // then destroy modal
+			let hchild_prev_focus_parent = unsafe { &mut *self.0.hchild_prev_focus_parent.get() };
+			if *hchild_prev_focus_parent != HWND::NULL {
+				hchild_prev_focus_parent.SetFocus(); // this focus could be set on WM_DESTROY as well
+			}
+		}
+		Ok(())
+	}
+
 	fn run_modal_loop(&self) -> SysResult<i32> {
 		loop {
 			let mut msg = MSG::default();
@@ -125,11 +145,11 @@ impl RawModal {
 				// https://devblogs.microsoft.com/oldnewthing/20050222-00/?p=36393
 				// https://stackoverflow.com/a/29359913/6923555
 				PostQuitMessage(msg.wParam as _);
-				return Ok(0); // raw modals will always return 0
+				return Ok(0); // application is quitting, result is not meaningful
 			}
 
 			if *self.hwnd() == HWND::NULL || !self.hwnd().IsWindow() {
-				return Ok(0); // our modal was destroyed, terminate loop
+				return Ok(*unsafe { &*self.0.modal_result.get() }); // our modal was destroyed, terminate loop
 			}
 
 			// If a child window, will retrieve its top-level parent.
@@ -141,7 +161,7 @@ impl RawModal {
 			if hwnd_top_level.IsDialogMessage(&mut msg) {
 				// Processed all keyboard actions for child controls.
 				if *self.hwnd() == HWND::NULL {
-					return Ok(0); // our modal was destroyed, terminate loop
+					return Ok(*unsafe { &*self.0.modal_result.get() }); // our modal was destroyed, terminate loop
 				} else {
 					continue;
 				}
@@ -151,7 +171,7 @@ impl RawModal {
 			unsafe { DispatchMessage(&msg); }
 
 			if *self.hwnd() == HWND::NULL || !self.hwnd().IsWindow() {
-				return Ok(0); // our modal was destroyed, terminate loop
+				return Ok(*unsafe { &*self.0.modal_result.get() }); // our modal was destroyed, terminate loop
 			}
 		}
 	}
@@ -165,14 +185,7 @@ impl RawModal {
 
 		let self2 = self.clone();
 		self.on().wm_close(move || {
-			if let Ok(hparent) = self2.hwnd().GetWindow(co::GW::OWNER) {
-				hparent.EnableWindow(true); // re-enable parent
-				self2.hwnd().DestroyWindow()?; // then destroy modal
-				let hchild_prev_focus_parent = unsafe { &mut *self2.0.hchild_prev_focus_parent.get() };
-				if *hchild_prev_focus_parent != HWND::NULL {
-					hchild_prev_focus_parent.SetFocus(); // this focus could be set on WM_DESTROY as well
-				}
-			}
+			self2.destroy_modal()?;
 			Ok(())
 		});
 	}
@@ -0,0 +1,169 @@
+use std::cell::Cell;
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+use crate::gui::base::Base;
+use crate::gui::layout_arranger::{Horz, Vert};
+use crate::prelude::{GuiChild, GuiEvents, GuiParent, Handle};
+use crate::user::decl::{HWND, RECT, SIZE};
+
+struct Obj { // actual fields of Resizer
+	parent_ptr: NonNull<Base>,
+	pending: Cell<u32>, // controls still waiting for the parent creation
+}
+
+//------------------------------------------------------------------------------
+
+/// Rearranges child controls when the parent window is resized, with more
+/// control than the `horz_resize` and `vert_resize` options of each control:
+/// proportional anchoring, margins and size limits.
+///
+/// The rules are applied along with the ones given in the options of the
+/// controls; if a control is added to a `Resizer`, its rules replace the ones
+/// of its options.
+///
+/// # Examples
+///
+/// Two lists side by side, each one taking half of the window width, and
+/// stretching vertically:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{gui, RECT, SIZE};
+///
+/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// let list1 = gui::ListView::new(&wnd, gui::ListViewOpts::default());
+/// let list2 = gui::ListView::new(&wnd, gui::ListViewOpts::default());
+///
+/// let resizer = gui::Resizer::new(&wnd);
+/// resizer
+///     .add(&list1, gui::ResizerOpts {
+///         horz: gui::Horz::Percent { left: 0, width: 50 },
+///         vert: gui::Vert::Resize,
+///         margins: RECT { left: 10, top: 10, right: 5, bottom: 10 },
+///         min_size: SIZE::new(100, 80),
+///         ..Default::default()
+///     })
+///     .add(&list2, gui::ResizerOpts {
+///         horz: gui::Horz::Percent { left: 50, width: 50 },
+///         vert: gui::Vert::Resize,
+///         margins: RECT { left: 5, top: 10, right: 10, bottom: 10 },
+///         min_size: SIZE::new(100, 80),
+///         ..Default::default()
+///     });
+/// ```
+#[derive(Clone)]
+pub struct Resizer(Rc<Obj>);
+
+unsafe impl Send for Resizer {}
+
+impl Resizer {
+	/// Instantiates a new `Resizer` object, which will act upon the children of
+	/// the parent window.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Resizer` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+		if *parent_ref.hwnd() != HWND::NULL {
+			panic!("Cannot create Resizer after parent window creation.");
+		}
+
+		Self(
+			Rc::new(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					pending: Cell::new(0),
+				},
+			),
+		)
+	}
+
+	fn parent(&self) -> &Base {
+		unsafe { self.0.parent_ptr.as_ref() }
+	}
+
+	/// Adds a child control, which will be rearranged according to the given
+	/// rules. The control must have been created before the `Resizer`.
+	///
+	/// Returns the same `Resizer` object, so calls can be chained.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created.
+	pub fn add<C>(&self, child: &C, opts: ResizerOpts) -> &Self
+		where C: GuiChild + Clone + 'static,
+	{
+		let parent = self.parent();
+		if *parent.hwnd() != HWND::NULL {
+			panic!("Cannot add Resizer entries after parent window creation.");
+		}
+
+		self.0.pending.set(self.0.pending.get() + 1);
+
+		let (self2, child2) = (self.clone(), child.clone());
+		parent.privileged_on().wm(parent.creation_msg(), move |_| {
+			let parent = self2.parent();
+			parent.add_to_layout_arranger_constrained(child2.hwnd(),
+				opts.horz, opts.vert, opts.margins, opts.min_size, opts.max_size)?;
+
+			let pending = self2.0.pending.get() - 1;
+			self2.0.pending.set(pending);
+			if pending == 0 { // last one, apply the rules to the current size
+				parent.rearrange_layout()?;
+			}
+			Ok(None) // not meaningful
+		});
+
+		self
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options for each child control added to a
+/// [`Resizer`](crate::gui::Resizer) with
+/// [`Resizer::add`](crate::gui::Resizer::add).
+///
+/// All sizes are in pixels, and are rescaled when the DPI of the parent window
+/// changes.
+pub struct ResizerOpts {
+	/// Horizontal behavior of the control when the parent window is resized.
+	///
+	/// Defaults to `gui::Horz::None`.
+	pub horz: Horz,
+	/// Vertical behavior of the control when the parent window is resized.
+	///
+	/// Defaults to `gui::Vert::None`.
+	pub vert: Vert,
+	/// Distances kept from each edge. With `Percent`, they're the space left
+	/// inside the proportional area; with `Repos` and `Resize`, they're the
+	/// minimum distances to the edges of the parent client area.
+	///
+	/// Defaults to zero.
+	pub margins: RECT,
+	/// Minimum size when the control is resized. Has priority over the margins
+	/// and the maximum size.
+	///
+	/// Defaults to `SIZE::new(0, 0)`.
+	pub min_size: SIZE,
+	/// Maximum size when the control is resized.
+	///
+	/// Defaults to none.
+	pub max_size: Option<SIZE>,
+}
+
+impl Default for ResizerOpts {
+	fn default() -> Self {
+		Self {
+			horz: Horz::None,
+			vert: Vert::None,
+			margins: RECT::default(),
+			min_size: SIZE::default(),
+			max_size: None,
+		}
+	}
+}
@@ -0,0 +1,196 @@
+use std::cell::UnsafeCell;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::kernel::decl::AnyResult;
+use crate::prelude::{GuiEvents, GuiParent, shell_Hwnd};
+use crate::shell::decl::SHChangeNotification_Lock;
+use crate::shell::guard::SHChangeNotifyDeregisterGuard;
+
+static mut BASE_WM_SHELL_CHANGE: u32 = co::WM::APP.0 + 0x3ffc; // decremented for each object
+
+/// Returns the next private message to receive shell notifications.
+fn auto_wm_shell_change() -> co::WM {
+	unsafe {
+		let new_msg = BASE_WM_SHELL_CHANGE;
+		BASE_WM_SHELL_CHANGE -= 1;
+		co::WM(new_msg)
+	}
+}
+
+struct Obj { // actual fields of ShellChangeNotify
+	parent_ptr: NonNull<Base>,
+	opts: ShellChangeNotifyOpts,
+	wm_shell_change: co::WM,
+	reg_guard: UnsafeCell<Option<SHChangeNotifyDeregisterGuard>>,
+	change_fn: UnsafeCell<Option<Box<dyn Fn(ShellChange) -> AnyResult<()>>>>,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Watches changes in the shell namespace – files created or renamed, drives
+/// added, file associations changed, etc. – with
+/// [`HWND::SHChangeNotifyRegister`](crate::prelude::shell_Hwnd::SHChangeNotifyRegister).
+///
+/// The registration is made when the parent window is created, and removed
+/// when the parent window is destroyed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, gui};
+///
+/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let watcher = gui::ShellChangeNotify::new(&wnd, gui::ShellChangeNotifyOpts {
+///     folders: vec![("C:\\Temp".to_owned(), true)],
+///     events: co::SHCNE::CREATE | co::SHCNE::DELETE | co::SHCNE::RENAMEITEM,
+///     ..Default::default()
+/// });
+///
+/// watcher.on_change(|change: gui::ShellChange| {
+///     println!("{} {:?} {:?}", change.event, change.path1, change.path2);
+///     Ok(())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct ShellChangeNotify(Pin<Arc<Obj>>);
+
+unsafe impl Send for ShellChangeNotify {}
+
+impl ShellChangeNotify {
+	/// Instantiates a new `ShellChangeNotify` object, to be registered along
+	/// with the creation of the parent window.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `ShellChangeNotify` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: ShellChangeNotifyOpts) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					opts,
+					wm_shell_change: auto_wm_shell_change(),
+					reg_guard: UnsafeCell::new(None),
+					change_fn: UnsafeCell::new(None),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(parent_ref.creation_msg(), move |_| {
+			self2.register()?;
+			Ok(None) // not meaningful
+		});
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(co::WM::DESTROY, move |_| {
+			*unsafe { &mut *self2.0.reg_guard.get() } = None; // deregister
+			Ok(None) // not meaningful
+		});
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(new_self.0.wm_shell_change, move |p| {
+			let lock = SHChangeNotification_Lock(p.wparam, p.lparam as _)?;
+			let (path1, path2) = lock.paths();
+			let change = ShellChange { event: lock.event(), path1, path2 };
+			drop(lock); // release the notification before running user code
+
+			if let Some(func) = unsafe { &*self2.0.change_fn.get() } {
+				func(change)?;
+			}
+			Ok(None) // not meaningful
+		});
+
+		new_self
+	}
+
+	fn parent(&self) -> &Base {
+		unsafe { self.0.parent_ptr.as_ref() }
+	}
+
+	fn register(&self) -> AnyResult<()> {
+		let opts = &self.0.opts;
+		let folders = if opts.folders.is_empty() {
+			vec![("", true)] // whole namespace
+		} else {
+			opts.folders.iter()
+				.map(|(path, recursive)| (path.as_str(), *recursive))
+				.collect::<Vec<_>>()
+		};
+
+		let reg_guard = self.parent().hwnd().SHChangeNotifyRegister(
+			opts.sources | co::SHCNRF::NEWDELIVERY,
+			opts.events,
+			self.0.wm_shell_change,
+			&folders,
+		)?;
+		*unsafe { &mut *self.0.reg_guard.get() } = Some(reg_guard);
+		Ok(())
+	}
+
+	/// Sets the closure to be called when a change is notified. Only the last
+	/// closure is kept.
+	pub fn on_change<F>(&self, func: F)
+		where F: Fn(ShellChange) -> AnyResult<()> + 'static,
+	{
+		*unsafe { &mut *self.0.change_fn.get() } = Some(Box::new(func));
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A change notified by [`ShellChangeNotify`](crate::gui::ShellChangeNotify).
+pub struct ShellChange {
+	/// The event which occurred.
+	pub event: co::SHCNE,
+	/// File system path of the first item of the event, if any. In a rename,
+	/// this is the old path.
+	pub path1: Option<String>,
+	/// File system path of the second item of the event, if any. In a rename,
+	/// this is the new path.
+	pub path2: Option<String>,
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`ShellChangeNotify`](crate::gui::ShellChangeNotify)
+/// with [`ShellChangeNotify::new`](crate::gui::ShellChangeNotify::new).
+pub struct ShellChangeNotifyOpts {
+	/// Folders to be watched, and whether their subfolders are also watched.
+	///
+	/// Defaults to none, which watches the whole shell namespace.
+	pub folders: Vec<(String, bool)>,
+	/// Events to be notified.
+	///
+	/// Defaults to `co::SHCNE::ALLEVENTS`.
+	pub events: co::SHCNE,
+	/// Sources of the events.
+	/// [`co::SHCNRF::NEWDELIVERY`](crate::co::SHCNRF::NEWDELIVERY) is always
+	/// added.
+	///
+	/// Defaults to `co::SHCNRF::INTERRUPTLEVEL | co::SHCNRF::SHELLLEVEL`.
+	pub sources: co::SHCNRF,
+}
+
+impl Default for ShellChangeNotifyOpts {
+	fn default() -> Self {
+		Self {
+			folders: Vec::default(),
+			events: co::SHCNE::ALLEVENTS,
+			sources: co::SHCNRF::INTERRUPTLEVEL | co::SHCNRF::SHELLLEVEL,
+		}
+	}
+}
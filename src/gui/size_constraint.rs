@@ -0,0 +1,245 @@
+use std::cell::Cell;
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::kernel::decl::{AnyResult, MulDiv};
+use crate::prelude::{GuiEvents, GuiParent, user_Hwnd};
+use crate::user::decl::{RECT, SIZE};
+
+struct Obj { // actual fields of SizeConstraint
+	parent_ptr: NonNull<Base>,
+	opts: SizeConstraintOpts,
+	enabled: Cell<bool>,
+}
+
+//------------------------------------------------------------------------------
+
+/// Constrains the client area of a window while it's being resized by the
+/// user: fixed aspect ratio, step size and minimum/maximum bounds.
+///
+/// The constraints are applied by adjusting the rectangle of
+/// [`WM_SIZING`](crate::msg::wm::Sizing) messages, according to the edge
+/// being dragged.
+///
+/// # Examples
+///
+/// A window whose client area keeps a 16:9 aspect ratio, with at least
+/// 320x180 pixels:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{gui, SIZE};
+///
+/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let constraint = gui::SizeConstraint::new(&wnd, gui::SizeConstraintOpts {
+///     aspect_ratio: Some((16, 9)),
+///     min: Some(SIZE::new(320, 180)),
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Clone)]
+pub struct SizeConstraint(Rc<Obj>);
+
+unsafe impl Send for SizeConstraint {}
+
+impl SizeConstraint {
+	/// Instantiates a new `SizeConstraint` object, which will act upon the
+	/// parent window.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `SizeConstraint` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: SizeConstraintOpts) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+
+		let new_self = Self(
+			Rc::new(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					opts,
+					enabled: Cell::new(true),
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm_sizing(move |p| {
+			if self2.is_enabled() {
+				self2.constrain(p.window_edge, p.coords)?;
+			}
+			Ok(())
+		});
+
+		new_self
+	}
+
+	fn parent(&self) -> &Base {
+		unsafe { self.0.parent_ptr.as_ref() }
+	}
+
+	/// Tells whether the constraints are currently enabled.
+	#[must_use]
+	pub fn is_enabled(&self) -> bool {
+		self.0.enabled.get()
+	}
+
+	/// Enables or disables the constraints. They're initially enabled.
+	pub fn set_enabled(&self, enabled: bool) {
+		self.0.enabled.set(enabled);
+	}
+
+	fn constrain(&self, edge: co::WMSZ, rc: &mut RECT) -> AnyResult<()> {
+		// The constraints refer to the client area, so the non-client size is
+		// taken into account.
+		let hwnd = self.parent().hwnd();
+		let (rc_wnd, rc_client) = (hwnd.GetWindowRect()?, hwnd.GetClientRect()?);
+		let nc = SIZE::new(
+			(rc_wnd.right - rc_wnd.left) - rc_client.right,
+			(rc_wnd.bottom - rc_wnd.top) - rc_client.bottom,
+		);
+
+		let sz = self.0.opts.apply(
+			edge,
+			SIZE::new(rc.right - rc.left - nc.cx, rc.bottom - rc.top - nc.cy),
+		);
+
+		match edge { // the opposite edge stays in place
+			co::WMSZ::LEFT | co::WMSZ::TOPLEFT | co::WMSZ::BOTTOMLEFT =>
+				rc.left = rc.right - sz.cx - nc.cx,
+			_ => rc.right = rc.left + sz.cx + nc.cx,
+		}
+		match edge {
+			co::WMSZ::TOP | co::WMSZ::TOPLEFT | co::WMSZ::TOPRIGHT =>
+				rc.top = rc.bottom - sz.cy - nc.cy,
+			_ => rc.bottom = rc.top + sz.cy + nc.cy,
+		}
+		Ok(())
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`SizeConstraint`](crate::gui::SizeConstraint) with
+/// [`SizeConstraint::new`](crate::gui::SizeConstraint::new).
+///
+/// All sizes are in pixels, and refer to the client area of the window.
+#[derive(Default)]
+pub struct SizeConstraintOpts {
+	/// Fixed aspect ratio, as width and height proportions.
+	///
+	/// Defaults to none.
+	pub aspect_ratio: Option<(u32, u32)>,
+	/// Step size; width and height will be multiples of these values. Zero
+	/// values are ignored.
+	///
+	/// Defaults to none.
+	pub step: Option<SIZE>,
+	/// Minimum size.
+	///
+	/// Defaults to none.
+	pub min: Option<SIZE>,
+	/// Maximum size.
+	///
+	/// Defaults to none.
+	pub max: Option<SIZE>,
+}
+
+impl SizeConstraintOpts {
+	/// Returns the constrained size, considering the edge being dragged.
+	fn apply(&self, edge: co::WMSZ, sz: SIZE) -> SIZE {
+		let mut sz = sz;
+
+		if let Some(step) = self.step {
+			if step.cx > 0 {
+				sz.cx = (sz.cx + step.cx / 2) / step.cx * step.cx;
+			}
+			if step.cy > 0 {
+				sz.cy = (sz.cy + step.cy / 2) / step.cy * step.cy;
+			}
+		}
+
+		sz = self.clamp(sz);
+
+		if let Some((num, den)) = self.aspect_ratio {
+			if num > 0 && den > 0 {
+				let (num, den) = (num as i32, den as i32);
+				match edge {
+					co::WMSZ::TOP | co::WMSZ::BOTTOM => { // height rules
+						sz.cx = MulDiv(sz.cy, num, den);
+					},
+					_ => { // width rules
+						sz.cy = MulDiv(sz.cx, den, num);
+					},
+				}
+
+				// If the derived dimension broke the bounds, the other one is
+				// recalculated from the clamped value.
+				let clamped = self.clamp(sz);
+				if clamped.cy != sz.cy {
+					sz = SIZE::new(MulDiv(clamped.cy, num, den), clamped.cy);
+				} else if clamped.cx != sz.cx {
+					sz = SIZE::new(clamped.cx, MulDiv(clamped.cx, den, num));
+				}
+			}
+		}
+
+		sz
+	}
+
+	fn clamp(&self, sz: SIZE) -> SIZE {
+		let mut sz = sz;
+		if let Some(min) = self.min {
+			sz.cx = sz.cx.max(min.cx);
+			sz.cy = sz.cy.max(min.cy);
+		}
+		if let Some(max) = self.max {
+			sz.cx = sz.cx.min(max.cx);
+			sz.cy = sz.cy.min(max.cy);
+		}
+		sz
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn step() {
+		let opts = SizeConstraintOpts { step: Some(SIZE::new(10, 0)), ..Default::default() };
+		assert!(opts.apply(co::WMSZ::RIGHT, SIZE::new(104, 96)) == SIZE::new(100, 96));
+		assert!(opts.apply(co::WMSZ::RIGHT, SIZE::new(105, 96)) == SIZE::new(110, 96));
+	}
+
+	#[test]
+	fn min_max() {
+		let opts = SizeConstraintOpts {
+			min: Some(SIZE::new(50, 50)),
+			max: Some(SIZE::new(200, 100)),
+			..Default::default()
+		};
+		assert!(opts.apply(co::WMSZ::RIGHT, SIZE::new(10, 500)) == SIZE::new(50, 100));
+	}
+
+	#[test]
+	fn aspect_ratio() {
+		let opts = SizeConstraintOpts { aspect_ratio: Some((16, 9)), ..Default::default() };
+		assert!(opts.apply(co::WMSZ::RIGHT, SIZE::new(160, 50)) == SIZE::new(160, 90));
+		assert!(opts.apply(co::WMSZ::BOTTOM, SIZE::new(100, 90)) == SIZE::new(160, 90));
+	}
+
+	#[test]
+	fn aspect_ratio_within_bounds() {
+		let opts = SizeConstraintOpts {
+			aspect_ratio: Some((16, 9)),
+			max: Some(SIZE::new(150, 1000)),
+			..Default::default()
+		};
+		assert!(opts.apply(co::WMSZ::BOTTOM, SIZE::new(100, 90)) == SIZE::new(150, 84));
+	}
+}
@@ -0,0 +1,362 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::rc::Rc;
+
+use crate::co;
+use crate::gui::layout_arranger::{Horz, Vert};
+use crate::gui::raw_base::{Brush, Cursor};
+use crate::gui::raw_control::WindowControlOpts;
+use crate::gui::window_control::WindowControl;
+use crate::kernel::decl::{MulDiv, SysResult};
+use crate::prelude::{
+	GuiChild, GuiEvents, GuiParent, GuiWindow, user_Hdwp, user_Hwnd,
+};
+use crate::user::decl::{HDWP, HWND, HwndPlace, POINT, ReleaseCapture, SIZE};
+
+/// How the two panes of a [`Splitter`](crate::gui::Splitter) are laid out.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SplitterPanes {
+	/// The first pane is at left, the second at right, and the bar is
+	/// vertical.
+	LeftRight,
+	/// The first pane is at top, the second at bottom, and the bar is
+	/// horizontal.
+	TopBottom,
+}
+
+struct Obj { // actual fields of Splitter
+	container: WindowControl,
+	panes: (WindowControl, WindowControl),
+	opts: SplitterOpts,
+	split_pos: Cell<i32>, // size of first pane, in pixels
+	dpi: Cell<u32>, // DPI split_pos refers to
+	drag_offset: Cell<Option<i32>>, // distance from the bar start while dragging
+}
+
+//------------------------------------------------------------------------------
+
+/// A container with two resizable panes, separated by a bar which can be
+/// dragged with the mouse.
+///
+/// Each pane is a [`WindowControl`](crate::gui::WindowControl), to be used as
+/// the parent of the controls within it. When the splitter is resized by the
+/// [layout arranger](crate::gui::Horz) of its parent, the first pane keeps its
+/// size and the second one takes the rest of the space; the panes' own
+/// controls are then rearranged by the panes.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let splitter = gui::Splitter::new(&wnd, gui::SplitterOpts {
+///     size: (600, 500),
+///     split_pos: 200,
+///     horz_resize: gui::Horz::Resize,
+///     vert_resize: gui::Vert::Resize,
+///     ..Default::default()
+/// });
+///
+/// let (left, right) = splitter.panes();
+///
+/// let tree = gui::TreeView::new(left, gui::TreeViewOpts {
+///     size: (200, 500),
+///     horz_resize: gui::Horz::Resize,
+///     vert_resize: gui::Vert::Resize,
+///     ..Default::default()
+/// });
+///
+/// let list = gui::ListView::new(right, gui::ListViewOpts {
+///     size: (396, 500),
+///     horz_resize: gui::Horz::Resize,
+///     vert_resize: gui::Vert::Resize,
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Clone)]
+pub struct Splitter(Rc<Obj>);
+
+unsafe impl Send for Splitter {}
+
+impl GuiWindow for Splitter {
+	fn hwnd(&self) -> &HWND {
+		self.0.container.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiChild for Splitter {
+	fn ctrl_id(&self) -> u16 {
+		self.0.container.ctrl_id()
+	}
+}
+
+impl Splitter {
+	/// Instantiates a new `Splitter` object, to be created on the parent window
+	/// with [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Splitter` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: SplitterOpts) -> Self {
+		let container = WindowControl::new(parent, WindowControlOpts {
+			class_cursor: Cursor::Idc(match opts.panes {
+				SplitterPanes::LeftRight => co::IDC::SIZEWE,
+				SplitterPanes::TopBottom => co::IDC::SIZENS,
+			}),
+			class_bg_brush: Brush::Color(co::COLOR::BTNFACE),
+			position: opts.position,
+			size: opts.size,
+			style: co::WS::CHILD | co::WS::VISIBLE | co::WS::CLIPCHILDREN | co::WS::CLIPSIBLINGS,
+			ex_style: co::WS_EX::CONTROLPARENT,
+			ctrl_id: opts.ctrl_id,
+			horz_resize: opts.horz_resize,
+			vert_resize: opts.vert_resize,
+			..Default::default()
+		});
+
+		let pane_opts = || WindowControlOpts { // actual positions are set by the splitter
+			class_bg_brush: Brush::Color(co::COLOR::BTNFACE),
+			style: co::WS::CHILD | co::WS::VISIBLE | co::WS::CLIPCHILDREN | co::WS::CLIPSIBLINGS,
+			ex_style: co::WS_EX::CONTROLPARENT,
+			..Default::default()
+		};
+		let panes = (
+			WindowControl::new(&container, pane_opts()),
+			WindowControl::new(&container, pane_opts()),
+		);
+
+		let new_self = Self(
+			Rc::new(
+				Obj {
+					container,
+					panes,
+					opts,
+					split_pos: Cell::new(0),
+					dpi: Cell::new(0),
+					drag_offset: Cell::new(None),
+				},
+			),
+		);
+		new_self.events();
+		new_self
+	}
+
+	fn events(&self) {
+		let container = &self.0.container;
+
+		let self2 = self.clone();
+		container.on().wm_create(move |_| { // panes are created by now
+			let dpi = self2.dpi();
+			self2.0.dpi.set(dpi);
+			self2.0.split_pos.set(Self::scale(self2.0.opts.split_pos, dpi));
+			self2.arrange()?;
+			Ok(0)
+		});
+
+		let self2 = self.clone();
+		container.on().wm_size(move |_| {
+			self2.arrange()?;
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		container.on().wm_l_button_down(move |p| {
+			let coord = self2.coord(p.coords);
+			self2.0.drag_offset.set(Some(coord - self2.0.split_pos.get()));
+			std::mem::forget(self2.hwnd().SetCapture()); // released in WM_LBUTTONUP
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		container.on().wm_mouse_move(move |p| {
+			if let Some(offset) = self2.0.drag_offset.get() {
+				let new_pos = self2.coord(p.coords) - offset;
+				if new_pos != self2.0.split_pos.get() {
+					self2.0.split_pos.set(new_pos);
+					self2.arrange()?;
+				}
+			}
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		container.on().wm_l_button_up(move |_| {
+			if self2.0.drag_offset.take().is_some() {
+				ReleaseCapture()?;
+			}
+			Ok(())
+		});
+
+		let self2 = self.clone();
+		container.on().wm_capture_changed(move |_| {
+			self2.0.drag_offset.set(None); // capture lost, stop dragging
+			Ok(())
+		});
+	}
+
+	fn dpi(&self) -> u32 {
+		match self.hwnd().GetDpiForWindow() {
+			0 => 96, // USER_DEFAULT_SCREEN_DPI
+			dpi => dpi,
+		}
+	}
+
+	fn scale(v: u32, dpi: u32) -> i32 {
+		MulDiv(v as _, dpi as _, 96)
+	}
+
+	/// Mouse coordinate along the split direction.
+	fn coord(&self, pt: POINT) -> i32 {
+		match self.0.opts.panes {
+			SplitterPanes::LeftRight => pt.x,
+			SplitterPanes::TopBottom => pt.y,
+		}
+	}
+
+	/// Positions the panes according to the current split position, which is
+	/// clamped to the minimum pane sizes.
+	fn arrange(&self) -> SysResult<()> {
+		let dpi = self.dpi();
+		let prev_dpi = self.0.dpi.replace(dpi);
+		if prev_dpi != 0 && prev_dpi != dpi { // moved to a monitor with another DPI
+			self.0.split_pos.set(MulDiv(self.0.split_pos.get(), dpi as _, prev_dpi as _));
+		}
+
+		let rc = self.hwnd().GetClientRect()?;
+		let (total, across) = match self.0.opts.panes {
+			SplitterPanes::LeftRight => (rc.right, rc.bottom),
+			SplitterPanes::TopBottom => (rc.bottom, rc.right),
+		};
+		let bar = Self::scale(self.0.opts.bar_width, dpi);
+		let min_first = Self::scale(self.0.opts.min_pane_sizes.0, dpi);
+		let min_second = Self::scale(self.0.opts.min_pane_sizes.1, dpi);
+
+		let max_first = (total - bar - min_second).max(min_first);
+		let pos = self.0.split_pos.get().clamp(min_first, max_first);
+		if self.0.drag_offset.get().is_some() {
+			self.0.split_pos.set(pos); // while dragging, the bar stops at the limits
+		}
+		let second = (total - pos - bar).max(0);
+
+		let (pos_first, sz_first, pos_second, sz_second) = match self.0.opts.panes {
+			SplitterPanes::LeftRight => (
+				POINT::new(0, 0), SIZE::new(pos, across),
+				POINT::new(pos + bar, 0), SIZE::new(second, across),
+			),
+			SplitterPanes::TopBottom => (
+				POINT::new(0, 0), SIZE::new(across, pos),
+				POINT::new(0, pos + bar), SIZE::new(across, second),
+			),
+		};
+
+		let mut hdwp = HDWP::BeginDeferWindowPos(2)?;
+		hdwp.DeferWindowPos(self.0.panes.0.hwnd(), HwndPlace::None,
+			pos_first, sz_first, co::SWP::NOZORDER | co::SWP::NOACTIVATE)?;
+		hdwp.DeferWindowPos(self.0.panes.1.hwnd(), HwndPlace::None,
+			pos_second, sz_second, co::SWP::NOZORDER | co::SWP::NOACTIVATE)?;
+		Ok(())
+	}
+
+	/// Returns the first and the second panes, which are the parents of the
+	/// controls to be shown within them.
+	#[must_use]
+	pub fn panes(&self) -> (&WindowControl, &WindowControl) {
+		(&self.0.panes.0, &self.0.panes.1)
+	}
+
+	/// Returns the current size of the first pane, in pixels.
+	#[must_use]
+	pub fn split_pos(&self) -> i32 {
+		self.0.split_pos.get()
+	}
+
+	/// Sets the size of the first pane, in pixels, respecting the minimum pane
+	/// sizes, and rearranges the panes.
+	pub fn set_split_pos(&self, pos: i32) -> SysResult<()> {
+		self.0.split_pos.set(pos);
+		self.arrange()
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`Splitter`](crate::gui::Splitter) programmatically
+/// with [`Splitter::new`](crate::gui::Splitter::new).
+pub struct SplitterOpts {
+	/// Left and top position coordinates of the splitter within parent's
+	/// client area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of the whole splitter, including both panes and the
+	/// bar, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in pixels, which will be multiplied to match current
+	/// system DPI.
+	///
+	/// Defaults to `(300, 200)`.
+	pub size: (u32, u32),
+	/// How the two panes are laid out.
+	///
+	/// Defaults to `SplitterPanes::LeftRight`.
+	pub panes: SplitterPanes,
+	/// Initial size of the first pane, in pixels, which will be multiplied to
+	/// match current DPI.
+	///
+	/// Defaults to `100`.
+	pub split_pos: u32,
+	/// Thickness of the bar between the panes, in pixels, which will be
+	/// multiplied to match current DPI.
+	///
+	/// Defaults to `4`.
+	pub bar_width: u32,
+	/// Minimum sizes of the first and second panes, in pixels, which will be
+	/// multiplied to match current DPI.
+	///
+	/// Defaults to `(20, 20)`.
+	pub min_pane_sizes: (u32, u32),
+	/// The control ID of the splitter container.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal behavior when the parent is resized.
+	///
+	/// Defaults to `Horz::None`.
+	pub horz_resize: Horz,
+	/// Vertical behavior when the parent is resized.
+	///
+	/// Defaults to `Vert::None`.
+	pub vert_resize: Vert,
+}
+
+impl Default for SplitterOpts {
+	fn default() -> Self {
+		Self {
+			position: (0, 0),
+			size: (300, 200),
+			panes: SplitterPanes::LeftRight,
+			split_pos: 100,
+			bar_width: 4,
+			min_pane_sizes: (20, 20),
+			ctrl_id: 0,
+			horz_resize: Horz::None,
+			vert_resize: Vert::None,
+		}
+	}
+}
@@ -0,0 +1,166 @@
+use std::cell::UnsafeCell;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::ole::decl::{CoCreateInstance, CoInitializeEx, HrResult};
+use crate::ole::guard::CoUninitializeGuard;
+use crate::prelude::{GuiEvents, shell_ITaskbarList, shell_ITaskbarList3};
+use crate::shell::decl::{ITaskbarList3, THUMBBUTTON};
+use crate::user::decl::RegisterWindowMessage;
+
+struct Obj { // actual fields of TaskbarProgress
+	parent_ptr: NonNull<Base>,
+	taskbar: UnsafeCell<Option<ITaskbarList3>>, // released before CoUninitialize
+	com_guard: UnsafeCell<Option<CoUninitializeGuard>>,
+	unsupported: UnsafeCell<bool>, // TaskbarList couldn't be created
+	button_created: UnsafeCell<bool>,
+	pending: UnsafeCell<Option<(co::TBPF, u64, u64)>>, // set before the button was created
+	thumbs_added: UnsafeCell<bool>, // ThumbBarAddButtons can be called only once
+	pending_thumbs: UnsafeCell<Vec<THUMBBUTTON>>, // set before the button was created
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Manages the progress and the thumbnail toolbar buttons shown in the taskbar
+/// button of a window, through [`ITaskbarList3`](crate::ITaskbarList3).
+///
+/// The COM object is created only when progress or buttons are first set, and
+/// only after the `TaskbarButtonCreated` message arrives; values set before
+/// that are kept and applied then.
+#[derive(Clone)]
+pub(in crate::gui) struct TaskbarProgress(Pin<Arc<Obj>>);
+
+impl TaskbarProgress {
+	pub(in crate::gui) fn new(parent_ref: &Base) -> Self {
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					taskbar: UnsafeCell::new(None),
+					com_guard: UnsafeCell::new(None),
+					unsupported: UnsafeCell::new(false),
+					button_created: UnsafeCell::new(false),
+					pending: UnsafeCell::new(None),
+					thumbs_added: UnsafeCell::new(false),
+					pending_thumbs: UnsafeCell::new(Vec::default()),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		if let Ok(button_created) = RegisterWindowMessage("TaskbarButtonCreated") {
+			let self2 = new_self.clone();
+			parent_ref.privileged_on().wm(co::WM(button_created), move |_| {
+				*unsafe { &mut *self2.0.button_created.get() } = true;
+				if let Some((state, completed, total)) = unsafe { &mut *self2.0.pending.get() }.take() {
+					self2.set(state, completed, total)?;
+				}
+				let pending_thumbs = std::mem::take(unsafe { &mut *self2.0.pending_thumbs.get() });
+				if !pending_thumbs.is_empty() {
+					self2.set_thumb_buttons(&pending_thumbs)?;
+				}
+				Ok(None) // not meaningful
+			});
+		}
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(co::WM::DESTROY, move |_| {
+			*unsafe { &mut *self2.0.taskbar.get() } = None; // COM object released before CoUninitialize
+			*unsafe { &mut *self2.0.com_guard.get() } = None;
+			Ok(None) // not meaningful
+		});
+
+		new_self
+	}
+
+	fn parent(&self) -> &Base {
+		unsafe { self.0.parent_ptr.as_ref() }
+	}
+
+	/// Sets the progress state and value; does nothing if the TaskbarList is
+	/// not available.
+	pub(in crate::gui) fn set(&self,
+		state: co::TBPF, completed: u64, total: u64) -> HrResult<()>
+	{
+		if !unsafe { *self.0.button_created.get() } {
+			*unsafe { &mut *self.0.pending.get() } = Some((state, completed, total));
+			return Ok(());
+		}
+
+		let taskbar = match self.taskbar() {
+			Some(taskbar) => taskbar,
+			None => return Ok(()), // unsupported system, silently ignore
+		};
+
+		let hwnd = self.parent().hwnd();
+		if state != co::TBPF::NOPROGRESS && state != co::TBPF::INDETERMINATE {
+			taskbar.SetProgressValue(hwnd, completed, total)?;
+		}
+		taskbar.SetProgressState(hwnd, state)
+	}
+
+	/// Adds the thumbnail toolbar buttons upon the first call, and updates
+	/// them, matched by `iId`, on subsequent calls; does nothing if the
+	/// TaskbarList is not available.
+	pub(in crate::gui) fn set_thumb_buttons(&self,
+		buttons: &[THUMBBUTTON]) -> HrResult<()>
+	{
+		if !unsafe { *self.0.button_created.get() } {
+			let pending_thumbs = unsafe { &mut *self.0.pending_thumbs.get() };
+			for button in buttons.iter() {
+				let button = unsafe { std::ptr::read(button) }; // plain data, HICON is not owned
+				match pending_thumbs.iter_mut().find(|p| p.iId == button.iId) {
+					Some(pending) => *pending = button,
+					None => pending_thumbs.push(button),
+				}
+			}
+			return Ok(());
+		}
+
+		let taskbar = match self.taskbar() {
+			Some(taskbar) => taskbar,
+			None => return Ok(()), // unsupported system, silently ignore
+		};
+
+		let hwnd = self.parent().hwnd();
+		let thumbs_added = unsafe { &mut *self.0.thumbs_added.get() };
+		if *thumbs_added {
+			taskbar.ThumbBarUpdateButtons(hwnd, buttons)
+		} else {
+			taskbar.ThumbBarAddButtons(hwnd, buttons)?;
+			*thumbs_added = true;
+			Ok(())
+		}
+	}
+
+	fn taskbar(&self) -> Option<&ITaskbarList3> {
+		let taskbar = unsafe { &mut *self.0.taskbar.get() };
+		if taskbar.is_none() && !unsafe { *self.0.unsupported.get() } {
+			match self.create_taskbar() {
+				Ok((obj, com_guard)) => {
+					*unsafe { &mut *self.0.com_guard.get() } = Some(com_guard);
+					*taskbar = Some(obj);
+				},
+				Err(_) => *unsafe { &mut *self.0.unsupported.get() } = true, // won't try again
+			}
+		}
+		taskbar.as_ref()
+	}
+
+	fn create_taskbar(&self) -> HrResult<(ITaskbarList3, CoUninitializeGuard)> {
+		let com_guard = CoInitializeEx(
+			co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+		let obj = CoCreateInstance::<ITaskbarList3>(
+			&co::CLSID::TaskbarList,
+			None,
+			co::CLSCTX::INPROC_SERVER,
+		)?;
+		obj.HrInit()?;
+		Ok((obj, com_guard))
+	}
+}
@@ -0,0 +1,174 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::co;
+use crate::gui::panic_handler::catch_panic;
+use crate::gui::privs::post_quit_error;
+use crate::kernel::decl::{AnyResult, GetCurrentThreadId, SysResult};
+use crate::msg::WndMsg;
+use crate::prelude::{GuiWindow, Handle, user_Hwnd};
+use crate::user::decl::HWND;
+
+static NEXT_TIMER_ID: AtomicUsize = AtomicUsize::new(0x5449_0000); // far from user-chosen IDs
+
+struct TimerEntry {
+	oneshot: bool,
+	func: Rc<RefCell<Box<dyn FnMut() -> AnyResult<()>>>>,
+}
+
+thread_local! {
+	// Timer closures, keyed by window handle and timer ID.
+	static TIMERS: RefCell<HashMap<(usize, usize), TimerEntry>> = RefCell::new(HashMap::new());
+}
+
+//------------------------------------------------------------------------------
+
+/// A timer tied to a window, created with
+/// [`HWND::SetTimer`](crate::prelude::user_Hwnd::SetTimer), which runs a
+/// closure when it fires. The timer is killed with
+/// [`HWND::KillTimer`](crate::prelude::user_Hwnd::KillTimer) when the object
+/// goes out of scope.
+///
+/// Since the timer requires an existing window, it's usually created within
+/// an event closure.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// let timer = Rc::new(RefCell::new(None::<gui::Timer>));
+///
+/// wnd.on().wm_create({
+///     let wnd = wnd.clone();
+///     let timer = timer.clone();
+///     move |_| {
+///         *timer.borrow_mut() = Some(
+///             gui::Timer::new_interval(&wnd, 1000, || {
+///                 println!("Tick");
+///                 Ok(())
+///             })?,
+///         );
+///         Ok(0)
+///     }
+/// });
+/// ```
+pub struct Timer {
+	hwnd: HWND,
+	id: usize,
+}
+
+impl Drop for Timer {
+	fn drop(&mut self) {
+		self.hwnd.KillTimer(self.id).ok(); // ignore errors, window may be gone
+		remove_timer(&self.hwnd, self.id);
+	}
+}
+
+impl Timer {
+	/// Creates a timer which runs the closure repeatedly, every `interval_ms`
+	/// milliseconds, until the object is dropped.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet, or if called from a thread
+	/// other than the window's UI thread.
+	pub fn new_interval<F>(wnd: &impl GuiWindow, interval_ms: u32, func: F) -> SysResult<Self>
+		where F: FnMut() -> AnyResult<()> + 'static,
+	{
+		Self::new(wnd.hwnd(), interval_ms, false, Box::new(func))
+	}
+
+	/// Creates a timer which runs the closure only once, after `delay_ms`
+	/// milliseconds.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is not created yet, or if called from a thread
+	/// other than the window's UI thread.
+	pub fn new_oneshot<F>(wnd: &impl GuiWindow, delay_ms: u32, func: F) -> SysResult<Self>
+		where F: FnMut() -> AnyResult<()> + 'static,
+	{
+		Self::new(wnd.hwnd(), delay_ms, true, Box::new(func))
+	}
+
+	fn new(
+		hwnd: &HWND,
+		elapse_ms: u32,
+		oneshot: bool,
+		func: Box<dyn FnMut() -> AnyResult<()>>,
+	) -> SysResult<Self>
+	{
+		if *hwnd == HWND::NULL {
+			panic!("Cannot create a timer before window creation.");
+		}
+		if hwnd.GetWindowThreadProcessId().0 != GetCurrentThreadId() {
+			panic!("Timers must be created in the window UI thread.");
+		}
+
+		let id = NEXT_TIMER_ID.fetch_add(1, Ordering::Relaxed);
+		TIMERS.with(|timers| {
+			timers.borrow_mut().insert((hwnd.as_ptr() as _, id), TimerEntry {
+				oneshot,
+				func: Rc::new(RefCell::new(func)),
+			});
+		});
+
+		match hwnd.SetTimer(id, elapse_ms, Some(Self::timer_proc)) {
+			Ok(_) => Ok(Self { hwnd: unsafe { hwnd.raw_copy() }, id }),
+			Err(e) => {
+				remove_timer(hwnd, id);
+				Err(e)
+			},
+		}
+	}
+
+	/// Returns the timer ID, which is automatically generated.
+	#[must_use]
+	pub const fn id(&self) -> usize {
+		self.id
+	}
+
+	extern "system" fn timer_proc(hwnd: HWND, msg: co::WM, id: usize, _: u32) {
+		let wm_any = WndMsg::new(msg, id, 0);
+		catch_panic(wm_any, || Self::timer_proc_proc(hwnd, id))
+			.unwrap_or_else(|err| post_quit_error(wm_any, err));
+	}
+
+	fn timer_proc_proc(hwnd: HWND, id: usize) -> AnyResult<()> {
+		let entry = TIMERS.with(|timers| {
+			timers.borrow()
+				.get(&(hwnd.as_ptr() as _, id))
+				.map(|entry| (entry.oneshot, entry.func.clone()))
+		});
+
+		match entry {
+			None => Ok(()), // timer already gone
+			Some((oneshot, func)) => {
+				if oneshot {
+					hwnd.KillTimer(id).ok();
+					remove_timer(&hwnd, id);
+				}
+				// The map is not borrowed while the closure runs, so it can
+				// create and drop other timers.
+				match func.try_borrow_mut() {
+					Ok(mut func) => func(),
+					Err(_) => Ok(()), // already running, reentrant call
+				}
+			},
+		}
+	}
+}
+
+fn remove_timer(hwnd: &HWND, id: usize) {
+	let entry = TIMERS.with(|timers| {
+		timers.borrow_mut().remove(&(hwnd.as_ptr() as _, id))
+	});
+	drop(entry); // closure dropped outside the borrow
+}
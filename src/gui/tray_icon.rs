@@ -0,0 +1,415 @@
+use std::cell::UnsafeCell;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::gui::popup_menu::PopupMenu;
+use crate::gui::privs::auto_ctrl_id;
+use crate::gui::raw_base::Icon;
+use crate::kernel::decl::{AnyResult, HIWORD, LOWORD, MAKEDWORD, SysResult};
+use crate::msg;
+use crate::ole::decl::{CoInitializeEx, HrResult};
+use crate::ole::guard::CoUninitializeGuard;
+use crate::prelude::{GuiEvents, GuiParent, Handle, user_Hwnd};
+use crate::shell::decl::{
+	GetCurrentProcessExplicitAppUserModelID, NOTIFYICONDATA, Shell_NotifyIcon,
+	ShownToast, Toast,
+};
+use crate::user::decl::{HICON, HWND, POINT, RegisterWindowMessage};
+
+/// Message sent by the shell to the parent window, with the tray icon events.
+const WM_TRAY_ICON: co::WM = co::WM(co::WM::APP.0 + 0x3ffd);
+
+/// Version of the tray icon behavior, set with
+/// [`NIM::SETVERSION`](crate::co::NIM::SETVERSION).
+const NOTIFYICON_VERSION_4: u32 = 4;
+
+struct Obj { // actual fields of TrayIcon
+	parent_ptr: NonNull<Base>,
+	opts: TrayIconOpts,
+	hicon: UnsafeCell<HICON>,
+	tip: UnsafeCell<String>,
+	context_menu: UnsafeCell<Option<PopupMenu>>,
+	click_fn: UnsafeCell<Option<Box<dyn Fn() -> AnyResult<()>>>>,
+	double_click_fn: UnsafeCell<Option<Box<dyn Fn() -> AnyResult<()>>>>,
+	balloon_click_fn: UnsafeCell<Option<Box<dyn Fn() -> AnyResult<()>>>>,
+	toast: UnsafeCell<Option<(ShownToast, CoUninitializeGuard)>>,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// An icon in the notification area of the taskbar, managed with
+/// [`Shell_NotifyIcon`](crate::Shell_NotifyIcon).
+///
+/// The icon is added when the parent window is created, and removed when the
+/// parent window is destroyed. If Windows Explorer restarts, the icon is added
+/// again, upon the `TaskbarCreated` message.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, gui, msg};
+///
+/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let tray = gui::TrayIcon::new(&wnd, gui::TrayIconOpts {
+///     tip: "My application".to_owned(),
+///     ..Default::default()
+/// });
+///
+/// let tray2 = tray.clone();
+/// tray.on_click(move || {
+///     tray2.show_balloon("Hello", "The icon was clicked.", co::NIIF::INFO)?;
+///     Ok(())
+/// });
+///
+/// let wnd2 = wnd.clone();
+/// tray.set_context_menu(gui::PopupMenu::new()
+///     .add_item("E&xit", move || {
+///         wnd2.hwnd().PostMessage(msg::wm::Close {})?;
+///         Ok(())
+///     }),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct TrayIcon(Pin<Arc<Obj>>);
+
+unsafe impl Send for TrayIcon {}
+
+impl TrayIcon {
+	/// Instantiates a new `TrayIcon` object, to be added to the notification
+	/// area along with the creation of the parent window.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `TrayIcon` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: TrayIconOpts) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+		let opts = TrayIconOpts::define_id(opts);
+		let tip = opts.tip.clone();
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					parent_ptr: NonNull::from(parent_ref),
+					opts,
+					hicon: UnsafeCell::new(HICON::NULL),
+					tip: UnsafeCell::new(tip),
+					context_menu: UnsafeCell::new(None),
+					click_fn: UnsafeCell::new(None),
+					double_click_fn: UnsafeCell::new(None),
+					balloon_click_fn: UnsafeCell::new(None),
+					toast: UnsafeCell::new(None),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(parent_ref.creation_msg(), move |_| {
+			let hinst = self2.parent().parent_hinstance()?;
+			*unsafe { &mut *self2.0.hicon.get() } = self2.0.opts.icon.as_hicon(&hinst)?;
+			self2.add()?;
+			Ok(None) // not meaningful
+		});
+
+		let taskbar_created = RegisterWindowMessage("TaskbarCreated").unwrap();
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(co::WM(taskbar_created), move |_| {
+			self2.add()?; // Explorer restarted, so the icon is gone
+			Ok(None) // not meaningful
+		});
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(co::WM::DESTROY, move |_| {
+			Shell_NotifyIcon(co::NIM::DELETE, &mut self2.nid(co::NIF::default())).ok(); // ignore errors
+			*unsafe { &mut *self2.0.toast.get() } = None; // COM objects released before CoUninitialize
+			Ok(None) // not meaningful
+		});
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm(WM_TRAY_ICON, move |p| {
+			if HIWORD(p.lparam as _) as u32 == self2.0.opts.id { // other tray icons share the message
+				self2.process_event(LOWORD(p.lparam as _) as _, p.wparam)?;
+			}
+			Ok(None) // not meaningful
+		});
+
+		new_self
+	}
+
+	fn parent(&self) -> &Base {
+		unsafe { self.0.parent_ptr.as_ref() }
+	}
+
+	fn nid(&self, flags: co::NIF) -> NOTIFYICONDATA {
+		let mut nid = NOTIFYICONDATA::default();
+		nid.hWnd = unsafe { self.parent().hwnd().raw_copy() };
+		nid.uID = self.0.opts.id;
+		nid.uFlags = flags;
+		nid
+	}
+
+	fn add(&self) -> SysResult<()> {
+		let mut nid = self.nid(
+			co::NIF::MESSAGE | co::NIF::ICON | co::NIF::TIP | co::NIF::SHOWTIP);
+		nid.uCallbackMessage = WM_TRAY_ICON;
+		nid.hIcon = unsafe { (*self.0.hicon.get()).raw_copy() };
+		nid.set_szTip(unsafe { &*self.0.tip.get() });
+		Shell_NotifyIcon(co::NIM::ADD, &mut nid)?;
+
+		nid.uVersion = NOTIFYICON_VERSION_4;
+		Shell_NotifyIcon(co::NIM::SETVERSION, &mut nid)
+	}
+
+	fn process_event(&self, event: u32, wparam: usize) -> AnyResult<()> {
+		let func = match co::NIN(event) {
+			co::NIN::SELECT | co::NIN::KEYSELECT => unsafe { &*self.0.click_fn.get() },
+			co::NIN::BALLOONUSERCLICK => unsafe { &*self.0.balloon_click_fn.get() },
+			_ => match co::WM(event) {
+				co::WM::LBUTTONDBLCLK => unsafe { &*self.0.double_click_fn.get() },
+				co::WM::CONTEXTMENU => {
+					if let Some(menu) = unsafe { &*self.0.context_menu.get() } {
+						let pos = POINT::new( // anchor coordinates, in screen
+							LOWORD(wparam as _) as i16 as _,
+							HIWORD(wparam as _) as i16 as _,
+						);
+						menu.show_at_hwnd(self.parent().hwnd(), pos)?;
+					}
+					return Ok(());
+				},
+				_ => return Ok(()),
+			},
+		};
+
+		match func {
+			Some(func) => func(),
+			None => Ok(()),
+		}
+	}
+
+	/// Returns the ID of the icon, which identifies it along with the parent
+	/// window.
+	#[must_use]
+	pub fn id(&self) -> u32 {
+		self.0.opts.id
+	}
+
+	/// Sets the closure to be called when the user clicks a balloon
+	/// notification shown by
+	/// [`show_balloon`](crate::gui::TrayIcon::show_balloon), or a notification
+	/// shown by
+	/// [`show_notification`](crate::gui::TrayIcon::show_notification). Only
+	/// the last closure is kept.
+	pub fn on_balloon_click<F>(&self, func: F)
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		*unsafe { &mut *self.0.balloon_click_fn.get() } = Some(Box::new(func));
+	}
+
+	/// Sets the closure to be called when the user clicks the icon, or
+	/// selects it with the keyboard. Only the last closure is kept.
+	pub fn on_click<F>(&self, func: F)
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		*unsafe { &mut *self.0.click_fn.get() } = Some(Box::new(func));
+	}
+
+	/// Sets the closure to be called when the user double-clicks the icon.
+	/// Only the last closure is kept.
+	///
+	/// Note that the first click of a double-click is also delivered to
+	/// [`on_click`](crate::gui::TrayIcon::on_click).
+	pub fn on_double_click<F>(&self, func: F)
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		*unsafe { &mut *self.0.double_click_fn.get() } = Some(Box::new(func));
+	}
+
+	/// Sets the menu to be shown when the user right-clicks the icon, or
+	/// presses the context menu key over it.
+	pub fn set_context_menu(&self, menu: PopupMenu) {
+		*unsafe { &mut *self.0.context_menu.get() } = Some(menu);
+	}
+
+	/// Replaces the icon.
+	///
+	/// The icon is not copied, so it must remain valid while displayed.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is not created yet.
+	pub fn set_icon(&self, hicon: &HICON) -> SysResult<()> {
+		*unsafe { &mut *self.0.hicon.get() } = unsafe { hicon.raw_copy() };
+		let mut nid = self.nid(co::NIF::ICON);
+		nid.hIcon = unsafe { hicon.raw_copy() };
+		Shell_NotifyIcon(co::NIM::MODIFY, &mut nid)
+	}
+
+	/// Replaces the tooltip text, displayed when the mouse hovers the icon.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is not created yet.
+	pub fn set_tip(&self, text: &str) -> SysResult<()> {
+		*unsafe { &mut *self.0.tip.get() } = text.to_owned();
+		let mut nid = self.nid(co::NIF::TIP | co::NIF::SHOWTIP);
+		nid.set_szTip(text);
+		Shell_NotifyIcon(co::NIM::MODIFY, &mut nid)
+	}
+
+	/// Displays a balloon notification next to the icon.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is not created yet.
+	pub fn show_balloon(&self,
+		title: &str, text: &str, icon: co::NIIF) -> SysResult<()>
+	{
+		let mut nid = self.nid(co::NIF::INFO);
+		nid.set_szInfoTitle(title);
+		nid.set_szInfo(text);
+		nid.dwInfoFlags = icon;
+		Shell_NotifyIcon(co::NIM::MODIFY, &mut nid)
+	}
+
+	/// Displays a notification as a modern toast, falling back to a
+	/// [balloon](crate::gui::TrayIcon::show_balloon) if the toast cannot be
+	/// shown – for example, on systems older than Windows 10, or if the
+	/// application has no AppUserModelID.
+	///
+	/// Clicks on the notification are delivered to
+	/// [`on_balloon_click`](crate::gui::TrayIcon::on_balloon_click), in the
+	/// thread of the parent window.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window is not created yet.
+	pub fn show_notification(&self, opts: NotificationOpts) -> SysResult<()> {
+		match self.show_toast(&opts) {
+			Ok(shown) => {
+				*unsafe { &mut *self.0.toast.get() } = Some(shown);
+				Ok(())
+			},
+			Err(_) => self.show_balloon(&opts.title, &opts.body, opts.balloon_icon),
+		}
+	}
+
+	fn show_toast(&self,
+		opts: &NotificationOpts) -> HrResult<(ShownToast, CoUninitializeGuard)>
+	{
+		let app_id = match &opts.app_id {
+			Some(app_id) => app_id.clone(),
+			None => GetCurrentProcessExplicitAppUserModelID()?,
+		};
+		let com_guard = CoInitializeEx(
+			co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+		*unsafe { &mut *self.0.toast.get() } = None; // previous toast released before CoUninitialize
+
+		let hparent = self.parent().hwnd().as_ptr() as usize; // the handler runs in another thread
+		let lparam = MAKEDWORD(co::NIN::BALLOONUSERCLICK.0 as _, self.0.opts.id as _);
+		let mut toast = Toast::new(&opts.title, &opts.body)
+			.on_activated(move || {
+				let hparent = unsafe { HWND::from_ptr(hparent as _) };
+				hparent.PostMessage(msg::WndMsg {
+					msg_id: WM_TRAY_ICON,
+					wparam: 0,
+					lparam: lparam as _,
+				}).ok(); // ignore errors
+			});
+		if let Some(image) = &opts.image {
+			toast = toast.image(image);
+		}
+
+		let shown = toast.show(&app_id)?;
+		Ok((shown, com_guard))
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to show a notification with
+/// [`TrayIcon::show_notification`](crate::gui::TrayIcon::show_notification).
+pub struct NotificationOpts {
+	/// Title of the notification.
+	///
+	/// Defaults to empty string.
+	pub title: String,
+	/// Body text of the notification.
+	///
+	/// Defaults to empty string.
+	pub body: String,
+	/// Path or URI of an image to be displayed in the toast. Not used by the
+	/// balloon fallback.
+	///
+	/// Defaults to `None`.
+	pub image: Option<String>,
+	/// AppUserModelID used to show the toast.
+	///
+	/// Defaults to `None`, meaning the value retrieved with
+	/// [`GetCurrentProcessExplicitAppUserModelID`](crate::GetCurrentProcessExplicitAppUserModelID).
+	pub app_id: Option<String>,
+	/// Icon of the balloon fallback.
+	///
+	/// Defaults to `co::NIIF::INFO`.
+	pub balloon_icon: co::NIIF,
+}
+
+impl Default for NotificationOpts {
+	fn default() -> Self {
+		Self {
+			title: "".to_owned(),
+			body: "".to_owned(),
+			image: None,
+			app_id: None,
+			balloon_icon: co::NIIF::INFO,
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`TrayIcon`](crate::gui::TrayIcon) with
+/// [`TrayIcon::new`](crate::gui::TrayIcon::new).
+pub struct TrayIconOpts {
+	/// ID of the icon, which must be unique among the icons of the same
+	/// parent window.
+	///
+	/// Defaults to an auto-generated ID.
+	pub id: u32,
+	/// Icon to be displayed.
+	///
+	/// Defaults to `gui::Icon::Idi(co::IDI::APPLICATION)`.
+	pub icon: Icon,
+	/// Tooltip text, displayed when the mouse hovers the icon. Limited to 127
+	/// characters.
+	///
+	/// Defaults to empty string.
+	pub tip: String,
+}
+
+impl Default for TrayIconOpts {
+	fn default() -> Self {
+		Self {
+			id: 0,
+			icon: Icon::Idi(co::IDI::APPLICATION),
+			tip: "".to_owned(),
+		}
+	}
+}
+
+impl TrayIconOpts {
+	fn define_id(mut self) -> Self {
+		if self.id == 0 {
+			self.id = auto_ctrl_id() as _;
+		}
+		self
+	}
+}
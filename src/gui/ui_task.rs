@@ -0,0 +1,198 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::kernel::decl::{AnyResult, GetCurrentThreadId};
+use crate::prelude::{Handle, user_Hwnd};
+use crate::user::decl::HWND;
+
+struct UiTask {
+	hwnd_root: usize,
+	future: Pin<Box<dyn Future<Output = AnyResult<()>>>>,
+}
+
+thread_local! {
+	// Tasks are polled only in the UI thread which spawned them.
+	static UI_TASKS: RefCell<HashMap<u64, UiTask>> = RefCell::new(HashMap::new());
+	static NEXT_ID: Cell<u64> = Cell::new(1);
+}
+
+/// Schedules the future to be polled in the UI thread of the given window.
+pub(in crate::gui) fn spawn_ui_future<F>(hwnd: &HWND, future: F)
+	where F: Future<Output = AnyResult<()>> + 'static,
+{
+	let hwnd_root = match hwnd.GetAncestor(co::GA::ROOTOWNER) {
+		Some(hwnd_root) if hwnd_root.IsWindow() => hwnd_root,
+		_ => panic!("Cannot spawn a UI future before window creation."),
+	};
+	if hwnd_root.GetWindowThreadProcessId().0 != GetCurrentThreadId() {
+		panic!("UI futures must be spawned from the window UI thread.");
+	}
+
+	let id = NEXT_ID.with(|next_id| {
+		let id = next_id.get();
+		next_id.set(id + 1);
+		id
+	});
+	UI_TASKS.with(|tasks| {
+		tasks.borrow_mut().insert(id, UiTask {
+			hwnd_root: hwnd_root.as_ptr() as _,
+			future: Box::pin(future),
+		});
+	});
+
+	// The first poll is also posted, so the future never runs inside the
+	// event handler which spawned it.
+	Arc::new(UiTaskWaker { hwnd_root: hwnd_root.as_ptr() as _, id }).wake();
+}
+
+/// Drops all pending futures tied to the given root window, which is being
+/// destroyed.
+pub(in crate::gui) fn drop_ui_futures(hwnd: &HWND) {
+	let hwnd_root = hwnd.as_ptr() as usize;
+	let dropped = UI_TASKS.with(|tasks| {
+		let mut tasks = tasks.borrow_mut();
+		let ids = tasks.iter()
+			.filter(|(_, task)| task.hwnd_root == hwnd_root)
+			.map(|(id, _)| *id)
+			.collect::<Vec<_>>();
+		ids.iter()
+			.filter_map(|id| tasks.remove(id))
+			.collect::<Vec<_>>()
+	});
+	drop(dropped); // futures dropped outside the borrow, they may spawn again
+}
+
+fn poll_ui_future(id: u64) -> AnyResult<()> {
+	// The task is taken out of the map while polled, so the future itself can
+	// safely spawn other futures.
+	let mut task = match UI_TASKS.with(|tasks| tasks.borrow_mut().remove(&id)) {
+		Some(task) => task,
+		None => return Ok(()), // already completed or dropped
+	};
+
+	let waker = Waker::from(Arc::new(UiTaskWaker { hwnd_root: task.hwnd_root, id }));
+	let mut cx = Context::from_waker(&waker);
+
+	match task.future.as_mut().poll(&mut cx) {
+		Poll::Ready(res) => res,
+		Poll::Pending => {
+			UI_TASKS.with(|tasks| tasks.borrow_mut().insert(id, task));
+			Ok(())
+		},
+	}
+}
+
+/// Wakes a UI task by posting a message to its root window, so the task is
+/// polled again in the UI thread.
+struct UiTaskWaker {
+	hwnd_root: usize,
+	id: u64,
+}
+
+impl Wake for UiTaskWaker {
+	fn wake(self: Arc<Self>) {
+		self.wake_by_ref();
+	}
+
+	fn wake_by_ref(self: &Arc<Self>) {
+		let id = self.id;
+		let hwnd_root = unsafe { HWND::from_ptr(self.hwnd_root as _) };
+		Base::post_ui_thread(&hwnd_root, move || poll_ui_future(id));
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Runs a closure in a new thread, returning a
+/// [`Future`](https://doc.rust-lang.org/std/future/trait.Future.html) which
+/// resolves to the closure result.
+///
+/// This is intended to move blocking work out of a future spawned with
+/// [`spawn_ui_future`](crate::prelude::GuiThread::spawn_ui_future), so the
+/// continuation resumes in the UI thread once the work is done.
+///
+/// If the closure panics, the panic is resumed when the future is polled.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// wnd.spawn_ui_future({
+///     let wnd = wnd.clone();
+///     async move {
+///         let total = gui::spawn_blocking(|| (0..1_000_000u64).sum::<u64>()).await;
+///         wnd.hwnd().SetWindowText(&format!("Total: {}", total))?;
+///         Ok(())
+///     }
+/// });
+/// ```
+#[must_use]
+pub fn spawn_blocking<F, T>(func: F) -> BlockingTask<T>
+	where F: FnOnce() -> T + Send + 'static,
+		T: Send + 'static,
+{
+	let shared = Arc::new(Mutex::new(BlockingState {
+		result: None,
+		waker: None,
+	}));
+
+	std::thread::spawn({
+		let shared = shared.clone();
+		move || {
+			let result = panic::catch_unwind(AssertUnwindSafe(func));
+			let waker = {
+				let mut state = shared.lock().unwrap();
+				state.result = Some(result);
+				state.waker.take()
+			};
+			if let Some(waker) = waker {
+				waker.wake();
+			}
+		}
+	});
+
+	BlockingTask { shared }
+}
+
+struct BlockingState<T> {
+	result: Option<std::thread::Result<T>>,
+	waker: Option<Waker>,
+}
+
+/// A [`Future`](https://doc.rust-lang.org/std/future/trait.Future.html)
+/// returned by [`spawn_blocking`](crate::gui::spawn_blocking), which resolves
+/// to the result of the closure running in another thread.
+pub struct BlockingTask<T> {
+	shared: Arc<Mutex<BlockingState<T>>>,
+}
+
+impl<T> Future for BlockingTask<T> {
+	type Output = T;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let mut state = self.shared.lock().unwrap();
+		match state.result.take() {
+			Some(Ok(val)) => Poll::Ready(val),
+			Some(Err(payload)) => {
+				drop(state);
+				panic::resume_unwind(payload)
+			},
+			None => {
+				state.waker = Some(cx.waker().clone());
+				Poll::Pending
+			},
+		}
+	}
+}
@@ -0,0 +1,264 @@
+use std::cell::RefCell;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::gui::privs::auto_ctrl_id;
+use crate::kernel::decl::AnyResult;
+use crate::prelude::{GuiEvents, GuiEventsAll, GuiParent, GuiUndoCommand, user_Hmenu};
+use crate::user::decl::{ACCEL, IdPos};
+
+struct Obj { // actual fields of UndoStack
+	opts: UndoStackOpts,
+	undo_cmds: RefCell<Vec<Box<dyn GuiUndoCommand>>>,
+	redo_cmds: RefCell<Vec<Box<dyn GuiUndoCommand>>>,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Stack of [`GuiUndoCommand`](crate::prelude::GuiUndoCommand) objects, which
+/// implements the undo/redo operations of an editor.
+///
+/// The stack handles the
+/// [`WM_COMMAND`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-command)
+/// messages of both its undo and redo command IDs, coming from either menus or
+/// accelerators. When a popup menu is about to be displayed, the items with
+/// these IDs are automatically enabled or disabled, according to the state of
+/// the stack.
+///
+/// The accelerator entries for `Ctrl+Z` and `Ctrl+Y` are returned by
+/// [`accelerators`](crate::gui::UndoStack::accelerators).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{gui, AnyResult, HACCEL};
+///
+/// struct AppendText { txt: gui::Edit, text: String }
+///
+/// impl GuiUndoCommand for AppendText {
+///     fn execute(&self) -> AnyResult<()> {
+///         let cur = self.txt.text();
+///         self.txt.set_text(&format!("{}{}", cur, self.text));
+///         Ok(())
+///     }
+///
+///     fn undo(&self) -> AnyResult<()> {
+///         let cur = self.txt.text();
+///         self.txt.set_text(&cur[..cur.len() - self.text.len()]);
+///         Ok(())
+///     }
+/// }
+///
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// # let txt = gui::Edit::new(&wnd, gui::EditOpts::default());
+/// let undo = gui::UndoStack::new(&wnd, gui::UndoStackOpts::default());
+/// let haccel = HACCEL::CreateAcceleratorTable(&mut undo.accelerators())?;
+///
+/// undo.execute(AppendText { txt: txt.clone(), text: "abc".to_owned() })?;
+/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[derive(Clone)]
+pub struct UndoStack(Pin<Arc<Obj>>);
+
+unsafe impl Send for UndoStack {}
+
+impl UndoStack {
+	/// Instantiates a new `UndoStack` object, which will handle the undo and
+	/// redo commands sent to the given window.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create an `UndoStack` in an event closure.
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: UndoStackOpts) -> Self {
+		let parent_ref = unsafe { Base::from_guiparent(parent) };
+		let opts = UndoStackOpts::define_cmd_ids(opts);
+		let (undo_cmd_id, redo_cmd_id) = (opts.undo_cmd_id, opts.redo_cmd_id);
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					opts,
+					undo_cmds: RefCell::new(Vec::new()),
+					redo_cmds: RefCell::new(Vec::new()),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm_command_accel_menu(undo_cmd_id, move || {
+			self2.undo()?;
+			Ok(())
+		});
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm_command_accel_menu(redo_cmd_id, move || {
+			self2.redo()?;
+			Ok(())
+		});
+
+		let self2 = new_self.clone();
+		parent_ref.privileged_on().wm_init_menu_popup(move |p| {
+			// Menus which don't have the items simply fail, so errors are ignored.
+			p.hmenu.EnableMenuItem(IdPos::Id(undo_cmd_id), self2.can_undo()).ok();
+			p.hmenu.EnableMenuItem(IdPos::Id(redo_cmd_id), self2.can_redo()).ok();
+			Ok(())
+		});
+
+		new_self
+	}
+
+	// The borrows are never held while a command runs, because the command may
+	// call back into the stack.
+
+	fn push_undo(&self, cmd: Box<dyn GuiUndoCommand>) {
+		let mut undo_cmds = self.0.undo_cmds.borrow_mut();
+		undo_cmds.push(cmd);
+		if self.0.opts.max_depth > 0 && undo_cmds.len() > self.0.opts.max_depth {
+			undo_cmds.remove(0); // discard the oldest command
+		}
+	}
+
+	/// Returns the accelerator entries which bind `Ctrl+Z` and `Ctrl+Y` to the
+	/// undo and redo command IDs, to be passed to
+	/// [`HACCEL::CreateAcceleratorTable`](crate::prelude::user_Haccel::CreateAcceleratorTable).
+	#[must_use]
+	pub fn accelerators(&self) -> [ACCEL; 2] {
+		[
+			ACCEL {
+				fVirt: co::ACCELF::VIRTKEY | co::ACCELF::CONTROL,
+				key: co::VK::CHAR_Z,
+				cmd: self.0.opts.undo_cmd_id,
+			},
+			ACCEL {
+				fVirt: co::ACCELF::VIRTKEY | co::ACCELF::CONTROL,
+				key: co::VK::CHAR_Y,
+				cmd: self.0.opts.redo_cmd_id,
+			},
+		]
+	}
+
+	/// Returns whether there is any command to be redone.
+	#[must_use]
+	pub fn can_redo(&self) -> bool {
+		!self.0.redo_cmds.borrow().is_empty()
+	}
+
+	/// Returns whether there is any command to be undone.
+	#[must_use]
+	pub fn can_undo(&self) -> bool {
+		!self.0.undo_cmds.borrow().is_empty()
+	}
+
+	/// Discards all the stored commands.
+	pub fn clear(&self) {
+		self.0.undo_cmds.borrow_mut().clear();
+		self.0.redo_cmds.borrow_mut().clear();
+	}
+
+	/// Executes the command and pushes it onto the stack, discarding all the
+	/// commands which could be redone.
+	///
+	/// If the command fails, it's not pushed.
+	pub fn execute(&self, cmd: impl GuiUndoCommand + 'static) -> AnyResult<()> {
+		cmd.execute()?;
+		self.push(cmd);
+		Ok(())
+	}
+
+	/// Pushes a command which has already been executed onto the stack,
+	/// discarding all the commands which could be redone.
+	pub fn push(&self, cmd: impl GuiUndoCommand + 'static) {
+		self.0.redo_cmds.borrow_mut().clear();
+		self.push_undo(Box::new(cmd));
+	}
+
+	/// Redoes the last undone command, if any, returning whether a command was
+	/// redone.
+	///
+	/// If the command fails, it remains available to be redone.
+	pub fn redo(&self) -> AnyResult<bool> {
+		let cmd = self.0.redo_cmds.borrow_mut().pop(); // borrow released here
+		let cmd = match cmd {
+			Some(cmd) => cmd,
+			None => return Ok(false),
+		};
+
+		if let Err(e) = cmd.execute() {
+			self.0.redo_cmds.borrow_mut().push(cmd);
+			return Err(e);
+		}
+		self.push_undo(cmd);
+		Ok(true)
+	}
+
+	/// Undoes the last executed command, if any, returning whether a command
+	/// was undone.
+	///
+	/// If the command fails, it remains available to be undone.
+	pub fn undo(&self) -> AnyResult<bool> {
+		let cmd = self.0.undo_cmds.borrow_mut().pop(); // borrow released here
+		let cmd = match cmd {
+			Some(cmd) => cmd,
+			None => return Ok(false),
+		};
+
+		if let Err(e) = cmd.undo() {
+			self.0.undo_cmds.borrow_mut().push(cmd);
+			return Err(e);
+		}
+		self.0.redo_cmds.borrow_mut().push(cmd);
+		Ok(true)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create an [`UndoStack`](crate::gui::UndoStack) with
+/// [`UndoStack::new`](crate::gui::UndoStack::new).
+pub struct UndoStackOpts {
+	/// Command ID of the undo operation, shared by menu items and
+	/// accelerators.
+	///
+	/// Defaults to an auto-generated ID.
+	pub undo_cmd_id: u16,
+	/// Command ID of the redo operation, shared by menu items and
+	/// accelerators.
+	///
+	/// Defaults to an auto-generated ID.
+	pub redo_cmd_id: u16,
+	/// Maximum number of commands which can be undone. When exceeded, the
+	/// oldest commands are discarded. If zero, there is no limit.
+	///
+	/// Defaults to `100`.
+	pub max_depth: usize,
+}
+
+impl Default for UndoStackOpts {
+	fn default() -> Self {
+		Self {
+			undo_cmd_id: 0,
+			redo_cmd_id: 0,
+			max_depth: 100,
+		}
+	}
+}
+
+impl UndoStackOpts {
+	fn define_cmd_ids(mut self) -> Self {
+		if self.undo_cmd_id == 0 {
+			self.undo_cmd_id = auto_ctrl_id();
+		}
+		if self.redo_cmd_id == 0 {
+			self.redo_cmd_id = auto_ctrl_id();
+		}
+		self
+	}
+}
@@ -17,6 +17,61 @@ enum RawDlg { Raw(RawControl), Dlg(DlgControl) }
 
 /// An user child window, which can handle events. Can be programmatically
 /// created or load a dialog resource from a `.res` file.
+///
+/// Since it's an ordinary window, it's ideal to build custom-drawn panels,
+/// which are painted and respond to mouse input by themselves.
+///
+/// # Examples
+///
+/// A panel which draws a circle where the user clicks, and stretches along
+/// with its parent:
+///
+/// ```rust,no_run
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// use winsafe::prelude::*;
+/// use winsafe::{gui, msg, AnyResult, POINT, RECT};
+///
+/// let wnd: gui::WindowMain; // initialized somewhere
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+///
+/// let panel = gui::WindowControl::new(
+///     &wnd,
+///     gui::WindowControlOpts {
+///         position: (10, 10),
+///         size: (300, 200),
+///         horz_resize: gui::Horz::Resize,
+///         vert_resize: gui::Vert::Resize,
+///         ..Default::default()
+///     },
+/// );
+///
+/// let click = Rc::new(Cell::new(POINT::default()));
+///
+/// panel.on().wm_paint({
+///     let panel = panel.clone();
+///     let click = click.clone();
+///     move || -> AnyResult<()> {
+///         let hdc = panel.hwnd().BeginPaint()?;
+///         let pt = click.get();
+///         hdc.Ellipse(RECT {
+///             left: pt.x - 10, top: pt.y - 10,
+///             right: pt.x + 10, bottom: pt.y + 10,
+///         })?;
+///         Ok(())
+///     }
+/// });
+///
+/// panel.on().wm_l_button_down({
+///     let panel = panel.clone();
+///     move |p: msg::wm::LButtonDown| -> AnyResult<()> {
+///         click.set(p.coords);
+///         panel.hwnd().InvalidateRect(None, true)?; // schedule a repaint
+///         Ok(())
+///     }
+/// });
+/// ```
 #[derive(Clone)]
 pub struct WindowControl(RawDlg);
 
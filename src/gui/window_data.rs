@@ -0,0 +1,71 @@
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::co;
+use crate::kernel::decl::GetCurrentThreadId;
+use crate::prelude::{comctl_Hwnd, Handle, user_Hwnd};
+use crate::user::decl::HWND;
+
+type TypedValues = HashMap<TypeId, Box<dyn Any>>;
+
+thread_local! {
+	// User data of each window, keyed by window handle and then by type.
+	static WINDOW_DATA: RefCell<HashMap<usize, TypedValues>> = RefCell::new(HashMap::new());
+}
+
+/// Stores the value for the given window, returning the previous value of the
+/// same type. Upon the first value, installs a subclass which drops all the
+/// values of the window when it's destroyed.
+pub(in crate::gui) fn set_window_data<T: 'static>(hwnd: &HWND, value: T) -> Option<T> {
+	if *hwnd == HWND::NULL {
+		panic!("Cannot set window data before window creation.");
+	}
+	if hwnd.GetWindowThreadProcessId().0 != GetCurrentThreadId() {
+		panic!("Window data must be set in the window UI thread.");
+	}
+
+	let (prev, first) = WINDOW_DATA.with(|data| {
+		let mut data = data.borrow_mut();
+		let first = !data.contains_key(&(hwnd.as_ptr() as _));
+		let prev = data.entry(hwnd.as_ptr() as _)
+			.or_default()
+			.insert(TypeId::of::<T>(), Box::new(value));
+		(prev, first)
+	});
+
+	if first {
+		hwnd.add_subclass(i32::MIN, |hwnd, p| { // runs after any other subclass
+			if p.msg_id == co::WM::NCDESTROY {
+				let values = WINDOW_DATA.with(|data| {
+					data.borrow_mut().remove(&(hwnd.as_ptr() as _))
+				});
+				drop(values); // values dropped outside the borrow
+			}
+			None
+		}).unwrap();
+	}
+
+	prev.map(|prev| *prev.downcast::<T>().unwrap())
+}
+
+/// Returns a copy of the value of the given type stored for the window.
+#[must_use]
+pub(in crate::gui) fn get_window_data<T: Clone + 'static>(hwnd: &HWND) -> Option<T> {
+	WINDOW_DATA.with(|data| {
+		data.borrow()
+			.get(&(hwnd.as_ptr() as _))
+			.and_then(|values| values.get(&TypeId::of::<T>()))
+			.and_then(|value| value.downcast_ref::<T>())
+			.cloned()
+	})
+}
+
+/// Removes and returns the value of the given type stored for the window.
+pub(in crate::gui) fn take_window_data<T: 'static>(hwnd: &HWND) -> Option<T> {
+	WINDOW_DATA.with(|data| {
+		data.borrow_mut()
+			.get_mut(&(hwnd.as_ptr() as _))
+			.and_then(|values| values.remove(&TypeId::of::<T>()))
+	}).map(|value| *value.downcast::<T>().unwrap())
+}
@@ -2,17 +2,27 @@ use std::any::Any;
 
 use crate::co;
 use crate::comctl::decl::InitCommonControls;
+use crate::gui::base::Base;
 use crate::gui::dlg_main::DlgMain;
-use crate::gui::events::WindowEventsAll;
-use crate::gui::privs::{create_ui_font, delete_ui_font};
+use crate::gui::events::{CloseDecision, CloseReason, WindowEventsAll};
+use crate::gui::message_loop::MessageLoop;
+use crate::gui::privs::{create_ui_font, delete_ui_font, set_dpi_awareness};
 use crate::gui::raw_main::{RawMain, WindowMainOpts};
+use crate::gui::taskbar_progress::TaskbarProgress;
 use crate::kernel::decl::{AnyResult, HPROCESS, IsWindowsVistaOrGreater};
 use crate::kernel::ffi_types::BOOL;
+use crate::msg::WndMsg;
+use crate::ole::decl::HrResult;
 use crate::prelude::{
-	GuiParent, GuiThread, GuiWindow, GuiWindowText, kernel_Hprocess,
-	user_Hprocess,
+	GuiEvents, GuiParent, GuiThread, GuiWindow, GuiWindowText, kernel_Hprocess,
+	user_Hprocess, user_Hwnd,
 };
-use crate::user::decl::{HWND, SetProcessDPIAware};
+use crate::shell::decl::THUMBBUTTON;
+use crate::user::decl::HWND;
+
+/// Sent as `WPARAM` of `WM_CLOSE` by [`WindowMain::close`], so the close
+/// pipeline can tell it apart from a close requested by the user.
+const PROGRAMMATIC_CLOSE: usize = 0x5753_434c; // arbitrary
 
 /// Keeps a raw or dialog window.
 #[derive(Clone)]
@@ -24,7 +34,7 @@ enum RawDlg { Raw(RawMain), Dlg(DlgMain) }
 /// window of your application, launched directly from the `main` function. Can
 /// be programmatically created or load a dialog resource from a `.res` file.
 #[derive(Clone)]
-pub struct WindowMain(RawDlg);
+pub struct WindowMain(RawDlg, TaskbarProgress);
 
 unsafe impl Send for WindowMain {}
 
@@ -84,16 +94,42 @@ impl WindowMain {
 	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
 	#[must_use]
 	pub fn new(opts: WindowMainOpts) -> Self {
-		Self(
-			RawDlg::Raw(
-				RawMain::new(opts),
-			),
-		)
+		let raw = RawMain::new(opts);
+		let taskbar = TaskbarProgress::new(unsafe { &*(raw.as_base() as *const Base) });
+		let new_self = Self(RawDlg::Raw(raw), taskbar);
+		new_self.default_message_handlers();
+		new_self
 	}
 
 	/// Instantiates a new `WindowMain` object, to be loaded from a dialog
 	/// resource with
 	/// [`HINSTANCE::CreateDialogParam`](crate::prelude::user_Hinstance::CreateDialogParam).
+	///
+	/// The controls of the dialog resource must be loaded with their `new_dlg`
+	/// constructors, using the same control IDs of the resource file.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui};
+	///
+	/// const DLG_MAIN: u16 = 101; // IDs defined in the resource file
+	/// const ICO_MAIN: u16 = 102;
+	/// const BTN_OK: u16 = 1001;
+	///
+	/// let wnd = gui::WindowMain::new_dlg(DLG_MAIN, Some(ICO_MAIN), None);
+	/// let btn = gui::Button::new_dlg(&wnd, BTN_OK, (gui::Horz::None, gui::Vert::None));
+	///
+	/// let wnd2 = wnd.clone();
+	/// btn.on().bn_clicked(move || {
+	///     wnd2.hwnd().SetWindowText("Clicked")?;
+	///     Ok(())
+	/// });
+	///
+	/// wnd.run_main(None)?;
+	/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+	/// ```
 	#[must_use]
 	pub fn new_dlg(
 		dialog_id: u16,
@@ -101,11 +137,65 @@ impl WindowMain {
 		accel_table_id: Option<u16>,
 	) -> Self
 	{
-		Self(
-			RawDlg::Dlg(
-				DlgMain::new(dialog_id, icon_id, accel_table_id),
-			),
-		)
+		let dlg = DlgMain::new(dialog_id, icon_id, accel_table_id);
+		let taskbar = TaskbarProgress::new(unsafe { &*(dlg.as_base() as *const Base) });
+		let new_self = Self(RawDlg::Dlg(dlg), taskbar);
+		new_self.default_message_handlers();
+		new_self
+	}
+
+	/// The close pipeline is made of privileged closures, so user closures
+	/// for the same messages still run, but the results come from here.
+	fn default_message_handlers(&self) {
+		let base = self.base();
+
+		let self2 = self.clone();
+		base.privileged_on().wm(co::WM::CLOSE, move |p| {
+			let reason = if p.wparam == PROGRAMMATIC_CLOSE {
+				CloseReason::Programmatic
+			} else {
+				CloseReason::User
+			};
+			if self2.base().process_closing(reason)? == CloseDecision::Close {
+				self2.hwnd().DestroyWindow().ok(); // ignore errors
+			}
+			self2.base().set_privileged_ret(match &self2.0 {
+				RawDlg::Raw(_) => 0,
+				RawDlg::Dlg(_) => 1, // TRUE, so DefDlgProc won't process it
+			});
+			Ok(None)
+		});
+
+		let self2 = self.clone();
+		base.privileged_on().wm(co::WM::QUERYENDSESSION, move |p| {
+			let reason = CloseReason::SessionEnd(co::ENDSESSION(p.lparam as _));
+			let can_end: isize = match self2.base().process_closing(reason)? {
+				CloseDecision::Close => 1, // TRUE
+				CloseDecision::Cancel => 0, // FALSE
+				CloseDecision::BlockShutdown(text) => {
+					self2.hwnd().ShutdownBlockReasonCreate(&text).ok(); // ignore errors
+					0 // FALSE
+				},
+			};
+			self2.base().set_privileged_ret(match &self2.0 {
+				RawDlg::Raw(_) => can_end,
+				RawDlg::Dlg(d) => {
+					d.hwnd().SetWindowLongPtr(co::GWLP::DWLP_MSGRESULT, can_end);
+					1 // TRUE
+				},
+			});
+			Ok(None)
+		});
+
+		let self2 = self.clone();
+		base.privileged_on().wm(co::WM::ENDSESSION, move |_| {
+			self2.hwnd().ShutdownBlockReasonDestroy().ok(); // there may be no reason
+			Ok(None)
+		});
+	}
+
+	fn base(&self) -> &Base {
+		unsafe { &*(self.as_base() as *const Base) }
 	}
 
 	/// Physically creates the window, then runs the main application loop. This
@@ -118,8 +208,24 @@ impl WindowMain {
 	///
 	/// Panics if the window is already created.
 	pub fn run_main(&self, cmd_show: Option<co::SW>) -> AnyResult<i32> {
+		self.run_main_with_loop(cmd_show, MessageLoop::new())
+	}
+
+	/// Physically creates the window, then runs the given customized
+	/// [`MessageLoop`](crate::gui::MessageLoop). This method will block until
+	/// the window is closed.
+	///
+	/// The `cmd_show` parameter defaults to
+	/// [`co::SW::SHOW`](crate::co::SW::SHOW).
+	///
+	/// # Panics
+	///
+	/// Panics if the window is already created.
+	pub fn run_main_with_loop(&self,
+		cmd_show: Option<co::SW>, msg_loop: MessageLoop) -> AnyResult<i32>
+	{
 		if IsWindowsVistaOrGreater().unwrap() {
-			SetProcessDPIAware().unwrap();
+			set_dpi_awareness();
 		}
 
 		InitCommonControls();
@@ -133,11 +239,100 @@ impl WindowMain {
 		create_ui_font().unwrap();
 
 		let res = match &self.0 {
-			RawDlg::Raw(r) => r.run_main(cmd_show),
-			RawDlg::Dlg(d) => d.run_main(cmd_show),
+			RawDlg::Raw(r) => r.run_main(cmd_show, msg_loop),
+			RawDlg::Dlg(d) => d.run_main(cmd_show, msg_loop),
 		};
 
 		delete_ui_font(); // cleanup
 		res
 	}
+
+	/// Closes the window by posting a
+	/// [`WM_CLOSE`](crate::msg::wm::Close) message, which goes through the
+	/// [`closing`](crate::prelude::GuiEventsAll::closing) event with
+	/// [`CloseReason::Programmatic`](crate::gui::events::CloseReason::Programmatic).
+	pub fn close(&self) {
+		self.hwnd().PostMessage(WndMsg {
+			msg_id: co::WM::CLOSE,
+			wparam: PROGRAMMATIC_CLOSE,
+			lparam: 0,
+		}).ok(); // ignore errors
+	}
+
+	/// Sets the progress shown in the taskbar button of the window, with
+	/// [`ITaskbarList3::SetProgressValue`](crate::prelude::shell_ITaskbarList3::SetProgressValue)
+	/// and
+	/// [`ITaskbarList3::SetProgressState`](crate::prelude::shell_ITaskbarList3::SetProgressState).
+	/// The `completed` and `total` values are ignored for
+	/// [`co::TBPF::NOPROGRESS`](crate::co::TBPF::NOPROGRESS) and
+	/// [`co::TBPF::INDETERMINATE`](crate::co::TBPF::INDETERMINATE).
+	///
+	/// The [`ITaskbarList3`](crate::ITaskbarList3) object is created upon the
+	/// first call. If the taskbar button was not created yet, the values are
+	/// applied when it is. On systems where the object is not available, the
+	/// method does nothing.
+	///
+	/// Must be called from the UI thread.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::{co, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.taskbar_progress(co::TBPF::NORMAL, 30, 100)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	pub fn taskbar_progress(&self,
+		state: co::TBPF, completed: u64, total: u64) -> HrResult<()>
+	{
+		self.1.set(state, completed, total)
+	}
+
+	/// Adds thumbnail toolbar buttons to the taskbar button of the window,
+	/// through [`ITaskbarList3`](crate::ITaskbarList3).
+	///
+	/// The buttons are added upon the first call, which must have all of them,
+	/// up to 7. Subsequent calls update the existing buttons, matched by their
+	/// `iId`. If the taskbar button was not created yet, the buttons are applied
+	/// when it is. On systems where the object is not available, the method
+	/// does nothing.
+	///
+	/// Clicks are routed to the window as
+	/// [`wm_command`](crate::prelude::GuiEventsAll::wm_command) events with
+	/// the [`co::THBN::CLICKED`](crate::co::THBN::CLICKED) code and the `iId`
+	/// of the button.
+	///
+	/// Must be called from the UI thread.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui, THUMBBUTTON};
+	///
+	/// const BTN_PLAY: u16 = 1001;
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// wnd.on().wm_command(co::THBN::CLICKED, BTN_PLAY, move || {
+	///     println!("Play clicked.");
+	///     Ok(())
+	/// });
+	///
+	/// let mut btn = THUMBBUTTON::default();
+	/// btn.dwMask = co::THB::TOOLTIP | co::THB::FLAGS;
+	/// btn.iId = BTN_PLAY as _;
+	/// btn.set_szTip("Play");
+	/// btn.dwFlags = co::THBF::ENABLED;
+	///
+	/// wnd.taskbar_thumb_buttons(&[btn])?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	pub fn taskbar_thumb_buttons(&self, buttons: &[THUMBBUTTON]) -> HrResult<()> {
+		self.1.set_thumb_buttons(buttons)
+	}
 }
@@ -0,0 +1,253 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::gui::base::Base;
+use crate::gui::events::WindowEventsAll;
+use crate::gui::privs::multiply_dpi;
+use crate::gui::raw_base::{Brush, Cursor, DefProc, Icon, RawBase};
+use crate::gui::window_mdi_frame::WindowMdiFrame;
+use crate::kernel::decl::{AnyResult, SysResult, WString};
+use crate::msg::{wm, WndMsg};
+use crate::prelude::{
+	GuiEvents, GuiParent, GuiThread, GuiWindow, GuiWindowText, Handle,
+	user_Hwnd,
+};
+use crate::user::decl::{HWND, POINT, SIZE, WNDCLASSEX};
+
+/// Posted by a destroyed MDI child to its frame, carrying a boxed
+/// [`WindowMdiChild`](crate::gui::WindowMdiChild) to be dropped.
+pub(in crate::gui) const WM_MDI_CHILD_RELEASE: co::WM = co::WM(co::WM::APP.0 + 0x3ffe);
+
+struct Obj { // actual fields of WindowMdiChild
+	raw_base: RawBase,
+	frame: WindowMdiFrame,
+	opts: WindowMdiChildOpts,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// An user
+/// [MDI child window](https://learn.microsoft.com/en-us/windows/win32/winmsg/multiple-document-interface),
+/// which lives inside the client area of a
+/// [`WindowMdiFrame`](crate::gui::WindowMdiFrame).
+///
+/// Unlike other windows, MDI children are usually created on demand – like
+/// when the user opens a document – by calling
+/// [`create`](crate::gui::WindowMdiChild::create). The object is kept alive
+/// until the window is destroyed, so it's not necessary to store it.
+///
+/// Child controls are created when the window is created, and the events must
+/// be set before it.
+#[derive(Clone)]
+pub struct WindowMdiChild(Pin<Arc<Obj>>);
+
+unsafe impl Send for WindowMdiChild {}
+
+impl GuiWindow for WindowMdiChild {
+	fn hwnd(&self) -> &HWND {
+		self.0.raw_base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiWindowText for WindowMdiChild {}
+
+impl GuiParent for WindowMdiChild {
+	fn on(&self) -> &WindowEventsAll {
+		self.0.raw_base.on()
+	}
+
+	unsafe fn as_base(&self) -> *mut std::ffi::c_void {
+		self.0.raw_base.as_base()
+	}
+}
+
+impl GuiThread for WindowMdiChild {
+	fn spawn_new_thread<F>(&self, func: F)
+		where F: FnOnce() -> AnyResult<()> + Send + 'static,
+	{
+		self.0.raw_base.spawn_new_thread(func);
+	}
+
+	fn run_ui_thread<F>(&self, func: F)
+		where F: FnOnce() -> AnyResult<()> + Send + 'static
+	{
+		self.0.raw_base.run_ui_thread(func);
+	}
+}
+
+impl WindowMdiChild {
+	/// Instantiates a new `WindowMdiChild` object, to be created with
+	/// [`create`](crate::gui::WindowMdiChild::create).
+	#[must_use]
+	pub fn new(frame: &WindowMdiFrame, opts: WindowMdiChildOpts) -> Self {
+		let frame_ref = unsafe { Base::from_guiparent(frame) };
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					raw_base: RawBase::new_with_def_proc(Some(frame_ref), DefProc::MdiChild),
+					frame: frame.clone(),
+					opts,
+					_pin: PhantomPinned,
+				},
+			),
+		);
+		new_self.default_message_handlers();
+		new_self
+	}
+
+	/// Physically creates the window inside the MDI client area of the frame,
+	/// with [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the window is already created.
+	pub fn create(&self) -> SysResult<()> {
+		let opts = &self.0.opts;
+
+		let parent_hinst = self.0.raw_base.parent_hinstance()?;
+		let mut class_name_custom = WString::from_str(&opts.class_name);
+		let mut wcx = WNDCLASSEX::default();
+		if !opts.class_name.is_empty() {
+			wcx.set_lpszClassName(Some(&mut class_name_custom));
+		}
+		let mut class_name_buf = WString::default();
+		RawBase::fill_wndclassex(
+			&parent_hinst,
+			opts.class_style, &opts.class_icon, &opts.class_icon,
+			&opts.class_bg_brush, &opts.class_cursor, &mut wcx,
+			&mut class_name_buf)?;
+		let atom = self.0.raw_base.register_class(&mut wcx)?;
+
+		let mut wnd_sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
+		multiply_dpi(None, Some(&mut wnd_sz))?;
+
+		self.0.raw_base.create_mdi_child_window(
+			atom,
+			Some(&opts.title),
+			POINT::new(i32::MIN, i32::MIN), // CW_USEDEFAULT, cascaded by the MDI client
+			wnd_sz,
+			opts.style,
+			self.0.frame.hwnd_mdi_client(),
+		)
+	}
+
+	/// Returns the [`WindowMdiFrame`](crate::gui::WindowMdiFrame) which hosts
+	/// this window.
+	#[must_use]
+	pub fn frame(&self) -> &WindowMdiFrame {
+		&self.0.frame
+	}
+
+	/// Activates this window.
+	///
+	/// Sends a [`wm::MdiActivate`](crate::msg::wm::MdiActivate) message to the
+	/// MDI client.
+	pub fn activate(&self) {
+		self.0.frame.hwnd_mdi_client().SendMessage(wm::MdiActivate {
+			hwnd_deactivated: None,
+			hwnd_activated: Some(unsafe { self.hwnd().raw_copy() }),
+		});
+	}
+
+	/// Destroys this window.
+	///
+	/// Sends a [`wm::MdiDestroy`](crate::msg::wm::MdiDestroy) message to the
+	/// MDI client.
+	pub fn close(&self) {
+		self.0.frame.hwnd_mdi_client().SendMessage(wm::MdiDestroy {
+			hwnd: unsafe { self.hwnd().raw_copy() },
+		});
+	}
+
+	fn default_message_handlers(&self) {
+		let self2 = self.clone();
+		self.0.raw_base.privileged_on().wm(co::WM::NCDESTROY, move |_| {
+			// The events, which hold our references, are cleared right after
+			// this message, so the last reference is handed to the frame.
+			let ptr_self = Box::into_raw(Box::new(self2.clone()));
+			self2.0.frame.hwnd().PostMessage(WndMsg {
+				msg_id: WM_MDI_CHILD_RELEASE,
+				wparam: 0,
+				lparam: ptr_self as _,
+			}).ok(); // if the frame is gone, the application is finishing anyway
+			Ok(None) // not meaningful
+		});
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`WindowMdiChild`](crate::gui::WindowMdiChild)
+/// programmatically with
+/// [`WindowMdiChild::new`](crate::gui::WindowMdiChild::new).
+pub struct WindowMdiChildOpts {
+	/// Window class name to be
+	/// [registered](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to an auto-generated string.
+	pub class_name: String,
+	/// Window class styles to be
+	/// [registered](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to `co::CS::DBLCLKS`.
+	pub class_style: co::CS,
+	/// Window main icon to be
+	/// [registered](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw),
+	/// shown in the title bar.
+	///
+	/// Defaults to `Icon::None`.
+	pub class_icon: Icon,
+	/// Window cursor to be
+	/// [registered](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to `Cursor::Idc(co::IDC::ARROW)`.
+	pub class_cursor: Cursor,
+	/// Window background brush to be
+	/// [registered](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to `Brush::Color(co::COLOR::WINDOW)`.
+	pub class_bg_brush: Brush,
+
+	/// Window title to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw),
+	/// also shown in the window menu of the frame.
+	///
+	/// Defaults to empty string.
+	pub title: String,
+	/// Width and height of window, in pixels, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	/// The position is chosen by the MDI client.
+	///
+	/// Will be adjusted to match current system DPI.
+	///
+	/// Defaults to `(400, 300)`.
+	pub size: (u32, u32),
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::OVERLAPPEDWINDOW | WS::CHILD | WS::CLIPCHILDREN | WS::VISIBLE`.
+	pub style: co::WS,
+}
+
+impl Default for WindowMdiChildOpts {
+	fn default() -> Self {
+		Self {
+			class_name: "".to_owned(),
+			class_style: co::CS::DBLCLKS,
+			class_icon: Icon::None,
+			class_cursor: Cursor::Idc(co::IDC::ARROW),
+			class_bg_brush: Brush::Color(co::COLOR::WINDOW),
+			title: "".to_owned(),
+			size: (400, 300),
+			style: co::WS::OVERLAPPEDWINDOW | co::WS::CHILD | co::WS::CLIPCHILDREN | co::WS::VISIBLE,
+		}
+	}
+}
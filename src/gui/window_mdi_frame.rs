@@ -0,0 +1,376 @@
+use std::any::Any;
+
+use crate::co;
+use crate::comctl::decl::InitCommonControls;
+use crate::gui::base::Base;
+use crate::gui::events::WindowEventsAll;
+use crate::gui::message_loop::MessageLoop;
+use crate::gui::privs::{create_ui_font, delete_ui_font, set_dpi_awareness};
+use crate::gui::raw_base::{Brush, Cursor, Icon};
+use crate::gui::raw_main::{RawMain, WindowMainOpts};
+use crate::gui::window_mdi_child::{WindowMdiChild, WM_MDI_CHILD_RELEASE};
+use crate::kernel::decl::{AnyResult, HPROCESS, IsWindowsVistaOrGreater};
+use crate::kernel::ffi_types::BOOL;
+use crate::msg::wm;
+use crate::prelude::{
+	GuiEvents, GuiParent, GuiThread, GuiWindow, GuiWindowText, Handle,
+	kernel_Hprocess, user_Hprocess, user_Hwnd,
+};
+use crate::user::decl::{CLIENTCREATESTRUCT, HMENU, HWND};
+use crate::user::guard::DestroyAcceleratorTableGuard;
+
+/// An user main window which hosts a
+/// [multiple-document interface](https://learn.microsoft.com/en-us/windows/win32/winmsg/multiple-document-interface)
+/// (MDI) client area, where [`WindowMdiChild`](crate::gui::WindowMdiChild)
+/// windows are created.
+///
+/// The MDI client window is created right before the user
+/// [`wm_create`](crate::prelude::GuiEventsAll::wm_create) closure is called,
+/// and it's automatically resized to fill the frame client area.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{gui, HMENU};
+///
+/// let hmenu_window = HMENU::CreatePopupMenu()?; // child windows will be listed here
+///
+/// let frame = gui::WindowMdiFrame::new(
+///     gui::WindowMdiFrameOpts {
+///         title: "MDI".to_owned(),
+///         window_menu: hmenu_window,
+///         ..Default::default()
+///     },
+/// );
+///
+/// let frame2 = frame.clone();
+/// frame.on().wm_create(move |_| {
+///     let child = gui::WindowMdiChild::new(&frame2, gui::WindowMdiChildOpts {
+///         title: "Document 1".to_owned(),
+///         ..Default::default()
+///     });
+///     child.create()?;
+///     Ok(0)
+/// });
+///
+/// frame.run_main(None)?;
+/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[derive(Clone)]
+pub struct WindowMdiFrame(RawMain);
+
+unsafe impl Send for WindowMdiFrame {}
+
+impl GuiWindow for WindowMdiFrame {
+	fn hwnd(&self) -> &HWND {
+		self.0.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiWindowText for WindowMdiFrame {}
+
+impl GuiParent for WindowMdiFrame {
+	fn on(&self) -> &WindowEventsAll {
+		self.0.on()
+	}
+
+	unsafe fn as_base(&self) -> *mut std::ffi::c_void {
+		self.0.as_base()
+	}
+}
+
+impl GuiThread for WindowMdiFrame {
+	fn spawn_new_thread<F>(&self, func: F)
+		where F: FnOnce() -> AnyResult<()> + Send + 'static,
+	{
+		self.0.spawn_new_thread(func);
+	}
+
+	fn run_ui_thread<F>(&self, func: F)
+		where F: FnOnce() -> AnyResult<()> + Send + 'static
+	{
+		self.0.run_ui_thread(func);
+	}
+}
+
+impl WindowMdiFrame {
+	/// Instantiates a new `WindowMdiFrame` object, to be created internally
+	/// with [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	#[must_use]
+	pub fn new(opts: WindowMdiFrameOpts) -> Self {
+		let (main_opts, mdi_client) = opts.split();
+		let new_self = Self(RawMain::new_mdi_frame(main_opts, mdi_client));
+
+		let base_ref = unsafe { Base::from_guiparent(&new_self) };
+		base_ref.privileged_on().wm(WM_MDI_CHILD_RELEASE, |p| {
+			// Last reference of a destroyed child, posted by itself.
+			let _ = unsafe { Box::from_raw(p.lparam as *mut WindowMdiChild) };
+			Ok(None) // not meaningful
+		});
+
+		new_self
+	}
+
+	/// Returns the currently active MDI child window, if any, and whether it's
+	/// maximized.
+	///
+	/// Sends a [`wm::MdiGetActive`](crate::msg::wm::MdiGetActive) message.
+	#[must_use]
+	pub fn active_child(&self) -> Option<(HWND, bool)> {
+		self.hwnd_mdi_client().SendMessage(wm::MdiGetActive::default())
+	}
+
+	/// Arranges the minimized MDI child windows.
+	///
+	/// Sends a [`wm::MdiIconArrange`](crate::msg::wm::MdiIconArrange) message.
+	pub fn arrange_icons(&self) {
+		self.hwnd_mdi_client().SendMessage(wm::MdiIconArrange {});
+	}
+
+	/// Arranges the MDI child windows in a cascade format.
+	///
+	/// Sends a [`wm::MdiCascade`](crate::msg::wm::MdiCascade) message.
+	pub fn cascade(&self) {
+		self.hwnd_mdi_client().SendMessage(wm::MdiCascade {
+			how: co::MDITILE::SKIPDISABLED,
+		});
+	}
+
+	/// Returns the handle to the MDI client window, which is the actual parent
+	/// of the MDI child windows.
+	///
+	/// Returns `HWND::NULL` if the window was not created yet.
+	#[must_use]
+	pub fn hwnd_mdi_client(&self) -> &HWND {
+		self.0.hwnd_mdi_client()
+	}
+
+	/// Arranges the MDI child windows in a tile format.
+	///
+	/// Sends a [`wm::MdiTile`](crate::msg::wm::MdiTile) message.
+	pub fn tile(&self, how: co::MDITILE) {
+		self.hwnd_mdi_client().SendMessage(wm::MdiTile { how });
+	}
+
+	/// Physically creates the window, then runs the main application loop. This
+	/// method will block until the window is closed.
+	///
+	/// The `cmd_show` parameter defaults to
+	/// [`co::SW::SHOW`](crate::co::SW::SHOW).
+	///
+	/// # Panics
+	///
+	/// Panics if the window is already created.
+	pub fn run_main(&self, cmd_show: Option<co::SW>) -> AnyResult<i32> {
+		self.run_main_with_loop(cmd_show, MessageLoop::new())
+	}
+
+	/// Physically creates the window, then runs the given customized
+	/// [`MessageLoop`](crate::gui::MessageLoop). This method will block until
+	/// the window is closed.
+	///
+	/// The `cmd_show` parameter defaults to
+	/// [`co::SW::SHOW`](crate::co::SW::SHOW).
+	///
+	/// # Panics
+	///
+	/// Panics if the window is already created.
+	pub fn run_main_with_loop(&self,
+		cmd_show: Option<co::SW>, msg_loop: MessageLoop) -> AnyResult<i32>
+	{
+		if IsWindowsVistaOrGreater().unwrap() {
+			set_dpi_awareness();
+		}
+
+		InitCommonControls();
+
+		let mut b_val: BOOL = 0; // false
+		unsafe {
+			HPROCESS::GetCurrentProcess().SetUserObjectInformation( // SetTimer() safety
+				co::UOI::TIMERPROC_EXCEPTION_SUPPRESSION, &mut b_val).unwrap();
+		}
+
+		create_ui_font().unwrap();
+		let res = self.0.run_main(cmd_show, msg_loop);
+		delete_ui_font(); // cleanup
+		res
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`WindowMdiFrame`](crate::gui::WindowMdiFrame)
+/// programmatically with
+/// [`WindowMdiFrame::new`](crate::gui::WindowMdiFrame::new).
+pub struct WindowMdiFrameOpts {
+	/// Window class name to be
+	/// [registered](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to an auto-generated string.
+	pub class_name: String,
+	/// Window class styles to be
+	/// [registered](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to `co::CS::DBLCLKS`.
+	pub class_style: co::CS,
+	/// Window main icon to be
+	/// [registered](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to `Icon::None`.
+	pub class_icon: Icon,
+	/// Big and small icons to be
+	/// [set](crate::prelude::user_Hwnd::set_icon) right after the window is
+	/// created.
+	///
+	/// Icons from resources are loaded with the sizes matching the window DPI,
+	/// and reloaded when the window moves to a monitor with a different DPI.
+	///
+	/// Defaults to `Icon::None`.
+	pub icon: Icon,
+	/// Small icon to be
+	/// [set](crate::prelude::user_Hwnd::set_icon) right after the window is
+	/// created, replacing the small version of `icon`.
+	///
+	/// Defaults to `Icon::None`, which means the small icon comes from `icon`.
+	pub small_icon: Icon,
+	/// Window cursor to be
+	/// [registered](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to `Cursor::Idc(co::IDC::ARROW)`.
+	pub class_cursor: Cursor,
+	/// Window background brush to be
+	/// [registered](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	///
+	/// Defaults to `Brush::Color(co::COLOR::BTNFACE)`.
+	pub class_bg_brush: Brush,
+
+	/// Window title to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to empty string.
+	pub title: String,
+	/// Width and height of window client area, in pixels, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	/// Does not include title bar or borders.
+	///
+	/// Will be adjusted to match current system DPI.
+	///
+	/// Defaults to `(800, 600)`.
+	pub size: (u32, u32),
+	/// Minimum width and height of window client area, in pixels, when the
+	/// user resizes the window.
+	///
+	/// Will be adjusted to match the window DPI.
+	///
+	/// Defaults to `(0, 0)`, which means no limit.
+	pub min_size: (u32, u32),
+	/// Maximum width and height of window client area, in pixels, when the
+	/// user resizes the window. A zero dimension is not limited.
+	///
+	/// Will be adjusted to match the window DPI.
+	///
+	/// Defaults to `(0, 0)`, which means no limit.
+	pub max_size: (u32, u32),
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CAPTION | WS::SYSMENU | WS::CLIPCHILDREN | WS::BORDER | WS::VISIBLE | WS::SIZEBOX | WS::MINIMIZEBOX | WS::MAXIMIZEBOX`.
+	pub style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT`.
+	pub ex_style: co::WS_EX,
+	/// Main menu of the window to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// This menu is **not** shared: the window will own it, and destroy it when
+	/// the window is destroyed.
+	///
+	/// Defaults to none.
+	pub menu: HMENU,
+	/// Main accelerator table of the window to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	/// Use
+	/// [`HACCEL::CreateAcceleratorTable`](crate::prelude::user_Haccel::CreateAcceleratorTable)
+	/// to create one.
+	///
+	/// Defaults to `None`.
+	pub accel_table: Option<DestroyAcceleratorTableGuard>,
+	/// Registry key, under `HKEY_CURRENT_USER`, where the window placement is
+	/// saved when the window is destroyed, and restored in the next run.
+	///
+	/// Defaults to `None`.
+	pub placement_key: Option<String>,
+
+	/// Submenu where the MDI child windows will be listed, usually the
+	/// "Window" submenu of the main menu.
+	///
+	/// Defaults to none.
+	pub window_menu: HMENU,
+	/// Command ID of the first MDI child window, used by the menu items of
+	/// `window_menu`. The subsequent children receive the subsequent IDs, so
+	/// they must not collide with other command IDs.
+	///
+	/// Defaults to `0xff00`.
+	pub first_child_id: u16,
+}
+
+impl Default for WindowMdiFrameOpts {
+	fn default() -> Self {
+		Self {
+			class_name: "".to_owned(),
+			class_style: co::CS::DBLCLKS,
+			class_icon: Icon::None,
+			icon: Icon::None,
+			small_icon: Icon::None,
+			class_cursor: Cursor::Idc(co::IDC::ARROW),
+			class_bg_brush: Brush::Color(co::COLOR::BTNFACE),
+			title: "".to_owned(),
+			size: (800, 600),
+			min_size: (0, 0),
+			max_size: (0, 0),
+			style: co::WS::CAPTION | co::WS::SYSMENU | co::WS::CLIPCHILDREN | co::WS::BORDER | co::WS::VISIBLE | co::WS::SIZEBOX | co::WS::MINIMIZEBOX | co::WS::MAXIMIZEBOX,
+			ex_style: co::WS_EX::LEFT,
+			menu: HMENU::NULL,
+			accel_table: None,
+			placement_key: None,
+			window_menu: HMENU::NULL,
+			first_child_id: 0xff00,
+		}
+	}
+}
+
+impl WindowMdiFrameOpts {
+	fn split(self) -> (WindowMainOpts, CLIENTCREATESTRUCT) {
+		(
+			WindowMainOpts {
+				class_name: self.class_name,
+				class_style: self.class_style,
+				class_icon: self.class_icon,
+				icon: self.icon,
+				small_icon: self.small_icon,
+				class_cursor: self.class_cursor,
+				class_bg_brush: self.class_bg_brush,
+				title: self.title,
+				size: self.size,
+				min_size: self.min_size,
+				max_size: self.max_size,
+				style: self.style,
+				ex_style: self.ex_style,
+				menu: self.menu,
+				accel_table: self.accel_table,
+				placement_key: self.placement_key,
+			},
+			CLIENTCREATESTRUCT {
+				hWindowMenu: self.window_menu,
+				idFirstChild: self.first_child_id as _,
+			},
+		)
+	}
+}
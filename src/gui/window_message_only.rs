@@ -0,0 +1,193 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::gui::events::WindowEventsAll;
+use crate::gui::message_loop::MessageLoop;
+use crate::gui::raw_base::{Brush, Cursor, Icon, RawBase};
+use crate::kernel::decl::{AnyResult, HINSTANCE, WString};
+use crate::prelude::{
+	GuiEvents, GuiParent, GuiThread, GuiWindow, GuiWindowText, Handle,
+	kernel_Hinstance,
+};
+use crate::user::decl::{
+	HBRUSH, HCURSOR, HWND, PostQuitMessage, WNDCLASSEX,
+};
+
+struct Obj { // actual fields of WindowMessageOnly
+	raw_base: RawBase,
+	opts: WindowMessageOnlyOpts,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// A
+/// [message-only window](https://learn.microsoft.com/en-us/windows/win32/winmsg/window-features#message-only-windows),
+/// which can handle events but is never displayed. Like
+/// [`WindowMain`](crate::gui::WindowMain), it runs the main application loop.
+///
+/// Useful for applications which only need timers, clipboard monitoring or
+/// messages sent by other processes. A message-only window doesn't receive
+/// broadcast messages, and it cannot be found by
+/// [`HWND::FindWindow`](crate::prelude::user_Hwnd::FindWindow) – use
+/// [`HWND::FindWindowEx`](crate::prelude::user_Hwnd::FindWindowEx) with
+/// [`HWND::MESSAGE`](crate::prelude::user_Hwnd::MESSAGE) as the parent.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::gui;
+///
+/// let wnd = gui::WindowMessageOnly::new(gui::WindowMessageOnlyOpts::default());
+///
+/// wnd.on().wm_clipboard_update(move || { // listener added automatically
+///     println!("Clipboard changed.");
+///     Ok(())
+/// });
+///
+/// wnd.run_main()?;
+/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[derive(Clone)]
+pub struct WindowMessageOnly(Pin<Arc<Obj>>);
+
+unsafe impl Send for WindowMessageOnly {}
+
+impl GuiWindow for WindowMessageOnly {
+	fn hwnd(&self) -> &HWND {
+		self.0.raw_base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiWindowText for WindowMessageOnly {}
+
+impl GuiParent for WindowMessageOnly {
+	fn on(&self) -> &WindowEventsAll {
+		self.0.raw_base.on()
+	}
+
+	unsafe fn as_base(&self) -> *mut std::ffi::c_void {
+		self.0.raw_base.as_base()
+	}
+}
+
+impl GuiThread for WindowMessageOnly {
+	fn spawn_new_thread<F>(&self, func: F)
+		where F: FnOnce() -> AnyResult<()> + Send + 'static,
+	{
+		self.0.raw_base.spawn_new_thread(func);
+	}
+
+	fn run_ui_thread<F>(&self, func: F)
+		where F: FnOnce() -> AnyResult<()> + Send + 'static
+	{
+		self.0.raw_base.run_ui_thread(func);
+	}
+}
+
+impl WindowMessageOnly {
+	/// Instantiates a new `WindowMessageOnly` object, to be created internally
+	/// with [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	#[must_use]
+	pub fn new(opts: WindowMessageOnlyOpts) -> Self {
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					raw_base: RawBase::new(None),
+					opts,
+					_pin: PhantomPinned,
+				},
+			),
+		);
+		new_self.default_message_handlers();
+		new_self
+	}
+
+	/// Physically creates the window, then runs the main application loop. This
+	/// method will block until the window is destroyed.
+	///
+	/// Since the window has no visual presence, it cannot be closed by the
+	/// user; call
+	/// [`HWND::DestroyWindow`](crate::prelude::user_Hwnd::DestroyWindow) or
+	/// send a [`WM_CLOSE`](crate::msg::wm::Close) message to finish the loop.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is already created.
+	pub fn run_main(&self) -> AnyResult<i32> {
+		self.run_main_with_loop(MessageLoop::new())
+	}
+
+	/// Physically creates the window, then runs the given customized
+	/// [`MessageLoop`](crate::gui::MessageLoop). This method will block until
+	/// the window is destroyed.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is already created.
+	pub fn run_main_with_loop(&self, msg_loop: MessageLoop) -> AnyResult<i32> {
+		let opts = &self.0.opts;
+
+		let hinst = HINSTANCE::GetModuleHandle(None).unwrap();
+		let mut class_name_custom = WString::from_str(&opts.class_name);
+		let mut wcx = WNDCLASSEX::default();
+		if !opts.class_name.is_empty() {
+			wcx.set_lpszClassName(Some(&mut class_name_custom));
+		}
+		let mut class_name_buf = WString::default();
+		RawBase::fill_wndclassex(
+			&hinst,
+			co::CS::default(), &Icon::None, &Icon::None,
+			&Brush::Handle(HBRUSH::NULL), &Cursor::Handle(HCURSOR::NULL),
+			&mut wcx, &mut class_name_buf).unwrap();
+		let atom = self.0.raw_base.register_class(&mut wcx).unwrap();
+
+		self.0.raw_base.create_message_only_window(atom, Some(&opts.title)).unwrap();
+
+		msg_loop.run() // blocks until window is destroyed
+	}
+
+	fn default_message_handlers(&self) {
+		self.on().wm_nc_destroy(move || {
+			PostQuitMessage(0);
+			Ok(())
+		});
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a
+/// [`WindowMessageOnly`](crate::gui::WindowMessageOnly) programmatically with
+/// [`WindowMessageOnly::new`](crate::gui::WindowMessageOnly::new).
+pub struct WindowMessageOnlyOpts {
+	/// Window class name to be
+	/// [registered](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassexw).
+	/// A known class name allows other processes to find the window with
+	/// [`HWND::FindWindowEx`](crate::prelude::user_Hwnd::FindWindowEx).
+	///
+	/// Defaults to an auto-generated string.
+	pub class_name: String,
+	/// Window title to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to empty string.
+	pub title: String,
+}
+
+impl Default for WindowMessageOnlyOpts {
+	fn default() -> Self {
+		Self {
+			class_name: "".to_owned(),
+			title: "".to_owned(),
+		}
+	}
+}
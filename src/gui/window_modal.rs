@@ -98,20 +98,55 @@ impl WindowModal {
 		)
 	}
 
-	/// Physically creates the window, then runs the modal loop. This method
-	/// will block until the window is closed.
+	/// Closes the modal, making
+	/// [`show_modal`](crate::gui::WindowModal::show_modal) return the given
+	/// value.
 	///
 	/// For a modal created with
-	/// [`WindowModal::new`](crate::gui::WindowModal::new), the returned `i32`
-	/// is always zero.
+	/// [`WindowModal::new_dlg`](crate::gui::WindowModal::new_dlg), this is the
+	/// same as calling
+	/// [`HWND::EndDialog`](crate::prelude::user_Hwnd::EndDialog).
 	///
-	/// For a modal created with
-	/// [`WindowModal::new_dlg`](crate::gui::WindowModal::new_dlg), the returned
-	/// `i32` is the value passed to
-	/// [`HWND::EndDialog`](crate::prelude::user_Hwnd::EndDialog). Note that, if
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let modal = gui::WindowModal::new(&wnd, gui::WindowModalOpts::default());
+	/// let btn_ok = gui::Button::new(&modal, gui::ButtonOpts::default());
+	///
+	/// btn_ok.on().bn_clicked({
+	///     let modal = modal.clone();
+	///     move || {
+	///         modal.close(u16::from(co::DLGID::OK) as _)?;
+	///         Ok(())
+	///     }
+	/// });
+	///
+	/// if modal.show_modal()? == u16::from(co::DLGID::OK) as _ {
+	///     println!("User confirmed.");
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	pub fn close(&self, result: i32) -> SysResult<()> {
+		match &self.0 {
+			RawDlg::Raw(r) => r.close(result),
+			RawDlg::Dlg(d) => d.close(result),
+		}
+	}
+
+	/// Physically creates the window, then runs the modal loop. This method
+	/// will block until the window is closed.
+	///
+	/// The returned `i32` is the value passed to
+	/// [`WindowModal::close`](crate::gui::WindowModal::close). Note that, if
 	/// the user clicks the "X" to close the modal, the default behavior is to
-	/// call `EndDialog(0)`. To override this behavior, simply handle the
-	/// modal's [`wm_close`](crate::prelude::GuiEvents::wm_close) yourself.
+	/// return zero. To override this behavior, simply handle the modal's
+	/// [`wm_close`](crate::prelude::GuiEvents::wm_close) yourself.
 	///
 	/// # Panics
 	///
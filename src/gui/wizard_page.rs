@@ -0,0 +1,237 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::comctl::decl::PROPSHEETPAGE;
+use crate::gui::base::Base;
+use crate::gui::dlg_base::DlgBase;
+use crate::gui::events::{WindowEventsAll, WizardPageEvents};
+use crate::kernel::decl::{AnyResult, IdStr, SysResult, WString};
+use crate::msg::WndMsg;
+use crate::prelude::{GuiParent, GuiThread, GuiWindow, Handle, user_Hwnd};
+use crate::user::decl::HWND;
+
+struct Obj { // actual fields of WizardPage
+	dlg_base: DlgBase,
+	opts: WizardPageOpts,
+	events: WizardPageEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// A page of a [`PropertySheet`](crate::gui::PropertySheet), loaded from a
+/// dialog resource in a `.res` file. The same page type is used for tabbed
+/// property sheets and for wizards.
+///
+/// Child controls are created with their `new_dlg` constructors, just like in
+/// any other dialog window. Page notifications, like Next and Finish clicks,
+/// are handled with [`on_page`](crate::gui::WizardPage::on_page).
+#[derive(Clone)]
+pub struct WizardPage(Pin<Arc<Obj>>);
+
+unsafe impl Send for WizardPage {}
+
+impl GuiWindow for WizardPage {
+	fn hwnd(&self) -> &HWND {
+		self.0.dlg_base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiParent for WizardPage {
+	fn on(&self) -> &WindowEventsAll {
+		self.0.dlg_base.on()
+	}
+
+	unsafe fn as_base(&self) -> *mut std::ffi::c_void {
+		self.0.dlg_base.as_base()
+	}
+}
+
+impl GuiThread for WizardPage {
+	fn spawn_new_thread<F>(&self, func: F)
+		where F: FnOnce() -> AnyResult<()> + Send + 'static,
+	{
+		self.0.dlg_base.spawn_new_thread(func);
+	}
+
+	fn run_ui_thread<F>(&self, func: F)
+		where F: FnOnce() -> AnyResult<()> + Send + 'static
+	{
+		self.0.dlg_base.run_ui_thread(func);
+	}
+}
+
+impl WizardPage {
+	/// Instantiates a new `WizardPage` object, to be loaded from a dialog
+	/// resource when the [`PropertySheet`](crate::gui::PropertySheet) is shown.
+	#[must_use]
+	pub fn new(opts: WizardPageOpts) -> Self {
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					dlg_base: DlgBase::new(None, opts.dialog_id),
+					opts,
+					events: WizardPageEvents::new(),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+		new_self.0.events.set_page(
+			unsafe { &*(new_self.0.dlg_base.as_base() as *const Base) });
+		new_self
+	}
+
+	/// Exposes the property sheet page notifications.
+	///
+	/// # Panics
+	///
+	/// Panics if the property sheet is already being shown. Events must be set
+	/// before it's shown.
+	#[must_use]
+	pub fn on_page(&self) -> &WizardPageEvents {
+		&self.0.events
+	}
+
+	/// Returns the handle to the property sheet window, which is the parent of
+	/// the page.
+	pub fn sheet_hwnd(&self) -> SysResult<HWND> {
+		self.hwnd().GetParent()
+	}
+
+	/// Marks the page as modified, enabling the Apply button, with
+	/// [`PSM_CHANGED`](https://learn.microsoft.com/en-us/windows/win32/controls/psm-changed),
+	/// or as unmodified, with
+	/// [`PSM_UNCHANGED`](https://learn.microsoft.com/en-us/windows/win32/controls/psm-unchanged).
+	pub fn set_modified(&self, modified: bool) -> SysResult<()> {
+		self.sheet_hwnd()?.SendMessage(WndMsg {
+			msg_id: if modified { co::PSM::CHANGED } else { co::PSM::UNCHANGED }.into(),
+			wparam: self.hwnd().as_ptr() as _,
+			lparam: 0,
+		});
+		Ok(())
+	}
+
+	/// Sets the wizard buttons which are enabled, with
+	/// [`PSM_SETWIZBUTTONS`](https://learn.microsoft.com/en-us/windows/win32/controls/psm-setwizbuttons).
+	/// Usually called when handling
+	/// [`psn_set_active`](crate::gui::events::WizardPageEvents::psn_set_active).
+	pub fn set_wizard_buttons(&self, buttons: co::PSWIZB) -> SysResult<()> {
+		self.sheet_hwnd()?.PostMessage(WndMsg {
+			msg_id: co::PSM::SETWIZBUTTONS.into(),
+			wparam: 0,
+			lparam: buttons.0 as _,
+		})
+	}
+
+	/// Fills the fields of the `PROPSHEETPAGE` which will host this page, using
+	/// the given buffers, which must outlive the property sheet.
+	pub(in crate::gui) fn fill_property_sheet_page<'a>(&self,
+		psp: &mut PROPSHEETPAGE<'a, 'a, 'a, 'a>,
+		bufs: &'a mut WizardPageBufs,
+	) -> SysResult<()>
+	{
+		self.0.dlg_base.fill_property_sheet_page(psp)?;
+		psp.set_pszTemplate(Some(&bufs.template));
+
+		let opts = &self.0.opts;
+		if opts.title.is_some() {
+			psp.dwFlags |= co::PSP::USETITLE;
+			psp.set_pszTitle(Some(&mut bufs.title));
+		}
+		if opts.header_title.is_some() {
+			psp.dwFlags |= co::PSP::USEHEADERTITLE;
+			psp.set_pszHeaderTitle(Some(&mut bufs.header_title));
+		}
+		if opts.header_subtitle.is_some() {
+			psp.dwFlags |= co::PSP::USEHEADERSUBTITLE;
+			psp.set_pszHeaderSubTitle(Some(&mut bufs.header_subtitle));
+		}
+		if opts.hide_header {
+			psp.dwFlags |= co::PSP::HIDEHEADER;
+		}
+		Ok(())
+	}
+
+	/// Allocates the buffers used by
+	/// [`fill_property_sheet_page`](crate::gui::WizardPage::fill_property_sheet_page).
+	pub(in crate::gui) fn new_bufs(&self) -> WizardPageBufs {
+		let opts = &self.0.opts;
+		WizardPageBufs {
+			template: IdStr::Id(self.0.dlg_base.dialog_id()),
+			title: WString::from_opt_str(opts.title.as_deref()),
+			header_title: WString::from_opt_str(opts.header_title.as_deref()),
+			header_subtitle: WString::from_opt_str(opts.header_subtitle.as_deref()),
+		}
+	}
+}
+
+/// String buffers of a page, kept alive while the property sheet is shown.
+pub(in crate::gui) struct WizardPageBufs {
+	template: IdStr,
+	title: WString,
+	header_title: WString,
+	header_subtitle: WString,
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`WizardPage`](crate::gui::WizardPage) with
+/// [`WizardPage::new`](crate::gui::WizardPage::new).
+#[derive(Default)]
+pub struct WizardPageOpts {
+	/// ID of the dialog resource to be loaded.
+	///
+	/// Defaults to `0`.
+	pub dialog_id: u16,
+	/// Text of the tab, or of the wizard title bar. If not set, the dialog
+	/// resource caption is used.
+	///
+	/// Defaults to `None`.
+	pub title: Option<String>,
+	/// Header title, displayed by Wizard97 and Aero wizards.
+	///
+	/// Defaults to `None`.
+	pub header_title: Option<String>,
+	/// Header subtitle, displayed by Wizard97 wizards.
+	///
+	/// Defaults to `None`.
+	pub header_subtitle: Option<String>,
+	/// Hides the header of Wizard97 wizards, usually in the welcome and
+	/// completion pages.
+	///
+	/// Defaults to `false`.
+	pub hide_header: bool,
+}
+
+//------------------------------------------------------------------------------
+
+/// Where to go when the user clicks the Back or Next button of a wizard,
+/// returned by
+/// [`psn_wiz_back`](crate::gui::events::WizardPageEvents::psn_wiz_back) and
+/// [`psn_wiz_next`](crate::gui::events::WizardPageEvents::psn_wiz_next).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum WizardNav {
+	/// Goes to the previous or next page.
+	Default,
+	/// Stays in the current page.
+	Stay,
+	/// Goes to the page with the given dialog resource ID.
+	GoTo(u16),
+}
+
+impl From<WizardNav> for isize {
+	fn from(v: WizardNav) -> Self {
+		match v {
+			WizardNav::Default => 0,
+			WizardNav::Stay => -1,
+			WizardNav::GoTo(dialog_id) => dialog_id as _,
+		}
+	}
+}
@@ -137,6 +137,44 @@ const_bitflag! { CREATE: u32;
 	INHERIT_PARENT_AFFINITY 0x0001_0000
 }
 
+const_ordinary! { CTRL: u32;
+	/// Console control signals received by the closure of
+	/// [`SetConsoleCtrlHandler`](crate::SetConsoleCtrlHandler) (`u32`).
+	=>
+	=>
+	/// A CTRL+C signal was received.
+	C_EVENT 0
+	/// A CTRL+BREAK signal was received.
+	BREAK_EVENT 1
+	/// The user closed the console. The process has a few seconds to clean up
+	/// before it is terminated.
+	CLOSE_EVENT 2
+	/// A user is logging off. Received only by services.
+	LOGOFF_EVENT 5
+	/// The system is shutting down. Received only by services.
+	SHUTDOWN_EVENT 6
+}
+
+const_ordinary! { DEP_SYSTEM_POLICY: u32;
+	/// [`GetSystemDEPPolicy`](crate::GetSystemDEPPolicy) return value (`u32`).
+	///
+	/// Originally `DEP_SYSTEM_POLICY_TYPE` enum, with `DEPPolicy` prefix.
+	=>
+	=>
+	/// DEP is disabled for all parts of the system, regardless of hardware
+	/// support for DEP.
+	ALWAYS_OFF 0
+	/// DEP is enabled for all parts of the system. All processes always run
+	/// with DEP enabled.
+	ALWAYS_ON 1
+	/// On systems with processors that are capable of hardware-enforced DEP,
+	/// DEP is automatically enabled only for operating system components.
+	OPT_IN 2
+	/// DEP is automatically enabled for operating system components and all
+	/// processes, except those explicitly excluded.
+	OPT_OUT 3
+}
+
 const_ordinary! { DISPOSITION: u32;
 	/// [`HFILE::CreateFile`](crate::prelude::kernel_Hfile::CreateFile)
 	/// `creation_disposition` (`u32`).
@@ -204,6 +242,35 @@ const_ordinary! { DRIVE: u32;
 	RAMDISK 6
 }
 
+const_bitflag! { EVENT: u32;
+	/// Event
+	/// [security and access rights](https://learn.microsoft.com/en-us/windows/win32/sync/synchronization-object-security-and-access-rights)
+	/// (`u32`).
+	=>
+	=>
+	DELETE ACCESS_RIGHTS::DELETE.0
+	READ_CONTROL ACCESS_RIGHTS::READ_CONTROL.0
+	SYNCHRONIZE ACCESS_RIGHTS::SYNCHRONIZE.0
+	WRITE_DAC ACCESS_RIGHTS::WRITE_DAC.0
+	WRITE_OWNER ACCESS_RIGHTS::WRITE_OWNER.0
+
+	ALL_ACCESS STANDARD_RIGHTS::REQUIRED.0 | ACCESS_RIGHTS::SYNCHRONIZE.0 | 0x3
+	MODIFY_STATE 0x0002
+}
+
+const_bitflag! { EXECUTION_STATE: u32;
+	/// [`SetThreadExecutionState`](crate::SetThreadExecutionState) `flags`
+	/// (`u32`).
+	///
+	/// Originally has `ES` prefix.
+	=>
+	=>
+	AWAYMODE_REQUIRED 0x0000_0040
+	CONTINUOUS 0x8000_0000
+	DISPLAY_REQUIRED 0x0000_0002
+	SYSTEM_REQUIRED 0x0000_0001
+}
+
 const_bitflag! { FILE_ATTRIBUTE: u32;
 	/// File attribute
 	/// [flags](https://learn.microsoft.com/en-us/windows/win32/fileio/file-attribute-constants),
@@ -687,6 +754,22 @@ const_bitflag! { MBC: u32;
 	USEGLYPHCHARS 0x0000_0004
 }
 
+const_bitflag! { MUTEX: u32;
+	/// Mutex
+	/// [security and access rights](https://learn.microsoft.com/en-us/windows/win32/sync/synchronization-object-security-and-access-rights)
+	/// (`u32`).
+	=>
+	=>
+	DELETE ACCESS_RIGHTS::DELETE.0
+	READ_CONTROL ACCESS_RIGHTS::READ_CONTROL.0
+	SYNCHRONIZE ACCESS_RIGHTS::SYNCHRONIZE.0
+	WRITE_DAC ACCESS_RIGHTS::WRITE_DAC.0
+	WRITE_OWNER ACCESS_RIGHTS::WRITE_OWNER.0
+
+	ALL_ACCESS STANDARD_RIGHTS::REQUIRED.0 | ACCESS_RIGHTS::SYNCHRONIZE.0 | 0x1
+	MODIFY_STATE 0x0001
+}
+
 const_ordinary! { PAGE: u32;
 	/// [`HFILE::CreateFileMapping`](crate::prelude::kernel_Hfile::CreateFileMapping)
 	/// `protect` (`u32`).
@@ -743,6 +826,40 @@ const_ordinary! { PAGE: u32;
 	SEC_WRITECOMBINE 0x4000_0000
 }
 
+const_bitflag! { PIPE: u32;
+	/// [`HPIPE::CreateNamedPipe`](crate::prelude::kernel_Hpipe::CreateNamedPipe)
+	/// `pipe_mode` (`u32`).
+	///
+	/// Originally has `PIPE` prefix.
+	=>
+	=>
+	TYPE_BYTE 0x0000_0000
+	TYPE_MESSAGE 0x0000_0004
+	READMODE_BYTE 0x0000_0000
+	READMODE_MESSAGE 0x0000_0002
+	WAIT 0x0000_0000
+	NOWAIT 0x0000_0001
+	ACCEPT_REMOTE_CLIENTS 0x0000_0000
+	REJECT_REMOTE_CLIENTS 0x0000_0008
+}
+
+const_bitflag! { PIPE_ACCESS: u32;
+	/// [`HPIPE::CreateNamedPipe`](crate::prelude::kernel_Hpipe::CreateNamedPipe)
+	/// `open_mode` (`u32`).
+	=>
+	=>
+	DUPLEX 0x0000_0003
+	INBOUND 0x0000_0001
+	OUTBOUND 0x0000_0002
+
+	/// Originally `FILE_FLAG_FIRST_PIPE_INSTANCE`.
+	FIRST_PIPE_INSTANCE 0x0008_0000
+	/// Originally `FILE_FLAG_WRITE_THROUGH`.
+	WRITE_THROUGH 0x8000_0000
+	/// Originally `FILE_FLAG_OVERLAPPED`.
+	OVERLAPPED 0x4000_0000
+}
+
 const_bitflag! { PRIORITY_CLASS: u32;
 	/// [`GetPriorityClass`](crate::prelude::kernel_Hprocess::GetPriorityClass)
 	/// and
@@ -760,6 +877,28 @@ const_bitflag! { PRIORITY_CLASS: u32;
 	REALTIME 0x0000_0100
 }
 
+const_ordinary! { PROC_THREAD_ATTRIBUTE: usize;
+	/// [`ProcThreadAttributeList::update`](crate::ProcThreadAttributeList::update)
+	/// `attribute` (`usize`).
+	=>
+	=>
+	CHILD_PROCESS_POLICY 0x0002_000e
+	DESKTOP_APP_POLICY 0x0002_0012
+	ENABLE_OPTIONAL_XSTATE_FEATURES 0x0003_001b
+	GROUP_AFFINITY 0x0003_0003
+	HANDLE_LIST 0x0002_0002
+	IDEAL_PROCESSOR 0x0003_0005
+	JOB_LIST 0x0002_000d
+	MACHINE_TYPE 0x0002_0019
+	MITIGATION_POLICY 0x0002_0007
+	PARENT_PROCESS 0x0002_0000
+	PREFERRED_NODE 0x0002_0004
+	PROTECTION_LEVEL 0x0002_000b
+	PSEUDOCONSOLE 0x0002_0016
+	SECURITY_CAPABILITIES 0x0002_0009
+	UMS_THREAD 0x0003_0006
+}
+
 const_bitflag! { PROCESS: u32;
 	/// Process
 	/// [security and access rights](https://learn.microsoft.com/en-us/windows/win32/procthread/process-security-and-access-rights)
@@ -799,6 +938,93 @@ const_bitflag! { PROCESS_AFFINITY: u32;
 	ENABLE_AUTO_UPDATE 0x0000_0001
 }
 
+const_bitflag! { PROCESS_CREATION_CHILD_PROCESS: u32;
+	/// [`ProcThreadAttributeList::child_process_policy`](crate::ProcThreadAttributeList::child_process_policy)
+	/// `flags` (`u32`).
+	=>
+	=>
+	/// The process being created is not allowed to create child processes.
+	RESTRICTED 0x01
+	/// The process being created is allowed to create a child process, if it
+	/// would otherwise be restricted.
+	OVERRIDE 0x02
+	/// The process being created is allowed to create a child process only if
+	/// it is a secure process.
+	RESTRICTED_UNLESS_SECURE 0x04
+}
+
+const_bitflag! { PROCESS_CREATION_MITIGATION_POLICY: u64;
+	/// [`ProcThreadAttributeList::mitigation_policy`](crate::ProcThreadAttributeList::mitigation_policy)
+	/// `flags` (`u64`).
+	///
+	/// Originally has `PROCESS_CREATION_MITIGATION_POLICY` prefix.
+	=>
+	=>
+	DEP_ENABLE 0x01
+	DEP_ATL_THUNK_ENABLE 0x02
+	SEHOP_ENABLE 0x04
+
+	FORCE_RELOCATE_IMAGES_ALWAYS_ON 0x1 << 8
+	FORCE_RELOCATE_IMAGES_ALWAYS_OFF 0x2 << 8
+	FORCE_RELOCATE_IMAGES_ALWAYS_ON_REQ_RELOCS 0x3 << 8
+
+	HEAP_TERMINATE_ALWAYS_ON 0x1 << 12
+	HEAP_TERMINATE_ALWAYS_OFF 0x2 << 12
+
+	BOTTOM_UP_ASLR_ALWAYS_ON 0x1 << 16
+	BOTTOM_UP_ASLR_ALWAYS_OFF 0x2 << 16
+
+	HIGH_ENTROPY_ASLR_ALWAYS_ON 0x1 << 20
+	HIGH_ENTROPY_ASLR_ALWAYS_OFF 0x2 << 20
+
+	STRICT_HANDLE_CHECKS_ALWAYS_ON 0x1 << 24
+	STRICT_HANDLE_CHECKS_ALWAYS_OFF 0x2 << 24
+
+	WIN32K_SYSTEM_CALL_DISABLE_ALWAYS_ON 0x1 << 28
+	WIN32K_SYSTEM_CALL_DISABLE_ALWAYS_OFF 0x2 << 28
+
+	EXTENSION_POINT_DISABLE_ALWAYS_ON 0x1 << 32
+	EXTENSION_POINT_DISABLE_ALWAYS_OFF 0x2 << 32
+
+	PROHIBIT_DYNAMIC_CODE_ALWAYS_ON 0x1 << 36
+	PROHIBIT_DYNAMIC_CODE_ALWAYS_OFF 0x2 << 36
+	PROHIBIT_DYNAMIC_CODE_ALWAYS_ON_ALLOW_OPT_OUT 0x3 << 36
+
+	CONTROL_FLOW_GUARD_ALWAYS_ON 0x1 << 40
+	CONTROL_FLOW_GUARD_ALWAYS_OFF 0x2 << 40
+	CONTROL_FLOW_GUARD_EXPORT_SUPPRESSION 0x3 << 40
+
+	BLOCK_NON_MICROSOFT_BINARIES_ALWAYS_ON 0x1 << 44
+	BLOCK_NON_MICROSOFT_BINARIES_ALWAYS_OFF 0x2 << 44
+	BLOCK_NON_MICROSOFT_BINARIES_ALLOW_STORE 0x3 << 44
+
+	FONT_DISABLE_ALWAYS_ON 0x1 << 48
+	FONT_DISABLE_ALWAYS_OFF 0x2 << 48
+	AUDIT_NONSYSTEM_FONTS 0x3 << 48
+
+	IMAGE_LOAD_NO_REMOTE_ALWAYS_ON 0x1 << 52
+	IMAGE_LOAD_NO_REMOTE_ALWAYS_OFF 0x2 << 52
+
+	IMAGE_LOAD_NO_LOW_LABEL_ALWAYS_ON 0x1 << 56
+	IMAGE_LOAD_NO_LOW_LABEL_ALWAYS_OFF 0x2 << 56
+
+	IMAGE_LOAD_PREFER_SYSTEM32_ALWAYS_ON 0x1 << 60
+	IMAGE_LOAD_PREFER_SYSTEM32_ALWAYS_OFF 0x2 << 60
+}
+
+const_bitflag! { PROCESS_DEP: u32;
+	/// [`GetProcessDEPPolicy`](crate::prelude::kernel_Hprocess::GetProcessDEPPolicy)
+	/// and [`SetProcessDEPPolicy`](crate::SetProcessDEPPolicy) `flags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	/// DEP is enabled for the process.
+	ENABLE 0x0000_0001
+	/// DEP-ATL thunk emulation is disabled for the process.
+	DISABLE_ATL_THUNK_EMULATION 0x0000_0002
+}
+
 const_bitflag! { PROCESS_HEAP: u16;
 	/// [`PROCESS_HEAP_ENTRY`](crate::PROCESS_HEAP_ENTRY) `wFlags` (`u16`).
 	=>
@@ -810,6 +1036,32 @@ const_bitflag! { PROCESS_HEAP: u16;
 	UNCOMMITTED_RANGE 0x0002
 }
 
+const_ordinary! { PROCESS_MITIGATION_POLICY: i32;
+	/// [`GetProcessMitigationPolicy`](crate::prelude::kernel_Hprocess::GetProcessMitigationPolicy)
+	/// `policy` (`i32`).
+	///
+	/// Originally has `Process` prefix and `Policy` suffix.
+	=>
+	=>
+	DEP 0
+	ASLR 1
+	DYNAMIC_CODE 2
+	STRICT_HANDLE_CHECK 3
+	SYSTEM_CALL_DISABLE 4
+	MITIGATION_OPTIONS_MASK 5
+	EXTENSION_POINT_DISABLE 6
+	CONTROL_FLOW_GUARD 7
+	SIGNATURE 8
+	FONT_DISABLE 9
+	IMAGE_LOAD 10
+	SYSTEM_CALL_FILTER 11
+	PAYLOAD_RESTRICTION 12
+	CHILD_PROCESS 13
+	SIDE_CHANNEL_ISOLATION 14
+	USER_SHADOW_STACK 15
+	REDIRECTION_TRUST 16
+}
+
 const_ordinary! { PROCESS_NAME: u32;
 	/// [`QueryFullProcessImageName`](crate::prelude::kernel_Hprocess::QueryFullProcessImageName)
 	/// flags (`u32`).
@@ -1215,6 +1467,23 @@ const_bitflag! { SE: u16;
 	SELF_RELATIVE 0x8000
 }
 
+const_bitflag! { SE_GROUP: u32;
+	/// [`SID_AND_ATTRIBUTES`](crate::SID_AND_ATTRIBUTES) `Attributes` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	MANDATORY 0x0000_0001
+	ENABLED_BY_DEFAULT 0x0000_0002
+	ENABLED 0x0000_0004
+	OWNER 0x0000_0008
+	USE_FOR_DENY_ONLY 0x0000_0010
+	INTEGRITY 0x0000_0020
+	INTEGRITY_ENABLED 0x0000_0040
+	LOGON_ID 0xc000_0000
+	RESOURCE 0x2000_0000
+}
+
 const_bitflag! { SECTION: u32;
 	/// Composes [`FILE_MAP`](crate::co::FILE_MAP) (`u32`).
 	=>
@@ -1674,6 +1943,32 @@ const_bitflag! { TH32CS: u32;
 	INHERIT 0x8000_0000
 }
 
+const_bitflag! { THREAD: u32;
+	/// Thread
+	/// [security and access rights](https://learn.microsoft.com/en-us/windows/win32/procthread/thread-security-and-access-rights)
+	/// (`u32`).
+	=>
+	=>
+	DELETE ACCESS_RIGHTS::DELETE.0
+	READ_CONTROL ACCESS_RIGHTS::READ_CONTROL.0
+	SYNCHRONIZE ACCESS_RIGHTS::SYNCHRONIZE.0
+	WRITE_DAC ACCESS_RIGHTS::WRITE_DAC.0
+	WRITE_OWNER ACCESS_RIGHTS::WRITE_OWNER.0
+
+	ALL_ACCESS STANDARD_RIGHTS::REQUIRED.0 | ACCESS_RIGHTS::SYNCHRONIZE.0 | 0xffff
+	DIRECT_IMPERSONATION 0x0200
+	GET_CONTEXT 0x0008
+	IMPERSONATE 0x0100
+	QUERY_INFORMATION 0x0040
+	QUERY_LIMITED_INFORMATION 0x0800
+	SET_CONTEXT 0x0010
+	SET_INFORMATION 0x0020
+	SET_LIMITED_INFORMATION 0x0400
+	SET_THREAD_TOKEN 0x0080
+	SUSPEND_RESUME 0x0002
+	TERMINATE 0x0001
+}
+
 const_bitflag! { THREAD_CREATE: u32;
 	/// [`HTHREAD::CreateThread`](crate::prelude::kernel_Hthread::CreateThread)
 	/// `flags` (`u32`).
@@ -1714,6 +2009,73 @@ const_bitflag! { TOKEN: u32;
 	ACCESS_PSEUDO_HANDLE Self::QUERY.0 | Self::QUERY_SOURCE.0
 }
 
+const_ordinary! { TOKEN_INFORMATION_CLASS: u32;
+	/// [`TOKEN_INFORMATION_CLASS`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-token_information_class)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `Token` prefix.
+	=>
+	=>
+	User 1
+	Groups 2
+	Privileges 3
+	Owner 4
+	PrimaryGroup 5
+	DefaultDacl 6
+	Source 7
+	Type 8
+	ImpersonationLevel 9
+	Statistics 10
+	RestrictedSids 11
+	SessionId 12
+	GroupsAndPrivileges 13
+	SessionReference 14
+	SandBoxInert 15
+	AuditPolicy 16
+	Origin 17
+	ElevationType 18
+	LinkedToken 19
+	Elevation 20
+	HasRestrictions 21
+	AccessInformation 22
+	VirtualizationAllowed 23
+	VirtualizationEnabled 24
+	IntegrityLevel 25
+	UIAccess 26
+	MandatoryPolicy 27
+	LogonSid 28
+	IsAppContainer 29
+	Capabilities 30
+	AppContainerSid 31
+	AppContainerNumber 32
+	UserClaimAttributes 33
+	DeviceClaimAttributes 34
+	RestrictedUserClaimAttributes 35
+	RestrictedDeviceClaimAttributes 36
+	DeviceGroups 37
+	RestrictedDeviceGroups 38
+	SecurityAttributes 39
+	IsRestricted 40
+	ProcessTrustLevel 41
+	PrivateNameSpace 42
+	SingletonAttributes 43
+	BnoIsolation 44
+	ChildProcessFlags 45
+	IsLessPrivilegedAppContainer 46
+	IsSandboxed 47
+}
+
+const_ordinary! { TOKEN_TYPE: u32;
+	/// [`TOKEN_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-token_type)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `Token` prefix.
+	=>
+	=>
+	Primary 1
+	Impersonation 2
+}
+
 const_bitflag! { TRANSACTION: u32;
 	/// [`Transaction access masks`](https://learn.microsoft.com/en-us/windows/win32/ktm/transaction-access-masks)
 	/// (`u32`).
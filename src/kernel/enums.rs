@@ -1,5 +1,14 @@
 use crate::co;
-use crate::kernel::decl::WString;
+use crate::kernel::decl::{
+	PROCESS_MITIGATION_ASLR_POLICY, PROCESS_MITIGATION_BINARY_SIGNATURE_POLICY,
+	PROCESS_MITIGATION_CHILD_PROCESS_POLICY,
+	PROCESS_MITIGATION_CONTROL_FLOW_GUARD_POLICY, PROCESS_MITIGATION_DEP_POLICY,
+	PROCESS_MITIGATION_DYNAMIC_CODE_POLICY,
+	PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY,
+	PROCESS_MITIGATION_FONT_DISABLE_POLICY, PROCESS_MITIGATION_IMAGE_LOAD_POLICY,
+	PROCESS_MITIGATION_STRICT_HANDLE_CHECK_POLICY,
+	PROCESS_MITIGATION_SYSTEM_CALL_DISABLE_POLICY, TOKEN_MANDATORY_LABEL, WString,
+};
 use crate::kernel::privs::{IS_INTRESOURCE, MAKEINTRESOURCE, parse_multi_z_str};
 
 /// A resource identifier.
@@ -51,6 +60,96 @@ impl IdStr {
 	}
 }
 
+/// A process mitigation policy, along with its data.
+///
+/// Variant parameter for:
+///
+/// * [`HPROCESS::GetProcessMitigationPolicy`](crate::prelude::kernel_Hprocess::GetProcessMitigationPolicy);
+/// * [`SetProcessMitigationPolicy`](crate::SetProcessMitigationPolicy).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ProcessMitigationPolicy {
+	/// [`PROCESS_MITIGATION_POLICY::ASLR`](crate::co::PROCESS_MITIGATION_POLICY::ASLR).
+	Aslr(PROCESS_MITIGATION_ASLR_POLICY),
+	/// [`PROCESS_MITIGATION_POLICY::SIGNATURE`](crate::co::PROCESS_MITIGATION_POLICY::SIGNATURE).
+	BinarySignature(PROCESS_MITIGATION_BINARY_SIGNATURE_POLICY),
+	/// [`PROCESS_MITIGATION_POLICY::CHILD_PROCESS`](crate::co::PROCESS_MITIGATION_POLICY::CHILD_PROCESS).
+	ChildProcess(PROCESS_MITIGATION_CHILD_PROCESS_POLICY),
+	/// [`PROCESS_MITIGATION_POLICY::CONTROL_FLOW_GUARD`](crate::co::PROCESS_MITIGATION_POLICY::CONTROL_FLOW_GUARD).
+	ControlFlowGuard(PROCESS_MITIGATION_CONTROL_FLOW_GUARD_POLICY),
+	/// [`PROCESS_MITIGATION_POLICY::DEP`](crate::co::PROCESS_MITIGATION_POLICY::DEP).
+	Dep(PROCESS_MITIGATION_DEP_POLICY),
+	/// [`PROCESS_MITIGATION_POLICY::DYNAMIC_CODE`](crate::co::PROCESS_MITIGATION_POLICY::DYNAMIC_CODE).
+	DynamicCode(PROCESS_MITIGATION_DYNAMIC_CODE_POLICY),
+	/// [`PROCESS_MITIGATION_POLICY::EXTENSION_POINT_DISABLE`](crate::co::PROCESS_MITIGATION_POLICY::EXTENSION_POINT_DISABLE).
+	ExtensionPointDisable(PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY),
+	/// [`PROCESS_MITIGATION_POLICY::FONT_DISABLE`](crate::co::PROCESS_MITIGATION_POLICY::FONT_DISABLE).
+	FontDisable(PROCESS_MITIGATION_FONT_DISABLE_POLICY),
+	/// [`PROCESS_MITIGATION_POLICY::IMAGE_LOAD`](crate::co::PROCESS_MITIGATION_POLICY::IMAGE_LOAD).
+	ImageLoad(PROCESS_MITIGATION_IMAGE_LOAD_POLICY),
+	/// [`PROCESS_MITIGATION_POLICY::STRICT_HANDLE_CHECK`](crate::co::PROCESS_MITIGATION_POLICY::STRICT_HANDLE_CHECK).
+	StrictHandleCheck(PROCESS_MITIGATION_STRICT_HANDLE_CHECK_POLICY),
+	/// [`PROCESS_MITIGATION_POLICY::SYSTEM_CALL_DISABLE`](crate::co::PROCESS_MITIGATION_POLICY::SYSTEM_CALL_DISABLE).
+	SystemCallDisable(PROCESS_MITIGATION_SYSTEM_CALL_DISABLE_POLICY),
+}
+
+impl ProcessMitigationPolicy {
+	/// Creates the variant of the given policy, with its data zeroed. Returns
+	/// `None` if the policy is not supported.
+	#[must_use]
+	pub fn from_policy(policy: co::PROCESS_MITIGATION_POLICY) -> Option<Self> {
+		Some(match policy {
+			co::PROCESS_MITIGATION_POLICY::ASLR => Self::Aslr(PROCESS_MITIGATION_ASLR_POLICY::default()),
+			co::PROCESS_MITIGATION_POLICY::SIGNATURE => Self::BinarySignature(PROCESS_MITIGATION_BINARY_SIGNATURE_POLICY::default()),
+			co::PROCESS_MITIGATION_POLICY::CHILD_PROCESS => Self::ChildProcess(PROCESS_MITIGATION_CHILD_PROCESS_POLICY::default()),
+			co::PROCESS_MITIGATION_POLICY::CONTROL_FLOW_GUARD => Self::ControlFlowGuard(PROCESS_MITIGATION_CONTROL_FLOW_GUARD_POLICY::default()),
+			co::PROCESS_MITIGATION_POLICY::DEP => Self::Dep(PROCESS_MITIGATION_DEP_POLICY::default()),
+			co::PROCESS_MITIGATION_POLICY::DYNAMIC_CODE => Self::DynamicCode(PROCESS_MITIGATION_DYNAMIC_CODE_POLICY::default()),
+			co::PROCESS_MITIGATION_POLICY::EXTENSION_POINT_DISABLE => Self::ExtensionPointDisable(PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY::default()),
+			co::PROCESS_MITIGATION_POLICY::FONT_DISABLE => Self::FontDisable(PROCESS_MITIGATION_FONT_DISABLE_POLICY::default()),
+			co::PROCESS_MITIGATION_POLICY::IMAGE_LOAD => Self::ImageLoad(PROCESS_MITIGATION_IMAGE_LOAD_POLICY::default()),
+			co::PROCESS_MITIGATION_POLICY::STRICT_HANDLE_CHECK => Self::StrictHandleCheck(PROCESS_MITIGATION_STRICT_HANDLE_CHECK_POLICY::default()),
+			co::PROCESS_MITIGATION_POLICY::SYSTEM_CALL_DISABLE => Self::SystemCallDisable(PROCESS_MITIGATION_SYSTEM_CALL_DISABLE_POLICY::default()),
+			_ => return None,
+		})
+	}
+
+	/// Returns the policy identifier of the variant.
+	#[must_use]
+	pub const fn policy(&self) -> co::PROCESS_MITIGATION_POLICY {
+		match self {
+			Self::Aslr(_) => co::PROCESS_MITIGATION_POLICY::ASLR,
+			Self::BinarySignature(_) => co::PROCESS_MITIGATION_POLICY::SIGNATURE,
+			Self::ChildProcess(_) => co::PROCESS_MITIGATION_POLICY::CHILD_PROCESS,
+			Self::ControlFlowGuard(_) => co::PROCESS_MITIGATION_POLICY::CONTROL_FLOW_GUARD,
+			Self::Dep(_) => co::PROCESS_MITIGATION_POLICY::DEP,
+			Self::DynamicCode(_) => co::PROCESS_MITIGATION_POLICY::DYNAMIC_CODE,
+			Self::ExtensionPointDisable(_) => co::PROCESS_MITIGATION_POLICY::EXTENSION_POINT_DISABLE,
+			Self::FontDisable(_) => co::PROCESS_MITIGATION_POLICY::FONT_DISABLE,
+			Self::ImageLoad(_) => co::PROCESS_MITIGATION_POLICY::IMAGE_LOAD,
+			Self::StrictHandleCheck(_) => co::PROCESS_MITIGATION_POLICY::STRICT_HANDLE_CHECK,
+			Self::SystemCallDisable(_) => co::PROCESS_MITIGATION_POLICY::SYSTEM_CALL_DISABLE,
+		}
+	}
+
+	/// Returns a pointer to the data, and its size.
+	#[must_use]
+	pub(crate) fn as_mut_ptr_size(&mut self) -> (*mut std::ffi::c_void, usize) {
+		match self {
+			Self::Aslr(p) => (p as *mut _ as _, std::mem::size_of_val(p)),
+			Self::BinarySignature(p) => (p as *mut _ as _, std::mem::size_of_val(p)),
+			Self::ChildProcess(p) => (p as *mut _ as _, std::mem::size_of_val(p)),
+			Self::ControlFlowGuard(p) => (p as *mut _ as _, std::mem::size_of_val(p)),
+			Self::Dep(p) => (p as *mut _ as _, std::mem::size_of_val(p)),
+			Self::DynamicCode(p) => (p as *mut _ as _, std::mem::size_of_val(p)),
+			Self::ExtensionPointDisable(p) => (p as *mut _ as _, std::mem::size_of_val(p)),
+			Self::FontDisable(p) => (p as *mut _ as _, std::mem::size_of_val(p)),
+			Self::ImageLoad(p) => (p as *mut _ as _, std::mem::size_of_val(p)),
+			Self::StrictHandleCheck(p) => (p as *mut _ as _, std::mem::size_of_val(p)),
+			Self::SystemCallDisable(p) => (p as *mut _ as _, std::mem::size_of_val(p)),
+		}
+	}
+}
+
 /// Registry value types.
 ///
 /// This is a high-level abstraction over the [`co::REG`](crate::co::REG)
@@ -193,6 +292,26 @@ impl RegistryValue {
 	}
 }
 
+/// Information to be set to an access token.
+///
+/// This is a high-level abstraction over the
+/// [`co::TOKEN_INFORMATION_CLASS`](crate::co::TOKEN_INFORMATION_CLASS)
+/// constants, plus the value they carry.
+///
+/// Variant parameter for:
+///
+/// * [`HACCESSTOKEN::SetTokenInformation`](crate::prelude::kernel_Haccesstoken::SetTokenInformation).
+pub enum TokenInfo<'a> {
+	/// [`TOKEN_INFORMATION_CLASS::IntegrityLevel`](crate::co::TOKEN_INFORMATION_CLASS::IntegrityLevel).
+	IntegrityLevel(TOKEN_MANDATORY_LABEL<'a>),
+	/// [`TOKEN_INFORMATION_CLASS::SessionId`](crate::co::TOKEN_INFORMATION_CLASS::SessionId).
+	SessionId(u32),
+	/// [`TOKEN_INFORMATION_CLASS::UIAccess`](crate::co::TOKEN_INFORMATION_CLASS::UIAccess).
+	UIAccess(bool),
+	/// [`TOKEN_INFORMATION_CLASS::VirtualizationEnabled`](crate::co::TOKEN_INFORMATION_CLASS::VirtualizationEnabled).
+	VirtualizationEnabled(bool),
+}
+
 /// A predefined resource identifier.
 ///
 /// Variant parameter for:
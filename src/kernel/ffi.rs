@@ -5,8 +5,10 @@ extern_sys! { "advapi32";
 	ConvertSidToStringSidW(PCVOID, *mut PSTR) -> BOOL
 	ConvertStringSidToSidW(PCSTR, *mut *mut u8) -> BOOL
 	CopySid(u32, *mut u8, PCVOID) -> BOOL
+	CreateProcessAsUserW(HANDLE, PCSTR, PSTR, PVOID, PVOID, BOOL, u32, PVOID, PCSTR, PVOID, PVOID) -> BOOL
 	CreateWellKnownSid(u32, PCVOID, *mut u8, *mut u32) -> BOOL
 	DecryptFileW(PCSTR, u32) -> BOOL
+	DuplicateTokenEx(HANDLE, u32, PVOID, u32, u32, *mut HANDLE) -> BOOL
 	EncryptFileW(PCSTR) -> BOOL
 	EncryptionDisable(PCSTR, BOOL) -> BOOL
 	EqualDomainSid(PVOID, PVOID, *mut BOOL) -> BOOL
@@ -57,20 +59,30 @@ extern_sys! { "advapi32";
 	RegSetKeyValueW(HANDLE, PCSTR, PCSTR, u32, PCVOID, u32) -> i32
 	RegSetValueExW(HANDLE, PCSTR, u32, u32, *const u8, u32) -> i32
 	RegUnLoadKeyW(HANDLE, PCSTR) -> i32
+	SetTokenInformation(HANDLE, u32, PVOID, u32) -> BOOL
 }
 
 extern_sys! { "kernel32";
+	AllocConsole() -> BOOL
+	AttachConsole(u32) -> BOOL
 	BeginUpdateResourceW(PCSTR, BOOL) -> HANDLE
+	CancelSynchronousIo(HANDLE) -> BOOL
 	CheckRemoteDebuggerPresent(HANDLE, *mut BOOL) -> BOOL
 	CloseHandle(HANDLE) -> BOOL
+	ConnectNamedPipe(HANDLE, PVOID) -> BOOL
 	CopyFileW(PCSTR, PCSTR, BOOL) -> BOOL
+	CreateEventW(PVOID, BOOL, BOOL, PCSTR) -> HANDLE
 	CreateFileMappingFromApp(HANDLE, PVOID, u32, u64, PCSTR) -> HANDLE
 	CreateFileW(PCSTR, u32, u32, PVOID, u32, u32, HANDLE) -> HANDLE
+	CreateMutexW(PVOID, BOOL, PCSTR) -> HANDLE
+	CreateNamedPipeW(PCSTR, u32, u32, u32, u32, u32, u32, PVOID) -> HANDLE
 	CreatePipe(*mut HANDLE, *mut HANDLE, PVOID, u32) -> BOOL
 	CreateProcessW(PCSTR, PSTR, PVOID, PVOID, BOOL, u32, PVOID, PCSTR, PVOID, PVOID) -> BOOL
 	CreateThread(PVOID, usize, PVOID, PVOID, u32, *mut u32) -> HANDLE
 	CreateToolhelp32Snapshot(u32, u32) -> HANDLE
 	DeleteFileW(PCSTR) -> BOOL
+	DeleteProcThreadAttributeList(PVOID)
+	DisconnectNamedPipe(HANDLE) -> BOOL
 	DuplicateToken(HANDLE, u32, *mut HANDLE) -> BOOL
 	EndUpdateResourceW(HANDLE, BOOL) -> BOOL
 	EnumResourceLanguagesW(HANDLE, PCSTR, PCSTR, PFUNC, isize) -> BOOL
@@ -89,6 +101,7 @@ extern_sys! { "kernel32";
 	FlushInstructionCache(HANDLE, PCVOID, usize) -> BOOL
 	FlushProcessWriteBuffers()
 	FormatMessageW(u32, PCVOID, u32, u32, PSTR, u32, PVOID) -> u32
+	FreeConsole() -> BOOL
 	FreeEnvironmentStringsW(HANDLE) -> BOOL
 	FreeLibrary(HANDLE) -> BOOL
 	GetBinaryTypeW(PCSTR, *mut u32) -> BOOL
@@ -110,7 +123,6 @@ extern_sys! { "kernel32";
 	GetFileInformationByHandle(HANDLE, PVOID) -> BOOL
 	GetFileSizeEx(HANDLE, *mut i64) -> BOOL
 	GetFileType(HANDLE) -> u32
-	GetFirmwareType(*mut u32) -> BOOL
 	GetGuiResources(HANDLE, u32) -> u32
 	GetLargePageMinimum() -> usize
 	GetLastError() -> u32
@@ -122,6 +134,7 @@ extern_sys! { "kernel32";
 	GetNativeSystemInfo(PVOID)
 	GetPriorityClass(HANDLE) -> u32
 	GetProcAddress(HANDLE, *const u8) -> PCVOID
+	GetProcessDEPPolicy(HANDLE, *mut u32, *mut BOOL) -> BOOL
 	GetProcessHandleCount(HANDLE, &mut u32) -> BOOL
 	GetProcessHeap() -> HANDLE
 	GetProcessHeaps(u32, *mut HANDLE) -> u32
@@ -130,16 +143,17 @@ extern_sys! { "kernel32";
 	GetProcessTimes(HANDLE, PVOID, PVOID, PVOID, PVOID) -> BOOL
 	GetStartupInfoW(PVOID)
 	GetStdHandle(u32) -> HANDLE
+	GetSystemDEPPolicy() -> i32
 	GetSystemDirectoryW(PSTR, u32) -> u32
 	GetSystemFileCacheSize(*mut usize, *mut usize, *mut u32) -> BOOL
 	GetSystemInfo(PVOID)
 	GetSystemTime(PVOID)
 	GetSystemTimeAsFileTime(PVOID)
-	GetSystemTimePreciseAsFileTime(PVOID)
 	GetSystemTimes(PVOID, PVOID, PVOID) -> BOOL
 	GetTempPathW(u32, PSTR) -> u32
 	GetThreadId(HANDLE) -> u32
 	GetThreadTimes(HANDLE, PVOID, PVOID, PVOID, PVOID) -> BOOL
+	GetTickCount() -> u32
 	GetTickCount64() -> u64
 	GetVolumeInformationW(PCSTR, PSTR, u32, *mut u32, *mut u32, *mut u32, PSTR, u32) -> BOOL
 	GlobalAlloc(u32, usize) -> HANDLE
@@ -162,6 +176,7 @@ extern_sys! { "kernel32";
 	HeapSize(HANDLE, u32, PVOID) -> usize
 	HeapUnlock(HANDLE) -> BOOL
 	HeapWalk(HANDLE, PVOID) -> BOOL
+	InitializeProcThreadAttributeList(PVOID, u32, u32, *mut usize) -> BOOL
 	IsDebuggerPresent() -> BOOL
 	IsNativeVhdBoot(*mut BOOL) -> BOOL
 	IsProcessCritical(HANDLE, *mut BOOL) -> BOOL
@@ -169,22 +184,21 @@ extern_sys! { "kernel32";
 	IsWow64Process(HANDLE, *mut BOOL) -> BOOL
 	LoadLibraryW(PCSTR) -> HANDLE
 	LoadResource(HANDLE, HANDLE) -> HANDLE
-	LocalAlloc(u32, usize) -> HANDLE
 	LocalFlags(HANDLE) -> u32
-	LocalFree(HANDLE) -> HANDLE
 	LocalReAlloc(HANDLE, usize, u32) -> HANDLE
 	LocalSize(HANDLE) -> usize
 	LockFile(HANDLE, u32, u32, u32, u32) -> BOOL
 	LockResource(HANDLE) -> PVOID
-	lstrlenW(PCSTR) -> i32
 	MapViewOfFileFromApp(HANDLE, u32, u64, usize) -> PVOID
 	Module32FirstW(HANDLE, PVOID) -> BOOL
 	Module32NextW(HANDLE, PVOID) -> BOOL
 	MoveFileW(PCSTR, PCSTR) -> BOOL
-	MulDiv(i32, i32, i32) -> i32
-	MultiByteToWideChar(u32, u32, *const u8, i32, PSTR, i32) -> i32
+	OpenEventW(u32, BOOL, PCSTR) -> HANDLE
+	OpenFileMappingW(u32, BOOL, PCSTR) -> HANDLE
+	OpenMutexW(u32, BOOL, PCSTR) -> HANDLE
 	OpenProcess(u32, BOOL, u32) -> HANDLE
 	OpenProcessToken(HANDLE, u32, *mut HANDLE) -> BOOL
+	OpenThread(u32, BOOL, u32) -> HANDLE
 	OpenThreadToken(HANDLE, u32, BOOL, *mut HANDLE) -> BOOL
 	OutputDebugStringW(PCSTR)
 	Process32FirstW(HANDLE, PVOID) -> BOOL
@@ -195,16 +209,24 @@ extern_sys! { "kernel32";
 	QueryProcessAffinityUpdateMode(HANDLE, *mut u32) -> BOOL
 	ReadConsoleW(HANDLE, PVOID, u32, *mut u32, PVOID) -> BOOL
 	ReadFile(HANDLE, PVOID, u32, *mut u32, PVOID) -> BOOL
+	ReleaseMutex(HANDLE) -> BOOL
 	ReplaceFileW(PCSTR, PCSTR, PCSTR, u32, PVOID, PVOID) -> BOOL
+	ResetEvent(HANDLE) -> BOOL
 	ResumeThread(HANDLE) -> u32
+	SetConsoleCtrlHandler(PFUNC, BOOL) -> BOOL
 	SetConsoleMode(HANDLE, u32) -> BOOL
 	SetCurrentDirectoryW(PCSTR) -> BOOL
 	SetEndOfFile(HANDLE) -> BOOL
+	SetEvent(HANDLE) -> BOOL
 	SetFilePointerEx(HANDLE, i64, *mut i64, u32) -> BOOL
 	SetLastError(u32)
+	SetNamedPipeHandleState(HANDLE, *mut u32, *mut u32, *mut u32) -> BOOL
 	SetPriorityClass(HANDLE, u32) -> BOOL
 	SetProcessAffinityUpdateMode(HANDLE, u32) -> BOOL
+	SetProcessDEPPolicy(u32) -> BOOL
 	SetProcessPriorityBoost(HANDLE, BOOL) -> BOOL
+	SetStdHandle(u32, HANDLE) -> BOOL
+	SetThreadExecutionState(u32) -> u32
 	SetThreadIdealProcessor(HANDLE, u32) -> u32
 	SetThreadIdealProcessorEx(HANDLE, PCVOID, PVOID) -> BOOL
 	SetThreadPriorityBoost(HANDLE, BOOL) -> BOOL
@@ -221,10 +243,12 @@ extern_sys! { "kernel32";
 	Thread32Next(HANDLE, PVOID) -> BOOL
 	UnlockFile(HANDLE, u32, u32, u32, u32) -> BOOL
 	UnmapViewOfFile(PCVOID) -> BOOL
+	UpdateProcThreadAttribute(PVOID, u32, usize, PVOID, usize, PVOID, *mut usize) -> BOOL
 	UpdateResourceW(HANDLE, PCSTR, PCSTR, u16, PVOID, u32) -> BOOL
 	VerifyVersionInfoW(PVOID, u32, u64) -> BOOL
 	VerSetConditionMask(u64, u32, u8) -> u64
 	WaitForSingleObject(HANDLE, u32) -> u32
+	WaitNamedPipeW(PCSTR, u32) -> BOOL
 	WideCharToMultiByte(u32, u32, PCSTR, i32, PSTR, i32, *const u8, *mut BOOL) -> i32
 	WriteConsoleW(HANDLE, PCVOID, u32, *mut u32, PVOID) -> BOOL
 	WriteFile(HANDLE, PCVOID, u32, *mut u32, PVOID) -> BOOL
@@ -237,3 +261,25 @@ extern_sys! { "ktmw32";
 	OpenTransaction(u32, PVOID) -> HANDLE
 	RollbackTransaction(HANDLE) -> BOOL
 }
+
+extern_sys_delay! { "kernel32"; // Windows 8
+	GetFirmwareType(*mut u32) -> BOOL
+	GetProcessMitigationPolicy(HANDLE, i32, PVOID, usize) -> BOOL
+	GetSystemTimePreciseAsFileTime(PVOID)
+	SetProcessMitigationPolicy(i32, PCVOID, usize) -> BOOL
+}
+
+// Functions which don't need a live Windows session, replaced by pure
+// implementations in the unit tests off Windows.
+
+#[cfg(not(all(test, not(windows))))]
+extern_sys! { "kernel32";
+	LocalAlloc(u32, usize) -> HANDLE
+	LocalFree(HANDLE) -> HANDLE
+	lstrlenW(PCSTR) -> i32
+	MulDiv(i32, i32, i32) -> i32
+	MultiByteToWideChar(u32, u32, *const u8, i32, PSTR, i32) -> i32
+}
+
+#[cfg(all(test, not(windows)))]
+pub(crate) use super::ffi_fakes::*;
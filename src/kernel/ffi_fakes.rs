@@ -0,0 +1,91 @@
+//! Pure implementations of some kernel32 functions, used in the unit tests off
+//! Windows. Only the behavior the library relies upon is implemented.
+
+#![allow(non_snake_case)]
+
+use std::alloc::{alloc_zeroed, dealloc, Layout};
+
+use crate::kernel::ffi_types::{HANDLE, PCSTR, PSTR};
+
+const HEADER: usize = std::mem::size_of::<usize>(); // stores the block size
+
+const LMEM_MOVEABLE: u32 = 0x0002;
+const CP_UTF8: u32 = 65001;
+
+fn layout(num_bytes: usize) -> Layout {
+	Layout::from_size_align(HEADER + num_bytes, HEADER).unwrap()
+}
+
+/// Only fixed memory is supported, and it's always zero-initialized.
+pub(crate) unsafe fn LocalAlloc(uFlags: u32, uBytes: usize) -> HANDLE {
+	assert_eq!(uFlags & LMEM_MOVEABLE, 0, "Moveable memory is not supported in tests.");
+	unsafe {
+		let ptr = alloc_zeroed(layout(uBytes));
+		if ptr.is_null() {
+			return std::ptr::null_mut();
+		}
+		*(ptr as *mut usize) = uBytes;
+		ptr.add(HEADER) as _
+	}
+}
+
+pub(crate) unsafe fn LocalFree(hMem: HANDLE) -> HANDLE {
+	if !hMem.is_null() {
+		unsafe {
+			let ptr = (hMem as *mut u8).sub(HEADER);
+			dealloc(ptr, layout(*(ptr as *const usize)));
+		}
+	}
+	std::ptr::null_mut()
+}
+
+pub(crate) unsafe fn lstrlenW(lpString: PCSTR) -> i32 {
+	if lpString.is_null() {
+		return 0;
+	}
+	let mut len = 0;
+	while unsafe { *lpString.add(len) } != 0 {
+		len += 1;
+	}
+	len as _
+}
+
+/// Rounds half away from zero, and returns -1 on overflow or division by zero.
+pub(crate) unsafe fn MulDiv(nNumber: i32, nNumerator: i32, nDenominator: i32) -> i32 {
+	if nDenominator == 0 {
+		return -1;
+	}
+	let (prod, den) = (nNumber as i64 * nNumerator as i64, nDenominator as i64);
+	let half = den.abs() / 2;
+	let res = if (prod < 0) != (den < 0) { (prod - half) / den } else { (prod + half) / den };
+	i32::try_from(res).unwrap_or(-1)
+}
+
+/// Only UTF-8 is supported, and invalid sequences are replaced.
+pub(crate) unsafe fn MultiByteToWideChar(
+	CodePage: u32,
+	_dwFlags: u32,
+	lpMultiByteStr: *const u8,
+	cbMultiByte: i32,
+	lpWideCharStr: PSTR,
+	cchWideChar: i32,
+) -> i32
+{
+	assert_eq!(CodePage, CP_UTF8, "Only UTF-8 is supported in tests.");
+	let src = unsafe {
+		std::slice::from_raw_parts(lpMultiByteStr, match cbMultiByte {
+			-1 => (0..).take_while(|i| *lpMultiByteStr.add(*i) != 0).count() + 1,
+			n => n as _,
+		})
+	};
+	let wide = String::from_utf8_lossy(src).encode_utf16().collect::<Vec<_>>();
+
+	if cchWideChar == 0 {
+		wide.len() as _
+	} else if (cchWideChar as usize) < wide.len() {
+		0 // ERROR_INSUFFICIENT_BUFFER
+	} else {
+		unsafe { lpWideCharStr.copy_from_nonoverlapping(wide.as_ptr(), wide.len()); }
+		wide.len() as _
+	}
+}
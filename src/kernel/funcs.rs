@@ -1,16 +1,21 @@
 #![allow(non_snake_case)]
 
 use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::{co, kernel};
 use crate::kernel::decl::{
 	FILETIME, HLOCAL, LANGID, MEMORYSTATUSEX, OSVERSIONINFOEX,
-	SECURITY_DESCRIPTOR, SID, SID_IDENTIFIER_AUTHORITY, STARTUPINFO, SysResult,
-	SYSTEM_INFO, SYSTEMTIME, TIME_ZONE_INFORMATION, WString,
+	ProcessMitigationPolicy, SECURITY_DESCRIPTOR, SID, SID_IDENTIFIER_AUTHORITY,
+	STARTUPINFO, SysResult, SYSTEM_INFO, SYSTEMTIME, TIME_ZONE_INFORMATION,
+	WString,
 };
 use crate::kernel::ffi_types::BOOL;
 use crate::kernel::guard::{
-	FreeSidGuard, LocalFreeGuard, LocalFreeSidGuard, SidGuard,
+	ConsoleCtrlHandlerGuard, FreeSidGuard, LocalFreeGuard, LocalFreeSidGuard,
+	SidGuard,
 };
 use crate::kernel::privs::{
 	bool_to_sysresult, INVALID_FILE_ATTRIBUTES, MAX_COMPUTERNAME_LENGTH,
@@ -18,6 +23,16 @@ use crate::kernel::privs::{
 };
 use crate::prelude::Handle;
 
+/// [`AllocConsole`](https://learn.microsoft.com/en-us/windows/console/allocconsole)
+/// function.
+///
+/// Prefer using
+/// [`Console::attach_parent_or_alloc`](crate::Console::attach_parent_or_alloc),
+/// which also redirects the standard handles.
+pub fn AllocConsole() -> SysResult<()> {
+	bool_to_sysresult(unsafe { kernel::ffi::AllocConsole() })
+}
+
 /// [`AllocateAndInitializeSid`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-allocateandinitializesid)
 /// function.
 /// 
@@ -88,6 +103,17 @@ pub fn AllocateAndInitializeSid(
 	}
 }
 
+/// [`AttachConsole`](https://learn.microsoft.com/en-us/windows/console/attachconsole)
+/// function.
+///
+/// If `process_id` is `None`, attaches to the console of the parent process
+/// (`ATTACH_PARENT_PROCESS`).
+pub fn AttachConsole(process_id: Option<u32>) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe { kernel::ffi::AttachConsole(process_id.unwrap_or(u32::MAX)) },
+	)
+}
+
 /// [`ConvertSidToStringSid`](https://learn.microsoft.com/en-us/windows/win32/api/sddl/nf-sddl-convertsidtostringsidw)
 /// function.
 /// 
@@ -401,6 +427,12 @@ pub unsafe fn FormatMessage(
 	Ok(final_str)
 }
 
+/// [`FreeConsole`](https://learn.microsoft.com/en-us/windows/console/freeconsole)
+/// function.
+pub fn FreeConsole() -> SysResult<()> {
+	bool_to_sysresult(unsafe { kernel::ffi::FreeConsole() })
+}
+
 /// [`GetBinaryType`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getbinarytypew)
 /// function.
 #[must_use]
@@ -521,10 +553,14 @@ pub fn GetEnvironmentStrings() -> SysResult<HashMap<String, String>> {
 
 /// [`GetFirmwareType`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getfirmwaretype)
 /// function.
+///
+/// Returns [`co::ERROR::CALL_NOT_IMPLEMENTED`](crate::co::ERROR::CALL_NOT_IMPLEMENTED)
+/// on systems older than Windows 8.
 #[must_use]
 pub fn GetFirmwareType() -> SysResult<co::FIRMWARE_TYPE> {
+	let func = kernel::ffi::GetFirmwareType()?;
 	let mut ft = u32::default();
-	bool_to_sysresult(unsafe { kernel::ffi::GetFirmwareType(&mut ft) })
+	bool_to_sysresult(unsafe { func(&mut ft) })
 		.map(|_| co::FIRMWARE_TYPE(ft))
 }
 
@@ -668,6 +704,13 @@ pub fn GetStartupInfo(si: &mut STARTUPINFO) {
 	unsafe { kernel::ffi::GetStartupInfoW(si as *mut _ as _) }
 }
 
+/// [`GetSystemDEPPolicy`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getsystemdeppolicy)
+/// function.
+#[must_use]
+pub fn GetSystemDEPPolicy() -> co::DEP_SYSTEM_POLICY {
+	co::DEP_SYSTEM_POLICY(unsafe { kernel::ffi::GetSystemDEPPolicy() } as _)
+}
+
 /// [`GetSystemDirectory`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getsystemdirectoryw)
 /// function.
 #[must_use]
@@ -739,8 +782,15 @@ pub fn GetSystemTimeAsFileTime(ft: &mut FILETIME) {
 
 /// [`GetSystemTimePreciseAsFileTime`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getsystemtimepreciseasfiletime)
 /// function.
+///
+/// On systems older than Windows 8, where this function is not available,
+/// [`GetSystemTimeAsFileTime`](crate::GetSystemTimeAsFileTime) is called
+/// instead.
 pub fn GetSystemTimePreciseAsFileTime(ft: &mut FILETIME) {
-	unsafe { kernel::ffi::GetSystemTimePreciseAsFileTime(ft as *mut _ as _) }
+	match kernel::ffi::GetSystemTimePreciseAsFileTime() {
+		Ok(func) => unsafe { func(ft as *mut _ as _) },
+		Err(_) => GetSystemTimeAsFileTime(ft),
+	}
 }
 
 /// [`GetSystemTimes`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getsystemtimes)
@@ -774,6 +824,17 @@ pub fn GetTempPath() -> SysResult<String> {
 	).map(|_| buf.to_string())
 }
 
+/// [`GetTickCount`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-gettickcount)
+/// function.
+///
+/// The value wraps around every 49.7 days; prefer
+/// [`GetTickCount64`](crate::GetTickCount64), unless comparing with other
+/// 32-bit tick counts.
+#[must_use]
+pub fn GetTickCount() -> u32 {
+	unsafe { kernel::ffi::GetTickCount() }
+}
+
 /// [`GetTickCount64`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-gettickcount64)
 /// function.
 #[must_use]
@@ -1381,6 +1442,93 @@ pub fn ReplaceFile(
 	)
 }
 
+type ConsoleCtrlFunc = Arc<dyn Fn(co::CTRL) -> bool + Send + Sync>;
+
+/// Closures added with [`SetConsoleCtrlHandler`](crate::SetConsoleCtrlHandler),
+/// along with their unique IDs.
+static CONSOLE_CTRL_HANDLERS: Mutex<Vec<(usize, ConsoleCtrlFunc)>> =
+	Mutex::new(Vec::new());
+
+static NEXT_CONSOLE_CTRL_HANDLER_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// [`SetConsoleCtrlHandler`](https://learn.microsoft.com/en-us/windows/console/setconsolectrlhandler)
+/// function.
+///
+/// Adds a closure to be called when the process receives a console control
+/// signal. The closure runs in a separate thread created by the system, and
+/// must return true if it handled the signal.
+///
+/// Closures are called in the reverse order they were added, until one of
+/// them returns true. If none does, the default handler is called, which
+/// terminates the process. A panicking closure is considered to have returned
+/// false.
+///
+/// The closure is removed when the returned
+/// [`ConsoleCtrlHandlerGuard`](crate::guard::ConsoleCtrlHandlerGuard) goes out
+/// of scope.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, SetConsoleCtrlHandler};
+///
+/// let _ctrl_handler = SetConsoleCtrlHandler(|ctrl: co::CTRL| -> bool {
+///     match ctrl {
+///         co::CTRL::C_EVENT | co::CTRL::BREAK_EVENT => {
+///             println!("Interrupted, saving state...");
+///             true // don't terminate the process
+///         },
+///         _ => false,
+///     }
+/// })?; // keep guard alive
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub fn SetConsoleCtrlHandler<F>(func: F) -> SysResult<ConsoleCtrlHandlerGuard>
+	where F: Fn(co::CTRL) -> bool + Send + Sync + 'static,
+{
+	let mut handlers = CONSOLE_CTRL_HANDLERS.lock()
+		.unwrap_or_else(PoisonError::into_inner);
+	if handlers.is_empty() { // first closure, install our native handler
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::SetConsoleCtrlHandler(console_ctrl_handler as _, 1)
+			},
+		)?;
+	}
+
+	let id = NEXT_CONSOLE_CTRL_HANDLER_ID.fetch_add(1, Ordering::Relaxed);
+	handlers.push((id, Arc::new(func)));
+	Ok(unsafe { ConsoleCtrlHandlerGuard::new(id) })
+}
+extern "system" fn console_ctrl_handler(ctrl_type: u32) -> BOOL {
+	let handlers = CONSOLE_CTRL_HANDLERS.lock()
+		.unwrap_or_else(PoisonError::into_inner)
+		.iter()
+		.map(|(_, func)| func.clone())
+		.collect::<Vec<_>>(); // copy, so closures are free to add/remove handlers
+	handlers.iter()
+		.rev()
+		.any(|func| {
+			// A panic cannot unwind across the handler, which is called by the
+			// system.
+			panic::catch_unwind(AssertUnwindSafe(|| func(co::CTRL(ctrl_type))))
+				.unwrap_or(false)
+		}) as _
+}
+
+/// Removes the closure added by
+/// [`SetConsoleCtrlHandler`](crate::SetConsoleCtrlHandler); called by
+/// [`ConsoleCtrlHandlerGuard`](crate::guard::ConsoleCtrlHandlerGuard).
+pub(in crate::kernel) fn remove_console_ctrl_handler(id: usize) {
+	let mut handlers = CONSOLE_CTRL_HANDLERS.lock()
+		.unwrap_or_else(PoisonError::into_inner);
+	handlers.retain(|(handler_id, _)| *handler_id != id);
+	if handlers.is_empty() { // last closure, uninstall our native handler
+		unsafe { kernel::ffi::SetConsoleCtrlHandler(console_ctrl_handler as _, 0); }
+	}
+}
+
 /// [`SetCurrentDirectory`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-setcurrentdirectory)
 /// function.
 pub fn SetCurrentDirectory(path_name: &str) -> SysResult<()> {
@@ -1397,6 +1545,69 @@ pub fn SetLastError(err_code: co::ERROR) {
 	unsafe { kernel::ffi::SetLastError(err_code.0) }
 }
 
+/// [`SetProcessDEPPolicy`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-setprocessdeppolicy)
+/// function.
+///
+/// This function works only for 32-bit processes.
+pub fn SetProcessDEPPolicy(flags: co::PROCESS_DEP) -> SysResult<()> {
+	bool_to_sysresult(unsafe { kernel::ffi::SetProcessDEPPolicy(flags.0) })
+}
+
+/// [`SetProcessMitigationPolicy`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-setprocessmitigationpolicy)
+/// function.
+///
+/// Returns [`co::ERROR::CALL_NOT_IMPLEMENTED`](crate::co::ERROR::CALL_NOT_IMPLEMENTED)
+/// on systems older than Windows 8.
+///
+/// # Examples
+///
+/// Prevent the current process from generating dynamic code:
+///
+/// ```rust,no_run
+/// use winsafe::{ProcessMitigationPolicy, PROCESS_MITIGATION_DYNAMIC_CODE_POLICY};
+/// use winsafe::SetProcessMitigationPolicy;
+///
+/// let mut dyn_code = PROCESS_MITIGATION_DYNAMIC_CODE_POLICY::default();
+/// dyn_code.set_ProhibitDynamicCode(true);
+///
+/// SetProcessMitigationPolicy(
+///     &ProcessMitigationPolicy::DynamicCode(dyn_code))?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub fn SetProcessMitigationPolicy(
+	policy: &ProcessMitigationPolicy) -> SysResult<()>
+{
+	let func = kernel::ffi::SetProcessMitigationPolicy()?;
+	let mut policy = *policy; // the function doesn't write to it
+	let (ptr, sz) = policy.as_mut_ptr_size();
+	bool_to_sysresult(unsafe { func(policy.policy().0, ptr, sz) })
+}
+
+/// [`SetThreadExecutionState`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-setthreadexecutionstate)
+/// function.
+///
+/// Returns the previous state, or `None` if the function fails.
+///
+/// # Examples
+///
+/// Keeping the display on while a presentation is running, then restoring:
+///
+/// ```rust,no_run
+/// use winsafe::{co, SetThreadExecutionState};
+///
+/// SetThreadExecutionState(co::EXECUTION_STATE::CONTINUOUS | co::EXECUTION_STATE::DISPLAY_REQUIRED);
+///
+/// // presentation running...
+///
+/// SetThreadExecutionState(co::EXECUTION_STATE::CONTINUOUS);
+/// ```
+pub fn SetThreadExecutionState(flags: co::EXECUTION_STATE) -> Option<co::EXECUTION_STATE> {
+	match unsafe { kernel::ffi::SetThreadExecutionState(flags.0) } {
+		0 => None,
+		prev => Some(co::EXECUTION_STATE(prev)),
+	}
+}
+
 /// [`SetThreadStackGuarantee`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-setthreadstackguarantee)
 /// function.
 /// 
@@ -1488,6 +1699,21 @@ pub fn VerSetConditionMask(
 	}
 }
 
+/// [`WaitNamedPipe`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-waitnamedpipew)
+/// function.
+///
+/// If `milliseconds` is `None`, waits for the default time-out of the pipe.
+pub fn WaitNamedPipe(name: &str, milliseconds: Option<u32>) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe {
+			kernel::ffi::WaitNamedPipeW(
+				WString::from_str(name).as_ptr(),
+				milliseconds.unwrap_or_default(), // NMPWAIT_USE_DEFAULT_WAIT
+			)
+		},
+	)
+}
+
 /// [`WideCharToMultiByte`](https://learn.microsoft.com/en-us/windows/win32/api/stringapiset/nf-stringapiset-widechartomultibyte)
 /// function.
 ///
@@ -141,6 +141,37 @@ impl CloseHandlePiGuard {
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation which automatically removes the closure added by
+/// [`SetConsoleCtrlHandler`](crate::SetConsoleCtrlHandler) when the object
+/// goes out of scope.
+pub struct ConsoleCtrlHandlerGuard {
+	id: usize,
+}
+
+impl Drop for ConsoleCtrlHandlerGuard {
+	fn drop(&mut self) {
+		kernel::funcs::remove_console_ctrl_handler(self.id);
+	}
+}
+
+impl ConsoleCtrlHandlerGuard {
+	/// Constructs the guard by taking ownership of the closure ID.
+	/// 
+	/// # Safety
+	/// 
+	/// Be sure the ID refers to a closure added by
+	/// [`SetConsoleCtrlHandler`](crate::SetConsoleCtrlHandler).
+	/// 
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(id: usize) -> Self {
+		Self { id }
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation [`HUPDATERSRC`](crate::HUPDATERSRC) which automatically
 /// calls
 /// [`EndUpdateResource`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-endupdateresourcew)
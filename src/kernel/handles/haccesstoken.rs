@@ -1,8 +1,11 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
 use crate::{co, kernel};
-use crate::kernel::decl::{GetLastError, SysResult};
-use crate::kernel::guard::CloseHandleGuard;
+use crate::kernel::decl::{
+	GetLastError, GetLengthSid, PROCESS_INFORMATION, SECURITY_ATTRIBUTES,
+	STARTUPINFO, SysResult, TOKEN_MANDATORY_LABEL, TokenInfo, WString,
+};
+use crate::kernel::guard::{CloseHandleGuard, CloseHandlePiGuard};
 use crate::kernel::privs::bool_to_sysresult;
 use crate::prelude::Handle;
 
@@ -23,6 +26,87 @@ impl kernel_Haccesstoken for HACCESSTOKEN {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Haccesstoken: Handle {
+	/// [`CreateProcessAsUser`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-createprocessasuserw)
+	/// method.
+	///
+	/// The token must be a primary token, which can be obtained with
+	/// [`DuplicateTokenEx`](crate::prelude::kernel_Haccesstoken::DuplicateTokenEx).
+	///
+	/// # Examples
+	///
+	/// Launching a low-integrity process:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CreateWellKnownSid, HPROCESS, STARTUPINFO};
+	/// use winsafe::{TOKEN_MANDATORY_LABEL, TokenInfo};
+	///
+	/// let htoken = HPROCESS::GetCurrentProcess().OpenProcessToken(
+	///     co::TOKEN::DUPLICATE | co::TOKEN::QUERY
+	///     | co::TOKEN::ADJUST_DEFAULT | co::TOKEN::ASSIGN_PRIMARY)?;
+	///
+	/// let hlow = htoken.DuplicateTokenEx(
+	///     co::TOKEN::DUPLICATE | co::TOKEN::QUERY
+	///     | co::TOKEN::ADJUST_DEFAULT | co::TOKEN::ASSIGN_PRIMARY,
+	///     None,
+	///     co::SECURITY_IMPERSONATION::Impersonation,
+	///     co::TOKEN_TYPE::Primary,
+	/// )?;
+	///
+	/// let low_sid = CreateWellKnownSid(co::WELL_KNOWN_SID_TYPE::LowLabel, None)?;
+	/// let mut label = TOKEN_MANDATORY_LABEL::default();
+	/// label.Label.set_Sid(Some(&low_sid));
+	/// label.Label.Attributes = co::SE_GROUP::INTEGRITY;
+	/// hlow.SetTokenInformation(TokenInfo::IntegrityLevel(label))?;
+	///
+	/// let mut si = STARTUPINFO::default();
+	/// let pi = hlow.CreateProcessAsUser(
+	///     None, Some("C:\\Windows\\notepad.exe"), None, None, false,
+	///     co::CREATE::NoValue, None, None, &mut si,
+	/// )?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn CreateProcessAsUser(&self,
+		application_name: Option<&str>,
+		command_line: Option<&str>,
+		process_attrs: Option<&mut SECURITY_ATTRIBUTES>,
+		thread_attrs: Option<&mut SECURITY_ATTRIBUTES>,
+		inherit_handles: bool,
+		creation_flags: co::CREATE,
+		environment: Option<Vec<(&str, &str)>>,
+		current_dir: Option<&str>,
+		si: &mut STARTUPINFO,
+	) -> SysResult<CloseHandlePiGuard>
+	{
+		let mut buf_cmd_line = WString::from_opt_str(command_line);
+		let mut pi = PROCESS_INFORMATION::default();
+
+		unsafe {
+			bool_to_sysresult(
+				kernel::ffi::CreateProcessAsUserW(
+					self.as_ptr(),
+					WString::from_opt_str(application_name).as_ptr(),
+					buf_cmd_line.as_mut_ptr(),
+					process_attrs.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+					thread_attrs.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+					inherit_handles as _,
+					creation_flags.0,
+					environment.map_or(std::ptr::null_mut(), |environment| {
+						WString::from_str_vec(
+							&environment.iter()
+								.map(|(name, val)| format!("{}={}", name, val))
+								.collect::<Vec<_>>()
+						).as_ptr() as _
+					}),
+					WString::from_opt_str(current_dir).as_ptr(),
+					si as *mut _ as _,
+					&mut pi as *mut _ as _,
+				),
+			).map(|_| CloseHandlePiGuard::new(pi))
+		}
+	}
+
 	/// [`DuplicateToken`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-duplicatetoken)
 	/// method.
 	#[must_use]
@@ -42,6 +126,35 @@ pub trait kernel_Haccesstoken: Handle {
 		}
 	}
 
+	/// [`DuplicateTokenEx`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-duplicatetokenex)
+	/// method.
+	///
+	/// If `desired_access` is
+	/// [`TOKEN::NoValue`](crate::co::TOKEN::NoValue), the new token will have
+	/// the same access rights of the existing one.
+	#[must_use]
+	fn DuplicateTokenEx(&self,
+		desired_access: co::TOKEN,
+		token_attrs: Option<&mut SECURITY_ATTRIBUTES>,
+		impersonation_level: co::SECURITY_IMPERSONATION,
+		token_type: co::TOKEN_TYPE,
+	) -> SysResult<CloseHandleGuard<HACCESSTOKEN>>
+	{
+		let mut handle = HACCESSTOKEN::NULL;
+		unsafe {
+			bool_to_sysresult(
+				kernel::ffi::DuplicateTokenEx(
+					self.as_ptr(),
+					desired_access.0,
+					token_attrs.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+					impersonation_level.0,
+					token_type.0,
+					handle.as_mut(),
+				),
+			).map(|_| CloseHandleGuard::new(handle))
+		}
+	}
+
 	/// [`GetCurrentProcessToken`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getcurrentprocesstoken)
 	/// static method.
 	#[must_use]
@@ -68,4 +181,38 @@ pub trait kernel_Haccesstoken: Handle {
 			_ => Ok(true),
 		}
 	}
+
+	/// [`SetTokenInformation`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-settokeninformation)
+	/// method.
+	fn SetTokenInformation(&self, information: TokenInfo) -> SysResult<()> {
+		let mut dword: u32 = match &information { // storage for the integer values
+			TokenInfo::SessionId(id) => *id,
+			TokenInfo::UIAccess(enabled)
+				| TokenInfo::VirtualizationEnabled(enabled) => *enabled as _,
+			_ => 0,
+		};
+		let dword_ptr = &mut dword as *mut u32 as *mut std::ffi::c_void;
+		let dword_sz = std::mem::size_of::<u32>() as u32;
+
+		let (class, ptr, sz) = match &information {
+			TokenInfo::IntegrityLevel(label) => (
+				co::TOKEN_INFORMATION_CLASS::IntegrityLevel,
+				label as *const _ as *mut std::ffi::c_void,
+				std::mem::size_of::<TOKEN_MANDATORY_LABEL>() as u32
+					+ label.Label.Sid().map_or(0, GetLengthSid),
+			),
+			TokenInfo::SessionId(_) =>
+				(co::TOKEN_INFORMATION_CLASS::SessionId, dword_ptr, dword_sz),
+			TokenInfo::UIAccess(_) =>
+				(co::TOKEN_INFORMATION_CLASS::UIAccess, dword_ptr, dword_sz),
+			TokenInfo::VirtualizationEnabled(_) =>
+				(co::TOKEN_INFORMATION_CLASS::VirtualizationEnabled, dword_ptr, dword_sz),
+		};
+
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::SetTokenInformation(self.as_ptr(), class.0, ptr, sz)
+			},
+		)
+	}
 }
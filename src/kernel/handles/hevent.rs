@@ -0,0 +1,105 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::{co, kernel};
+use crate::kernel::decl::{
+	GetLastError, SECURITY_ATTRIBUTES, SysResult, WString,
+};
+use crate::kernel::guard::CloseHandleGuard;
+use crate::kernel::privs::{
+	bool_to_sysresult, INFINITE, ptr_to_sysresult_handle,
+};
+use crate::prelude::Handle;
+
+impl_handle! { HEVENT;
+	/// Handle to an
+	/// [event](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createeventw).
+	/// Originally just a `HANDLE`.
+}
+
+impl kernel_Hevent for HEVENT {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HEVENT`](crate::HEVENT).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hevent: Handle {
+	/// [`CreateEvent`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createeventw)
+	/// static method.
+	///
+	/// If a named event already exists, it will be opened, and
+	/// [`GetLastError`](crate::GetLastError) will return
+	/// [`ERROR::ALREADY_EXISTS`](crate::co::ERROR::ALREADY_EXISTS).
+	#[must_use]
+	fn CreateEvent(
+		security_attributes: Option<&mut SECURITY_ATTRIBUTES>,
+		manual_reset: bool,
+		initial_state: bool,
+		name: Option<&str>,
+	) -> SysResult<CloseHandleGuard<HEVENT>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::CreateEventW(
+					security_attributes.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+					manual_reset as _,
+					initial_state as _,
+					WString::from_opt_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`OpenEvent`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-openeventw)
+	/// static method.
+	#[must_use]
+	fn OpenEvent(
+		desired_access: co::EVENT,
+		inherit_handle: bool,
+		name: &str,
+	) -> SysResult<CloseHandleGuard<HEVENT>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::OpenEventW(
+					desired_access.0,
+					inherit_handle as _,
+					WString::from_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`ResetEvent`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-resetevent)
+	/// method.
+	fn ResetEvent(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { kernel::ffi::ResetEvent(self.as_ptr()) })
+	}
+
+	/// [`SetEvent`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-setevent)
+	/// method.
+	fn SetEvent(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { kernel::ffi::SetEvent(self.as_ptr()) })
+	}
+
+	/// [`WaitForSingleObject`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-waitforsingleobject)
+	/// method.
+	fn WaitForSingleObject(&self,
+		milliseconds: Option<u32>) -> SysResult<co::WAIT>
+	{
+		match unsafe {
+			co::WAIT(
+				kernel::ffi::WaitForSingleObject(
+					self.as_ptr(),
+					milliseconds.unwrap_or(INFINITE),
+				),
+			)
+		} {
+			co::WAIT::FAILED => Err(GetLastError()),
+			wait => Ok(wait),
+		}
+	}
+}
@@ -1,8 +1,8 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
 use crate::{co, kernel};
-use crate::kernel::decl::SysResult;
-use crate::kernel::guard::UnmapViewOfFileGuard;
+use crate::kernel::decl::{SysResult, WString};
+use crate::kernel::guard::{CloseHandleGuard, UnmapViewOfFileGuard};
 use crate::kernel::privs::ptr_to_sysresult_handle;
 use crate::prelude::Handle;
 
@@ -46,4 +46,24 @@ pub trait kernel_Hfilemap: Handle {
 			).map(|h| UnmapViewOfFileGuard::new(h))
 		}
 	}
+
+	/// [`OpenFileMapping`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-openfilemappingw)
+	/// static method.
+	#[must_use]
+	fn OpenFileMapping(
+		desired_access: co::FILE_MAP,
+		inherit_handle: bool,
+		name: &str,
+	) -> SysResult<CloseHandleGuard<HFILEMAP>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::OpenFileMappingW(
+					desired_access.0,
+					inherit_handle as _,
+					WString::from_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
 }
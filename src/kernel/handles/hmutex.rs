@@ -0,0 +1,100 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::{co, kernel};
+use crate::kernel::decl::{
+	GetLastError, SECURITY_ATTRIBUTES, SysResult, WString,
+};
+use crate::kernel::guard::CloseHandleGuard;
+use crate::kernel::privs::{
+	bool_to_sysresult, INFINITE, ptr_to_sysresult_handle,
+};
+use crate::prelude::Handle;
+
+impl_handle! { HMUTEX;
+	/// Handle to a
+	/// [mutex](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createmutexw).
+	/// Originally just a `HANDLE`.
+}
+
+impl kernel_Hmutex for HMUTEX {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HMUTEX`](crate::HMUTEX).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hmutex: Handle {
+	/// [`CreateMutex`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createmutexw)
+	/// static method.
+	///
+	/// If a named mutex already exists, it will be opened, and
+	/// [`GetLastError`](crate::GetLastError) will return
+	/// [`ERROR::ALREADY_EXISTS`](crate::co::ERROR::ALREADY_EXISTS).
+	#[must_use]
+	fn CreateMutex(
+		security_attributes: Option<&mut SECURITY_ATTRIBUTES>,
+		initial_owner: bool,
+		name: Option<&str>,
+	) -> SysResult<CloseHandleGuard<HMUTEX>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::CreateMutexW(
+					security_attributes.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+					initial_owner as _,
+					WString::from_opt_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`OpenMutex`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-openmutexw)
+	/// static method.
+	#[must_use]
+	fn OpenMutex(
+		desired_access: co::MUTEX,
+		inherit_handle: bool,
+		name: &str,
+	) -> SysResult<CloseHandleGuard<HMUTEX>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::OpenMutexW(
+					desired_access.0,
+					inherit_handle as _,
+					WString::from_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`ReleaseMutex`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-releasemutex)
+	/// method.
+	fn ReleaseMutex(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { kernel::ffi::ReleaseMutex(self.as_ptr()) })
+	}
+
+	/// [`WaitForSingleObject`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-waitforsingleobject)
+	/// method.
+	///
+	/// If the mutex is acquired, it must be released with
+	/// [`ReleaseMutex`](crate::prelude::kernel_Hmutex::ReleaseMutex).
+	fn WaitForSingleObject(&self,
+		milliseconds: Option<u32>) -> SysResult<co::WAIT>
+	{
+		match unsafe {
+			co::WAIT(
+				kernel::ffi::WaitForSingleObject(
+					self.as_ptr(),
+					milliseconds.unwrap_or(INFINITE),
+				),
+			)
+		} {
+			co::WAIT::FAILED => Err(GetLastError()),
+			wait => Ok(wait),
+		}
+	}
+}
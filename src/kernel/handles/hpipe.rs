@@ -1,15 +1,22 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
-use crate::kernel;
-use crate::kernel::decl::{HFILE, OVERLAPPED, SECURITY_ATTRIBUTES, SysResult};
+use crate::{co, kernel};
+use crate::kernel::decl::{
+	GetLastError, HFILE, OVERLAPPED, SECURITY_ATTRIBUTES, SysResult, WString,
+};
 use crate::kernel::guard::CloseHandleGuard;
 use crate::kernel::privs::bool_to_sysresult;
 use crate::prelude::{Handle, kernel_Hfile};
 
 impl_handle! { HPIPE;
 	/// Handle to an
-	/// [anonymous pipe](https://learn.microsoft.com/en-us/windows/win32/ipc/anonymous-pipes).
+	/// [anonymous pipe](https://learn.microsoft.com/en-us/windows/win32/ipc/anonymous-pipes)
+	/// or a [named pipe](https://learn.microsoft.com/en-us/windows/win32/ipc/named-pipes).
 	/// Originally just a `HANDLE`.
+	///
+	/// Unless you need something specific, consider using the
+	/// [`PipeServer`](crate::PipeServer) and [`PipeClient`](crate::PipeClient)
+	/// high-level abstractions.
 }
 
 impl kernel_Hpipe for HPIPE {}
@@ -23,6 +30,63 @@ impl kernel_Hpipe for HPIPE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hpipe: Handle {
+	/// [`ConnectNamedPipe`](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-connectnamedpipe)
+	/// method.
+	///
+	/// If a client connected between
+	/// [`CreateNamedPipe`](crate::prelude::kernel_Hpipe::CreateNamedPipe) and
+	/// this call, succeeds as well.
+	fn ConnectNamedPipe(&self, overlapped: Option<&mut OVERLAPPED>) -> SysResult<()> {
+		match bool_to_sysresult(
+			unsafe {
+				kernel::ffi::ConnectNamedPipe(
+					self.as_ptr(),
+					overlapped.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+				)
+			},
+		) {
+			Err(co::ERROR::PIPE_CONNECTED) => Ok(()),
+			res => res,
+		}
+	}
+
+	/// [`CreateNamedPipe`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-createnamedpipew)
+	/// static method.
+	///
+	/// If `max_instances` is `None`, the number of instances is limited only
+	/// by system resources. If `default_timeout` is `None`, defaults to 50
+	/// milliseconds.
+	#[must_use]
+	fn CreateNamedPipe(
+		name: &str,
+		open_mode: co::PIPE_ACCESS,
+		pipe_mode: co::PIPE,
+		max_instances: Option<u32>,
+		out_buffer_size: u32,
+		in_buffer_size: u32,
+		default_timeout: Option<u32>,
+		security_attrs: Option<&mut SECURITY_ATTRIBUTES>,
+	) -> SysResult<CloseHandleGuard<HPIPE>>
+	{
+		unsafe {
+			match HPIPE(
+				kernel::ffi::CreateNamedPipeW(
+					WString::from_str(name).as_ptr(),
+					open_mode.0,
+					pipe_mode.0,
+					max_instances.unwrap_or(255), // PIPE_UNLIMITED_INSTANCES
+					out_buffer_size,
+					in_buffer_size,
+					default_timeout.unwrap_or_default(),
+					security_attrs.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
+				) as _,
+			) {
+				HPIPE::INVALID => Err(GetLastError()),
+				handle => Ok(CloseHandleGuard::new(handle)),
+			}
+		}
+	}
+
 	/// [`CreatePipe`](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-createpipe)
 	/// static method.
 	///
@@ -46,6 +110,14 @@ pub trait kernel_Hpipe: Handle {
 		}
 	}
 
+	/// [`DisconnectNamedPipe`](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-disconnectnamedpipe)
+	/// method.
+	fn DisconnectNamedPipe(&self) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe { kernel::ffi::DisconnectNamedPipe(self.as_ptr()) },
+		)
+	}
+
 	/// [`ReadFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-readfile)
 	/// method.
 	///
@@ -57,6 +129,24 @@ pub trait kernel_Hpipe: Handle {
 			.ReadFile(buffer, overlapped)
 	}
 
+	/// [`SetNamedPipeHandleState`](https://learn.microsoft.com/en-us/windows/win32/api/namedpipeapi/nf-namedpipeapi-setnamedpipehandlestate)
+	/// method.
+	///
+	/// Only the read and wait modes of `mode` are meaningful.
+	fn SetNamedPipeHandleState(&self, mode: co::PIPE) -> SysResult<()> {
+		let mut mode = mode.0;
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::SetNamedPipeHandleState(
+					self.as_ptr(),
+					&mut mode,
+					std::ptr::null_mut(),
+					std::ptr::null_mut(),
+				)
+			},
+		)
+	}
+
 	/// [`WriteFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-writefile)
 	/// method.
 	fn WriteFile(&self,
@@ -3,7 +3,8 @@
 use crate::{co, kernel};
 use crate::kernel::decl::{
 	FILETIME, GetLastError, HACCESSTOKEN, PROCESS_INFORMATION,
-	SECURITY_ATTRIBUTES, STARTUPINFO, SysResult, WString,
+	ProcessMitigationPolicy, SECURITY_ATTRIBUTES, STARTUPINFO, SysResult,
+	WString,
 };
 use crate::kernel::ffi_types::BOOL;
 use crate::kernel::guard::{CloseHandleGuard, CloseHandlePiGuard};
@@ -136,6 +137,23 @@ pub trait kernel_Hprocess: Handle {
 		}
 	}
 
+	/// [`GetProcessDEPPolicy`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getprocessdeppolicy)
+	/// method.
+	///
+	/// Returns the DEP flags, and whether DEP is permanently enabled or
+	/// disabled for the process.
+	#[must_use]
+	fn GetProcessDEPPolicy(&self) -> SysResult<(co::PROCESS_DEP, bool)> {
+		let mut flags = co::PROCESS_DEP::default();
+		let mut permanent: BOOL = 0;
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::GetProcessDEPPolicy(
+					self.as_ptr(), &mut flags.0, &mut permanent)
+			},
+		).map(|_| (flags, permanent != 0))
+	}
+
 	/// [`GetProcessHandleCount`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getprocesshandlecount)
 	/// method.
 	#[must_use]
@@ -158,6 +176,45 @@ pub trait kernel_Hprocess: Handle {
 		}
 	}
 
+	/// [`GetProcessMitigationPolicy`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getprocessmitigationpolicy)
+	/// method.
+	///
+	/// Returns [`ERROR::NOT_SUPPORTED`](crate::co::ERROR::NOT_SUPPORTED) if
+	/// the policy has no corresponding
+	/// [`ProcessMitigationPolicy`](crate::ProcessMitigationPolicy) variant,
+	/// and [`ERROR::CALL_NOT_IMPLEMENTED`](crate::co::ERROR::CALL_NOT_IMPLEMENTED)
+	/// on systems older than Windows 8.
+	///
+	/// # Examples
+	///
+	/// Checking whether ASLR is enabled for the current process:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HPROCESS, ProcessMitigationPolicy};
+	///
+	/// let policy = HPROCESS::GetCurrentProcess()
+	///     .GetProcessMitigationPolicy(co::PROCESS_MITIGATION_POLICY::ASLR)?;
+	///
+	/// if let ProcessMitigationPolicy::Aslr(aslr) = policy {
+	///     println!("Bottom-up: {}, high entropy: {}",
+	///         aslr.EnableBottomUpRandomization(), aslr.EnableHighEntropy());
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn GetProcessMitigationPolicy(&self,
+		policy: co::PROCESS_MITIGATION_POLICY,
+	) -> SysResult<ProcessMitigationPolicy>
+	{
+		let mut data = ProcessMitigationPolicy::from_policy(policy)
+			.ok_or(co::ERROR::NOT_SUPPORTED)?;
+		let func = kernel::ffi::GetProcessMitigationPolicy()?;
+		let (ptr, sz) = data.as_mut_ptr_size();
+		bool_to_sysresult(unsafe { func(self.as_ptr(), policy.0, ptr, sz) })
+			.map(|_| data)
+	}
+
 	/// [`GetProcessTimes`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getprocesstimes)
 	/// method.
 	fn GetProcessTimes(&self,
@@ -105,6 +105,21 @@ pub trait kernel_Hstd: Handle {
 		)
 	}
 
+	/// [`SetStdHandle`](https://learn.microsoft.com/en-us/windows/console/setstdhandle)
+	/// static method.
+	///
+	/// The handle is not duplicated, so it must outlive its use as a standard
+	/// handle.
+	fn SetStdHandle(
+		std_handle: co::STD_HANDLE,
+		handle: &impl Handle,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe { kernel::ffi::SetStdHandle(std_handle.0, handle.as_ptr()) },
+		)
+	}
+
 	/// [`WriteConsole`](https://learn.microsoft.com/en-us/windows/console/writeconsole)
 	/// method.
 	/// 
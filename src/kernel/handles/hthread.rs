@@ -28,6 +28,15 @@ impl kernel_Hthread for HTHREAD {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hthread: Handle {
+	/// [`CancelSynchronousIo`](https://learn.microsoft.com/en-us/windows/win32/fileio/cancelsynchronousio-func)
+	/// method.
+	///
+	/// The handle must have the
+	/// [`co::THREAD::TERMINATE`](crate::co::THREAD::TERMINATE) access right.
+	fn CancelSynchronousIo(&self) -> SysResult<()> {
+		bool_to_sysresult(unsafe { kernel::ffi::CancelSynchronousIo(self.as_ptr()) })
+	}
+
 	/// [`CreateThread`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-createthread)
 	/// static method.
 	///
@@ -117,6 +126,26 @@ pub trait kernel_Hthread: Handle {
 		)
 	}
 
+	/// [`OpenThread`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-openthread)
+	/// static method.
+	#[must_use]
+	fn OpenThread(
+		desired_access: co::THREAD,
+		inherit_handle: bool,
+		thread_id: u32,
+	) -> SysResult<CloseHandleGuard<HTHREAD>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				kernel::ffi::OpenThread(
+					desired_access.0,
+					inherit_handle as _,
+					thread_id,
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
 	/// [`OpenThreadToken`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-openthreadtoken)
 	/// method.
 	#[must_use]
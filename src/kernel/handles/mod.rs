@@ -1,5 +1,6 @@
 mod haccesstoken;
 mod handle_traits;
+mod hevent;
 mod hfile;
 mod hfilemap;
 mod hfilemapview;
@@ -9,6 +10,7 @@ mod hheapobj;
 mod hinstance;
 mod hkey;
 mod hlocal;
+mod hmutex;
 mod hpipe;
 mod hprocess;
 mod hprocesslist;
@@ -19,6 +21,7 @@ mod hupdatesrc;
 
 pub mod decl {
 	pub use super::haccesstoken::HACCESSTOKEN;
+	pub use super::hevent::HEVENT;
 	pub use super::hfile::HFILE;
 	pub use super::hfilemap::HFILEMAP;
 	pub use super::hfilemapview::HFILEMAPVIEW;
@@ -28,6 +31,7 @@ pub mod decl {
 	pub use super::hinstance::HINSTANCE;
 	pub use super::hkey::HKEY;
 	pub use super::hlocal::HLOCAL;
+	pub use super::hmutex::HMUTEX;
 	pub use super::hpipe::HPIPE;
 	pub use super::hprocess::HPROCESS;
 	pub use super::hprocesslist::HPROCESSLIST;
@@ -36,12 +40,6 @@ pub mod decl {
 	pub use super::htransaction::HTRANSACTION;
 	pub use super::hupdatesrc::HUPDATERSRC;
 
-	impl_handle! { HEVENT;
-		/// Handle to an
-		/// [event](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-createeventw).
-		/// Originally just a `HANDLE`.
-	}
-
 	impl_handle! { HHEAPMEM;
 		/// Handle to a
 		/// [heap memory block](https://learn.microsoft.com/en-us/windows/win32/api/heapapi/nf-heapapi-heapalloc).
@@ -72,6 +70,7 @@ pub mod decl {
 pub mod traits {
 	pub use super::haccesstoken::kernel_Haccesstoken;
 	pub use super::handle_traits::*;
+	pub use super::hevent::kernel_Hevent;
 	pub use super::hfile::kernel_Hfile;
 	pub use super::hfilemap::kernel_Hfilemap;
 	pub use super::hfilemapview::kernel_Hfilemapview;
@@ -81,6 +80,7 @@ pub mod traits {
 	pub use super::hinstance::kernel_Hinstance;
 	pub use super::hkey::kernel_Hkey;
 	pub use super::hlocal::kernel_Hlocal;
+	pub use super::hmutex::kernel_Hmutex;
 	pub use super::hpipe::kernel_Hpipe;
 	pub use super::hprocess::kernel_Hprocess;
 	pub use super::hprocesslist::kernel_Hprocesslist;
@@ -1,6 +1,7 @@
 #![cfg_attr(docsrs, doc(cfg(feature = "kernel")))]
 
 pub(in crate::kernel) mod ffi;
+#[cfg(all(test, not(windows)))] mod ffi_fakes;
 pub(crate) mod ffi_types;
 pub(crate) mod privs;
 pub mod co;
@@ -26,4 +27,5 @@ pub mod decl {
 pub mod traits {
 	pub use super::co_traits::*;
 	pub use super::handles::traits::*;
+	pub use super::utilities::traits::*;
 }
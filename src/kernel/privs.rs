@@ -1,6 +1,9 @@
 #![allow(dead_code, non_snake_case)]
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use crate::co::ERROR;
+use crate::kernel;
 use crate::kernel::decl::{GetLastError, SysResult, WString};
 use crate::kernel::ffi_types::{BOOL, HANDLE};
 use crate::prelude::Handle;
@@ -78,6 +81,43 @@ pub(crate) fn minus1_as_error(dword: u32) -> SysResult<u32> {
 	}
 }
 
+/// Resolves the address of a function exported by a system DLL, loading the
+/// DLL if needed, which is never freed. The result is cached, so the lookup is
+/// made only once.
+///
+/// Used by the bindings built with `extern_sys_delay!`.
+pub(crate) fn delay_load(
+	cache: &AtomicUsize, dll: &str, func: &str) -> SysResult<usize>
+{
+	const UNAVAILABLE: usize = usize::MAX;
+
+	let addr = match cache.load(Ordering::Relaxed) {
+		0 => { // not resolved yet
+			let dll = WString::from_str(dll);
+			let addr = unsafe {
+				let mut hmod = kernel::ffi::GetModuleHandleW(dll.as_ptr());
+				if hmod.is_null() {
+					hmod = kernel::ffi::LoadLibraryW(dll.as_ptr());
+				}
+				if hmod.is_null() {
+					std::ptr::null()
+				} else {
+					kernel::ffi::GetProcAddress(hmod, str_to_iso88591(func).as_ptr())
+				}
+			};
+			let addr = if addr.is_null() { UNAVAILABLE } else { addr as usize };
+			cache.store(addr, Ordering::Relaxed);
+			addr
+		},
+		addr => addr,
+	};
+
+	match addr {
+		UNAVAILABLE => Err(ERROR::CALL_NOT_IMPLEMENTED),
+		addr => Ok(addr),
+	}
+}
+
 /// Converts a string to an ISO-8859-1 null-terminated byte array.
 pub(crate) fn str_to_iso88591(s: &str) -> Vec<u8> {
 	s.chars().map(|ch| ch as u8)
@@ -106,3 +146,20 @@ pub(crate) fn parse_multi_z_str(src: *const u16) -> Vec<String> {
 	}
 	strings
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn multi_z_str() {
+		let src = "abc\0de\0\0".encode_utf16().collect::<Vec<_>>();
+		assert_eq!(parse_multi_z_str(src.as_ptr()), ["abc", "de"]);
+		assert!(parse_multi_z_str([0u16, 0].as_ptr()).is_empty());
+	}
+
+	#[test]
+	fn iso88591() {
+		assert_eq!(str_to_iso88591("Ab\u{e9}"), [b'A', b'b', 0xe9, 0]);
+	}
+}
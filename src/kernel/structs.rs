@@ -5,7 +5,7 @@ use std::marker::PhantomData;
 use crate::co;
 use crate::kernel::decl::{
 	ConvertSidToStringSid, HEVENT, HINSTANCE, HPIPE, HPROCESS, HTHREAD,
-	InitializeSecurityDescriptor, MAKEQWORD, WString,
+	InitializeSecurityDescriptor, MAKEQWORD, ProcThreadAttributeList, WString,
 };
 use crate::kernel::privs::{MAX_MODULE_NAME32, MAX_PATH};
 use crate::prelude::NativeBitflag;
@@ -443,6 +443,163 @@ pub struct PROCESS_INFORMATION {
 
 impl_default!(PROCESS_INFORMATION);
 
+/// [`PROCESS_MITIGATION_ASLR_POLICY`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-process_mitigation_aslr_policy)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct PROCESS_MITIGATION_ASLR_POLICY {
+	Flags: u32,
+}
+
+impl PROCESS_MITIGATION_ASLR_POLICY {
+	pub_fn_bitfield_get_set!(Flags, 0, EnableBottomUpRandomization, set_EnableBottomUpRandomization);
+	pub_fn_bitfield_get_set!(Flags, 1, EnableForceRelocateImages, set_EnableForceRelocateImages);
+	pub_fn_bitfield_get_set!(Flags, 2, EnableHighEntropy, set_EnableHighEntropy);
+	pub_fn_bitfield_get_set!(Flags, 3, DisallowStrippedImages, set_DisallowStrippedImages);
+}
+
+/// [`PROCESS_MITIGATION_BINARY_SIGNATURE_POLICY`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-process_mitigation_binary_signature_policy)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct PROCESS_MITIGATION_BINARY_SIGNATURE_POLICY {
+	Flags: u32,
+}
+
+impl PROCESS_MITIGATION_BINARY_SIGNATURE_POLICY {
+	pub_fn_bitfield_get_set!(Flags, 0, MicrosoftSignedOnly, set_MicrosoftSignedOnly);
+	pub_fn_bitfield_get_set!(Flags, 1, StoreSignedOnly, set_StoreSignedOnly);
+	pub_fn_bitfield_get_set!(Flags, 2, MitigationOptIn, set_MitigationOptIn);
+	pub_fn_bitfield_get_set!(Flags, 3, AuditMicrosoftSignedOnly, set_AuditMicrosoftSignedOnly);
+	pub_fn_bitfield_get_set!(Flags, 4, AuditStoreSignedOnly, set_AuditStoreSignedOnly);
+}
+
+/// [`PROCESS_MITIGATION_CHILD_PROCESS_POLICY`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-process_mitigation_child_process_policy)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct PROCESS_MITIGATION_CHILD_PROCESS_POLICY {
+	Flags: u32,
+}
+
+impl PROCESS_MITIGATION_CHILD_PROCESS_POLICY {
+	pub_fn_bitfield_get_set!(Flags, 0, NoChildProcessCreation, set_NoChildProcessCreation);
+	pub_fn_bitfield_get_set!(Flags, 1, AuditNoChildProcessCreation, set_AuditNoChildProcessCreation);
+	pub_fn_bitfield_get_set!(Flags, 2, AllowSecureProcessCreation, set_AllowSecureProcessCreation);
+}
+
+/// [`PROCESS_MITIGATION_CONTROL_FLOW_GUARD_POLICY`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-process_mitigation_control_flow_guard_policy)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct PROCESS_MITIGATION_CONTROL_FLOW_GUARD_POLICY {
+	Flags: u32,
+}
+
+impl PROCESS_MITIGATION_CONTROL_FLOW_GUARD_POLICY {
+	pub_fn_bitfield_get_set!(Flags, 0, EnableControlFlowGuard, set_EnableControlFlowGuard);
+	pub_fn_bitfield_get_set!(Flags, 1, EnableExportSuppression, set_EnableExportSuppression);
+	pub_fn_bitfield_get_set!(Flags, 2, StrictMode, set_StrictMode);
+	pub_fn_bitfield_get_set!(Flags, 3, EnableXfg, set_EnableXfg);
+	pub_fn_bitfield_get_set!(Flags, 4, EnableXfgAuditMode, set_EnableXfgAuditMode);
+}
+
+/// [`PROCESS_MITIGATION_DEP_POLICY`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-process_mitigation_dep_policy)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct PROCESS_MITIGATION_DEP_POLICY {
+	Flags: u32,
+	Permanent: u8,
+}
+
+impl PROCESS_MITIGATION_DEP_POLICY {
+	pub_fn_bitfield_get_set!(Flags, 0, Enable, set_Enable);
+	pub_fn_bitfield_get_set!(Flags, 1, DisableAtlThunkEmulation, set_DisableAtlThunkEmulation);
+	pub_fn_bool_get_set!(Permanent, set_Permanent);
+}
+
+/// [`PROCESS_MITIGATION_DYNAMIC_CODE_POLICY`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-process_mitigation_dynamic_code_policy)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct PROCESS_MITIGATION_DYNAMIC_CODE_POLICY {
+	Flags: u32,
+}
+
+impl PROCESS_MITIGATION_DYNAMIC_CODE_POLICY {
+	pub_fn_bitfield_get_set!(Flags, 0, ProhibitDynamicCode, set_ProhibitDynamicCode);
+	pub_fn_bitfield_get_set!(Flags, 1, AllowThreadOptOut, set_AllowThreadOptOut);
+	pub_fn_bitfield_get_set!(Flags, 2, AllowRemoteDowngrade, set_AllowRemoteDowngrade);
+	pub_fn_bitfield_get_set!(Flags, 3, AuditProhibitDynamicCode, set_AuditProhibitDynamicCode);
+}
+
+/// [`PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-process_mitigation_extension_point_disable_policy)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY {
+	Flags: u32,
+}
+
+impl PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY {
+	pub_fn_bitfield_get_set!(Flags, 0, DisableExtensionPoints, set_DisableExtensionPoints);
+}
+
+/// [`PROCESS_MITIGATION_FONT_DISABLE_POLICY`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-process_mitigation_font_disable_policy)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct PROCESS_MITIGATION_FONT_DISABLE_POLICY {
+	Flags: u32,
+}
+
+impl PROCESS_MITIGATION_FONT_DISABLE_POLICY {
+	pub_fn_bitfield_get_set!(Flags, 0, DisableNonSystemFonts, set_DisableNonSystemFonts);
+	pub_fn_bitfield_get_set!(Flags, 1, AuditNonSystemFontLoading, set_AuditNonSystemFontLoading);
+}
+
+/// [`PROCESS_MITIGATION_IMAGE_LOAD_POLICY`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-process_mitigation_image_load_policy)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct PROCESS_MITIGATION_IMAGE_LOAD_POLICY {
+	Flags: u32,
+}
+
+impl PROCESS_MITIGATION_IMAGE_LOAD_POLICY {
+	pub_fn_bitfield_get_set!(Flags, 0, NoRemoteImages, set_NoRemoteImages);
+	pub_fn_bitfield_get_set!(Flags, 1, NoLowMandatoryLabelImages, set_NoLowMandatoryLabelImages);
+	pub_fn_bitfield_get_set!(Flags, 2, PreferSystem32Images, set_PreferSystem32Images);
+	pub_fn_bitfield_get_set!(Flags, 3, AuditNoRemoteImages, set_AuditNoRemoteImages);
+	pub_fn_bitfield_get_set!(Flags, 4, AuditNoLowMandatoryLabelImages, set_AuditNoLowMandatoryLabelImages);
+}
+
+/// [`PROCESS_MITIGATION_STRICT_HANDLE_CHECK_POLICY`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-process_mitigation_strict_handle_check_policy)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct PROCESS_MITIGATION_STRICT_HANDLE_CHECK_POLICY {
+	Flags: u32,
+}
+
+impl PROCESS_MITIGATION_STRICT_HANDLE_CHECK_POLICY {
+	pub_fn_bitfield_get_set!(Flags, 0, RaiseExceptionOnInvalidHandleReference, set_RaiseExceptionOnInvalidHandleReference);
+	pub_fn_bitfield_get_set!(Flags, 1, HandleExceptionsPermanentlyEnabled, set_HandleExceptionsPermanentlyEnabled);
+}
+
+/// [`PROCESS_MITIGATION_SYSTEM_CALL_DISABLE_POLICY`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-process_mitigation_system_call_disable_policy)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct PROCESS_MITIGATION_SYSTEM_CALL_DISABLE_POLICY {
+	Flags: u32,
+}
+
+impl PROCESS_MITIGATION_SYSTEM_CALL_DISABLE_POLICY {
+	pub_fn_bitfield_get_set!(Flags, 0, DisallowWin32kSystemCalls, set_DisallowWin32kSystemCalls);
+	pub_fn_bitfield_get_set!(Flags, 1, AuditDisallowWin32kSystemCalls, set_AuditDisallowWin32kSystemCalls);
+}
 /// [`PROCESSENTRY32`](https://learn.microsoft.com/en-us/windows/win32/api/tlhelp32/ns-tlhelp32-processentry32w)
 /// struct.
 #[repr(C)]
@@ -493,6 +650,55 @@ impl<'a> SECURITY_ATTRIBUTES<'a> {
 	pub_fn_bool_get_set!(bInheritHandle, set_bInheritHandle);
 }
 
+/// [`SECURITY_CAPABILITIES`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-security_capabilities)
+/// struct.
+#[repr(C)]
+pub struct SECURITY_CAPABILITIES<'a, 'b> {
+	AppContainerSid: *mut SID,
+	Capabilities: *mut SID_AND_ATTRIBUTES<'b>,
+	CapabilityCount: u32,
+	Reserved: u32,
+
+	_AppContainerSid: PhantomData<&'a SID>,
+	_Capabilities: PhantomData<&'b mut SID_AND_ATTRIBUTES<'b>>,
+}
+
+impl_default!(SECURITY_CAPABILITIES, 'a, 'b);
+
+impl<'a, 'b> SECURITY_CAPABILITIES<'a, 'b> {
+	/// Returns the `AppContainerSid` field.
+	#[must_use]
+	pub fn AppContainerSid(&self) -> Option<&SID> {
+		unsafe { self.AppContainerSid.as_ref() }
+	}
+
+	/// Sets the `AppContainerSid` field.
+	pub fn set_AppContainerSid(&mut self, val: Option<&'a SID>) {
+		self.AppContainerSid = val.map_or(std::ptr::null_mut(), |val| val as *const _ as _);
+	}
+
+	/// Returns the `Capabilities` field.
+	#[must_use]
+	pub fn Capabilities(&self) -> &[SID_AND_ATTRIBUTES<'b>] {
+		if self.Capabilities.is_null() {
+			&[]
+		} else {
+			unsafe {
+				std::slice::from_raw_parts(
+					self.Capabilities, self.CapabilityCount as _)
+			}
+		}
+	}
+
+	/// Sets the `Capabilities` field, also setting `CapabilityCount`.
+	pub fn set_Capabilities(&mut self,
+		val: Option<&'b mut [SID_AND_ATTRIBUTES<'b>]>)
+	{
+		self.CapabilityCount = val.as_ref().map_or(0, |val| val.len() as _);
+		self.Capabilities = val.map_or(std::ptr::null_mut(), |val| val.as_mut_ptr());
+	}
+}
+
 /// [`SECURITY_DESCRIPTOR`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-security_descriptor)
 /// struct.
 #[repr(C)]
@@ -556,6 +762,31 @@ impl SID {
 	}
 }
 
+/// [`SID_AND_ATTRIBUTES`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-sid_and_attributes)
+/// struct.
+#[repr(C)]
+pub struct SID_AND_ATTRIBUTES<'a> {
+	Sid: *mut SID,
+	pub Attributes: co::SE_GROUP,
+
+	_Sid: PhantomData<&'a SID>,
+}
+
+impl_default!(SID_AND_ATTRIBUTES, 'a);
+
+impl<'a> SID_AND_ATTRIBUTES<'a> {
+	/// Returns the `Sid` field.
+	#[must_use]
+	pub fn Sid(&self) -> Option<&SID> {
+		unsafe { self.Sid.as_ref() }
+	}
+
+	/// Sets the `Sid` field.
+	pub fn set_Sid(&mut self, val: Option<&'a SID>) {
+		self.Sid = val.map_or(std::ptr::null_mut(), |val| val as *const _ as _);
+	}
+}
+
 /// [`SID_IDENTIFIER_AUTHORITY`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-sid_identifier_authority)
 /// struct.
 #[repr(C)]
@@ -638,6 +869,37 @@ impl<'a, 'b> STARTUPINFO<'a, 'b> {
 	}
 }
 
+/// [`STARTUPINFOEX`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/ns-winbase-startupinfoexw)
+/// struct.
+///
+/// To be used with [`HPROCESS::CreateProcess`](crate::prelude::kernel_Hprocess::CreateProcess),
+/// passing `&mut StartupInfo` along with
+/// [`CREATE::EXTENDED_STARTUPINFO_PRESENT`](crate::co::CREATE::EXTENDED_STARTUPINFO_PRESENT).
+#[repr(C)]
+pub struct STARTUPINFOEX<'a, 'b, 'c> {
+	pub StartupInfo: STARTUPINFO<'a, 'b>,
+	lpAttributeList: *mut std::ffi::c_void,
+
+	_lpAttributeList: PhantomData<&'c mut std::ffi::c_void>,
+}
+
+impl<'a, 'b, 'c> Default for STARTUPINFOEX<'a, 'b, 'c> {
+	fn default() -> Self {
+		let mut obj = unsafe { std::mem::zeroed::<Self>() };
+		obj.StartupInfo.cb = std::mem::size_of::<Self>() as _;
+		obj
+	}
+}
+
+impl<'a, 'b, 'c> STARTUPINFOEX<'a, 'b, 'c> {
+	/// Sets the `lpAttributeList` field.
+	pub fn set_lpAttributeList(&mut self,
+		val: Option<&'c mut ProcThreadAttributeList<'_>>)
+	{
+		self.lpAttributeList = val.map_or(std::ptr::null_mut(), |val| val.as_mut_ptr());
+	}
+}
+
 /// [`SYSTEM_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/ns-sysinfoapi-system_info)
 /// struct.
 #[repr(C)]
@@ -706,6 +968,14 @@ impl TIME_ZONE_INFORMATION {
 	pub_fn_string_arr_get_set!(daylightName, set_daylightName);
 }
 
+/// [`TOKEN_MANDATORY_LABEL`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-token_mandatory_label)
+/// struct.
+#[repr(C)]
+#[derive(Default)]
+pub struct TOKEN_MANDATORY_LABEL<'a> {
+	pub Label: SID_AND_ATTRIBUTES<'a>,
+}
+
 /// [`VALENT`](https://learn.microsoft.com/en-us/windows/win32/api/winreg/ns-winreg-valentw)
 /// struct.
 #[repr(C)]
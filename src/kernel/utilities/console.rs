@@ -0,0 +1,104 @@
+use crate::co;
+use crate::kernel::decl::{
+	AllocConsole, AttachConsole, FreeConsole, HFILE, HSTD, SysResult,
+};
+use crate::kernel::guard::CloseHandleGuard;
+use crate::prelude::{Handle, kernel_Hfile, kernel_Hstd};
+
+/// A console attached to the current process, with the standard input, output
+/// and error handles redirected to it.
+///
+/// Useful to GUI applications – which have no console by default – to display
+/// verbose or debug output. Since the Rust standard library retrieves the
+/// standard handles at each operation, `println!` and `eprintln!` will write
+/// to the console.
+///
+/// When the object goes out of scope, the standard handles are cleared and the
+/// console is freed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::Console;
+///
+/// let _console = Console::attach_parent_or_alloc()?;
+/// println!("Verbose output enabled.");
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub struct Console {
+	hconin: CloseHandleGuard<HFILE>,
+	hconout: CloseHandleGuard<HFILE>,
+	is_new: bool,
+	free_on_drop: bool,
+}
+
+impl Drop for Console {
+	fn drop(&mut self) {
+		if self.free_on_drop {
+			for std_handle in [co::STD_HANDLE::INPUT, co::STD_HANDLE::OUTPUT, co::STD_HANDLE::ERROR] {
+				HSTD::SetStdHandle(std_handle, &HSTD::NULL).ok(); // ignore errors
+			}
+			FreeConsole().ok();
+		}
+	}
+}
+
+impl Console {
+	/// Attaches the process to the console of its parent – so the output
+	/// appears in the terminal the application was launched from – or, if the
+	/// parent has no console, allocates a new one. Then redirects the standard
+	/// handles to it.
+	///
+	/// If the process already has a console, it's used as it is, and it won't
+	/// be freed when the object goes out of scope.
+	#[must_use]
+	pub fn attach_parent_or_alloc() -> SysResult<Self> {
+		let (is_new, free_on_drop) = match AttachConsole(None) {
+			Ok(_) => (false, true),
+			Err(co::ERROR::ACCESS_DENIED) => (false, false), // already has a console
+			Err(_) => {
+				AllocConsole()?;
+				(true, true)
+			},
+		};
+
+		let hconin = Self::open_buffer("CONIN$")?;
+		let hconout = Self::open_buffer("CONOUT$")?;
+		HSTD::SetStdHandle(co::STD_HANDLE::INPUT, &*hconin)?;
+		HSTD::SetStdHandle(co::STD_HANDLE::OUTPUT, &*hconout)?;
+		HSTD::SetStdHandle(co::STD_HANDLE::ERROR, &*hconout)?;
+
+		Ok(Self { hconin, hconout, is_new, free_on_drop })
+	}
+
+	fn open_buffer(name: &str) -> SysResult<CloseHandleGuard<HFILE>> {
+		HFILE::CreateFile(
+			name,
+			co::GENERIC::READ | co::GENERIC::WRITE,
+			co::FILE_SHARE::READ | co::FILE_SHARE::WRITE,
+			None,
+			co::DISPOSITION::OPEN_EXISTING,
+			co::FILE_ATTRIBUTE::NORMAL,
+			None,
+		).map(|(hfile, _)| hfile)
+	}
+
+	/// Returns the handle to the console input buffer, `CONIN$`.
+	#[must_use]
+	pub fn hconin(&self) -> &HFILE {
+		&self.hconin
+	}
+
+	/// Returns the handle to the console screen buffer, `CONOUT$`.
+	#[must_use]
+	pub fn hconout(&self) -> &HFILE {
+		&self.hconout
+	}
+
+	/// Returns `true` if a new console window was allocated, or `false` if an
+	/// existing console is being used.
+	#[must_use]
+	pub const fn is_new(&self) -> bool {
+		self.is_new
+	}
+}
@@ -1,13 +1,25 @@
+mod console;
 mod encoding;
 mod file_mapped;
 mod file;
 mod ini;
+mod named_pipe;
+mod proc_thread_attribute_list;
+mod shared_mem_channel;
 mod w_string;
 
 pub mod path;
 
+pub use console::Console;
 pub use encoding::Encoding;
 pub use file_mapped::FileMapped;
 pub use file::{File, FileAccess};
 pub use ini::{Ini, IniEntry, IniSection};
+pub use named_pipe::{PipeClient, PipeServer};
+pub use proc_thread_attribute_list::ProcThreadAttributeList;
+pub use shared_mem_channel::SharedMemChannel;
 pub use w_string::WString;
+
+pub(in crate::kernel) mod traits {
+	pub use super::shared_mem_channel::Pod;
+}
@@ -0,0 +1,266 @@
+use std::sync::{Arc, mpsc};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::co;
+use crate::kernel::decl::{
+	GetCurrentThreadId, HFILE, HPIPE, HTHREAD, SysResult, WaitNamedPipe,
+};
+use crate::kernel::guard::CloseHandleGuard;
+use crate::prelude::{Handle, kernel_Hfile, kernel_Hpipe, kernel_Hthread};
+
+/// Size of the chunks used to read a message.
+const CHUNK_SZ: usize = 4096;
+
+/// Reads an entire message from a pipe opened in message mode.
+fn read_message(hpipe: &HPIPE) -> SysResult<Vec<u8>> {
+	let mut msg = Vec::<u8>::new();
+	let mut chunk = vec![0u8; CHUNK_SZ];
+	loop {
+		match hpipe.ReadFile(&mut chunk, None) {
+			Ok(num_read) => {
+				msg.extend_from_slice(&chunk[..num_read as usize]);
+				return Ok(msg);
+			},
+			Err(co::ERROR::MORE_DATA) => msg.extend_from_slice(&chunk), // chunk was filled
+			Err(e) => return Err(e),
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A named pipe server, which accepts multiple clients, each one served in its
+/// own thread.
+///
+/// The pipe works in message mode, so each message written by a client is
+/// delivered as a whole frame to the callback. If the callback returns a
+/// frame, it is written back to the client. A client is served until it
+/// disconnects.
+///
+/// The server stops listening when [`stop`](crate::PipeServer::stop) is
+/// called, or when the object goes out of scope. Clients already connected
+/// keep being served until they disconnect.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{PipeClient, PipeServer};
+///
+/// // Elevated service
+/// let server = PipeServer::start("\\\\.\\pipe\\MyService", |frame| {
+///     let mut reply = b"echo: ".to_vec();
+///     reply.extend_from_slice(frame);
+///     Some(reply)
+/// })?;
+///
+/// // GUI
+/// let client = PipeClient::connect("\\\\.\\pipe\\MyService", Some(5000))?;
+/// let reply = client.transact(b"hello")?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub struct PipeServer {
+	name: String,
+	stop: Arc<AtomicBool>,
+	listener: Option<(JoinHandle<()>, CloseHandleGuard<HTHREAD>)>,
+}
+
+impl Drop for PipeServer {
+	fn drop(&mut self) {
+		self.stop();
+	}
+}
+
+impl PipeServer {
+	/// Creates the first instance of the named pipe, then starts listening to
+	/// clients in a new thread.
+	///
+	/// The callback is called from the client threads.
+	///
+	/// Fails with [`ERROR::ACCESS_DENIED`](crate::co::ERROR::ACCESS_DENIED) if
+	/// the pipe name is already in use.
+	#[must_use]
+	pub fn start<F>(name: &str, func: F) -> SysResult<Self>
+		where F: Fn(&[u8]) -> Option<Vec<u8>> + Send + Sync + 'static,
+	{
+		let first_hpipe = Self::create_instance(name, true)?;
+		let stop = Arc::new(AtomicBool::new(false));
+
+		let name2 = name.to_owned();
+		let stop2 = stop.clone();
+		let func = Arc::new(func);
+		let (tx_hthread, rx_hthread) = mpsc::channel();
+		let listener = std::thread::spawn(move || {
+			// The handle is used by stop() to cancel the blocking
+			// ConnectNamedPipe call.
+			let hthread = HTHREAD::OpenThread(
+				co::THREAD::TERMINATE, false, GetCurrentThreadId());
+			let failed = hthread.is_err();
+			tx_hthread.send(hthread).ok();
+			if failed {
+				return;
+			}
+
+			let mut hpipe = first_hpipe;
+			loop {
+				let res = hpipe.ConnectNamedPipe(None); // blocks until a client connects
+				if stop2.load(Ordering::SeqCst) {
+					break;
+				}
+				if res.is_ok() {
+					let func = func.clone();
+					std::thread::spawn(move || {
+						Self::serve_client(&hpipe, &*func);
+						hpipe.DisconnectNamedPipe().ok(); // ignore errors
+					});
+				}
+				hpipe = match Self::create_instance(&name2, false) {
+					Ok(hpipe) => hpipe,
+					Err(_) => break, // cannot accept more clients
+				};
+			}
+		});
+
+		let hthread = rx_hthread.recv().unwrap_or(Err(co::ERROR::INVALID_THREAD_ID));
+		match hthread {
+			Ok(hthread) => Ok(Self {
+				name: name.to_owned(),
+				stop,
+				listener: Some((listener, hthread)),
+			}),
+			Err(e) => {
+				listener.join().ok(); // the thread returns right away
+				Err(e)
+			},
+		}
+	}
+
+	fn create_instance(name: &str, first: bool) -> SysResult<CloseHandleGuard<HPIPE>> {
+		HPIPE::CreateNamedPipe(
+			name,
+			if first {
+				co::PIPE_ACCESS::DUPLEX | co::PIPE_ACCESS::FIRST_PIPE_INSTANCE
+			} else {
+				co::PIPE_ACCESS::DUPLEX
+			},
+			co::PIPE::TYPE_MESSAGE | co::PIPE::READMODE_MESSAGE | co::PIPE::WAIT
+				| co::PIPE::REJECT_REMOTE_CLIENTS,
+			None,
+			CHUNK_SZ as _,
+			CHUNK_SZ as _,
+			None,
+			None,
+		)
+	}
+
+	fn serve_client<F>(hpipe: &HPIPE, func: &F)
+		where F: Fn(&[u8]) -> Option<Vec<u8>>,
+	{
+		while let Ok(frame) = read_message(hpipe) { // until the client disconnects
+			if let Some(reply) = func(&frame) {
+				if hpipe.WriteFile(&reply, None).is_err() {
+					break;
+				}
+			}
+		}
+	}
+
+	/// Returns the name of the pipe.
+	#[must_use]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Stops listening to new clients, blocking until the listening thread
+	/// finishes. Calling this method more than once has no effect.
+	pub fn stop(&mut self) {
+		if let Some((listener, hthread)) = self.listener.take() {
+			self.stop.store(true, Ordering::SeqCst);
+
+			// The thread may be about to call ConnectNamedPipe, in which case
+			// there's nothing to cancel yet, so we keep trying until it
+			// finishes.
+			while !listener.is_finished() {
+				hthread.CancelSynchronousIo().ok(); // fails if no I/O is pending
+				std::thread::sleep(Duration::from_millis(10));
+			}
+			listener.join().ok();
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A client connection to a [`PipeServer`](crate::PipeServer), or to any
+/// other named pipe working in message mode. The pipe handle is closed
+/// automatically when the object goes out of scope.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::PipeClient;
+///
+/// let client = PipeClient::connect("\\\\.\\pipe\\MyService", None)?;
+/// client.send(b"shutdown")?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub struct PipeClient {
+	hpipe: CloseHandleGuard<HPIPE>,
+}
+
+impl PipeClient {
+	/// Connects to the named pipe, setting it to message read mode.
+	///
+	/// If all pipe instances are busy, waits for the given time-out with
+	/// [`WaitNamedPipe`](crate::WaitNamedPipe); if `milliseconds` is `None`,
+	/// waits for the default time-out of the pipe.
+	#[must_use]
+	pub fn connect(name: &str, milliseconds: Option<u32>) -> SysResult<Self> {
+		loop {
+			match HFILE::CreateFile(
+				name,
+				co::GENERIC::READ | co::GENERIC::WRITE,
+				co::FILE_SHARE::NoValue,
+				None,
+				co::DISPOSITION::OPEN_EXISTING,
+				co::FILE_ATTRIBUTE::NORMAL,
+				None,
+			) {
+				Ok((mut hfile, _)) => {
+					let hpipe = unsafe {
+						CloseHandleGuard::new(HPIPE::from_ptr(hfile.leak().as_ptr()))
+					};
+					hpipe.SetNamedPipeHandleState(co::PIPE::READMODE_MESSAGE)?;
+					return Ok(Self { hpipe });
+				},
+				Err(co::ERROR::PIPE_BUSY) => WaitNamedPipe(name, milliseconds)?,
+				Err(e) => return Err(e),
+			}
+		}
+	}
+
+	/// Returns the underlying pipe handle.
+	#[must_use]
+	pub fn hpipe(&self) -> &HPIPE {
+		&self.hpipe
+	}
+
+	/// Blocks until a message is received from the server, returning it.
+	pub fn receive(&self) -> SysResult<Vec<u8>> {
+		read_message(&self.hpipe)
+	}
+
+	/// Sends a message to the server.
+	pub fn send(&self, frame: &[u8]) -> SysResult<()> {
+		self.hpipe.WriteFile(frame, None).map(|_| ())
+	}
+
+	/// Sends a message to the server, then blocks until its reply is received.
+	pub fn transact(&self, frame: &[u8]) -> SysResult<Vec<u8>> {
+		self.send(frame)?;
+		self.receive()
+	}
+}
@@ -0,0 +1,196 @@
+use std::marker::PhantomData;
+
+use crate::{co, kernel};
+use crate::kernel::decl::{
+	GetLastError, HPROCESS, SECURITY_CAPABILITIES, SysResult,
+};
+use crate::kernel::privs::bool_to_sysresult;
+use crate::prelude::Handle;
+
+/// Manages a
+/// [`PROC_THREAD_ATTRIBUTE_LIST`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-initializeprocthreadattributelist)
+/// opaque list, which holds the extended attributes of a process being created
+/// with a [`STARTUPINFOEX`](crate::STARTUPINFOEX). The list is deleted
+/// automatically when the object goes out of scope.
+///
+/// Handles passed to the list are borrowed, so they must outlive it.
+///
+/// # Examples
+///
+/// Creating a process whose parent is another process, with some mitigation
+/// policies:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, HPROCESS, ProcThreadAttributeList, STARTUPINFOEX};
+///
+/// let hparent = HPROCESS::OpenProcess(
+///     co::PROCESS::CREATE_PROCESS, false, 1234)?;
+///
+/// let mut attrs = ProcThreadAttributeList::new(2)?;
+/// attrs.parent_process(&hparent)?;
+/// attrs.mitigation_policy(
+///     co::PROCESS_CREATION_MITIGATION_POLICY::DEP_ENABLE
+///     | co::PROCESS_CREATION_MITIGATION_POLICY::BOTTOM_UP_ASLR_ALWAYS_ON,
+/// )?;
+///
+/// let mut si = STARTUPINFOEX::default();
+/// si.set_lpAttributeList(Some(&mut attrs));
+///
+/// let pi = HPROCESS::CreateProcess(
+///     None, Some("C:\\Windows\\notepad.exe"), None, None, false,
+///     co::CREATE::EXTENDED_STARTUPINFO_PRESENT, None, None,
+///     &mut si.StartupInfo,
+/// )?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct ProcThreadAttributeList<'a> {
+	buf: Vec<usize>, // usize to keep the opaque list aligned
+	mitigation_policy: Box<u64>,
+	child_process_policy: Box<u32>,
+	_handles: PhantomData<&'a HPROCESS>,
+}
+
+impl<'a> Drop for ProcThreadAttributeList<'a> {
+	fn drop(&mut self) {
+		unsafe { kernel::ffi::DeleteProcThreadAttributeList(self.as_mut_ptr()); }
+	}
+}
+
+impl<'a> ProcThreadAttributeList<'a> {
+	/// Creates a new list, with room for the given number of attributes, by
+	/// calling
+	/// [`InitializeProcThreadAttributeList`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-initializeprocthreadattributelist).
+	#[must_use]
+	pub fn new(attribute_count: u32) -> SysResult<Self> {
+		let mut sz = usize::default();
+		unsafe { // first call retrieves the required size, and always fails
+			kernel::ffi::InitializeProcThreadAttributeList(
+				std::ptr::null_mut(), attribute_count, 0, &mut sz);
+		}
+		match GetLastError() {
+			co::ERROR::INSUFFICIENT_BUFFER => {},
+			err => return Err(err),
+		}
+
+		let mut buf = vec![0usize; sz.div_ceil(std::mem::size_of::<usize>())];
+		bool_to_sysresult(
+			unsafe {
+				kernel::ffi::InitializeProcThreadAttributeList(
+					buf.as_mut_ptr() as _, attribute_count, 0, &mut sz)
+			},
+		).map(|_| Self {
+			buf,
+			mitigation_policy: Box::new(0),
+			child_process_policy: Box::new(0),
+			_handles: PhantomData,
+		})
+	}
+
+	/// Returns a pointer to the underlying opaque list.
+	#[must_use]
+	pub(crate) fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+		self.buf.as_mut_ptr() as _
+	}
+
+	/// Sets the
+	/// [`PROC_THREAD_ATTRIBUTE::CHILD_PROCESS_POLICY`](crate::co::PROC_THREAD_ATTRIBUTE::CHILD_PROCESS_POLICY)
+	/// attribute.
+	pub fn child_process_policy(&mut self,
+		flags: co::PROCESS_CREATION_CHILD_PROCESS) -> SysResult<()>
+	{
+		*self.child_process_policy = flags.0;
+		let ptr = &mut *self.child_process_policy as *mut u32;
+		unsafe {
+			self.update(co::PROC_THREAD_ATTRIBUTE::CHILD_PROCESS_POLICY,
+				ptr as _, std::mem::size_of::<u32>())
+		}
+	}
+
+	/// Sets the
+	/// [`PROC_THREAD_ATTRIBUTE::HANDLE_LIST`](crate::co::PROC_THREAD_ATTRIBUTE::HANDLE_LIST)
+	/// attribute, which restricts the handles inherited by the new process.
+	///
+	/// The handles must be inheritable, and
+	/// [`HPROCESS::CreateProcess`](crate::prelude::kernel_Hprocess::CreateProcess)
+	/// must be called with `inherit_handles` set to `true`.
+	pub fn handle_list<H>(&mut self, handles: &'a [H]) -> SysResult<()>
+		where H: Handle,
+	{
+		unsafe {
+			self.update(co::PROC_THREAD_ATTRIBUTE::HANDLE_LIST,
+				handles.as_ptr() as _, std::mem::size_of_val(handles))
+		}
+	}
+
+	/// Sets the
+	/// [`PROC_THREAD_ATTRIBUTE::MITIGATION_POLICY`](crate::co::PROC_THREAD_ATTRIBUTE::MITIGATION_POLICY)
+	/// attribute.
+	pub fn mitigation_policy(&mut self,
+		flags: co::PROCESS_CREATION_MITIGATION_POLICY) -> SysResult<()>
+	{
+		*self.mitigation_policy = flags.0;
+		let ptr = &mut *self.mitigation_policy as *mut u64;
+		unsafe {
+			self.update(co::PROC_THREAD_ATTRIBUTE::MITIGATION_POLICY,
+				ptr as _, std::mem::size_of::<u64>())
+		}
+	}
+
+	/// Sets the
+	/// [`PROC_THREAD_ATTRIBUTE::PARENT_PROCESS`](crate::co::PROC_THREAD_ATTRIBUTE::PARENT_PROCESS)
+	/// attribute, so the new process will inherit from the given process
+	/// instead of the calling one.
+	///
+	/// The process handle must have the
+	/// [`PROCESS::CREATE_PROCESS`](crate::co::PROCESS::CREATE_PROCESS) access
+	/// right.
+	pub fn parent_process(&mut self, hprocess: &'a HPROCESS) -> SysResult<()> {
+		unsafe {
+			self.update(co::PROC_THREAD_ATTRIBUTE::PARENT_PROCESS,
+				hprocess as *const _ as _, std::mem::size_of::<HPROCESS>())
+		}
+	}
+
+	/// Sets the
+	/// [`PROC_THREAD_ATTRIBUTE::SECURITY_CAPABILITIES`](crate::co::PROC_THREAD_ATTRIBUTE::SECURITY_CAPABILITIES)
+	/// attribute, so the new process will run inside an AppContainer.
+	pub fn security_capabilities(&mut self,
+		caps: &'a SECURITY_CAPABILITIES) -> SysResult<()>
+	{
+		unsafe {
+			self.update(co::PROC_THREAD_ATTRIBUTE::SECURITY_CAPABILITIES,
+				caps as *const _ as _, std::mem::size_of::<SECURITY_CAPABILITIES>())
+		}
+	}
+
+	/// [`UpdateProcThreadAttribute`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-updateprocthreadattribute)
+	/// function.
+	///
+	/// Prefer the specific methods, like
+	/// [`parent_process`](crate::ProcThreadAttributeList::parent_process),
+	/// which take care of the value types.
+	///
+	/// # Safety
+	///
+	/// The value must match the attribute, and it must remain valid until the
+	/// list is deleted.
+	pub unsafe fn update(&mut self,
+		attribute: co::PROC_THREAD_ATTRIBUTE,
+		value: *mut std::ffi::c_void,
+		size: usize,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			kernel::ffi::UpdateProcThreadAttribute(
+				self.as_mut_ptr(),
+				0,
+				attribute.0,
+				value,
+				size,
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+			),
+		)
+	}
+}
@@ -0,0 +1,276 @@
+use crate::co;
+use crate::kernel::decl::{
+	GetLastError, HEVENT, HFILE, HFILEMAP, HMUTEX, SysResult,
+};
+use crate::kernel::guard::{CloseHandleGuard, UnmapViewOfFileGuard};
+use crate::prelude::{
+	Handle, kernel_Hevent, kernel_Hfile, kernel_Hfilemap, kernel_Hfilemapview,
+	kernel_Hmutex,
+};
+
+/// Marker for plain data types, which can be safely copied byte by byte to
+/// and from a [`SharedMemChannel`](crate::SharedMemChannel).
+///
+/// # Safety
+///
+/// The type must be `#[repr(C)]` (or a primitive), without pointers or
+/// references, and any bit pattern must be a valid value – so `bool`, `char`
+/// and most enums are not allowed.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+	($($t:ty)*) => { $( unsafe impl Pod for $t {} )* };
+}
+
+impl_pod!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+/// Header at the beginning of the shared memory block.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Header {
+	magic: u32,
+	version: u32,
+	capacity: u32,
+	len: u32,
+	seq: u64,
+}
+
+const MAGIC: u32 = 0x4d53_5357; // "WSSM"
+const VERSION: u32 = 1;
+
+/// Releases the mutex of a [`SharedMemChannel`](crate::SharedMemChannel)
+/// when dropped.
+struct ReleaseMutexGuard<'a>(&'a HMUTEX);
+
+impl Drop for ReleaseMutexGuard<'_> {
+	fn drop(&mut self) {
+		let _ = self.0.ReleaseMutex();
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A named block of shared memory, which carries [`Pod`](crate::prelude::Pod)
+/// values between processes.
+///
+/// The block is backed by the paging file, and it's guarded by a named mutex;
+/// a named auto-reset event is signaled at each write. The named objects are:
+///
+/// * `name` – the file mapping;
+/// * `name` + `_mutex` – the mutex;
+/// * `name` + `_event` – the event.
+///
+/// The block starts with a versioned header, so a process opening the channel
+/// will fail if the other side was built with an incompatible layout.
+///
+/// Each write replaces the previous value, thus the channel is intended to a
+/// single writer and a single reader.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::SharedMemChannel;
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy)]
+/// struct Progress { done: u32, total: u32 }
+///
+/// unsafe impl Pod for Progress {}
+///
+/// // Process A
+/// let chan = SharedMemChannel::create("Local\\MyAppProgress", 64)?;
+/// chan.write(&Progress { done: 3, total: 10 })?;
+///
+/// // Process B
+/// let chan = SharedMemChannel::open("Local\\MyAppProgress")?;
+/// if chan.wait(Some(5000))? {
+///     if let Some(p) = chan.read::<Progress>()? {
+///         println!("{}/{}", p.done, p.total);
+///     }
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub struct SharedMemChannel {
+	hmap: CloseHandleGuard<HFILEMAP>,
+	hview: UnmapViewOfFileGuard,
+	hmutex: CloseHandleGuard<HMUTEX>,
+	hevent: CloseHandleGuard<HEVENT>,
+	capacity: usize,
+}
+
+impl SharedMemChannel {
+	/// Creates the named objects of a new channel, which can hold values up to
+	/// `capacity` bytes.
+	///
+	/// Fails with [`ERROR::ALREADY_EXISTS`](crate::co::ERROR::ALREADY_EXISTS)
+	/// if a mapping with the same name exists.
+	#[must_use]
+	pub fn create(name: &str, capacity: u32) -> SysResult<Self> {
+		let hmap = HFILE::INVALID.CreateFileMapping(
+			None,
+			co::PAGE::READWRITE,
+			Some(std::mem::size_of::<Header>() as u64 + capacity as u64),
+			Some(name),
+		)?;
+		if GetLastError() == co::ERROR::ALREADY_EXISTS {
+			return Err(co::ERROR::ALREADY_EXISTS);
+		}
+
+		let hview = hmap.MapViewOfFile(co::FILE_MAP::READ | co::FILE_MAP::WRITE, 0, None)?;
+		let hevent = HEVENT::CreateEvent(None, false, false, Some(&Self::event_name(name)))?;
+
+		// The mutex is created owned, so a concurrent open() can't read the
+		// header before it's written.
+		let hmutex = HMUTEX::CreateMutex(None, true, Some(&Self::mutex_name(name)))?;
+		if GetLastError() == co::ERROR::ALREADY_EXISTS { // ownership not granted
+			hmutex.WaitForSingleObject(None)?;
+		}
+
+		let new_self = Self {
+			hmap,
+			hview,
+			hmutex,
+			hevent,
+			capacity: capacity as _,
+		};
+		{
+			let _release = ReleaseMutexGuard(&new_self.hmutex);
+			new_self.access(|header, _| {
+				*header = Header { magic: MAGIC, version: VERSION, capacity, len: 0, seq: 0 };
+			});
+		}
+		Ok(new_self)
+	}
+
+	/// Opens the named objects of an existing channel, created by another
+	/// process with [`create`](crate::SharedMemChannel::create).
+	///
+	/// Fails with [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the mapping is not a channel, or if it's smaller than the capacity
+	/// written in its header; or with
+	/// [`ERROR::REVISION_MISMATCH`](crate::co::ERROR::REVISION_MISMATCH) if the
+	/// channel has an incompatible version.
+	#[must_use]
+	pub fn open(name: &str) -> SysResult<Self> {
+		let hmap = HFILEMAP::OpenFileMapping(
+			co::FILE_MAP::READ | co::FILE_MAP::WRITE, false, name)?;
+		let hview = hmap.MapViewOfFile( // fails if the mapping is smaller than the header
+			co::FILE_MAP::READ | co::FILE_MAP::WRITE, 0, Some(std::mem::size_of::<Header>()))
+			.map_err(|_| co::ERROR::INVALID_DATA)?;
+		let hmutex = HMUTEX::OpenMutex(
+			co::MUTEX::SYNCHRONIZE | co::MUTEX::MODIFY_STATE, false, &Self::mutex_name(name))?;
+		let hevent = HEVENT::OpenEvent(
+			co::EVENT::SYNCHRONIZE | co::EVENT::MODIFY_STATE, false, &Self::event_name(name))?;
+
+		let mut new_self = Self {
+			hmap,
+			hview,
+			hmutex,
+			hevent,
+			capacity: 0,
+		};
+		let header = new_self.lock(|header, _| *header)?;
+		if header.magic != MAGIC {
+			return Err(co::ERROR::INVALID_DATA);
+		} else if header.version != VERSION {
+			return Err(co::ERROR::REVISION_MISMATCH);
+		}
+
+		// The capacity comes from the other process, so the whole block is
+		// mapped with an explicit size, which fails if the mapping is smaller.
+		let block_sz = std::mem::size_of::<Header>() + header.capacity as usize;
+		new_self.hview = new_self.hmap.MapViewOfFile(
+			co::FILE_MAP::READ | co::FILE_MAP::WRITE, 0, Some(block_sz))
+			.map_err(|_| co::ERROR::INVALID_DATA)?;
+		new_self.capacity = header.capacity as _;
+		Ok(new_self)
+	}
+
+	fn mutex_name(name: &str) -> String {
+		format!("{}_mutex", name)
+	}
+
+	fn event_name(name: &str) -> String {
+		format!("{}_event", name)
+	}
+
+	/// Acquires the mutex and runs the closure over the header and the data.
+	/// The mutex is released even if the closure panics.
+	fn lock<R, F>(&self, func: F) -> SysResult<R>
+		where F: FnOnce(&mut Header, &mut [u8]) -> R,
+	{
+		self.hmutex.WaitForSingleObject(None)?; // an abandoned mutex is acquired too
+		let _release = ReleaseMutexGuard(&self.hmutex);
+		Ok(self.access(func))
+	}
+
+	/// Runs the closure over the header and the data; the mutex must be held.
+	fn access<R, F>(&self, func: F) -> R
+		where F: FnOnce(&mut Header, &mut [u8]) -> R,
+	{
+		let block = self.hview.as_mut_slice(std::mem::size_of::<Header>() + self.capacity);
+		let (header, data) = block.split_at_mut(std::mem::size_of::<Header>());
+		func(unsafe { &mut *(header.as_mut_ptr() as *mut Header) }, data)
+	}
+
+	/// Returns the maximum size of a value, in bytes.
+	#[must_use]
+	pub const fn capacity(&self) -> usize {
+		self.capacity
+	}
+
+	/// Returns the last written value, or `None` if nothing was written yet.
+	///
+	/// Fails with [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the size of the written value is not the size of `T`.
+	pub fn read<T: Pod>(&self) -> SysResult<Option<T>> {
+		self.lock(|header, data| {
+			if header.seq == 0 {
+				Ok(None)
+			} else if header.len as usize != std::mem::size_of::<T>() {
+				Err(co::ERROR::INVALID_DATA)
+			} else {
+				Ok(Some(unsafe { std::ptr::read_unaligned(data.as_ptr() as *const T) }))
+			}
+		})?
+	}
+
+	/// Returns the number of values written to the channel so far, which can
+	/// be used to detect new values.
+	pub fn sequence(&self) -> SysResult<u64> {
+		self.lock(|header, _| header.seq)
+	}
+
+	/// Waits until a value is written, returning `false` if the timeout
+	/// elapses.
+	pub fn wait(&self, milliseconds: Option<u32>) -> SysResult<bool> {
+		self.hevent.WaitForSingleObject(milliseconds)
+			.map(|wait| wait == co::WAIT::OBJECT_0)
+	}
+
+	/// Writes a value, replacing the previous one, and signals the waiting
+	/// side.
+	///
+	/// Fails with
+	/// [`ERROR::INSUFFICIENT_BUFFER`](crate::co::ERROR::INSUFFICIENT_BUFFER) if
+	/// the value doesn't fit the channel capacity.
+	pub fn write<T: Pod>(&self, val: &T) -> SysResult<()> {
+		let sz = std::mem::size_of::<T>();
+		if sz > self.capacity {
+			return Err(co::ERROR::INSUFFICIENT_BUFFER);
+		}
+
+		self.lock(|header, data| {
+			unsafe {
+				std::ptr::copy_nonoverlapping(
+					val as *const T as *const u8, data.as_mut_ptr(), sz);
+			}
+			header.len = sz as _;
+			header.seq += 1;
+		})?;
+		self.hevent.SetEvent()
+	}
+}
@@ -37,8 +37,13 @@
 //! | `comctl` | ComCtl32.dll, for [Common Controls](https://learn.microsoft.com/en-us/windows/win32/api/_controls/) |
 //! | `comdlg` | ComDlg32.dll, for the old [Common Dialogs](https://learn.microsoft.com/en-us/windows/win32/uxguide/win-common-dlg) |
 //! | `dshow` | [DirectShow](https://learn.microsoft.com/en-us/windows/win32/directshow/directshow) |
+//! | `dwm` | Dwmapi.dll, the [Desktop Window Manager](https://learn.microsoft.com/en-us/windows/win32/dwm/dwm-overview) |
 //! | `dxgi` | [DirectX Graphics Infrastructure](https://learn.microsoft.com/en-us/windows/win32/direct3ddxgi/dx-graphics-dxgi) |
 //! | `gdi` | Gdi32.dll, the [Windows GDI](https://learn.microsoft.com/en-us/windows/win32/gdi/windows-gdi) |
+//! | `sapi` | Speech API (SAPI) 5.4 |
+//! | `spellcheck` | [Spell Checker API](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/) |
+//! | `taskschd` | [Task Scheduler 2.0](https://learn.microsoft.com/en-us/windows/win32/taskschd/task-scheduler-start-page) |
+//! | `uiautomation` | [UI Automation](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/) |
 //! | **`gui`** | **The WinSafe high-level GUI abstractions** |
 //! | `kernel` | Kernel32.dll, Advapi32.dll and Ktmw32.dll – all others will include it |
 //! | `msimg` | Msimg32.dll |
@@ -48,6 +53,7 @@
 //! | `user` | User32.dll, the basic Windows GUI support |
 //! | `uxtheme` | UxTheme.dll, extended window theming |
 //! | `version` | Version.dll, to manipulate *.exe version info |
+//! | `wmi` | [Windows Management Instrumentation](https://learn.microsoft.com/en-us/windows/win32/wmisdk/wmi-start-page) |
 //!
 //! Note that a Cargo feature may depend on other features, which will be
 //! enabled automatically.
@@ -241,12 +247,16 @@
 //!
 //! | Utility | Used for |
 //! | - | - |
+//! | [`Clipboard`](crate::Clipboard) | Reading and writing clipboard data. |
+//! | [`DataObject`](crate::DataObject) | Data for OLE drag and drop operations. |
 //! | [`Encoding`](crate::Encoding) | String encodings. |
 //! | [`File`](crate::File) | File read/write and other operations. |
 //! | [`FileMapped`](crate::FileMapped) | Memory-mapped file operations. |
 //! | [`Ini`](crate::Ini) | Managing key/value pairs of a `.ini` file. |
 //! | [`path`](crate::path) | File path operations. |
+//! | [`PersistentEnv`](crate::PersistentEnv) | Environment variables stored in the registry. |
 //! | [`ResourceInfo`](crate::ResourceInfo) | Retrieve embedded data from executables or DLLs. |
+//! | [`UiWatchdog`](crate::UiWatchdog) | Detecting when the UI thread stops responding. |
 //! | [`task_dlg`](crate::task_dlg) | Various dialog prompts. |
 //! | [`WString`](crate::WString) | Managing native wide strings. |
 
@@ -257,26 +267,40 @@
 
 #[macro_use] mod macros;
 
+// Unit tests.
+
+#[cfg(test)] mod tests;
+
 // Declarations of modules themselves.
 
 #[cfg(feature = "comctl")] mod comctl;
 #[cfg(feature = "comdlg")] mod comdlg;
 #[cfg(feature = "dshow")] mod dshow;
+#[cfg(feature = "dwm")] mod dwm;
 #[cfg(feature = "dxgi")] mod dxgi;
 #[cfg(feature = "gdi")] mod gdi;
 #[cfg(feature = "kernel")] mod kernel;
 #[cfg(feature = "msimg")] mod msimg;
 #[cfg(feature = "ole")] mod ole;
 #[cfg(feature = "oleaut")] mod oleaut;
+#[cfg(feature = "sapi")] mod sapi;
 #[cfg(feature = "shell")] mod shell;
+#[cfg(feature = "spellcheck")] mod spellcheck;
+#[cfg(feature = "taskschd")] mod taskschd;
+#[cfg(feature = "uiautomation")] mod uiautomation;
 #[cfg(feature = "user")] mod user;
 #[cfg(feature = "uxtheme")] mod uxtheme;
 #[cfg(feature = "version")] mod version;
+#[cfg(feature = "wmi")] mod wmi;
 #[cfg(all(feature = "comctl", feature = "gdi"))] mod comctl_gdi;
 #[cfg(all(feature = "comctl", feature = "ole"))] mod comctl_ole;
 #[cfg(all(feature = "comctl", feature = "shell"))] mod comctl_shell;
+#[cfg(all(feature = "comctl", feature = "uxtheme"))] mod comctl_uxtheme;
+#[cfg(all(feature = "comdlg", feature = "gdi"))] mod comdlg_gdi;
 #[cfg(all(feature = "dshow", feature = "gdi"))] mod dshow_gdi;
 #[cfg(all(feature = "gdi", feature = "ole"))] mod gdi_ole;
+#[cfg(all(feature = "gdi", feature = "shell"))] mod gdi_shell;
+#[cfg(all(feature = "oleaut", feature = "shell"))] mod oleaut_shell;
 
 // The gui module itself is public.
 
@@ -287,17 +311,27 @@
 #[cfg(feature = "comctl")] pub use comctl::decl::*;
 #[cfg(feature = "comdlg")] pub use comdlg::decl::*;
 #[cfg(feature = "dshow")] pub use dshow::decl::*;
+#[cfg(feature = "dwm")] pub use dwm::decl::*;
 #[cfg(feature = "dxgi")] pub use dxgi::decl::*;
 #[cfg(feature = "gdi")] pub use gdi::decl::*;
 #[cfg(feature = "kernel")] pub use kernel::decl::*;
+#[cfg(feature = "msimg")] pub use msimg::decl::*;
 #[cfg(feature = "ole")] pub use ole::decl::*;
 #[cfg(feature = "oleaut")] pub use oleaut::decl::*;
+#[cfg(feature = "sapi")] pub use sapi::decl::*;
 #[cfg(feature = "shell")] pub use shell::decl::*;
+#[cfg(feature = "spellcheck")] pub use spellcheck::decl::*;
+#[cfg(feature = "taskschd")] pub use taskschd::decl::*;
+#[cfg(feature = "uiautomation")] pub use uiautomation::decl::*;
 #[cfg(feature = "user")] pub use user::decl::*;
 #[cfg(feature = "uxtheme")] pub use uxtheme::decl::*;
 #[cfg(feature = "version")] pub use version::decl::*;
+#[cfg(feature = "wmi")] pub use wmi::decl::*;
 #[cfg(all(feature = "comctl", feature = "gdi"))] pub use comctl_gdi::decl::*;
 #[cfg(all(feature = "comctl", feature = "ole"))] pub use comctl_ole::decl::*;
+#[cfg(all(feature = "comdlg", feature = "gdi"))] pub use comdlg_gdi::decl::*;
+#[cfg(all(feature = "gdi", feature = "shell"))] pub use gdi_shell::decl::*;
+#[cfg(all(feature = "oleaut", feature = "shell"))] pub use oleaut_shell::decl::*;
 
 #[cfg(feature = "kernel")]
 pub mod co {
@@ -314,15 +348,22 @@ pub mod co {
 	#[cfg(feature = "comctl")] pub use super::comctl::co::*;
 	#[cfg(feature = "comdlg")] pub use super::comdlg::co::*;
 	#[cfg(feature = "dshow")] pub use super::dshow::co::*;
+	#[cfg(feature = "dwm")] pub use super::dwm::co::*;
 	#[cfg(feature = "dxgi")] pub use super::dxgi::co::*;
 	#[cfg(feature = "gdi")] pub use super::gdi::co::*;
 	#[cfg(feature = "kernel")] pub use super::kernel::co::*;
+	#[cfg(feature = "msimg")] pub use super::msimg::co::*;
 	#[cfg(feature = "ole")] pub use super::ole::co::*;
 	#[cfg(feature = "oleaut")] pub use super::oleaut::co::*;
+	#[cfg(feature = "sapi")] pub use super::sapi::co::*;
 	#[cfg(feature = "shell")] pub use super::shell::co::*;
+	#[cfg(feature = "spellcheck")] pub use super::spellcheck::co::*;
+	#[cfg(feature = "taskschd")] pub use super::taskschd::co::*;
+	#[cfg(feature = "uiautomation")] pub use super::uiautomation::co::*;
 	#[cfg(feature = "user")] pub use super::user::co::*;
 	#[cfg(feature = "uxtheme")] pub use super::uxtheme::co::*;
 	#[cfg(feature = "version")] pub use super::version::co::*;
+	#[cfg(feature = "wmi")] pub use super::wmi::co::*;
 }
 
 #[cfg(feature = "kernel")]
@@ -435,6 +476,14 @@ pub mod msg {
 		pub use super::super::user::messages::lb::*;
 	}
 
+	#[cfg(feature = "comctl")]
+	pub mod lm {
+		//! SysLink control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-syslink-control-reference-messages),
+		//! whose constants have [`LM`](crate::co::LM) prefix.
+		pub use super::super::comctl::messages::lm::*;
+	}
+
 	#[cfg(feature = "comctl")]
 	pub mod lvm {
 		//! List view control
@@ -503,6 +552,14 @@ pub mod msg {
 		pub use super::super::comctl::messages::trbm::*;
 	}
 
+	#[cfg(feature = "comctl")]
+	pub mod ttm {
+		//! Tooltip control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tooltip-control-reference-messages),
+		//! whose constants have [`TTM`](crate::co::TTM) prefix.
+		pub use super::super::comctl::messages::ttm::*;
+	}
+
 	#[cfg(feature = "comctl")]
 	pub mod tvm {
 		//! Tree view control
@@ -545,6 +602,7 @@ pub mod prelude {
 	//! ```
 	#[cfg(feature = "comctl")] pub use super::comctl::traits::*;
 	#[cfg(feature = "dshow")] pub use super::dshow::traits::*;
+	#[cfg(feature = "dwm")] pub use super::dwm::traits::*;
 	#[cfg(feature = "dxgi")] pub use super::dxgi::traits::*;
 	#[cfg(feature = "gdi")] pub use super::gdi::traits::*;
 	#[cfg(feature = "gui")] pub use super::gui::traits::*;
@@ -552,13 +610,21 @@ pub mod prelude {
 	#[cfg(feature = "msimg")] pub use super::msimg::traits::*;
 	#[cfg(feature = "ole")] pub use super::ole::traits::*;
 	#[cfg(feature = "oleaut")] pub use super::oleaut::traits::*;
+	#[cfg(feature = "sapi")] pub use super::sapi::traits::*;
 	#[cfg(feature = "shell")] pub use super::shell::traits::*;
+	#[cfg(feature = "spellcheck")] pub use super::spellcheck::traits::*;
+	#[cfg(feature = "taskschd")] pub use super::taskschd::traits::*;
+	#[cfg(feature = "uiautomation")] pub use super::uiautomation::traits::*;
 	#[cfg(feature = "user")] pub use super::user::traits::*;
 	#[cfg(feature = "uxtheme")] pub use super::uxtheme::traits::*;
+	#[cfg(feature = "wmi")] pub use super::wmi::traits::*;
 	#[cfg(all(feature = "gdi", feature = "ole"))] pub use super::gdi_ole::traits::*;
+	#[cfg(all(feature = "gdi", feature = "shell"))] pub use super::gdi_shell::traits::*;
 	#[cfg(all(feature = "comctl", feature = "ole"))] pub use super::comctl_ole::traits::*;
 	#[cfg(all(feature = "comctl", feature = "shell"))] pub use super::comctl_shell::traits::*;
+	#[cfg(all(feature = "comctl", feature = "uxtheme"))] pub use super::comctl_uxtheme::traits::*;
 	#[cfg(all(feature = "dshow", feature = "gdi"))] pub use super::dshow_gdi::traits::*;
+	#[cfg(all(feature = "oleaut", feature = "shell"))] pub use super::oleaut_shell::traits::*;
 }
 
 #[cfg(feature = "ole")]
@@ -568,5 +634,11 @@ pub mod vt {
 	#[cfg(feature = "dxgi")] pub use super::dxgi::vt::*;
 	#[cfg(feature = "ole")] pub use super::ole::vt::*;
 	#[cfg(feature = "oleaut")] pub use super::oleaut::vt::*;
+	#[cfg(feature = "sapi")] pub use super::sapi::vt::*;
 	#[cfg(feature = "shell")] pub use super::shell::vt::*;
+	#[cfg(feature = "spellcheck")] pub use super::spellcheck::vt::*;
+	#[cfg(feature = "taskschd")] pub use super::taskschd::vt::*;
+	#[cfg(feature = "uiautomation")] pub use super::uiautomation::vt::*;
+	#[cfg(feature = "wmi")] pub use super::wmi::vt::*;
+	#[cfg(all(feature = "gdi", feature = "shell"))] pub use super::gdi_shell::vt::*;
 }
@@ -11,7 +11,22 @@ macro_rules! one_func {
 	};
 }
 
+/// Builds one single stub in place of an FFI binding, for unit tests which
+/// don't run on Windows. The stub panics if called.
+macro_rules! one_stub {
+	($func:ident( $( $parm:ty ),* ) $( -> $ret:ty )?) => {
+		#[allow(non_snake_case, clippy::too_many_arguments)]
+		pub(crate) unsafe fn $func( $( _: $parm, )* ) $(-> $ret)? {
+			unimplemented!(concat!(stringify!($func), " is not available in tests off Windows."))
+		}
+	};
+}
+
 /// Builds a block of FFI bindings.
+///
+/// When compiling the unit tests off Windows, the bindings are replaced by
+/// stubs, so the library links and the code which doesn't call the system can
+/// be tested.
 macro_rules! extern_sys {
 	(
 		$dll:expr;
@@ -19,11 +34,42 @@ macro_rules! extern_sys {
 			$func:ident( $( $parm:ty ),* ) $( -> $ret:ty )?
 		)*
 	) => {
+		#[cfg(not(all(test, not(windows))))]
 		#[link(name = $dll)]
 		extern "system" {
 			$(
 				one_func!( $func( $( $parm ),* ) $(-> $ret)? );
 			)*
 		}
+
+		$(
+			#[cfg(all(test, not(windows)))]
+			one_stub!( $func( $( $parm ),* ) $(-> $ret)? );
+		)*
+	};
+}
+
+/// Builds a block of FFI bindings which are resolved at runtime, on first use,
+/// for functions which don't exist in older Windows versions. Each binding
+/// returns the function pointer, or `co::ERROR::CALL_NOT_IMPLEMENTED` if the
+/// function is not available.
+macro_rules! extern_sys_delay {
+	(
+		$dll:expr;
+		$(
+			$func:ident( $( $parm:ty ),* ) $( -> $ret:ty )?
+		)*
+	) => {
+		$(
+			#[allow(non_snake_case)]
+			pub(crate) fn $func() -> crate::kernel::decl::SysResult<
+				unsafe extern "system" fn( $( $parm ),* ) $(-> $ret)?>
+			{
+				static ADDR: std::sync::atomic::AtomicUsize =
+					std::sync::atomic::AtomicUsize::new(0);
+				crate::kernel::privs::delay_load(&ADDR, $dll, stringify!($func))
+					.map(|addr| unsafe { std::mem::transmute(addr) })
+			}
+		)*
 	};
 }
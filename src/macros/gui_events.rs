@@ -100,6 +100,40 @@ macro_rules! fn_wm_ctlcolor {
 	};
 }
 
+/// WM_CTLCOLOR* message, returning text and background colors; the brushes are
+/// cached and destroyed along with the events.
+macro_rules! fn_wm_ctlcolor_colors {
+	(
+		$name:ident, $wmconst:expr, $parm:ty;
+		$( #[$doc:meta] )*
+	) => {
+		$( #[$doc] )*
+		fn $name<F>(&self, func: F)
+			where F: Fn($parm) -> AnyResult<crate::gui::events::CtlColors> + 'static,
+		{
+			let brushes = std::cell::RefCell::new(std::collections::HashMap::<
+				u32, crate::gdi::guard::DeleteObjectGuard<crate::user::decl::HBRUSH>,
+			>::new());
+
+			self.wm($wmconst, move |p| {
+				let p = <$parm>::from_generic_wm(p);
+				let hdc = unsafe { p.hdc.raw_copy() };
+				let colors = func(p)?;
+				hdc.SetTextColor(colors.text)?;
+				hdc.SetBkColor(colors.background)?;
+
+				let mut brushes = brushes.borrow_mut();
+				let hbrush = match brushes.entry(colors.background.0) {
+					std::collections::hash_map::Entry::Occupied(e) => e.into_mut(),
+					std::collections::hash_map::Entry::Vacant(e) =>
+						e.insert(crate::user::decl::HBRUSH::CreateSolidBrush(colors.background)?),
+				};
+				Ok(Some(hbrush.as_ptr() as _))
+			});
+		}
+	};
+}
+
 //------------------------------------------------------------------------------
 
 /// WM_COMMAND message, no parameters, no meaningful return.
@@ -256,10 +256,7 @@ macro_rules! pub_struct_msg_button {
 			fn from_generic_wm(p: WndMsg) -> Self {
 				Self {
 					vkey_code: co::VK(p.wparam as _),
-					coords: POINT {
-						x: LOWORD(p.lparam as _) as _,
-						y: HIWORD(p.lparam as _) as _,
-					},
+					coords: POINT::from(p.lparam as u32),
 				}
 			}
 		}
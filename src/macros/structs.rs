@@ -56,6 +56,27 @@ macro_rules! pub_fn_bool_get_set {
 	};
 }
 
+/// Implements getter and setter methods for a single bit of the given integer
+/// field, which is a C bit field.
+macro_rules! pub_fn_bitfield_get_set {
+	($field:ident, $bit:expr, $getter:ident, $setter:ident) => {
+		/// Returns the bit field.
+		#[must_use]
+		pub const fn $getter(&self) -> bool {
+			self.$field & (1 << $bit) != 0
+		}
+
+		/// Sets the bit field.
+		pub fn $setter(&mut self, val: bool) {
+			if val {
+				self.$field |= 1 << $bit;
+			} else {
+				self.$field &= !(1 << $bit);
+			}
+		}
+	};
+}
+
 /// Implements getter and setter methods for the given resource ID field, stored
 /// as `*mut u16`.
 macro_rules! pub_fn_resource_id_get_set {
@@ -0,0 +1,11 @@
+#![allow(non_camel_case_types)]
+
+const_ordinary! { GRADIENT_FILL: u32;
+	/// [`HDC::GradientFill`](crate::prelude::msimg_Hdc::GradientFill) mode
+	/// (`u32`).
+	=>
+	=>
+	RECT_H 0x0000_0000
+	RECT_V 0x0000_0001
+	TRIANGLE 0x0000_0002
+}
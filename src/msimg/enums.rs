@@ -0,0 +1,29 @@
+use crate::co;
+use crate::msimg::decl::{GRADIENT_RECT, GRADIENT_TRIANGLE};
+
+/// Variant parameter for:
+///
+/// * [`HDC::GradientFill`](crate::prelude::msimg_Hdc::GradientFill).
+#[derive(Clone, Copy)]
+pub enum GradientMesh<'a> {
+	/// Rectangles filled with a horizontal gradient, from left to right.
+	RectH(&'a [GRADIENT_RECT]),
+	/// Rectangles filled with a vertical gradient, from top to bottom.
+	RectV(&'a [GRADIENT_RECT]),
+	/// Triangles filled with the colors of their vertices.
+	Triangle(&'a [GRADIENT_TRIANGLE]),
+}
+
+impl<'a> GradientMesh<'a> {
+	/// Returns the mesh pointer, its number of elements, and the fill mode.
+	#[must_use]
+	pub(in crate::msimg) fn ptr_len_mode(&self)
+		-> (*const std::ffi::c_void, u32, co::GRADIENT_FILL)
+	{
+		match self {
+			Self::RectH(rcs) => (rcs.as_ptr() as _, rcs.len() as _, co::GRADIENT_FILL::RECT_H),
+			Self::RectV(rcs) => (rcs.as_ptr() as _, rcs.len() as _, co::GRADIENT_FILL::RECT_V),
+			Self::Triangle(tris) => (tris.as_ptr() as _, tris.len() as _, co::GRADIENT_FILL::TRIANGLE),
+		}
+	}
+}
@@ -1,5 +1,7 @@
-use crate::kernel::ffi_types::{BOOL, HANDLE};
+use crate::kernel::ffi_types::{BOOL, HANDLE, PCVOID};
 
 extern_sys! { "msimg32";
+	AlphaBlend(HANDLE, i32, i32, i32, i32, HANDLE, i32, i32, i32, i32, u32) -> BOOL
+	GradientFill(HANDLE, PCVOID, u32, PCVOID, u32, u32) -> BOOL
 	TransparentBlt(HANDLE, i32, i32, i32, i32, HANDLE, i32, i32, i32, i32, u32) -> BOOL
 }
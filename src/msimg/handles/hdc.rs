@@ -3,8 +3,9 @@
 use crate::kernel::decl::SysResult;
 use crate::kernel::privs::bool_to_sysresult;
 use crate::msimg;
+use crate::msimg::decl::{GradientMesh, TRIVERTEX};
 use crate::prelude::Handle;
-use crate::user::decl::{COLORREF, HDC, POINT, SIZE};
+use crate::user::decl::{BLENDFUNCTION, COLORREF, HDC, POINT, SIZE};
 
 impl msimg_Hdc for HDC {}
 
@@ -17,6 +18,75 @@ impl msimg_Hdc for HDC {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait msimg_Hdc: Handle {
+	/// [`AlphaBlend`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-alphablend)
+	/// method.
+	fn AlphaBlend(&self,
+		dest_top_left: POINT,
+		dest_sz: SIZE,
+		hdc_src: &HDC,
+		src_top_left: POINT,
+		src_sz: SIZE,
+		blend: BLENDFUNCTION,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				msimg::ffi::AlphaBlend(
+					self.as_ptr(),
+					dest_top_left.x, dest_top_left.y,
+					dest_sz.cx, dest_sz.cy,
+					hdc_src.as_ptr(),
+					src_top_left.x, src_top_left.y,
+					src_sz.cx, src_sz.cy,
+					std::mem::transmute::<BLENDFUNCTION, u32>(blend),
+				)
+			},
+		)
+	}
+
+	/// [`GradientFill`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-gradientfill)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// A header with a vertical gradient, from white to light blue:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{COLORREF, GRADIENT_RECT, GradientMesh, HDC, POINT, TRIVERTEX};
+	///
+	/// let hdc: HDC; // initialized somewhere
+	/// # let hdc = HDC::NULL;
+	///
+	/// hdc.GradientFill(
+	///     &[
+	///         TRIVERTEX::new(POINT::new(0, 0), COLORREF::new(255, 255, 255), 0),
+	///         TRIVERTEX::new(POINT::new(400, 40), COLORREF::new(200, 220, 255), 0),
+	///     ],
+	///     GradientMesh::RectV(&[GRADIENT_RECT { UpperLeft: 0, LowerRight: 1 }]),
+	/// )?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	fn GradientFill(&self,
+		vertices: &[TRIVERTEX],
+		mesh: GradientMesh,
+	) -> SysResult<()>
+	{
+		let (mesh_ptr, mesh_len, mode) = mesh.ptr_len_mode();
+		bool_to_sysresult(
+			unsafe {
+				msimg::ffi::GradientFill(
+					self.as_ptr(),
+					vertices.as_ptr() as _,
+					vertices.len() as _,
+					mesh_ptr,
+					mesh_len,
+					mode.0,
+				)
+			},
+		)
+	}
+
 	/// [`TransparentBlt`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-transparentblt)
 	/// method.
 	fn TransparentBlt(&self,
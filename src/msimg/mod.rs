@@ -1,8 +1,16 @@
 #![cfg_attr(docsrs, doc(cfg(feature = "msimg")))]
 
 pub(in crate::msimg) mod ffi;
+pub mod co;
 
+mod enums;
 mod handles;
+mod structs;
+
+pub mod decl {
+	pub use super::enums::*;
+	pub use super::structs::*;
+}
 
 pub mod traits {
 	pub use super::handles::traits::*;
@@ -0,0 +1,51 @@
+#![allow(non_snake_case)]
+
+use crate::user::decl::{COLORREF, POINT};
+
+/// [`GRADIENT_RECT`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-gradient_rect)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct GRADIENT_RECT {
+	pub UpperLeft: u32,
+	pub LowerRight: u32,
+}
+
+/// [`GRADIENT_TRIANGLE`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-gradient_triangle)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct GRADIENT_TRIANGLE {
+	pub Vertex1: u32,
+	pub Vertex2: u32,
+	pub Vertex3: u32,
+}
+
+/// [`TRIVERTEX`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-trivertex)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct TRIVERTEX {
+	pub x: i32,
+	pub y: i32,
+	pub Red: u16,
+	pub Green: u16,
+	pub Blue: u16,
+	pub Alpha: u16,
+}
+
+impl TRIVERTEX {
+	/// Creates a new `TRIVERTEX` at the given point, converting the 8-bit
+	/// color components into the 16-bit ones used by the struct.
+	#[must_use]
+	pub const fn new(pt: POINT, color: COLORREF, alpha: u8) -> Self {
+		Self {
+			x: pt.x,
+			y: pt.y,
+			Red: (color.0 as u8 as u16) << 8,
+			Green: ((color.0 >> 8) as u8 as u16) << 8,
+			Blue: ((color.0 >> 16) as u8 as u16) << 8,
+			Alpha: (alpha as u16) << 8,
+		}
+	}
+}
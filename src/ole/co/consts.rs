@@ -1,5 +1,91 @@
 #![allow(non_camel_case_types)]
 
+const_ordinary! { BG_ERROR_CONTEXT: u32;
+	/// [`BG_ERROR_CONTEXT`](https://learn.microsoft.com/en-us/windows/win32/api/bits/ne-bits-bg_error_context)
+	/// enumeration (`u32`).
+	=>
+	=>
+	NONE 0
+	UNKNOWN 1
+	GENERAL_QUEUE_MANAGER 2
+	QUEUE_MANAGER_NOTIFICATION 3
+	LOCAL_FILE 4
+	REMOTE_FILE 5
+	GENERAL_TRANSPORT 6
+	REMOTE_APPLICATION 7
+	SERVER_CERTIFICATE_CALLBACK 8
+}
+
+const_bitflag! { BG_JOB_ENUM: u32;
+	/// [`IBackgroundCopyManager::EnumJobs`](crate::prelude::ole_IBackgroundCopyManager::EnumJobs)
+	/// `flags` (`u32`).
+	=>
+	=>
+	CURRENT_USER 0
+	ALL_USERS 0x0001
+}
+
+const_ordinary! { BG_JOB_PRIORITY: u32;
+	/// [`BG_JOB_PRIORITY`](https://learn.microsoft.com/en-us/windows/win32/api/bits/ne-bits-bg_job_priority)
+	/// enumeration (`u32`).
+	=>
+	=>
+	FOREGROUND 0
+	HIGH 1
+	NORMAL 2
+	LOW 3
+}
+
+const_ordinary! { BG_JOB_PROXY_USAGE: u32;
+	/// [`BG_JOB_PROXY_USAGE`](https://learn.microsoft.com/en-us/windows/win32/api/bits/ne-bits-bg_job_proxy_usage)
+	/// enumeration (`u32`).
+	=>
+	=>
+	PRECONFIG 0
+	NO_PROXY 1
+	OVERRIDE 2
+	AUTODETECT 3
+}
+
+const_ordinary! { BG_JOB_STATE: u32;
+	/// [`BG_JOB_STATE`](https://learn.microsoft.com/en-us/windows/win32/api/bits/ne-bits-bg_job_state)
+	/// enumeration (`u32`).
+	=>
+	=>
+	QUEUED 0
+	CONNECTING 1
+	TRANSFERRING 2
+	SUSPENDED 3
+	ERROR 4
+	TRANSIENT_ERROR 5
+	TRANSFERRED 6
+	ACKNOWLEDGED 7
+	CANCELLED 8
+}
+
+const_ordinary! { BG_JOB_TYPE: u32;
+	/// [`BG_JOB_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/bits/ne-bits-bg_job_type)
+	/// enumeration (`u32`).
+	=>
+	=>
+	DOWNLOAD 0
+	UPLOAD 1
+	UPLOAD_REPLY 2
+}
+
+const_bitflag! { BG_NOTIFY: u32;
+	/// [`IBackgroundCopyJob::SetNotifyFlags`](crate::prelude::ole_IBackgroundCopyJob::SetNotifyFlags)
+	/// `flags` (`u32`).
+	=>
+	=>
+	JOB_TRANSFERRED 0x0001
+	JOB_ERROR 0x0002
+	DISABLE 0x0004
+	JOB_MODIFICATION 0x0008
+	FILE_TRANSFERRED 0x0010
+	FILE_RANGES_TRANSFERRED 0x0020
+}
+
 const_ordinary! { CLSCTX: u32;
 	/// [`CLSCTX`](https://learn.microsoft.com/en-us/windows/win32/api/wtypesbase/ne-wtypesbase-clsctx)
 	/// enumeration (`u32`).
@@ -83,7 +169,7 @@ const_bitflag! { COINIT: u32;
 	SPEED_OVER_MEMORY 0x8
 }
 
-const_ordinary! { DROPEFFECT: u32;
+const_bitflag! { DROPEFFECT: u32;
 	/// [`DROPEFFECT`](https://learn.microsoft.com/en-us/windows/win32/com/dropeffect-constants)
 	/// constants (`u32`).
 	=>
@@ -95,6 +181,29 @@ const_ordinary! { DROPEFFECT: u32;
 	SCROLL 0x8000_0000
 }
 
+const_bitflag! { EOAC: u32;
+	/// [`EOLE_AUTHENTICATION_CAPABILITIES`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ne-objidl-eole_authentication_capabilities)
+	/// enumeration (`u32`).
+	=>
+	=>
+	NONE 0
+	MUTUAL_AUTH 0x1
+	SECURE_REFS 0x2
+	ACCESS_CONTROL 0x4
+	APPID 0x8
+	DYNAMIC 0x10
+	STATIC_CLOAKING 0x20
+	DYNAMIC_CLOAKING 0x40
+	ANY_AUTHORITY 0x80
+	MAKE_FULLSIC 0x100
+	REQUIRE_FULLSIC 0x200
+	AUTO_IMPERSONATE 0x400
+	DEFAULT 0x800
+	DISABLE_AAA 0x1000
+	NO_CUSTOM_MARSHAL 0x2000
+	RESERVED1 0x4000
+}
+
 const_ordinary! { FACILITY: u32;
 	/// [`HRESULT`](crate::co::HRESULT) facility (`u32`).
 	=>
@@ -324,6 +433,21 @@ const_ordinary! { RPC_C_AUTHN: u32;
 	DEFAULT 0xffff_ffff
 }
 
+const_ordinary! { RPC_C_AUTHN_LEVEL: u32;
+	/// Authentication level
+	/// [constants](https://learn.microsoft.com/en-us/windows/win32/com/com-authentication-level-constants)
+	/// (`u32`).
+	=>
+	=>
+	DEFAULT 0
+	NONE 1
+	CONNECT 2
+	CALL 3
+	PKT 4
+	PKT_INTEGRITY 5
+	PKT_PRIVACY 6
+}
+
 const_ordinary! { RPC_C_AUTHZ: u32;
 	/// Authorization
 	/// [constants](https://learn.microsoft.com/en-us/windows/win32/com/com-authorization-constants)
@@ -391,6 +515,31 @@ const_bitflag! { STGC: u32;
 	CONSOLIDATE 8
 }
 
+const_bitflag! { STGM: u32;
+	/// [`STGM`](https://learn.microsoft.com/en-us/windows/win32/stg/stgm-constants)
+	/// constants (`u32`).
+	=>
+	=>
+	READ 0x0000_0000
+	WRITE 0x0000_0001
+	READWRITE 0x0000_0002
+	SHARE_DENY_NONE 0x0000_0040
+	SHARE_DENY_READ 0x0000_0030
+	SHARE_DENY_WRITE 0x0000_0020
+	SHARE_EXCLUSIVE 0x0000_0010
+	PRIORITY 0x0004_0000
+	CREATE 0x0000_1000
+	CONVERT 0x0002_0000
+	FAILIFTHERE 0x0000_0000
+	DIRECT 0x0000_0000
+	TRANSACTED 0x0001_0000
+	NOSCRATCH 0x0010_0000
+	NOSNAPSHOT 0x0020_0000
+	SIMPLE 0x0800_0000
+	DIRECT_SWMR 0x0040_0000
+	DELETEONRELEASE 0x0400_0000
+}
+
 const_ordinary! { STREAM_SEEK: u32;
 	/// [`STREAM_SEEK`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ne-objidl-stream_seek)
 	/// enumeration (`u32`).
@@ -415,3 +564,46 @@ const_ordinary! { TYMED: u32;
 	ENHMF 64
 	NULL 0
 }
+
+const_bitflag! { WER_FAULT_REPORTING: u32;
+	/// [`WerSetFlags`](crate::WerSetFlags) `flags` (`u32`).
+	=>
+	=>
+	/// Do not collect heap information in the event of an application crash or
+	/// non-response.
+	FLAG_NOHEAP 1
+	/// Queue critical reports.
+	FLAG_QUEUE 2
+	/// Do not suspend the process threads before reporting the error.
+	FLAG_DISABLE_THREAD_SUSPENSION 4
+	/// Queue critical reports and upload from the queue.
+	FLAG_QUEUE_UPLOAD 8
+	/// Always show the error reporting UI for this process.
+	ALWAYS_SHOW_UI 16
+}
+
+const_bitflag! { WER_FILE: u32;
+	/// [`WerRegisterFile`](crate::WerRegisterFile) `flags` (`u32`).
+	=>
+	=>
+	/// Automatically delete the file after the report is submitted.
+	DELETE_WHEN_DONE 1
+	/// The file does not contain personal information that could be used to
+	/// identify or contact the user.
+	ANONYMOUS_DATA 2
+	/// The file is a CAB file, and should not be compressed again.
+	COMPRESSED 4
+}
+
+const_ordinary! { WER_REGFILE_TYPE: u32;
+	/// [`WER_REGISTER_FILE_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/werapi/ne-werapi-wer_register_file_type)
+	/// enumeration (`u32`).
+	=>
+	=>
+	/// The document in use by the application at the time of the event. This
+	/// document is only collected if the Watson server asks for it.
+	USER_DOCUMENT 1
+	/// Any other type of file. This file will always get added to the cab (but
+	/// only if the server asks for a cab).
+	OTHER 2
+}
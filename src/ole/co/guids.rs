@@ -1,3 +1,5 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
 const_guid! { CLSID;
 	/// A COM class ID, from which the interfaces are created (`GUID`).
 	=>
@@ -7,3 +9,7 @@ const_guid! { IID;
 	/// A COM interface ID, which uniquely identifies the interface (`GUID`).
 	=>
 }
+
+const_guid_values! { CLSID;
+	BackgroundCopyManager "4991d34b-80a1-4291-83b6-3328366b9097"
+}
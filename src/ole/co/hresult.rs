@@ -49,6 +49,22 @@ const_no_debug_display! { HRESULT: u32;
 	/// let err = ERROR::FILE_NOT_FOUND;
 	///
 	/// let hr = err.to_hresult();
+	/// let hr: HRESULT = err.into(); // same thing
+	/// ```
+	///
+	/// The opposite conversion is fallible, since only the `HRESULT` values of
+	/// [`FACILITY::WIN32`](crate::co::FACILITY::WIN32) – plus `S_OK` – carry
+	/// an `ERROR`:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co::ERROR, co::HRESULT};
+	///
+	/// let hr = HRESULT::E_ACCESSDENIED;
+	///
+	/// assert_eq!(hr.to_error(), Some(ERROR::ACCESS_DENIED));
+	/// assert_eq!(ERROR::try_from(hr), Ok(ERROR::ACCESS_DENIED));
+	/// assert_eq!(HRESULT::E_NOINTERFACE.to_error(), None);
 	/// ```
 }
 
@@ -78,6 +94,22 @@ impl std::fmt::Display for HRESULT {
 
 impl FormattedError for HRESULT {}
 
+impl From<co::ERROR> for HRESULT {
+	fn from(v: co::ERROR) -> Self {
+		v.to_hresult()
+	}
+}
+
+impl TryFrom<HRESULT> for co::ERROR {
+	type Error = HRESULT;
+
+	/// Converts the `HRESULT` back into an `ERROR`, returning the same `HRESULT`
+	/// as the error if it doesn't carry an `ERROR`.
+	fn try_from(v: HRESULT) -> Result<Self, Self::Error> {
+		v.to_error().ok_or(v)
+	}
+}
+
 impl co::ERROR {
 	/// [`HRESULT_FROM_WIN32`](https://learn.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-hresult_from_win32)
 	/// method. Originally a macro.
@@ -91,6 +123,9 @@ impl co::ERROR {
 }
 
 impl HRESULT {
+	const CUSTOMER_BIT: u32 = 0x2000_0000;
+	const NT_BIT: u32 = 0x1000_0000; // FACILITY_NT_BIT
+
 	/// [`HRESULT_CODE`](https://learn.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-hresult_code)
 	/// method. Originally a macro.
 	pub fn code(self) -> u16 {
@@ -103,11 +138,76 @@ impl HRESULT {
 		co::FACILITY((self.0 >> 16) & 0x1fff)
 	}
 
+	/// [`FAILED`](https://learn.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-failed)
+	/// method. Originally a macro.
+	#[must_use]
+	pub const fn failed(self) -> bool {
+		(self.0 as i32) < 0
+	}
+
+	/// [`HRESULT_FROM_NT`](https://learn.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-hresult_from_nt)
+	/// static method. Originally a macro.
+	#[must_use]
+	pub const fn from_ntstatus(ntstatus: u32) -> HRESULT {
+		HRESULT(ntstatus | Self::NT_BIT)
+	}
+
+	/// Tells whether the customer bit is set, meaning the value was defined by
+	/// a third party, not by Microsoft.
+	#[must_use]
+	pub const fn is_customer(self) -> bool {
+		self.0 & Self::CUSTOMER_BIT != 0
+	}
+
+	/// Tells whether the value was created from an `NTSTATUS` with
+	/// [`HRESULT::from_ntstatus`](crate::co::HRESULT::from_ntstatus).
+	#[must_use]
+	pub const fn is_ntstatus(self) -> bool {
+		self.0 & Self::NT_BIT != 0
+	}
+
 	/// [`HRESULT_SEVERITY`](https://learn.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-hresult_severity)
 	/// method. Originally a macro.
 	pub fn severity(self) -> co::SEVERITY {
 		co::SEVERITY(((self.0 >> 31) & 0x1) as _)
 	}
+
+	/// [`SUCCEEDED`](https://learn.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-succeeded)
+	/// method. Originally a macro.
+	#[must_use]
+	pub const fn succeeded(self) -> bool {
+		!self.failed()
+	}
+
+	/// Returns the [`ERROR`](crate::co::ERROR) carried by the value, which is
+	/// the opposite of
+	/// [`ERROR::to_hresult`](crate::co::ERROR::to_hresult). Returns `None` if
+	/// the facility is not [`FACILITY::WIN32`](crate::co::FACILITY::WIN32),
+	/// unless the value is `S_OK`, which becomes
+	/// [`ERROR::SUCCESS`](crate::co::ERROR::SUCCESS).
+	#[must_use]
+	pub const fn to_error(self) -> Option<co::ERROR> {
+		if self.0 == HRESULT::S_OK.0 {
+			Some(co::ERROR::SUCCESS)
+		} else if self.failed() && !self.is_ntstatus()
+			&& (self.0 >> 16) & 0x1fff == co::FACILITY::WIN32.0
+		{
+			Some(co::ERROR(self.0 & 0xffff))
+		} else {
+			None
+		}
+	}
+
+	/// Returns the `NTSTATUS` the value was created from, if it was created with
+	/// [`HRESULT::from_ntstatus`](crate::co::HRESULT::from_ntstatus).
+	#[must_use]
+	pub const fn to_ntstatus(self) -> Option<u32> {
+		if self.is_ntstatus() {
+			Some(self.0 & !Self::NT_BIT)
+		} else {
+			None
+		}
+	}
 }
 
 const_values! { HRESULT;
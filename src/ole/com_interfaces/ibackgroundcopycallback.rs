@@ -0,0 +1,76 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::{
+	ole_IBackgroundCopyError, ole_IBackgroundCopyJob, ole_IUnknown,
+};
+use crate::vt::IUnknownVT;
+
+/// [`IBackgroundCopyCallback`](crate::IBackgroundCopyCallback) virtual table.
+#[repr(C)]
+pub struct IBackgroundCopyCallbackVT {
+	pub IUnknownVT: IUnknownVT,
+	pub JobTransferred: fn(ComPtr, ComPtr) -> HRES,
+	pub JobError: fn(ComPtr, ComPtr, ComPtr) -> HRES,
+	pub JobModification: fn(ComPtr, ComPtr, u32) -> HRES,
+}
+
+com_interface! { IBackgroundCopyCallback: "97ea99c7-0186-4ad4-8df9-c5b4e0ed6b22";
+	/// [`IBackgroundCopyCallback`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nn-bits-ibackgroundcopycallback)
+	/// COM interface over
+	/// [`IBackgroundCopyCallbackVT`](crate::vt::IBackgroundCopyCallbackVT).
+	///
+	/// To receive BITS notifications, create an implementation with
+	/// [`BackgroundCopyCallback`](crate::BackgroundCopyCallback), then pass it
+	/// to
+	/// [`IBackgroundCopyJob::SetNotifyInterface`](crate::prelude::ole_IBackgroundCopyJob::SetNotifyInterface).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl ole_IBackgroundCopyCallback for IBackgroundCopyCallback {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IBackgroundCopyCallback`](crate::IBackgroundCopyCallback).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IBackgroundCopyCallback: ole_IUnknown {
+	/// [`IBackgroundCopyCallback::JobError`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopycallback-joberror)
+	/// method.
+	fn JobError(&self,
+		job: &impl ole_IBackgroundCopyJob,
+		error: &impl ole_IBackgroundCopyError,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyCallbackVT>();
+			ok_to_hrresult((vt.JobError)(self.ptr(), job.ptr(), error.ptr()))
+		}
+	}
+
+	/// [`IBackgroundCopyCallback::JobModification`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopycallback-jobmodification)
+	/// method.
+	fn JobModification(&self, job: &impl ole_IBackgroundCopyJob) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyCallbackVT>();
+			ok_to_hrresult((vt.JobModification)(self.ptr(), job.ptr(), 0)) // reserved
+		}
+	}
+
+	/// [`IBackgroundCopyCallback::JobTransferred`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopycallback-jobtransferred)
+	/// method.
+	fn JobTransferred(&self, job: &impl ole_IBackgroundCopyJob) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyCallbackVT>();
+			ok_to_hrresult((vt.JobTransferred)(self.ptr(), job.ptr()))
+		}
+	}
+}
@@ -0,0 +1,119 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::{LANGID, WString};
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult, IBackgroundCopyFile};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IBackgroundCopyError`](crate::IBackgroundCopyError) virtual table.
+#[repr(C)]
+pub struct IBackgroundCopyErrorVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetError: fn(ComPtr, *mut u32, *mut HRES) -> HRES,
+	pub GetFile: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub GetErrorDescription: fn(ComPtr, u32, *mut *mut u16) -> HRES,
+	pub GetErrorContextDescription: fn(ComPtr, u32, *mut *mut u16) -> HRES,
+	pub GetProtocol: fn(ComPtr, *mut *mut u16) -> HRES,
+}
+
+com_interface! { IBackgroundCopyError: "19c613a0-fcb8-4f28-81ae-897c3d078f81";
+	/// [`IBackgroundCopyError`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nn-bits-ibackgroundcopyerror)
+	/// COM interface over
+	/// [`IBackgroundCopyErrorVT`](crate::vt::IBackgroundCopyErrorVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl ole_IBackgroundCopyError for IBackgroundCopyError {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IBackgroundCopyError`](crate::IBackgroundCopyError).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IBackgroundCopyError: ole_IUnknown {
+	/// [`IBackgroundCopyError::GetError`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyerror-geterror)
+	/// method.
+	///
+	/// Returns the context of the error, and the error itself.
+	#[must_use]
+	fn GetError(&self) -> HrResult<(co::BG_ERROR_CONTEXT, co::HRESULT)> {
+		let mut context = co::BG_ERROR_CONTEXT::default();
+		let mut hr = co::HRESULT::default();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyErrorVT>();
+			ok_to_hrresult((vt.GetError)(self.ptr(), &mut context.0, &mut hr.0))
+		}.map(|_| (context, hr))
+	}
+
+	/// [`IBackgroundCopyError::GetErrorContextDescription`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyerror-geterrorcontextdescription)
+	/// method.
+	#[must_use]
+	fn GetErrorContextDescription(&self, lang_id: LANGID) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyErrorVT>();
+			ok_to_hrresult(
+				(vt.GetErrorContextDescription)(self.ptr(), lang_id.0 as _, &mut pstr),
+			)
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// [`IBackgroundCopyError::GetErrorDescription`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyerror-geterrordescription)
+	/// method.
+	#[must_use]
+	fn GetErrorDescription(&self, lang_id: LANGID) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyErrorVT>();
+			ok_to_hrresult(
+				(vt.GetErrorDescription)(self.ptr(), lang_id.0 as _, &mut pstr),
+			)
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// [`IBackgroundCopyError::GetFile`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyerror-getfile)
+	/// method.
+	///
+	/// Fails if the error is not associated with a file.
+	#[must_use]
+	fn GetFile(&self) -> HrResult<IBackgroundCopyFile> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IBackgroundCopyErrorVT>();
+			ok_to_hrresult((vt.GetFile)(self.ptr(), &mut ppv_queried))
+				.map(|_| IBackgroundCopyFile::from(ppv_queried))
+		}
+	}
+
+	/// [`IBackgroundCopyError::GetProtocol`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyerror-getprotocol)
+	/// method.
+	#[must_use]
+	fn GetProtocol(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyErrorVT>();
+			ok_to_hrresult((vt.GetProtocol)(self.ptr(), &mut pstr))
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+}
@@ -0,0 +1,82 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{HRES, PVOID};
+use crate::ole::decl::{BG_FILE_PROGRESS, ComPtr, CoTaskMemFree, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IBackgroundCopyFile`](crate::IBackgroundCopyFile) virtual table.
+#[repr(C)]
+pub struct IBackgroundCopyFileVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetRemoteName: fn(ComPtr, *mut *mut u16) -> HRES,
+	pub GetLocalName: fn(ComPtr, *mut *mut u16) -> HRES,
+	pub GetProgress: fn(ComPtr, PVOID) -> HRES,
+}
+
+com_interface! { IBackgroundCopyFile: "01b7bd23-fb88-4a77-8490-5891d3e4653a";
+	/// [`IBackgroundCopyFile`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nn-bits-ibackgroundcopyfile)
+	/// COM interface over
+	/// [`IBackgroundCopyFileVT`](crate::vt::IBackgroundCopyFileVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl ole_IBackgroundCopyFile for IBackgroundCopyFile {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IBackgroundCopyFile`](crate::IBackgroundCopyFile).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IBackgroundCopyFile: ole_IUnknown {
+	/// [`IBackgroundCopyFile::GetLocalName`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyfile-getlocalname)
+	/// method.
+	#[must_use]
+	fn GetLocalName(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyFileVT>();
+			ok_to_hrresult((vt.GetLocalName)(self.ptr(), &mut pstr))
+		}.map(|_| {
+			let name = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			name.to_string()
+		})
+	}
+
+	/// [`IBackgroundCopyFile::GetProgress`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyfile-getprogress)
+	/// method.
+	#[must_use]
+	fn GetProgress(&self) -> HrResult<BG_FILE_PROGRESS> {
+		let mut progress = BG_FILE_PROGRESS::default();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyFileVT>();
+			ok_to_hrresult(
+				(vt.GetProgress)(self.ptr(), &mut progress as *mut _ as _),
+			)
+		}.map(|_| progress)
+	}
+
+	/// [`IBackgroundCopyFile::GetRemoteName`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyfile-getremotename)
+	/// method.
+	#[must_use]
+	fn GetRemoteName(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyFileVT>();
+			ok_to_hrresult((vt.GetRemoteName)(self.ptr(), &mut pstr))
+		}.map(|_| {
+			let name = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			name.to_string()
+		})
+	}
+}
@@ -0,0 +1,422 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::{GUID, WString};
+use crate::kernel::ffi_types::{HRES, PCSTR, PCVOID, PVOID};
+use crate::ole::decl::{
+	BG_JOB_PROGRESS, BG_JOB_TIMES, ComPtr, CoTaskMemFree, HrResult,
+	IBackgroundCopyError, IEnumBackgroundCopyFiles,
+};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IBackgroundCopyJob`](crate::IBackgroundCopyJob) virtual table.
+#[repr(C)]
+pub struct IBackgroundCopyJobVT {
+	pub IUnknownVT: IUnknownVT,
+	pub AddFileSet: fn(ComPtr, u32, PCVOID) -> HRES,
+	pub AddFile: fn(ComPtr, PCSTR, PCSTR) -> HRES,
+	pub EnumFiles: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub Suspend: fn(ComPtr) -> HRES,
+	pub Resume: fn(ComPtr) -> HRES,
+	pub Cancel: fn(ComPtr) -> HRES,
+	pub Complete: fn(ComPtr) -> HRES,
+	pub GetId: fn(ComPtr, PVOID) -> HRES,
+	pub GetType: fn(ComPtr, *mut u32) -> HRES,
+	pub GetProgress: fn(ComPtr, PVOID) -> HRES,
+	pub GetTimes: fn(ComPtr, PVOID) -> HRES,
+	pub GetState: fn(ComPtr, *mut u32) -> HRES,
+	pub GetError: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub GetOwner: fn(ComPtr, *mut *mut u16) -> HRES,
+	pub SetDisplayName: fn(ComPtr, PCSTR) -> HRES,
+	pub GetDisplayName: fn(ComPtr, *mut *mut u16) -> HRES,
+	pub SetDescription: fn(ComPtr, PCSTR) -> HRES,
+	pub GetDescription: fn(ComPtr, *mut *mut u16) -> HRES,
+	pub SetPriority: fn(ComPtr, u32) -> HRES,
+	pub GetPriority: fn(ComPtr, *mut u32) -> HRES,
+	pub SetNotifyFlags: fn(ComPtr, u32) -> HRES,
+	pub GetNotifyFlags: fn(ComPtr, *mut u32) -> HRES,
+	pub SetNotifyInterface: fn(ComPtr, ComPtr) -> HRES,
+	pub GetNotifyInterface: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub SetMinimumRetryDelay: fn(ComPtr, u32) -> HRES,
+	pub GetMinimumRetryDelay: fn(ComPtr, *mut u32) -> HRES,
+	pub SetNoProgressTimeout: fn(ComPtr, u32) -> HRES,
+	pub GetNoProgressTimeout: fn(ComPtr, *mut u32) -> HRES,
+	pub GetErrorCount: fn(ComPtr, *mut u32) -> HRES,
+	pub SetProxySettings: fn(ComPtr, u32, PCSTR, PCSTR) -> HRES,
+	pub GetProxySettings: fn(ComPtr, *mut u32, *mut *mut u16, *mut *mut u16) -> HRES,
+	pub TakeOwnership: fn(ComPtr) -> HRES,
+}
+
+com_interface! { IBackgroundCopyJob: "37668d37-507e-4160-9316-26306d150b12";
+	/// [`IBackgroundCopyJob`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nn-bits-ibackgroundcopyjob)
+	/// COM interface over
+	/// [`IBackgroundCopyJobVT`](crate::vt::IBackgroundCopyJobVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl ole_IBackgroundCopyJob for IBackgroundCopyJob {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IBackgroundCopyJob`](crate::IBackgroundCopyJob).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IBackgroundCopyJob: ole_IUnknown {
+	/// [`IBackgroundCopyJob::AddFile`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-addfile)
+	/// method.
+	///
+	/// The `local_name` must be an absolute path.
+	fn AddFile(&self, remote_url: &str, local_name: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult(
+				(vt.AddFile)(
+					self.ptr(),
+					WString::from_str(remote_url).as_ptr(),
+					WString::from_str(local_name).as_ptr(),
+				),
+			)
+		}
+	}
+
+	/// [`IBackgroundCopyJob::Cancel`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-cancel)
+	/// method.
+	fn Cancel(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.Cancel)(self.ptr()))
+		}
+	}
+
+	/// [`IBackgroundCopyJob::Complete`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-complete)
+	/// method.
+	///
+	/// Makes the downloaded files available to the user. Returns false if
+	/// some files were not transferred, what is reported as
+	/// `BG_S_PARTIAL_COMPLETE`.
+	fn Complete(&self) -> HrResult<bool> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			match (vt.Complete)(self.ptr()) {
+				0x0020_0017 => Ok(false), // BG_S_PARTIAL_COMPLETE
+				hr => ok_to_hrresult(hr).map(|_| true),
+			}
+		}
+	}
+
+	/// [`IBackgroundCopyJob::EnumFiles`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-enumfiles)
+	/// method.
+	#[must_use]
+	fn EnumFiles(&self) -> HrResult<IEnumBackgroundCopyFiles> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.EnumFiles)(self.ptr(), &mut ppv_queried))
+				.map(|_| IEnumBackgroundCopyFiles::from(ppv_queried))
+		}
+	}
+
+	/// [`IBackgroundCopyJob::GetDescription`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-getdescription)
+	/// method.
+	#[must_use]
+	fn GetDescription(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetDescription)(self.ptr(), &mut pstr))
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// [`IBackgroundCopyJob::GetDisplayName`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-getdisplayname)
+	/// method.
+	#[must_use]
+	fn GetDisplayName(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetDisplayName)(self.ptr(), &mut pstr))
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// [`IBackgroundCopyJob::GetError`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-geterror)
+	/// method.
+	///
+	/// Must be called only when the job is in
+	/// [`co::BG_JOB_STATE::ERROR`](crate::co::BG_JOB_STATE::ERROR) or
+	/// [`co::BG_JOB_STATE::TRANSIENT_ERROR`](crate::co::BG_JOB_STATE::TRANSIENT_ERROR)
+	/// state.
+	#[must_use]
+	fn GetError(&self) -> HrResult<IBackgroundCopyError> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetError)(self.ptr(), &mut ppv_queried))
+				.map(|_| IBackgroundCopyError::from(ppv_queried))
+		}
+	}
+
+	/// [`IBackgroundCopyJob::GetErrorCount`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-geterrorcount)
+	/// method.
+	#[must_use]
+	fn GetErrorCount(&self) -> HrResult<u32> {
+		let mut count = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetErrorCount)(self.ptr(), &mut count))
+		}.map(|_| count)
+	}
+
+	/// [`IBackgroundCopyJob::GetId`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-getid)
+	/// method.
+	#[must_use]
+	fn GetId(&self) -> HrResult<GUID> {
+		let mut id = GUID::new("00000000-0000-0000-0000-000000000000"); // just a placeholder
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetId)(self.ptr(), &mut id as *mut _ as _))
+		}.map(|_| id)
+	}
+
+	/// [`IBackgroundCopyJob::GetMinimumRetryDelay`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-getminimumretrydelay)
+	/// method.
+	#[must_use]
+	fn GetMinimumRetryDelay(&self) -> HrResult<u32> {
+		let mut seconds = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetMinimumRetryDelay)(self.ptr(), &mut seconds))
+		}.map(|_| seconds)
+	}
+
+	/// [`IBackgroundCopyJob::GetNoProgressTimeout`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-getnoprogresstimeout)
+	/// method.
+	#[must_use]
+	fn GetNoProgressTimeout(&self) -> HrResult<u32> {
+		let mut seconds = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetNoProgressTimeout)(self.ptr(), &mut seconds))
+		}.map(|_| seconds)
+	}
+
+	/// [`IBackgroundCopyJob::GetNotifyFlags`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-getnotifyflags)
+	/// method.
+	#[must_use]
+	fn GetNotifyFlags(&self) -> HrResult<co::BG_NOTIFY> {
+		let mut flags = co::BG_NOTIFY::default();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetNotifyFlags)(self.ptr(), &mut flags.0))
+		}.map(|_| flags)
+	}
+
+	/// [`IBackgroundCopyJob::GetOwner`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-getowner)
+	/// method.
+	///
+	/// Returns the SID of the job owner, as a string.
+	#[must_use]
+	fn GetOwner(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetOwner)(self.ptr(), &mut pstr))
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// [`IBackgroundCopyJob::GetPriority`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-getpriority)
+	/// method.
+	#[must_use]
+	fn GetPriority(&self) -> HrResult<co::BG_JOB_PRIORITY> {
+		let mut priority = co::BG_JOB_PRIORITY::default();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetPriority)(self.ptr(), &mut priority.0))
+		}.map(|_| priority)
+	}
+
+	/// [`IBackgroundCopyJob::GetProgress`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-getprogress)
+	/// method.
+	#[must_use]
+	fn GetProgress(&self) -> HrResult<BG_JOB_PROGRESS> {
+		let mut progress = BG_JOB_PROGRESS::default();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetProgress)(self.ptr(), &mut progress as *mut _ as _))
+		}.map(|_| progress)
+	}
+
+	/// [`IBackgroundCopyJob::GetState`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-getstate)
+	/// method.
+	#[must_use]
+	fn GetState(&self) -> HrResult<co::BG_JOB_STATE> {
+		let mut state = co::BG_JOB_STATE::default();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetState)(self.ptr(), &mut state.0))
+		}.map(|_| state)
+	}
+
+	/// [`IBackgroundCopyJob::GetTimes`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-gettimes)
+	/// method.
+	#[must_use]
+	fn GetTimes(&self) -> HrResult<BG_JOB_TIMES> {
+		let mut times = BG_JOB_TIMES::default();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetTimes)(self.ptr(), &mut times as *mut _ as _))
+		}.map(|_| times)
+	}
+
+	/// [`IBackgroundCopyJob::GetType`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-gettype)
+	/// method.
+	#[must_use]
+	fn GetType(&self) -> HrResult<co::BG_JOB_TYPE> {
+		let mut job_type = co::BG_JOB_TYPE::default();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.GetType)(self.ptr(), &mut job_type.0))
+		}.map(|_| job_type)
+	}
+
+	/// [`IBackgroundCopyJob::Resume`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-resume)
+	/// method.
+	///
+	/// Jobs are created suspended, so this method must be called to start
+	/// the transfer.
+	fn Resume(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.Resume)(self.ptr()))
+		}
+	}
+
+	/// [`IBackgroundCopyJob::SetDescription`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-setdescription)
+	/// method.
+	fn SetDescription(&self, description: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult(
+				(vt.SetDescription)(self.ptr(), WString::from_str(description).as_ptr()),
+			)
+		}
+	}
+
+	/// [`IBackgroundCopyJob::SetDisplayName`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-setdisplayname)
+	/// method.
+	fn SetDisplayName(&self, name: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult(
+				(vt.SetDisplayName)(self.ptr(), WString::from_str(name).as_ptr()),
+			)
+		}
+	}
+
+	/// [`IBackgroundCopyJob::SetMinimumRetryDelay`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-setminimumretrydelay)
+	/// method.
+	fn SetMinimumRetryDelay(&self, seconds: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.SetMinimumRetryDelay)(self.ptr(), seconds))
+		}
+	}
+
+	/// [`IBackgroundCopyJob::SetNoProgressTimeout`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-setnoprogresstimeout)
+	/// method.
+	fn SetNoProgressTimeout(&self, seconds: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.SetNoProgressTimeout)(self.ptr(), seconds))
+		}
+	}
+
+	/// [`IBackgroundCopyJob::SetNotifyFlags`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-setnotifyflags)
+	/// method.
+	fn SetNotifyFlags(&self, flags: co::BG_NOTIFY) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.SetNotifyFlags)(self.ptr(), flags.0))
+		}
+	}
+
+	/// [`IBackgroundCopyJob::SetNotifyInterface`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-setnotifyinterface)
+	/// method.
+	///
+	/// Usually receives a
+	/// [`IBackgroundCopyCallback`](crate::IBackgroundCopyCallback) created
+	/// with [`BackgroundCopyCallback`](crate::BackgroundCopyCallback).
+	fn SetNotifyInterface(&self, callback: &impl ole_IUnknown) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.SetNotifyInterface)(self.ptr(), callback.ptr()))
+		}
+	}
+
+	/// [`IBackgroundCopyJob::SetPriority`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-setpriority)
+	/// method.
+	fn SetPriority(&self, priority: co::BG_JOB_PRIORITY) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.SetPriority)(self.ptr(), priority.0))
+		}
+	}
+
+	/// [`IBackgroundCopyJob::SetProxySettings`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-setproxysettings)
+	/// method.
+	fn SetProxySettings(&self,
+		usage: co::BG_JOB_PROXY_USAGE,
+		proxy_list: Option<&str>,
+		proxy_bypass_list: Option<&str>,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult(
+				(vt.SetProxySettings)(
+					self.ptr(),
+					usage.0,
+					WString::from_opt_str(proxy_list).as_ptr(),
+					WString::from_opt_str(proxy_bypass_list).as_ptr(),
+				),
+			)
+		}
+	}
+
+	/// [`IBackgroundCopyJob::Suspend`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-suspend)
+	/// method.
+	fn Suspend(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.Suspend)(self.ptr()))
+		}
+	}
+
+	/// [`IBackgroundCopyJob::TakeOwnership`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopyjob-takeownership)
+	/// method.
+	fn TakeOwnership(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyJobVT>();
+			ok_to_hrresult((vt.TakeOwnership)(self.ptr()))
+		}
+	}
+}
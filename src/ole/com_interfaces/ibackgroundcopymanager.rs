@@ -0,0 +1,144 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::{GUID, LANGID, WString};
+use crate::kernel::ffi_types::{HRES, PCSTR, PCVOID, PVOID};
+use crate::ole::decl::{
+	ComPtr, CoTaskMemFree, HrResult, IBackgroundCopyJob,
+	IEnumBackgroundCopyJobs,
+};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IBackgroundCopyManager`](crate::IBackgroundCopyManager) virtual table.
+#[repr(C)]
+pub struct IBackgroundCopyManagerVT {
+	pub IUnknownVT: IUnknownVT,
+	pub CreateJob: fn(ComPtr, PCSTR, u32, PVOID, *mut ComPtr) -> HRES,
+	pub GetJob: fn(ComPtr, PCVOID, *mut ComPtr) -> HRES,
+	pub EnumJobs: fn(ComPtr, u32, *mut ComPtr) -> HRES,
+	pub GetErrorDescription: fn(ComPtr, HRES, u32, *mut *mut u16) -> HRES,
+}
+
+com_interface! { IBackgroundCopyManager: "5ce34c0d-0dc9-4c1f-897c-daa1b78cee7c";
+	/// [`IBackgroundCopyManager`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nn-bits-ibackgroundcopymanager)
+	/// COM interface over
+	/// [`IBackgroundCopyManagerVT`](crate::vt::IBackgroundCopyManagerVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// Starting a background download:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, IBackgroundCopyManager};
+	///
+	/// let bits = CoCreateInstance::<IBackgroundCopyManager>(
+	///     &co::CLSID::BackgroundCopyManager,
+	///     None,
+	///     co::CLSCTX::LOCAL_SERVER,
+	/// )?;
+	///
+	/// let (_id, job) = bits.CreateJob("My download", co::BG_JOB_TYPE::DOWNLOAD)?;
+	/// job.AddFile("https://example.com/file.zip", "C:\\Temp\\file.zip")?;
+	/// job.Resume()?;
+	///
+	/// let progress = job.GetProgress()?;
+	/// println!("{} of {} bytes",
+	///     progress.BytesTransferred, progress.BytesTotal);
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl ole_IBackgroundCopyManager for IBackgroundCopyManager {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IBackgroundCopyManager`](crate::IBackgroundCopyManager).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IBackgroundCopyManager: ole_IUnknown {
+	/// [`IBackgroundCopyManager::CreateJob`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopymanager-createjob)
+	/// method.
+	///
+	/// Returns the ID of the new job, and the job itself, which is created in
+	/// suspended state.
+	#[must_use]
+	fn CreateJob(&self,
+		display_name: &str,
+		job_type: co::BG_JOB_TYPE,
+	) -> HrResult<(GUID, IBackgroundCopyJob)>
+	{
+		let mut id = GUID::new("00000000-0000-0000-0000-000000000000"); // just a placeholder
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IBackgroundCopyManagerVT>();
+			ok_to_hrresult(
+				(vt.CreateJob)(
+					self.ptr(),
+					WString::from_str(display_name).as_ptr(),
+					job_type.0,
+					&mut id as *mut _ as _,
+					&mut ppv_queried,
+				),
+			).map(|_| (id, IBackgroundCopyJob::from(ppv_queried)))
+		}
+	}
+
+	/// [`IBackgroundCopyManager::EnumJobs`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopymanager-enumjobs)
+	/// method.
+	#[must_use]
+	fn EnumJobs(&self,
+		flags: co::BG_JOB_ENUM,
+	) -> HrResult<IEnumBackgroundCopyJobs>
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IBackgroundCopyManagerVT>();
+			ok_to_hrresult((vt.EnumJobs)(self.ptr(), flags.0, &mut ppv_queried))
+				.map(|_| IEnumBackgroundCopyJobs::from(ppv_queried))
+		}
+	}
+
+	/// [`IBackgroundCopyManager::GetErrorDescription`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopymanager-geterrordescription)
+	/// method.
+	#[must_use]
+	fn GetErrorDescription(&self,
+		hr: co::HRESULT,
+		lang_id: LANGID,
+	) -> HrResult<String>
+	{
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IBackgroundCopyManagerVT>();
+			ok_to_hrresult(
+				(vt.GetErrorDescription)(self.ptr(), hr.0, lang_id.0 as _, &mut pstr),
+			)
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// [`IBackgroundCopyManager::GetJob`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopymanager-getjob)
+	/// method.
+	#[must_use]
+	fn GetJob(&self, job_id: &GUID) -> HrResult<IBackgroundCopyJob> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IBackgroundCopyManagerVT>();
+			ok_to_hrresult(
+				(vt.GetJob)(self.ptr(), job_id as *const _ as _, &mut ppv_queried),
+			).map(|_| IBackgroundCopyJob::from(ppv_queried))
+		}
+	}
+}
@@ -0,0 +1,89 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PVOID};
+use crate::ole::decl::{ComPtr, HrResult, IConnectionPointContainer};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IConnectionPoint`](crate::IConnectionPoint) virtual table.
+#[repr(C)]
+pub struct IConnectionPointVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetConnectionInterface: fn(ComPtr, PVOID) -> HRES,
+	pub GetConnectionPointContainer: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub Advise: fn(ComPtr, ComPtr, *mut u32) -> HRES,
+	pub Unadvise: fn(ComPtr, u32) -> HRES,
+	pub EnumConnections: fn(ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IConnectionPoint: "b196b286-bab4-101a-b69c-00aa00341d07";
+	/// [`IConnectionPoint`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nn-ocidl-iconnectionpoint)
+	/// COM interface over
+	/// [`IConnectionPointVT`](crate::vt::IConnectionPointVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl ole_IConnectionPoint for IConnectionPoint {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IConnectionPoint`](crate::IConnectionPoint).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IConnectionPoint: ole_IUnknown {
+	/// [`IConnectionPoint::Advise`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-advise)
+	/// method.
+	///
+	/// Returns the cookie to be passed to
+	/// [`Unadvise`](crate::prelude::ole_IConnectionPoint::Unadvise).
+	fn Advise(&self, sink: &impl ole_IUnknown) -> HrResult<u32> {
+		let mut cookie = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IConnectionPointVT>();
+			ok_to_hrresult((vt.Advise)(self.ptr(), sink.ptr(), &mut cookie))
+		}.map(|_| cookie)
+	}
+
+	/// [`IConnectionPoint::GetConnectionInterface`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-getconnectioninterface)
+	/// method.
+	#[must_use]
+	fn GetConnectionInterface(&self) -> HrResult<co::IID> {
+		let mut iid = co::IID::new("00000000-0000-0000-0000-000000000000"); // just a placeholder
+		unsafe {
+			let vt = self.vt_ref::<IConnectionPointVT>();
+			ok_to_hrresult(
+				(vt.GetConnectionInterface)(self.ptr(), &mut iid as *mut _ as _),
+			)
+		}.map(|_| iid)
+	}
+
+	/// [`IConnectionPoint::GetConnectionPointContainer`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-getconnectionpointcontainer)
+	/// method.
+	#[must_use]
+	fn GetConnectionPointContainer(&self) -> HrResult<IConnectionPointContainer> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IConnectionPointVT>();
+			ok_to_hrresult(
+				(vt.GetConnectionPointContainer)(self.ptr(), &mut ppv_queried),
+			).map(|_| IConnectionPointContainer::from(ppv_queried))
+		}
+	}
+
+	/// [`IConnectionPoint::Unadvise`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-unadvise)
+	/// method.
+	fn Unadvise(&self, cookie: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IConnectionPointVT>();
+			ok_to_hrresult((vt.Unadvise)(self.ptr(), cookie))
+		}
+	}
+}
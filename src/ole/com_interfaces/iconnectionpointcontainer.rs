@@ -0,0 +1,56 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PCVOID};
+use crate::ole::decl::{ComPtr, HrResult, IConnectionPoint};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IConnectionPointContainer`](crate::IConnectionPointContainer) virtual
+/// table.
+#[repr(C)]
+pub struct IConnectionPointContainerVT {
+	pub IUnknownVT: IUnknownVT,
+	pub EnumConnectionPoints: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub FindConnectionPoint: fn(ComPtr, PCVOID, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IConnectionPointContainer: "b196b284-bab4-101a-b69c-00aa00341d07";
+	/// [`IConnectionPointContainer`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nn-ocidl-iconnectionpointcontainer)
+	/// COM interface over
+	/// [`IConnectionPointContainerVT`](crate::vt::IConnectionPointContainerVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl ole_IConnectionPointContainer for IConnectionPointContainer {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IConnectionPointContainer`](crate::IConnectionPointContainer).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IConnectionPointContainer: ole_IUnknown {
+	/// [`IConnectionPointContainer::FindConnectionPoint`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpointcontainer-findconnectionpoint)
+	/// method.
+	#[must_use]
+	fn FindConnectionPoint(&self, iid: &co::IID) -> HrResult<IConnectionPoint> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IConnectionPointContainerVT>();
+			ok_to_hrresult(
+				(vt.FindConnectionPoint)(
+					self.ptr(),
+					iid as *const _ as _,
+					&mut ppv_queried,
+				),
+			).map(|_| IConnectionPoint::from(ppv_queried))
+		}
+	}
+}
@@ -1,7 +1,9 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
 use crate::kernel::ffi_types::{BOOL, HRES, PVOID};
-use crate::ole::decl::ComPtr;
+use crate::ole::decl::{ComPtr, FORMATETC, HrResult, STGMEDIUM};
+use crate::ole::guard::ReleaseStgMediumGuard;
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
 use crate::prelude::ole_IUnknown;
 use crate::vt::IUnknownVT;
 
@@ -40,5 +42,32 @@ impl ole_IDataObject for IDataObject {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait ole_IDataObject: ole_IUnknown {
+	/// [`IDataObject::GetData`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-idataobject-getdata)
+	/// method.
+	#[must_use]
+	fn GetData(&self, format_etc: &FORMATETC) -> HrResult<ReleaseStgMediumGuard> {
+		let mut stgm = STGMEDIUM::default();
+		ok_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IDataObjectVT>();
+				(vt.GetData)(
+					self.ptr(),
+					format_etc as *const _ as _,
+					&mut stgm as *mut _ as _,
+				)
+			},
+		).map(|_| unsafe { ReleaseStgMediumGuard::new(stgm) })
+	}
 
+	/// [`IDataObject::QueryGetData`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-idataobject-querygetdata)
+	/// method.
+	#[must_use]
+	fn QueryGetData(&self, format_etc: &FORMATETC) -> HrResult<bool> {
+		okfalse_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IDataObjectVT>();
+				(vt.QueryGetData)(self.ptr(), format_etc as *const _ as _)
+			},
+		)
+	}
 }
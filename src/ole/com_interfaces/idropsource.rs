@@ -0,0 +1,74 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{BOOL, HRES};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IDropSource`](crate::IDropSource) virtual table.
+#[repr(C)]
+pub struct IDropSourceVT {
+	pub IUnknownVT: IUnknownVT,
+	pub QueryContinueDrag: fn(ComPtr, BOOL, u32) -> HRES,
+	pub GiveFeedback: fn(ComPtr, u32) -> HRES,
+}
+
+com_interface! { IDropSource: "00000121-0000-0000-c000-000000000046";
+	/// [`IDropSource`](https://learn.microsoft.com/en-us/windows/win32/api/oleidl/nn-oleidl-idropsource)
+	/// COM interface over [`IDropSourceVT`](crate::vt::IDropSourceVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl ole_IDropSource for IDropSource {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IDropSource`](crate::IDropSource).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IDropSource: ole_IUnknown {
+	/// [`IDropSource::GiveFeedback`](https://learn.microsoft.com/en-us/windows/win32/api/oleidl/nf-oleidl-idropsource-givefeedback)
+	/// method.
+	///
+	/// Returns `S_OK` or `DRAGDROP_S_USEDEFAULTCURSORS`.
+	fn GiveFeedback(&self, effect: co::DROPEFFECT) -> HrResult<co::HRESULT> {
+		match co::HRESULT(
+			unsafe {
+				let vt = self.vt_ref::<IDropSourceVT>();
+				(vt.GiveFeedback)(self.ptr(), effect.0)
+			},
+		) {
+			hr @ (co::HRESULT::S_OK
+				| co::HRESULT::DRAGDROP_S_USEDEFAULTCURSORS) => Ok(hr),
+			hr => Err(hr),
+		}
+	}
+
+	/// [`IDropSource::QueryContinueDrag`](https://learn.microsoft.com/en-us/windows/win32/api/oleidl/nf-oleidl-idropsource-querycontinuedrag)
+	/// method.
+	///
+	/// Returns `S_OK`, `DRAGDROP_S_DROP` or `DRAGDROP_S_CANCEL`.
+	fn QueryContinueDrag(&self,
+		escape_pressed: bool, key_state: co::MK) -> HrResult<co::HRESULT>
+	{
+		match co::HRESULT(
+			unsafe {
+				let vt = self.vt_ref::<IDropSourceVT>();
+				(vt.QueryContinueDrag)(
+					self.ptr(), escape_pressed as _, key_state.0 as _)
+			},
+		) {
+			hr @ (co::HRESULT::S_OK
+				| co::HRESULT::DRAGDROP_S_DROP
+				| co::HRESULT::DRAGDROP_S_CANCEL) => Ok(hr),
+			hr => Err(hr),
+		}
+	}
+}
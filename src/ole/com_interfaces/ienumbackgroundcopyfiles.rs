@@ -0,0 +1,160 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult, IBackgroundCopyFile};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IEnumBackgroundCopyFiles`](crate::IEnumBackgroundCopyFiles) virtual table.
+#[repr(C)]
+pub struct IEnumBackgroundCopyFilesVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Next: fn(ComPtr, u32, *mut ComPtr, *mut u32) -> HRES,
+	pub Skip: fn(ComPtr, u32) -> HRES,
+	pub Reset: fn(ComPtr) -> HRES,
+	pub Clone: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub GetCount: fn(ComPtr, *mut u32) -> HRES,
+}
+
+com_interface! { IEnumBackgroundCopyFiles: "ca51e165-c365-424c-8d41-24aaa4ff3c40";
+	/// [`IEnumBackgroundCopyFiles`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nn-bits-ienumbackgroundcopyfiles)
+	/// COM interface over
+	/// [`IEnumBackgroundCopyFilesVT`](crate::vt::IEnumBackgroundCopyFilesVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl ole_IEnumBackgroundCopyFiles for IEnumBackgroundCopyFiles {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IEnumBackgroundCopyFiles`](crate::IEnumBackgroundCopyFiles).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IEnumBackgroundCopyFiles: ole_IUnknown {
+	/// Returns an iterator over the [`IBackgroundCopyFile`](crate::IBackgroundCopyFile) elements
+	/// which calls
+	/// [`IEnumBackgroundCopyFiles::Next`](crate::prelude::ole_IEnumBackgroundCopyFiles::Next)
+	/// internally.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IEnumBackgroundCopyFiles};
+	///
+	/// let files: IEnumBackgroundCopyFiles; // initialized somewhere
+	/// # let files = IEnumBackgroundCopyFiles::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// for file in files.iter() {
+	///     let file = file?;
+	///     println!("{}", file.GetRemoteName()?);
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<IBackgroundCopyFile>> + '_> {
+		Box::new(EnumBackgroundCopyFilesIter::new(self))
+	}
+
+	/// [`IEnumBackgroundCopyFiles::Clone`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ienumbackgroundcopyfiles-clone)
+	/// method.
+	#[must_use]
+	fn Clone(&self) -> HrResult<IEnumBackgroundCopyFiles> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumBackgroundCopyFilesVT>();
+			ok_to_hrresult((vt.Clone)(self.ptr(), &mut ppv_queried))
+				.map(|_| IEnumBackgroundCopyFiles::from(ppv_queried))
+		}
+	}
+
+	/// [`IEnumBackgroundCopyFiles::GetCount`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ienumbackgroundcopyfiles-getcount)
+	/// method.
+	#[must_use]
+	fn GetCount(&self) -> HrResult<u32> {
+		let mut count = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IEnumBackgroundCopyFilesVT>();
+			ok_to_hrresult((vt.GetCount)(self.ptr(), &mut count))
+		}.map(|_| count)
+	}
+
+	/// [`IEnumBackgroundCopyFiles::Next`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ienumbackgroundcopyfiles-next)
+	/// method.
+	///
+	/// Prefer using
+	/// [`IEnumBackgroundCopyFiles::iter`](crate::prelude::ole_IEnumBackgroundCopyFiles::iter),
+	/// which is simpler.
+	#[must_use]
+	fn Next(&self) -> HrResult<Option<IBackgroundCopyFile>> {
+		let mut fetched = u32::default();
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumBackgroundCopyFilesVT>();
+			match ok_to_hrresult(
+				(vt.Next)(self.ptr(), 1, &mut ppv_queried, &mut fetched), // retrieve only 1
+			) {
+				Ok(_) => Ok(Some(IBackgroundCopyFile::from(ppv_queried))),
+				Err(hr) => match hr {
+					co::HRESULT::S_FALSE => Ok(None), // no item found
+					hr => Err(hr), // actual error
+				},
+			}
+		}
+	}
+
+	/// [`IEnumBackgroundCopyFiles::Reset`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ienumbackgroundcopyfiles-reset)
+	/// method.
+	fn Reset(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumBackgroundCopyFilesVT>();
+			ok_to_hrresult((vt.Reset)(self.ptr()))
+		}
+	}
+
+	/// [`IEnumBackgroundCopyFiles::Skip`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ienumbackgroundcopyfiles-skip)
+	/// method.
+	fn Skip(&self, count: u32) -> HrResult<bool> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumBackgroundCopyFilesVT>();
+			okfalse_to_hrresult((vt.Skip)(self.ptr(), count))
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct EnumBackgroundCopyFilesIter<'a, I>
+	where I: ole_IEnumBackgroundCopyFiles,
+{
+	enumerator: &'a I,
+}
+
+impl<'a, I> Iterator for EnumBackgroundCopyFilesIter<'a, I>
+	where I: ole_IEnumBackgroundCopyFiles,
+{
+	type Item = HrResult<IBackgroundCopyFile>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.enumerator.Next() {
+			Err(err) => Some(Err(err)),
+			Ok(maybe_item) => maybe_item.map(Ok),
+		}
+	}
+}
+
+impl<'a, I> EnumBackgroundCopyFilesIter<'a, I>
+	where I: ole_IEnumBackgroundCopyFiles,
+{
+	fn new(enumerator: &'a I) -> Self {
+		Self { enumerator }
+	}
+}
@@ -0,0 +1,160 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult, IBackgroundCopyJob};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IEnumBackgroundCopyJobs`](crate::IEnumBackgroundCopyJobs) virtual table.
+#[repr(C)]
+pub struct IEnumBackgroundCopyJobsVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Next: fn(ComPtr, u32, *mut ComPtr, *mut u32) -> HRES,
+	pub Skip: fn(ComPtr, u32) -> HRES,
+	pub Reset: fn(ComPtr) -> HRES,
+	pub Clone: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub GetCount: fn(ComPtr, *mut u32) -> HRES,
+}
+
+com_interface! { IEnumBackgroundCopyJobs: "1af4f612-3b71-466f-8f58-7b6f73ac57ad";
+	/// [`IEnumBackgroundCopyJobs`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nn-bits-ienumbackgroundcopyjobs)
+	/// COM interface over
+	/// [`IEnumBackgroundCopyJobsVT`](crate::vt::IEnumBackgroundCopyJobsVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl ole_IEnumBackgroundCopyJobs for IEnumBackgroundCopyJobs {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IEnumBackgroundCopyJobs`](crate::IEnumBackgroundCopyJobs).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IEnumBackgroundCopyJobs: ole_IUnknown {
+	/// Returns an iterator over the [`IBackgroundCopyJob`](crate::IBackgroundCopyJob) elements
+	/// which calls
+	/// [`IEnumBackgroundCopyJobs::Next`](crate::prelude::ole_IEnumBackgroundCopyJobs::Next)
+	/// internally.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IEnumBackgroundCopyJobs};
+	///
+	/// let jobs: IEnumBackgroundCopyJobs; // initialized somewhere
+	/// # let jobs = IEnumBackgroundCopyJobs::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// for job in jobs.iter() {
+	///     let job = job?;
+	///     println!("{}", job.GetDisplayName()?);
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<IBackgroundCopyJob>> + '_> {
+		Box::new(EnumBackgroundCopyJobsIter::new(self))
+	}
+
+	/// [`IEnumBackgroundCopyJobs::Clone`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ienumbackgroundcopyjobs-clone)
+	/// method.
+	#[must_use]
+	fn Clone(&self) -> HrResult<IEnumBackgroundCopyJobs> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumBackgroundCopyJobsVT>();
+			ok_to_hrresult((vt.Clone)(self.ptr(), &mut ppv_queried))
+				.map(|_| IEnumBackgroundCopyJobs::from(ppv_queried))
+		}
+	}
+
+	/// [`IEnumBackgroundCopyJobs::GetCount`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ienumbackgroundcopyjobs-getcount)
+	/// method.
+	#[must_use]
+	fn GetCount(&self) -> HrResult<u32> {
+		let mut count = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IEnumBackgroundCopyJobsVT>();
+			ok_to_hrresult((vt.GetCount)(self.ptr(), &mut count))
+		}.map(|_| count)
+	}
+
+	/// [`IEnumBackgroundCopyJobs::Next`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ienumbackgroundcopyjobs-next)
+	/// method.
+	///
+	/// Prefer using
+	/// [`IEnumBackgroundCopyJobs::iter`](crate::prelude::ole_IEnumBackgroundCopyJobs::iter),
+	/// which is simpler.
+	#[must_use]
+	fn Next(&self) -> HrResult<Option<IBackgroundCopyJob>> {
+		let mut fetched = u32::default();
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumBackgroundCopyJobsVT>();
+			match ok_to_hrresult(
+				(vt.Next)(self.ptr(), 1, &mut ppv_queried, &mut fetched), // retrieve only 1
+			) {
+				Ok(_) => Ok(Some(IBackgroundCopyJob::from(ppv_queried))),
+				Err(hr) => match hr {
+					co::HRESULT::S_FALSE => Ok(None), // no item found
+					hr => Err(hr), // actual error
+				},
+			}
+		}
+	}
+
+	/// [`IEnumBackgroundCopyJobs::Reset`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ienumbackgroundcopyjobs-reset)
+	/// method.
+	fn Reset(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumBackgroundCopyJobsVT>();
+			ok_to_hrresult((vt.Reset)(self.ptr()))
+		}
+	}
+
+	/// [`IEnumBackgroundCopyJobs::Skip`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ienumbackgroundcopyjobs-skip)
+	/// method.
+	fn Skip(&self, count: u32) -> HrResult<bool> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumBackgroundCopyJobsVT>();
+			okfalse_to_hrresult((vt.Skip)(self.ptr(), count))
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct EnumBackgroundCopyJobsIter<'a, I>
+	where I: ole_IEnumBackgroundCopyJobs,
+{
+	enumerator: &'a I,
+}
+
+impl<'a, I> Iterator for EnumBackgroundCopyJobsIter<'a, I>
+	where I: ole_IEnumBackgroundCopyJobs,
+{
+	type Item = HrResult<IBackgroundCopyJob>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.enumerator.Next() {
+			Err(err) => Some(Err(err)),
+			Ok(maybe_item) => maybe_item.map(Ok),
+		}
+	}
+}
+
+impl<'a, I> EnumBackgroundCopyJobsIter<'a, I>
+	where I: ole_IEnumBackgroundCopyJobs,
+{
+	fn new(enumerator: &'a I) -> Self {
+		Self { enumerator }
+	}
+}
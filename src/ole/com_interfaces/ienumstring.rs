@@ -0,0 +1,151 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IEnumString`](crate::IEnumString) virtual table.
+#[repr(C)]
+pub struct IEnumStringVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Next: fn(ComPtr, u32, *mut *mut u16, *mut u32) -> HRES,
+	pub Skip: fn(ComPtr, u32) -> HRES,
+	pub Reset: fn(ComPtr) -> HRES,
+	pub Clone: fn(ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IEnumString: "00000101-0000-0000-c000-000000000046";
+	/// [`IEnumString`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nn-objidl-ienumstring)
+	/// COM interface over [`IEnumStringVT`](crate::vt::IEnumStringVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl ole_IEnumString for IEnumString {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IEnumString`](crate::IEnumString).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IEnumString: ole_IUnknown {
+	/// Returns an iterator over the `String` elements which calls
+	/// [`IEnumString::Next`](crate::prelude::ole_IEnumString::Next)
+	/// internally.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IEnumString};
+	///
+	/// let strs: IEnumString; // initialized somewhere
+	/// # let strs = IEnumString::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// for s in strs.iter() {
+	///     let s = s?;
+	///     println!("{}", s);
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<String>> + '_> {
+		Box::new(EnumStringIter::new(self))
+	}
+
+	/// [`IEnumString::Clone`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-ienumstring-clone)
+	/// method.
+	#[must_use]
+	fn Clone(&self) -> HrResult<IEnumString> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumStringVT>();
+			ok_to_hrresult((vt.Clone)(self.ptr(), &mut ppv_queried))
+				.map(|_| IEnumString::from(ppv_queried))
+		}
+	}
+
+	/// [`IEnumString::Next`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-ienumstring-next)
+	/// method.
+	///
+	/// Prefer using
+	/// [`IEnumString::iter`](crate::prelude::ole_IEnumString::iter), which is
+	/// simpler.
+	#[must_use]
+	fn Next(&self) -> HrResult<Option<String>> {
+		let mut fetched = u32::default();
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IEnumStringVT>();
+			match ok_to_hrresult(
+				(vt.Next)(self.ptr(), 1, &mut pstr, &mut fetched), // retrieve only 1
+			) {
+				Ok(_) => {
+					let text = WString::from_wchars_nullt(pstr);
+					CoTaskMemFree(pstr as _);
+					Ok(Some(text.to_string()))
+				},
+				Err(hr) => match hr {
+					co::HRESULT::S_FALSE => Ok(None), // no item found
+					hr => Err(hr), // actual error
+				},
+			}
+		}
+	}
+
+	/// [`IEnumString::Reset`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-ienumstring-reset)
+	/// method.
+	fn Reset(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumStringVT>();
+			ok_to_hrresult((vt.Reset)(self.ptr()))
+		}
+	}
+
+	/// [`IEnumString::Skip`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-ienumstring-skip)
+	/// method.
+	fn Skip(&self, count: u32) -> HrResult<bool> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumStringVT>();
+			okfalse_to_hrresult((vt.Skip)(self.ptr(), count))
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct EnumStringIter<'a, I>
+	where I: ole_IEnumString,
+{
+	enum_str: &'a I,
+}
+
+impl<'a, I> Iterator for EnumStringIter<'a, I>
+	where I: ole_IEnumString,
+{
+	type Item = HrResult<String>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.enum_str.Next() {
+			Err(err) => Some(Err(err)),
+			Ok(maybe_str) => maybe_str.map(Ok),
+		}
+	}
+}
+
+impl<'a, I> EnumStringIter<'a, I>
+	where I: ole_IEnumString,
+{
+	fn new(enum_str: &'a I) -> Self {
+		Self { enum_str }
+	}
+}
@@ -0,0 +1,138 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{BOOL, HRES, PCSTR};
+use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::prelude::ole_IPersist;
+use crate::vt::IPersistVT;
+
+/// [`IPersistFile`](crate::IPersistFile) virtual table.
+#[repr(C)]
+pub struct IPersistFileVT {
+	pub IPersistVT: IPersistVT,
+	pub IsDirty: fn(ComPtr) -> HRES,
+	pub Load: fn(ComPtr, PCSTR, u32) -> HRES,
+	pub Save: fn(ComPtr, PCSTR, BOOL) -> HRES,
+	pub SaveCompleted: fn(ComPtr, PCSTR) -> HRES,
+	pub GetCurFile: fn(ComPtr, *mut *mut u16) -> HRES,
+}
+
+com_interface! { IPersistFile: "0000010b-0000-0000-c000-000000000046";
+	/// [`IPersistFile`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nn-objidl-ipersistfile)
+	/// COM interface over [`IPersistFileVT`](crate::vt::IPersistFileVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// Creating a `.lnk` shortcut file:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, IPersistFile, IShellLink};
+	///
+	/// let link = CoCreateInstance::<IShellLink>(
+	///     &co::CLSID::ShellLink,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// link.SetPath("C:\\Temp\\foo.exe")?;
+	/// link.SetArguments("--bar")?;
+	///
+	/// let file = link.QueryInterface::<IPersistFile>()?;
+	/// file.Save(Some("C:\\Temp\\foo.lnk"), true)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl ole_IPersist for IPersistFile {}
+impl ole_IPersistFile for IPersistFile {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IPersistFile`](crate::IPersistFile).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IPersistFile: ole_IPersist {
+	/// [`IPersistFile::GetCurFile`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-ipersistfile-getcurfile)
+	/// method.
+	#[must_use]
+	fn GetCurFile(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		ok_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IPersistFileVT>();
+				(vt.GetCurFile)(self.ptr(), &mut pstr)
+			},
+		).map(|_| {
+			let name = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			name.to_string()
+		})
+	}
+
+	/// [`IPersistFile::IsDirty`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-ipersistfile-isdirty)
+	/// method.
+	#[must_use]
+	fn IsDirty(&self) -> HrResult<bool> {
+		okfalse_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IPersistFileVT>();
+				(vt.IsDirty)(self.ptr())
+			},
+		)
+	}
+
+	/// [`IPersistFile::Load`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-ipersistfile-load)
+	/// method.
+	fn Load(&self, file_name: &str, mode: co::STGM) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IPersistFileVT>();
+				(vt.Load)(
+					self.ptr(),
+					WString::from_str(file_name).as_ptr(),
+					mode.0,
+				)
+			},
+		)
+	}
+
+	/// [`IPersistFile::Save`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-ipersistfile-save)
+	/// method.
+	///
+	/// If `file_name` is `None`, the object is saved to its current file.
+	fn Save(&self, file_name: Option<&str>, remember: bool) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IPersistFileVT>();
+				(vt.Save)(
+					self.ptr(),
+					WString::from_opt_str(file_name).as_ptr(),
+					remember as _,
+				)
+			},
+		)
+	}
+
+	/// [`IPersistFile::SaveCompleted`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/nf-objidl-ipersistfile-savecompleted)
+	/// method.
+	fn SaveCompleted(&self, file_name: &str) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IPersistFileVT>();
+				(vt.SaveCompleted)(
+					self.ptr(),
+					WString::from_str(file_name).as_ptr(),
+				)
+			},
+		)
+	}
+}
@@ -1,8 +1,20 @@
+mod ibackgroundcopycallback;
+mod ibackgroundcopyerror;
+mod ibackgroundcopyfile;
+mod ibackgroundcopyjob;
+mod ibackgroundcopymanager;
 mod ibindctx;
+mod iconnectionpoint;
+mod iconnectionpointcontainer;
 mod idataobject;
+mod idropsource;
 mod idroptarget;
+mod ienumbackgroundcopyfiles;
+mod ienumbackgroundcopyjobs;
+mod ienumstring;
 mod imoniker;
 mod ipersist;
+mod ipersistfile;
 mod ipersiststream;
 mod ipicture;
 mod isequentialstream;
@@ -10,11 +22,23 @@ mod istream;
 mod iunknown;
 
 pub mod decl {
+	pub use super::ibackgroundcopycallback::IBackgroundCopyCallback;
+	pub use super::ibackgroundcopyerror::IBackgroundCopyError;
+	pub use super::ibackgroundcopyfile::IBackgroundCopyFile;
+	pub use super::ibackgroundcopyjob::IBackgroundCopyJob;
+	pub use super::ibackgroundcopymanager::IBackgroundCopyManager;
 	pub use super::ibindctx::IBindCtx;
+	pub use super::iconnectionpoint::IConnectionPoint;
+	pub use super::iconnectionpointcontainer::IConnectionPointContainer;
 	pub use super::idataobject::IDataObject;
+	pub use super::idropsource::IDropSource;
 	pub use super::idroptarget::IDropTarget;
+	pub use super::ienumbackgroundcopyfiles::IEnumBackgroundCopyFiles;
+	pub use super::ienumbackgroundcopyjobs::IEnumBackgroundCopyJobs;
+	pub use super::ienumstring::IEnumString;
 	pub use super::imoniker::IMoniker;
 	pub use super::ipersist::IPersist;
+	pub use super::ipersistfile::IPersistFile;
 	pub use super::ipersiststream::IPersistStream;
 	pub use super::ipicture::IPicture;
 	pub use super::isequentialstream::ISequentialStream;
@@ -23,11 +47,23 @@ pub mod decl {
 }
 
 pub mod traits {
+	pub use super::ibackgroundcopycallback::ole_IBackgroundCopyCallback;
+	pub use super::ibackgroundcopyerror::ole_IBackgroundCopyError;
+	pub use super::ibackgroundcopyfile::ole_IBackgroundCopyFile;
+	pub use super::ibackgroundcopyjob::ole_IBackgroundCopyJob;
+	pub use super::ibackgroundcopymanager::ole_IBackgroundCopyManager;
 	pub use super::ibindctx::ole_IBindCtx;
+	pub use super::iconnectionpoint::ole_IConnectionPoint;
+	pub use super::iconnectionpointcontainer::ole_IConnectionPointContainer;
 	pub use super::idataobject::ole_IDataObject;
+	pub use super::idropsource::ole_IDropSource;
 	pub use super::idroptarget::ole_IDropTarget;
+	pub use super::ienumbackgroundcopyfiles::ole_IEnumBackgroundCopyFiles;
+	pub use super::ienumbackgroundcopyjobs::ole_IEnumBackgroundCopyJobs;
+	pub use super::ienumstring::ole_IEnumString;
 	pub use super::imoniker::ole_IMoniker;
 	pub use super::ipersist::ole_IPersist;
+	pub use super::ipersistfile::ole_IPersistFile;
 	pub use super::ipersiststream::ole_IPersistStream;
 	pub use super::ipicture::ole_IPicture;
 	pub use super::isequentialstream::ole_ISequentialStream;
@@ -36,11 +72,23 @@ pub mod traits {
 }
 
 pub mod vt {
+	pub use super::ibackgroundcopycallback::IBackgroundCopyCallbackVT;
+	pub use super::ibackgroundcopyerror::IBackgroundCopyErrorVT;
+	pub use super::ibackgroundcopyfile::IBackgroundCopyFileVT;
+	pub use super::ibackgroundcopyjob::IBackgroundCopyJobVT;
+	pub use super::ibackgroundcopymanager::IBackgroundCopyManagerVT;
 	pub use super::ibindctx::IBindCtxVT;
+	pub use super::iconnectionpoint::IConnectionPointVT;
+	pub use super::iconnectionpointcontainer::IConnectionPointContainerVT;
 	pub use super::idataobject::IDataObjectVT;
+	pub use super::idropsource::IDropSourceVT;
 	pub use super::idroptarget::IDropTargetVT;
+	pub use super::ienumbackgroundcopyfiles::IEnumBackgroundCopyFilesVT;
+	pub use super::ienumbackgroundcopyjobs::IEnumBackgroundCopyJobsVT;
+	pub use super::ienumstring::IEnumStringVT;
 	pub use super::imoniker::IMonikerVT;
 	pub use super::ipersist::IPersistVT;
+	pub use super::ipersistfile::IPersistFileVT;
 	pub use super::ipersiststream::IPersistStreamVT;
 	pub use super::ipicture::IPictureVT;
 	pub use super::isequentialstream::ISequentialStreamVT;
@@ -1,5 +1,14 @@
 use crate::kernel::ffi_types::{BOOL, HANDLE, HRES, PCSTR, PCVOID, PSTR, PVOID};
 
+extern_sys! { "kernel32";
+	WerGetFlags(HANDLE, *mut u32) -> HRES
+	WerRegisterFile(PCSTR, u32, u32) -> HRES
+	WerRegisterMemoryBlock(PVOID, u32) -> HRES
+	WerSetFlags(u32) -> HRES
+	WerUnregisterFile(PCSTR) -> HRES
+	WerUnregisterMemoryBlock(PVOID) -> HRES
+}
+
 extern_sys! { "ole32";
 	CLSIDFromProgID(PCSTR, PVOID) -> HRES
 	CLSIDFromProgIDEx(PCSTR, PVOID) -> HRES
@@ -7,7 +16,9 @@ extern_sys! { "ole32";
 	CoCreateInstance(PCVOID, PVOID, u32, PCVOID, *mut PVOID) -> HRES
 	CoCreateInstanceEx(PCVOID, PVOID, u32, PCVOID, u32, PVOID) -> HRES
 	CoInitializeEx(PVOID, u32) -> HRES
+	CoInitializeSecurity(PVOID, i32, PVOID, PVOID, u32, u32, PVOID, u32, PVOID) -> HRES
 	CoLockObjectExternal(PVOID, BOOL, BOOL) -> HRES
+	CoSetProxyBlanket(PVOID, u32, u32, PCSTR, u32, u32, PVOID, u32) -> HRES
 	CoTaskMemAlloc(usize) -> PVOID
 	CoTaskMemFree(PVOID)
 	CoTaskMemRealloc(PVOID, usize) -> PVOID
@@ -17,7 +28,24 @@ extern_sys! { "ole32";
 	CreateItemMoniker(PCSTR, PCSTR, *mut PVOID) -> HRES
 	CreateObjrefMoniker(PVOID, *mut PVOID) -> HRES
 	CreatePointerMoniker(PVOID, *mut PVOID) -> HRES
+	DoDragDrop(PVOID, PVOID, u32, *mut u32) -> HRES
+	OleInitialize(PVOID) -> HRES
+	OleUninitialize()
 	RegisterDragDrop(HANDLE, PVOID) -> HRES
+	ReleaseStgMedium(PVOID)
 	RevokeDragDrop(HANDLE) -> HRES
 	StringFromCLSID(PCVOID, *mut PSTR) -> HRES
 }
+
+extern_sys_delay! { "combase";
+	RoActivateInstance(PVOID, *mut PVOID) -> HRES
+	RoGetActivationFactory(PVOID, PCVOID, *mut PVOID) -> HRES
+	WindowsCreateString(PCSTR, u32, *mut PVOID) -> HRES
+	WindowsDeleteString(PVOID) -> HRES
+}
+
+extern_sys! { "userenv";
+	CreateAppContainerProfile(PCSTR, PCSTR, PCSTR, PCVOID, u32, *mut PVOID) -> HRES
+	DeleteAppContainerProfile(PCSTR) -> HRES
+	DeriveAppContainerSidFromAppContainerName(PCSTR, *mut PVOID) -> HRES
+}
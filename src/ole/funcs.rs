@@ -1,13 +1,20 @@
 #![allow(non_snake_case)]
 
 use crate::{co, ole};
-use crate::kernel::decl::WString;
+use crate::kernel::decl::{
+	HPROCESS, SECURITY_DESCRIPTOR, SID_AND_ATTRIBUTES, WString,
+};
+use crate::kernel::guard::FreeSidGuard;
 use crate::ole::decl::{
 	ComPtr, COSERVERINFO, HrResult, IMoniker, IUnknown, MULTI_QI,
 };
-use crate::ole::guard::CoUninitializeGuard;
+use crate::ole::guard::{
+	CoUninitializeGuard, OleUninitializeGuard, WerUnregisterMemoryBlockGuard,
+};
 use crate::ole::privs::ok_to_hrresult;
-use crate::prelude::ole_IUnknown;
+use crate::prelude::{
+	Handle, ole_IDataObject, ole_IDropSource, ole_IUnknown,
+};
 
 /// [`CLSIDFromProgID`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-clsidfromprogid)
 /// function.
@@ -177,6 +184,54 @@ pub fn CoInitializeEx(coinit: co::COINIT) -> HrResult<CoUninitializeGuard> {
 	}
 }
 
+/// [`CoInitializeSecurity`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-coinitializesecurity)
+/// function.
+///
+/// Must be called once per process, after
+/// [`CoInitializeEx`](crate::CoInitializeEx) and before any interface is
+/// marshaled. The authentication services are negotiated by COM, and no
+/// authentication list is passed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let _com_guard = w::CoInitializeEx(
+///     co::COINIT::MULTITHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+///
+/// w::CoInitializeSecurity(
+///     None,
+///     co::RPC_C_AUTHN_LEVEL::DEFAULT,
+///     co::RPC_C_IMP_LEVEL::IMPERSONATE,
+///     co::EOAC::NONE,
+/// )?;
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+pub fn CoInitializeSecurity(
+	sec_desc: Option<&SECURITY_DESCRIPTOR>,
+	authn_level: co::RPC_C_AUTHN_LEVEL,
+	imp_level: co::RPC_C_IMP_LEVEL,
+	capabilities: co::EOAC,
+) -> HrResult<()>
+{
+	ok_to_hrresult(
+		unsafe {
+			ole::ffi::CoInitializeSecurity(
+				sec_desc.map_or(std::ptr::null_mut(), |sd| sd as *const _ as _),
+				-1,
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+				authn_level.0,
+				imp_level.0,
+				std::ptr::null_mut(),
+				capabilities.0,
+				std::ptr::null_mut(),
+			)
+		},
+	)
+}
+
 /// [`CoLockObjectExternal`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-colockobjectexternal)
 /// function.
 ///
@@ -199,6 +254,36 @@ pub fn CoLockObjectExternal(
 	)
 }
 
+/// [`CoSetProxyBlanket`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cosetproxyblanket)
+/// function.
+///
+/// The authentication information is taken from the current process token.
+pub fn CoSetProxyBlanket(
+	proxy: &impl ole_IUnknown,
+	authn_svc: co::RPC_C_AUTHN,
+	authz_svc: co::RPC_C_AUTHZ,
+	server_princ_name: Option<&str>,
+	authn_level: co::RPC_C_AUTHN_LEVEL,
+	imp_level: co::RPC_C_IMP_LEVEL,
+	capabilities: co::EOAC,
+) -> HrResult<()>
+{
+	ok_to_hrresult(
+		unsafe {
+			ole::ffi::CoSetProxyBlanket(
+				proxy.ptr().0 as _,
+				authn_svc.0,
+				authz_svc.0,
+				WString::from_opt_str(server_princ_name).as_ptr(),
+				authn_level.0,
+				imp_level.0,
+				std::ptr::null_mut(),
+				capabilities.0,
+			)
+		},
+	)
+}
+
 /// [`CoTaskMemAlloc`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cotaskmemalloc)
 /// function.
 ///
@@ -239,6 +324,33 @@ pub unsafe fn CoTaskMemRealloc(pv: *mut u8, cb: usize) -> HrResult<*mut u8> {
 	}
 }
 
+/// [`CreateAppContainerProfile`](https://learn.microsoft.com/en-us/windows/win32/api/userenv/nf-userenv-createappcontainerprofile)
+/// function.
+///
+/// Returns the SID of the new AppContainer.
+#[must_use]
+pub fn CreateAppContainerProfile(
+	container_name: &str,
+	display_name: &str,
+	description: &str,
+	capabilities: Option<&[SID_AND_ATTRIBUTES]>,
+) -> HrResult<FreeSidGuard>
+{
+	let mut psid = std::ptr::null_mut() as *mut std::ffi::c_void;
+	unsafe {
+		ok_to_hrresult(
+			ole::ffi::CreateAppContainerProfile(
+				WString::from_str(container_name).as_ptr(),
+				WString::from_str(display_name).as_ptr(),
+				WString::from_str(description).as_ptr(),
+				capabilities.map_or(std::ptr::null(), |c| c.as_ptr() as _),
+				capabilities.map_or(0, |c| c.len() as _),
+				&mut psid,
+			),
+		).map(|_| FreeSidGuard::new(psid as _))
+	}
+}
+
 /// [`CreateClassMoniker`](https://learn.microsoft.com/en-us/windows/win32/api/objbase/nf-objbase-createclassmoniker)
 /// function.
 #[must_use]
@@ -315,6 +427,97 @@ pub fn CreatePointerMoniker(unk: &impl ole_IUnknown) -> HrResult<IMoniker> {
 	}
 }
 
+/// [`DeleteAppContainerProfile`](https://learn.microsoft.com/en-us/windows/win32/api/userenv/nf-userenv-deleteappcontainerprofile)
+/// function.
+pub fn DeleteAppContainerProfile(container_name: &str) -> HrResult<()> {
+	ok_to_hrresult(
+		unsafe {
+			ole::ffi::DeleteAppContainerProfile(
+				WString::from_str(container_name).as_ptr(),
+			)
+		},
+	)
+}
+
+/// [`DeriveAppContainerSidFromAppContainerName`](https://learn.microsoft.com/en-us/windows/win32/api/userenv/nf-userenv-deriveappcontainersidfromappcontainername)
+/// function.
+#[must_use]
+pub fn DeriveAppContainerSidFromAppContainerName(
+	container_name: &str) -> HrResult<FreeSidGuard>
+{
+	let mut psid = std::ptr::null_mut() as *mut std::ffi::c_void;
+	unsafe {
+		ok_to_hrresult(
+			ole::ffi::DeriveAppContainerSidFromAppContainerName(
+				WString::from_str(container_name).as_ptr(),
+				&mut psid,
+			),
+		).map(|_| FreeSidGuard::new(psid as _))
+	}
+}
+
+/// [`DoDragDrop`](https://learn.microsoft.com/en-us/windows/win32/api/ole2/nf-ole2-dodragdrop)
+/// function.
+///
+/// Blocks until the drag operation finishes, returning the effect performed by
+/// the drop target, or `None` if the operation was cancelled.
+///
+/// Prefer using [`DataObject::drag`](crate::DataObject::drag), which builds the
+/// data object and the drop source.
+pub fn DoDragDrop(
+	data_obj: &impl ole_IDataObject,
+	drop_source: &impl ole_IDropSource,
+	ok_effects: co::DROPEFFECT,
+) -> HrResult<Option<co::DROPEFFECT>>
+{
+	let mut effect = co::DROPEFFECT::default();
+	match co::HRESULT(
+		unsafe {
+			ole::ffi::DoDragDrop(
+				data_obj.ptr().0 as _,
+				drop_source.ptr().0 as _,
+				ok_effects.0,
+				&mut effect.0,
+			)
+		},
+	) {
+		co::HRESULT::DRAGDROP_S_DROP => Ok(Some(effect)),
+		co::HRESULT::DRAGDROP_S_CANCEL => Ok(None),
+		hr => Err(hr),
+	}
+}
+
+/// [`OleInitialize`](https://learn.microsoft.com/en-us/windows/win32/api/ole2/nf-ole2-oleinitialize)
+/// function.
+///
+/// Initializes COM in a single-threaded apartment, along with OLE features like
+/// clipboard and drag and drop. Returns an
+/// [`OleUninitializeGuard`](crate::guard::OleUninitializeGuard), which
+/// automatically calls `OleUninitialize` when the guard goes out of scope.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, OleInitialize};
+///
+/// let _ole_lib = OleInitialize()?; // keep guard alive
+///
+/// // program runs...
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+#[must_use]
+pub fn OleInitialize() -> HrResult<OleUninitializeGuard> {
+	unsafe {
+		let hr = co::HRESULT(ole::ffi::OleInitialize(std::ptr::null_mut()));
+		match hr {
+			co::HRESULT::S_OK
+			| co::HRESULT::S_FALSE => Ok(OleUninitializeGuard::new(hr)),
+			hr => Err(hr),
+		}
+	}
+}
+
 /// [`StringFromCLSID`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-stringfromclsid)
 /// function.
 #[must_use]
@@ -328,3 +531,98 @@ pub fn StringFromCLSID(clsid: &co::CLSID) -> HrResult<String> {
 		name.to_string()
 	})
 }
+
+/// [`WerGetFlags`](https://learn.microsoft.com/en-us/windows/win32/api/werapi/nf-werapi-wergetflags)
+/// function.
+#[must_use]
+pub fn WerGetFlags(hprocess: &HPROCESS) -> HrResult<co::WER_FAULT_REPORTING> {
+	let mut flags = co::WER_FAULT_REPORTING::default();
+	ok_to_hrresult(
+		unsafe { ole::ffi::WerGetFlags(hprocess.as_ptr(), &mut flags.0) },
+	).map(|_| flags)
+}
+
+/// [`WerRegisterFile`](https://learn.microsoft.com/en-us/windows/win32/api/werapi/nf-werapi-werregisterfile)
+/// function.
+///
+/// The file will be added to the error report when the process crashes. It
+/// can be removed with [`WerUnregisterFile`](crate::WerUnregisterFile).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, WerRegisterFile};
+///
+/// WerRegisterFile(
+///     "C:\\Temp\\app.log",
+///     co::WER_REGFILE_TYPE::OTHER,
+///     co::WER_FILE::ANONYMOUS_DATA,
+/// )?;
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+pub fn WerRegisterFile(
+	file: &str,
+	reg_file_type: co::WER_REGFILE_TYPE,
+	flags: co::WER_FILE,
+) -> HrResult<()>
+{
+	ok_to_hrresult(
+		unsafe {
+			ole::ffi::WerRegisterFile(
+				WString::from_str(file).as_ptr(),
+				reg_file_type.0,
+				flags.0,
+			)
+		},
+	)
+}
+
+/// [`WerRegisterMemoryBlock`](https://learn.microsoft.com/en-us/windows/win32/api/werapi/nf-werapi-werregistermemoryblock)
+/// function.
+///
+/// The contents of the block, at the time of the crash, will be added to the
+/// error report. The block is unregistered when the returned
+/// [`WerUnregisterMemoryBlockGuard`](crate::guard::WerUnregisterMemoryBlockGuard)
+/// goes out of scope.
+///
+/// The block must not be larger than 64 KB.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, WerRegisterMemoryBlock};
+///
+/// let app_state = [0u8; 256];
+/// let _wer_block = WerRegisterMemoryBlock(&app_state)?; // keep guard alive
+///
+/// // program runs...
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+#[must_use]
+pub fn WerRegisterMemoryBlock(
+	block: &[u8]) -> HrResult<WerUnregisterMemoryBlockGuard<'_>>
+{
+	unsafe {
+		ok_to_hrresult(
+			ole::ffi::WerRegisterMemoryBlock(block.as_ptr() as _, block.len() as _),
+		).map(|_| WerUnregisterMemoryBlockGuard::new(block.as_ptr() as _))
+	}
+}
+
+/// [`WerSetFlags`](https://learn.microsoft.com/en-us/windows/win32/api/werapi/nf-werapi-wersetflags)
+/// function.
+pub fn WerSetFlags(flags: co::WER_FAULT_REPORTING) -> HrResult<()> {
+	ok_to_hrresult(unsafe { ole::ffi::WerSetFlags(flags.0) })
+}
+
+/// [`WerUnregisterFile`](https://learn.microsoft.com/en-us/windows/win32/api/werapi/nf-werapi-werunregisterfile)
+/// function.
+pub fn WerUnregisterFile(file: &str) -> HrResult<()> {
+	ok_to_hrresult(
+		unsafe {
+			ole::ffi::WerUnregisterFile(WString::from_str(file).as_ptr())
+		},
+	)
+}
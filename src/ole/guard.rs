@@ -1,5 +1,9 @@
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
 use crate::co;
 use crate::ole;
+use crate::ole::decl::STGMEDIUM;
 
 /// RAII implementation which automatically calls
 /// [`CoUninitialize`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-couninitialize)
@@ -37,3 +41,135 @@ impl CoUninitializeGuard {
 		self.hr
 	}
 }
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation which automatically calls
+/// [`OleUninitialize`](https://learn.microsoft.com/en-us/windows/win32/api/ole2/nf-ole2-oleuninitialize)
+/// when the object goes out of scope.
+pub struct OleUninitializeGuard {
+	hr: co::HRESULT,
+}
+
+impl Drop for OleUninitializeGuard {
+	fn drop(&mut self) {
+		unsafe { ole::ffi::OleUninitialize() }
+	}
+}
+
+impl OleUninitializeGuard {
+	/// Constructs the guard by taking ownership of the code.
+	/// 
+	/// # Safety
+	/// 
+	/// Be sure you need to call
+	/// [`OleUninitialize`](https://learn.microsoft.com/en-us/windows/win32/api/ole2/nf-ole2-oleuninitialize)
+	/// at the end of scope.
+	/// 
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(hr: co::HRESULT) -> Self {
+		Self { hr }
+	}
+
+	/// Returns the informational success code returned by
+	/// [`OleInitialize`](crate::OleInitialize).
+	#[must_use]
+	pub const fn hr(&self) -> co::HRESULT {
+		self.hr
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for [`STGMEDIUM`](crate::STGMEDIUM) which automatically
+/// calls
+/// [`ReleaseStgMedium`](https://learn.microsoft.com/en-us/windows/win32/api/ole2/nf-ole2-releasestgmedium)
+/// when the object goes out of scope.
+pub struct ReleaseStgMediumGuard {
+	stgm: STGMEDIUM,
+}
+
+impl Drop for ReleaseStgMediumGuard {
+	fn drop(&mut self) {
+		if self.stgm.tymed != co::TYMED::NULL {
+			unsafe { ole::ffi::ReleaseStgMedium(&mut self.stgm as *mut _ as _); }
+		}
+	}
+}
+
+impl Deref for ReleaseStgMediumGuard {
+	type Target = STGMEDIUM;
+
+	fn deref(&self) -> &Self::Target {
+		&self.stgm
+	}
+}
+
+impl DerefMut for ReleaseStgMediumGuard {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.stgm
+	}
+}
+
+impl ReleaseStgMediumGuard {
+	/// Constructs the guard by taking ownership of the struct.
+	/// 
+	/// # Safety
+	/// 
+	/// Be sure the struct must be freed with
+	/// [`ReleaseStgMedium`](https://learn.microsoft.com/en-us/windows/win32/api/ole2/nf-ole2-releasestgmedium)
+	/// at the end of scope.
+	/// 
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(stgm: STGMEDIUM) -> Self {
+		Self { stgm }
+	}
+
+	/// Ejects the underlying struct, leaving
+	/// [`STGMEDIUM::default`](crate::STGMEDIUM::default) in its place.
+	///
+	/// Since the internal struct will be invalidated, the destructor will not
+	/// run. It's your responsibility to run it, otherwise you'll cause a
+	/// resource leak.
+	#[must_use]
+	pub fn leak(&mut self) -> STGMEDIUM {
+		std::mem::take(&mut self.stgm)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation which automatically calls
+/// [`WerUnregisterMemoryBlock`](https://learn.microsoft.com/en-us/windows/win32/api/werapi/nf-werapi-werunregistermemoryblock)
+/// when the object goes out of scope.
+pub struct WerUnregisterMemoryBlockGuard<'a> {
+	ptr: *mut u8,
+	_block: PhantomData<&'a [u8]>,
+}
+
+impl<'a> Drop for WerUnregisterMemoryBlockGuard<'a> {
+	fn drop(&mut self) {
+		unsafe { ole::ffi::WerUnregisterMemoryBlock(self.ptr as _); }
+	}
+}
+
+impl<'a> WerUnregisterMemoryBlockGuard<'a> {
+	/// Constructs the guard by taking ownership of the registered block.
+	/// 
+	/// # Safety
+	/// 
+	/// Be sure you need to call
+	/// [`WerUnregisterMemoryBlock`](https://learn.microsoft.com/en-us/windows/win32/api/werapi/nf-werapi-werunregistermemoryblock)
+	/// at the end of scope.
+	/// 
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(ptr: *mut u8) -> Self {
+		Self { ptr, _block: PhantomData }
+	}
+}
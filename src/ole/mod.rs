@@ -10,12 +10,14 @@ mod com_interfaces;
 mod funcs;
 mod handles;
 mod structs;
+mod utilities;
 
 pub mod decl {
 	pub use super::aliases::*;
 	pub use super::com_interfaces::decl::*;
 	pub use super::funcs::*;
 	pub use super::structs::decl::*;
+	pub use super::utilities::*;
 }
 
 pub mod traits {
@@ -1,6 +1,10 @@
-use crate::co;
-use crate::kernel::ffi_types::HRES;
-use crate::ole::decl::HrResult;
+use std::panic::AssertUnwindSafe;
+
+use crate::{co, ole};
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{HRES, PVOID};
+use crate::ole::decl::{ComPtr, HrResult, IUnknown};
+use crate::prelude::ole_IUnknown;
 
 /// If value is `S_OK` yields `Ok()`, othersize `Err(hresult)`.
 pub(crate) const fn ok_to_hrresult(hr: HRES) -> HrResult<()> {
@@ -19,3 +23,74 @@ pub(crate) const fn okfalse_to_hrresult(hr: HRES) -> HrResult<bool> {
 		hr => Err(hr),
 	}
 }
+
+/// Runs the body of a COM method implemented in Rust, catching any panic,
+/// which cannot unwind across the COM boundary. A panic yields
+/// `E_UNEXPECTED`.
+pub(crate) fn catch_com_panic<F>(func: F) -> HRES
+	where F: FnOnce() -> HRES,
+{
+	std::panic::catch_unwind(AssertUnwindSafe(func))
+		.unwrap_or(co::HRESULT::E_UNEXPECTED.0)
+}
+
+/// A WinRT `HSTRING`, created with `WindowsCreateString` and freed with
+/// `WindowsDeleteString` when dropped.
+pub(crate) struct HString(PVOID);
+
+impl Drop for HString {
+	fn drop(&mut self) {
+		if !self.0.is_null() {
+			if let Ok(func) = ole::ffi::WindowsDeleteString() {
+				unsafe { func(self.0); }
+			}
+		}
+	}
+}
+
+impl HString {
+	/// Creates a new `HSTRING`; fails with `E_NOTIMPL` if WinRT is not
+	/// available, which happens before Windows 8.
+	pub(crate) fn new(s: &str) -> HrResult<Self> {
+		let func = ole::ffi::WindowsCreateString()
+			.map_err(|_| co::HRESULT::E_NOTIMPL)?;
+		let wstr = WString::from_str(s);
+		let mut hstr: PVOID = std::ptr::null_mut();
+		ok_to_hrresult(
+			unsafe { func(wstr.as_ptr(), wstr.str_len() as _, &mut hstr) },
+		).map(|_| Self(hstr))
+	}
+
+	pub(crate) const fn as_ptr(&self) -> PVOID {
+		self.0
+	}
+}
+
+/// Calls `RoGetActivationFactory` for the given WinRT runtime class; fails
+/// with `E_NOTIMPL` if WinRT is not available.
+pub(crate) fn ro_get_activation_factory<T>(class_id: &str) -> HrResult<T>
+	where T: ole_IUnknown,
+{
+	let func = ole::ffi::RoGetActivationFactory()
+		.map_err(|_| co::HRESULT::E_NOTIMPL)?;
+	let class_id = HString::new(class_id)?;
+	let mut ppv = unsafe { ComPtr::null() };
+	ok_to_hrresult(
+		unsafe {
+			func(class_id.as_ptr(), &T::IID as *const _ as _, &mut ppv as *mut _ as _)
+		},
+	).map(|_| T::from(ppv))
+}
+
+/// Calls `RoActivateInstance` for the given WinRT runtime class, returning
+/// its default `IInspectable` interface; fails with `E_NOTIMPL` if WinRT is not
+/// available.
+pub(crate) fn ro_activate_instance(class_id: &str) -> HrResult<IUnknown> {
+	let func = ole::ffi::RoActivateInstance()
+		.map_err(|_| co::HRESULT::E_NOTIMPL)?;
+	let class_id = HString::new(class_id)?;
+	let mut ppv = unsafe { ComPtr::null() };
+	ok_to_hrresult(
+		unsafe { func(class_id.as_ptr(), &mut ppv as *mut _ as _) },
+	).map(|_| IUnknown::from(ppv))
+}
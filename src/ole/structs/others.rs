@@ -1,11 +1,47 @@
-#![allow(non_snake_case)]
+#![allow(non_camel_case_types, non_snake_case)]
 
 use std::marker::PhantomData;
 
 use crate::co;
-use crate::kernel::decl::WString;
+use crate::kernel::decl::{FILETIME, HGLOBAL, WString};
+use crate::kernel::ffi_types::BOOL;
 use crate::ole::decl::ComPtr;
-use crate::prelude::ole_IUnknown;
+use crate::prelude::{Handle, ole_IUnknown};
+
+/// [`BG_FILE_PROGRESS`](https://learn.microsoft.com/en-us/windows/win32/api/bits/ns-bits-bg_file_progress)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct BG_FILE_PROGRESS {
+	pub BytesTotal: u64,
+	pub BytesTransferred: u64,
+	Completed: BOOL,
+}
+
+impl BG_FILE_PROGRESS {
+	pub_fn_bool_get_set!(Completed, set_Completed);
+}
+
+/// [`BG_JOB_PROGRESS`](https://learn.microsoft.com/en-us/windows/win32/api/bits/ns-bits-bg_job_progress)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct BG_JOB_PROGRESS {
+	pub BytesTotal: u64,
+	pub BytesTransferred: u64,
+	pub FilesTotal: u32,
+	pub FilesTransferred: u32,
+}
+
+/// [`BG_JOB_TIMES`](https://learn.microsoft.com/en-us/windows/win32/api/bits/ns-bits-bg_job_times)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct BG_JOB_TIMES {
+	pub CreationTime: FILETIME,
+	pub ModificationTime: FILETIME,
+	pub TransferCompletionTime: FILETIME,
+}
 
 /// [`COAUTHIDENTITY`](https://learn.microsoft.com/en-us/windows/win32/api/wtypesbase/ns-wtypesbase-coauthidentity)
 /// struct.
@@ -139,3 +175,38 @@ impl<'a> MULTI_QI<'a> {
 	pub_fn_ptr_get_set!('a, pIID, set_pIID, co::IID);
 	pub_fn_comptr_get_set!(pItf, set_pItf, ole_IUnknown);
 }
+
+/// [`STGMEDIUM`](https://learn.microsoft.com/en-us/windows/win32/api/objidl/ns-objidl-ustgmedium-r1)
+/// struct.
+///
+/// Usually returned within a
+/// [`ReleaseStgMediumGuard`](crate::guard::ReleaseStgMediumGuard), by
+/// [`IDataObject::GetData`](crate::prelude::ole_IDataObject::GetData).
+#[repr(C)]
+pub struct STGMEDIUM {
+	pub tymed: co::TYMED,
+	data: usize, // union
+	pUnkForRelease: ComPtr,
+}
+
+impl_default!(STGMEDIUM);
+
+impl STGMEDIUM {
+	/// Returns the `hGlobal` union field, if `tymed` is
+	/// [`TYMED::HGLOBAL`](crate::co::TYMED::HGLOBAL).
+	#[must_use]
+	pub fn hGlobal(&self) -> Option<HGLOBAL> {
+		if self.tymed == co::TYMED::HGLOBAL {
+			Some(unsafe { HGLOBAL::from_ptr(self.data as _) })
+		} else {
+			None
+		}
+	}
+
+	/// Sets the `hGlobal` union field, also setting `tymed` to
+	/// [`TYMED::HGLOBAL`](crate::co::TYMED::HGLOBAL).
+	pub fn set_hGlobal(&mut self, hglobal: HGLOBAL) {
+		self.tymed = co::TYMED::HGLOBAL;
+		self.data = hglobal.as_ptr() as _;
+	}
+}
@@ -0,0 +1,204 @@
+#![allow(non_snake_case)]
+
+use std::mem::ManuallyDrop;
+use std::sync::atomic::{AtomicU32, fence, Ordering};
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PCVOID};
+use crate::ole::decl::{
+	ComPtr, HrResult, IBackgroundCopyCallback, IBackgroundCopyError,
+	IBackgroundCopyJob, IUnknown,
+};
+use crate::ole::privs::catch_com_panic;
+use crate::prelude::ole_IUnknown;
+use crate::vt::{IBackgroundCopyCallbackVT, IUnknownVT};
+
+/// Builder of an
+/// [`IBackgroundCopyCallback`](crate::IBackgroundCopyCallback) implemented
+/// in Rust, which runs the given closures when BITS notifies about a job.
+///
+/// The built object is passed to
+/// [`IBackgroundCopyJob::SetNotifyInterface`](crate::prelude::ole_IBackgroundCopyJob::SetNotifyInterface).
+/// BITS calls the closures from its own threads, so they must be `Send` and
+/// `Sync`. Which events are delivered is set with
+/// [`IBackgroundCopyJob::SetNotifyFlags`](crate::prelude::ole_IBackgroundCopyJob::SetNotifyFlags).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{BackgroundCopyCallback, co, IBackgroundCopyJob};
+///
+/// let job: IBackgroundCopyJob; // initialized somewhere
+/// # let job = IBackgroundCopyJob::from(unsafe { winsafe::ComPtr::null() });
+///
+/// let callback = BackgroundCopyCallback::new()
+///     .job_transferred(|job| {
+///         job.Complete()?;
+///         Ok(())
+///     })
+///     .job_error(|job, err| {
+///         let (_, hr) = err.GetError()?;
+///         println!("Job failed: {}", hr);
+///         job.Cancel()
+///     })
+///     .build();
+///
+/// job.SetNotifyInterface(&callback)?;
+/// job.SetNotifyFlags(co::BG_NOTIFY::JOB_TRANSFERRED | co::BG_NOTIFY::JOB_ERROR)?;
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+#[derive(Default)]
+pub struct BackgroundCopyCallback {
+	job_transferred: Option<Box<dyn Fn(&IBackgroundCopyJob) -> HrResult<()> + Send + Sync>>,
+	job_error: Option<Box<dyn Fn(&IBackgroundCopyJob, &IBackgroundCopyError) -> HrResult<()> + Send + Sync>>,
+	job_modification: Option<Box<dyn Fn(&IBackgroundCopyJob) -> HrResult<()> + Send + Sync>>,
+}
+
+impl BackgroundCopyCallback {
+	/// Creates a new builder, with no closures.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// [`IBackgroundCopyCallback::JobError`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopycallback-joberror)
+	/// event, called when the job enters the
+	/// [`co::BG_JOB_STATE::ERROR`](crate::co::BG_JOB_STATE::ERROR) state.
+	#[must_use]
+	pub fn job_error<F>(mut self, func: F) -> Self
+		where F: Fn(&IBackgroundCopyJob, &IBackgroundCopyError) -> HrResult<()> + Send + Sync + 'static,
+	{
+		self.job_error = Some(Box::new(func));
+		self
+	}
+
+	/// [`IBackgroundCopyCallback::JobModification`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopycallback-jobmodification)
+	/// event, called when the job has been modified, including transfer
+	/// progress.
+	#[must_use]
+	pub fn job_modification<F>(mut self, func: F) -> Self
+		where F: Fn(&IBackgroundCopyJob) -> HrResult<()> + Send + Sync + 'static,
+	{
+		self.job_modification = Some(Box::new(func));
+		self
+	}
+
+	/// [`IBackgroundCopyCallback::JobTransferred`](https://learn.microsoft.com/en-us/windows/win32/api/bits/nf-bits-ibackgroundcopycallback-jobtransferred)
+	/// event, called when all files in the job have been transferred.
+	#[must_use]
+	pub fn job_transferred<F>(mut self, func: F) -> Self
+		where F: Fn(&IBackgroundCopyJob) -> HrResult<()> + Send + Sync + 'static,
+	{
+		self.job_transferred = Some(Box::new(func));
+		self
+	}
+
+	/// Creates the
+	/// [`IBackgroundCopyCallback`](crate::IBackgroundCopyCallback) object.
+	#[must_use]
+	pub fn build(self) -> IBackgroundCopyCallback {
+		let obj = Box::new(BackgroundCopyCallbackImpl {
+			vt: &BACKGROUND_COPY_CALLBACK_VT,
+			ref_count: AtomicU32::new(1),
+			events: self,
+		});
+		IBackgroundCopyCallback::from(ComPtr(Box::into_raw(obj) as _))
+	}
+}
+
+//------------------------------------------------------------------------------
+
+static BACKGROUND_COPY_CALLBACK_VT: IBackgroundCopyCallbackVT = IBackgroundCopyCallbackVT {
+	IUnknownVT: IUnknownVT {
+		QueryInterface: BackgroundCopyCallbackImpl::QueryInterface,
+		AddRef: BackgroundCopyCallbackImpl::AddRef,
+		Release: BackgroundCopyCallbackImpl::Release,
+	},
+	JobTransferred: BackgroundCopyCallbackImpl::JobTransferred,
+	JobError: BackgroundCopyCallbackImpl::JobError,
+	JobModification: BackgroundCopyCallbackImpl::JobModification,
+};
+
+/// Implementation of the `IBackgroundCopyCallback` COM interface.
+#[repr(C)]
+struct BackgroundCopyCallbackImpl {
+	vt: *const IBackgroundCopyCallbackVT, // must be the first field, as in any COM object
+	ref_count: AtomicU32,
+	events: BackgroundCopyCallback,
+}
+
+impl BackgroundCopyCallbackImpl {
+	fn from_com_ptr<'a>(p: ComPtr) -> &'a Self {
+		unsafe { &*(p.0 as *const Self) }
+	}
+
+	fn to_hres(res: HrResult<()>) -> HRES {
+		match res {
+			Ok(_) => co::HRESULT::S_OK.0,
+			Err(hr) => hr.0,
+		}
+	}
+
+	fn QueryInterface(p: ComPtr, riid: PCVOID, ppv: *mut ComPtr) -> HRES {
+		let riid = unsafe { &*(riid as *const co::IID) };
+		if *riid == IUnknown::IID || *riid == IBackgroundCopyCallback::IID {
+			Self::AddRef(p);
+			unsafe { *ppv = p; }
+			co::HRESULT::S_OK.0
+		} else {
+			unsafe { *ppv = ComPtr::null(); }
+			co::HRESULT::E_NOINTERFACE.0
+		}
+	}
+
+	fn AddRef(p: ComPtr) -> u32 {
+		Self::from_com_ptr(p).ref_count.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn Release(p: ComPtr) -> u32 {
+		let count = Self::from_com_ptr(p).ref_count.fetch_sub(1, Ordering::Release) - 1;
+		if count == 0 {
+			fence(Ordering::Acquire); // see the drop of std::sync::Arc
+			let _ = unsafe { Box::from_raw(p.0 as *mut Self) };
+		}
+		count
+	}
+
+	fn JobTransferred(p: ComPtr, job: ComPtr) -> HRES {
+		catch_com_panic(|| {
+			match &Self::from_com_ptr(p).events.job_transferred {
+				Some(func) => {
+					let job = ManuallyDrop::new(IBackgroundCopyJob::from(job)); // not owned
+					Self::to_hres(func(&job))
+				},
+				None => co::HRESULT::S_OK.0,
+			}
+		})
+	}
+
+	fn JobError(p: ComPtr, job: ComPtr, error: ComPtr) -> HRES {
+		catch_com_panic(|| {
+			match &Self::from_com_ptr(p).events.job_error {
+				Some(func) => {
+					let job = ManuallyDrop::new(IBackgroundCopyJob::from(job)); // not owned
+					let error = ManuallyDrop::new(IBackgroundCopyError::from(error));
+					Self::to_hres(func(&job, &error))
+				},
+				None => co::HRESULT::S_OK.0,
+			}
+		})
+	}
+
+	fn JobModification(p: ComPtr, job: ComPtr, _reserved: u32) -> HRES {
+		catch_com_panic(|| {
+			match &Self::from_com_ptr(p).events.job_modification {
+				Some(func) => {
+					let job = ManuallyDrop::new(IBackgroundCopyJob::from(job)); // not owned
+					Self::to_hres(func(&job))
+				},
+				None => co::HRESULT::S_OK.0,
+			}
+		})
+	}
+}
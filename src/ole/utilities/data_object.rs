@@ -0,0 +1,478 @@
+#![allow(non_snake_case)]
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicU32, fence, Ordering};
+
+use crate::co;
+use crate::kernel::decl::HGLOBAL;
+use crate::kernel::ffi_types::{BOOL, HRES, PCVOID, PVOID};
+use crate::ole::decl::{
+	ComPtr, DoDragDrop, FORMATETC, HrResult, IDataObject, IDropSource,
+	IUnknown, STGMEDIUM,
+};
+use crate::ole::privs::catch_com_panic;
+use crate::prelude::{kernel_Hglobal, ole_IUnknown};
+use crate::vt::{IDataObjectVT, IDropSourceVT, IUnknownVT};
+
+/// Size of the
+/// [`DROPFILES`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/ns-shlobj_core-dropfiles)
+/// struct, which precedes the file list in `CF_HDROP` data.
+const DROPFILES_SIZE: u32 = 20;
+
+/// `DATADIR_GET` direction of `IDataObject::EnumFormatEtc`.
+const DATADIR_GET: u32 = 1;
+
+/// `DVASPECT_CONTENT` aspect of [`FORMATETC`](crate::FORMATETC).
+const DVASPECT_CONTENT: u32 = 1;
+
+/// Builder of an [`IDataObject`](crate::IDataObject) implemented in Rust,
+/// which holds data in one or more clipboard formats.
+///
+/// The data object can be used to start an OLE drag operation with
+/// [`drag`](crate::DataObject::drag). OLE must be initialized with
+/// [`OleInitialize`](crate::OleInitialize).
+///
+/// # Examples
+///
+/// Dragging files from a list view, upon its `LVN_BEGINDRAG` notification:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, DataObject};
+///
+/// let effect = DataObject::new()
+///     .files(&["C:\\Temp\\foo.txt", "C:\\Temp\\bar.txt"])
+///     .text("C:\\Temp\\foo.txt\r\nC:\\Temp\\bar.txt")
+///     .drag(co::DROPEFFECT::COPY | co::DROPEFFECT::MOVE)?;
+///
+/// if effect == Some(co::DROPEFFECT::MOVE) {
+///     // the target moved the files
+/// }
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+#[derive(Default)]
+pub struct DataObject {
+	formats: Vec<(co::CF, Vec<u8>)>,
+}
+
+impl DataObject {
+	/// Creates a new, empty data object builder.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds data in a custom format, usually registered with
+	/// [`RegisterClipboardFormat`](crate::RegisterClipboardFormat). If the
+	/// format already exists, its data is replaced.
+	#[must_use]
+	pub fn custom(mut self, format: co::CF, data: &[u8]) -> Self {
+		self.formats.retain(|(cf, _)| *cf != format);
+		self.formats.push((format, data.to_vec()));
+		self
+	}
+
+	/// Adds a list of file paths, in the `CF_HDROP` format.
+	#[must_use]
+	pub fn files(self, paths: &[impl AsRef<str>]) -> Self {
+		let mut data = Vec::default();
+		data.extend_from_slice(&DROPFILES_SIZE.to_le_bytes()); // pFiles
+		data.extend_from_slice(&[0; 12]); // pt, fNC
+		data.extend_from_slice(&1u32.to_le_bytes()); // fWide
+		for path in paths.iter() {
+			path.as_ref().encode_utf16()
+				.chain(std::iter::once(0)) // each path is null-terminated
+				.for_each(|ch| data.extend_from_slice(&ch.to_le_bytes()));
+		}
+		data.extend_from_slice(&[0, 0]); // list is double null-terminated
+		self.custom(co::CF::HDROP, &data)
+	}
+
+	/// Adds text, in the `CF_UNICODETEXT` format.
+	#[must_use]
+	pub fn text(self, text: &str) -> Self {
+		let data = text.encode_utf16()
+			.chain(std::iter::once(0))
+			.flat_map(|ch| ch.to_le_bytes())
+			.collect::<Vec<_>>();
+		self.custom(co::CF::UNICODETEXT, &data)
+	}
+
+	/// Creates the [`IDataObject`](crate::IDataObject), which serves the data
+	/// in an `HGLOBAL` upon
+	/// [`IDataObject::GetData`](crate::prelude::ole_IDataObject::GetData).
+	#[must_use]
+	pub fn build(self) -> IDataObject {
+		let obj = Box::new(DataObjectImpl {
+			vt: &DATA_OBJECT_VT,
+			ref_count: AtomicU32::new(1),
+			formats: self.formats,
+		});
+		IDataObject::from(ComPtr(Box::into_raw(obj) as _))
+	}
+
+	/// Creates the [`IDataObject`](crate::IDataObject) and a default
+	/// [`IDropSource`](crate::IDropSource), and starts an OLE drag operation
+	/// with [`DoDragDrop`](crate::DoDragDrop).
+	///
+	/// The operation ends when the mouse button is released, or cancelled
+	/// when ESC is pressed. Returns the effect performed by the drop target,
+	/// or `None` if the operation was cancelled.
+	pub fn drag(self,
+		ok_effects: co::DROPEFFECT) -> HrResult<Option<co::DROPEFFECT>>
+	{
+		let data_obj = self.build();
+		let drop_source = IDropSource::from(
+			ComPtr(Box::into_raw(Box::new(DropSourceImpl {
+				vt: &DROP_SOURCE_VT,
+				ref_count: AtomicU32::new(1),
+			})) as _),
+		);
+		DoDragDrop(&data_obj, &drop_source, ok_effects)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+static DATA_OBJECT_VT: IDataObjectVT = IDataObjectVT {
+	IUnknownVT: IUnknownVT {
+		QueryInterface: DataObjectImpl::QueryInterface,
+		AddRef: DataObjectImpl::AddRef,
+		Release: DataObjectImpl::Release,
+	},
+	GetData: DataObjectImpl::GetData,
+	GetDataHere: DataObjectImpl::GetDataHere,
+	QueryGetData: DataObjectImpl::QueryGetData,
+	GetCanonicalFormatEtc: DataObjectImpl::GetCanonicalFormatEtc,
+	SetData: DataObjectImpl::SetData,
+	EnumFormatEtc: DataObjectImpl::EnumFormatEtc,
+	DAdvise: DataObjectImpl::DAdvise,
+	DUnadvise: DataObjectImpl::DUnadvise,
+	EnumDAdvise: DataObjectImpl::EnumDAdvise,
+};
+
+/// Implementation of the `IDataObject` COM interface.
+#[repr(C)]
+struct DataObjectImpl {
+	vt: *const IDataObjectVT, // must be the first field, as in any COM object
+	ref_count: AtomicU32,
+	formats: Vec<(co::CF, Vec<u8>)>,
+}
+
+impl DataObjectImpl {
+	fn from_com_ptr<'a>(p: ComPtr) -> &'a Self {
+		unsafe { &*(p.0 as *const Self) }
+	}
+
+	fn QueryInterface(p: ComPtr, riid: PCVOID, ppv: *mut ComPtr) -> HRES {
+		let riid = unsafe { &*(riid as *const co::IID) };
+		if *riid == IUnknown::IID || *riid == IDataObject::IID {
+			Self::AddRef(p);
+			unsafe { *ppv = p; }
+			co::HRESULT::S_OK.0
+		} else {
+			unsafe { *ppv = ComPtr::null(); }
+			co::HRESULT::E_NOINTERFACE.0
+		}
+	}
+
+	fn AddRef(p: ComPtr) -> u32 {
+		Self::from_com_ptr(p).ref_count.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn Release(p: ComPtr) -> u32 {
+		let count = Self::from_com_ptr(p).ref_count.fetch_sub(1, Ordering::Release) - 1;
+		if count == 0 {
+			fence(Ordering::Acquire); // see the drop of std::sync::Arc
+			let _ = unsafe { Box::from_raw(p.0 as *mut Self) };
+		}
+		count
+	}
+
+	/// Returns the data of the requested format, if available.
+	fn find(&self, format_etc: &FORMATETC) -> Result<&[u8], co::HRESULT> {
+		if format_etc.dwAspect != DVASPECT_CONTENT {
+			Err(co::HRESULT::DV_E_DVASPECT)
+		} else if format_etc.tymed.0 & co::TYMED::HGLOBAL.0 == 0 {
+			Err(co::HRESULT::DV_E_TYMED)
+		} else {
+			self.formats.iter()
+				.find(|(cf, _)| *cf == format_etc.cfFormat())
+				.map(|(_, data)| data.as_slice())
+				.ok_or(co::HRESULT::DV_E_FORMATETC)
+		}
+	}
+
+	fn GetData(p: ComPtr, format_etc: PVOID, medium: PVOID) -> HRES {
+		catch_com_panic(|| {
+			let obj = Self::from_com_ptr(p);
+			let format_etc = unsafe { &*(format_etc as *const FORMATETC) };
+			let data = match obj.find(format_etc) {
+				Ok(data) => data,
+				Err(hr) => return hr.0,
+			};
+
+			let hglobal = match HGLOBAL::GlobalAlloc(co::GMEM::MOVEABLE, data.len()) {
+				Ok(mut hglobal) => hglobal.leak(), // will be freed by the receiver
+				Err(_) => return co::HRESULT::E_OUTOFMEMORY.0,
+			};
+			if let Ok((block, _unlock_guard)) = hglobal.GlobalLock() {
+				block[..data.len()].copy_from_slice(data);
+			}
+
+			let medium = unsafe { &mut *(medium as *mut STGMEDIUM) };
+			*medium = STGMEDIUM::default();
+			medium.set_hGlobal(hglobal);
+			co::HRESULT::S_OK.0
+		})
+	}
+
+	fn GetDataHere(_p: ComPtr, _format_etc: PVOID, _medium: PVOID) -> HRES {
+		co::HRESULT::E_NOTIMPL.0
+	}
+
+	fn QueryGetData(p: ComPtr, format_etc: PVOID) -> HRES {
+		catch_com_panic(|| {
+			let format_etc = unsafe { &*(format_etc as *const FORMATETC) };
+			match Self::from_com_ptr(p).find(format_etc) {
+				Ok(_) => co::HRESULT::S_OK.0,
+				Err(hr) => hr.0,
+			}
+		})
+	}
+
+	fn GetCanonicalFormatEtc(_p: ComPtr, _format_in: PVOID, format_out: PVOID) -> HRES {
+		let format_out = unsafe { &mut *(format_out as *mut FORMATETC) };
+		format_out.set_ptd(None); // as required by the documentation
+		co::HRESULT::E_NOTIMPL.0
+	}
+
+	fn SetData(_p: ComPtr, _format_etc: PVOID, _medium: PVOID, _release: BOOL) -> HRES {
+		co::HRESULT::E_NOTIMPL.0
+	}
+
+	fn EnumFormatEtc(p: ComPtr, direction: u32, enum_format_etc: *mut ComPtr) -> HRES {
+		catch_com_panic(|| {
+			if direction != DATADIR_GET {
+				return co::HRESULT::E_NOTIMPL.0;
+			}
+			let formats = Self::from_com_ptr(p).formats.iter()
+				.map(|(cf, _)| *cf)
+				.collect::<Vec<_>>();
+			unsafe { *enum_format_etc = EnumFormatEtcImpl::new_ptr(formats, 0); }
+			co::HRESULT::S_OK.0
+		})
+	}
+
+	fn DAdvise(_p: ComPtr,
+		_format_etc: PVOID, _advf: u32, _sink: ComPtr, _connection: *mut u32) -> HRES
+	{
+		co::HRESULT::OLE_E_ADVISENOTSUPPORTED.0
+	}
+
+	fn DUnadvise(_p: ComPtr, _connection: u32) -> HRES {
+		co::HRESULT::OLE_E_ADVISENOTSUPPORTED.0
+	}
+
+	fn EnumDAdvise(_p: ComPtr, _enum_advise: *mut ComPtr) -> HRES {
+		co::HRESULT::OLE_E_ADVISENOTSUPPORTED.0
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// `IEnumFORMATETC` virtual table, private because the interface is only
+/// implemented, never consumed, by the library.
+#[repr(C)]
+struct IEnumFORMATETCVT {
+	IUnknownVT: IUnknownVT,
+	Next: fn(ComPtr, u32, PVOID, *mut u32) -> HRES,
+	Skip: fn(ComPtr, u32) -> HRES,
+	Reset: fn(ComPtr) -> HRES,
+	Clone: fn(ComPtr, *mut ComPtr) -> HRES,
+}
+
+static ENUM_FORMAT_ETC_VT: IEnumFORMATETCVT = IEnumFORMATETCVT {
+	IUnknownVT: IUnknownVT {
+		QueryInterface: EnumFormatEtcImpl::QueryInterface,
+		AddRef: EnumFormatEtcImpl::AddRef,
+		Release: EnumFormatEtcImpl::Release,
+	},
+	Next: EnumFormatEtcImpl::Next,
+	Skip: EnumFormatEtcImpl::Skip,
+	Reset: EnumFormatEtcImpl::Reset,
+	Clone: EnumFormatEtcImpl::Clone,
+};
+
+/// Implementation of the `IEnumFORMATETC` COM interface, returned by
+/// `IDataObject::EnumFormatEtc`.
+#[repr(C)]
+struct EnumFormatEtcImpl {
+	vt: *const IEnumFORMATETCVT, // must be the first field, as in any COM object
+	ref_count: AtomicU32,
+	formats: Vec<co::CF>,
+	pos: Cell<usize>,
+}
+
+impl EnumFormatEtcImpl {
+	const IID: co::IID = co::IID::new("00000103-0000-0000-c000-000000000046");
+
+	/// Creates a new COM object, returning its pointer with a reference count
+	/// of 1.
+	fn new_ptr(formats: Vec<co::CF>, pos: usize) -> ComPtr {
+		let obj = Box::new(Self {
+			vt: &ENUM_FORMAT_ETC_VT,
+			ref_count: AtomicU32::new(1),
+			formats,
+			pos: Cell::new(pos),
+		});
+		ComPtr(Box::into_raw(obj) as _)
+	}
+
+	fn from_com_ptr<'a>(p: ComPtr) -> &'a Self {
+		unsafe { &*(p.0 as *const Self) }
+	}
+
+	fn QueryInterface(p: ComPtr, riid: PCVOID, ppv: *mut ComPtr) -> HRES {
+		let riid = unsafe { &*(riid as *const co::IID) };
+		if *riid == IUnknown::IID || *riid == Self::IID {
+			Self::AddRef(p);
+			unsafe { *ppv = p; }
+			co::HRESULT::S_OK.0
+		} else {
+			unsafe { *ppv = ComPtr::null(); }
+			co::HRESULT::E_NOINTERFACE.0
+		}
+	}
+
+	fn AddRef(p: ComPtr) -> u32 {
+		Self::from_com_ptr(p).ref_count.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn Release(p: ComPtr) -> u32 {
+		let count = Self::from_com_ptr(p).ref_count.fetch_sub(1, Ordering::Release) - 1;
+		if count == 0 {
+			fence(Ordering::Acquire); // see the drop of std::sync::Arc
+			let _ = unsafe { Box::from_raw(p.0 as *mut Self) };
+		}
+		count
+	}
+
+	fn Next(p: ComPtr, count: u32, formats: PVOID, fetched: *mut u32) -> HRES {
+		catch_com_panic(|| {
+			let obj = Self::from_com_ptr(p);
+			let formats = unsafe {
+				std::slice::from_raw_parts_mut(formats as *mut FORMATETC, count as _)
+			};
+
+			let mut num_fetched = 0;
+			for format_etc in formats.iter_mut() {
+				match obj.formats.get(obj.pos.get()) {
+					Some(cf) => {
+						*format_etc = FORMATETC::default();
+						format_etc.set_cfFormat(*cf);
+						format_etc.dwAspect = DVASPECT_CONTENT;
+						format_etc.lindex = -1;
+						format_etc.tymed = co::TYMED::HGLOBAL;
+						obj.pos.set(obj.pos.get() + 1);
+						num_fetched += 1;
+					},
+					None => break,
+				}
+			}
+
+			if !fetched.is_null() {
+				unsafe { *fetched = num_fetched; }
+			}
+			if num_fetched == count { co::HRESULT::S_OK.0 } else { co::HRESULT::S_FALSE.0 }
+		})
+	}
+
+	fn Skip(p: ComPtr, count: u32) -> HRES {
+		catch_com_panic(|| {
+			let obj = Self::from_com_ptr(p);
+			let new_pos = obj.pos.get() + count as usize;
+			obj.pos.set(new_pos.min(obj.formats.len()));
+			if new_pos <= obj.formats.len() { co::HRESULT::S_OK.0 } else { co::HRESULT::S_FALSE.0 }
+		})
+	}
+
+	fn Reset(p: ComPtr) -> HRES {
+		Self::from_com_ptr(p).pos.set(0);
+		co::HRESULT::S_OK.0
+	}
+
+	fn Clone(p: ComPtr, enum_format_etc: *mut ComPtr) -> HRES {
+		catch_com_panic(|| {
+			let obj = Self::from_com_ptr(p);
+			unsafe { *enum_format_etc = Self::new_ptr(obj.formats.clone(), obj.pos.get()); }
+			co::HRESULT::S_OK.0
+		})
+	}
+}
+
+//------------------------------------------------------------------------------
+
+static DROP_SOURCE_VT: IDropSourceVT = IDropSourceVT {
+	IUnknownVT: IUnknownVT {
+		QueryInterface: DropSourceImpl::QueryInterface,
+		AddRef: DropSourceImpl::AddRef,
+		Release: DropSourceImpl::Release,
+	},
+	QueryContinueDrag: DropSourceImpl::QueryContinueDrag,
+	GiveFeedback: DropSourceImpl::GiveFeedback,
+};
+
+/// Implementation of the `IDropSource` COM interface, with the default
+/// behavior: drops when the mouse button is released, cancels on ESC.
+#[repr(C)]
+struct DropSourceImpl {
+	vt: *const IDropSourceVT, // must be the first field, as in any COM object
+	ref_count: AtomicU32,
+}
+
+impl DropSourceImpl {
+	fn from_com_ptr<'a>(p: ComPtr) -> &'a Self {
+		unsafe { &*(p.0 as *const Self) }
+	}
+
+	fn QueryInterface(p: ComPtr, riid: PCVOID, ppv: *mut ComPtr) -> HRES {
+		let riid = unsafe { &*(riid as *const co::IID) };
+		if *riid == IUnknown::IID || *riid == IDropSource::IID {
+			Self::AddRef(p);
+			unsafe { *ppv = p; }
+			co::HRESULT::S_OK.0
+		} else {
+			unsafe { *ppv = ComPtr::null(); }
+			co::HRESULT::E_NOINTERFACE.0
+		}
+	}
+
+	fn AddRef(p: ComPtr) -> u32 {
+		Self::from_com_ptr(p).ref_count.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn Release(p: ComPtr) -> u32 {
+		let count = Self::from_com_ptr(p).ref_count.fetch_sub(1, Ordering::Release) - 1;
+		if count == 0 {
+			fence(Ordering::Acquire); // see the drop of std::sync::Arc
+			let _ = unsafe { Box::from_raw(p.0 as *mut Self) };
+		}
+		count
+	}
+
+	fn QueryContinueDrag(_p: ComPtr, escape_pressed: BOOL, key_state: u32) -> HRES {
+		let buttons = (co::MK::LBUTTON | co::MK::RBUTTON).0 as u32;
+		if escape_pressed != 0 {
+			co::HRESULT::DRAGDROP_S_CANCEL.0
+		} else if key_state & buttons == 0 {
+			co::HRESULT::DRAGDROP_S_DROP.0 // mouse button released
+		} else {
+			co::HRESULT::S_OK.0
+		}
+	}
+
+	fn GiveFeedback(_p: ComPtr, _effect: u32) -> HRES {
+		co::HRESULT::DRAGDROP_S_USEDEFAULTCURSORS.0
+	}
+}
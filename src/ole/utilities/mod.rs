@@ -0,0 +1,5 @@
+mod background_copy_callback;
+mod data_object;
+
+pub use background_copy_callback::BackgroundCopyCallback;
+pub use data_object::DataObject;
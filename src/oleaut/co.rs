@@ -1,3 +1,57 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+use crate::co::CLSID;
+
+const_guid_values! { CLSID;
+	NetworkListManager "dcb00c01-570f-4a9b-8d69-199fdba5723b"
+}
+
+const_bitflag! { NLM_CONNECTIVITY: u32;
+	/// [`NLM_CONNECTIVITY`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/ne-netlistmgr-nlm_connectivity)
+	/// enumeration (`u32`).
+	=>
+	=>
+	DISCONNECTED 0
+	IPV4_NOTRAFFIC 0x0001
+	IPV6_NOTRAFFIC 0x0002
+	IPV4_SUBNET 0x0010
+	IPV4_LOCALNETWORK 0x0020
+	IPV4_INTERNET 0x0040
+	IPV6_SUBNET 0x0100
+	IPV6_LOCALNETWORK 0x0200
+	IPV6_INTERNET 0x0400
+}
+
+const_ordinary! { NLM_DOMAIN_TYPE: u32;
+	/// [`NLM_DOMAIN_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/ne-netlistmgr-nlm_domain_type)
+	/// enumeration (`u32`).
+	=>
+	=>
+	NON_DOMAIN_NETWORK 0
+	DOMAIN_NETWORK 1
+	DOMAIN_AUTHENTICATED 2
+}
+
+const_bitflag! { NLM_ENUM_NETWORK: u32;
+	/// [`NLM_ENUM_NETWORK`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/ne-netlistmgr-nlm_enum_network)
+	/// enumeration (`u32`).
+	=>
+	=>
+	CONNECTED 0x01
+	DISCONNECTED 0x02
+	ALL 0x03
+}
+
+const_ordinary! { NLM_NETWORK_CATEGORY: u32;
+	/// [`NLM_NETWORK_CATEGORY`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/ne-netlistmgr-nlm_network_category)
+	/// enumeration (`u32`).
+	=>
+	=>
+	PUBLIC 0
+	PRIVATE 1
+	DOMAIN_AUTHENTICATED 2
+}
+
 const_bitflag! { VT: u16;
 	/// [`VARENUM`](https://learn.microsoft.com/en-us/windows/win32/api/wtypes/ne-wtypes-varenum)
 	/// enumeration (`u16`).
@@ -0,0 +1,134 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::oleaut::decl::INetworkConnection;
+use crate::prelude::oleaut_IDispatch;
+use crate::vt::IDispatchVT;
+
+/// [`IEnumNetworkConnections`](crate::IEnumNetworkConnections) virtual table.
+#[repr(C)]
+pub struct IEnumNetworkConnectionsVT {
+	pub IDispatchVT: IDispatchVT,
+	pub get__NewEnum: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub Next: fn(ComPtr, u32, *mut ComPtr, *mut u32) -> HRES,
+	pub Skip: fn(ComPtr, u32) -> HRES,
+	pub Reset: fn(ComPtr) -> HRES,
+	pub Clone: fn(ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IEnumNetworkConnections: "dcb00006-570f-4a9b-8d69-199fdba5723b";
+	/// [`IEnumNetworkConnections`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nn-netlistmgr-ienumnetworkconnections)
+	/// COM interface over [`IEnumNetworkConnectionsVT`](crate::vt::IEnumNetworkConnectionsVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for IEnumNetworkConnections {}
+impl oleaut_IEnumNetworkConnections for IEnumNetworkConnections {}
+
+/// This trait is enabled with the `oleaut` feature, and provides methods for
+/// [`IEnumNetworkConnections`](crate::IEnumNetworkConnections).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait oleaut_IEnumNetworkConnections: oleaut_IDispatch {
+	/// Returns an iterator over the [`INetworkConnection`](crate::INetworkConnection) elements
+	/// which calls
+	/// [`IEnumNetworkConnections::Next`](crate::prelude::oleaut_IEnumNetworkConnections::Next)
+	/// internally.
+	#[must_use]
+	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<INetworkConnection>> + '_> {
+		Box::new(EnumNetworkConnectionsIter::new(self))
+	}
+
+	/// [`IEnumNetworkConnections::Clone`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-ienumnetworkconnections-clone)
+	/// method.
+	#[must_use]
+	fn Clone(&self) -> HrResult<IEnumNetworkConnections> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumNetworkConnectionsVT>();
+			ok_to_hrresult((vt.Clone)(self.ptr(), &mut ppv_queried))
+				.map(|_| IEnumNetworkConnections::from(ppv_queried))
+		}
+	}
+
+	/// [`IEnumNetworkConnections::Next`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-ienumnetworkconnections-next)
+	/// method.
+	///
+	/// Prefer using
+	/// [`IEnumNetworkConnections::iter`](crate::prelude::oleaut_IEnumNetworkConnections::iter), which is
+	/// simpler.
+	#[must_use]
+	fn Next(&self) -> HrResult<Option<INetworkConnection>> {
+		let mut fetched = u32::default();
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumNetworkConnectionsVT>();
+			match ok_to_hrresult(
+				(vt.Next)(self.ptr(), 1, &mut ppv_queried, &mut fetched), // retrieve only 1
+			) {
+				Ok(_) => Ok(Some(INetworkConnection::from(ppv_queried))),
+				Err(hr) => match hr {
+					co::HRESULT::S_FALSE => Ok(None), // no item found
+					hr => Err(hr), // actual error
+				},
+			}
+		}
+	}
+
+	/// [`IEnumNetworkConnections::Reset`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-ienumnetworkconnections-reset)
+	/// method.
+	fn Reset(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumNetworkConnectionsVT>();
+			ok_to_hrresult((vt.Reset)(self.ptr()))
+		}
+	}
+
+	/// [`IEnumNetworkConnections::Skip`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-ienumnetworkconnections-skip)
+	/// method.
+	fn Skip(&self, count: u32) -> HrResult<bool> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumNetworkConnectionsVT>();
+			okfalse_to_hrresult((vt.Skip)(self.ptr(), count))
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct EnumNetworkConnectionsIter<'a, I>
+	where I: oleaut_IEnumNetworkConnections,
+{
+	enum_obj: &'a I,
+}
+
+impl<'a, I> Iterator for EnumNetworkConnectionsIter<'a, I>
+	where I: oleaut_IEnumNetworkConnections,
+{
+	type Item = HrResult<INetworkConnection>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.enum_obj.Next() {
+			Err(err) => Some(Err(err)),
+			Ok(maybe_item) => maybe_item.map(Ok),
+		}
+	}
+}
+
+impl<'a, I> EnumNetworkConnectionsIter<'a, I>
+	where I: oleaut_IEnumNetworkConnections,
+{
+	fn new(enum_obj: &'a I) -> Self {
+		Self { enum_obj }
+	}
+}
@@ -0,0 +1,134 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::oleaut::decl::INetwork;
+use crate::prelude::oleaut_IDispatch;
+use crate::vt::IDispatchVT;
+
+/// [`IEnumNetworks`](crate::IEnumNetworks) virtual table.
+#[repr(C)]
+pub struct IEnumNetworksVT {
+	pub IDispatchVT: IDispatchVT,
+	pub get__NewEnum: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub Next: fn(ComPtr, u32, *mut ComPtr, *mut u32) -> HRES,
+	pub Skip: fn(ComPtr, u32) -> HRES,
+	pub Reset: fn(ComPtr) -> HRES,
+	pub Clone: fn(ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IEnumNetworks: "dcb00003-570f-4a9b-8d69-199fdba5723b";
+	/// [`IEnumNetworks`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nn-netlistmgr-ienumnetworks)
+	/// COM interface over [`IEnumNetworksVT`](crate::vt::IEnumNetworksVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for IEnumNetworks {}
+impl oleaut_IEnumNetworks for IEnumNetworks {}
+
+/// This trait is enabled with the `oleaut` feature, and provides methods for
+/// [`IEnumNetworks`](crate::IEnumNetworks).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait oleaut_IEnumNetworks: oleaut_IDispatch {
+	/// Returns an iterator over the [`INetwork`](crate::INetwork) elements
+	/// which calls
+	/// [`IEnumNetworks::Next`](crate::prelude::oleaut_IEnumNetworks::Next)
+	/// internally.
+	#[must_use]
+	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<INetwork>> + '_> {
+		Box::new(EnumNetworksIter::new(self))
+	}
+
+	/// [`IEnumNetworks::Clone`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-ienumnetworks-clone)
+	/// method.
+	#[must_use]
+	fn Clone(&self) -> HrResult<IEnumNetworks> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumNetworksVT>();
+			ok_to_hrresult((vt.Clone)(self.ptr(), &mut ppv_queried))
+				.map(|_| IEnumNetworks::from(ppv_queried))
+		}
+	}
+
+	/// [`IEnumNetworks::Next`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-ienumnetworks-next)
+	/// method.
+	///
+	/// Prefer using
+	/// [`IEnumNetworks::iter`](crate::prelude::oleaut_IEnumNetworks::iter), which is
+	/// simpler.
+	#[must_use]
+	fn Next(&self) -> HrResult<Option<INetwork>> {
+		let mut fetched = u32::default();
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumNetworksVT>();
+			match ok_to_hrresult(
+				(vt.Next)(self.ptr(), 1, &mut ppv_queried, &mut fetched), // retrieve only 1
+			) {
+				Ok(_) => Ok(Some(INetwork::from(ppv_queried))),
+				Err(hr) => match hr {
+					co::HRESULT::S_FALSE => Ok(None), // no item found
+					hr => Err(hr), // actual error
+				},
+			}
+		}
+	}
+
+	/// [`IEnumNetworks::Reset`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-ienumnetworks-reset)
+	/// method.
+	fn Reset(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumNetworksVT>();
+			ok_to_hrresult((vt.Reset)(self.ptr()))
+		}
+	}
+
+	/// [`IEnumNetworks::Skip`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-ienumnetworks-skip)
+	/// method.
+	fn Skip(&self, count: u32) -> HrResult<bool> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumNetworksVT>();
+			okfalse_to_hrresult((vt.Skip)(self.ptr(), count))
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct EnumNetworksIter<'a, I>
+	where I: oleaut_IEnumNetworks,
+{
+	enum_obj: &'a I,
+}
+
+impl<'a, I> Iterator for EnumNetworksIter<'a, I>
+	where I: oleaut_IEnumNetworks,
+{
+	type Item = HrResult<INetwork>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.enum_obj.Next() {
+			Err(err) => Some(Err(err)),
+			Ok(maybe_item) => maybe_item.map(Ok),
+		}
+	}
+}
+
+impl<'a, I> EnumNetworksIter<'a, I>
+	where I: oleaut_IEnumNetworks,
+{
+	fn new(enum_obj: &'a I) -> Self {
+		Self { enum_obj }
+	}
+}
@@ -0,0 +1,204 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::{FILETIME, GUID};
+use crate::kernel::ffi_types::{HRES, PSTR, PVOID};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::{BSTR, IEnumNetworkConnections};
+use crate::prelude::oleaut_IDispatch;
+use crate::vt::IDispatchVT;
+
+/// [`INetwork`](crate::INetwork) virtual table.
+#[repr(C)]
+pub struct INetworkVT {
+	pub IDispatchVT: IDispatchVT,
+	pub GetName: fn(ComPtr, *mut PSTR) -> HRES,
+	pub SetName: fn(ComPtr, PSTR) -> HRES,
+	pub GetDescription: fn(ComPtr, *mut PSTR) -> HRES,
+	pub SetDescription: fn(ComPtr, PSTR) -> HRES,
+	pub GetNetworkId: fn(ComPtr, PVOID) -> HRES,
+	pub GetDomainType: fn(ComPtr, *mut u32) -> HRES,
+	pub GetNetworkConnections: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub GetTimeCreatedAndConnected: fn(ComPtr, *mut u32, *mut u32, *mut u32, *mut u32) -> HRES,
+	pub get_IsConnectedToInternet: fn(ComPtr, *mut i16) -> HRES,
+	pub get_IsConnected: fn(ComPtr, *mut i16) -> HRES,
+	pub GetConnectivity: fn(ComPtr, *mut u32) -> HRES,
+	pub GetCategory: fn(ComPtr, *mut u32) -> HRES,
+	pub SetCategory: fn(ComPtr, u32) -> HRES,
+}
+
+com_interface! { INetwork: "dcb00002-570f-4a9b-8d69-199fdba5723b";
+	/// [`INetwork`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nn-netlistmgr-inetwork)
+	/// COM interface over [`INetworkVT`](crate::vt::INetworkVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for INetwork {}
+impl oleaut_INetwork for INetwork {}
+
+/// This trait is enabled with the `oleaut` feature, and provides methods for
+/// [`INetwork`](crate::INetwork).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait oleaut_INetwork: oleaut_IDispatch {
+	/// [`INetwork::get_IsConnected`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetwork-get_isconnected)
+	/// method.
+	#[must_use]
+	fn get_IsConnected(&self) -> HrResult<bool> {
+		let mut connected = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkVT>();
+			ok_to_hrresult((vt.get_IsConnected)(self.ptr(), &mut connected))
+		}.map(|_| connected != 0)
+	}
+
+	/// [`INetwork::get_IsConnectedToInternet`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetwork-get_isconnectedtointernet)
+	/// method.
+	#[must_use]
+	fn get_IsConnectedToInternet(&self) -> HrResult<bool> {
+		let mut connected = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkVT>();
+			ok_to_hrresult((vt.get_IsConnectedToInternet)(self.ptr(), &mut connected))
+		}.map(|_| connected != 0)
+	}
+
+	/// [`INetwork::GetCategory`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetwork-getcategory)
+	/// method.
+	#[must_use]
+	fn GetCategory(&self) -> HrResult<co::NLM_NETWORK_CATEGORY> {
+		let mut category = co::NLM_NETWORK_CATEGORY::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkVT>();
+			ok_to_hrresult((vt.GetCategory)(self.ptr(), &mut category.0))
+		}.map(|_| category)
+	}
+
+	/// [`INetwork::GetConnectivity`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetwork-getconnectivity)
+	/// method.
+	#[must_use]
+	fn GetConnectivity(&self) -> HrResult<co::NLM_CONNECTIVITY> {
+		let mut connectivity = co::NLM_CONNECTIVITY::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkVT>();
+			ok_to_hrresult((vt.GetConnectivity)(self.ptr(), &mut connectivity.0))
+		}.map(|_| connectivity)
+	}
+
+	/// [`INetwork::GetDescription`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetwork-getdescription)
+	/// method.
+	#[must_use]
+	fn GetDescription(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkVT>();
+			ok_to_hrresult((vt.GetDescription)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`INetwork::GetDomainType`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetwork-getdomaintype)
+	/// method.
+	#[must_use]
+	fn GetDomainType(&self) -> HrResult<co::NLM_DOMAIN_TYPE> {
+		let mut domain_type = co::NLM_DOMAIN_TYPE::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkVT>();
+			ok_to_hrresult((vt.GetDomainType)(self.ptr(), &mut domain_type.0))
+		}.map(|_| domain_type)
+	}
+
+	/// [`INetwork::GetName`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetwork-getname)
+	/// method.
+	#[must_use]
+	fn GetName(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkVT>();
+			ok_to_hrresult((vt.GetName)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`INetwork::GetNetworkConnections`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetwork-getnetworkconnections)
+	/// method.
+	#[must_use]
+	fn GetNetworkConnections(&self) -> HrResult<IEnumNetworkConnections> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<INetworkVT>();
+			ok_to_hrresult((vt.GetNetworkConnections)(self.ptr(), &mut ppv_queried))
+				.map(|_| IEnumNetworkConnections::from(ppv_queried))
+		}
+	}
+
+	/// [`INetwork::GetNetworkId`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetwork-getnetworkid)
+	/// method.
+	#[must_use]
+	fn GetNetworkId(&self) -> HrResult<GUID> {
+		let mut id = GUID::new("00000000-0000-0000-0000-000000000000"); // just a placeholder
+		unsafe {
+			let vt = self.vt_ref::<INetworkVT>();
+			ok_to_hrresult((vt.GetNetworkId)(self.ptr(), &mut id as *mut _ as _))
+		}.map(|_| id)
+	}
+
+	/// [`INetwork::GetTimeCreatedAndConnected`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetwork-gettimecreatedandconnected)
+	/// method.
+	///
+	/// Returns the creation time and the last connection time.
+	#[must_use]
+	fn GetTimeCreatedAndConnected(&self) -> HrResult<(FILETIME, FILETIME)> {
+		let mut created = FILETIME::default();
+		let mut connected = FILETIME::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkVT>();
+			ok_to_hrresult(
+				(vt.GetTimeCreatedAndConnected)(
+					self.ptr(),
+					&mut created.dwLowDateTime,
+					&mut created.dwHighDateTime,
+					&mut connected.dwLowDateTime,
+					&mut connected.dwHighDateTime,
+				),
+			)
+		}.map(|_| (created, connected))
+	}
+
+	/// [`INetwork::SetCategory`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetwork-setcategory)
+	/// method.
+	///
+	/// Requires administrative privileges.
+	fn SetCategory(&self, category: co::NLM_NETWORK_CATEGORY) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<INetworkVT>();
+			ok_to_hrresult((vt.SetCategory)(self.ptr(), category.0))
+		}
+	}
+
+	/// [`INetwork::SetDescription`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetwork-setdescription)
+	/// method.
+	fn SetDescription(&self, description: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(description)?;
+		unsafe {
+			let vt = self.vt_ref::<INetworkVT>();
+			ok_to_hrresult((vt.SetDescription)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`INetwork::SetName`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetwork-setname)
+	/// method.
+	fn SetName(&self, name: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(name)?;
+		unsafe {
+			let vt = self.vt_ref::<INetworkVT>();
+			ok_to_hrresult((vt.SetName)(self.ptr(), bstr.0))
+		}
+	}
+}
@@ -0,0 +1,124 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::GUID;
+use crate::kernel::ffi_types::{HRES, PVOID};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::INetwork;
+use crate::prelude::oleaut_IDispatch;
+use crate::vt::IDispatchVT;
+
+/// [`INetworkConnection`](crate::INetworkConnection) virtual table.
+#[repr(C)]
+pub struct INetworkConnectionVT {
+	pub IDispatchVT: IDispatchVT,
+	pub GetNetwork: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_IsConnectedToInternet: fn(ComPtr, *mut i16) -> HRES,
+	pub get_IsConnected: fn(ComPtr, *mut i16) -> HRES,
+	pub GetConnectivity: fn(ComPtr, *mut u32) -> HRES,
+	pub GetConnectionId: fn(ComPtr, PVOID) -> HRES,
+	pub GetAdapterId: fn(ComPtr, PVOID) -> HRES,
+	pub GetDomainType: fn(ComPtr, *mut u32) -> HRES,
+}
+
+com_interface! { INetworkConnection: "dcb00005-570f-4a9b-8d69-199fdba5723b";
+	/// [`INetworkConnection`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nn-netlistmgr-inetworkconnection)
+	/// COM interface over
+	/// [`INetworkConnectionVT`](crate::vt::INetworkConnectionVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for INetworkConnection {}
+impl oleaut_INetworkConnection for INetworkConnection {}
+
+/// This trait is enabled with the `oleaut` feature, and provides methods for
+/// [`INetworkConnection`](crate::INetworkConnection).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait oleaut_INetworkConnection: oleaut_IDispatch {
+	/// [`INetworkConnection::get_IsConnected`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworkconnection-get_isconnected)
+	/// method.
+	#[must_use]
+	fn get_IsConnected(&self) -> HrResult<bool> {
+		let mut connected = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkConnectionVT>();
+			ok_to_hrresult((vt.get_IsConnected)(self.ptr(), &mut connected))
+		}.map(|_| connected != 0)
+	}
+
+	/// [`INetworkConnection::get_IsConnectedToInternet`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworkconnection-get_isconnectedtointernet)
+	/// method.
+	#[must_use]
+	fn get_IsConnectedToInternet(&self) -> HrResult<bool> {
+		let mut connected = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkConnectionVT>();
+			ok_to_hrresult((vt.get_IsConnectedToInternet)(self.ptr(), &mut connected))
+		}.map(|_| connected != 0)
+	}
+
+	/// [`INetworkConnection::GetAdapterId`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworkconnection-getadapterid)
+	/// method.
+	#[must_use]
+	fn GetAdapterId(&self) -> HrResult<GUID> {
+		let mut id = GUID::new("00000000-0000-0000-0000-000000000000"); // just a placeholder
+		unsafe {
+			let vt = self.vt_ref::<INetworkConnectionVT>();
+			ok_to_hrresult((vt.GetAdapterId)(self.ptr(), &mut id as *mut _ as _))
+		}.map(|_| id)
+	}
+
+	/// [`INetworkConnection::GetConnectionId`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworkconnection-getconnectionid)
+	/// method.
+	#[must_use]
+	fn GetConnectionId(&self) -> HrResult<GUID> {
+		let mut id = GUID::new("00000000-0000-0000-0000-000000000000"); // just a placeholder
+		unsafe {
+			let vt = self.vt_ref::<INetworkConnectionVT>();
+			ok_to_hrresult((vt.GetConnectionId)(self.ptr(), &mut id as *mut _ as _))
+		}.map(|_| id)
+	}
+
+	/// [`INetworkConnection::GetConnectivity`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworkconnection-getconnectivity)
+	/// method.
+	#[must_use]
+	fn GetConnectivity(&self) -> HrResult<co::NLM_CONNECTIVITY> {
+		let mut connectivity = co::NLM_CONNECTIVITY::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkConnectionVT>();
+			ok_to_hrresult((vt.GetConnectivity)(self.ptr(), &mut connectivity.0))
+		}.map(|_| connectivity)
+	}
+
+	/// [`INetworkConnection::GetDomainType`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworkconnection-getdomaintype)
+	/// method.
+	#[must_use]
+	fn GetDomainType(&self) -> HrResult<co::NLM_DOMAIN_TYPE> {
+		let mut domain_type = co::NLM_DOMAIN_TYPE::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkConnectionVT>();
+			ok_to_hrresult((vt.GetDomainType)(self.ptr(), &mut domain_type.0))
+		}.map(|_| domain_type)
+	}
+
+	/// [`INetworkConnection::GetNetwork`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworkconnection-getnetwork)
+	/// method.
+	#[must_use]
+	fn GetNetwork(&self) -> HrResult<INetwork> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<INetworkConnectionVT>();
+			ok_to_hrresult((vt.GetNetwork)(self.ptr(), &mut ppv_queried))
+				.map(|_| INetwork::from(ppv_queried))
+		}
+	}
+}
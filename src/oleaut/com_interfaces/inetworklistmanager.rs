@@ -0,0 +1,189 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::GUID;
+use crate::kernel::ffi_types::{HRES, PCVOID};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::{
+	IEnumNetworkConnections, IEnumNetworks, INetwork, INetworkConnection,
+};
+use crate::prelude::oleaut_IDispatch;
+use crate::vt::IDispatchVT;
+
+/// [`INetworkListManager`](crate::INetworkListManager) virtual table.
+#[repr(C)]
+pub struct INetworkListManagerVT {
+	pub IDispatchVT: IDispatchVT,
+	pub GetNetworks: fn(ComPtr, u32, *mut ComPtr) -> HRES,
+	pub GetNetwork: fn(ComPtr, PCVOID, *mut ComPtr) -> HRES,
+	pub GetNetworkConnections: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub GetNetworkConnection: fn(ComPtr, PCVOID, *mut ComPtr) -> HRES,
+	pub get_IsConnectedToInternet: fn(ComPtr, *mut i16) -> HRES,
+	pub get_IsConnected: fn(ComPtr, *mut i16) -> HRES,
+	pub GetConnectivity: fn(ComPtr, *mut u32) -> HRES,
+	pub SetSimulatedProfileInfo: fn(ComPtr, PCVOID) -> HRES,
+	pub ClearSimulatedProfileInfo: fn(ComPtr) -> HRES,
+}
+
+com_interface! { INetworkListManager: "dcb00000-570f-4a9b-8d69-199fdba5723b";
+	/// [`INetworkListManager`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nn-netlistmgr-inetworklistmanager)
+	/// COM interface over
+	/// [`INetworkListManagerVT`](crate::vt::INetworkListManagerVT).
+	///
+	/// Connectivity changes can be monitored by advising a
+	/// [`NetworkListManagerEvents`](crate::NetworkListManagerEvents) to the
+	/// connection point of
+	/// [`INetworkListManagerEvents`](crate::INetworkListManagerEvents).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, INetworkListManager};
+	///
+	/// let nlm = CoCreateInstance::<INetworkListManager>(
+	///     &co::CLSID::NetworkListManager,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	///
+	/// if nlm.get_IsConnectedToInternet()? {
+	///     println!("Online.");
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl oleaut_IDispatch for INetworkListManager {}
+impl oleaut_INetworkListManager for INetworkListManager {}
+
+/// This trait is enabled with the `oleaut` feature, and provides methods for
+/// [`INetworkListManager`](crate::INetworkListManager).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait oleaut_INetworkListManager: oleaut_IDispatch {
+	/// [`INetworkListManager::ClearSimulatedProfileInfo`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworklistmanager-clearsimulatedprofileinfo)
+	/// method.
+	fn ClearSimulatedProfileInfo(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<INetworkListManagerVT>();
+			ok_to_hrresult((vt.ClearSimulatedProfileInfo)(self.ptr()))
+		}
+	}
+
+	/// [`INetworkListManager::get_IsConnected`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworklistmanager-get_isconnected)
+	/// method.
+	#[must_use]
+	fn get_IsConnected(&self) -> HrResult<bool> {
+		let mut connected = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkListManagerVT>();
+			ok_to_hrresult((vt.get_IsConnected)(self.ptr(), &mut connected))
+		}.map(|_| connected != 0)
+	}
+
+	/// [`INetworkListManager::get_IsConnectedToInternet`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworklistmanager-get_isconnectedtointernet)
+	/// method.
+	#[must_use]
+	fn get_IsConnectedToInternet(&self) -> HrResult<bool> {
+		let mut connected = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkListManagerVT>();
+			ok_to_hrresult((vt.get_IsConnectedToInternet)(self.ptr(), &mut connected))
+		}.map(|_| connected != 0)
+	}
+
+	/// [`INetworkListManager::GetConnectivity`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworklistmanager-getconnectivity)
+	/// method.
+	#[must_use]
+	fn GetConnectivity(&self) -> HrResult<co::NLM_CONNECTIVITY> {
+		let mut connectivity = co::NLM_CONNECTIVITY::default();
+		unsafe {
+			let vt = self.vt_ref::<INetworkListManagerVT>();
+			ok_to_hrresult((vt.GetConnectivity)(self.ptr(), &mut connectivity.0))
+		}.map(|_| connectivity)
+	}
+
+	/// [`INetworkListManager::GetNetwork`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworklistmanager-getnetwork)
+	/// method.
+	#[must_use]
+	fn GetNetwork(&self, network_id: &GUID) -> HrResult<INetwork> {
+		let network_id = *network_id; // passed by value, through a pointer to a copy
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<INetworkListManagerVT>();
+			ok_to_hrresult(
+				(vt.GetNetwork)(self.ptr(), &network_id as *const _ as _, &mut ppv_queried),
+			).map(|_| INetwork::from(ppv_queried))
+		}
+	}
+
+	/// [`INetworkListManager::GetNetworkConnection`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworklistmanager-getnetworkconnection)
+	/// method.
+	#[must_use]
+	fn GetNetworkConnection(&self,
+		connection_id: &GUID) -> HrResult<INetworkConnection>
+	{
+		let connection_id = *connection_id; // passed by value, through a pointer to a copy
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<INetworkListManagerVT>();
+			ok_to_hrresult(
+				(vt.GetNetworkConnection)(
+					self.ptr(),
+					&connection_id as *const _ as _,
+					&mut ppv_queried,
+				),
+			).map(|_| INetworkConnection::from(ppv_queried))
+		}
+	}
+
+	/// [`INetworkListManager::GetNetworkConnections`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworklistmanager-getnetworkconnections)
+	/// method.
+	#[must_use]
+	fn GetNetworkConnections(&self) -> HrResult<IEnumNetworkConnections> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<INetworkListManagerVT>();
+			ok_to_hrresult((vt.GetNetworkConnections)(self.ptr(), &mut ppv_queried))
+				.map(|_| IEnumNetworkConnections::from(ppv_queried))
+		}
+	}
+
+	/// [`INetworkListManager::GetNetworks`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworklistmanager-getnetworks)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, INetworkListManager};
+	///
+	/// let nlm: INetworkListManager; // initialized somewhere
+	/// # let nlm = INetworkListManager::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// for network in nlm.GetNetworks(co::NLM_ENUM_NETWORK::CONNECTED)?.iter() {
+	///     let network = network?;
+	///     println!("{}", network.GetName()?);
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn GetNetworks(&self, flags: co::NLM_ENUM_NETWORK) -> HrResult<IEnumNetworks> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<INetworkListManagerVT>();
+			ok_to_hrresult((vt.GetNetworks)(self.ptr(), flags.0, &mut ppv_queried))
+				.map(|_| IEnumNetworks::from(ppv_queried))
+		}
+	}
+}
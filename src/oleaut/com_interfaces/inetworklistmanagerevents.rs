@@ -0,0 +1,52 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`INetworkListManagerEvents`](crate::INetworkListManagerEvents) virtual
+/// table.
+#[repr(C)]
+pub struct INetworkListManagerEventsVT {
+	pub IUnknownVT: IUnknownVT,
+	pub ConnectivityChanged: fn(ComPtr, u32) -> HRES,
+}
+
+com_interface! { INetworkListManagerEvents: "dcb00001-570f-4a9b-8d69-199fdba5723b";
+	/// [`INetworkListManagerEvents`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nn-netlistmgr-inetworklistmanagerevents)
+	/// COM interface over
+	/// [`INetworkListManagerEventsVT`](crate::vt::INetworkListManagerEventsVT).
+	///
+	/// An implementation can be created with
+	/// [`NetworkListManagerEvents`](crate::NetworkListManagerEvents).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_INetworkListManagerEvents for INetworkListManagerEvents {}
+
+/// This trait is enabled with the `oleaut` feature, and provides methods for
+/// [`INetworkListManagerEvents`](crate::INetworkListManagerEvents).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait oleaut_INetworkListManagerEvents: ole_IUnknown {
+	/// [`INetworkListManagerEvents::ConnectivityChanged`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworklistmanagerevents-connectivitychanged)
+	/// method.
+	fn ConnectivityChanged(&self,
+		connectivity: co::NLM_CONNECTIVITY) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<INetworkListManagerEventsVT>();
+			ok_to_hrresult((vt.ConnectivityChanged)(self.ptr(), connectivity.0))
+		}
+	}
+}
@@ -28,6 +28,8 @@ com_interface! { IPropertyStore: "886d8eeb-8cf2-4446-8d02-cdba1dbdcf99";
 	/// when the object goes out of scope.
 	///
 	/// Usually, this interface is taken via
+	/// [`IShellItem2::GetPropertyStore`](crate::prelude::oleaut_shell_IShellItem2::GetPropertyStore)
+	/// or
 	/// [`IShellItem::BindToHandler`](crate::prelude::shell_IShellItem::BindToHandler).
 }
 
@@ -124,6 +126,24 @@ pub trait oleaut_IPropertyStore: ole_IUnknown {
 			}
 		}
 	}
+
+	/// [`IPropertyStore::SetValue`](https://learn.microsoft.com/en-us/windows/win32/api/propsys/nf-propsys-ipropertystore-setvalue)
+	/// method.
+	///
+	/// Changes are persisted only after
+	/// [`Commit`](crate::prelude::oleaut_IPropertyStore::Commit) is called.
+	fn SetValue(&self, key: &PROPERTYKEY, value: &PROPVARIANT) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IPropertyStoreVT>();
+			ok_to_hrresult(
+				(vt.SetValue)(
+					self.ptr(),
+					key as *const _ as _,
+					value as *const _ as _,
+				),
+			)
+		}
+	}
 }
 
 //------------------------------------------------------------------------------
@@ -1,16 +1,34 @@
 mod idispatch;
+mod ienumnetworkconnections;
+mod ienumnetworks;
+mod inetwork;
+mod inetworkconnection;
+mod inetworklistmanager;
+mod inetworklistmanagerevents;
 mod ipicture;
 mod ipropertystore;
 mod itypeinfo;
 
 pub mod decl {
 	pub use super::idispatch::IDispatch;
+	pub use super::ienumnetworkconnections::IEnumNetworkConnections;
+	pub use super::ienumnetworks::IEnumNetworks;
+	pub use super::inetwork::INetwork;
+	pub use super::inetworkconnection::INetworkConnection;
+	pub use super::inetworklistmanager::INetworkListManager;
+	pub use super::inetworklistmanagerevents::INetworkListManagerEvents;
 	pub use super::ipropertystore::IPropertyStore;
 	pub use super::itypeinfo::ITypeInfo;
 }
 
 pub mod traits {
 	pub use super::idispatch::oleaut_IDispatch;
+	pub use super::ienumnetworkconnections::oleaut_IEnumNetworkConnections;
+	pub use super::ienumnetworks::oleaut_IEnumNetworks;
+	pub use super::inetwork::oleaut_INetwork;
+	pub use super::inetworkconnection::oleaut_INetworkConnection;
+	pub use super::inetworklistmanager::oleaut_INetworkListManager;
+	pub use super::inetworklistmanagerevents::oleaut_INetworkListManagerEvents;
 	pub use super::ipicture::oleaut_IPicture;
 	pub use super::ipropertystore::oleaut_IPropertyStore;
 	pub use super::itypeinfo::oleaut_ITypeInfo;
@@ -18,6 +36,12 @@ pub mod traits {
 
 pub mod vt {
 	pub use super::idispatch::IDispatchVT;
+	pub use super::ienumnetworkconnections::IEnumNetworkConnectionsVT;
+	pub use super::ienumnetworks::IEnumNetworksVT;
+	pub use super::inetwork::INetworkVT;
+	pub use super::inetworkconnection::INetworkConnectionVT;
+	pub use super::inetworklistmanager::INetworkListManagerVT;
+	pub use super::inetworklistmanagerevents::INetworkListManagerEventsVT;
 	pub use super::ipropertystore::IPropertyStoreVT;
 	pub use super::itypeinfo::ITypeInfoVT;
 }
@@ -6,11 +6,13 @@ pub mod co;
 mod com_interfaces;
 mod funcs;
 mod structs;
+mod utilities;
 
 pub mod decl {
 	pub use super::com_interfaces::decl::*;
 	pub use super::funcs::*;
 	pub use super::structs::decl::*;
+	pub use super::utilities::*;
 }
 
 pub mod traits {
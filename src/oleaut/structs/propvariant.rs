@@ -1,6 +1,8 @@
 #![allow(non_snake_case)]
 
 use crate::{co, oleaut};
+use crate::kernel::decl::{FILETIME, GUID, WString};
+use crate::ole::decl::{CoTaskMemAlloc, HrResult};
 use crate::prelude::oleaut_Variant;
 
 /// [`PROPVARIANT`](https://learn.microsoft.com/en-us/windows/win32/api/propidlbase/ns-propidlbase-propvariant)
@@ -52,6 +54,54 @@ impl oleaut_Variant for PROPVARIANT {
 }
 
 impl PROPVARIANT {
+	/// Creates a new object holding a [`GUID`](crate::GUID) value
+	/// (`VT_CLSID`), allocated with [`CoTaskMemAlloc`](crate::CoTaskMemAlloc).
+	#[must_use]
+	pub fn new_clsid(val: &GUID) -> HrResult<Self> {
+		let ptr = unsafe {
+			let ptr = CoTaskMemAlloc(std::mem::size_of::<GUID>())? as *mut GUID;
+			ptr.write(*val);
+			ptr as usize
+		};
+		Ok(unsafe { Self::from_raw(co::VT::CLSID, &ptr.to_ne_bytes()) })
+	}
+
+	/// If the object holds a [`GUID`](crate::GUID) value (`VT_CLSID`), returns
+	/// it, otherwise `None`.
+	#[must_use]
+	pub fn clsid(&self) -> Option<GUID> {
+		if self.vt() == co::VT::CLSID {
+			let ptr = usize::from_ne_bytes(unsafe { self.raw() }[..std::mem::size_of::<usize>()].try_into().unwrap());
+			Some(unsafe { *(ptr as *const GUID) })
+		} else {
+			None
+		}
+	}
+
+	/// Creates a new object holding a [`FILETIME`](crate::FILETIME) value.
+	#[must_use]
+	pub fn new_filetime(val: &FILETIME) -> Self {
+		let mut data = [0u8; 8];
+		data[..4].copy_from_slice(&val.dwLowDateTime.to_ne_bytes());
+		data[4..].copy_from_slice(&val.dwHighDateTime.to_ne_bytes());
+		unsafe { Self::from_raw(co::VT::FILETIME, &data) }
+	}
+
+	/// If the object holds a [`FILETIME`](crate::FILETIME) value, returns it,
+	/// otherwise `None`.
+	#[must_use]
+	pub fn filetime(&self) -> Option<FILETIME> {
+		if self.vt() == co::VT::FILETIME {
+			let raw = unsafe { self.raw() };
+			Some(FILETIME {
+				dwLowDateTime: u32::from_ne_bytes(raw[..4].try_into().unwrap()),
+				dwHighDateTime: u32::from_ne_bytes(raw[4..8].try_into().unwrap()),
+			})
+		} else {
+			None
+		}
+	}
+
 	/// Creates a new object holding an `i64` value.
 	#[must_use]
 	pub fn new_i64(val: i64) -> Self {
@@ -68,6 +118,32 @@ impl PROPVARIANT {
 		}
 	}
 
+	/// Creates a new object holding a null-terminated wide string
+	/// (`VT_LPWSTR`), allocated with [`CoTaskMemAlloc`](crate::CoTaskMemAlloc).
+	#[must_use]
+	pub fn new_lpwstr(val: &str) -> HrResult<Self> {
+		let wstr = WString::from_str(val);
+		let src = wstr.as_slice();
+		let ptr = unsafe {
+			let ptr = CoTaskMemAlloc(src.len() * std::mem::size_of::<u16>())? as *mut u16;
+			std::ptr::copy_nonoverlapping(src.as_ptr(), ptr, src.len()); // includes terminating null
+			ptr as usize
+		};
+		Ok(unsafe { Self::from_raw(co::VT::LPWSTR, &ptr.to_ne_bytes()) })
+	}
+
+	/// If the object holds a null-terminated wide string (`VT_LPWSTR`), returns
+	/// it, otherwise `None`.
+	#[must_use]
+	pub fn lpwstr(&self) -> Option<String> {
+		if self.vt() == co::VT::LPWSTR {
+			let ptr = usize::from_ne_bytes(unsafe { self.raw() }[..std::mem::size_of::<usize>()].try_into().unwrap());
+			Some(WString::from_wchars_nullt(ptr as _).to_string())
+		} else {
+			None
+		}
+	}
+
 	/// Creates a new object holding an `u64` value.
 	#[must_use]
 	pub fn new_u64(val: u64) -> Self {
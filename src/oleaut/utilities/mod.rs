@@ -0,0 +1,3 @@
+mod network_list_manager_events;
+
+pub use network_list_manager_events::NetworkListManagerEvents;
@@ -0,0 +1,153 @@
+#![allow(non_snake_case)]
+
+use std::sync::atomic::{AtomicU32, fence, Ordering};
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PCVOID};
+use crate::ole::decl::{ComPtr, HrResult, IUnknown};
+use crate::ole::privs::catch_com_panic;
+use crate::oleaut::decl::INetworkListManagerEvents;
+use crate::prelude::ole_IUnknown;
+use crate::vt::{INetworkListManagerEventsVT, IUnknownVT};
+
+/// Builder of an
+/// [`INetworkListManagerEvents`](crate::INetworkListManagerEvents)
+/// implemented in Rust, which runs the given closure when the connectivity of
+/// the machine changes.
+///
+/// The built object is passed to
+/// [`IConnectionPoint::Advise`](crate::prelude::ole_IConnectionPoint::Advise),
+/// on the connection point of the
+/// [`INetworkListManager`](crate::INetworkListManager). The closure is called
+/// in the thread which called `Advise`, which must run a message loop.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{
+///     co, CoCreateInstance, IConnectionPointContainer,
+///     INetworkListManager, INetworkListManagerEvents, NetworkListManagerEvents,
+/// };
+///
+/// let nlm = CoCreateInstance::<INetworkListManager>(
+///     &co::CLSID::NetworkListManager,
+///     None,
+///     co::CLSCTX::INPROC_SERVER,
+/// )?;
+///
+/// let events = NetworkListManagerEvents::new()
+///     .connectivity_changed(|connectivity| {
+///         let online = connectivity.has(co::NLM_CONNECTIVITY::IPV4_INTERNET)
+///             || connectivity.has(co::NLM_CONNECTIVITY::IPV6_INTERNET);
+///         println!("Online: {}", online);
+///         Ok(())
+///     })
+///     .build();
+///
+/// let cp = nlm.QueryInterface::<IConnectionPointContainer>()?
+///     .FindConnectionPoint(&INetworkListManagerEvents::IID)?;
+/// let cookie = cp.Advise(&events)?;
+///
+/// // ...
+///
+/// cp.Unadvise(cookie)?;
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+#[derive(Default)]
+pub struct NetworkListManagerEvents {
+	connectivity_changed: Option<Box<dyn Fn(co::NLM_CONNECTIVITY) -> HrResult<()>>>,
+}
+
+impl NetworkListManagerEvents {
+	/// Creates a new builder, with no closures.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// [`INetworkListManagerEvents::ConnectivityChanged`](https://learn.microsoft.com/en-us/windows/win32/api/netlistmgr/nf-netlistmgr-inetworklistmanagerevents-connectivitychanged)
+	/// event, called when the overall connectivity of the machine changes.
+	#[must_use]
+	pub fn connectivity_changed<F>(mut self, func: F) -> Self
+		where F: Fn(co::NLM_CONNECTIVITY) -> HrResult<()> + 'static,
+	{
+		self.connectivity_changed = Some(Box::new(func));
+		self
+	}
+
+	/// Creates the
+	/// [`INetworkListManagerEvents`](crate::INetworkListManagerEvents)
+	/// object.
+	#[must_use]
+	pub fn build(self) -> INetworkListManagerEvents {
+		let obj = Box::new(NetworkListManagerEventsImpl {
+			vt: &NETWORK_LIST_MANAGER_EVENTS_VT,
+			ref_count: AtomicU32::new(1),
+			events: self,
+		});
+		INetworkListManagerEvents::from(ComPtr(Box::into_raw(obj) as _))
+	}
+}
+
+//------------------------------------------------------------------------------
+
+static NETWORK_LIST_MANAGER_EVENTS_VT: INetworkListManagerEventsVT = INetworkListManagerEventsVT {
+	IUnknownVT: IUnknownVT {
+		QueryInterface: NetworkListManagerEventsImpl::QueryInterface,
+		AddRef: NetworkListManagerEventsImpl::AddRef,
+		Release: NetworkListManagerEventsImpl::Release,
+	},
+	ConnectivityChanged: NetworkListManagerEventsImpl::ConnectivityChanged,
+};
+
+/// Implementation of the `INetworkListManagerEvents` COM interface.
+#[repr(C)]
+struct NetworkListManagerEventsImpl {
+	vt: *const INetworkListManagerEventsVT, // must be the first field, as in any COM object
+	ref_count: AtomicU32,
+	events: NetworkListManagerEvents,
+}
+
+impl NetworkListManagerEventsImpl {
+	fn from_com_ptr<'a>(p: ComPtr) -> &'a Self {
+		unsafe { &*(p.0 as *const Self) }
+	}
+
+	fn QueryInterface(p: ComPtr, riid: PCVOID, ppv: *mut ComPtr) -> HRES {
+		let riid = unsafe { &*(riid as *const co::IID) };
+		if *riid == IUnknown::IID || *riid == INetworkListManagerEvents::IID {
+			Self::AddRef(p);
+			unsafe { *ppv = p; }
+			co::HRESULT::S_OK.0
+		} else {
+			unsafe { *ppv = ComPtr::null(); }
+			co::HRESULT::E_NOINTERFACE.0
+		}
+	}
+
+	fn AddRef(p: ComPtr) -> u32 {
+		Self::from_com_ptr(p).ref_count.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn Release(p: ComPtr) -> u32 {
+		let count = Self::from_com_ptr(p).ref_count.fetch_sub(1, Ordering::Release) - 1;
+		if count == 0 {
+			fence(Ordering::Acquire); // see the drop of std::sync::Arc
+			let _ = unsafe { Box::from_raw(p.0 as *mut Self) };
+		}
+		count
+	}
+
+	fn ConnectivityChanged(p: ComPtr, connectivity: u32) -> HRES {
+		catch_com_panic(|| {
+			match &Self::from_com_ptr(p).events.connectivity_changed {
+				Some(func) => match func(co::NLM_CONNECTIVITY(connectivity)) {
+					Ok(_) => co::HRESULT::S_OK.0,
+					Err(hr) => hr.0,
+				},
+				None => co::HRESULT::S_OK.0,
+			}
+		})
+	}
+}
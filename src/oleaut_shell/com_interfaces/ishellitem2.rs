@@ -0,0 +1,173 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::{FILETIME, WString};
+use crate::kernel::ffi_types::BOOL;
+use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::{IPropertyStore, PROPERTYKEY, PROPVARIANT};
+use crate::prelude::{ole_IUnknown, shell_IShellItem2};
+use crate::shell::decl::IShellItem2;
+use crate::vt::IShellItem2VT;
+
+impl oleaut_shell_IShellItem2 for IShellItem2 {}
+
+/// This trait is enabled with `oleaut` and `shell` features, and provides
+/// methods for [`IShellItem2`](crate::IShellItem2).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait oleaut_shell_IShellItem2: shell_IShellItem2 {
+	/// [`IShellItem2::GetBool`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem2-getbool)
+	/// method.
+	#[must_use]
+	fn GetBool(&self, key: &PROPERTYKEY) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IShellItem2VT>();
+			ok_to_hrresult((vt.GetBool)(self.ptr(), key as *const _ as _, &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IShellItem2::GetCLSID`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem2-getclsid)
+	/// method.
+	#[must_use]
+	fn GetCLSID(&self, key: &PROPERTYKEY) -> HrResult<co::CLSID> {
+		let mut clsid = co::CLSID::default();
+		unsafe {
+			let vt = self.vt_ref::<IShellItem2VT>();
+			ok_to_hrresult(
+				(vt.GetCLSID)(
+					self.ptr(),
+					key as *const _ as _,
+					&mut clsid as *mut _ as _,
+				),
+			)
+		}.map(|_| clsid)
+	}
+
+	/// [`IShellItem2::GetFileTime`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem2-getfiletime)
+	/// method.
+	#[must_use]
+	fn GetFileTime(&self, key: &PROPERTYKEY) -> HrResult<FILETIME> {
+		let mut ft = FILETIME::default();
+		unsafe {
+			let vt = self.vt_ref::<IShellItem2VT>();
+			ok_to_hrresult(
+				(vt.GetFileTime)(
+					self.ptr(),
+					key as *const _ as _,
+					&mut ft as *mut _ as _,
+				),
+			)
+		}.map(|_| ft)
+	}
+
+	/// [`IShellItem2::GetInt32`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem2-getint32)
+	/// method.
+	#[must_use]
+	fn GetInt32(&self, key: &PROPERTYKEY) -> HrResult<i32> {
+		let mut val = i32::default();
+		unsafe {
+			let vt = self.vt_ref::<IShellItem2VT>();
+			ok_to_hrresult((vt.GetInt32)(self.ptr(), key as *const _ as _, &mut val))
+		}.map(|_| val)
+	}
+
+	/// [`IShellItem2::GetProperty`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem2-getproperty)
+	/// method.
+	#[must_use]
+	fn GetProperty(&self, key: &PROPERTYKEY) -> HrResult<PROPVARIANT> {
+		let mut var = PROPVARIANT::default();
+		unsafe {
+			let vt = self.vt_ref::<IShellItem2VT>();
+			ok_to_hrresult(
+				(vt.GetProperty)(
+					self.ptr(),
+					key as *const _ as _,
+					&mut var as *mut _ as _,
+				),
+			)
+		}.map(|_| var)
+	}
+
+	/// [`IShellItem2::GetPropertyStore`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem2-getpropertystore)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Listing the metadata of a file:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IBindCtx, IShellItem2, SHCreateItemFromParsingName};
+	///
+	/// let item = SHCreateItemFromParsingName::<IShellItem2>(
+	///     "C:\\Temp\\foo.mp3",
+	///     None::<&IBindCtx>,
+	/// )?;
+	/// let store = item.GetPropertyStore(co::GPS::DEFAULT)?;
+	///
+	/// for key in store.iter()? {
+	///     let key = key?;
+	///     let val = store.GetValue(&key)?;
+	///     println!("{} {}: {:?}", key.fmtid, key.pid, val.lpwstr());
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn GetPropertyStore(&self, flags: co::GPS) -> HrResult<IPropertyStore> {
+		unsafe {
+			let mut queried = ComPtr::null();
+			let vt = self.vt_ref::<IShellItem2VT>();
+			ok_to_hrresult(
+				(vt.GetPropertyStore)(
+					self.ptr(),
+					flags.0,
+					&IPropertyStore::IID as *const _ as _,
+					&mut queried,
+				),
+			).map(|_| IPropertyStore::from(queried))
+		}
+	}
+
+	/// [`IShellItem2::GetString`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem2-getstring)
+	/// method.
+	#[must_use]
+	fn GetString(&self, key: &PROPERTYKEY) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IShellItem2VT>();
+			ok_to_hrresult((vt.GetString)(self.ptr(), key as *const _ as _, &mut pstr))
+		}.map(|_| {
+			let s = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			s.to_string()
+		})
+	}
+
+	/// [`IShellItem2::GetUInt32`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem2-getuint32)
+	/// method.
+	#[must_use]
+	fn GetUInt32(&self, key: &PROPERTYKEY) -> HrResult<u32> {
+		let mut val = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IShellItem2VT>();
+			ok_to_hrresult((vt.GetUInt32)(self.ptr(), key as *const _ as _, &mut val))
+		}.map(|_| val)
+	}
+
+	/// [`IShellItem2::GetUInt64`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem2-getuint64)
+	/// method.
+	#[must_use]
+	fn GetUInt64(&self, key: &PROPERTYKEY) -> HrResult<u64> {
+		let mut val = u64::default();
+		unsafe {
+			let vt = self.vt_ref::<IShellItem2VT>();
+			ok_to_hrresult((vt.GetUInt64)(self.ptr(), key as *const _ as _, &mut val))
+		}.map(|_| val)
+	}
+}
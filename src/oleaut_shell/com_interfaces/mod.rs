@@ -0,0 +1,5 @@
+mod ishellitem2;
+
+pub mod traits {
+	pub use super::ishellitem2::oleaut_shell_IShellItem2;
+}
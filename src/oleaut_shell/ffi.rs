@@ -0,0 +1,6 @@
+use crate::kernel::ffi_types::{HANDLE, HRES, PCVOID};
+use crate::ole::decl::ComPtr;
+
+extern_sys! { "shell32";
+	SHGetPropertyStoreForWindow(HANDLE, PCVOID, *mut ComPtr) -> HRES
+}
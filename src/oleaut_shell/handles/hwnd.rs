@@ -0,0 +1,62 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::oleaut_shell;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::IPropertyStore;
+use crate::prelude::{Handle, ole_IUnknown};
+use crate::user::decl::HWND;
+
+impl oleaut_shell_Hwnd for HWND {}
+
+/// This trait is enabled with `oleaut` and `shell` features, and provides
+/// methods for [`HWND`](crate::HWND).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait oleaut_shell_Hwnd: Handle {
+	/// [`SHGetPropertyStoreForWindow`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shgetpropertystoreforwindow)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Setting the
+	/// [AppUserModelID](https://learn.microsoft.com/en-us/windows/win32/shell/appids)
+	/// of a window, so it's grouped separately in the taskbar:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{GUID, HWND, PROPERTYKEY, PROPVARIANT};
+	///
+	/// const PKEY_APPUSERMODEL_ID: PROPERTYKEY = PROPERTYKEY {
+	///     fmtid: GUID::new("9f4c2855-9f79-4b39-a8d0-e1d42de1d5f3"),
+	///     pid: 5,
+	/// };
+	///
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	///
+	/// let store = hwnd.SHGetPropertyStoreForWindow()?;
+	/// store.SetValue(
+	///     &PKEY_APPUSERMODEL_ID,
+	///     &PROPVARIANT::new_lpwstr("MyCompany.MyApp")?,
+	/// )?;
+	/// # Ok::<_, winsafe::co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn SHGetPropertyStoreForWindow(&self) -> HrResult<IPropertyStore> {
+		let mut queried = unsafe { ComPtr::null() };
+		ok_to_hrresult(
+			unsafe {
+				oleaut_shell::ffi::SHGetPropertyStoreForWindow(
+					self.as_ptr(),
+					&IPropertyStore::IID as *const _ as _,
+					&mut queried,
+				)
+			},
+		).map(|_| IPropertyStore::from(queried))
+	}
+}
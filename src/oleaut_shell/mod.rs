@@ -0,0 +1,16 @@
+#![cfg_attr(docsrs, doc(cfg(all(feature = "oleaut", feature = "shell"))))]
+
+pub(in crate::oleaut_shell) mod ffi;
+
+mod com_interfaces;
+mod handles;
+mod utilities;
+
+pub mod decl {
+	pub use super::utilities::*;
+}
+
+pub mod traits {
+	pub use super::com_interfaces::traits::*;
+	pub use super::handles::traits::*;
+}
@@ -0,0 +1,259 @@
+use crate::co;
+use crate::kernel::decl::GUID;
+use crate::ole::decl::{CoCreateInstance, HrResult};
+use crate::oleaut::decl::{IPropertyStore, PROPERTYKEY, PROPVARIANT};
+use crate::prelude::{
+	ole_IUnknown, oleaut_IPropertyStore, oleaut_Variant,
+	shell_ICustomDestinationList, shell_IObjectArray, shell_IObjectCollection,
+	shell_IShellLink,
+};
+use crate::shell::decl::{
+	ICustomDestinationList, IObjectArray, IObjectCollection, IShellLink,
+};
+
+/// [`PKEY_Title`](https://learn.microsoft.com/en-us/windows/win32/properties/props-system-title)
+/// property key.
+const PKEY_TITLE: PROPERTYKEY = PROPERTYKEY {
+	fmtid: GUID::new("f29f85e0-4ff9-1068-ab91-08002b27b3d9"),
+	pid: 2,
+};
+
+/// [`PKEY_AppUserModel_IsDestListSeparator`](https://learn.microsoft.com/en-us/windows/win32/properties/props-system-appusermodel-isdestlistseparator)
+/// property key.
+const PKEY_APPUSERMODEL_ISDESTLISTSEPARATOR: PROPERTYKEY = PROPERTYKEY {
+	fmtid: GUID::new("9f4c2855-9f79-4b39-a8d0-e1d42de1d5f3"),
+	pid: 6,
+};
+
+enum Task {
+	Item(JumpListItem),
+	Separator,
+}
+
+enum Category {
+	Custom {
+		title: String,
+		items: Vec<JumpListItem>,
+	},
+	Known(co::KDC),
+}
+
+//------------------------------------------------------------------------------
+
+/// An item of a [`JumpList`](crate::JumpList), which is a shell link to an
+/// executable.
+#[derive(Default, Clone)]
+pub struct JumpListItem {
+	/// Text displayed in the jump list.
+	pub title: String,
+	/// Path of the executable to be launched.
+	pub path: String,
+	/// Command line arguments.
+	pub arguments: String,
+	/// Tooltip text.
+	pub description: String,
+	/// Path of the file containing the icon, and the index of the icon within
+	/// the file. If `None`, the icon of the executable is used.
+	pub icon: Option<(String, i32)>,
+}
+
+impl JumpListItem {
+	fn create_link(&self) -> HrResult<IShellLink> {
+		let link = CoCreateInstance::<IShellLink>(
+			&co::CLSID::ShellLink, None, co::CLSCTX::INPROC_SERVER)?;
+		link.SetPath(&self.path)?;
+		link.SetArguments(&self.arguments)?;
+		if !self.description.is_empty() {
+			link.SetDescription(&self.description)?;
+		}
+		if let Some((icon_path, icon_index)) = &self.icon {
+			link.SetIconLocation(icon_path, *icon_index)?;
+		}
+
+		let props = link.QueryInterface::<IPropertyStore>()?;
+		props.SetValue(&PKEY_TITLE, &PROPVARIANT::new_lpwstr(&self.title)?)?;
+		props.Commit()?;
+		Ok(link)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Builder of the
+/// [jump list](https://learn.microsoft.com/en-us/windows/win32/shell/taskbar-extensions#jump-lists)
+/// of the application, displayed when its taskbar button is right-clicked.
+///
+/// The list is written with
+/// [`ICustomDestinationList`](crate::ICustomDestinationList) when
+/// [`commit`](crate::JumpList::commit) is called, replacing the previous one.
+/// COM must be initialized.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, CoInitializeEx, HINSTANCE, JumpList, JumpListItem};
+///
+/// let _com_guard = CoInitializeEx(
+///     co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+/// let exe = HINSTANCE::NULL.GetModuleFileName()?;
+///
+/// JumpList::new()
+///     .add_task(JumpListItem {
+///         title: "New window".to_owned(),
+///         path: exe.clone(),
+///         arguments: "--new-window".to_owned(),
+///         ..Default::default()
+///     })
+///     .add_separator()
+///     .add_task(JumpListItem {
+///         title: "Settings".to_owned(),
+///         path: exe.clone(),
+///         arguments: "--settings".to_owned(),
+///         ..Default::default()
+///     })
+///     .add_known_category(co::KDC::RECENT)
+///     .commit()?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Default)]
+pub struct JumpList {
+	app_id: Option<String>,
+	tasks: Vec<Task>,
+	categories: Vec<Category>,
+}
+
+impl JumpList {
+	/// Creates a new, empty jump list.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Sets the
+	/// [application user model ID](https://learn.microsoft.com/en-us/windows/win32/shell/appids)
+	/// which owns the list. If not set, the ID of the current process is used.
+	#[must_use]
+	pub fn app_id(mut self, app_id: &str) -> Self {
+		self.app_id = Some(app_id.to_owned());
+		self
+	}
+
+	/// Adds a custom category, with the given items.
+	///
+	/// Items previously removed by the user from the jump list are skipped.
+	#[must_use]
+	pub fn add_category(mut self, title: &str, items: Vec<JumpListItem>) -> Self {
+		self.categories.push(Category::Custom {
+			title: title.to_owned(),
+			items,
+		});
+		self
+	}
+
+	/// Adds a category maintained by the system, with the recent or frequent
+	/// files of the application.
+	///
+	/// The files must have a file type registered to the application, and they
+	/// are usually added to the category with
+	/// [`SHAddToRecentDocs`](crate::SHAddToRecentDocs).
+	#[must_use]
+	pub fn add_known_category(mut self, category: co::KDC) -> Self {
+		self.categories.push(Category::Known(category));
+		self
+	}
+
+	/// Adds a separator to the tasks.
+	#[must_use]
+	pub fn add_separator(mut self) -> Self {
+		self.tasks.push(Task::Separator);
+		self
+	}
+
+	/// Adds an item to the tasks, which are displayed in the bottom of the
+	/// jump list.
+	#[must_use]
+	pub fn add_task(mut self, item: JumpListItem) -> Self {
+		self.tasks.push(Task::Item(item));
+		self
+	}
+
+	/// Writes the jump list, replacing the previous one.
+	pub fn commit(&self) -> HrResult<()> {
+		let dest_list = CoCreateInstance::<ICustomDestinationList>(
+			&co::CLSID::DestinationList, None, co::CLSCTX::INPROC_SERVER)?;
+		if let Some(app_id) = &self.app_id {
+			dest_list.SetAppID(app_id)?;
+		}
+
+		let (_, removed) = dest_list.BeginList::<IObjectArray>()?;
+		if let Err(e) = self.fill(&dest_list, &removed) {
+			dest_list.AbortList().ok(); // ignore errors
+			return Err(e);
+		}
+		dest_list.CommitList()
+	}
+
+	fn fill(&self,
+		dest_list: &ICustomDestinationList, removed: &IObjectArray) -> HrResult<()>
+	{
+		let removed_links = (0..removed.GetCount()?)
+			.filter_map(|i| removed.GetAt::<IShellLink>(i).ok()) // removed items may not be links
+			.map(|link| Ok((link.GetPath(None, co::SLGP::RAWPATH)?, link.GetArguments()?)))
+			.collect::<HrResult<Vec<_>>>()?;
+
+		for category in self.categories.iter() {
+			match category {
+				Category::Custom { title, items } => {
+					let coll = Self::new_collection()?;
+					for item in items.iter() {
+						if !removed_links.iter().any(|(path, args)|
+							*path == item.path && *args == item.arguments)
+						{
+							coll.AddObject(&item.create_link()?)?;
+						}
+					}
+					if coll.GetCount()? > 0 {
+						dest_list.AppendCategory(title, &coll)?;
+					}
+				},
+				Category::Known(kdc) => dest_list.AppendKnownCategory(*kdc)?,
+			}
+		}
+
+		if !self.tasks.is_empty() {
+			let coll = Self::new_collection()?;
+			for task in self.tasks.iter() {
+				coll.AddObject(&match task {
+					Task::Item(item) => item.create_link()?,
+					Task::Separator => Self::create_separator()?,
+				})?;
+			}
+			dest_list.AddUserTasks(&coll)?;
+		}
+		Ok(())
+	}
+
+	fn new_collection() -> HrResult<IObjectCollection> {
+		CoCreateInstance::<IObjectCollection>(
+			&co::CLSID::EnumerableObjectCollection, None, co::CLSCTX::INPROC_SERVER)
+	}
+
+	fn create_separator() -> HrResult<IShellLink> {
+		let link = CoCreateInstance::<IShellLink>(
+			&co::CLSID::ShellLink, None, co::CLSCTX::INPROC_SERVER)?;
+		let props = link.QueryInterface::<IPropertyStore>()?;
+		props.SetValue(&PKEY_APPUSERMODEL_ISDESTLISTSEPARATOR, &PROPVARIANT::new_bool(true))?;
+		props.Commit()?;
+		Ok(link)
+	}
+
+	/// Deletes the jump list of the given
+	/// [application user model ID](https://learn.microsoft.com/en-us/windows/win32/shell/appids),
+	/// or of the current process if `None`.
+	pub fn delete(app_id: Option<&str>) -> HrResult<()> {
+		let dest_list = CoCreateInstance::<ICustomDestinationList>(
+			&co::CLSID::DestinationList, None, co::CLSCTX::INPROC_SERVER)?;
+		dest_list.DeleteList(app_id)
+	}
+}
@@ -0,0 +1,3 @@
+mod jump_list;
+
+pub use jump_list::{JumpList, JumpListItem};
@@ -0,0 +1,33 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+use crate::co::CLSID;
+
+const_guid_values! { CLSID;
+	SpObjectTokenCategory "a910187f-0c7a-45ac-92cc-59edafb77b53"
+	SpVoice "96749377-3391-11d2-9ee3-00c04f797396"
+}
+
+const_bitflag! { SPF: u32;
+	/// Speech API `SPEAKFLAGS` enumeration (`u32`).
+	=>
+	=>
+	DEFAULT 0
+	ASYNC 0x1
+	PURGEBEFORESPEAK 0x2
+	IS_FILENAME 0x4
+	IS_XML 0x8
+	IS_NOT_XML 0x10
+	PERSIST_XML 0x20
+	NLP_SPEAK_PUNC 0x40
+	PARSE_SAPI 0x80
+	PARSE_SSML 0x100
+}
+
+const_ordinary! { SPVPRIORITY: u32;
+	/// Speech API `SPVPRIORITY` enumeration (`u32`).
+	=>
+	=>
+	NORMAL 0
+	ALERT 1
+	OVER 2
+}
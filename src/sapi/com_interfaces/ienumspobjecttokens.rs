@@ -0,0 +1,168 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::prelude::ole_IUnknown;
+use crate::sapi::decl::ISpObjectToken;
+use crate::vt::IUnknownVT;
+
+/// [`IEnumSpObjectTokens`](crate::IEnumSpObjectTokens) virtual table.
+#[repr(C)]
+pub struct IEnumSpObjectTokensVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Next: fn(ComPtr, u32, *mut ComPtr, *mut u32) -> HRES,
+	pub Skip: fn(ComPtr, u32) -> HRES,
+	pub Reset: fn(ComPtr) -> HRES,
+	pub Clone: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub Item: fn(ComPtr, u32, *mut ComPtr) -> HRES,
+	pub GetCount: fn(ComPtr, *mut u32) -> HRES,
+}
+
+com_interface! { IEnumSpObjectTokens: "06b64f9e-7fda-11d2-b4f2-00c04f797396";
+	/// `IEnumSpObjectTokens` COM interface over
+	/// [`IEnumSpObjectTokensVT`](crate::vt::IEnumSpObjectTokensVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl sapi_IEnumSpObjectTokens for IEnumSpObjectTokens {}
+
+/// This trait is enabled with the `sapi` feature, and provides methods for
+/// [`IEnumSpObjectTokens`](crate::IEnumSpObjectTokens).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait sapi_IEnumSpObjectTokens: ole_IUnknown {
+	/// Returns an iterator over the [`ISpObjectToken`](crate::ISpObjectToken)
+	/// elements which calls
+	/// [`IEnumSpObjectTokens::Next`](crate::prelude::sapi_IEnumSpObjectTokens::Next)
+	/// internally.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IEnumSpObjectTokens};
+	///
+	/// let tokens: IEnumSpObjectTokens; // initialized somewhere
+	/// # let tokens = IEnumSpObjectTokens::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// for token in tokens.iter() {
+	///     let token = token?;
+	///     println!("{}", token.GetId()?);
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<ISpObjectToken>> + '_> {
+		Box::new(EnumSpObjectTokensIter::new(self))
+	}
+
+	/// `IEnumSpObjectTokens::Clone` method.
+	#[must_use]
+	fn Clone(&self) -> HrResult<IEnumSpObjectTokens> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumSpObjectTokensVT>();
+			ok_to_hrresult((vt.Clone)(self.ptr(), &mut ppv_queried))
+				.map(|_| IEnumSpObjectTokens::from(ppv_queried))
+		}
+	}
+
+	/// `IEnumSpObjectTokens::GetCount` method.
+	#[must_use]
+	fn GetCount(&self) -> HrResult<u32> {
+		let mut count = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IEnumSpObjectTokensVT>();
+			ok_to_hrresult((vt.GetCount)(self.ptr(), &mut count))
+				.map(|_| count)
+		}
+	}
+
+	/// `IEnumSpObjectTokens::Item` method.
+	#[must_use]
+	fn Item(&self, index: u32) -> HrResult<ISpObjectToken> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumSpObjectTokensVT>();
+			ok_to_hrresult((vt.Item)(self.ptr(), index, &mut ppv_queried))
+				.map(|_| ISpObjectToken::from(ppv_queried))
+		}
+	}
+
+	/// `IEnumSpObjectTokens::Next` method.
+	///
+	/// Prefer using
+	/// [`IEnumSpObjectTokens::iter`](crate::prelude::sapi_IEnumSpObjectTokens::iter),
+	/// which is simpler.
+	#[must_use]
+	fn Next(&self) -> HrResult<Option<ISpObjectToken>> {
+		let mut fetched = u32::default();
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumSpObjectTokensVT>();
+			match ok_to_hrresult(
+				(vt.Next)(self.ptr(), 1, &mut ppv_queried, &mut fetched), // retrieve only 1
+			) {
+				Ok(_) => Ok(Some(ISpObjectToken::from(ppv_queried))),
+				Err(hr) => match hr {
+					co::HRESULT::S_FALSE => Ok(None), // no item found
+					hr => Err(hr), // actual error
+				},
+			}
+		}
+	}
+
+	/// `IEnumSpObjectTokens::Reset` method.
+	fn Reset(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumSpObjectTokensVT>();
+			ok_to_hrresult((vt.Reset)(self.ptr()))
+		}
+	}
+
+	/// `IEnumSpObjectTokens::Skip` method.
+	fn Skip(&self, count: u32) -> HrResult<bool> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumSpObjectTokensVT>();
+			okfalse_to_hrresult((vt.Skip)(self.ptr(), count))
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct EnumSpObjectTokensIter<'a, I>
+	where I: sapi_IEnumSpObjectTokens,
+{
+	enum_tokens: &'a I,
+}
+
+impl<'a, I> Iterator for EnumSpObjectTokensIter<'a, I>
+	where I: sapi_IEnumSpObjectTokens,
+{
+	type Item = HrResult<ISpObjectToken>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.enum_tokens.Next() {
+			Err(err) => Some(Err(err)),
+			Ok(maybe_token) => maybe_token.map(Ok),
+		}
+	}
+}
+
+impl<'a, I> EnumSpObjectTokensIter<'a, I>
+	where I: sapi_IEnumSpObjectTokens,
+{
+	fn new(enum_tokens: &'a I) -> Self {
+		Self { enum_tokens }
+	}
+}
@@ -0,0 +1,167 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{HRES, PCSTR, PCVOID, PSTR, PVOID};
+use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`ISpDataKey`](crate::ISpDataKey) virtual table.
+#[repr(C)]
+pub struct ISpDataKeyVT {
+	pub IUnknownVT: IUnknownVT,
+	pub SetData: fn(ComPtr, PCSTR, u32, PCVOID) -> HRES,
+	pub GetData: fn(ComPtr, PCSTR, *mut u32, PVOID) -> HRES,
+	pub SetStringValue: fn(ComPtr, PCSTR, PCSTR) -> HRES,
+	pub GetStringValue: fn(ComPtr, PCSTR, *mut PSTR) -> HRES,
+	pub SetDWORD: fn(ComPtr, PCSTR, u32) -> HRES,
+	pub GetDWORD: fn(ComPtr, PCSTR, *mut u32) -> HRES,
+	pub OpenKey: fn(ComPtr, PCSTR, *mut ComPtr) -> HRES,
+	pub CreateKey: fn(ComPtr, PCSTR, *mut ComPtr) -> HRES,
+	pub DeleteKey: fn(ComPtr, PCSTR) -> HRES,
+	pub DeleteValue: fn(ComPtr, PCSTR) -> HRES,
+	pub EnumKeys: fn(ComPtr, u32, *mut PSTR) -> HRES,
+	pub EnumValues: fn(ComPtr, u32, *mut PSTR) -> HRES,
+}
+
+com_interface! { ISpDataKey: "14056581-e16c-11d2-bb90-00c04f8ee6c0";
+	/// `ISpDataKey` COM interface over [`ISpDataKeyVT`](crate::vt::ISpDataKeyVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl sapi_ISpDataKey for ISpDataKey {}
+
+/// This trait is enabled with the `sapi` feature, and provides methods for
+/// [`ISpDataKey`](crate::ISpDataKey).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait sapi_ISpDataKey: ole_IUnknown {
+	/// `ISpDataKey::CreateKey` method.
+	#[must_use]
+	fn CreateKey(&self, sub_key_name: &str) -> HrResult<ISpDataKey> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ISpDataKeyVT>();
+			ok_to_hrresult(
+				(vt.CreateKey)(
+					self.ptr(),
+					WString::from_str(sub_key_name).as_ptr(),
+					&mut ppv_queried,
+				),
+			).map(|_| ISpDataKey::from(ppv_queried))
+		}
+	}
+
+	/// `ISpDataKey::DeleteKey` method.
+	fn DeleteKey(&self, sub_key_name: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpDataKeyVT>();
+			ok_to_hrresult(
+				(vt.DeleteKey)(self.ptr(), WString::from_str(sub_key_name).as_ptr()),
+			)
+		}
+	}
+
+	/// `ISpDataKey::DeleteValue` method.
+	fn DeleteValue(&self, value_name: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpDataKeyVT>();
+			ok_to_hrresult(
+				(vt.DeleteValue)(self.ptr(), WString::from_str(value_name).as_ptr()),
+			)
+		}
+	}
+
+	/// `ISpDataKey::GetDWORD` method.
+	#[must_use]
+	fn GetDWORD(&self, value_name: &str) -> HrResult<u32> {
+		let mut val = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<ISpDataKeyVT>();
+			ok_to_hrresult(
+				(vt.GetDWORD)(
+					self.ptr(),
+					WString::from_str(value_name).as_ptr(),
+					&mut val,
+				),
+			).map(|_| val)
+		}
+	}
+
+	/// `ISpDataKey::GetStringValue` method.
+	///
+	/// If `value_name` is `None`, the default value of the key is returned.
+	/// For voice tokens, this is the human-readable voice description.
+	#[must_use]
+	fn GetStringValue(&self, value_name: Option<&str>) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<ISpDataKeyVT>();
+			ok_to_hrresult(
+				(vt.GetStringValue)(
+					self.ptr(),
+					WString::from_opt_str(value_name).as_ptr(),
+					&mut pstr,
+				),
+			)
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// `ISpDataKey::OpenKey` method.
+	#[must_use]
+	fn OpenKey(&self, sub_key_name: &str) -> HrResult<ISpDataKey> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ISpDataKeyVT>();
+			ok_to_hrresult(
+				(vt.OpenKey)(
+					self.ptr(),
+					WString::from_str(sub_key_name).as_ptr(),
+					&mut ppv_queried,
+				),
+			).map(|_| ISpDataKey::from(ppv_queried))
+		}
+	}
+
+	/// `ISpDataKey::SetDWORD` method.
+	fn SetDWORD(&self, value_name: &str, value: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpDataKeyVT>();
+			ok_to_hrresult(
+				(vt.SetDWORD)(
+					self.ptr(),
+					WString::from_str(value_name).as_ptr(),
+					value,
+				),
+			)
+		}
+	}
+
+	/// `ISpDataKey::SetStringValue` method.
+	///
+	/// If `value_name` is `None`, the default value of the key is set.
+	fn SetStringValue(&self, value_name: Option<&str>, value: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpDataKeyVT>();
+			ok_to_hrresult(
+				(vt.SetStringValue)(
+					self.ptr(),
+					WString::from_opt_str(value_name).as_ptr(),
+					WString::from_str(value).as_ptr(),
+				),
+			)
+		}
+	}
+}
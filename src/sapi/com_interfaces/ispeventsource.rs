@@ -0,0 +1,50 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::{HRES, PVOID};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::sapi_ISpNotifySource;
+use crate::vt::ISpNotifySourceVT;
+
+/// [`ISpEventSource`](crate::ISpEventSource) virtual table.
+#[repr(C)]
+pub struct ISpEventSourceVT {
+	pub ISpNotifySourceVT: ISpNotifySourceVT,
+	pub SetInterest: fn(ComPtr, u64, u64) -> HRES,
+	pub GetEvents: fn(ComPtr, u32, PVOID, *mut u32) -> HRES,
+	pub GetInfo: fn(ComPtr, PVOID) -> HRES,
+}
+
+com_interface! { ISpEventSource: "be7a9cce-5f9e-11d2-960f-00c04f8ee628";
+	/// `ISpEventSource` COM interface over [`ISpEventSourceVT`](crate::vt::ISpEventSourceVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl sapi_ISpNotifySource for ISpEventSource {}
+impl sapi_ISpEventSource for ISpEventSource {}
+
+/// This trait is enabled with the `sapi` feature, and provides methods for
+/// [`ISpEventSource`](crate::ISpEventSource).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait sapi_ISpEventSource: sapi_ISpNotifySource {
+	/// `ISpEventSource::SetInterest` method.
+	///
+	/// Both arguments are bitmasks of `SPEI` event IDs, where each event sets
+	/// the bit `1 << id`.
+	fn SetInterest(&self, event_interest: u64, queued_interest: u64) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpEventSourceVT>();
+			ok_to_hrresult(
+				(vt.SetInterest)(self.ptr(), event_interest, queued_interest),
+			)
+		}
+	}
+}
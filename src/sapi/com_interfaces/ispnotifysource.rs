@@ -0,0 +1,84 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{HANDLE, HRES, PVOID};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::prelude::{Handle, ole_IUnknown};
+use crate::user::decl::HWND;
+use crate::vt::IUnknownVT;
+
+/// [`ISpNotifySource`](crate::ISpNotifySource) virtual table.
+#[repr(C)]
+pub struct ISpNotifySourceVT {
+	pub IUnknownVT: IUnknownVT,
+	pub SetNotifySink: fn(ComPtr, ComPtr) -> HRES,
+	pub SetNotifyWindowMessage: fn(ComPtr, HANDLE, u32, usize, isize) -> HRES,
+	pub SetNotifyCallbackFunction: fn(ComPtr, PVOID, usize, isize) -> HRES,
+	pub SetNotifyCallbackInterface: fn(ComPtr, PVOID, usize, isize) -> HRES,
+	pub SetNotifyWin32Event: fn(ComPtr) -> HRES,
+	pub WaitForNotifyEvent: fn(ComPtr, u32) -> HRES,
+	pub GetNotifyEventHandle: fn(ComPtr) -> HANDLE,
+}
+
+com_interface! { ISpNotifySource: "5eff4aef-8487-11d2-961c-00c04f8ee628";
+	/// `ISpNotifySource` COM interface over [`ISpNotifySourceVT`](crate::vt::ISpNotifySourceVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl sapi_ISpNotifySource for ISpNotifySource {}
+
+/// This trait is enabled with the `sapi` feature, and provides methods for
+/// [`ISpNotifySource`](crate::ISpNotifySource).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait sapi_ISpNotifySource: ole_IUnknown {
+	/// `ISpNotifySource::SetNotifyWin32Event` method.
+	fn SetNotifyWin32Event(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpNotifySourceVT>();
+			ok_to_hrresult((vt.SetNotifyWin32Event)(self.ptr()))
+		}
+	}
+
+	/// `ISpNotifySource::SetNotifyWindowMessage` method.
+	fn SetNotifyWindowMessage(&self,
+		hwnd: &HWND,
+		msg: co::WM,
+		wparam: usize,
+		lparam: isize,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<ISpNotifySourceVT>();
+			ok_to_hrresult(
+				(vt.SetNotifyWindowMessage)(
+					self.ptr(),
+					hwnd.as_ptr(),
+					msg.0,
+					wparam,
+					lparam,
+				),
+			)
+		}
+	}
+
+	/// `ISpNotifySource::WaitForNotifyEvent` method.
+	///
+	/// Requires a previous call to
+	/// [`ISpNotifySource::SetNotifyWin32Event`](crate::prelude::sapi_ISpNotifySource::SetNotifyWin32Event).
+	/// Returns `false` if the timeout elapsed.
+	fn WaitForNotifyEvent(&self, milliseconds: u32) -> HrResult<bool> {
+		unsafe {
+			let vt = self.vt_ref::<ISpNotifySourceVT>();
+			okfalse_to_hrresult((vt.WaitForNotifyEvent)(self.ptr(), milliseconds))
+		}
+	}
+}
@@ -0,0 +1,111 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{BOOL, HANDLE, HRES, PCSTR, PCVOID, PSTR, PVOID};
+use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::sapi_ISpDataKey;
+use crate::sapi::decl::ISpObjectTokenCategory;
+use crate::vt::ISpDataKeyVT;
+
+/// [`ISpObjectToken`](crate::ISpObjectToken) virtual table.
+#[repr(C)]
+pub struct ISpObjectTokenVT {
+	pub ISpDataKeyVT: ISpDataKeyVT,
+	pub SetId: fn(ComPtr, PCSTR, PCSTR, BOOL) -> HRES,
+	pub GetId: fn(ComPtr, *mut PSTR) -> HRES,
+	pub GetCategory: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub CreateInstance: fn(ComPtr, ComPtr, u32, PCVOID, *mut ComPtr) -> HRES,
+	pub GetStorageFileName: fn(ComPtr, PCVOID, PCSTR, PCSTR, u32, *mut PSTR) -> HRES,
+	pub RemoveStorageFileName: fn(ComPtr, PCVOID, PCSTR, BOOL) -> HRES,
+	pub Remove: fn(ComPtr, PCVOID) -> HRES,
+	pub IsUISupported: fn(ComPtr, PCSTR, PVOID, u32, ComPtr, *mut BOOL) -> HRES,
+	pub DisplayUI: fn(ComPtr, HANDLE, PCSTR, PCSTR, PVOID, u32, ComPtr) -> HRES,
+	pub MatchesAttributes: fn(ComPtr, PCSTR, *mut BOOL) -> HRES,
+}
+
+com_interface! { ISpObjectToken: "14056589-e16c-11d2-bb90-00c04f8ee6c0";
+	/// `ISpObjectToken` COM interface over
+	/// [`ISpObjectTokenVT`](crate::vt::ISpObjectTokenVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl sapi_ISpDataKey for ISpObjectToken {}
+impl sapi_ISpObjectToken for ISpObjectToken {}
+
+/// This trait is enabled with the `sapi` feature, and provides methods for
+/// [`ISpObjectToken`](crate::ISpObjectToken).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait sapi_ISpObjectToken: sapi_ISpDataKey {
+	/// `ISpObjectToken::GetCategory` method.
+	#[must_use]
+	fn GetCategory(&self) -> HrResult<ISpObjectTokenCategory> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ISpObjectTokenVT>();
+			ok_to_hrresult((vt.GetCategory)(self.ptr(), &mut ppv_queried))
+				.map(|_| ISpObjectTokenCategory::from(ppv_queried))
+		}
+	}
+
+	/// `ISpObjectToken::GetId` method.
+	#[must_use]
+	fn GetId(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<ISpObjectTokenVT>();
+			ok_to_hrresult((vt.GetId)(self.ptr(), &mut pstr))
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// `ISpObjectToken::MatchesAttributes` method.
+	///
+	/// The attributes are given as `"Name=Value"` pairs separated by
+	/// semicolons, like `"Gender=Female;Language=409"`.
+	#[must_use]
+	fn MatchesAttributes(&self, attributes: &str) -> HrResult<bool> {
+		let mut matches: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<ISpObjectTokenVT>();
+			ok_to_hrresult(
+				(vt.MatchesAttributes)(
+					self.ptr(),
+					WString::from_str(attributes).as_ptr(),
+					&mut matches,
+				),
+			).map(|_| matches != 0)
+		}
+	}
+
+	/// `ISpObjectToken::SetId` method.
+	fn SetId(&self,
+		category_id: Option<&str>,
+		token_id: &str,
+		create_if_not_exist: bool,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<ISpObjectTokenVT>();
+			ok_to_hrresult(
+				(vt.SetId)(
+					self.ptr(),
+					WString::from_opt_str(category_id).as_ptr(),
+					WString::from_str(token_id).as_ptr(),
+					create_if_not_exist as _,
+				),
+			)
+		}
+	}
+}
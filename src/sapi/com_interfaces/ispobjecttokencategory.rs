@@ -0,0 +1,147 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{BOOL, HRES, PCSTR, PSTR};
+use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::sapi_ISpDataKey;
+use crate::sapi::decl::IEnumSpObjectTokens;
+use crate::vt::ISpDataKeyVT;
+
+/// [`ISpObjectTokenCategory`](crate::ISpObjectTokenCategory) virtual table.
+#[repr(C)]
+pub struct ISpObjectTokenCategoryVT {
+	pub ISpDataKeyVT: ISpDataKeyVT,
+	pub SetId: fn(ComPtr, PCSTR, BOOL) -> HRES,
+	pub GetId: fn(ComPtr, *mut PSTR) -> HRES,
+	pub GetDataKey: fn(ComPtr, u32, *mut ComPtr) -> HRES,
+	pub EnumTokens: fn(ComPtr, PCSTR, PCSTR, *mut ComPtr) -> HRES,
+	pub SetDefaultTokenId: fn(ComPtr, PCSTR) -> HRES,
+	pub GetDefaultTokenId: fn(ComPtr, *mut PSTR) -> HRES,
+}
+
+com_interface! { ISpObjectTokenCategory: "2d3d3845-39af-4850-bbf9-40b49780011d";
+	/// `ISpObjectTokenCategory` COM interface over
+	/// [`ISpObjectTokenCategoryVT`](crate::vt::ISpObjectTokenCategoryVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// Listing the installed voices:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{self as w, co};
+	///
+	/// let voices = w::CoCreateInstance::<w::ISpObjectTokenCategory>(
+	///     &co::CLSID::SpObjectTokenCategory,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// voices.SetId(
+	///     "HKEY_LOCAL_MACHINE\\SOFTWARE\\Microsoft\\Speech\\Voices", false)?;
+	///
+	/// for token in voices.EnumTokens(None, None)?.iter() {
+	///     let token = token?;
+	///     println!("{}", token.GetStringValue(None)?);
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl sapi_ISpDataKey for ISpObjectTokenCategory {}
+impl sapi_ISpObjectTokenCategory for ISpObjectTokenCategory {}
+
+/// This trait is enabled with the `sapi` feature, and provides methods for
+/// [`ISpObjectTokenCategory`](crate::ISpObjectTokenCategory).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait sapi_ISpObjectTokenCategory: sapi_ISpDataKey {
+	/// `ISpObjectTokenCategory::EnumTokens` method.
+	///
+	/// The attributes are given as `"Name=Value"` pairs separated by
+	/// semicolons. Tokens which don't satisfy `required_attrs` are left out;
+	/// those matching `optional_attrs` are listed first.
+	#[must_use]
+	fn EnumTokens(&self,
+		required_attrs: Option<&str>,
+		optional_attrs: Option<&str>,
+	) -> HrResult<IEnumSpObjectTokens>
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ISpObjectTokenCategoryVT>();
+			ok_to_hrresult(
+				(vt.EnumTokens)(
+					self.ptr(),
+					WString::from_opt_str(required_attrs).as_ptr(),
+					WString::from_opt_str(optional_attrs).as_ptr(),
+					&mut ppv_queried,
+				),
+			).map(|_| IEnumSpObjectTokens::from(ppv_queried))
+		}
+	}
+
+	/// `ISpObjectTokenCategory::GetDefaultTokenId` method.
+	#[must_use]
+	fn GetDefaultTokenId(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<ISpObjectTokenCategoryVT>();
+			ok_to_hrresult((vt.GetDefaultTokenId)(self.ptr(), &mut pstr))
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// `ISpObjectTokenCategory::GetId` method.
+	#[must_use]
+	fn GetId(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<ISpObjectTokenCategoryVT>();
+			ok_to_hrresult((vt.GetId)(self.ptr(), &mut pstr))
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// `ISpObjectTokenCategory::SetDefaultTokenId` method.
+	fn SetDefaultTokenId(&self, token_id: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpObjectTokenCategoryVT>();
+			ok_to_hrresult(
+				(vt.SetDefaultTokenId)(self.ptr(), WString::from_str(token_id).as_ptr()),
+			)
+		}
+	}
+
+	/// `ISpObjectTokenCategory::SetId` method.
+	///
+	/// Must be called before any other method, to bind the object to a
+	/// category, like the voices category
+	/// `"HKEY_LOCAL_MACHINE\SOFTWARE\Microsoft\Speech\Voices"`.
+	fn SetId(&self, category_id: &str, create_if_not_exist: bool) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpObjectTokenCategoryVT>();
+			ok_to_hrresult(
+				(vt.SetId)(
+					self.ptr(),
+					WString::from_str(category_id).as_ptr(),
+					create_if_not_exist as _,
+				),
+			)
+		}
+	}
+}
@@ -0,0 +1,288 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{BOOL, HANDLE, HRES, PCSTR, PSTR, PVOID};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::prelude::{
+	sapi_ISpEventSource, sapi_ISpNotifySource, sapi_ISpObjectToken, ole_IStream,
+};
+use crate::sapi::decl::ISpObjectToken;
+use crate::vt::ISpEventSourceVT;
+
+/// [`ISpVoice`](crate::ISpVoice) virtual table.
+#[repr(C)]
+pub struct ISpVoiceVT {
+	pub ISpEventSourceVT: ISpEventSourceVT,
+	pub SetOutput: fn(ComPtr, ComPtr, BOOL) -> HRES,
+	pub GetOutputObjectToken: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub GetOutputStream: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub Pause: fn(ComPtr) -> HRES,
+	pub Resume: fn(ComPtr) -> HRES,
+	pub SetVoice: fn(ComPtr, ComPtr) -> HRES,
+	pub GetVoice: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub Speak: fn(ComPtr, PCSTR, u32, *mut u32) -> HRES,
+	pub SpeakStream: fn(ComPtr, ComPtr, u32, *mut u32) -> HRES,
+	pub GetStatus: fn(ComPtr, PVOID, *mut PSTR) -> HRES,
+	pub Skip: fn(ComPtr, PCSTR, i32, *mut u32) -> HRES,
+	pub SetPriority: fn(ComPtr, u32) -> HRES,
+	pub GetPriority: fn(ComPtr, *mut u32) -> HRES,
+	pub SetAlertBoundary: fn(ComPtr, u32) -> HRES,
+	pub GetAlertBoundary: fn(ComPtr, *mut u32) -> HRES,
+	pub SetRate: fn(ComPtr, i32) -> HRES,
+	pub GetRate: fn(ComPtr, *mut i32) -> HRES,
+	pub SetVolume: fn(ComPtr, u16) -> HRES,
+	pub GetVolume: fn(ComPtr, *mut u16) -> HRES,
+	pub WaitUntilDone: fn(ComPtr, u32) -> HRES,
+	pub SetSyncSpeakTimeout: fn(ComPtr, u32) -> HRES,
+	pub GetSyncSpeakTimeout: fn(ComPtr, *mut u32) -> HRES,
+	pub SpeakCompleteEvent: fn(ComPtr) -> HANDLE,
+	pub IsUISupported: fn(ComPtr, PCSTR, PVOID, u32, *mut BOOL) -> HRES,
+	pub DisplayUI: fn(ComPtr, HANDLE, PCSTR, PCSTR, PVOID, u32) -> HRES,
+}
+
+com_interface! { ISpVoice: "6c44df74-72b9-4992-a1ec-ef996e0422d4";
+	/// `ISpVoice` COM interface over [`ISpVoiceVT`](crate::vt::ISpVoiceVT),
+	/// the text-to-speech engine of the Speech API (SAPI).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{self as w, co};
+	///
+	/// let _com_guard = w::CoInitializeEx(
+	///     co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+	///
+	/// let voice = w::CoCreateInstance::<w::ISpVoice>(
+	///     &co::CLSID::SpVoice,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// voice.SetRate(2)?;
+	/// voice.SetVolume(80)?;
+	/// voice.Speak("Download complete.", co::SPF::DEFAULT)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl sapi_ISpNotifySource for ISpVoice {}
+impl sapi_ISpEventSource for ISpVoice {}
+impl sapi_ISpVoice for ISpVoice {}
+
+/// This trait is enabled with the `sapi` feature, and provides methods for
+/// [`ISpVoice`](crate::ISpVoice).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait sapi_ISpVoice: sapi_ISpEventSource {
+	/// `ISpVoice::GetPriority` method.
+	#[must_use]
+	fn GetPriority(&self) -> HrResult<co::SPVPRIORITY> {
+		let mut priority = co::SPVPRIORITY::default();
+		unsafe {
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			ok_to_hrresult((vt.GetPriority)(self.ptr(), &mut priority.0))
+				.map(|_| priority)
+		}
+	}
+
+	/// `ISpVoice::GetRate` method.
+	#[must_use]
+	fn GetRate(&self) -> HrResult<i32> {
+		let mut rate = i32::default();
+		unsafe {
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			ok_to_hrresult((vt.GetRate)(self.ptr(), &mut rate))
+				.map(|_| rate)
+		}
+	}
+
+	/// `ISpVoice::GetVoice` method.
+	#[must_use]
+	fn GetVoice(&self) -> HrResult<ISpObjectToken> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			ok_to_hrresult((vt.GetVoice)(self.ptr(), &mut ppv_queried))
+				.map(|_| ISpObjectToken::from(ppv_queried))
+		}
+	}
+
+	/// `ISpVoice::GetVolume` method.
+	#[must_use]
+	fn GetVolume(&self) -> HrResult<u16> {
+		let mut volume = u16::default();
+		unsafe {
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			ok_to_hrresult((vt.GetVolume)(self.ptr(), &mut volume))
+				.map(|_| volume)
+		}
+	}
+
+	/// `ISpVoice::Pause` method.
+	fn Pause(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			ok_to_hrresult((vt.Pause)(self.ptr()))
+		}
+	}
+
+	/// `ISpVoice::Resume` method.
+	fn Resume(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			ok_to_hrresult((vt.Resume)(self.ptr()))
+		}
+	}
+
+	/// `ISpVoice::SetPriority` method.
+	fn SetPriority(&self, priority: co::SPVPRIORITY) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			ok_to_hrresult((vt.SetPriority)(self.ptr(), priority.0))
+		}
+	}
+
+	/// `ISpVoice::SetRate` method.
+	///
+	/// The rate ranges from `-10` to `10`, where `0` is the normal speed.
+	fn SetRate(&self, rate: i32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			ok_to_hrresult((vt.SetRate)(self.ptr(), rate))
+		}
+	}
+
+	/// `ISpVoice::SetVoice` method.
+	///
+	/// # Examples
+	///
+	/// Choosing the first female voice, if any:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{self as w, co};
+	///
+	/// let voice: w::ISpVoice; // initialized somewhere
+	/// # let voice = w::ISpVoice::from(unsafe { w::ComPtr::null() });
+	///
+	/// let voices = w::CoCreateInstance::<w::ISpObjectTokenCategory>(
+	///     &co::CLSID::SpObjectTokenCategory,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// voices.SetId(
+	///     "HKEY_LOCAL_MACHINE\\SOFTWARE\\Microsoft\\Speech\\Voices", false)?;
+	///
+	/// if let Some(token) = voices.EnumTokens(Some("Gender=Female"), None)?
+	///     .iter()
+	///     .next()
+	/// {
+	///     voice.SetVoice(&token?)?;
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	fn SetVoice(&self, token: &impl sapi_ISpObjectToken) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			ok_to_hrresult((vt.SetVoice)(self.ptr(), token.ptr()))
+		}
+	}
+
+	/// `ISpVoice::SetVolume` method.
+	///
+	/// The volume ranges from `0` to `100`.
+	fn SetVolume(&self, volume: u16) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			ok_to_hrresult((vt.SetVolume)(self.ptr(), volume))
+		}
+	}
+
+	/// `ISpVoice::Skip` method.
+	///
+	/// Skips the given number of sentences, forward or backward, in the
+	/// current speech. Returns the number of sentences actually skipped.
+	fn Skip(&self, num_sentences: i32) -> HrResult<u32> {
+		let mut skipped = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			ok_to_hrresult(
+				(vt.Skip)(
+					self.ptr(),
+					WString::from_str("SENTENCE").as_ptr(),
+					num_sentences,
+					&mut skipped,
+				),
+			).map(|_| skipped)
+		}
+	}
+
+	/// `ISpVoice::Speak` method.
+	///
+	/// Unless [`co::SPF::ASYNC`](crate::co::SPF::ASYNC) is passed, the call
+	/// blocks until the whole text is spoken. Returns the number of the
+	/// stream which was queued.
+	fn Speak(&self, text: &str, flags: co::SPF) -> HrResult<u32> {
+		let mut stream_number = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			ok_to_hrresult(
+				(vt.Speak)(
+					self.ptr(),
+					WString::from_str(text).as_ptr(),
+					flags.0,
+					&mut stream_number,
+				),
+			).map(|_| stream_number)
+		}
+	}
+
+	/// `ISpVoice::SpeakStream` method.
+	///
+	/// Speaks the contents of the given stream. Returns the number of the
+	/// stream which was queued.
+	fn SpeakStream(&self,
+		stream: &impl ole_IStream,
+		flags: co::SPF,
+	) -> HrResult<u32>
+	{
+		let mut stream_number = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			ok_to_hrresult(
+				(vt.SpeakStream)(
+					self.ptr(),
+					stream.ptr(),
+					flags.0,
+					&mut stream_number,
+				),
+			).map(|_| stream_number)
+		}
+	}
+
+	/// `ISpVoice::WaitUntilDone` method.
+	///
+	/// If `milliseconds` is `None`, waits indefinitely. Returns `false` if
+	/// the timeout elapsed before the speech ended.
+	fn WaitUntilDone(&self, milliseconds: Option<u32>) -> HrResult<bool> {
+		unsafe {
+			let vt = self.vt_ref::<ISpVoiceVT>();
+			okfalse_to_hrresult(
+				(vt.WaitUntilDone)(
+					self.ptr(),
+					milliseconds.unwrap_or(0xffff_ffff), // INFINITE
+				),
+			)
+		}
+	}
+}
@@ -0,0 +1,37 @@
+mod ienumspobjecttokens;
+mod ispdatakey;
+mod ispeventsource;
+mod ispnotifysource;
+mod ispobjecttoken;
+mod ispobjecttokencategory;
+mod ispvoice;
+
+pub mod decl {
+	pub use super::ienumspobjecttokens::IEnumSpObjectTokens;
+	pub use super::ispdatakey::ISpDataKey;
+	pub use super::ispeventsource::ISpEventSource;
+	pub use super::ispnotifysource::ISpNotifySource;
+	pub use super::ispobjecttoken::ISpObjectToken;
+	pub use super::ispobjecttokencategory::ISpObjectTokenCategory;
+	pub use super::ispvoice::ISpVoice;
+}
+
+pub mod traits {
+	pub use super::ienumspobjecttokens::sapi_IEnumSpObjectTokens;
+	pub use super::ispdatakey::sapi_ISpDataKey;
+	pub use super::ispeventsource::sapi_ISpEventSource;
+	pub use super::ispnotifysource::sapi_ISpNotifySource;
+	pub use super::ispobjecttoken::sapi_ISpObjectToken;
+	pub use super::ispobjecttokencategory::sapi_ISpObjectTokenCategory;
+	pub use super::ispvoice::sapi_ISpVoice;
+}
+
+pub mod vt {
+	pub use super::ienumspobjecttokens::IEnumSpObjectTokensVT;
+	pub use super::ispdatakey::ISpDataKeyVT;
+	pub use super::ispeventsource::ISpEventSourceVT;
+	pub use super::ispnotifysource::ISpNotifySourceVT;
+	pub use super::ispobjecttoken::ISpObjectTokenVT;
+	pub use super::ispobjecttokencategory::ISpObjectTokenCategoryVT;
+	pub use super::ispvoice::ISpVoiceVT;
+}
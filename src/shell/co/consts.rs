@@ -35,6 +35,31 @@ const_bitflag! { FOF: u16;
 	NO_UI Self::SILENT.0 | Self::NOCONFIRMATION.0 | Self::NOERRORUI.0 | Self::NOCONFIRMMKDIR.0
 }
 
+const_bitflag! { FOFX: u32;
+	/// [`IFileOperation::SetOperationFlags`](crate::prelude::shell_IFileOperation::SetOperationFlags)
+	/// extended flags (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	NOSKIPJUNCTIONS 0x0001_0000
+	PREFERHARDLINK 0x0002_0000
+	SHOWELEVATIONPROMPT 0x0004_0000
+	RECYCLEONDELETE 0x0008_0000
+	EARLYFAILURE 0x0010_0000
+	PRESERVEFILEEXTENSIONS 0x0020_0000
+	KEEPNEWERFILE 0x0040_0000
+	NOCOPYHOOKS 0x0080_0000
+	NOMINIMIZEBOX 0x0100_0000
+	MOVEACLSACROSSVOLUMES 0x0200_0000
+	DONTDISPLAYSOURCEPATH 0x0400_0000
+	DONTDISPLAYDESTPATH 0x0800_0000
+	REQUIREELEVATION 0x1000_0000
+	ADDUNDORECORD 0x2000_0000
+	COPYASDOWNLOAD 0x4000_0000
+	DONTDISPLAYLOCATIONS 0x8000_0000
+}
+
 const_bitflag! { FOS: u32;
 	/// [`_FILEOPENDIALOGOPTIONS`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-_fileopendialogoptions)
 	/// enumeration (`u32`).
@@ -137,6 +162,73 @@ const_bitflag! { FOS: u32;
 	SUPPORTSTREAMABLEITEMS 0x8000_0000
 }
 
+const_bitflag! { AO: u32;
+	/// [`ACTIVATEOPTIONS`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-activateoptions)
+	/// enumeration (`u32`).
+	=>
+	=>
+	NONE 0x0000_0000
+	DESIGNMODE 0x0000_0001
+	NOERRORUI 0x0000_0002
+	NOSPLASHSCREEN 0x0000_0004
+	PRELAUNCH 0x0200_0000
+}
+
+const_bitflag! { CDCS: u32;
+	/// [`CDCONTROLSTATEF`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-cdcontrolstatef)
+	/// enumeration (`u32`).
+	=>
+	=>
+	/// The control is inactive and cannot be accessed by the user.
+	INACTIVE 0x0000_0000
+	/// The control is active.
+	ENABLED 0x0000_0001
+	/// The control is visible.
+	VISIBLE 0x0000_0002
+	/// The control is visible and enabled.
+	ENABLEDVISIBLE 0x0000_0003
+}
+
+const_bitflag! { CMF: u32;
+	/// [`IContextMenu::QueryContextMenu`](crate::prelude::shell_IContextMenu::QueryContextMenu)
+	/// `flags` (`u32`).
+	=>
+	=>
+	NORMAL 0x0000_0000
+	DEFAULTONLY 0x0000_0001
+	VERBSONLY 0x0000_0002
+	EXPLORE 0x0000_0004
+	NOVERBS 0x0000_0008
+	CANRENAME 0x0000_0010
+	NODEFAULT 0x0000_0020
+	ITEMMENU 0x0000_0080
+	EXTENDEDVERBS 0x0000_0100
+	DISABLEDVERBS 0x0000_0200
+	ASYNCVERBSTATE 0x0000_0400
+	OPTIMIZEFORINVOKE 0x0000_0800
+	SYNCCASCADEMENU 0x0000_1000
+	DONOTPICKDEFAULT 0x0000_2000
+}
+
+const_bitflag! { CMIC: u32;
+	/// [`CMINVOKECOMMANDINFOEX`](crate::CMINVOKECOMMANDINFOEX) `fMask`
+	/// (`u32`).
+	=>
+	=>
+	HOTKEY 0x0000_0020
+	ICON 0x0000_0010
+	FLAG_NO_UI 0x0000_0400
+	UNICODE 0x0000_4000
+	NO_CONSOLE 0x0000_8000
+	ASYNCOK 0x0010_0000
+	NOASYNC 0x0000_0100
+	SHIFT_DOWN 0x1000_0000
+	CONTROL_DOWN 0x4000_0000
+	FLAG_LOG_USAGE 0x0400_0000
+	NOZONECHECKS 0x0080_0000
+	PTINVOKE 0x2000_0000
+}
+
 const_ordinary! { FDAP: u32;
 	/// [`FDAP`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-fdap)
 	/// enumeration (`u32`).
@@ -146,6 +238,78 @@ const_ordinary! { FDAP: u32;
 	TOP 1
 }
 
+const_ordinary! { FDEOR: u32;
+	/// [`FDE_OVERWRITE_RESPONSE`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-fde_overwrite_response)
+	/// enumeration (`u32`).
+	=>
+	=>
+	DEFAULT 0
+	ACCEPT 1
+	REFUSE 2
+}
+
+const_ordinary! { FDESVR: u32;
+	/// [`FDE_SHAREVIOLATION_RESPONSE`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-fde_shareviolation_response)
+	/// enumeration (`u32`).
+	=>
+	=>
+	DEFAULT 0
+	ACCEPT 1
+	REFUSE 2
+}
+
+const_ordinary! { FFFP_MODE: u32;
+	/// [`FFFP_MODE`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-ffffp_mode)
+	/// enumeration (`u32`).
+	=>
+	=>
+	EXACTMATCH 0
+	NEARESTPARENTMATCH 1
+}
+
+const_ordinary! { GCS: u32;
+	/// [`IContextMenu::GetCommandString`](crate::prelude::shell_IContextMenu::GetCommandString)
+	/// `flags` (`u32`).
+	///
+	/// Only the Unicode values are supported.
+	=>
+	=>
+	VERBW 0x0000_0004
+	HELPTEXTW 0x0000_0005
+	VALIDATEW 0x0000_0006
+	VERBICONW 0x0000_0014
+}
+
+const_bitflag! { GPS: u32;
+	/// [`GETPROPERTYSTOREFLAGS`](https://learn.microsoft.com/en-us/windows/win32/api/propsys/ne-propsys-getpropertystoreflags)
+	/// enumeration (`u32`).
+	=>
+	=>
+	DEFAULT 0x0000_0000
+	HANDLERPROPERTIESONLY 0x0000_0001
+	READWRITE 0x0000_0002
+	TEMPORARY 0x0000_0004
+	FASTPROPERTIESONLY 0x0000_0008
+	OPENSLOWITEM 0x0000_0010
+	DELAYCREATION 0x0000_0020
+	BESTEFFORT 0x0000_0040
+	NO_OPLOCK 0x0000_0080
+	PREFERQUERYPROPERTIES 0x0000_0100
+	EXTRINSICPROPERTIES 0x0000_0200
+	EXTRINSICPROPERTIESONLY 0x0000_0400
+	VOLATILEPROPERTIES 0x0000_0800
+	VOLATILEPROPERTIESONLY 0x0000_1000
+}
+
+const_ordinary! { KDC: u32;
+	/// [`KNOWNDESTCATEGORY`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-knowndestcategory)
+	/// enumeration (`u32`).
+	=>
+	=>
+	FREQUENT 1
+	RECENT 2
+}
+
 const_bitflag! { KF: u32;
 	/// [`KNOWN_FOLDER_FLAG`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/ne-shlobj_core-known_folder_flag)
 	/// enumeration (`u32`).
@@ -169,6 +333,49 @@ const_bitflag! { KF: u32;
 	ALIAS_ONLY 0x8000_0000
 }
 
+const_ordinary! { KF_CATEGORY: u32;
+	/// [`KF_CATEGORY`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-kf_category)
+	/// enumeration (`u32`).
+	=>
+	=>
+	VIRTUAL 1
+	FIXED 2
+	COMMON 3
+	PERUSER 4
+}
+
+const_bitflag! { KF_REDIRECT: u32;
+	/// [`KF_REDIRECT_FLAGS`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-_kf_redirect_flags)
+	/// enumeration (`u32`).
+	=>
+	=>
+	NoValue 0
+	USER_EXCLUSIVE 0x0000_0001
+	COPY_SOURCE_DACL 0x0000_0002
+	OWNER_USER 0x0000_0004
+	SET_OWNER_EXPLICIT 0x0000_0008
+	CHECK_ONLY 0x0000_0010
+	WITH_UI 0x0000_0020
+	UNPIN 0x0000_0040
+	PIN 0x0000_0080
+	COPY_CONTENTS 0x0000_0200
+	DEL_SOURCE_CONTENTS 0x0000_0400
+	EXCLUDE_ALL_KNOWN_SUBFOLDERS 0x0000_0800
+}
+
+const_bitflag! { KF_REDIRECTION_CAPABILITIES: u32;
+	/// [`KF_REDIRECTION_CAPABILITIES`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-_kf_redirection_capabilities)
+	/// enumeration (`u32`).
+	=>
+	=>
+	ALLOW_ALL 0x0000_00ff
+	REDIRECTABLE 0x0000_0001
+	DENY_ALL 0x000f_ff00
+	DENY_POLICY_REDIRECTED 0x0000_0100
+	DENY_POLICY 0x0000_0200
+	DENY_PERMISSIONS 0x0000_0400
+}
+
 const_bitflag! { NIF: u32;
 	/// [`NOTIFYICONDATA`](crate::NOTIFYICONDATA) `uFlags` (`u32`).
 	=>
@@ -208,6 +415,22 @@ const_ordinary! { NIM: u32;
 	SETVERSION 0x0000_0004
 }
 
+const_ordinary! { NIN: u32;
+	/// Notifications sent to the
+	/// [`NOTIFYICONDATA`](crate::NOTIFYICONDATA) `uCallbackMessage`, in the
+	/// low-order word of `lParam` (`u32`).
+	=>
+	=>
+	SELECT 0x0400
+	KEYSELECT 0x0401
+	BALLOONSHOW 0x0402
+	BALLOONHIDE 0x0403
+	BALLOONTIMEOUT 0x0404
+	BALLOONUSERCLICK 0x0405
+	POPUPOPEN 0x0406
+	POPUPCLOSE 0x0407
+}
+
 const_bitflag! { NIS: u32;
 	/// [`NOTIFYICONDATA`](crate::NOTIFYICONDATA) `dwState` and `dwStateFlags`
 	/// (`u32`).
@@ -294,6 +517,94 @@ const_ordinary! { SHARD: u32;
 	SHELLITEM 0x0000_0008
 }
 
+const_bitflag! { SHCNE: u32;
+	/// [`SHChangeNotify`](crate::SHChangeNotify) `event_id` and
+	/// [`HWND::SHChangeNotifyRegister`](crate::prelude::shell_Hwnd::SHChangeNotifyRegister)
+	/// `events` (`i32`).
+	=>
+	=>
+	RENAMEITEM 0x0000_0001
+	CREATE 0x0000_0002
+	DELETE 0x0000_0004
+	MKDIR 0x0000_0008
+	RMDIR 0x0000_0010
+	MEDIAINSERTED 0x0000_0020
+	MEDIAREMOVED 0x0000_0040
+	DRIVEREMOVED 0x0000_0080
+	DRIVEADD 0x0000_0100
+	NETSHARE 0x0000_0200
+	NETUNSHARE 0x0000_0400
+	ATTRIBUTES 0x0000_0800
+	UPDATEDIR 0x0000_1000
+	UPDATEITEM 0x0000_2000
+	SERVERDISCONNECT 0x0000_4000
+	UPDATEIMAGE 0x0000_8000
+	DRIVEADDGUI 0x0001_0000
+	RENAMEFOLDER 0x0002_0000
+	FREESPACE 0x0004_0000
+	EXTENDED_EVENT 0x0400_0000
+	ASSOCCHANGED 0x0800_0000
+	DISKEVENTS 0x0002_381f
+	GLOBALEVENTS 0x0c05_81e0
+	ALLEVENTS 0x7fff_ffff
+	INTERRUPT 0x8000_0000
+}
+
+const_bitflag! { SHCNF: u32;
+	/// [`SHChangeNotify`](crate::SHChangeNotify) `flags` (`u32`).
+	///
+	/// The item type flags are set automatically from the
+	/// [`ChangeNotifyItems`](crate::ChangeNotifyItems) variant.
+	=>
+	=>
+	FLUSH 0x1000
+	FLUSHNOWAIT 0x3000
+	NOTIFYRECURSIVE 0x1_0000
+}
+
+const_bitflag! { SHCNRF: u32;
+	/// [`HWND::SHChangeNotifyRegister`](crate::prelude::shell_Hwnd::SHChangeNotifyRegister)
+	/// `sources` (`i32`).
+	=>
+	=>
+	INTERRUPTLEVEL 0x0001
+	SHELLLEVEL 0x0002
+	RECURSIVEINTERRUPT 0x1000
+	NEWDELIVERY 0x8000
+}
+
+const_bitflag! { SHCONTF: u32;
+	/// [`_SHCONTF`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-_shcontf)
+	/// enumeration (`u32`).
+	=>
+	=>
+	CHECKING_FOR_CHILDREN 0x0_0010
+	FOLDERS 0x0_0020
+	NONFOLDERS 0x0_0040
+	INCLUDEHIDDEN 0x0_0080
+	INIT_ON_FIRST_NEXT 0x0_0100
+	NETPRINTERSRCH 0x0_0200
+	SHAREABLE 0x0_0400
+	STORAGE 0x0_0800
+	NAVIGATION_ENUM 0x0_1000
+	FASTITEMS 0x0_2000
+	FLATLIST 0x0_4000
+	ENABLE_ASYNC 0x0_8000
+	INCLUDESUPERHIDDEN 0x1_0000
+}
+
+const_bitflag! { SHGDN: u32;
+	/// [`_SHGDNF`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-_shgdnf)
+	/// enumeration (`u32`).
+	=>
+	=>
+	NORMAL 0x0000
+	INFOLDER 0x0001
+	FOREDITING 0x1000
+	FORADDRESSBAR 0x4000
+	FORPARSING 0x8000
+}
+
 const_bitflag! { SHGFI: u32;
 	/// [`SHGetFileInfo`](crate::SHGetFileInfo) `flags` (`u32`).
 	=>
@@ -378,6 +689,23 @@ const_ordinary! { SIGDN: u32;
 	PARENTRELATIVEFORUI 0x8009_4001
 }
 
+const_bitflag! { SIIGBF: u32;
+	/// [`SIIGBF`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ishellitemimagefactory)
+	/// enumeration (`u32`).
+	=>
+	=>
+	RESIZETOFIT 0x0000_0000
+	BIGGERSIZEOK 0x0000_0001
+	MEMORYONLY 0x0000_0002
+	ICONONLY 0x0000_0004
+	THUMBNAILONLY 0x0000_0008
+	INCACHEONLY 0x0000_0010
+	CROPTOSQUARE 0x0000_0020
+	WIDETHUMBNAILS 0x0000_0040
+	ICONBACKGROUND 0x0000_0080
+	SCALEUP 0x0000_0100
+}
+
 const_ordinary! { SIID: u32;
 	/// [`SHSTOCKICONID`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/ne-shellapi-shstockiconid)
 	/// enumeration, [`SHGetStockIconInfo`](crate::SHGetStockIconInfo) `siid`
@@ -513,6 +841,39 @@ const_bitflag! { SLR: u32;
 	NO_OBJECT_ID 0x2000
 }
 
+const_bitflag! { SSF: u32;
+	/// [`SHGetSetSettings`](crate::SHGetSetSettings) `mask` (`u32`).
+	=>
+	=>
+	SHOWALLOBJECTS 0x0000_0001
+	SHOWEXTENSIONS 0x0000_0002
+	HIDDENFILEEXTS 0x0000_0004
+	SERVERADMINUI 0x0000_0004
+	SHOWCOMPCOLOR 0x0000_0008
+	SORTCOLUMNS 0x0000_0010
+	SHOWSYSFILES 0x0000_0020
+	DOUBLECLICKINWEBVIEW 0x0000_0080
+	SHOWATTRIBCOL 0x0000_0100
+	DESKTOPHTML 0x0000_0200
+	WIN95CLASSIC 0x0000_0400
+	DONTPRETTYPATH 0x0000_0800
+	MAPNETDRVBUTTON 0x0000_1000
+	SHOWINFOTIP 0x0000_2000
+	HIDEICONS 0x0000_4000
+	NOCONFIRMRECYCLE 0x0000_8000
+	FILTER 0x0001_0000
+	WEBVIEW 0x0002_0000
+	SHOWSUPERHIDDEN 0x0004_0000
+	SEPPROCESS 0x0008_0000
+	NONETCRAWLING 0x0010_0000
+	STARTPANELON 0x0020_0000
+	SHOWSTARTPAGE 0x0040_0000
+	AUTOCHECKSELECT 0x0080_0000
+	ICONSONLY 0x0100_0000
+	SHOWTYPEOVERLAY 0x0200_0000
+	SHOWSTATUSBAR 0x0400_0000
+}
+
 const_ordinary! { STPFLAG: u32;
 	/// [`STPFLAG`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-stpflag)
 	/// enumeration (`u32`).
@@ -559,3 +920,34 @@ const_ordinary! { TBPF: u32;
 	/// generic percentage not indicative of actual progress.
 	PAUSED 0x8
 }
+
+const_bitflag! { THB: u32;
+	/// [`THUMBBUTTON`](crate::THUMBBUTTON) `dwMask` (`u32`).
+	=>
+	=>
+	BITMAP 0x1
+	ICON 0x2
+	TOOLTIP 0x4
+	FLAGS 0x8
+}
+
+const_bitflag! { THBF: u32;
+	/// [`THUMBBUTTON`](crate::THUMBBUTTON) `dwFlags` (`u32`).
+	=>
+	=>
+	ENABLED 0
+	DISABLED 0x1
+	DISMISSONCLICK 0x2
+	NOBACKGROUND 0x4
+	HIDDEN 0x8
+	NONINTERACTIVE 0x10
+}
+
+const_cmd! { THBN;
+	/// Thumbnail toolbar button `WM_COMMAND`
+	/// [notification](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-itaskbarlist3-thumbbaraddbuttons)
+	/// (`u16`), sent when the user clicks a [`THUMBBUTTON`](crate::THUMBBUTTON).
+	=>
+	=>
+	CLICKED 0x1800
+}
@@ -3,10 +3,16 @@
 use crate::co::CLSID;
 
 const_guid_values! { CLSID;
+	ApplicationActivationManager "45ba127d-10a8-46ea-8ab7-56ea9078943c"
+	DestinationList "77f10cf0-3db5-4966-b520-b7c54fd35ed6"
+	EnumerableObjectCollection "2d3468c1-36a7-43b6-ac24-d3f02fd9607a"
 	FileOpenDialog "dc1c5a9c-e88a-4dde-a5a1-60f82a20aef7"
+	FileOperation "3ad05575-8857-4850-9277-11b85bdb8e09"
 	FileSaveDialog "c0b4e2f3-ba21-4773-8dba-335ec946eb8b"
+	KnownFolderManager "4df0c730-df9d-4ae3-9153-aa6b82e9795a"
 	ShellLink "00021401-0000-0000-c000-000000000046"
 	TaskbarList "56fdf344-fd6d-11d0-958a-006097c9a090"
+	VirtualDesktopManager "aa509086-5ca9-4c25-8f95-589d3c07b48a"
 }
 
 const_guid! { BHID;
@@ -0,0 +1,145 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{HRES, PCSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::{ole_IUnknown, shell_IShellItemArray};
+use crate::vt::IUnknownVT;
+
+/// [`IApplicationActivationManager`](crate::IApplicationActivationManager)
+/// virtual table.
+#[repr(C)]
+pub struct IApplicationActivationManagerVT {
+	pub IUnknownVT: IUnknownVT,
+	pub ActivateApplication: fn(ComPtr, PCSTR, PCSTR, u32, *mut u32) -> HRES,
+	pub ActivateForFile: fn(ComPtr, PCSTR, ComPtr, PCSTR, *mut u32) -> HRES,
+	pub ActivateForProtocol: fn(ComPtr, PCSTR, ComPtr, *mut u32) -> HRES,
+}
+
+com_interface! { IApplicationActivationManager: "2e941141-7f97-4756-ba1d-9decde894a3d";
+	/// [`IApplicationActivationManager`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-iapplicationactivationmanager)
+	/// COM interface over
+	/// [`IApplicationActivationManagerVT`](crate::vt::IApplicationActivationManagerVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, IApplicationActivationManager};
+	///
+	/// let obj = CoCreateInstance::<IApplicationActivationManager>(
+	///     &co::CLSID::ApplicationActivationManager,
+	///     None,
+	///     co::CLSCTX::LOCAL_SERVER,
+	/// )?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl shell_IApplicationActivationManager for IApplicationActivationManager {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IApplicationActivationManager`](crate::IApplicationActivationManager).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IApplicationActivationManager: ole_IUnknown {
+	/// [`IApplicationActivationManager::ActivateApplication`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iapplicationactivationmanager-activateapplication)
+	/// method.
+	///
+	/// Returns the process ID of the activated application.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IApplicationActivationManager};
+	///
+	/// let mgr: IApplicationActivationManager; // initialized somewhere
+	/// # let mgr = IApplicationActivationManager::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// let pid = mgr.ActivateApplication(
+	///     "Microsoft.WindowsCalculator_8wekyb3d8bbwe!App",
+	///     None,
+	///     co::AO::NONE,
+	/// )?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	fn ActivateApplication(&self,
+		app_user_model_id: &str,
+		arguments: Option<&str>,
+		options: co::AO,
+	) -> HrResult<u32>
+	{
+		let mut pid = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IApplicationActivationManagerVT>();
+			ok_to_hrresult(
+				(vt.ActivateApplication)(
+					self.ptr(),
+					WString::from_str(app_user_model_id).as_ptr(),
+					WString::from_opt_str(arguments).as_ptr(),
+					options.0,
+					&mut pid,
+				),
+			)
+		}.map(|_| pid)
+	}
+
+	/// [`IApplicationActivationManager::ActivateForFile`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iapplicationactivationmanager-activateforfile)
+	/// method.
+	///
+	/// Returns the process ID of the activated application.
+	fn ActivateForFile(&self,
+		app_user_model_id: &str,
+		items: &impl shell_IShellItemArray,
+		verb: Option<&str>,
+	) -> HrResult<u32>
+	{
+		let mut pid = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IApplicationActivationManagerVT>();
+			ok_to_hrresult(
+				(vt.ActivateForFile)(
+					self.ptr(),
+					WString::from_str(app_user_model_id).as_ptr(),
+					items.ptr(),
+					WString::from_opt_str(verb).as_ptr(),
+					&mut pid,
+				),
+			)
+		}.map(|_| pid)
+	}
+
+	/// [`IApplicationActivationManager::ActivateForProtocol`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iapplicationactivationmanager-activateforprotocol)
+	/// method.
+	///
+	/// Returns the process ID of the activated application.
+	fn ActivateForProtocol(&self,
+		app_user_model_id: &str,
+		items: &impl shell_IShellItemArray,
+	) -> HrResult<u32>
+	{
+		let mut pid = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IApplicationActivationManagerVT>();
+			ok_to_hrresult(
+				(vt.ActivateForProtocol)(
+					self.ptr(),
+					WString::from_str(app_user_model_id).as_ptr(),
+					items.ptr(),
+					&mut pid,
+				),
+			)
+		}.map(|_| pid)
+	}
+}
@@ -0,0 +1,149 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{HANDLE, HRES, PCVOID, PVOID};
+use crate::kernel::privs::MAX_PATH;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::{Handle, ole_IUnknown};
+use crate::shell::decl::CMINVOKECOMMANDINFOEX;
+use crate::user::decl::HMENU;
+use crate::vt::IUnknownVT;
+
+/// [`IContextMenu`](crate::IContextMenu) virtual table.
+#[repr(C)]
+pub struct IContextMenuVT {
+	pub IUnknownVT: IUnknownVT,
+	pub QueryContextMenu: fn(ComPtr, HANDLE, u32, u32, u32, u32) -> HRES,
+	pub InvokeCommand: fn(ComPtr, PCVOID) -> HRES,
+	pub GetCommandString: fn(ComPtr, usize, u32, *mut u32, PVOID, u32) -> HRES,
+}
+
+com_interface! { IContextMenu: "000214e4-0000-0000-c000-000000000046";
+	/// [`IContextMenu`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-icontextmenu)
+	/// COM interface over [`IContextMenuVT`](crate::vt::IContextMenuVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// Showing the Explorer context menu of a file, and running the chosen
+	/// command:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CMINVOKECOMMANDINFOEX, GetCursorPos, HMENU, HWND,
+	///     IBindCtx, IContextMenu, IdStr, IShellItem, SHCreateItemFromParsingName};
+	///
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	///
+	/// let item = SHCreateItemFromParsingName::<IShellItem>(
+	///     "C:\\Temp\\foo.txt",
+	///     None::<&IBindCtx>,
+	/// )?;
+	/// let ctx_menu = item.BindToHandler::<IContextMenu>(
+	///     None::<&IBindCtx>,
+	///     &co::BHID::SFUIObject,
+	/// )?;
+	///
+	/// const FIRST_ID: u16 = 1;
+	/// let mut hmenu = HMENU::CreatePopupMenu()?;
+	/// ctx_menu.QueryContextMenu(&hmenu, 0, FIRST_ID, 0x7fff, co::CMF::NORMAL)?;
+	///
+	/// if let Some(cmd_id) = hmenu.TrackPopupMenu(
+	///     co::TPM::RETURNCMD, GetCursorPos()?, &hwnd)?
+	/// {
+	///     let verb = IdStr::Id(cmd_id as u16 - FIRST_ID);
+	///     let mut cmi = CMINVOKECOMMANDINFOEX::default();
+	///     cmi.hwnd = unsafe { hwnd.raw_copy() };
+	///     cmi.nShow = co::SW::SHOWNORMAL;
+	///     cmi.set_lpVerbW(Some(&verb));
+	///     ctx_menu.InvokeCommand(&cmi)?;
+	/// }
+	///
+	/// hmenu.DestroyMenu()?;
+	/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+	/// ```
+}
+
+impl shell_IContextMenu for IContextMenu {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IContextMenu`](crate::IContextMenu).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IContextMenu: ole_IUnknown {
+	/// [`IContextMenu::GetCommandString`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icontextmenu-getcommandstring)
+	/// method.
+	///
+	/// `cmd_offset` is relative to the first ID given to
+	/// [`IContextMenu::QueryContextMenu`](crate::prelude::shell_IContextMenu::QueryContextMenu).
+	#[must_use]
+	fn GetCommandString(&self,
+		cmd_offset: u16, flags: co::GCS) -> HrResult<String>
+	{
+		let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
+		unsafe {
+			let vt = self.vt_ref::<IContextMenuVT>();
+			ok_to_hrresult(
+				(vt.GetCommandString)(
+					self.ptr(),
+					cmd_offset as _,
+					flags.0,
+					std::ptr::null_mut(),
+					buf.as_mut_ptr() as _,
+					buf.as_slice().len() as _,
+				),
+			).map(|_| buf.to_string())
+		}
+	}
+
+	/// [`IContextMenu::InvokeCommand`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icontextmenu-invokecommand)
+	/// method.
+	fn InvokeCommand(&self, info: &CMINVOKECOMMANDINFOEX) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IContextMenuVT>();
+			ok_to_hrresult((vt.InvokeCommand)(self.ptr(), info as *const _ as _))
+		}
+	}
+
+	/// [`IContextMenu::QueryContextMenu`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icontextmenu-querycontextmenu)
+	/// method.
+	///
+	/// The menu items are inserted at `index_menu`, with command IDs between
+	/// `id_cmd_first` and `id_cmd_last`. Returns the offset of the largest
+	/// command ID assigned, plus one.
+	fn QueryContextMenu(&self,
+		hmenu: &HMENU,
+		index_menu: u32,
+		id_cmd_first: u16,
+		id_cmd_last: u16,
+		flags: co::CMF,
+	) -> HrResult<u16>
+	{
+		let hr = unsafe {
+			let vt = self.vt_ref::<IContextMenuVT>();
+			(vt.QueryContextMenu)(
+				self.ptr(),
+				hmenu.as_ptr(),
+				index_menu,
+				id_cmd_first as _,
+				id_cmd_last as _,
+				flags.0,
+			)
+		};
+		if hr & 0x8000_0000 != 0 { // FAILED
+			Err(co::HRESULT(hr))
+		} else {
+			Ok(hr as u16) // HRESULT_CODE
+		}
+	}
+}
@@ -0,0 +1,54 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::HRES;
+use crate::msg::WndMsg;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::shell_IContextMenu;
+use crate::vt::IContextMenuVT;
+
+/// [`IContextMenu2`](crate::IContextMenu2) virtual table.
+#[repr(C)]
+pub struct IContextMenu2VT {
+	pub IContextMenuVT: IContextMenuVT,
+	pub HandleMenuMsg: fn(ComPtr, u32, usize, isize) -> HRES,
+}
+
+com_interface! { IContextMenu2: "000214f4-0000-0000-c000-000000000046";
+	/// [`IContextMenu2`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-icontextmenu2)
+	/// COM interface over [`IContextMenu2VT`](crate::vt::IContextMenu2VT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl shell_IContextMenu for IContextMenu2 {}
+impl shell_IContextMenu2 for IContextMenu2 {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IContextMenu2`](crate::IContextMenu2).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IContextMenu2: shell_IContextMenu {
+	/// [`IContextMenu2::HandleMenuMsg`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icontextmenu2-handlemenumsg)
+	/// method.
+	///
+	/// While the menu is shown, the owner window must forward
+	/// [`WM_INITMENUPOPUP`](crate::msg::wm::InitMenuPopup),
+	/// [`WM_DRAWITEM`](crate::msg::wm::DrawItem) and
+	/// [`WM_MEASUREITEM`](crate::msg::wm::MeasureItem) to this method, so the
+	/// owner-drawn items and the submenus are rendered.
+	fn HandleMenuMsg(&self, msg: WndMsg) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IContextMenu2VT>();
+			ok_to_hrresult(
+				(vt.HandleMenuMsg)(self.ptr(), msg.msg_id.0, msg.wparam, msg.lparam),
+			)
+		}
+	}
+}
@@ -0,0 +1,84 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::HRES;
+use crate::msg::WndMsg;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::{shell_IContextMenu, shell_IContextMenu2};
+use crate::vt::IContextMenu2VT;
+
+/// [`IContextMenu3`](crate::IContextMenu3) virtual table.
+#[repr(C)]
+pub struct IContextMenu3VT {
+	pub IContextMenu2VT: IContextMenu2VT,
+	pub HandleMenuMsg2: fn(ComPtr, u32, usize, isize, *mut isize) -> HRES,
+}
+
+com_interface! { IContextMenu3: "bcfce0a0-ec17-11d0-8d10-00a0c90f2719";
+	/// [`IContextMenu3`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-icontextmenu3)
+	/// COM interface over [`IContextMenu3VT`](crate::vt::IContextMenu3VT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl shell_IContextMenu for IContextMenu3 {}
+impl shell_IContextMenu2 for IContextMenu3 {}
+impl shell_IContextMenu3 for IContextMenu3 {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IContextMenu3`](crate::IContextMenu3).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IContextMenu3: shell_IContextMenu2 {
+	/// [`IContextMenu3::HandleMenuMsg2`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icontextmenu3-handlemenumsg2)
+	/// method.
+	///
+	/// Like [`IContextMenu2::HandleMenuMsg`](crate::prelude::shell_IContextMenu2::HandleMenuMsg),
+	/// but also handles [`WM_MENUCHAR`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-menuchar), returning
+	/// the value to be returned by the window procedure.
+	///
+	/// # Examples
+	///
+	/// Forwarding the menu messages while the context menu is shown:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IContextMenu3, msg};
+	///
+	/// let ctx_menu3: IContextMenu3; // initialized somewhere
+	/// # let ctx_menu3 = IContextMenu3::from(unsafe { winsafe::ComPtr::null() });
+	/// let p: msg::WndMsg; // received in the window procedure
+	/// # let p = msg::WndMsg::new(co::WM::MENUCHAR, 0, 0);
+	///
+	/// match p.msg_id {
+	///     co::WM::INITMENUPOPUP | co::WM::DRAWITEM
+	///     | co::WM::MEASUREITEM | co::WM::MENUCHAR => {
+	///         let ret_val = ctx_menu3.HandleMenuMsg2(p)?;
+	///         // return ret_val from the window procedure
+	///     },
+	///     _ => {},
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	fn HandleMenuMsg2(&self, msg: WndMsg) -> HrResult<isize> {
+		let mut result = isize::default();
+		unsafe {
+			let vt = self.vt_ref::<IContextMenu3VT>();
+			ok_to_hrresult(
+				(vt.HandleMenuMsg2)(
+					self.ptr(),
+					msg.msg_id.0,
+					msg.wparam,
+					msg.lparam,
+					&mut result,
+				),
+			).map(|_| result)
+		}
+	}
+}
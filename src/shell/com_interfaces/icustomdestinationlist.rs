@@ -0,0 +1,185 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{HRES, PCSTR, PCVOID};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::{ole_IUnknown, shell_IObjectArray};
+use crate::vt::IUnknownVT;
+
+/// [`ICustomDestinationList`](crate::ICustomDestinationList) virtual table.
+#[repr(C)]
+pub struct ICustomDestinationListVT {
+	pub IUnknownVT: IUnknownVT,
+	pub SetAppID: fn(ComPtr, PCSTR) -> HRES,
+	pub BeginList: fn(ComPtr, *mut u32, PCVOID, *mut ComPtr) -> HRES,
+	pub AppendCategory: fn(ComPtr, PCSTR, ComPtr) -> HRES,
+	pub AppendKnownCategory: fn(ComPtr, u32) -> HRES,
+	pub AddUserTasks: fn(ComPtr, ComPtr) -> HRES,
+	pub CommitList: fn(ComPtr) -> HRES,
+	pub GetRemovedDestinations: fn(ComPtr, PCVOID, *mut ComPtr) -> HRES,
+	pub DeleteList: fn(ComPtr, PCSTR) -> HRES,
+	pub AbortList: fn(ComPtr) -> HRES,
+}
+
+com_interface! { ICustomDestinationList: "6332debf-87b5-4670-90c0-5e57b408a49e";
+	/// [`ICustomDestinationList`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-icustomdestinationlist)
+	/// COM interface over [`ICustomDestinationListVT`](crate::vt::ICustomDestinationListVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// Prefer using the [`JumpList`](crate::JumpList) builder.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, ICustomDestinationList};
+	///
+	/// let obj = CoCreateInstance::<ICustomDestinationList>(
+	///     &co::CLSID::DestinationList,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl shell_ICustomDestinationList for ICustomDestinationList {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`ICustomDestinationList`](crate::ICustomDestinationList).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_ICustomDestinationList: ole_IUnknown {
+	/// [`ICustomDestinationList::AbortList`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icustomdestinationlist-abortlist)
+	/// method.
+	fn AbortList(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ICustomDestinationListVT>();
+			ok_to_hrresult((vt.AbortList)(self.ptr()))
+		}
+	}
+
+	/// [`ICustomDestinationList::AddUserTasks`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icustomdestinationlist-addusertasks)
+	/// method.
+	fn AddUserTasks(&self, tasks: &impl shell_IObjectArray) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ICustomDestinationListVT>();
+			ok_to_hrresult((vt.AddUserTasks)(self.ptr(), tasks.ptr()))
+		}
+	}
+
+	/// [`ICustomDestinationList::AppendCategory`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icustomdestinationlist-appendcategory)
+	/// method.
+	fn AppendCategory(&self,
+		category: &str, items: &impl shell_IObjectArray) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<ICustomDestinationListVT>();
+			ok_to_hrresult(
+				(vt.AppendCategory)(
+					self.ptr(),
+					WString::from_str(category).as_ptr(),
+					items.ptr(),
+				),
+			)
+		}
+	}
+
+	/// [`ICustomDestinationList::AppendKnownCategory`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icustomdestinationlist-appendknowncategory)
+	/// method.
+	fn AppendKnownCategory(&self, category: co::KDC) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ICustomDestinationListVT>();
+			ok_to_hrresult((vt.AppendKnownCategory)(self.ptr(), category.0))
+		}
+	}
+
+	/// [`ICustomDestinationList::BeginList`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icustomdestinationlist-beginlist)
+	/// method.
+	///
+	/// Returns the maximum number of items which will fit the list, and the
+	/// items removed by the user, which must not be added again.
+	#[must_use]
+	fn BeginList<T>(&self) -> HrResult<(u32, T)>
+		where T: ole_IUnknown,
+	{
+		let mut min_slots = u32::default();
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ICustomDestinationListVT>();
+			ok_to_hrresult(
+				(vt.BeginList)(
+					self.ptr(),
+					&mut min_slots,
+					&T::IID as *const _ as _,
+					&mut ppv_queried,
+				),
+			).map(|_| (min_slots, T::from(ppv_queried)))
+		}
+	}
+
+	/// [`ICustomDestinationList::CommitList`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icustomdestinationlist-commitlist)
+	/// method.
+	fn CommitList(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ICustomDestinationListVT>();
+			ok_to_hrresult((vt.CommitList)(self.ptr()))
+		}
+	}
+
+	/// [`ICustomDestinationList::DeleteList`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icustomdestinationlist-deletelist)
+	/// method.
+	fn DeleteList(&self, app_id: Option<&str>) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ICustomDestinationListVT>();
+			ok_to_hrresult(
+				(vt.DeleteList)(
+					self.ptr(),
+					WString::from_opt_str(app_id).as_ptr(),
+				),
+			)
+		}
+	}
+
+	/// [`ICustomDestinationList::GetRemovedDestinations`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icustomdestinationlist-getremoveddestinations)
+	/// method.
+	#[must_use]
+	fn GetRemovedDestinations<T>(&self) -> HrResult<T>
+		where T: ole_IUnknown,
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ICustomDestinationListVT>();
+			ok_to_hrresult(
+				(vt.GetRemovedDestinations)(
+					self.ptr(),
+					&T::IID as *const _ as _,
+					&mut ppv_queried,
+				),
+			).map(|_| T::from(ppv_queried))
+		}
+	}
+
+	/// [`ICustomDestinationList::SetAppID`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-icustomdestinationlist-setappid)
+	/// method.
+	fn SetAppID(&self, app_id: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ICustomDestinationListVT>();
+			ok_to_hrresult(
+				(vt.SetAppID)(
+					self.ptr(),
+					WString::from_str(app_id).as_ptr(),
+				),
+			)
+		}
+	}
+}
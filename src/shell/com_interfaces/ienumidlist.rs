@@ -0,0 +1,152 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PVOID};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::prelude::ole_IUnknown;
+use crate::shell::guard::CoTaskMemFreePidlGuard;
+use crate::vt::IUnknownVT;
+
+/// [`IEnumIDList`](crate::IEnumIDList) virtual table.
+#[repr(C)]
+pub struct IEnumIDListVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Next: fn(ComPtr, u32, *mut PVOID, *mut u32) -> HRES,
+	pub Skip: fn(ComPtr, u32) -> HRES,
+	pub Reset: fn(ComPtr) -> HRES,
+	pub Clone: fn(ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IEnumIDList: "000214f2-0000-0000-c000-000000000046";
+	/// [`IEnumIDList`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ienumidlist)
+	/// COM interface over [`IEnumIDListVT`](crate::vt::IEnumIDListVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl shell_IEnumIDList for IEnumIDList {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IEnumIDList`](crate::IEnumIDList).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IEnumIDList: ole_IUnknown {
+	/// Returns an iterator over the [`ITEMIDLIST`](crate::ITEMIDLIST) elements
+	/// which calls
+	/// [`IEnumIDList::Next`](crate::prelude::shell_IEnumIDList::Next)
+	/// internally.
+	///
+	/// # Examples
+	///
+	/// Enumerating the items in a folder:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IEnumIDList};
+	///
+	/// let items: IEnumIDList; // initialized somewhere
+	/// # let items = IEnumIDList::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// for pidl in items.iter() {
+	///     let pidl = pidl?;
+	///     println!("{} bytes", pidl.size());
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn iter(&self,
+	) -> Box<dyn Iterator<Item = HrResult<CoTaskMemFreePidlGuard>> + '_>
+	{
+		Box::new(EnumIdListIter::new(self))
+	}
+
+	/// [`IEnumIDList::Clone`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ienumidlist-clone)
+	/// method.
+	#[must_use]
+	fn Clone(&self) -> HrResult<IEnumIDList> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumIDListVT>();
+			ok_to_hrresult((vt.Clone)(self.ptr(), &mut ppv_queried))
+				.map(|_| IEnumIDList::from(ppv_queried))
+		}
+	}
+
+	/// [`IEnumIDList::Next`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ienumidlist-next)
+	/// method.
+	///
+	/// Prefer using
+	/// [`IEnumIDList::iter`](crate::prelude::shell_IEnumIDList::iter), which is
+	/// simpler.
+	#[must_use]
+	fn Next(&self) -> HrResult<Option<CoTaskMemFreePidlGuard>> {
+		let mut fetched = u32::default();
+		let mut pidl = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IEnumIDListVT>();
+			match ok_to_hrresult(
+				(vt.Next)(self.ptr(), 1, &mut pidl, &mut fetched), // retrieve only 1
+			) {
+				Ok(_) => Ok(Some(CoTaskMemFreePidlGuard::new(pidl as _))),
+				Err(hr) => match hr {
+					co::HRESULT::S_FALSE => Ok(None), // no item found
+					hr => Err(hr), // actual error
+				},
+			}
+		}
+	}
+
+	/// [`IEnumIDList::Reset`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ienumidlist-reset)
+	/// method.
+	fn Reset(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumIDListVT>();
+			ok_to_hrresult((vt.Reset)(self.ptr()))
+		}
+	}
+
+	/// [`IEnumIDList::Skip`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ienumidlist-skip)
+	/// method.
+	fn Skip(&self, count: u32) -> HrResult<bool> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumIDListVT>();
+			okfalse_to_hrresult((vt.Skip)(self.ptr(), count))
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct EnumIdListIter<'a, I>
+	where I: shell_IEnumIDList,
+{
+	enum_idl: &'a I,
+}
+
+impl<'a, I> Iterator for EnumIdListIter<'a, I>
+	where I: shell_IEnumIDList,
+{
+	type Item = HrResult<CoTaskMemFreePidlGuard>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.enum_idl.Next() {
+			Err(err) => Some(Err(err)),
+			Ok(maybe_pidl) => maybe_pidl.map(Ok),
+		}
+	}
+}
+
+impl<'a, I> EnumIdListIter<'a, I>
+	where I: shell_IEnumIDList,
+{
+	fn new(enum_idl: &'a I) -> Self {
+		Self { enum_idl }
+	}
+}
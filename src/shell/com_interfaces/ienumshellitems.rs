@@ -91,7 +91,7 @@ pub trait shell_IEnumShellItems: ole_IUnknown {
 				Ok(_) => Ok(Some(IShellItem::from(ppv_queried))),
 				Err(hr) => match hr {
 					co::HRESULT::S_FALSE => Ok(None), // no item found
-				hr => Err(hr), // actual error
+					hr => Err(hr), // actual error
 				},
 			}
 		}
@@ -0,0 +1,426 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{BOOL, HRES, PCSTR, PSTR};
+use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IFileDialogCustomize`](crate::IFileDialogCustomize) virtual table.
+#[repr(C)]
+pub struct IFileDialogCustomizeVT {
+	pub IUnknownVT: IUnknownVT,
+	pub EnableOpenDropDown: fn(ComPtr, u32) -> HRES,
+	pub AddMenu: fn(ComPtr, u32, PCSTR) -> HRES,
+	pub AddPushButton: fn(ComPtr, u32, PCSTR) -> HRES,
+	pub AddComboBox: fn(ComPtr, u32) -> HRES,
+	pub AddRadioButtonList: fn(ComPtr, u32) -> HRES,
+	pub AddCheckButton: fn(ComPtr, u32, PCSTR, BOOL) -> HRES,
+	pub AddEditBox: fn(ComPtr, u32, PCSTR) -> HRES,
+	pub AddSeparator: fn(ComPtr, u32) -> HRES,
+	pub AddText: fn(ComPtr, u32, PCSTR) -> HRES,
+	pub SetControlLabel: fn(ComPtr, u32, PCSTR) -> HRES,
+	pub GetControlState: fn(ComPtr, u32, *mut u32) -> HRES,
+	pub SetControlState: fn(ComPtr, u32, u32) -> HRES,
+	pub GetEditBoxText: fn(ComPtr, u32, *mut PSTR) -> HRES,
+	pub SetEditBoxText: fn(ComPtr, u32, PCSTR) -> HRES,
+	pub GetCheckButtonState: fn(ComPtr, u32, *mut BOOL) -> HRES,
+	pub SetCheckButtonState: fn(ComPtr, u32, BOOL) -> HRES,
+	pub AddControlItem: fn(ComPtr, u32, u32, PCSTR) -> HRES,
+	pub RemoveControlItem: fn(ComPtr, u32, u32) -> HRES,
+	pub RemoveAllControlItems: fn(ComPtr, u32) -> HRES,
+	pub GetControlItemState: fn(ComPtr, u32, u32, *mut u32) -> HRES,
+	pub SetControlItemState: fn(ComPtr, u32, u32, u32) -> HRES,
+	pub GetSelectedControlItem: fn(ComPtr, u32, *mut u32) -> HRES,
+	pub SetSelectedControlItem: fn(ComPtr, u32, u32) -> HRES,
+	pub StartVisualGroup: fn(ComPtr, u32, PCSTR) -> HRES,
+	pub EndVisualGroup: fn(ComPtr) -> HRES,
+	pub MakeProminent: fn(ComPtr, u32) -> HRES,
+	pub SetControlItemText: fn(ComPtr, u32, u32, PCSTR) -> HRES,
+}
+
+com_interface! { IFileDialogCustomize: "e6fdd21a-163f-4975-9c8c-a69f1ba37034";
+	/// [`IFileDialogCustomize`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ifiledialogcustomize)
+	/// COM interface over
+	/// [`IFileDialogCustomizeVT`](crate::vt::IFileDialogCustomizeVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// Adding a check button to an open dialog, and reading its state after
+	/// the dialog is closed:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, HWND};
+	/// use winsafe::{IFileDialogCustomize, IFileOpenDialog};
+	///
+	/// const ID_READ_ONLY: u32 = 1000;
+	///
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	///
+	/// let file_open = CoCreateInstance::<IFileOpenDialog>(
+	///     &co::CLSID::FileOpenDialog,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	///
+	/// let customize = file_open.QueryInterface::<IFileDialogCustomize>()?;
+	/// customize.AddCheckButton(ID_READ_ONLY, "Open as read-only", false)?;
+	///
+	/// if file_open.Show(&hwnd)? {
+	///     let read_only = customize.GetCheckButtonState(ID_READ_ONLY)?;
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl shell_IFileDialogCustomize for IFileDialogCustomize {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IFileDialogCustomize`](crate::IFileDialogCustomize).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IFileDialogCustomize: ole_IUnknown {
+	/// [`IFileDialogCustomize::AddCheckButton`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-addcheckbutton)
+	/// method.
+	fn AddCheckButton(&self,
+		ctrl_id: u32, label: &str, checked: bool) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.AddCheckButton)(
+					self.ptr(),
+					ctrl_id,
+					WString::from_str(label).as_ptr(),
+					checked as _,
+				),
+			)
+		}
+	}
+
+	/// [`IFileDialogCustomize::AddComboBox`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-addcombobox)
+	/// method.
+	///
+	/// Items are added with
+	/// [`AddControlItem`](crate::prelude::shell_IFileDialogCustomize::AddControlItem).
+	fn AddComboBox(&self, ctrl_id: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult((vt.AddComboBox)(self.ptr(), ctrl_id))
+		}
+	}
+
+	/// [`IFileDialogCustomize::AddControlItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-addcontrolitem)
+	/// method.
+	fn AddControlItem(&self,
+		ctrl_id: u32, item_id: u32, label: &str) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.AddControlItem)(
+					self.ptr(),
+					ctrl_id,
+					item_id,
+					WString::from_str(label).as_ptr(),
+				),
+			)
+		}
+	}
+
+	/// [`IFileDialogCustomize::AddEditBox`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-addeditbox)
+	/// method.
+	fn AddEditBox(&self, ctrl_id: u32, text: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.AddEditBox)(
+					self.ptr(), ctrl_id, WString::from_str(text).as_ptr()),
+			)
+		}
+	}
+
+	/// [`IFileDialogCustomize::AddMenu`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-addmenu)
+	/// method.
+	fn AddMenu(&self, ctrl_id: u32, label: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.AddMenu)(self.ptr(), ctrl_id, WString::from_str(label).as_ptr()),
+			)
+		}
+	}
+
+	/// [`IFileDialogCustomize::AddPushButton`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-addpushbutton)
+	/// method.
+	fn AddPushButton(&self, ctrl_id: u32, label: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.AddPushButton)(
+					self.ptr(), ctrl_id, WString::from_str(label).as_ptr()),
+			)
+		}
+	}
+
+	/// [`IFileDialogCustomize::AddRadioButtonList`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-addradiobuttonlist)
+	/// method.
+	///
+	/// Items are added with
+	/// [`AddControlItem`](crate::prelude::shell_IFileDialogCustomize::AddControlItem).
+	fn AddRadioButtonList(&self, ctrl_id: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult((vt.AddRadioButtonList)(self.ptr(), ctrl_id))
+		}
+	}
+
+	/// [`IFileDialogCustomize::AddSeparator`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-addseparator)
+	/// method.
+	fn AddSeparator(&self, ctrl_id: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult((vt.AddSeparator)(self.ptr(), ctrl_id))
+		}
+	}
+
+	/// [`IFileDialogCustomize::AddText`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-addtext)
+	/// method.
+	fn AddText(&self, ctrl_id: u32, text: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.AddText)(self.ptr(), ctrl_id, WString::from_str(text).as_ptr()),
+			)
+		}
+	}
+
+	/// [`IFileDialogCustomize::EnableOpenDropDown`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-enableopendropdown)
+	/// method.
+	fn EnableOpenDropDown(&self, ctrl_id: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult((vt.EnableOpenDropDown)(self.ptr(), ctrl_id))
+		}
+	}
+
+	/// [`IFileDialogCustomize::EndVisualGroup`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-endvisualgroup)
+	/// method.
+	fn EndVisualGroup(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult((vt.EndVisualGroup)(self.ptr()))
+		}
+	}
+
+	/// [`IFileDialogCustomize::GetCheckButtonState`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-getcheckbuttonstate)
+	/// method.
+	#[must_use]
+	fn GetCheckButtonState(&self, ctrl_id: u32) -> HrResult<bool> {
+		let mut checked: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.GetCheckButtonState)(self.ptr(), ctrl_id, &mut checked),
+			)
+		}.map(|_| checked != 0)
+	}
+
+	/// [`IFileDialogCustomize::GetControlItemState`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-getcontrolitemstate)
+	/// method.
+	#[must_use]
+	fn GetControlItemState(&self,
+		ctrl_id: u32, item_id: u32) -> HrResult<co::CDCS>
+	{
+		let mut state = co::CDCS::default();
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.GetControlItemState)(self.ptr(), ctrl_id, item_id, &mut state.0),
+			)
+		}.map(|_| state)
+	}
+
+	/// [`IFileDialogCustomize::GetControlState`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-getcontrolstate)
+	/// method.
+	#[must_use]
+	fn GetControlState(&self, ctrl_id: u32) -> HrResult<co::CDCS> {
+		let mut state = co::CDCS::default();
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.GetControlState)(self.ptr(), ctrl_id, &mut state.0),
+			)
+		}.map(|_| state)
+	}
+
+	/// [`IFileDialogCustomize::GetEditBoxText`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-geteditboxtext)
+	/// method.
+	#[must_use]
+	fn GetEditBoxText(&self, ctrl_id: u32) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult((vt.GetEditBoxText)(self.ptr(), ctrl_id, &mut pstr))
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// [`IFileDialogCustomize::GetSelectedControlItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-getselectedcontrolitem)
+	/// method.
+	///
+	/// Returns the ID of the selected item.
+	#[must_use]
+	fn GetSelectedControlItem(&self, ctrl_id: u32) -> HrResult<u32> {
+		let mut item_id = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.GetSelectedControlItem)(self.ptr(), ctrl_id, &mut item_id),
+			)
+		}.map(|_| item_id)
+	}
+
+	/// [`IFileDialogCustomize::MakeProminent`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-makeprominent)
+	/// method.
+	fn MakeProminent(&self, ctrl_id: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult((vt.MakeProminent)(self.ptr(), ctrl_id))
+		}
+	}
+
+	/// [`IFileDialogCustomize::RemoveAllControlItems`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-removeallcontrolitems)
+	/// method.
+	fn RemoveAllControlItems(&self, ctrl_id: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult((vt.RemoveAllControlItems)(self.ptr(), ctrl_id))
+		}
+	}
+
+	/// [`IFileDialogCustomize::RemoveControlItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-removecontrolitem)
+	/// method.
+	fn RemoveControlItem(&self, ctrl_id: u32, item_id: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult((vt.RemoveControlItem)(self.ptr(), ctrl_id, item_id))
+		}
+	}
+
+	/// [`IFileDialogCustomize::SetCheckButtonState`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-setcheckbuttonstate)
+	/// method.
+	fn SetCheckButtonState(&self, ctrl_id: u32, checked: bool) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.SetCheckButtonState)(self.ptr(), ctrl_id, checked as _),
+			)
+		}
+	}
+
+	/// [`IFileDialogCustomize::SetControlItemState`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-setcontrolitemstate)
+	/// method.
+	fn SetControlItemState(&self,
+		ctrl_id: u32, item_id: u32, state: co::CDCS) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.SetControlItemState)(self.ptr(), ctrl_id, item_id, state.0),
+			)
+		}
+	}
+
+	/// [`IFileDialogCustomize::SetControlItemText`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-setcontrolitemtext)
+	/// method.
+	fn SetControlItemText(&self,
+		ctrl_id: u32, item_id: u32, label: &str) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.SetControlItemText)(
+					self.ptr(),
+					ctrl_id,
+					item_id,
+					WString::from_str(label).as_ptr(),
+				),
+			)
+		}
+	}
+
+	/// [`IFileDialogCustomize::SetControlLabel`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-setcontrollabel)
+	/// method.
+	fn SetControlLabel(&self, ctrl_id: u32, label: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.SetControlLabel)(
+					self.ptr(), ctrl_id, WString::from_str(label).as_ptr()),
+			)
+		}
+	}
+
+	/// [`IFileDialogCustomize::SetControlState`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-setcontrolstate)
+	/// method.
+	fn SetControlState(&self, ctrl_id: u32, state: co::CDCS) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult((vt.SetControlState)(self.ptr(), ctrl_id, state.0))
+		}
+	}
+
+	/// [`IFileDialogCustomize::SetEditBoxText`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-seteditboxtext)
+	/// method.
+	fn SetEditBoxText(&self, ctrl_id: u32, text: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.SetEditBoxText)(
+					self.ptr(), ctrl_id, WString::from_str(text).as_ptr()),
+			)
+		}
+	}
+
+	/// [`IFileDialogCustomize::SetSelectedControlItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-setselectedcontrolitem)
+	/// method.
+	fn SetSelectedControlItem(&self, ctrl_id: u32, item_id: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.SetSelectedControlItem)(self.ptr(), ctrl_id, item_id),
+			)
+		}
+	}
+
+	/// [`IFileDialogCustomize::StartVisualGroup`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogcustomize-startvisualgroup)
+	/// method.
+	///
+	/// The group is closed with
+	/// [`EndVisualGroup`](crate::prelude::shell_IFileDialogCustomize::EndVisualGroup).
+	fn StartVisualGroup(&self, ctrl_id: u32, label: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileDialogCustomizeVT>();
+			ok_to_hrresult(
+				(vt.StartVisualGroup)(
+					self.ptr(), ctrl_id, WString::from_str(label).as_ptr()),
+			)
+		}
+	}
+}
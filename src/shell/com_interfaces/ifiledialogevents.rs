@@ -0,0 +1,139 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::prelude::{ole_IUnknown, shell_IFileDialog, shell_IShellItem};
+use crate::vt::IUnknownVT;
+
+/// [`IFileDialogEvents`](crate::IFileDialogEvents) virtual table.
+#[repr(C)]
+pub struct IFileDialogEventsVT {
+	pub IUnknownVT: IUnknownVT,
+	pub OnFileOk: fn(ComPtr, ComPtr) -> HRES,
+	pub OnFolderChanging: fn(ComPtr, ComPtr, ComPtr) -> HRES,
+	pub OnFolderChange: fn(ComPtr, ComPtr) -> HRES,
+	pub OnSelectionChange: fn(ComPtr, ComPtr) -> HRES,
+	pub OnShareViolation: fn(ComPtr, ComPtr, ComPtr, *mut u32) -> HRES,
+	pub OnTypeChange: fn(ComPtr, ComPtr) -> HRES,
+	pub OnOverwrite: fn(ComPtr, ComPtr, ComPtr, *mut u32) -> HRES,
+}
+
+com_interface! { IFileDialogEvents: "973510db-7d7f-452b-8975-74a85828d354";
+	/// [`IFileDialogEvents`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ifiledialogevents)
+	/// COM interface over
+	/// [`IFileDialogEventsVT`](crate::vt::IFileDialogEventsVT).
+	///
+	/// An implementation can be created with
+	/// [`FileDialogEvents`](crate::FileDialogEvents).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl shell_IFileDialogEvents for IFileDialogEvents {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IFileDialogEvents`](crate::IFileDialogEvents).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IFileDialogEvents: ole_IUnknown {
+	/// [`IFileDialogEvents::OnFileOk`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onfileok)
+	/// method.
+	///
+	/// Returns false if the selection was rejected.
+	fn OnFileOk(&self, fd: &impl shell_IFileDialog) -> HrResult<bool> {
+		okfalse_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IFileDialogEventsVT>();
+				(vt.OnFileOk)(self.ptr(), fd.ptr())
+			},
+		)
+	}
+
+	/// [`IFileDialogEvents::OnFolderChange`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onfolderchange)
+	/// method.
+	fn OnFolderChange(&self, fd: &impl shell_IFileDialog) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IFileDialogEventsVT>();
+				(vt.OnFolderChange)(self.ptr(), fd.ptr())
+			},
+		)
+	}
+
+	/// [`IFileDialogEvents::OnFolderChanging`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onfolderchanging)
+	/// method.
+	fn OnFolderChanging(&self,
+		fd: &impl shell_IFileDialog,
+		folder: &impl shell_IShellItem,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IFileDialogEventsVT>();
+				(vt.OnFolderChanging)(self.ptr(), fd.ptr(), folder.ptr())
+			},
+		)
+	}
+
+	/// [`IFileDialogEvents::OnOverwrite`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onoverwrite)
+	/// method.
+	fn OnOverwrite(&self,
+		fd: &impl shell_IFileDialog,
+		si: &impl shell_IShellItem,
+	) -> HrResult<co::FDEOR>
+	{
+		let mut response = co::FDEOR::default();
+		ok_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IFileDialogEventsVT>();
+				(vt.OnOverwrite)(self.ptr(), fd.ptr(), si.ptr(), &mut response.0)
+			},
+		).map(|_| response)
+	}
+
+	/// [`IFileDialogEvents::OnSelectionChange`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onselectionchange)
+	/// method.
+	fn OnSelectionChange(&self, fd: &impl shell_IFileDialog) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IFileDialogEventsVT>();
+				(vt.OnSelectionChange)(self.ptr(), fd.ptr())
+			},
+		)
+	}
+
+	/// [`IFileDialogEvents::OnShareViolation`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onshareviolation)
+	/// method.
+	fn OnShareViolation(&self,
+		fd: &impl shell_IFileDialog,
+		si: &impl shell_IShellItem,
+	) -> HrResult<co::FDESVR>
+	{
+		let mut response = co::FDESVR::default();
+		ok_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IFileDialogEventsVT>();
+				(vt.OnShareViolation)(self.ptr(), fd.ptr(), si.ptr(), &mut response.0)
+			},
+		).map(|_| response)
+	}
+
+	/// [`IFileDialogEvents::OnTypeChange`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-ontypechange)
+	/// method.
+	fn OnTypeChange(&self, fd: &impl shell_IFileDialog) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				let vt = self.vt_ref::<IFileDialogEventsVT>();
+				(vt.OnTypeChange)(self.ptr(), fd.ptr())
+			},
+		)
+	}
+}
@@ -0,0 +1,337 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{BOOL, HANDLE, HRES, PCSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::{
+	Handle, ole_IUnknown, shell_IFileOperationProgressSink, shell_IShellItem,
+};
+use crate::user::decl::HWND;
+use crate::vt::IUnknownVT;
+
+/// [`IFileOperation`](crate::IFileOperation) virtual table.
+#[repr(C)]
+pub struct IFileOperationVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Advise: fn(ComPtr, ComPtr, *mut u32) -> HRES,
+	pub Unadvise: fn(ComPtr, u32) -> HRES,
+	pub SetOperationFlags: fn(ComPtr, u32) -> HRES,
+	pub SetProgressMessage: fn(ComPtr, PCSTR) -> HRES,
+	pub SetProgressDialog: fn(ComPtr, ComPtr) -> HRES,
+	pub SetProperties: fn(ComPtr, ComPtr) -> HRES,
+	pub SetOwnerWindow: fn(ComPtr, HANDLE) -> HRES,
+	pub ApplyPropertiesToItem: fn(ComPtr, ComPtr) -> HRES,
+	pub ApplyPropertiesToItems: fn(ComPtr, ComPtr) -> HRES,
+	pub RenameItem: fn(ComPtr, ComPtr, PCSTR, ComPtr) -> HRES,
+	pub RenameItems: fn(ComPtr, ComPtr, PCSTR) -> HRES,
+	pub MoveItem: fn(ComPtr, ComPtr, ComPtr, PCSTR, ComPtr) -> HRES,
+	pub MoveItems: fn(ComPtr, ComPtr, ComPtr) -> HRES,
+	pub CopyItem: fn(ComPtr, ComPtr, ComPtr, PCSTR, ComPtr) -> HRES,
+	pub CopyItems: fn(ComPtr, ComPtr, ComPtr) -> HRES,
+	pub DeleteItem: fn(ComPtr, ComPtr, ComPtr) -> HRES,
+	pub DeleteItems: fn(ComPtr, ComPtr) -> HRES,
+	pub NewItem: fn(ComPtr, ComPtr, u32, PCSTR, PCSTR, ComPtr) -> HRES,
+	pub PerformOperations: fn(ComPtr) -> HRES,
+	pub GetAnyOperationsAborted: fn(ComPtr, *mut BOOL) -> HRES,
+}
+
+com_interface! { IFileOperation: "947aab5f-0a5c-4c13-b4d6-4bf7836fc9f8";
+	/// [`IFileOperation`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ifileoperation)
+	/// COM interface over [`IFileOperationVT`](crate::vt::IFileOperationVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// Sending a file to the recycle bin:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, IBindCtx, IFileOperation};
+	/// use winsafe::{IFileOperationProgressSink, IShellItem};
+	/// use winsafe::SHCreateItemFromParsingName;
+	///
+	/// let file_op = CoCreateInstance::<IFileOperation>(
+	///     &co::CLSID::FileOperation,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// file_op.SetOperationFlags(co::FOF::ALLOWUNDO, co::FOFX::RECYCLEONDELETE)?;
+	///
+	/// let item = SHCreateItemFromParsingName::<IShellItem>(
+	///     "C:\\Temp\\foo.txt", None::<&IBindCtx>)?;
+	/// file_op.DeleteItem(&item, None::<&IFileOperationProgressSink>)?;
+	///
+	/// file_op.PerformOperations()?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl shell_IFileOperation for IFileOperation {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IFileOperation`](crate::IFileOperation).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IFileOperation: ole_IUnknown {
+	/// [`IFileOperation::Advise`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-advise)
+	/// method.
+	///
+	/// Returns a cookie to be passed to
+	/// [`Unadvise`](crate::prelude::shell_IFileOperation::Unadvise).
+	fn Advise(&self,
+		sink: &impl shell_IFileOperationProgressSink) -> HrResult<u32>
+	{
+		let mut cookie = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult((vt.Advise)(self.ptr(), sink.ptr(), &mut cookie))
+		}.map(|_| cookie)
+	}
+
+	/// [`IFileOperation::CopyItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-copyitem)
+	/// method.
+	///
+	/// The operation is performed by
+	/// [`PerformOperations`](crate::prelude::shell_IFileOperation::PerformOperations).
+	fn CopyItem(&self,
+		item: &impl shell_IShellItem,
+		dest_folder: &impl shell_IShellItem,
+		copy_name: Option<&str>,
+		sink: Option<&impl shell_IFileOperationProgressSink>,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult(
+				(vt.CopyItem)(
+					self.ptr(),
+					item.ptr(),
+					dest_folder.ptr(),
+					WString::from_opt_str(copy_name).as_ptr(),
+					sink.map_or(ComPtr::null(), |s| s.ptr()),
+				),
+			)
+		}
+	}
+
+	/// [`IFileOperation::CopyItems`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-copyitems)
+	/// method.
+	///
+	/// The items are usually an [`IShellItemArray`](crate::IShellItemArray) or
+	/// an [`IDataObject`](crate::IDataObject).
+	fn CopyItems(&self,
+		items: &impl ole_IUnknown,
+		dest_folder: &impl shell_IShellItem,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult(
+				(vt.CopyItems)(self.ptr(), items.ptr(), dest_folder.ptr()),
+			)
+		}
+	}
+
+	/// [`IFileOperation::DeleteItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-deleteitem)
+	/// method.
+	///
+	/// The item is sent to the recycle bin if
+	/// [`FOF::ALLOWUNDO`](crate::co::FOF::ALLOWUNDO) is set.
+	fn DeleteItem(&self,
+		item: &impl shell_IShellItem,
+		sink: Option<&impl shell_IFileOperationProgressSink>,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult(
+				(vt.DeleteItem)(
+					self.ptr(),
+					item.ptr(),
+					sink.map_or(ComPtr::null(), |s| s.ptr()),
+				),
+			)
+		}
+	}
+
+	/// [`IFileOperation::DeleteItems`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-deleteitems)
+	/// method.
+	fn DeleteItems(&self, items: &impl ole_IUnknown) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult((vt.DeleteItems)(self.ptr(), items.ptr()))
+		}
+	}
+
+	/// [`IFileOperation::GetAnyOperationsAborted`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-getanyoperationsaborted)
+	/// method.
+	#[must_use]
+	fn GetAnyOperationsAborted(&self) -> HrResult<bool> {
+		let mut aborted: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult((vt.GetAnyOperationsAborted)(self.ptr(), &mut aborted))
+		}.map(|_| aborted != 0)
+	}
+
+	/// [`IFileOperation::MoveItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-moveitem)
+	/// method.
+	fn MoveItem(&self,
+		item: &impl shell_IShellItem,
+		dest_folder: &impl shell_IShellItem,
+		new_name: Option<&str>,
+		sink: Option<&impl shell_IFileOperationProgressSink>,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult(
+				(vt.MoveItem)(
+					self.ptr(),
+					item.ptr(),
+					dest_folder.ptr(),
+					WString::from_opt_str(new_name).as_ptr(),
+					sink.map_or(ComPtr::null(), |s| s.ptr()),
+				),
+			)
+		}
+	}
+
+	/// [`IFileOperation::MoveItems`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-moveitems)
+	/// method.
+	fn MoveItems(&self,
+		items: &impl ole_IUnknown,
+		dest_folder: &impl shell_IShellItem,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult(
+				(vt.MoveItems)(self.ptr(), items.ptr(), dest_folder.ptr()),
+			)
+		}
+	}
+
+	/// [`IFileOperation::NewItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-newitem)
+	/// method.
+	fn NewItem(&self,
+		dest_folder: &impl shell_IShellItem,
+		file_attributes: co::FILE_ATTRIBUTE,
+		name: &str,
+		template_name: Option<&str>,
+		sink: Option<&impl shell_IFileOperationProgressSink>,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult(
+				(vt.NewItem)(
+					self.ptr(),
+					dest_folder.ptr(),
+					file_attributes.0,
+					WString::from_str(name).as_ptr(),
+					WString::from_opt_str(template_name).as_ptr(),
+					sink.map_or(ComPtr::null(), |s| s.ptr()),
+				),
+			)
+		}
+	}
+
+	/// [`IFileOperation::PerformOperations`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-performoperations)
+	/// method.
+	///
+	/// Note that, if the user cancels the operation, the returned value is
+	/// still successful; check
+	/// [`GetAnyOperationsAborted`](crate::prelude::shell_IFileOperation::GetAnyOperationsAborted).
+	fn PerformOperations(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult((vt.PerformOperations)(self.ptr()))
+		}
+	}
+
+	/// [`IFileOperation::RenameItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-renameitem)
+	/// method.
+	fn RenameItem(&self,
+		item: &impl shell_IShellItem,
+		new_name: &str,
+		sink: Option<&impl shell_IFileOperationProgressSink>,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult(
+				(vt.RenameItem)(
+					self.ptr(),
+					item.ptr(),
+					WString::from_str(new_name).as_ptr(),
+					sink.map_or(ComPtr::null(), |s| s.ptr()),
+				),
+			)
+		}
+	}
+
+	/// [`IFileOperation::RenameItems`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-renameitems)
+	/// method.
+	fn RenameItems(&self,
+		items: &impl ole_IUnknown, new_name: &str) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult(
+				(vt.RenameItems)(
+					self.ptr(), items.ptr(), WString::from_str(new_name).as_ptr()),
+			)
+		}
+	}
+
+	/// [`IFileOperation::SetOperationFlags`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-setoperationflags)
+	/// method.
+	fn SetOperationFlags(&self, fof: co::FOF, fofx: co::FOFX) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult(
+				(vt.SetOperationFlags)(self.ptr(), fof.0 as u32 | fofx.0),
+			)
+		}
+	}
+
+	/// [`IFileOperation::SetOwnerWindow`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-setownerwindow)
+	/// method.
+	fn SetOwnerWindow(&self, hwnd_owner: &HWND) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult((vt.SetOwnerWindow)(self.ptr(), hwnd_owner.as_ptr()))
+		}
+	}
+
+	/// [`IFileOperation::SetProgressMessage`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-setprogressmessage)
+	/// method.
+	fn SetProgressMessage(&self, message: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult(
+				(vt.SetProgressMessage)(
+					self.ptr(), WString::from_str(message).as_ptr()),
+			)
+		}
+	}
+
+	/// [`IFileOperation::Unadvise`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperation-unadvise)
+	/// method.
+	fn Unadvise(&self, cookie: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationVT>();
+			ok_to_hrresult((vt.Unadvise)(self.ptr(), cookie))
+		}
+	}
+}
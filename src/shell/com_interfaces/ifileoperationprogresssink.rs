@@ -0,0 +1,112 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PCSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IFileOperationProgressSink`](crate::IFileOperationProgressSink) virtual
+/// table.
+#[repr(C)]
+pub struct IFileOperationProgressSinkVT {
+	pub IUnknownVT: IUnknownVT,
+	pub StartOperations: fn(ComPtr) -> HRES,
+	pub FinishOperations: fn(ComPtr, HRES) -> HRES,
+	pub PreRenameItem: fn(ComPtr, u32, ComPtr, PCSTR) -> HRES,
+	pub PostRenameItem: fn(ComPtr, u32, ComPtr, PCSTR, HRES, ComPtr) -> HRES,
+	pub PreMoveItem: fn(ComPtr, u32, ComPtr, ComPtr, PCSTR) -> HRES,
+	pub PostMoveItem: fn(ComPtr, u32, ComPtr, ComPtr, PCSTR, HRES, ComPtr) -> HRES,
+	pub PreCopyItem: fn(ComPtr, u32, ComPtr, ComPtr, PCSTR) -> HRES,
+	pub PostCopyItem: fn(ComPtr, u32, ComPtr, ComPtr, PCSTR, HRES, ComPtr) -> HRES,
+	pub PreDeleteItem: fn(ComPtr, u32, ComPtr) -> HRES,
+	pub PostDeleteItem: fn(ComPtr, u32, ComPtr, HRES, ComPtr) -> HRES,
+	pub PreNewItem: fn(ComPtr, u32, ComPtr, PCSTR) -> HRES,
+	pub PostNewItem: fn(ComPtr, u32, ComPtr, PCSTR, PCSTR, u32, HRES, ComPtr) -> HRES,
+	pub UpdateProgress: fn(ComPtr, u32, u32) -> HRES,
+	pub ResetTimer: fn(ComPtr) -> HRES,
+	pub PauseTimer: fn(ComPtr) -> HRES,
+	pub ResumeTimer: fn(ComPtr) -> HRES,
+}
+
+com_interface! { IFileOperationProgressSink: "04b0f1a7-9490-44bc-96e1-4296a31252e2";
+	/// [`IFileOperationProgressSink`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ifileoperationprogresssink)
+	/// COM interface over
+	/// [`IFileOperationProgressSinkVT`](crate::vt::IFileOperationProgressSinkVT).
+	///
+	/// An implementation can be created with
+	/// [`FileOperationProgressSink`](crate::FileOperationProgressSink).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl shell_IFileOperationProgressSink for IFileOperationProgressSink {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IFileOperationProgressSink`](crate::IFileOperationProgressSink).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IFileOperationProgressSink: ole_IUnknown {
+	/// [`IFileOperationProgressSink::FinishOperations`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperationprogresssink-finishoperations)
+	/// method.
+	fn FinishOperations(&self, result: co::HRESULT) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationProgressSinkVT>();
+			ok_to_hrresult((vt.FinishOperations)(self.ptr(), result.0))
+		}
+	}
+
+	/// [`IFileOperationProgressSink::PauseTimer`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperationprogresssink-pausetimer)
+	/// method.
+	fn PauseTimer(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationProgressSinkVT>();
+			ok_to_hrresult((vt.PauseTimer)(self.ptr()))
+		}
+	}
+
+	/// [`IFileOperationProgressSink::ResetTimer`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperationprogresssink-resettimer)
+	/// method.
+	fn ResetTimer(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationProgressSinkVT>();
+			ok_to_hrresult((vt.ResetTimer)(self.ptr()))
+		}
+	}
+
+	/// [`IFileOperationProgressSink::ResumeTimer`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperationprogresssink-resumetimer)
+	/// method.
+	fn ResumeTimer(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationProgressSinkVT>();
+			ok_to_hrresult((vt.ResumeTimer)(self.ptr()))
+		}
+	}
+
+	/// [`IFileOperationProgressSink::StartOperations`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperationprogresssink-startoperations)
+	/// method.
+	fn StartOperations(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationProgressSinkVT>();
+			ok_to_hrresult((vt.StartOperations)(self.ptr()))
+		}
+	}
+
+	/// [`IFileOperationProgressSink::UpdateProgress`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperationprogresssink-updateprogress)
+	/// method.
+	fn UpdateProgress(&self, work_total: u32, work_so_far: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IFileOperationProgressSinkVT>();
+			ok_to_hrresult(
+				(vt.UpdateProgress)(self.ptr(), work_total, work_so_far),
+			)
+		}
+	}
+}
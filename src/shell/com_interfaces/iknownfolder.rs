@@ -0,0 +1,163 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{HRES, PCSTR, PCVOID, PVOID};
+use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IKnownFolder`](crate::IKnownFolder) virtual table.
+#[repr(C)]
+pub struct IKnownFolderVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetId: fn(ComPtr, PVOID) -> HRES,
+	pub GetCategory: fn(ComPtr, *mut u32) -> HRES,
+	pub GetShellItem: fn(ComPtr, u32, PCVOID, *mut ComPtr) -> HRES,
+	pub GetPath: fn(ComPtr, u32, *mut *mut u16) -> HRES,
+	pub SetPath: fn(ComPtr, u32, PCSTR) -> HRES,
+	pub GetIDList: fn(ComPtr, u32, *mut PVOID) -> HRES,
+	pub GetFolderType: fn(ComPtr, PVOID) -> HRES,
+	pub GetRedirectionCapabilities: fn(ComPtr, *mut u32) -> HRES,
+	pub GetFolderDefinition: fn(ComPtr, PVOID) -> HRES,
+}
+
+com_interface! { IKnownFolder: "3aa7af7e-9b36-420c-a8e3-f77d4674a488";
+	/// [`IKnownFolder`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-iknownfolder)
+	/// COM interface over [`IKnownFolderVT`](crate::vt::IKnownFolderVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, IKnownFolderManager};
+	///
+	/// let mgr = CoCreateInstance::<IKnownFolderManager>(
+	///     &co::CLSID::KnownFolderManager,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// let folder = mgr.GetFolder(&co::KNOWNFOLDERID::Downloads)?;
+	/// println!("{}", folder.GetPath(co::KF::DEFAULT)?);
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl shell_IKnownFolder for IKnownFolder {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IKnownFolder`](crate::IKnownFolder).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IKnownFolder: ole_IUnknown {
+	/// [`IKnownFolder::GetCategory`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfolder-getcategory)
+	/// method.
+	#[must_use]
+	fn GetCategory(&self) -> HrResult<co::KF_CATEGORY> {
+		let mut category = co::KF_CATEGORY::default();
+		unsafe {
+			let vt = self.vt_ref::<IKnownFolderVT>();
+			ok_to_hrresult((vt.GetCategory)(self.ptr(), &mut category.0))
+				.map(|_| category)
+		}
+	}
+
+	/// [`IKnownFolder::GetId`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfolder-getid)
+	/// method.
+	#[must_use]
+	fn GetId(&self) -> HrResult<co::KNOWNFOLDERID> {
+		let mut id = co::KNOWNFOLDERID::default();
+		unsafe {
+			let vt = self.vt_ref::<IKnownFolderVT>();
+			ok_to_hrresult((vt.GetId)(self.ptr(), &mut id as *mut _ as _))
+				.map(|_| id)
+		}
+	}
+
+	/// [`IKnownFolder::GetPath`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfolder-getpath)
+	/// method.
+	#[must_use]
+	fn GetPath(&self, flags: co::KF) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IKnownFolderVT>();
+			ok_to_hrresult((vt.GetPath)(self.ptr(), flags.0, &mut pstr))
+		}.map(|_| {
+			let path = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			path.to_string()
+		})
+	}
+
+	/// [`IKnownFolder::GetRedirectionCapabilities`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfolder-getredirectioncapabilities)
+	/// method.
+	#[must_use]
+	fn GetRedirectionCapabilities(&self,
+	) -> HrResult<co::KF_REDIRECTION_CAPABILITIES>
+	{
+		let mut caps = co::KF_REDIRECTION_CAPABILITIES::default();
+		unsafe {
+			let vt = self.vt_ref::<IKnownFolderVT>();
+			ok_to_hrresult(
+				(vt.GetRedirectionCapabilities)(self.ptr(), &mut caps.0),
+			).map(|_| caps)
+		}
+	}
+
+	/// [`IKnownFolder::GetShellItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfolder-getshellitem)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IKnownFolder, IShellItem};
+	///
+	/// let folder: IKnownFolder; // initialized somewhere
+	/// # let folder = IKnownFolder::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// let item = folder.GetShellItem::<IShellItem>(co::KF::DEFAULT)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn GetShellItem<T>(&self, flags: co::KF) -> HrResult<T>
+		where T: ole_IUnknown,
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IKnownFolderVT>();
+			ok_to_hrresult(
+				(vt.GetShellItem)(
+					self.ptr(),
+					flags.0,
+					&T::IID as *const _ as _,
+					&mut ppv_queried,
+				),
+			).map(|_| T::from(ppv_queried))
+		}
+	}
+
+	/// [`IKnownFolder::SetPath`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfolder-setpath)
+	/// method.
+	fn SetPath(&self, flags: co::KF, path: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IKnownFolderVT>();
+			ok_to_hrresult(
+				(vt.SetPath)(
+					self.ptr(),
+					flags.0,
+					WString::from_str(path).as_ptr(),
+				),
+			)
+		}
+	}
+}
@@ -0,0 +1,229 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{HANDLE, HRES, PCSTR, PCVOID, PVOID};
+use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::{Handle, ole_IUnknown};
+use crate::shell::decl::IKnownFolder;
+use crate::user::decl::HWND;
+use crate::vt::IUnknownVT;
+
+/// [`IKnownFolderManager`](crate::IKnownFolderManager) virtual table.
+#[repr(C)]
+pub struct IKnownFolderManagerVT {
+	pub IUnknownVT: IUnknownVT,
+	pub FolderIdFromCsidl: fn(ComPtr, i32, PVOID) -> HRES,
+	pub FolderIdToCsidl: fn(ComPtr, PCVOID, *mut i32) -> HRES,
+	pub GetFolderIds: fn(ComPtr, *mut PVOID, *mut u32) -> HRES,
+	pub GetFolder: fn(ComPtr, PCVOID, *mut ComPtr) -> HRES,
+	pub GetFolderByName: fn(ComPtr, PCSTR, *mut ComPtr) -> HRES,
+	pub RegisterFolder: fn(ComPtr, PCVOID, PCVOID) -> HRES,
+	pub UnregisterFolder: fn(ComPtr, PCVOID) -> HRES,
+	pub FindFolderFromPath: fn(ComPtr, PCSTR, u32, *mut ComPtr) -> HRES,
+	pub FindFolderFromIDList: fn(ComPtr, PCVOID, *mut ComPtr) -> HRES,
+	pub Redirect: fn(ComPtr, PCVOID, HANDLE, u32, PCSTR, u32, PCVOID, *mut *mut u16) -> HRES,
+}
+
+com_interface! { IKnownFolderManager: "8be2d872-86aa-4d47-b776-32cca40c7018";
+	/// [`IKnownFolderManager`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-iknownfoldermanager)
+	/// COM interface over
+	/// [`IKnownFolderManagerVT`](crate::vt::IKnownFolderManagerVT).
+	///
+	/// Automatically calls
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, IKnownFolderManager};
+	///
+	/// let mgr = CoCreateInstance::<IKnownFolderManager>(
+	///     &co::CLSID::KnownFolderManager,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl shell_IKnownFolderManager for IKnownFolderManager {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IKnownFolderManager`](crate::IKnownFolderManager).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IKnownFolderManager: ole_IUnknown {
+	/// [`IKnownFolderManager::FindFolderFromPath`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfoldermanager-findfolderfrompath)
+	/// method.
+	#[must_use]
+	fn FindFolderFromPath(&self,
+		path: &str, mode: co::FFFP_MODE) -> HrResult<IKnownFolder>
+	{
+		unsafe {
+			let mut queried = ComPtr::null();
+			let vt = self.vt_ref::<IKnownFolderManagerVT>();
+			ok_to_hrresult(
+				(vt.FindFolderFromPath)(
+					self.ptr(),
+					WString::from_str(path).as_ptr(),
+					mode.0,
+					&mut queried,
+				),
+			).map(|_| IKnownFolder::from(queried))
+		}
+	}
+
+	/// [`IKnownFolderManager::FolderIdFromCsidl`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfoldermanager-folderidfromcsidl)
+	/// method.
+	#[must_use]
+	fn FolderIdFromCsidl(&self, csidl: i32) -> HrResult<co::KNOWNFOLDERID> {
+		let mut id = co::KNOWNFOLDERID::default();
+		unsafe {
+			let vt = self.vt_ref::<IKnownFolderManagerVT>();
+			ok_to_hrresult(
+				(vt.FolderIdFromCsidl)(self.ptr(), csidl, &mut id as *mut _ as _),
+			).map(|_| id)
+		}
+	}
+
+	/// [`IKnownFolderManager::FolderIdToCsidl`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfoldermanager-folderidtocsidl)
+	/// method.
+	#[must_use]
+	fn FolderIdToCsidl(&self, folder_id: &co::KNOWNFOLDERID) -> HrResult<i32> {
+		let mut csidl = i32::default();
+		unsafe {
+			let vt = self.vt_ref::<IKnownFolderManagerVT>();
+			ok_to_hrresult(
+				(vt.FolderIdToCsidl)(
+					self.ptr(),
+					folder_id as *const _ as _,
+					&mut csidl,
+				),
+			).map(|_| csidl)
+		}
+	}
+
+	/// [`IKnownFolderManager::GetFolder`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfoldermanager-getfolder)
+	/// method.
+	#[must_use]
+	fn GetFolder(&self,
+		folder_id: &co::KNOWNFOLDERID) -> HrResult<IKnownFolder>
+	{
+		unsafe {
+			let mut queried = ComPtr::null();
+			let vt = self.vt_ref::<IKnownFolderManagerVT>();
+			ok_to_hrresult(
+				(vt.GetFolder)(
+					self.ptr(),
+					folder_id as *const _ as _,
+					&mut queried,
+				),
+			).map(|_| IKnownFolder::from(queried))
+		}
+	}
+
+	/// [`IKnownFolderManager::GetFolderByName`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfoldermanager-getfolderbyname)
+	/// method.
+	#[must_use]
+	fn GetFolderByName(&self, canonical_name: &str) -> HrResult<IKnownFolder> {
+		unsafe {
+			let mut queried = ComPtr::null();
+			let vt = self.vt_ref::<IKnownFolderManagerVT>();
+			ok_to_hrresult(
+				(vt.GetFolderByName)(
+					self.ptr(),
+					WString::from_str(canonical_name).as_ptr(),
+					&mut queried,
+				),
+			).map(|_| IKnownFolder::from(queried))
+		}
+	}
+
+	/// [`IKnownFolderManager::GetFolderIds`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfoldermanager-getfolderids)
+	/// method.
+	///
+	/// Returns the IDs of all known folders registered in the system.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IKnownFolderManager};
+	///
+	/// let mgr: IKnownFolderManager; // initialized somewhere
+	/// # let mgr = IKnownFolderManager::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// for folder_id in mgr.GetFolderIds()?.iter() {
+	///     let folder = mgr.GetFolder(folder_id)?;
+	///     println!("{:?}", folder.GetCategory()?);
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn GetFolderIds(&self) -> HrResult<Vec<co::KNOWNFOLDERID>> {
+		let mut pids: PVOID = std::ptr::null_mut();
+		let mut count = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IKnownFolderManagerVT>();
+			ok_to_hrresult((vt.GetFolderIds)(self.ptr(), &mut pids, &mut count))
+		}.map(|_| {
+			let ids = unsafe {
+				std::slice::from_raw_parts(
+					pids as *const co::KNOWNFOLDERID,
+					count as _,
+				)
+			}.to_vec();
+			CoTaskMemFree(pids as _);
+			ids
+		})
+	}
+
+	/// [`IKnownFolderManager::Redirect`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfoldermanager-redirect)
+	/// method.
+	///
+	/// Pass [`co::KF_REDIRECT::CHECK_ONLY`](crate::co::KF_REDIRECT::CHECK_ONLY)
+	/// to only check whether the folder can be redirected.
+	fn Redirect(&self,
+		folder_id: &co::KNOWNFOLDERID,
+		hwnd: Option<&HWND>,
+		flags: co::KF_REDIRECT,
+		target_path: Option<&str>,
+		exclusion: &[co::KNOWNFOLDERID],
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IKnownFolderManagerVT>();
+			ok_to_hrresult(
+				(vt.Redirect)(
+					self.ptr(),
+					folder_id as *const _ as _,
+					hwnd.map_or(std::ptr::null_mut(), |h| h.as_ptr()),
+					flags.0,
+					WString::from_opt_str(target_path).as_ptr(),
+					exclusion.len() as _,
+					exclusion.as_ptr() as _,
+					std::ptr::null_mut(),
+				),
+			)
+		}
+	}
+
+	/// [`IKnownFolderManager::UnregisterFolder`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-iknownfoldermanager-unregisterfolder)
+	/// method.
+	fn UnregisterFolder(&self, folder_id: &co::KNOWNFOLDERID) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IKnownFolderManagerVT>();
+			ok_to_hrresult(
+				(vt.UnregisterFolder)(self.ptr(), folder_id as *const _ as _),
+			)
+		}
+	}
+}
@@ -0,0 +1,67 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::{HRES, PCVOID};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IObjectArray`](crate::IObjectArray) virtual table.
+#[repr(C)]
+pub struct IObjectArrayVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetCount: fn(ComPtr, *mut u32) -> HRES,
+	pub GetAt: fn(ComPtr, u32, PCVOID, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IObjectArray: "92ca9dcd-5622-4bba-a805-5e9f541bd8c9";
+	/// [`IObjectArray`](https://learn.microsoft.com/en-us/windows/win32/api/objectarray/nn-objectarray-iobjectarray)
+	/// COM interface over [`IObjectArrayVT`](crate::vt::IObjectArrayVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl shell_IObjectArray for IObjectArray {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IObjectArray`](crate::IObjectArray).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IObjectArray: ole_IUnknown {
+	/// [`IObjectArray::GetAt`](https://learn.microsoft.com/en-us/windows/win32/api/objectarray/nf-objectarray-iobjectarray-getat)
+	/// method.
+	#[must_use]
+	fn GetAt<T>(&self, index: u32) -> HrResult<T>
+		where T: ole_IUnknown,
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IObjectArrayVT>();
+			ok_to_hrresult(
+				(vt.GetAt)(
+					self.ptr(),
+					index,
+					&T::IID as *const _ as _,
+					&mut ppv_queried,
+				),
+			).map(|_| T::from(ppv_queried))
+		}
+	}
+
+	/// [`IObjectArray::GetCount`](https://learn.microsoft.com/en-us/windows/win32/api/objectarray/nf-objectarray-iobjectarray-getcount)
+	/// method.
+	#[must_use]
+	fn GetCount(&self) -> HrResult<u32> {
+		let mut count = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IObjectArrayVT>();
+			ok_to_hrresult((vt.GetCount)(self.ptr(), &mut count))
+		}.map(|_| count)
+	}
+}
@@ -0,0 +1,89 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::{ole_IUnknown, shell_IObjectArray};
+use crate::vt::IObjectArrayVT;
+
+/// [`IObjectCollection`](crate::IObjectCollection) virtual table.
+#[repr(C)]
+pub struct IObjectCollectionVT {
+	pub IObjectArrayVT: IObjectArrayVT,
+	pub AddObject: fn(ComPtr, ComPtr) -> HRES,
+	pub AddFromArray: fn(ComPtr, ComPtr) -> HRES,
+	pub RemoveObjectAt: fn(ComPtr, u32) -> HRES,
+	pub Clear: fn(ComPtr) -> HRES,
+}
+
+com_interface! { IObjectCollection: "5632b1a4-e38a-400a-928a-d4cd63230295";
+	/// [`IObjectCollection`](https://learn.microsoft.com/en-us/windows/win32/api/objectarray/nn-objectarray-iobjectcollection)
+	/// COM interface over [`IObjectCollectionVT`](crate::vt::IObjectCollectionVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, IObjectCollection};
+	///
+	/// let obj = CoCreateInstance::<IObjectCollection>(
+	///     &co::CLSID::EnumerableObjectCollection,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl shell_IObjectArray for IObjectCollection {}
+impl shell_IObjectCollection for IObjectCollection {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IObjectCollection`](crate::IObjectCollection).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IObjectCollection: shell_IObjectArray {
+	/// [`IObjectCollection::AddFromArray`](https://learn.microsoft.com/en-us/windows/win32/api/objectarray/nf-objectarray-iobjectcollection-addfromarray)
+	/// method.
+	fn AddFromArray(&self, source: &impl shell_IObjectArray) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IObjectCollectionVT>();
+			ok_to_hrresult((vt.AddFromArray)(self.ptr(), source.ptr()))
+		}
+	}
+
+	/// [`IObjectCollection::AddObject`](https://learn.microsoft.com/en-us/windows/win32/api/objectarray/nf-objectarray-iobjectcollection-addobject)
+	/// method.
+	fn AddObject(&self, obj: &impl ole_IUnknown) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IObjectCollectionVT>();
+			ok_to_hrresult((vt.AddObject)(self.ptr(), obj.ptr()))
+		}
+	}
+
+	/// [`IObjectCollection::Clear`](https://learn.microsoft.com/en-us/windows/win32/api/objectarray/nf-objectarray-iobjectcollection-clear)
+	/// method.
+	fn Clear(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IObjectCollectionVT>();
+			ok_to_hrresult((vt.Clear)(self.ptr()))
+		}
+	}
+
+	/// [`IObjectCollection::RemoveObjectAt`](https://learn.microsoft.com/en-us/windows/win32/api/objectarray/nf-objectarray-iobjectcollection-removeobjectat)
+	/// method.
+	fn RemoveObjectAt(&self, index: u32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IObjectCollectionVT>();
+			ok_to_hrresult((vt.RemoveObjectAt)(self.ptr(), index))
+		}
+	}
+}
@@ -0,0 +1,363 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::{co, shell};
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{HANDLE, HRES, PCSTR, PCVOID, PVOID};
+use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::prelude::{Handle, ole_IBindCtx, ole_IUnknown};
+use crate::shell::decl::{IEnumIDList, ITEMIDLIST};
+use crate::shell::guard::CoTaskMemFreePidlGuard;
+use crate::shell::privs::STRRET;
+use crate::user::decl::HWND;
+use crate::vt::IUnknownVT;
+
+/// [`IShellFolder`](crate::IShellFolder) virtual table.
+#[repr(C)]
+pub struct IShellFolderVT {
+	pub IUnknownVT: IUnknownVT,
+	pub ParseDisplayName: fn(ComPtr, HANDLE, PVOID, PCSTR, *mut u32, *mut PVOID, *mut u32) -> HRES,
+	pub EnumObjects: fn(ComPtr, HANDLE, u32, *mut ComPtr) -> HRES,
+	pub BindToObject: fn(ComPtr, PCVOID, PVOID, PCVOID, *mut ComPtr) -> HRES,
+	pub BindToStorage: fn(ComPtr, PCVOID, PVOID, PCVOID, *mut ComPtr) -> HRES,
+	pub CompareIDs: fn(ComPtr, isize, PCVOID, PCVOID) -> HRES,
+	pub CreateViewObject: fn(ComPtr, HANDLE, PCVOID, *mut ComPtr) -> HRES,
+	pub GetAttributesOf: fn(ComPtr, u32, *const PCVOID, *mut u32) -> HRES,
+	pub GetUIObjectOf: fn(ComPtr, HANDLE, u32, *const PCVOID, PCVOID, *mut u32, *mut ComPtr) -> HRES,
+	pub GetDisplayNameOf: fn(ComPtr, PCVOID, u32, PVOID) -> HRES,
+	pub SetNameOf: fn(ComPtr, HANDLE, PCVOID, PCSTR, u32, *mut PVOID) -> HRES,
+}
+
+com_interface! { IShellFolder: "000214e6-0000-0000-c000-000000000046";
+	/// [`IShellFolder`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ishellfolder)
+	/// COM interface over [`IShellFolderVT`](crate::vt::IShellFolderVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// Retrieving the desktop folder, the root of the shell namespace:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::SHGetDesktopFolder;
+	///
+	/// let desktop = SHGetDesktopFolder()?;
+	/// # Ok::<_, winsafe::co::HRESULT>(())
+	/// ```
+}
+
+impl shell_IShellFolder for IShellFolder {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IShellFolder`](crate::IShellFolder).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IShellFolder: ole_IUnknown {
+	/// [`IShellFolder::BindToObject`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellfolder-bindtoobject)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Opening a subfolder:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IBindCtx, IShellFolder, SHGetDesktopFolder};
+	///
+	/// let desktop = SHGetDesktopFolder()?;
+	/// let (pidl, _) = desktop.ParseDisplayName(
+	///     None, None::<&IBindCtx>, "C:\\Temp", co::SFGAO::default())?;
+	///
+	/// let temp = desktop.BindToObject::<IShellFolder>(&pidl, None::<&IBindCtx>)?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn BindToObject<T>(&self,
+		pidl: &ITEMIDLIST,
+		bind_ctx: Option<&impl ole_IBindCtx>,
+	) -> HrResult<T>
+		where T: ole_IUnknown,
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IShellFolderVT>();
+			ok_to_hrresult(
+				(vt.BindToObject)(
+					self.ptr(),
+					pidl as *const _ as _,
+					bind_ctx.map_or(std::ptr::null_mut(), |i| i.ptr().0 as _),
+					&T::IID as *const _ as _,
+					&mut ppv_queried,
+				),
+			).map(|_| T::from(ppv_queried))
+		}
+	}
+
+	/// [`IShellFolder::BindToStorage`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellfolder-bindtostorage)
+	/// method.
+	#[must_use]
+	fn BindToStorage<T>(&self,
+		pidl: &ITEMIDLIST,
+		bind_ctx: Option<&impl ole_IBindCtx>,
+	) -> HrResult<T>
+		where T: ole_IUnknown,
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IShellFolderVT>();
+			ok_to_hrresult(
+				(vt.BindToStorage)(
+					self.ptr(),
+					pidl as *const _ as _,
+					bind_ctx.map_or(std::ptr::null_mut(), |i| i.ptr().0 as _),
+					&T::IID as *const _ as _,
+					&mut ppv_queried,
+				),
+			).map(|_| T::from(ppv_queried))
+		}
+	}
+
+	/// [`IShellFolder::CompareIDs`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellfolder-compareids)
+	/// method.
+	///
+	/// The lower 16 bits of `lparam` hold the column used for sorting, usually
+	/// zero, which means the name.
+	#[must_use]
+	fn CompareIDs(&self,
+		lparam: isize,
+		pidl1: &ITEMIDLIST,
+		pidl2: &ITEMIDLIST,
+	) -> HrResult<std::cmp::Ordering>
+	{
+		let hr = unsafe {
+			let vt = self.vt_ref::<IShellFolderVT>();
+			(vt.CompareIDs)(
+				self.ptr(),
+				lparam,
+				pidl1 as *const _ as _,
+				pidl2 as *const _ as _,
+			)
+		};
+		if hr & 0x8000_0000 != 0 { // FAILED
+			Err(co::HRESULT(hr))
+		} else {
+			Ok((hr as u16 as i16).cmp(&0)) // HRESULT_CODE
+		}
+	}
+
+	/// [`IShellFolder::CreateViewObject`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellfolder-createviewobject)
+	/// method.
+	#[must_use]
+	fn CreateViewObject<T>(&self, hwnd_owner: Option<&HWND>) -> HrResult<T>
+		where T: ole_IUnknown,
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IShellFolderVT>();
+			ok_to_hrresult(
+				(vt.CreateViewObject)(
+					self.ptr(),
+					hwnd_owner.map_or(std::ptr::null_mut(), |h| h.as_ptr()),
+					&T::IID as *const _ as _,
+					&mut ppv_queried,
+				),
+			).map(|_| T::from(ppv_queried))
+		}
+	}
+
+	/// [`IShellFolder::EnumObjects`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellfolder-enumobjects)
+	/// method.
+	///
+	/// Returns `None` if the folder has no children to be enumerated.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, SHGetDesktopFolder};
+	///
+	/// let desktop = SHGetDesktopFolder()?;
+	///
+	/// if let Some(items) = desktop.EnumObjects(None, co::SHCONTF::FOLDERS)? {
+	///     for pidl in items.iter() {
+	///         let pidl = pidl?;
+	///         println!("{}",
+	///             desktop.GetDisplayNameOf(&pidl, co::SHGDN::FORPARSING)?);
+	///     }
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn EnumObjects(&self,
+		hwnd: Option<&HWND>,
+		flags: co::SHCONTF,
+	) -> HrResult<Option<IEnumIDList>>
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IShellFolderVT>();
+			okfalse_to_hrresult(
+				(vt.EnumObjects)(
+					self.ptr(),
+					hwnd.map_or(std::ptr::null_mut(), |h| h.as_ptr()),
+					flags.0,
+					&mut ppv_queried,
+				),
+			).map(|has_items| if has_items && !ppv_queried.0.is_null() {
+				Some(IEnumIDList::from(ppv_queried))
+			} else {
+				None
+			})
+		}
+	}
+
+	/// [`IShellFolder::GetAttributesOf`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellfolder-getattributesof)
+	/// method.
+	///
+	/// Returns the attributes of `sfgao_mask` which are common to all the given
+	/// items.
+	#[must_use]
+	fn GetAttributesOf(&self,
+		pidls: &[&ITEMIDLIST],
+		sfgao_mask: co::SFGAO,
+	) -> HrResult<co::SFGAO>
+	{
+		let mut attrs = sfgao_mask.0;
+		unsafe {
+			let vt = self.vt_ref::<IShellFolderVT>();
+			ok_to_hrresult(
+				(vt.GetAttributesOf)(
+					self.ptr(),
+					pidls.len() as _,
+					pidls.as_ptr() as _,
+					&mut attrs,
+				),
+			).map(|_| co::SFGAO(attrs))
+		}
+	}
+
+	/// [`IShellFolder::GetDisplayNameOf`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellfolder-getdisplaynameof)
+	/// method.
+	#[must_use]
+	fn GetDisplayNameOf(&self,
+		pidl: &ITEMIDLIST,
+		flags: co::SHGDN,
+	) -> HrResult<String>
+	{
+		let mut strret = STRRET::default();
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IShellFolderVT>();
+			ok_to_hrresult(
+				(vt.GetDisplayNameOf)(
+					self.ptr(),
+					pidl as *const _ as _,
+					flags.0,
+					&mut strret as *mut _ as _,
+				),
+			)?;
+			ok_to_hrresult(
+				shell::ffi::StrRetToStrW( // also frees the STRRET string, if any
+					&mut strret as *mut _ as _,
+					pidl as *const _ as _,
+					&mut pstr,
+				),
+			).map(|_| {
+				let name = WString::from_wchars_nullt(pstr);
+				CoTaskMemFree(pstr as _);
+				name.to_string()
+			})
+		}
+	}
+
+	/// [`IShellFolder::GetUIObjectOf`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellfolder-getuiobjectof)
+	/// method.
+	#[must_use]
+	fn GetUIObjectOf<T>(&self,
+		hwnd_owner: Option<&HWND>,
+		pidls: &[&ITEMIDLIST],
+	) -> HrResult<T>
+		where T: ole_IUnknown,
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IShellFolderVT>();
+			ok_to_hrresult(
+				(vt.GetUIObjectOf)(
+					self.ptr(),
+					hwnd_owner.map_or(std::ptr::null_mut(), |h| h.as_ptr()),
+					pidls.len() as _,
+					pidls.as_ptr() as _,
+					&T::IID as *const _ as _,
+					std::ptr::null_mut(),
+					&mut ppv_queried,
+				),
+			).map(|_| T::from(ppv_queried))
+		}
+	}
+
+	/// [`IShellFolder::ParseDisplayName`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellfolder-parsedisplayname)
+	/// method.
+	///
+	/// Returns the item relative to this folder, and the attributes of
+	/// `sfgao_mask` which the item has.
+	#[must_use]
+	fn ParseDisplayName(&self,
+		hwnd: Option<&HWND>,
+		bind_ctx: Option<&impl ole_IBindCtx>,
+		display_name: &str,
+		sfgao_mask: co::SFGAO,
+	) -> HrResult<(CoTaskMemFreePidlGuard, co::SFGAO)>
+	{
+		let mut pidl = std::ptr::null_mut();
+		let mut attrs = sfgao_mask.0;
+		unsafe {
+			let vt = self.vt_ref::<IShellFolderVT>();
+			ok_to_hrresult(
+				(vt.ParseDisplayName)(
+					self.ptr(),
+					hwnd.map_or(std::ptr::null_mut(), |h| h.as_ptr()),
+					bind_ctx.map_or(std::ptr::null_mut(), |i| i.ptr().0 as _),
+					WString::from_str(display_name).as_ptr(),
+					std::ptr::null_mut(),
+					&mut pidl,
+					&mut attrs,
+				),
+			).map(|_| (CoTaskMemFreePidlGuard::new(pidl as _), co::SFGAO(attrs)))
+		}
+	}
+
+	/// [`IShellFolder::SetNameOf`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellfolder-setnameof)
+	/// method.
+	///
+	/// Returns the new item, since renaming changes it.
+	fn SetNameOf(&self,
+		hwnd: Option<&HWND>,
+		pidl: &ITEMIDLIST,
+		new_name: &str,
+		flags: co::SHGDN,
+	) -> HrResult<CoTaskMemFreePidlGuard>
+	{
+		let mut pidl_out = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IShellFolderVT>();
+			ok_to_hrresult(
+				(vt.SetNameOf)(
+					self.ptr(),
+					hwnd.map_or(std::ptr::null_mut(), |h| h.as_ptr()),
+					pidl as *const _ as _,
+					WString::from_str(new_name).as_ptr(),
+					flags.0,
+					&mut pidl_out,
+				),
+			).map(|_| CoTaskMemFreePidlGuard::new(pidl_out as _))
+		}
+	}
+}
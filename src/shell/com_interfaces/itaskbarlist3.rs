@@ -6,6 +6,7 @@ use crate::kernel::ffi_types::{HANDLE, HRES, PCSTR, PVOID};
 use crate::ole::decl::{ComPtr, HrResult};
 use crate::ole::privs::ok_to_hrresult;
 use crate::prelude::{Handle, shell_ITaskbarList, shell_ITaskbarList2};
+use crate::shell::decl::THUMBBUTTON;
 use crate::user::decl::{HICON, HWND, RECT};
 use crate::vt::ITaskbarList2VT;
 
@@ -199,4 +200,71 @@ pub trait shell_ITaskbarList3: shell_ITaskbarList2 {
 			)
 		}
 	}
+
+	/// [`ITaskbarList3::ThumbBarAddButtons`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-itaskbarlist3-thumbbaraddbuttons)
+	/// method.
+	///
+	/// Up to 7 buttons can be added, and only once per window; afterwards they
+	/// can only be changed with
+	/// [`ThumbBarUpdateButtons`](crate::prelude::shell_ITaskbarList3::ThumbBarUpdateButtons).
+	/// When clicked, each button sends a
+	/// [`wm::Command`](crate::msg::wm::Command) with the
+	/// [`co::THBN::CLICKED`](crate::co::THBN::CLICKED) code and its `iId`.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HWND, ITaskbarList3, THUMBBUTTON};
+	///
+	/// let taskbar: ITaskbarList3; // initialized somewhere
+	/// # let taskbar = ITaskbarList3::from(unsafe { winsafe::ComPtr::null() });
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	///
+	/// let mut btn = THUMBBUTTON::default();
+	/// btn.dwMask = co::THB::TOOLTIP | co::THB::FLAGS;
+	/// btn.iId = 1001;
+	/// btn.set_szTip("Play");
+	/// btn.dwFlags = co::THBF::ENABLED;
+	///
+	/// taskbar.ThumbBarAddButtons(&hwnd, &[btn])?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	fn ThumbBarAddButtons(&self,
+		hwnd: &HWND, buttons: &[THUMBBUTTON]) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<ITaskbarList3VT>();
+			ok_to_hrresult(
+				(vt.ThumbBarAddButtons)(
+					self.ptr(),
+					hwnd.as_ptr(),
+					buttons.len() as _,
+					buttons.as_ptr() as _,
+				),
+			)
+		}
+	}
+
+	/// [`ITaskbarList3::ThumbBarUpdateButtons`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-itaskbarlist3-thumbbarupdatebuttons)
+	/// method.
+	///
+	/// Buttons are matched by their `iId`, and only the members set in
+	/// `dwMask` are updated.
+	fn ThumbBarUpdateButtons(&self,
+		hwnd: &HWND, buttons: &[THUMBBUTTON]) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<ITaskbarList3VT>();
+			ok_to_hrresult(
+				(vt.ThumbBarUpdateButtons)(
+					self.ptr(),
+					hwnd.as_ptr(),
+					buttons.len() as _,
+					buttons.as_ptr() as _,
+				),
+			)
+		}
+	}
 }
@@ -0,0 +1,125 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::decl::GUID;
+use crate::kernel::ffi_types::{BOOL, HANDLE, HRES, PCVOID, PVOID};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::{Handle, ole_IUnknown};
+use crate::user::decl::HWND;
+use crate::vt::IUnknownVT;
+
+/// [`IVirtualDesktopManager`](crate::IVirtualDesktopManager) virtual table.
+#[repr(C)]
+pub struct IVirtualDesktopManagerVT {
+	pub IUnknownVT: IUnknownVT,
+	pub IsWindowOnCurrentVirtualDesktop: fn(ComPtr, HANDLE, *mut BOOL) -> HRES,
+	pub GetWindowDesktopId: fn(ComPtr, HANDLE, PVOID) -> HRES,
+	pub MoveWindowToDesktop: fn(ComPtr, HANDLE, PCVOID) -> HRES,
+}
+
+com_interface! { IVirtualDesktopManager: "a5cd92ff-29be-454c-8d04-d82879fb3f1b";
+	/// [`IVirtualDesktopManager`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ivirtualdesktopmanager)
+	/// COM interface over
+	/// [`IVirtualDesktopManagerVT`](crate::vt::IVirtualDesktopManagerVT).
+	///
+	/// Available on Windows 10 and later.
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, IVirtualDesktopManager};
+	///
+	/// let obj = CoCreateInstance::<IVirtualDesktopManager>(
+	///     &co::CLSID::VirtualDesktopManager,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl shell_IVirtualDesktopManager for IVirtualDesktopManager {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IVirtualDesktopManager`](crate::IVirtualDesktopManager).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IVirtualDesktopManager: ole_IUnknown {
+	/// [`IVirtualDesktopManager::GetWindowDesktopId`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ivirtualdesktopmanager-getwindowdesktopid)
+	/// method.
+	#[must_use]
+	fn GetWindowDesktopId(&self, hwnd: &HWND) -> HrResult<GUID> {
+		let mut id = GUID::new("00000000-0000-0000-0000-000000000000"); // just a placeholder
+		unsafe {
+			let vt = self.vt_ref::<IVirtualDesktopManagerVT>();
+			ok_to_hrresult(
+				(vt.GetWindowDesktopId)(
+					self.ptr(),
+					hwnd.as_ptr(),
+					&mut id as *mut _ as _,
+				),
+			)
+		}.map(|_| id)
+	}
+
+	/// [`IVirtualDesktopManager::IsWindowOnCurrentVirtualDesktop`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ivirtualdesktopmanager-iswindowoncurrentvirtualdesktop)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{HWND, IVirtualDesktopManager};
+	///
+	/// let vdm: IVirtualDesktopManager; // initialized somewhere
+	/// # let vdm = IVirtualDesktopManager::from(unsafe { winsafe::ComPtr::null() });
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	///
+	/// if !vdm.IsWindowOnCurrentVirtualDesktop(&hwnd)? {
+	///     let id = vdm.GetWindowDesktopId(&hwnd)?;
+	///     println!("Window is on desktop {}", id);
+	/// }
+	/// # Ok::<_, winsafe::co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn IsWindowOnCurrentVirtualDesktop(&self, hwnd: &HWND) -> HrResult<bool> {
+		let mut on_current: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IVirtualDesktopManagerVT>();
+			ok_to_hrresult(
+				(vt.IsWindowOnCurrentVirtualDesktop)(
+					self.ptr(),
+					hwnd.as_ptr(),
+					&mut on_current,
+				),
+			)
+		}.map(|_| on_current != 0)
+	}
+
+	/// [`IVirtualDesktopManager::MoveWindowToDesktop`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ivirtualdesktopmanager-movewindowtodesktop)
+	/// method.
+	///
+	/// Only windows owned by the calling process can be moved.
+	fn MoveWindowToDesktop(&self, hwnd: &HWND, desktop_id: &GUID) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IVirtualDesktopManagerVT>();
+			ok_to_hrresult(
+				(vt.MoveWindowToDesktop)(
+					self.ptr(),
+					hwnd.as_ptr(),
+					desktop_id as *const _ as _,
+				),
+			)
+		}
+	}
+}
@@ -1,8 +1,23 @@
+mod iapplicationactivationmanager;
+mod icontextmenu;
+mod icontextmenu2;
+mod icontextmenu3;
+mod icustomdestinationlist;
+mod ienumidlist;
 mod ienumshellitems;
 mod ifiledialog;
+mod ifiledialogcustomize;
+mod ifiledialogevents;
+mod ifileoperation;
+mod ifileoperationprogresssink;
 mod ifileopendialog;
 mod ifilesavedialog;
+mod iknownfolder;
+mod iknownfoldermanager;
 mod imodalwindow;
+mod iobjectarray;
+mod iobjectcollection;
+mod ishellfolder;
 mod ishellitem;
 mod ishellitem2;
 mod ishellitemarray;
@@ -11,13 +26,29 @@ mod itaskbarlist;
 mod itaskbarlist2;
 mod itaskbarlist3;
 mod itaskbarlist4;
+mod ivirtualdesktopmanager;
 
 pub mod decl {
+	pub use super::iapplicationactivationmanager::IApplicationActivationManager;
+	pub use super::icontextmenu::IContextMenu;
+	pub use super::icontextmenu2::IContextMenu2;
+	pub use super::icontextmenu3::IContextMenu3;
+	pub use super::icustomdestinationlist::ICustomDestinationList;
+	pub use super::ienumidlist::IEnumIDList;
 	pub use super::ienumshellitems::IEnumShellItems;
 	pub use super::ifiledialog::IFileDialog;
+	pub use super::ifiledialogcustomize::IFileDialogCustomize;
+	pub use super::ifiledialogevents::IFileDialogEvents;
+	pub use super::ifileoperation::IFileOperation;
+	pub use super::ifileoperationprogresssink::IFileOperationProgressSink;
 	pub use super::ifileopendialog::IFileOpenDialog;
 	pub use super::ifilesavedialog::IFileSaveDialog;
+	pub use super::iknownfolder::IKnownFolder;
+	pub use super::iknownfoldermanager::IKnownFolderManager;
 	pub use super::imodalwindow::IModalWindow;
+	pub use super::iobjectarray::IObjectArray;
+	pub use super::iobjectcollection::IObjectCollection;
+	pub use super::ishellfolder::IShellFolder;
 	pub use super::ishellitem::IShellItem;
 	pub use super::ishellitem2::IShellItem2;
 	pub use super::ishellitemarray::IShellItemArray;
@@ -26,14 +57,30 @@ pub mod decl {
 	pub use super::itaskbarlist2::ITaskbarList2;
 	pub use super::itaskbarlist3::ITaskbarList3;
 	pub use super::itaskbarlist4::ITaskbarList4;
+	pub use super::ivirtualdesktopmanager::IVirtualDesktopManager;
 }
 
 pub mod traits {
+	pub use super::iapplicationactivationmanager::shell_IApplicationActivationManager;
+	pub use super::icontextmenu::shell_IContextMenu;
+	pub use super::icontextmenu2::shell_IContextMenu2;
+	pub use super::icontextmenu3::shell_IContextMenu3;
+	pub use super::icustomdestinationlist::shell_ICustomDestinationList;
+	pub use super::ienumidlist::shell_IEnumIDList;
 	pub use super::ienumshellitems::shell_IEnumShellItems;
 	pub use super::ifiledialog::shell_IFileDialog;
+	pub use super::ifiledialogcustomize::shell_IFileDialogCustomize;
+	pub use super::ifiledialogevents::shell_IFileDialogEvents;
+	pub use super::ifileoperation::shell_IFileOperation;
+	pub use super::ifileoperationprogresssink::shell_IFileOperationProgressSink;
 	pub use super::ifileopendialog::shell_IFileOpenDialog;
 	pub use super::ifilesavedialog::shell_IFileSaveDialog;
+	pub use super::iknownfolder::shell_IKnownFolder;
+	pub use super::iknownfoldermanager::shell_IKnownFolderManager;
 	pub use super::imodalwindow::shell_IModalWindow;
+	pub use super::iobjectarray::shell_IObjectArray;
+	pub use super::iobjectcollection::shell_IObjectCollection;
+	pub use super::ishellfolder::shell_IShellFolder;
 	pub use super::ishellitem::shell_IShellItem;
 	pub use super::ishellitem2::shell_IShellItem2;
 	pub use super::ishellitemarray::shell_IShellItemArray;
@@ -42,14 +89,30 @@ pub mod traits {
 	pub use super::itaskbarlist2::shell_ITaskbarList2;
 	pub use super::itaskbarlist3::shell_ITaskbarList3;
 	pub use super::itaskbarlist4::shell_ITaskbarList4;
+	pub use super::ivirtualdesktopmanager::shell_IVirtualDesktopManager;
 }
 
 pub mod vt {
+	pub use super::iapplicationactivationmanager::IApplicationActivationManagerVT;
+	pub use super::icontextmenu::IContextMenuVT;
+	pub use super::icontextmenu2::IContextMenu2VT;
+	pub use super::icontextmenu3::IContextMenu3VT;
+	pub use super::icustomdestinationlist::ICustomDestinationListVT;
+	pub use super::ienumidlist::IEnumIDListVT;
 	pub use super::ienumshellitems::IEnumShellItemsVT;
 	pub use super::ifiledialog::IFileDialogVT;
+	pub use super::ifiledialogcustomize::IFileDialogCustomizeVT;
+	pub use super::ifiledialogevents::IFileDialogEventsVT;
+	pub use super::ifileoperation::IFileOperationVT;
+	pub use super::ifileoperationprogresssink::IFileOperationProgressSinkVT;
 	pub use super::ifileopendialog::IFileOpenDialogVT;
 	pub use super::ifilesavedialog::IFileSaveDialogVT;
+	pub use super::iknownfolder::IKnownFolderVT;
+	pub use super::iknownfoldermanager::IKnownFolderManagerVT;
 	pub use super::imodalwindow::IModalWindowVT;
+	pub use super::iobjectarray::IObjectArrayVT;
+	pub use super::iobjectcollection::IObjectCollectionVT;
+	pub use super::ishellfolder::IShellFolderVT;
 	pub use super::ishellitem::IShellItemVT;
 	pub use super::ishellitem2::IShellItem2VT;
 	pub use super::ishellitemarray::IShellItemArrayVT;
@@ -58,4 +121,5 @@ pub mod vt {
 	pub use super::itaskbarlist2::ITaskbarList2VT;
 	pub use super::itaskbarlist3::ITaskbarList3VT;
 	pub use super::itaskbarlist4::ITaskbarList4VT;
+	pub use super::ivirtualdesktopmanager::IVirtualDesktopManagerVT;
 }
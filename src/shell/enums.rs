@@ -0,0 +1,44 @@
+use crate::co;
+use crate::kernel::decl::WString;
+
+/// Variant parameter for:
+///
+/// * [`SHChangeNotify`](crate::SHChangeNotify).
+pub enum ChangeNotifyItems<'a> {
+	/// No items, like in
+	/// [`SHCNE::ASSOCCHANGED`](crate::co::SHCNE::ASSOCCHANGED). Sets
+	/// `SHCNF_IDLIST` flag.
+	None,
+	/// One or two `DWORD` values. Sets `SHCNF_DWORD` flag.
+	Dword(u32, Option<u32>),
+	/// One or two file or folder paths. Sets `SHCNF_PATHW` flag.
+	Path(&'a str, Option<&'a str>),
+	/// One or two printer names. Sets `SHCNF_PRINTERW` flag.
+	Printer(&'a str, Option<&'a str>),
+}
+
+impl<'a> ChangeNotifyItems<'a> {
+	/// Returns the item type flag.
+	#[must_use]
+	pub(in crate::shell) const fn flag(&self) -> co::SHCNF {
+		co::SHCNF(match self {
+			Self::None => 0x0000, // SHCNF_IDLIST
+			Self::Dword(_, _) => 0x0003, // SHCNF_DWORD
+			Self::Path(_, _) => 0x0005, // SHCNF_PATHW
+			Self::Printer(_, _) => 0x0006, // SHCNF_PRINTERW
+		})
+	}
+
+	/// Returns the strings of the items, if any, which must be kept alive
+	/// during the call.
+	#[must_use]
+	pub(in crate::shell) fn as_wstrings(&self) -> (Option<WString>, Option<WString>) {
+		match self {
+			Self::Path(s1, s2) | Self::Printer(s1, s2) => (
+				Some(WString::from_str(s1)),
+				s2.map(WString::from_str),
+			),
+			_ => (None, None),
+		}
+	}
+}
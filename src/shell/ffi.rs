@@ -6,14 +6,29 @@ extern_sys! { "shell32";
 	DragFinish(HANDLE)
 	DragQueryFileW(HANDLE, u32, PSTR, u32) -> u32
 	DragQueryPoint(HANDLE, PVOID) -> BOOL
+	GetCurrentProcessExplicitAppUserModelID(*mut PSTR) -> HRES
+	ILCreateFromPathW(PCSTR) -> PVOID
+	ILGetSize(PCVOID) -> u32
+	ILFree(PVOID)
+	SetCurrentProcessExplicitAppUserModelID(PCSTR) -> HRES
 	SHAddToRecentDocs(u32, PCVOID)
+	SHCreateItemFromIDList(PCVOID, PCVOID, *mut PVOID) -> HRES
 	SHCreateItemFromParsingName(PCSTR, PVOID, PCVOID, *mut PVOID) -> HRES
 	Shell_NotifyIconW(u32, PVOID) -> BOOL
 	ShellAboutW(HANDLE, PCSTR, PCSTR, HANDLE) -> i32
 	ShellExecuteW(HANDLE, PCSTR, PCSTR, PCSTR, PCSTR, i32) -> HANDLE
+	SHChangeNotification_Lock(HANDLE, u32, *mut *mut PVOID, *mut i32) -> HANDLE
+	SHChangeNotification_Unlock(HANDLE) -> BOOL
+	SHChangeNotify(i32, u32, PCVOID, PCVOID)
+	SHChangeNotifyDeregister(u32) -> BOOL
+	SHChangeNotifyRegister(HANDLE, i32, i32, u32, i32, PCVOID) -> u32
 	SHFileOperationW(PVOID) -> i32
+	SHGetDesktopFolder(*mut PVOID) -> HRES
 	SHGetFileInfoW(PCSTR, u32, PVOID, u32, u32) -> usize
+	SHGetIDListFromObject(PVOID, *mut PVOID) -> HRES
 	SHGetKnownFolderPath(PCVOID, u32, HANDLE, *mut PSTR) -> HRES
+	SHGetPathFromIDListW(PCVOID, PSTR) -> BOOL
+	SHGetSetSettings(PVOID, u32, BOOL)
 	SHGetStockIconInfo(u32, u32, PVOID) -> HRES
 }
 
@@ -25,4 +40,5 @@ extern_sys! { "shlwapi";
 	PathUndecorateW(PSTR)
 	PathUnquoteSpacesW(PSTR) -> BOOL
 	SHCreateMemStream(*const u8, u32) -> PVOID
+	StrRetToStrW(PVOID, PCVOID, *mut PSTR) -> HRES
 }
@@ -10,11 +10,15 @@ use crate::kernel::privs::{
 };
 use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult, IStream};
 use crate::ole::privs::ok_to_hrresult;
-use crate::prelude::{Handle, ole_IBindCtx, shell_IShellItem};
+use crate::prelude::{Handle, ole_IBindCtx, ole_IUnknown, shell_IShellItem};
 use crate::shell::decl::{
-	NOTIFYICONDATA, SHFILEINFO, SHFILEOPSTRUCT, SHSTOCKICONINFO,
+	ChangeNotifyItems, IShellFolder, ITEMIDLIST, NOTIFYICONDATA, SHELLSTATE,
+	SHFILEINFO, SHFILEOPSTRUCT, SHSTOCKICONINFO,
+};
+use crate::shell::guard::{
+	CoTaskMemFreePidlGuard, DestroyIconShfiGuard, DestroyIconSiiGuard,
+	SHChangeNotificationUnlockGuard,
 };
-use crate::shell::guard::{DestroyIconShfiGuard, DestroyIconSiiGuard};
 
 /// [`CommandLineToArgv`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-commandlinetoargvw)
 /// function.
@@ -53,6 +57,46 @@ pub fn CommandLineToArgv(cmd_line: &str) -> SysResult<Vec<String>> {
 	Ok(strs)
 }
 
+/// [`GetCurrentProcessExplicitAppUserModelID`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-getcurrentprocessexplicitappusermodelid)
+/// function.
+///
+/// Fails if no AppUserModelID was explicitly set with
+/// [`SetCurrentProcessExplicitAppUserModelID`](crate::SetCurrentProcessExplicitAppUserModelID).
+#[must_use]
+pub fn GetCurrentProcessExplicitAppUserModelID() -> HrResult<String> {
+	let mut pstr: *mut u16 = std::ptr::null_mut();
+	ok_to_hrresult(
+		unsafe { shell::ffi::GetCurrentProcessExplicitAppUserModelID(&mut pstr) },
+	).map(|_| {
+		let app_id = WString::from_wchars_nullt(pstr);
+		CoTaskMemFree(pstr as _);
+		app_id.to_string()
+	})
+}
+
+/// [`ILCreateFromPath`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-ilcreatefrompathw)
+/// function.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{ILCreateFromPath, SHGetPathFromIDList};
+///
+/// let pidl = ILCreateFromPath("C:\\Temp")?;
+/// println!("{:?}", SHGetPathFromIDList(&pidl));
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+#[must_use]
+pub fn ILCreateFromPath(path: &str) -> SysResult<CoTaskMemFreePidlGuard> {
+	match unsafe {
+		shell::ffi::ILCreateFromPathW(WString::from_str(path).as_ptr())
+	} {
+		p if p.is_null() => Err(co::ERROR::PATH_NOT_FOUND),
+		p => Ok(unsafe { CoTaskMemFreePidlGuard::new(p as _) }),
+	}
+}
+
 /// [`PathCombine`](https://learn.microsoft.com/en-us/windows/win32/api/shlwapi/nf-shlwapi-pathcombinew)
 /// function.
 ///
@@ -145,6 +189,32 @@ pub fn PathUnquoteSpaces(str_path: &str) -> String {
 	buf.to_string()
 }
 
+/// [`SetCurrentProcessExplicitAppUserModelID`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-setcurrentprocessexplicitappusermodelid)
+/// function.
+///
+/// Sets the [AppUserModelID](https://learn.microsoft.com/en-us/windows/win32/shell/appids)
+/// used by the taskbar to group the windows of the process. Must be called
+/// during application initialization, before any window is shown.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::SetCurrentProcessExplicitAppUserModelID;
+///
+/// SetCurrentProcessExplicitAppUserModelID("MyCompany.MyApp.Main.1")?;
+/// # Ok::<_, winsafe::co::HRESULT>(())
+/// ```
+pub fn SetCurrentProcessExplicitAppUserModelID(app_id: &str) -> HrResult<()> {
+	ok_to_hrresult(
+		unsafe {
+			shell::ffi::SetCurrentProcessExplicitAppUserModelID(
+				WString::from_str(app_id).as_ptr(),
+			)
+		},
+	)
+}
+
 /// [`SHAddToRecentDocs`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shaddtorecentdocs)
 /// function.
 ///
@@ -166,6 +236,116 @@ pub fn Shell_NotifyIcon(
 	)
 }
 
+/// [`SHChangeNotification_Lock`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shchangenotification_lock)
+/// function.
+///
+/// Reads the notification message registered with
+/// [`HWND::SHChangeNotifyRegister`](crate::prelude::shell_Hwnd::SHChangeNotifyRegister)
+/// along with [`SHCNRF::NEWDELIVERY`](crate::co::SHCNRF::NEWDELIVERY). The
+/// arguments are the `wparam` and `lparam` of the message.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, msg, SHChangeNotification_Lock};
+///
+/// let p: msg::WndMsg; // received in the window procedure
+/// # let p = msg::WndMsg::new(co::WM::APP, 0, 0);
+///
+/// let lock = SHChangeNotification_Lock(p.wparam, p.lparam as _)?;
+/// let (path1, path2) = lock.paths();
+/// println!("{} {:?} {:?}", lock.event(), path1, path2);
+/// # Ok::<_, co::ERROR>(())
+/// ```
+#[must_use]
+pub fn SHChangeNotification_Lock(
+	hchange: usize, proc_id: u32) -> SysResult<SHChangeNotificationUnlockGuard>
+{
+	let mut pidls = std::ptr::null_mut();
+	let mut event = i32::default();
+
+	match unsafe {
+		shell::ffi::SHChangeNotification_Lock(
+			hchange as _, proc_id, &mut pidls, &mut event)
+	} {
+		hlock if hlock.is_null() => Err(GetLastError()),
+		hlock => Ok(unsafe {
+			SHChangeNotificationUnlockGuard::new(hlock, pidls, co::SHCNE(event as _))
+		}),
+	}
+}
+
+/// [`SHChangeNotify`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shchangenotify)
+/// function.
+///
+/// # Examples
+///
+/// Refreshing Explorer views after registering a file association:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, ChangeNotifyItems, SHChangeNotify};
+///
+/// SHChangeNotify(
+///     co::SHCNE::ASSOCCHANGED,
+///     co::SHCNF::default(),
+///     ChangeNotifyItems::None,
+/// );
+/// ```
+pub fn SHChangeNotify(
+	event_id: co::SHCNE,
+	flags: co::SHCNF,
+	items: ChangeNotifyItems,
+) {
+	let (wstr1, wstr2) = items.as_wstrings();
+	let (item1, item2) = match items {
+		ChangeNotifyItems::None => (std::ptr::null(), std::ptr::null()),
+		ChangeNotifyItems::Dword(d1, d2) => (
+			d1 as usize as *const std::ffi::c_void,
+			d2.map_or(std::ptr::null(), |d2| d2 as usize as _),
+		),
+		ChangeNotifyItems::Path(_, _) | ChangeNotifyItems::Printer(_, _) => (
+			wstr1.as_ref().map_or(std::ptr::null(), |w| w.as_ptr() as _),
+			wstr2.as_ref().map_or(std::ptr::null(), |w| w.as_ptr() as _),
+		),
+	};
+
+	unsafe {
+		shell::ffi::SHChangeNotify(
+			event_id.0 as _, (flags | items.flag()).0, item1, item2);
+	}
+}
+
+/// [`SHCreateItemFromIDList`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-shcreateitemfromidlist)
+/// function.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{ILCreateFromPath, IShellItem, SHCreateItemFromIDList};
+///
+/// let pidl = ILCreateFromPath("C:\\Temp")?;
+/// let shi = SHCreateItemFromIDList::<IShellItem>(&pidl)?;
+/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+/// ```
+#[must_use]
+pub fn SHCreateItemFromIDList<T>(pidl: &ITEMIDLIST) -> HrResult<T>
+	where T: shell_IShellItem,
+{
+	unsafe {
+		let mut ppv_queried = ComPtr::null();
+		ok_to_hrresult(
+			shell::ffi::SHCreateItemFromIDList(
+				pidl as *const _ as _,
+				&T::IID as *const _ as _,
+				&mut ppv_queried as *mut _ as _,
+			),
+		).map(|_| T::from(ppv_queried))
+	}
+}
+
 /// [`SHCreateItemFromParsingName`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-shcreateitemfromparsingname)
 /// function.
 ///
@@ -238,6 +418,39 @@ pub fn SHFileOperation(file_op: &mut SHFILEOPSTRUCT) -> SysResult<()> {
 	)
 }
 
+/// [`SHGetDesktopFolder`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shgetdesktopfolder)
+/// function.
+///
+/// # Examples
+///
+/// Listing the display names of the items on the desktop:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, SHGetDesktopFolder};
+///
+/// let desktop = SHGetDesktopFolder()?;
+///
+/// if let Some(items) = desktop.EnumObjects(
+///     None, co::SHCONTF::FOLDERS | co::SHCONTF::NONFOLDERS)?
+/// {
+///     for pidl in items.iter() {
+///         let pidl = pidl?;
+///         println!("{}", desktop.GetDisplayNameOf(&pidl, co::SHGDN::NORMAL)?);
+///     }
+/// }
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+#[must_use]
+pub fn SHGetDesktopFolder() -> HrResult<IShellFolder> {
+	unsafe {
+		let mut ppv_queried = ComPtr::null();
+		ok_to_hrresult(
+			shell::ffi::SHGetDesktopFolder(&mut ppv_queried as *mut _ as _),
+		).map(|_| IShellFolder::from(ppv_queried))
+	}
+}
+
 /// [`SHGetFileInfo`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shgetfileinfow)
 /// function.
 pub fn SHGetFileInfo(
@@ -261,6 +474,19 @@ pub fn SHGetFileInfo(
 	}
 }
 
+/// [`SHGetIDListFromObject`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-shgetidlistfromobject)
+/// function.
+#[must_use]
+pub fn SHGetIDListFromObject(
+	obj: &impl ole_IUnknown,
+) -> HrResult<CoTaskMemFreePidlGuard>
+{
+	let mut pidl = std::ptr::null_mut();
+	ok_to_hrresult(
+		unsafe { shell::ffi::SHGetIDListFromObject(obj.ptr().0 as _, &mut pidl) },
+	).map(|_| unsafe { CoTaskMemFreePidlGuard::new(pidl as _) })
+}
+
 /// [`SHGetKnownFolderPath`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shgetknownfolderpath)
 /// function.
 ///
@@ -305,6 +531,51 @@ pub fn SHGetKnownFolderPath(
 	})
 }
 
+/// [`SHGetPathFromIDList`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shgetpathfromidlistw)
+/// function.
+///
+/// Returns `None` if the item is not part of the file system, like a printer.
+#[must_use]
+pub fn SHGetPathFromIDList(pidl: &ITEMIDLIST) -> Option<String> {
+	let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
+	match unsafe {
+		shell::ffi::SHGetPathFromIDListW(pidl as *const _ as _, buf.as_mut_ptr())
+	} {
+		0 => None,
+		_ => Some(buf.to_string()),
+	}
+}
+
+/// [`SHGetSetSettings`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shgetsetsettings)
+/// function.
+///
+/// If `set` is `false`, the fields given in `mask` are retrieved; otherwise,
+/// they are written.
+///
+/// # Examples
+///
+/// Checking whether Explorer shows hidden files and file extensions:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, SHELLSTATE, SHGetSetSettings};
+///
+/// let mut ss = SHELLSTATE::default();
+/// SHGetSetSettings(
+///     &mut ss,
+///     co::SSF::SHOWALLOBJECTS | co::SSF::SHOWEXTENSIONS,
+///     false,
+/// );
+///
+/// println!("Hidden files: {}, extensions: {}",
+///     ss.fShowAllObjects(), ss.fShowExtensions());
+/// ```
+pub fn SHGetSetSettings(state: &mut SHELLSTATE, mask: co::SSF, set: bool) {
+	unsafe {
+		shell::ffi::SHGetSetSettings(state as *mut _ as _, mask.0, set as _);
+	}
+}
+
 /// [`SHGetStockIconInfo`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shgetstockiconinfo)
 /// function.
 ///
@@ -1,9 +1,67 @@
 use std::ops::{Deref, DerefMut};
 
+use crate::{co, shell};
+use crate::kernel::ffi_types::{HANDLE, PVOID};
+use crate::ole::decl::CoTaskMemFree;
 use crate::prelude::Handle;
-use crate::shell::decl::{SHFILEINFO, SHSTOCKICONINFO};
+use crate::shell::decl::{
+	ITEMIDLIST, SHFILEINFO, SHGetPathFromIDList, SHSTOCKICONINFO,
+};
 use crate::user::guard::DestroyIconGuard;
 
+/// RAII implementation for [`ITEMIDLIST`](crate::ITEMIDLIST) which
+/// automatically calls
+/// [`CoTaskMemFree`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cotaskmemfree)
+/// when the object goes out of scope.
+pub struct CoTaskMemFreePidlGuard {
+	pidl: *mut ITEMIDLIST,
+}
+
+impl Drop for CoTaskMemFreePidlGuard {
+	fn drop(&mut self) {
+		if !self.pidl.is_null() {
+			CoTaskMemFree(self.pidl as _);
+		}
+	}
+}
+
+impl Deref for CoTaskMemFreePidlGuard {
+	type Target = ITEMIDLIST;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { &*self.pidl }
+	}
+}
+
+impl CoTaskMemFreePidlGuard {
+	/// Constructs the guard by taking ownership of the pointer.
+	/// 
+	/// # Safety
+	/// 
+	/// Be sure the pointer must be freed with
+	/// [`CoTaskMemFree`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cotaskmemfree)
+	/// at the end of scope.
+	/// 
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(pidl: *mut ITEMIDLIST) -> Self {
+		Self { pidl }
+	}
+
+	/// Ejects the underlying pointer, leaving a null pointer in its place.
+	///
+	/// Since the internal pointer will be invalidated, the destructor will not
+	/// run. It's your responsibility to run it, otherwise you'll cause a
+	/// resource leak.
+	#[must_use]
+	pub fn leak(&mut self) -> *mut ITEMIDLIST {
+		std::mem::replace(&mut self.pidl, std::ptr::null_mut())
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation for [`SHFILEINFO`](crate::SHFILEINFO) which
 /// automatically calls
 /// [`DestroyIcon`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-destroyicon)
@@ -122,3 +180,122 @@ impl DestroyIconSiiGuard {
 		std::mem::take(&mut self.sii)
 	}
 }
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for the shell change notification lock, returned by
+/// [`SHChangeNotification_Lock`](crate::SHChangeNotification_Lock), which
+/// automatically calls
+/// [`SHChangeNotification_Unlock`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shchangenotification_unlock)
+/// when the object goes out of scope.
+pub struct SHChangeNotificationUnlockGuard {
+	hlock: HANDLE,
+	pidls: *mut PVOID,
+	event: co::SHCNE,
+}
+
+impl Drop for SHChangeNotificationUnlockGuard {
+	fn drop(&mut self) {
+		if !self.hlock.is_null() {
+			unsafe { shell::ffi::SHChangeNotification_Unlock(self.hlock); } // ignore errors
+		}
+	}
+}
+
+impl SHChangeNotificationUnlockGuard {
+	/// Constructs the guard by taking ownership of the lock.
+	///
+	/// # Safety
+	///
+	/// Be sure the lock must be freed with
+	/// [`SHChangeNotification_Unlock`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shchangenotification_unlock)
+	/// at the end of scope.
+	///
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(
+		hlock: HANDLE, pidls: *mut PVOID, event: co::SHCNE) -> Self
+	{
+		Self { hlock, pidls, event }
+	}
+
+	/// Returns the event which occurred.
+	#[must_use]
+	pub const fn event(&self) -> co::SHCNE {
+		self.event
+	}
+
+	/// Returns the file system paths of the two items of the event, by calling
+	/// [`SHGetPathFromIDList`](crate::SHGetPathFromIDList).
+	///
+	/// An item is `None` if the event doesn't have it, or if it's not part of
+	/// the file system, like a printer.
+	#[must_use]
+	pub fn paths(&self) -> (Option<String>, Option<String>) {
+		let path_of = |idx: usize| -> Option<String> {
+			unsafe { (*self.pidls.add(idx) as *const ITEMIDLIST).as_ref() }
+				.and_then(SHGetPathFromIDList)
+		};
+
+		if self.pidls.is_null() {
+			(None, None)
+		} else {
+			(path_of(0), path_of(1))
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for the registration ID returned by
+/// [`HWND::SHChangeNotifyRegister`](crate::prelude::shell_Hwnd::SHChangeNotifyRegister),
+/// which automatically calls
+/// [`SHChangeNotifyDeregister`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shchangenotifyderegister)
+/// when the object goes out of scope.
+pub struct SHChangeNotifyDeregisterGuard {
+	reg_id: u32,
+}
+
+impl Drop for SHChangeNotifyDeregisterGuard {
+	fn drop(&mut self) {
+		if self.reg_id != 0 {
+			unsafe { shell::ffi::SHChangeNotifyDeregister(self.reg_id); } // ignore errors
+		}
+	}
+}
+
+impl Deref for SHChangeNotifyDeregisterGuard {
+	type Target = u32;
+
+	fn deref(&self) -> &Self::Target {
+		&self.reg_id
+	}
+}
+
+impl SHChangeNotifyDeregisterGuard {
+	/// Constructs the guard by taking ownership of the registration ID.
+	///
+	/// # Safety
+	///
+	/// Be sure the registration must be freed with
+	/// [`SHChangeNotifyDeregister`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shchangenotifyderegister)
+	/// at the end of scope.
+	///
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(reg_id: u32) -> Self {
+		Self { reg_id }
+	}
+
+	/// Ejects the underlying registration ID, leaving zero in its place.
+	///
+	/// Since the internal ID will be invalidated, the destructor will not run.
+	/// It's your responsibility to run it, otherwise you'll cause a resource
+	/// leak.
+	#[must_use]
+	pub fn leak(&mut self) -> u32 {
+		std::mem::replace(&mut self.reg_id, 0)
+	}
+}
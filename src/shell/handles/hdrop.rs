@@ -1,5 +1,7 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use std::path::PathBuf;
+
 use crate::kernel::decl::{GetLastError, SysResult, WString};
 use crate::kernel::privs::MAX_PATH;
 use crate::prelude::Handle;
@@ -64,6 +66,34 @@ pub trait shell_Hdrop: Handle {
 		Ok(Box::new(DropsIter::new(self)?))
 	}
 
+	/// Returns an iterator over the dropped files as
+	/// [`PathBuf`](std::path::PathBuf), by calling
+	/// [`HDROP::DragQueryFile`](crate::prelude::shell_Hdrop::DragQueryFile)
+	/// consecutively, then frees the handle by calling
+	/// [`HDROP::DragFinish`](crate::prelude::shell_Hdrop::DragFinish).
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{HDROP, SysResult};
+	///
+	/// let mut hdrop: HDROP; // initialized somewhere
+	/// # let mut hdrop = HDROP::NULL;
+	///
+	/// let (pt, _) = hdrop.DragQueryPoint(); // before the handle is freed
+	/// let paths = hdrop.iter_paths()?
+	///     .collect::<SysResult<Vec<_>>>()?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn iter_paths(&mut self) -> SysResult<Box<dyn Iterator<Item = SysResult<PathBuf>> + '_>> {
+		Ok(Box::new(
+			DropsIter::new(self)?
+				.map(|file_path| file_path.map(PathBuf::from)),
+		))
+	}
+
 	/// [`DragFinish`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-dragfinish)
 	/// method.
 	///
@@ -1,9 +1,11 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
 use crate::{co, shell};
-use crate::kernel::decl::{HINSTANCE, SysResult, WString};
+use crate::kernel::decl::{GetLastError, HINSTANCE, SysResult, WString};
 use crate::kernel::privs::bool_to_sysresult;
 use crate::prelude::Handle;
+use crate::shell::guard::SHChangeNotifyDeregisterGuard;
+use crate::shell::privs::SHChangeNotifyEntry;
 use crate::user::decl::{HICON, HWND};
 
 impl shell_Hwnd for HWND {}
@@ -23,6 +25,94 @@ pub trait shell_Hwnd: Handle {
 		unsafe { shell::ffi::DragAcceptFiles(self.as_ptr(), accept as _); }
 	}
 
+	/// [`SHChangeNotifyRegister`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shchangenotifyregister)
+	/// method.
+	///
+	/// Each entry is a folder path to be watched, and whether its subfolders
+	/// are also watched. An empty path stands for the desktop, the root of the
+	/// shell namespace.
+	///
+	/// If `sources` has [`SHCNRF::NEWDELIVERY`](crate::co::SHCNRF::NEWDELIVERY),
+	/// the notification message must be read with
+	/// [`SHChangeNotification_Lock`](crate::SHChangeNotification_Lock).
+	///
+	/// Prefer using [`gui::ShellChangeNotify`](crate::gui::ShellChangeNotify),
+	/// which delivers the notifications as events.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HWND};
+	///
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	/// let wm_shell_change = co::WM::APP;
+	///
+	/// let _reg_guard = hwnd.SHChangeNotifyRegister(
+	///     co::SHCNRF::SHELLLEVEL | co::SHCNRF::INTERRUPTLEVEL | co::SHCNRF::NEWDELIVERY,
+	///     co::SHCNE::CREATE | co::SHCNE::DELETE | co::SHCNE::RENAMEITEM,
+	///     wm_shell_change,
+	///     &[("C:\\Temp", true)],
+	/// )?;
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn SHChangeNotifyRegister(&self,
+		sources: co::SHCNRF,
+		events: co::SHCNE,
+		msg: co::WM,
+		entries: &[(&str, bool)],
+	) -> SysResult<SHChangeNotifyDeregisterGuard>
+	{
+		let empty_pidl = [0u8; 2]; // desktop
+		let mut pidls = Vec::with_capacity(entries.len());
+		let mut res = Ok(());
+
+		for (path, _) in entries.iter() {
+			if path.is_empty() {
+				pidls.push(empty_pidl.as_ptr() as *mut _);
+			} else {
+				let pidl = unsafe {
+					shell::ffi::ILCreateFromPathW(WString::from_str(path).as_ptr())
+				};
+				if pidl.is_null() {
+					res = Err(co::ERROR::PATH_NOT_FOUND);
+					break;
+				}
+				pidls.push(pidl);
+			}
+		}
+
+		let res = res.and_then(|_| {
+			let cn_entries = entries.iter()
+				.zip(pidls.iter())
+				.map(|((_, recursive), pidl)| SHChangeNotifyEntry {
+					pidl: *pidl,
+					fRecursive: *recursive as _,
+				})
+				.collect::<Vec<_>>();
+
+			match unsafe {
+				shell::ffi::SHChangeNotifyRegister(
+					self.as_ptr(),
+					sources.0 as _,
+					events.0 as _,
+					msg.0,
+					cn_entries.len() as _,
+					cn_entries.as_ptr() as _,
+				)
+			} {
+				0 => Err(GetLastError()),
+				reg_id => Ok(unsafe { SHChangeNotifyDeregisterGuard::new(reg_id) }),
+			}
+		});
+
+		pidls.iter() // the shell keeps its own copies
+			.filter(|pidl| **pidl != empty_pidl.as_ptr() as *mut _)
+			.for_each(|pidl| unsafe { shell::ffi::ILFree(*pidl) });
+		res
+	}
+
 	/// [`ShellAbout`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shellaboutw)
 	/// method.
 	fn ShellAbout(&self,
@@ -7,15 +7,19 @@ pub mod guard;
 pub mod messages;
 
 mod com_interfaces;
+mod enums;
 mod funcs;
 mod handles;
 mod structs;
+mod utilities;
 
 pub mod decl {
 	pub use super::com_interfaces::decl::*;
+	pub use super::enums::*;
 	pub use super::funcs::*;
 	pub use super::handles::decl::*;
 	pub use super::structs::*;
+	pub use super::utilities::*;
 }
 
 pub mod traits {
@@ -1 +1,33 @@
+#![allow(non_snake_case)]
+
+use crate::kernel::ffi_types::{BOOL, PCVOID};
+
 pub(crate) const INFOTIPSIZE: usize = 1024;
+
+/// [`STRRET`](https://learn.microsoft.com/en-us/windows/win32/api/shtypes/ns-shtypes-strret)
+/// struct.
+///
+/// Only used as an output buffer, which is then converted by
+/// [`StrRetToStr`](https://learn.microsoft.com/en-us/windows/win32/api/shlwapi/nf-shlwapi-strrettostrw).
+#[repr(C)]
+pub(in crate::shell) struct STRRET {
+	pub uType: u32,
+	pub union0: STRRET_union0,
+}
+
+#[repr(C)]
+pub(in crate::shell) union STRRET_union0 {
+	pub pOleStr: *mut u16,
+	pub uOffset: u32,
+	pub cStr: [u8; 260],
+}
+
+impl_default!(STRRET);
+
+/// [`SHChangeNotifyEntry`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/ns-shlobj_core-shchangenotifyentry)
+/// struct.
+#[repr(C)]
+pub(in crate::shell) struct SHChangeNotifyEntry {
+	pub pidl: PCVOID,
+	pub fRecursive: BOOL,
+}
@@ -2,11 +2,79 @@
 
 use std::marker::PhantomData;
 
-use crate::co;
-use crate::kernel::decl::{GUID, WString};
+use crate::{co, shell};
+use crate::kernel::decl::{GUID, IdStr, WString};
 use crate::kernel::ffi_types::BOOL;
-use crate::kernel::privs::{MAX_PATH, parse_multi_z_str};
-use crate::user::decl::{HICON, HWND};
+use crate::kernel::privs::{MAKEINTRESOURCE, MAX_PATH, parse_multi_z_str};
+use crate::user::decl::{HICON, HWND, POINT};
+
+/// [`CMINVOKECOMMANDINFOEX`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ns-shobjidl_core-cminvokecommandinfoex)
+/// struct.
+///
+/// Only the Unicode string fields are exposed, so the `Default` implementation
+/// sets `fMask` to [`CMIC::UNICODE`](crate::co::CMIC::UNICODE).
+#[repr(C)]
+pub struct CMINVOKECOMMANDINFOEX<'a, 'b, 'c, 'd> {
+	cbSize: u32,
+	pub fMask: co::CMIC,
+	pub hwnd: HWND,
+	lpVerb: *const u8,
+	lpParameters: *const u8,
+	lpDirectory: *const u8,
+	pub nShow: co::SW,
+	pub dwHotKey: u32,
+	pub hIcon: HICON,
+	lpTitle: *const u8,
+	lpVerbW: *const u16,
+	lpParametersW: *mut u16,
+	lpDirectoryW: *mut u16,
+	lpTitleW: *mut u16,
+	pub ptInvoke: POINT,
+
+	_lpVerbW: PhantomData<&'a IdStr>,
+	_lpParametersW: PhantomData<&'b mut u16>,
+	_lpDirectoryW: PhantomData<&'c mut u16>,
+	_lpTitleW: PhantomData<&'d mut u16>,
+}
+
+impl<'a, 'b, 'c, 'd> Default for CMINVOKECOMMANDINFOEX<'a, 'b, 'c, 'd> {
+	fn default() -> Self {
+		let mut obj = unsafe { std::mem::zeroed::<Self>() };
+		obj.cbSize = std::mem::size_of::<Self>() as _;
+		obj.fMask = co::CMIC::UNICODE;
+		obj
+	}
+}
+
+impl<'a, 'b, 'c, 'd> CMINVOKECOMMANDINFOEX<'a, 'b, 'c, 'd> {
+	/// Returns the `lpVerbW` field.
+	#[must_use]
+	pub fn lpVerbW(&self) -> Option<IdStr> {
+		if self.lpVerbW.is_null() {
+			None
+		} else {
+			Some(IdStr::from_ptr(self.lpVerbW))
+		}
+	}
+
+	/// Sets the `lpVerbW` field.
+	///
+	/// An [`IdStr::Id`](crate::IdStr::Id) is the command offset returned by
+	/// the menu, relative to the first ID given to
+	/// [`IContextMenu::QueryContextMenu`](crate::prelude::shell_IContextMenu::QueryContextMenu);
+	/// an [`IdStr::Str`](crate::IdStr::Str) is a canonical verb, like `open`.
+	pub fn set_lpVerbW(&mut self, val: Option<&'a IdStr>) {
+		self.lpVerbW = val.map_or(std::ptr::null(), |v| v.as_ptr());
+		self.lpVerb = match val { // handlers which ignore lpVerbW still get the offset
+			Some(IdStr::Id(id)) => MAKEINTRESOURCE(*id as _) as _,
+			_ => std::ptr::null(),
+		};
+	}
+
+	pub_fn_string_ptr_get_set!('b, lpParametersW, set_lpParametersW);
+	pub_fn_string_ptr_get_set!('c, lpDirectoryW, set_lpDirectoryW);
+	pub_fn_string_ptr_get_set!('d, lpTitleW, set_lpTitleW);
+}
 
 /// [`COMDLG_FILTERSPEC`](https://learn.microsoft.com/en-us/windows/win32/api/shtypes/ns-shtypes-comdlg_filterspec)
 /// struct.
@@ -26,6 +94,34 @@ impl<'a, 'b> COMDLG_FILTERSPEC<'a, 'b> {
 	pub_fn_string_ptr_get_set!('b, pszSpec, set_pszSpec);
 }
 
+/// [`ITEMIDLIST`](https://learn.microsoft.com/en-us/windows/win32/api/shtypes/ns-shtypes-itemidlist)
+/// struct, also known as PIDL.
+///
+/// Note that you cannot directly instantiate this struct, because its items
+/// are dynamically allocated by the shell, which yields a
+/// [`CoTaskMemFreePidlGuard`](crate::guard::CoTaskMemFreePidlGuard).
+#[repr(C)]
+pub struct ITEMIDLIST {
+	cb: u16,
+	abID: [u8; 1],
+}
+
+impl ITEMIDLIST {
+	/// Tells whether the list has no items, what means the desktop, the root
+	/// of the shell namespace.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.cb == 0
+	}
+
+	/// Returns the size of the list, in bytes, by calling
+	/// [`ILGetSize`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-ilgetsize).
+	#[must_use]
+	pub fn size(&self) -> u32 {
+		unsafe { shell::ffi::ILGetSize(self as *const _ as _) }
+	}
+}
+
 /// [`NOTIFYICONDATA`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/ns-shellapi-notifyicondataw)
 /// struct.
 #[repr(C)]
@@ -55,6 +151,48 @@ impl NOTIFYICONDATA {
 	pub_fn_string_arr_get_set!(szInfoTitle, set_szInfoTitle);
 }
 
+/// [`SHELLSTATE`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/ns-shlobj_core-shellstatew)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct SHELLSTATE {
+	bitfield1: u32,
+	dwWin95Unused: u32,
+	uWin95Unused: u32,
+	pub lParamSort: i32,
+	pub iSortDirection: i32,
+	pub version: u32,
+	uNotUsed: u32,
+	bitfield2: u32,
+}
+
+impl SHELLSTATE {
+	pub_fn_bitfield_get_set!(bitfield1, 0, fShowAllObjects, set_fShowAllObjects);
+	pub_fn_bitfield_get_set!(bitfield1, 1, fShowExtensions, set_fShowExtensions);
+	pub_fn_bitfield_get_set!(bitfield1, 2, fNoConfirmRecycle, set_fNoConfirmRecycle);
+	pub_fn_bitfield_get_set!(bitfield1, 3, fShowSysFiles, set_fShowSysFiles);
+	pub_fn_bitfield_get_set!(bitfield1, 4, fShowCompColor, set_fShowCompColor);
+	pub_fn_bitfield_get_set!(bitfield1, 5, fDoubleClickInWebView, set_fDoubleClickInWebView);
+	pub_fn_bitfield_get_set!(bitfield1, 6, fDesktopHTML, set_fDesktopHTML);
+	pub_fn_bitfield_get_set!(bitfield1, 7, fWin95Classic, set_fWin95Classic);
+	pub_fn_bitfield_get_set!(bitfield1, 8, fDontPrettyPath, set_fDontPrettyPath);
+	pub_fn_bitfield_get_set!(bitfield1, 9, fShowAttribCol, set_fShowAttribCol);
+	pub_fn_bitfield_get_set!(bitfield1, 10, fMapNetDrvBtn, set_fMapNetDrvBtn);
+	pub_fn_bitfield_get_set!(bitfield1, 11, fShowInfoTip, set_fShowInfoTip);
+	pub_fn_bitfield_get_set!(bitfield1, 12, fHideIcons, set_fHideIcons);
+	pub_fn_bitfield_get_set!(bitfield1, 13, fWebView, set_fWebView);
+	pub_fn_bitfield_get_set!(bitfield1, 14, fFilter, set_fFilter);
+	pub_fn_bitfield_get_set!(bitfield1, 15, fShowSuperHidden, set_fShowSuperHidden);
+	pub_fn_bitfield_get_set!(bitfield1, 16, fNoNetCrawling, set_fNoNetCrawling);
+	pub_fn_bitfield_get_set!(bitfield2, 0, fSepProcess, set_fSepProcess);
+	pub_fn_bitfield_get_set!(bitfield2, 1, fStartPanelOn, set_fStartPanelOn);
+	pub_fn_bitfield_get_set!(bitfield2, 2, fShowStartPage, set_fShowStartPage);
+	pub_fn_bitfield_get_set!(bitfield2, 3, fAutoCheckSelect, set_fAutoCheckSelect);
+	pub_fn_bitfield_get_set!(bitfield2, 4, fIconsOnly, set_fIconsOnly);
+	pub_fn_bitfield_get_set!(bitfield2, 5, fShowTypeOverlay, set_fShowTypeOverlay);
+	pub_fn_bitfield_get_set!(bitfield2, 6, fShowStatusBar, set_fShowStatusBar);
+}
+
 /// [`SHFILEINFO`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/ns-shellapi-shfileinfow)
 /// struct.
 #[repr(C)]
@@ -145,3 +283,21 @@ impl_default_with_size!(SHSTOCKICONINFO, cbSize);
 impl SHSTOCKICONINFO {
 	pub_fn_string_arr_get_set!(szPath, get_szPath);
 }
+
+/// [`THUMBBUTTON`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ns-shobjidl_core-thumbbutton)
+/// struct.
+#[repr(C)]
+pub struct THUMBBUTTON {
+	pub dwMask: co::THB,
+	pub iId: u32,
+	pub iBitmap: u32,
+	pub hIcon: HICON,
+	szTip: [u16; 260],
+	pub dwFlags: co::THBF,
+}
+
+impl_default!(THUMBBUTTON);
+
+impl THUMBBUTTON {
+	pub_fn_string_arr_get_set!(szTip, set_szTip);
+}
@@ -0,0 +1,222 @@
+#![allow(non_snake_case)]
+
+use std::mem::ManuallyDrop;
+use std::sync::atomic::{AtomicU32, fence, Ordering};
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PCVOID};
+use crate::ole::decl::{ComPtr, HrResult, IUnknown};
+use crate::ole::privs::catch_com_panic;
+use crate::prelude::ole_IUnknown;
+use crate::shell::decl::{IFileDialog, IFileDialogEvents};
+use crate::vt::{IFileDialogEventsVT, IUnknownVT};
+
+type Callback<R = ()> = Option<Box<dyn Fn(&IFileDialog) -> HrResult<R>>>;
+
+/// Builder of an [`IFileDialogEvents`](crate::IFileDialogEvents) implemented
+/// in Rust, which runs the given closures when the file dialog notifies its
+/// events.
+///
+/// The built object is passed to
+/// [`IFileDialog::Advise`](crate::prelude::shell_IFileDialog::Advise). Events
+/// without a closure are reported back to the dialog as not implemented, so
+/// the default behavior takes place.
+///
+/// # Examples
+///
+/// Accepting only files with the `.txt` extension:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, FileDialogEvents};
+///
+/// let events = FileDialogEvents::new()
+///     .file_ok(|fd| {
+///         let name = fd.GetFileName()?;
+///         Ok(name.to_lowercase().ends_with(".txt"))
+///     })
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct FileDialogEvents {
+	file_ok: Callback<bool>,
+	folder_change: Callback,
+	selection_change: Callback,
+	type_change: Callback,
+}
+
+impl FileDialogEvents {
+	/// Creates a new builder, with no closures.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// [`IFileDialogEvents::OnFileOk`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onfileok)
+	/// event, called just before the dialog is about to return a result.
+	///
+	/// Return false to reject the selection and keep the dialog open.
+	#[must_use]
+	pub fn file_ok<F>(mut self, func: F) -> Self
+		where F: Fn(&IFileDialog) -> HrResult<bool> + 'static,
+	{
+		self.file_ok = Some(Box::new(func));
+		self
+	}
+
+	/// [`IFileDialogEvents::OnFolderChange`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onfolderchange)
+	/// event, called when the user navigates to a new folder.
+	#[must_use]
+	pub fn folder_change<F>(mut self, func: F) -> Self
+		where F: Fn(&IFileDialog) -> HrResult<()> + 'static,
+	{
+		self.folder_change = Some(Box::new(func));
+		self
+	}
+
+	/// [`IFileDialogEvents::OnSelectionChange`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-onselectionchange)
+	/// event, called when the user changes the selection in the dialog's view.
+	#[must_use]
+	pub fn selection_change<F>(mut self, func: F) -> Self
+		where F: Fn(&IFileDialog) -> HrResult<()> + 'static,
+	{
+		self.selection_change = Some(Box::new(func));
+		self
+	}
+
+	/// [`IFileDialogEvents::OnTypeChange`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifiledialogevents-ontypechange)
+	/// event, called when the dialog is opened, and when the user changes the
+	/// selected file type.
+	#[must_use]
+	pub fn type_change<F>(mut self, func: F) -> Self
+		where F: Fn(&IFileDialog) -> HrResult<()> + 'static,
+	{
+		self.type_change = Some(Box::new(func));
+		self
+	}
+
+	/// Creates the [`IFileDialogEvents`](crate::IFileDialogEvents) object.
+	#[must_use]
+	pub fn build(self) -> IFileDialogEvents {
+		let obj = Box::new(FileDialogEventsImpl {
+			vt: &FILE_DIALOG_EVENTS_VT,
+			ref_count: AtomicU32::new(1),
+			events: self,
+		});
+		IFileDialogEvents::from(ComPtr(Box::into_raw(obj) as _))
+	}
+}
+
+//------------------------------------------------------------------------------
+
+static FILE_DIALOG_EVENTS_VT: IFileDialogEventsVT = IFileDialogEventsVT {
+	IUnknownVT: IUnknownVT {
+		QueryInterface: FileDialogEventsImpl::QueryInterface,
+		AddRef: FileDialogEventsImpl::AddRef,
+		Release: FileDialogEventsImpl::Release,
+	},
+	OnFileOk: FileDialogEventsImpl::OnFileOk,
+	OnFolderChanging: FileDialogEventsImpl::OnFolderChanging,
+	OnFolderChange: FileDialogEventsImpl::OnFolderChange,
+	OnSelectionChange: FileDialogEventsImpl::OnSelectionChange,
+	OnShareViolation: FileDialogEventsImpl::OnShareViolation,
+	OnTypeChange: FileDialogEventsImpl::OnTypeChange,
+	OnOverwrite: FileDialogEventsImpl::OnOverwrite,
+};
+
+/// Implementation of the `IFileDialogEvents` COM interface.
+#[repr(C)]
+struct FileDialogEventsImpl {
+	vt: *const IFileDialogEventsVT, // must be the first field, as in any COM object
+	ref_count: AtomicU32,
+	events: FileDialogEvents,
+}
+
+impl FileDialogEventsImpl {
+	fn from_com_ptr<'a>(p: ComPtr) -> &'a Self {
+		unsafe { &*(p.0 as *const Self) }
+	}
+
+	/// Runs the closure, if any, passing the dialog which is not owned by us. A
+	/// panic yields `E_UNEXPECTED`.
+	fn run<R>(
+		func: &Callback<R>,
+		fd: ComPtr,
+		ret: impl FnOnce(R) -> co::HRESULT,
+	) -> HRES
+	{
+		catch_com_panic(|| {
+			match func {
+				Some(func) => {
+					let fd = ManuallyDrop::new(IFileDialog::from(fd)); // not owned by us
+					match func(&fd) {
+						Ok(r) => ret(r).0,
+						Err(hr) => hr.0,
+					}
+				},
+				None => co::HRESULT::E_NOTIMPL.0,
+			}
+		})
+	}
+
+	fn QueryInterface(p: ComPtr, riid: PCVOID, ppv: *mut ComPtr) -> HRES {
+		let riid = unsafe { &*(riid as *const co::IID) };
+		if *riid == IUnknown::IID || *riid == IFileDialogEvents::IID {
+			Self::AddRef(p);
+			unsafe { *ppv = p; }
+			co::HRESULT::S_OK.0
+		} else {
+			unsafe { *ppv = ComPtr::null(); }
+			co::HRESULT::E_NOINTERFACE.0
+		}
+	}
+
+	fn AddRef(p: ComPtr) -> u32 {
+		Self::from_com_ptr(p).ref_count.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn Release(p: ComPtr) -> u32 {
+		let count = Self::from_com_ptr(p).ref_count.fetch_sub(1, Ordering::Release) - 1;
+		if count == 0 {
+			fence(Ordering::Acquire); // see the drop of std::sync::Arc
+			let _ = unsafe { Box::from_raw(p.0 as *mut Self) };
+		}
+		count
+	}
+
+	fn OnFileOk(p: ComPtr, fd: ComPtr) -> HRES {
+		Self::run(&Self::from_com_ptr(p).events.file_ok, fd,
+			|accept| if accept { co::HRESULT::S_OK } else { co::HRESULT::S_FALSE })
+	}
+
+	fn OnFolderChanging(_p: ComPtr, _fd: ComPtr, _folder: ComPtr) -> HRES {
+		co::HRESULT::E_NOTIMPL.0
+	}
+
+	fn OnFolderChange(p: ComPtr, fd: ComPtr) -> HRES {
+		Self::run(&Self::from_com_ptr(p).events.folder_change, fd,
+			|_| co::HRESULT::S_OK)
+	}
+
+	fn OnSelectionChange(p: ComPtr, fd: ComPtr) -> HRES {
+		Self::run(&Self::from_com_ptr(p).events.selection_change, fd,
+			|_| co::HRESULT::S_OK)
+	}
+
+	fn OnShareViolation(_p: ComPtr,
+		_fd: ComPtr, _si: ComPtr, _response: *mut u32) -> HRES
+	{
+		co::HRESULT::E_NOTIMPL.0
+	}
+
+	fn OnTypeChange(p: ComPtr, fd: ComPtr) -> HRES {
+		Self::run(&Self::from_com_ptr(p).events.type_change, fd,
+			|_| co::HRESULT::S_OK)
+	}
+
+	fn OnOverwrite(_p: ComPtr,
+		_fd: ComPtr, _si: ComPtr, _response: *mut u32) -> HRES
+	{
+		co::HRESULT::E_NOTIMPL.0
+	}
+}
@@ -0,0 +1,334 @@
+#![allow(non_snake_case)]
+
+use std::mem::ManuallyDrop;
+use std::sync::atomic::{AtomicU32, fence, Ordering};
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PCSTR, PCVOID};
+use crate::ole::decl::{ComPtr, HrResult, IUnknown};
+use crate::ole::privs::catch_com_panic;
+use crate::prelude::ole_IUnknown;
+use crate::shell::decl::{IFileOperationProgressSink, IShellItem};
+use crate::vt::{IFileOperationProgressSinkVT, IUnknownVT};
+
+type Callback<A = ()> = Option<Box<dyn Fn(A) -> HrResult<()>>>;
+type ItemCallback = Option<Box<dyn Fn(&IShellItem, Option<&IShellItem>, co::HRESULT) -> HrResult<()>>>;
+
+/// Builder of an
+/// [`IFileOperationProgressSink`](crate::IFileOperationProgressSink)
+/// implemented in Rust, which runs the given closures when an
+/// [`IFileOperation`](crate::IFileOperation) notifies its progress.
+///
+/// The built object is passed to
+/// [`IFileOperation::Advise`](crate::prelude::shell_IFileOperation::Advise),
+/// or to the individual item methods. If a closure returns an error, the
+/// whole operation is cancelled.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, FileOperationProgressSink, IFileOperation};
+///
+/// let file_op: IFileOperation; // initialized somewhere
+/// # let file_op = IFileOperation::from(unsafe { winsafe::ComPtr::null() });
+///
+/// let sink = FileOperationProgressSink::new()
+///     .update_progress(|(total, so_far)| {
+///         println!("{}/{}", so_far, total);
+///         Ok(())
+///     })
+///     .post_copy_item(|src, _new_item, hr| {
+///         println!("Copied {}: {}", src.GetDisplayName(co::SIGDN::FILESYSPATH)?, hr);
+///         Ok(())
+///     })
+///     .build();
+///
+/// let cookie = file_op.Advise(&sink)?;
+/// file_op.PerformOperations()?;
+/// file_op.Unadvise(cookie)?;
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+#[derive(Default)]
+pub struct FileOperationProgressSink {
+	start_operations: Callback,
+	finish_operations: Callback<co::HRESULT>,
+	update_progress: Callback<(u32, u32)>,
+	post_copy_item: ItemCallback,
+	post_delete_item: ItemCallback,
+	post_move_item: ItemCallback,
+	post_rename_item: ItemCallback,
+}
+
+impl FileOperationProgressSink {
+	/// Creates a new builder, with no closures.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// [`IFileOperationProgressSink::StartOperations`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperationprogresssink-startoperations)
+	/// event, called before any operation is performed.
+	#[must_use]
+	pub fn start_operations<F>(mut self, func: F) -> Self
+		where F: Fn(()) -> HrResult<()> + 'static,
+	{
+		self.start_operations = Some(Box::new(func));
+		self
+	}
+
+	/// [`IFileOperationProgressSink::FinishOperations`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperationprogresssink-finishoperations)
+	/// event, called after all operations are performed, receiving the final
+	/// result.
+	#[must_use]
+	pub fn finish_operations<F>(mut self, func: F) -> Self
+		where F: Fn(co::HRESULT) -> HrResult<()> + 'static,
+	{
+		self.finish_operations = Some(Box::new(func));
+		self
+	}
+
+	/// [`IFileOperationProgressSink::UpdateProgress`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperationprogresssink-updateprogress)
+	/// event, receiving the total amount of work, and the amount already
+	/// done.
+	#[must_use]
+	pub fn update_progress<F>(mut self, func: F) -> Self
+		where F: Fn((u32, u32)) -> HrResult<()> + 'static,
+	{
+		self.update_progress = Some(Box::new(func));
+		self
+	}
+
+	/// [`IFileOperationProgressSink::PostCopyItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperationprogresssink-postcopyitem)
+	/// event, receiving the source item, the newly created item, and the
+	/// result of the copy.
+	#[must_use]
+	pub fn post_copy_item<F>(mut self, func: F) -> Self
+		where F: Fn(&IShellItem, Option<&IShellItem>, co::HRESULT) -> HrResult<()> + 'static,
+	{
+		self.post_copy_item = Some(Box::new(func));
+		self
+	}
+
+	/// [`IFileOperationProgressSink::PostDeleteItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperationprogresssink-postdeleteitem)
+	/// event, receiving the deleted item, the item in the recycle bin (if
+	/// any), and the result of the deletion.
+	#[must_use]
+	pub fn post_delete_item<F>(mut self, func: F) -> Self
+		where F: Fn(&IShellItem, Option<&IShellItem>, co::HRESULT) -> HrResult<()> + 'static,
+	{
+		self.post_delete_item = Some(Box::new(func));
+		self
+	}
+
+	/// [`IFileOperationProgressSink::PostMoveItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperationprogresssink-postmoveitem)
+	/// event, receiving the source item, the item in its new location, and
+	/// the result of the move.
+	#[must_use]
+	pub fn post_move_item<F>(mut self, func: F) -> Self
+		where F: Fn(&IShellItem, Option<&IShellItem>, co::HRESULT) -> HrResult<()> + 'static,
+	{
+		self.post_move_item = Some(Box::new(func));
+		self
+	}
+
+	/// [`IFileOperationProgressSink::PostRenameItem`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ifileoperationprogresssink-postrenameitem)
+	/// event, receiving the original item, the renamed item, and the result
+	/// of the rename.
+	#[must_use]
+	pub fn post_rename_item<F>(mut self, func: F) -> Self
+		where F: Fn(&IShellItem, Option<&IShellItem>, co::HRESULT) -> HrResult<()> + 'static,
+	{
+		self.post_rename_item = Some(Box::new(func));
+		self
+	}
+
+	/// Creates the
+	/// [`IFileOperationProgressSink`](crate::IFileOperationProgressSink)
+	/// object.
+	#[must_use]
+	pub fn build(self) -> IFileOperationProgressSink {
+		let obj = Box::new(FileOperationProgressSinkImpl {
+			vt: &FILE_OPERATION_PROGRESS_SINK_VT,
+			ref_count: AtomicU32::new(1),
+			events: self,
+		});
+		IFileOperationProgressSink::from(ComPtr(Box::into_raw(obj) as _))
+	}
+}
+
+//------------------------------------------------------------------------------
+
+static FILE_OPERATION_PROGRESS_SINK_VT: IFileOperationProgressSinkVT = IFileOperationProgressSinkVT {
+	IUnknownVT: IUnknownVT {
+		QueryInterface: FileOperationProgressSinkImpl::QueryInterface,
+		AddRef: FileOperationProgressSinkImpl::AddRef,
+		Release: FileOperationProgressSinkImpl::Release,
+	},
+	StartOperations: FileOperationProgressSinkImpl::StartOperations,
+	FinishOperations: FileOperationProgressSinkImpl::FinishOperations,
+	PreRenameItem: FileOperationProgressSinkImpl::PreRenameItem,
+	PostRenameItem: FileOperationProgressSinkImpl::PostRenameItem,
+	PreMoveItem: FileOperationProgressSinkImpl::PreMoveItem,
+	PostMoveItem: FileOperationProgressSinkImpl::PostMoveItem,
+	PreCopyItem: FileOperationProgressSinkImpl::PreCopyItem,
+	PostCopyItem: FileOperationProgressSinkImpl::PostCopyItem,
+	PreDeleteItem: FileOperationProgressSinkImpl::PreDeleteItem,
+	PostDeleteItem: FileOperationProgressSinkImpl::PostDeleteItem,
+	PreNewItem: FileOperationProgressSinkImpl::PreNewItem,
+	PostNewItem: FileOperationProgressSinkImpl::PostNewItem,
+	UpdateProgress: FileOperationProgressSinkImpl::UpdateProgress,
+	ResetTimer: FileOperationProgressSinkImpl::ResetTimer,
+	PauseTimer: FileOperationProgressSinkImpl::PauseTimer,
+	ResumeTimer: FileOperationProgressSinkImpl::ResumeTimer,
+};
+
+/// Implementation of the `IFileOperationProgressSink` COM interface.
+#[repr(C)]
+struct FileOperationProgressSinkImpl {
+	vt: *const IFileOperationProgressSinkVT, // must be the first field, as in any COM object
+	ref_count: AtomicU32,
+	events: FileOperationProgressSink,
+}
+
+impl FileOperationProgressSinkImpl {
+	fn from_com_ptr<'a>(p: ComPtr) -> &'a Self {
+		unsafe { &*(p.0 as *const Self) }
+	}
+
+	/// Runs the closure, if any; by default, the operation proceeds. A panic
+	/// yields `E_UNEXPECTED`.
+	fn run<A>(func: &Callback<A>, arg: A) -> HRES {
+		catch_com_panic(|| {
+			match func.as_ref().map(|func| func(arg)) {
+				Some(Err(hr)) => hr.0,
+				_ => co::HRESULT::S_OK.0,
+			}
+		})
+	}
+
+	/// Runs the item closure, if any, passing the items which are not owned
+	/// by us. A panic yields `E_UNEXPECTED`.
+	fn run_item(func: &ItemCallback, item: ComPtr, new_item: ComPtr, hr: HRES) -> HRES {
+		catch_com_panic(|| {
+			if let Some(func) = func {
+				let item = ManuallyDrop::new(IShellItem::from(item)); // not owned by us
+				let new_item = if new_item.0.is_null() {
+					None
+				} else {
+					Some(ManuallyDrop::new(IShellItem::from(new_item)))
+				};
+				if let Err(hr) = func(&item, new_item.as_deref(), co::HRESULT(hr)) {
+					return hr.0;
+				}
+			}
+			co::HRESULT::S_OK.0
+		})
+	}
+
+	fn QueryInterface(p: ComPtr, riid: PCVOID, ppv: *mut ComPtr) -> HRES {
+		let riid = unsafe { &*(riid as *const co::IID) };
+		if *riid == IUnknown::IID || *riid == IFileOperationProgressSink::IID {
+			Self::AddRef(p);
+			unsafe { *ppv = p; }
+			co::HRESULT::S_OK.0
+		} else {
+			unsafe { *ppv = ComPtr::null(); }
+			co::HRESULT::E_NOINTERFACE.0
+		}
+	}
+
+	fn AddRef(p: ComPtr) -> u32 {
+		Self::from_com_ptr(p).ref_count.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn Release(p: ComPtr) -> u32 {
+		let count = Self::from_com_ptr(p).ref_count.fetch_sub(1, Ordering::Release) - 1;
+		if count == 0 {
+			fence(Ordering::Acquire); // see the drop of std::sync::Arc
+			let _ = unsafe { Box::from_raw(p.0 as *mut Self) };
+		}
+		count
+	}
+
+	fn StartOperations(p: ComPtr) -> HRES {
+		Self::run(&Self::from_com_ptr(p).events.start_operations, ())
+	}
+
+	fn FinishOperations(p: ComPtr, result: HRES) -> HRES {
+		Self::run(&Self::from_com_ptr(p).events.finish_operations, co::HRESULT(result))
+	}
+
+	fn PreRenameItem(_p: ComPtr, _flags: u32, _item: ComPtr, _new_name: PCSTR) -> HRES {
+		co::HRESULT::S_OK.0
+	}
+
+	fn PostRenameItem(p: ComPtr,
+		_flags: u32, item: ComPtr, _new_name: PCSTR, hr: HRES, new_item: ComPtr) -> HRES
+	{
+		Self::run_item(&Self::from_com_ptr(p).events.post_rename_item, item, new_item, hr)
+	}
+
+	fn PreMoveItem(_p: ComPtr,
+		_flags: u32, _item: ComPtr, _dest_folder: ComPtr, _new_name: PCSTR) -> HRES
+	{
+		co::HRESULT::S_OK.0
+	}
+
+	fn PostMoveItem(p: ComPtr,
+		_flags: u32, item: ComPtr, _dest_folder: ComPtr, _new_name: PCSTR,
+		hr: HRES, new_item: ComPtr) -> HRES
+	{
+		Self::run_item(&Self::from_com_ptr(p).events.post_move_item, item, new_item, hr)
+	}
+
+	fn PreCopyItem(_p: ComPtr,
+		_flags: u32, _item: ComPtr, _dest_folder: ComPtr, _new_name: PCSTR) -> HRES
+	{
+		co::HRESULT::S_OK.0
+	}
+
+	fn PostCopyItem(p: ComPtr,
+		_flags: u32, item: ComPtr, _dest_folder: ComPtr, _new_name: PCSTR,
+		hr: HRES, new_item: ComPtr) -> HRES
+	{
+		Self::run_item(&Self::from_com_ptr(p).events.post_copy_item, item, new_item, hr)
+	}
+
+	fn PreDeleteItem(_p: ComPtr, _flags: u32, _item: ComPtr) -> HRES {
+		co::HRESULT::S_OK.0
+	}
+
+	fn PostDeleteItem(p: ComPtr,
+		_flags: u32, item: ComPtr, hr: HRES, new_item: ComPtr) -> HRES
+	{
+		Self::run_item(&Self::from_com_ptr(p).events.post_delete_item, item, new_item, hr)
+	}
+
+	fn PreNewItem(_p: ComPtr, _flags: u32, _dest_folder: ComPtr, _new_name: PCSTR) -> HRES {
+		co::HRESULT::S_OK.0
+	}
+
+	fn PostNewItem(_p: ComPtr,
+		_flags: u32, _dest_folder: ComPtr, _new_name: PCSTR, _template_name: PCSTR,
+		_file_attributes: u32, _hr: HRES, _new_item: ComPtr) -> HRES
+	{
+		co::HRESULT::S_OK.0
+	}
+
+	fn UpdateProgress(p: ComPtr, work_total: u32, work_so_far: u32) -> HRES {
+		Self::run(&Self::from_com_ptr(p).events.update_progress, (work_total, work_so_far))
+	}
+
+	fn ResetTimer(_p: ComPtr) -> HRES {
+		co::HRESULT::S_OK.0
+	}
+
+	fn PauseTimer(_p: ComPtr) -> HRES {
+		co::HRESULT::S_OK.0
+	}
+
+	fn ResumeTimer(_p: ComPtr) -> HRES {
+		co::HRESULT::S_OK.0
+	}
+}
@@ -0,0 +1,7 @@
+mod file_dialog_events;
+mod file_operation_progress_sink;
+mod toast;
+
+pub use file_dialog_events::FileDialogEvents;
+pub use file_operation_progress_sink::FileOperationProgressSink;
+pub use toast::{ShownToast, Toast};
@@ -0,0 +1,349 @@
+#![allow(non_snake_case)]
+
+use std::sync::atomic::{AtomicU32, fence, Ordering};
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PCVOID, PVOID};
+use crate::ole::decl::{ComPtr, HrResult, IUnknown};
+use crate::ole::privs::{
+	catch_com_panic, HString, ok_to_hrresult, ro_activate_instance,
+	ro_get_activation_factory,
+};
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// Builder of a modern toast notification, shown through the WinRT
+/// [`ToastNotificationManager`](https://learn.microsoft.com/en-us/uwp/api/windows.ui.notifications.toastnotificationmanager),
+/// available on Windows 10 and later.
+///
+/// For the toast to appear, the application must have an
+/// [AppUserModelID](https://learn.microsoft.com/en-us/windows/win32/shell/appids),
+/// usually set with
+/// [`SetCurrentProcessExplicitAppUserModelID`](crate::SetCurrentProcessExplicitAppUserModelID),
+/// and a Start menu shortcut with this same ID.
+///
+/// COM must be initialized in the calling thread.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, CoInitializeEx, Toast};
+///
+/// let _com_guard = CoInitializeEx(
+///     co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+///
+/// let shown = Toast::new("Download finished", "foo.zip was saved.")
+///     .image("C:\\Temp\\foo.png")
+///     .on_activated(|| println!("Toast clicked."))
+///     .show("MyCompany.MyApp")?;
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+pub struct Toast {
+	title: String,
+	body: String,
+	image: Option<String>,
+	activated_fn: Option<Box<dyn Fn() + Send + Sync>>,
+}
+
+impl Toast {
+	/// Creates a new builder with the given title and body text.
+	#[must_use]
+	pub fn new(title: &str, body: &str) -> Self {
+		Self {
+			title: title.to_owned(),
+			body: body.to_owned(),
+			image: None,
+			activated_fn: None,
+		}
+	}
+
+	/// Sets an image to be displayed along with the text, either a file path
+	/// or an URI.
+	#[must_use]
+	pub fn image(mut self, path_or_uri: &str) -> Self {
+		self.image = Some(path_or_uri.to_owned());
+		self
+	}
+
+	/// Sets the closure to be called when the user clicks the toast.
+	///
+	/// The closure is called from a thread pool thread, not from the thread
+	/// which called [`show`](crate::Toast::show).
+	#[must_use]
+	pub fn on_activated<F>(mut self, func: F) -> Self
+		where F: Fn() + Send + Sync + 'static,
+	{
+		self.activated_fn = Some(Box::new(func));
+		self
+	}
+
+	/// Shows the toast for the given AppUserModelID.
+	///
+	/// Fails with [`co::HRESULT::E_NOTIMPL`](crate::co::HRESULT::E_NOTIMPL)
+	/// if WinRT is not available, what happens before Windows 8.
+	pub fn show(self, app_id: &str) -> HrResult<ShownToast> {
+		let statics = ro_get_activation_factory::<IToastNotificationManagerStatics>(
+			"Windows.UI.Notifications.ToastNotificationManager")?;
+		let app_id = HString::new(app_id)?;
+		let notifier = unsafe {
+			let mut ppv = ComPtr::null();
+			let vt = statics.vt_ref::<IToastNotificationManagerStaticsVT>();
+			ok_to_hrresult(
+				(vt.CreateToastNotifierWithId)(statics.ptr(), app_id.as_ptr(), &mut ppv),
+			).map(|_| IToastNotifier::from(ppv))
+		}?;
+
+		let xml_doc = ro_activate_instance("Windows.Data.Xml.Dom.XmlDocument")?;
+		let xml_io = xml_doc.QueryInterface::<IXmlDocumentIO>()?;
+		let xml = HString::new(&self.xml())?;
+		unsafe {
+			let vt = xml_io.vt_ref::<IXmlDocumentIOVT>();
+			ok_to_hrresult((vt.LoadXml)(xml_io.ptr(), xml.as_ptr()))?;
+		}
+		let xml_doc = xml_doc.QueryInterface::<IXmlDocument>()?;
+
+		let factory = ro_get_activation_factory::<IToastNotificationFactory>(
+			"Windows.UI.Notifications.ToastNotification")?;
+		let notification = unsafe {
+			let mut ppv = ComPtr::null();
+			let vt = factory.vt_ref::<IToastNotificationFactoryVT>();
+			ok_to_hrresult(
+				(vt.CreateToastNotification)(factory.ptr(), xml_doc.ptr(), &mut ppv),
+			).map(|_| IToastNotification::from(ppv))
+		}?;
+
+		let mut activated_token = None;
+		if let Some(func) = self.activated_fn {
+			let handler = ActivatedHandlerImpl::new_obj(func);
+			let mut token = i64::default();
+			unsafe {
+				let vt = notification.vt_ref::<IToastNotificationVT>();
+				ok_to_hrresult(
+					(vt.add_Activated)(notification.ptr(), handler.ptr(), &mut token),
+				)?;
+			}
+			activated_token = Some(token);
+		}
+
+		unsafe {
+			let vt = notifier.vt_ref::<IToastNotifierVT>();
+			ok_to_hrresult((vt.Show)(notifier.ptr(), notification.ptr()))?;
+		}
+		Ok(ShownToast { notifier, notification, activated_token })
+	}
+
+	fn xml(&self) -> String {
+		let image = match &self.image {
+			Some(image) => format!(
+				"<image placement=\"appLogoOverride\" src=\"{}\"/>",
+				escape_xml(&if image.contains("://") {
+					image.clone()
+				} else {
+					format!("file:///{}", image.replace('\\', "/"))
+				}),
+			),
+			None => String::default(),
+		};
+		format!(
+			"<toast><visual><binding template=\"ToastGeneric\">\
+				<text>{}</text><text>{}</text>{}\
+			</binding></visual></toast>",
+			escape_xml(&self.title),
+			escape_xml(&self.body),
+			image,
+		)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A toast notification being displayed, returned by
+/// [`Toast::show`](crate::Toast::show).
+///
+/// The activation closure is called only while this object is alive. Dropping
+/// it doesn't hide the toast.
+pub struct ShownToast {
+	notifier: IToastNotifier,
+	notification: IToastNotification,
+	activated_token: Option<i64>,
+}
+
+impl Drop for ShownToast {
+	fn drop(&mut self) {
+		if let Some(token) = self.activated_token {
+			unsafe {
+				let vt = self.notification.vt_ref::<IToastNotificationVT>();
+				(vt.remove_Activated)(self.notification.ptr(), token); // ignore errors
+			}
+		}
+	}
+}
+
+impl ShownToast {
+	/// Removes the toast from the screen.
+	pub fn hide(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.notifier.vt_ref::<IToastNotifierVT>();
+			ok_to_hrresult((vt.Hide)(self.notifier.ptr(), self.notification.ptr()))
+		}
+	}
+}
+
+fn escape_xml(s: &str) -> String {
+	s.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+		.replace('"', "&quot;")
+		.replace('\'', "&apos;")
+}
+
+//------------------------------------------------------------------------------
+
+// WinRT interfaces used internally, over IInspectable.
+
+#[repr(C)]
+struct IInspectableVT {
+	IUnknownVT: IUnknownVT,
+	GetIids: fn(ComPtr, *mut u32, *mut PVOID) -> HRES,
+	GetRuntimeClassName: fn(ComPtr, *mut PVOID) -> HRES,
+	GetTrustLevel: fn(ComPtr, *mut u32) -> HRES,
+}
+
+#[repr(C)]
+struct IToastNotificationManagerStaticsVT {
+	IInspectableVT: IInspectableVT,
+	CreateToastNotifier: fn(ComPtr, *mut ComPtr) -> HRES,
+	CreateToastNotifierWithId: fn(ComPtr, PVOID, *mut ComPtr) -> HRES,
+	GetTemplateContent: fn(ComPtr, u32, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IToastNotificationManagerStatics: "50ac103f-d235-4598-bbef-98fe4d1a3ad4"; }
+
+#[repr(C)]
+struct IToastNotifierVT {
+	IInspectableVT: IInspectableVT,
+	Show: fn(ComPtr, ComPtr) -> HRES,
+	Hide: fn(ComPtr, ComPtr) -> HRES,
+	get_Setting: fn(ComPtr, *mut u32) -> HRES,
+	AddToSchedule: fn(ComPtr, ComPtr) -> HRES,
+	RemoveFromSchedule: fn(ComPtr, ComPtr) -> HRES,
+	GetScheduledToastNotifications: fn(ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IToastNotifier: "75927b93-03f3-41ec-91d3-6e5bac1b38e7"; }
+
+#[repr(C)]
+struct IToastNotificationFactoryVT {
+	IInspectableVT: IInspectableVT,
+	CreateToastNotification: fn(ComPtr, ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IToastNotificationFactory: "04124b20-82c6-4229-b109-fd9ed4662b53"; }
+
+#[repr(C)]
+struct IToastNotificationVT {
+	IInspectableVT: IInspectableVT,
+	get_Content: fn(ComPtr, *mut ComPtr) -> HRES,
+	put_ExpirationTime: fn(ComPtr, ComPtr) -> HRES,
+	get_ExpirationTime: fn(ComPtr, *mut ComPtr) -> HRES,
+	add_Dismissed: fn(ComPtr, ComPtr, *mut i64) -> HRES,
+	remove_Dismissed: fn(ComPtr, i64) -> HRES,
+	add_Activated: fn(ComPtr, ComPtr, *mut i64) -> HRES,
+	remove_Activated: fn(ComPtr, i64) -> HRES,
+	add_Failed: fn(ComPtr, ComPtr, *mut i64) -> HRES,
+	remove_Failed: fn(ComPtr, i64) -> HRES,
+}
+
+com_interface! { IToastNotification: "997e2675-059e-4e60-8b06-1760917c8b80"; }
+
+#[repr(C)]
+struct IXmlDocumentIOVT {
+	IInspectableVT: IInspectableVT,
+	LoadXml: fn(ComPtr, PVOID) -> HRES,
+	LoadXmlWithSettings: fn(ComPtr, PVOID, ComPtr) -> HRES,
+	SaveToFileAsync: fn(ComPtr, ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IXmlDocumentIO: "6cd0e74e-ee65-4489-9ebf-ca43e87ba637"; }
+
+com_interface! { IXmlDocument: "f7f3a506-1e87-42d6-bcfb-b8c809fa5494"; }
+
+//------------------------------------------------------------------------------
+
+/// `TypedEventHandler<ToastNotification, IInspectable>` virtual table.
+#[repr(C)]
+struct ActivatedHandlerVT {
+	IUnknownVT: IUnknownVT,
+	Invoke: fn(ComPtr, ComPtr, ComPtr) -> HRES,
+}
+
+com_interface! { ActivatedHandler: "ab54de2d-97d9-5528-b6ad-105afe156530"; }
+
+/// `IAgileObject` interface ID, which tells the event handler can be called
+/// from any thread.
+const IID_IAGILEOBJECT: co::IID = co::IID::new("94ea2b94-e9cc-49e0-c0ff-ee64ca8f5b90");
+
+static ACTIVATED_HANDLER_VT: ActivatedHandlerVT = ActivatedHandlerVT {
+	IUnknownVT: IUnknownVT {
+		QueryInterface: ActivatedHandlerImpl::QueryInterface,
+		AddRef: ActivatedHandlerImpl::AddRef,
+		Release: ActivatedHandlerImpl::Release,
+	},
+	Invoke: ActivatedHandlerImpl::Invoke,
+};
+
+/// Implementation of the `Activated` event handler of a toast.
+#[repr(C)]
+struct ActivatedHandlerImpl {
+	vt: *const ActivatedHandlerVT, // must be the first field, as in any COM object
+	ref_count: AtomicU32,
+	func: Box<dyn Fn() + Send + Sync>,
+}
+
+impl ActivatedHandlerImpl {
+	fn new_obj(func: Box<dyn Fn() + Send + Sync>) -> ActivatedHandler {
+		let obj = Box::new(Self {
+			vt: &ACTIVATED_HANDLER_VT,
+			ref_count: AtomicU32::new(1),
+			func,
+		});
+		ActivatedHandler::from(ComPtr(Box::into_raw(obj) as _))
+	}
+
+	fn from_com_ptr<'a>(p: ComPtr) -> &'a Self {
+		unsafe { &*(p.0 as *const Self) }
+	}
+
+	fn QueryInterface(p: ComPtr, riid: PCVOID, ppv: *mut ComPtr) -> HRES {
+		let riid = unsafe { &*(riid as *const co::IID) };
+		if *riid == IUnknown::IID || *riid == ActivatedHandler::IID || *riid == IID_IAGILEOBJECT {
+			Self::AddRef(p);
+			unsafe { *ppv = p; }
+			co::HRESULT::S_OK.0
+		} else {
+			unsafe { *ppv = ComPtr::null(); }
+			co::HRESULT::E_NOINTERFACE.0
+		}
+	}
+
+	fn AddRef(p: ComPtr) -> u32 {
+		Self::from_com_ptr(p).ref_count.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn Release(p: ComPtr) -> u32 {
+		let count = Self::from_com_ptr(p).ref_count.fetch_sub(1, Ordering::Release) - 1;
+		if count == 0 {
+			fence(Ordering::Acquire); // see the drop of std::sync::Arc
+			let _ = unsafe { Box::from_raw(p.0 as *mut Self) };
+		}
+		count
+	}
+
+	fn Invoke(p: ComPtr, _sender: ComPtr, _args: ComPtr) -> HRES {
+		catch_com_panic(|| {
+			(Self::from_com_ptr(p).func)();
+			co::HRESULT::S_OK.0
+		})
+	}
+}
@@ -0,0 +1,18 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+use crate::co::CLSID;
+
+const_guid_values! { CLSID;
+	SpellCheckerFactory "7ab36653-1796-484b-bdfa-e74f1db7c1dc"
+}
+
+const_ordinary! { CORRECTIVE_ACTION: u32;
+	/// [`CORRECTIVE_ACTION`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/ne-spellcheck-corrective_action)
+	/// enumeration (`u32`).
+	=>
+	=>
+	NONE 0
+	GET_SUGGESTIONS 1
+	REPLACE 2
+	DELETE 3
+}
@@ -0,0 +1,113 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::spellcheck::decl::ISpellingError;
+use crate::vt::IUnknownVT;
+
+/// [`IEnumSpellingError`](crate::IEnumSpellingError) virtual table.
+#[repr(C)]
+pub struct IEnumSpellingErrorVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Next: fn(ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IEnumSpellingError: "803e3bd4-2828-4410-8290-418d1d73c762";
+	/// [`IEnumSpellingError`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nn-spellcheck-ienumspellingerror)
+	/// COM interface over
+	/// [`IEnumSpellingErrorVT`](crate::vt::IEnumSpellingErrorVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl spellcheck_IEnumSpellingError for IEnumSpellingError {}
+
+/// This trait is enabled with the `spellcheck` feature, and provides methods for
+/// [`IEnumSpellingError`](crate::IEnumSpellingError).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait spellcheck_IEnumSpellingError: ole_IUnknown {
+	/// Returns an iterator over the [`ISpellingError`](crate::ISpellingError)
+	/// elements which calls
+	/// [`IEnumSpellingError::Next`](crate::prelude::spellcheck_IEnumSpellingError::Next)
+	/// internally.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IEnumSpellingError};
+	///
+	/// let errors: IEnumSpellingError; // initialized somewhere
+	/// # let errors = IEnumSpellingError::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// for err in errors.iter() {
+	///     let err = err?;
+	///     println!("{} {}", err.get_StartIndex()?, err.get_Length()?);
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<ISpellingError>> + '_> {
+		Box::new(EnumSpellingErrorIter::new(self))
+	}
+
+	/// [`IEnumSpellingError::Next`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ienumspellingerror-next)
+	/// method.
+	///
+	/// Prefer using
+	/// [`IEnumSpellingError::iter`](crate::prelude::spellcheck_IEnumSpellingError::iter),
+	/// which is simpler.
+	#[must_use]
+	fn Next(&self) -> HrResult<Option<ISpellingError>> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumSpellingErrorVT>();
+			match ok_to_hrresult((vt.Next)(self.ptr(), &mut ppv_queried)) {
+				Ok(_) => Ok(Some(ISpellingError::from(ppv_queried))),
+				Err(hr) => match hr {
+					co::HRESULT::S_FALSE => Ok(None), // no item found
+					hr => Err(hr), // actual error
+				},
+			}
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct EnumSpellingErrorIter<'a, I>
+	where I: spellcheck_IEnumSpellingError,
+{
+	enum_err: &'a I,
+}
+
+impl<'a, I> Iterator for EnumSpellingErrorIter<'a, I>
+	where I: spellcheck_IEnumSpellingError,
+{
+	type Item = HrResult<ISpellingError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.enum_err.Next() {
+			Err(err) => Some(Err(err)),
+			Ok(maybe_err) => maybe_err.map(Ok),
+		}
+	}
+}
+
+impl<'a, I> EnumSpellingErrorIter<'a, I>
+	where I: spellcheck_IEnumSpellingError,
+{
+	fn new(enum_err: &'a I) -> Self {
+		Self { enum_err }
+	}
+}
@@ -0,0 +1,235 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{HRES, PCSTR};
+use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult, IEnumString};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::prelude::ole_IUnknown;
+use crate::spellcheck::decl::IEnumSpellingError;
+use crate::vt::IUnknownVT;
+
+/// [`ISpellChecker`](crate::ISpellChecker) virtual table.
+#[repr(C)]
+pub struct ISpellCheckerVT {
+	pub IUnknownVT: IUnknownVT,
+	pub get_LanguageTag: fn(ComPtr, *mut *mut u16) -> HRES,
+	pub Check: fn(ComPtr, PCSTR, *mut ComPtr) -> HRES,
+	pub Suggest: fn(ComPtr, PCSTR, *mut ComPtr) -> HRES,
+	pub Add: fn(ComPtr, PCSTR) -> HRES,
+	pub Ignore: fn(ComPtr, PCSTR) -> HRES,
+	pub AutoCorrect: fn(ComPtr, PCSTR, PCSTR) -> HRES,
+	pub GetOptionValue: fn(ComPtr, PCSTR, *mut u8) -> HRES,
+	pub get_OptionIds: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_Id: fn(ComPtr, *mut *mut u16) -> HRES,
+	pub get_LocalizedName: fn(ComPtr, *mut *mut u16) -> HRES,
+	pub add_SpellCheckerChanged: fn(ComPtr, ComPtr, *mut u32) -> HRES,
+	pub remove_SpellCheckerChanged: fn(ComPtr, u32) -> HRES,
+	pub GetOptionDescription: fn(ComPtr, PCSTR, *mut ComPtr) -> HRES,
+	pub ComprehensiveCheck: fn(ComPtr, PCSTR, *mut ComPtr) -> HRES,
+}
+
+com_interface! { ISpellChecker: "b6fd0b71-e2bc-4653-8d05-f197e412770b";
+	/// [`ISpellChecker`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nn-spellcheck-ispellchecker)
+	/// COM interface over [`ISpellCheckerVT`](crate::vt::ISpellCheckerVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// Listing the misspelled words of a text, with their suggestions:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, ISpellCheckerFactory};
+	///
+	/// let factory = CoCreateInstance::<ISpellCheckerFactory>(
+	///     &co::CLSID::SpellCheckerFactory,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// let checker = factory.CreateSpellChecker("en-US")?;
+	///
+	/// let text = "Ths is a tset.";
+	/// let text16 = text.encode_utf16().collect::<Vec<_>>();
+	///
+	/// for err in checker.Check(text)?.iter() {
+	///     let err = err?;
+	///     let start = err.get_StartIndex()? as usize;
+	///     let len = err.get_Length()? as usize;
+	///     let word = String::from_utf16_lossy(&text16[start..start + len]);
+	///
+	///     let suggestions = checker.Suggest(&word)?
+	///         .iter()
+	///         .collect::<Result<Vec<_>, _>>()?;
+	///     println!("{}: {}", word, suggestions.join(", "));
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl spellcheck_ISpellChecker for ISpellChecker {}
+
+/// This trait is enabled with the `spellcheck` feature, and provides methods for
+/// [`ISpellChecker`](crate::ISpellChecker).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait spellcheck_ISpellChecker: ole_IUnknown {
+	/// [`ISpellChecker::Add`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellchecker-add)
+	/// method.
+	fn Add(&self, word: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpellCheckerVT>();
+			ok_to_hrresult((vt.Add)(self.ptr(), WString::from_str(word).as_ptr()))
+		}
+	}
+
+	/// [`ISpellChecker::AutoCorrect`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellchecker-autocorrect)
+	/// method.
+	fn AutoCorrect(&self, from: &str, to: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpellCheckerVT>();
+			ok_to_hrresult(
+				(vt.AutoCorrect)(
+					self.ptr(),
+					WString::from_str(from).as_ptr(),
+					WString::from_str(to).as_ptr(),
+				),
+			)
+		}
+	}
+
+	/// [`ISpellChecker::Check`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellchecker-check)
+	/// method.
+	#[must_use]
+	fn Check(&self, text: &str) -> HrResult<IEnumSpellingError> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ISpellCheckerVT>();
+			ok_to_hrresult(
+				(vt.Check)(self.ptr(), WString::from_str(text).as_ptr(), &mut ppv_queried),
+			).map(|_| IEnumSpellingError::from(ppv_queried))
+		}
+	}
+
+	/// [`ISpellChecker::ComprehensiveCheck`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellchecker-comprehensivecheck)
+	/// method.
+	///
+	/// Slower than
+	/// [`ISpellChecker::Check`](crate::prelude::spellcheck_ISpellChecker::Check),
+	/// but also reports errors ignored by it.
+	#[must_use]
+	fn ComprehensiveCheck(&self, text: &str) -> HrResult<IEnumSpellingError> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ISpellCheckerVT>();
+			ok_to_hrresult(
+				(vt.ComprehensiveCheck)(self.ptr(), WString::from_str(text).as_ptr(), &mut ppv_queried),
+			).map(|_| IEnumSpellingError::from(ppv_queried))
+		}
+	}
+
+	/// [`ISpellChecker::get_Id`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellchecker-get_id)
+	/// method.
+	#[must_use]
+	fn get_Id(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<ISpellCheckerVT>();
+			ok_to_hrresult((vt.get_Id)(self.ptr(), &mut pstr))
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// [`ISpellChecker::get_LanguageTag`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellchecker-get_languagetag)
+	/// method.
+	#[must_use]
+	fn get_LanguageTag(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<ISpellCheckerVT>();
+			ok_to_hrresult((vt.get_LanguageTag)(self.ptr(), &mut pstr))
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// [`ISpellChecker::get_LocalizedName`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellchecker-get_localizedname)
+	/// method.
+	#[must_use]
+	fn get_LocalizedName(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<ISpellCheckerVT>();
+			ok_to_hrresult((vt.get_LocalizedName)(self.ptr(), &mut pstr))
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// [`ISpellChecker::get_OptionIds`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellchecker-get_optionids)
+	/// method.
+	#[must_use]
+	fn get_OptionIds(&self) -> HrResult<IEnumString> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ISpellCheckerVT>();
+			ok_to_hrresult((vt.get_OptionIds)(self.ptr(), &mut ppv_queried))
+				.map(|_| IEnumString::from(ppv_queried))
+		}
+	}
+
+	/// [`ISpellChecker::GetOptionValue`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellchecker-getoptionvalue)
+	/// method.
+	#[must_use]
+	fn GetOptionValue(&self, option_id: &str) -> HrResult<u8> {
+		let mut val = u8::default();
+		unsafe {
+			let vt = self.vt_ref::<ISpellCheckerVT>();
+			ok_to_hrresult(
+				(vt.GetOptionValue)(
+					self.ptr(),
+					WString::from_str(option_id).as_ptr(),
+					&mut val,
+				),
+			)
+		}.map(|_| val)
+	}
+
+	/// [`ISpellChecker::Ignore`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellchecker-ignore)
+	/// method.
+	fn Ignore(&self, word: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ISpellCheckerVT>();
+			ok_to_hrresult((vt.Ignore)(self.ptr(), WString::from_str(word).as_ptr()))
+		}
+	}
+
+	/// [`ISpellChecker::Suggest`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellchecker-suggest)
+	/// method.
+	///
+	/// If the word is spelled correctly, the returned enumerator contains only
+	/// the word itself.
+	#[must_use]
+	fn Suggest(&self, word: &str) -> HrResult<IEnumString> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ISpellCheckerVT>();
+			okfalse_to_hrresult( // S_FALSE if the word is spelled correctly
+				(vt.Suggest)(self.ptr(), WString::from_str(word).as_ptr(), &mut ppv_queried),
+			).map(|_| IEnumString::from(ppv_queried))
+		}
+	}
+}
@@ -0,0 +1,108 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{BOOL, HRES, PCSTR};
+use crate::ole::decl::{ComPtr, HrResult, IEnumString};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::spellcheck::decl::ISpellChecker;
+use crate::vt::IUnknownVT;
+
+/// [`ISpellCheckerFactory`](crate::ISpellCheckerFactory) virtual table.
+#[repr(C)]
+pub struct ISpellCheckerFactoryVT {
+	pub IUnknownVT: IUnknownVT,
+	pub get_SupportedLanguages: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub IsSupported: fn(ComPtr, PCSTR, *mut BOOL) -> HRES,
+	pub CreateSpellChecker: fn(ComPtr, PCSTR, *mut ComPtr) -> HRES,
+}
+
+com_interface! { ISpellCheckerFactory: "8e018a9d-2415-4677-bf08-794ea61f94bb";
+	/// [`ISpellCheckerFactory`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nn-spellcheck-ispellcheckerfactory)
+	/// COM interface over
+	/// [`ISpellCheckerFactoryVT`](crate::vt::ISpellCheckerFactoryVT).
+	///
+	/// Available on Windows 8 and later.
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, ISpellCheckerFactory};
+	///
+	/// let factory = CoCreateInstance::<ISpellCheckerFactory>(
+	///     &co::CLSID::SpellCheckerFactory,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	///
+	/// for lang in factory.get_SupportedLanguages()?.iter() {
+	///     println!("{}", lang?);
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl spellcheck_ISpellCheckerFactory for ISpellCheckerFactory {}
+
+/// This trait is enabled with the `spellcheck` feature, and provides methods for
+/// [`ISpellCheckerFactory`](crate::ISpellCheckerFactory).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait spellcheck_ISpellCheckerFactory: ole_IUnknown {
+	/// [`ISpellCheckerFactory::CreateSpellChecker`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellcheckerfactory-createspellchecker)
+	/// method.
+	///
+	/// The language is given as a BCP47 tag, like `"en-US"`.
+	#[must_use]
+	fn CreateSpellChecker(&self, language_tag: &str) -> HrResult<ISpellChecker> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ISpellCheckerFactoryVT>();
+			ok_to_hrresult(
+				(vt.CreateSpellChecker)(
+					self.ptr(),
+					WString::from_str(language_tag).as_ptr(),
+					&mut ppv_queried,
+				),
+			).map(|_| ISpellChecker::from(ppv_queried))
+		}
+	}
+
+	/// [`ISpellCheckerFactory::get_SupportedLanguages`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellcheckerfactory-get_supportedlanguages)
+	/// method.
+	#[must_use]
+	fn get_SupportedLanguages(&self) -> HrResult<IEnumString> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ISpellCheckerFactoryVT>();
+			ok_to_hrresult((vt.get_SupportedLanguages)(self.ptr(), &mut ppv_queried))
+				.map(|_| IEnumString::from(ppv_queried))
+		}
+	}
+
+	/// [`ISpellCheckerFactory::IsSupported`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellcheckerfactory-issupported)
+	/// method.
+	#[must_use]
+	fn IsSupported(&self, language_tag: &str) -> HrResult<bool> {
+		let mut supported: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<ISpellCheckerFactoryVT>();
+			ok_to_hrresult(
+				(vt.IsSupported)(
+					self.ptr(),
+					WString::from_str(language_tag).as_ptr(),
+					&mut supported,
+				),
+			)
+		}.map(|_| supported != 0)
+	}
+}
@@ -0,0 +1,96 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, CoTaskMemFree, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`ISpellingError`](crate::ISpellingError) virtual table.
+#[repr(C)]
+pub struct ISpellingErrorVT {
+	pub IUnknownVT: IUnknownVT,
+	pub get_StartIndex: fn(ComPtr, *mut u32) -> HRES,
+	pub get_Length: fn(ComPtr, *mut u32) -> HRES,
+	pub get_CorrectiveAction: fn(ComPtr, *mut u32) -> HRES,
+	pub get_Replacement: fn(ComPtr, *mut *mut u16) -> HRES,
+}
+
+com_interface! { ISpellingError: "b7c82d61-fbe8-4b47-9b27-6c0d2e0de0a3";
+	/// [`ISpellingError`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nn-spellcheck-ispellingerror)
+	/// COM interface over [`ISpellingErrorVT`](crate::vt::ISpellingErrorVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl spellcheck_ISpellingError for ISpellingError {}
+
+/// This trait is enabled with the `spellcheck` feature, and provides methods for
+/// [`ISpellingError`](crate::ISpellingError).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait spellcheck_ISpellingError: ole_IUnknown {
+	/// [`ISpellingError::get_CorrectiveAction`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellingerror-get_correctiveaction)
+	/// method.
+	#[must_use]
+	fn get_CorrectiveAction(&self) -> HrResult<co::CORRECTIVE_ACTION> {
+		let mut action = co::CORRECTIVE_ACTION::default();
+		unsafe {
+			let vt = self.vt_ref::<ISpellingErrorVT>();
+			ok_to_hrresult((vt.get_CorrectiveAction)(self.ptr(), &mut action.0))
+		}.map(|_| action)
+	}
+
+	/// [`ISpellingError::get_Length`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellingerror-get_length)
+	/// method.
+	///
+	/// The length is given in UTF-16 code units.
+	#[must_use]
+	fn get_Length(&self) -> HrResult<u32> {
+		let mut len = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<ISpellingErrorVT>();
+			ok_to_hrresult((vt.get_Length)(self.ptr(), &mut len))
+		}.map(|_| len)
+	}
+
+	/// [`ISpellingError::get_Replacement`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellingerror-get_replacement)
+	/// method.
+	///
+	/// Meaningful only if the corrective action is
+	/// [`co::CORRECTIVE_ACTION::REPLACE`](crate::co::CORRECTIVE_ACTION::REPLACE).
+	#[must_use]
+	fn get_Replacement(&self) -> HrResult<String> {
+		let mut pstr: *mut u16 = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<ISpellingErrorVT>();
+			ok_to_hrresult((vt.get_Replacement)(self.ptr(), &mut pstr))
+		}.map(|_| {
+			let text = WString::from_wchars_nullt(pstr);
+			CoTaskMemFree(pstr as _);
+			text.to_string()
+		})
+	}
+
+	/// [`ISpellingError::get_StartIndex`](https://learn.microsoft.com/en-us/windows/win32/api/spellcheck/nf-spellcheck-ispellingerror-get_startindex)
+	/// method.
+	///
+	/// The index is given in UTF-16 code units, relative to the text passed
+	/// to [`ISpellChecker::Check`](crate::prelude::spellcheck_ISpellChecker::Check).
+	#[must_use]
+	fn get_StartIndex(&self) -> HrResult<u32> {
+		let mut idx = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<ISpellingErrorVT>();
+			ok_to_hrresult((vt.get_StartIndex)(self.ptr(), &mut idx))
+		}.map(|_| idx)
+	}
+}
@@ -0,0 +1,25 @@
+mod ienumspellingerror;
+mod ispellchecker;
+mod ispellcheckerfactory;
+mod ispellingerror;
+
+pub mod decl {
+	pub use super::ienumspellingerror::IEnumSpellingError;
+	pub use super::ispellchecker::ISpellChecker;
+	pub use super::ispellcheckerfactory::ISpellCheckerFactory;
+	pub use super::ispellingerror::ISpellingError;
+}
+
+pub mod traits {
+	pub use super::ienumspellingerror::spellcheck_IEnumSpellingError;
+	pub use super::ispellchecker::spellcheck_ISpellChecker;
+	pub use super::ispellcheckerfactory::spellcheck_ISpellCheckerFactory;
+	pub use super::ispellingerror::spellcheck_ISpellingError;
+}
+
+pub mod vt {
+	pub use super::ienumspellingerror::IEnumSpellingErrorVT;
+	pub use super::ispellchecker::ISpellCheckerVT;
+	pub use super::ispellcheckerfactory::ISpellCheckerFactoryVT;
+	pub use super::ispellingerror::ISpellingErrorVT;
+}
@@ -0,0 +1,167 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::{BSTR, VARIANT};
+use crate::prelude::{oleaut_IDispatch, oleaut_Variant};
+use crate::taskschd::decl::IAction;
+use crate::vt::IDispatchVT;
+
+/// [`IActionCollection`](crate::IActionCollection) virtual table.
+#[repr(C)]
+pub struct IActionCollectionVT {
+	pub IDispatchVT: IDispatchVT,
+	pub get_Count: fn(ComPtr, *mut i32) -> HRES,
+	pub get_Item: fn(ComPtr, i32, *mut ComPtr) -> HRES,
+	pub get__NewEnum: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_XmlText: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_XmlText: fn(ComPtr, PSTR) -> HRES,
+	pub Create: fn(ComPtr, u32, *mut ComPtr) -> HRES,
+	pub Remove: fn(ComPtr, VARIANT) -> HRES,
+	pub Clear: fn(ComPtr) -> HRES,
+	pub get_Context: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_Context: fn(ComPtr, PSTR) -> HRES,
+}
+
+com_interface! { IActionCollection: "02820e19-7b98-4ed2-b2e8-fdccceff619b";
+	/// [`IActionCollection`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nn-taskschd-iactioncollection)
+	/// COM interface over
+	/// [`IActionCollectionVT`](crate::vt::IActionCollectionVT).
+	///
+	/// Actions of specific types are retrieved by calling
+	/// [`QueryInterface`](crate::prelude::ole_IUnknown::QueryInterface) on the
+	/// [`IAction`](crate::IAction) objects.
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for IActionCollection {}
+impl taskschd_IActionCollection for IActionCollection {}
+
+/// This trait is enabled with the `taskschd` feature, and provides methods for
+/// [`IActionCollection`](crate::IActionCollection).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait taskschd_IActionCollection: oleaut_IDispatch {
+	/// [`IActionCollection::Clear`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iactioncollection-clear)
+	/// method.
+	fn Clear(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IActionCollectionVT>();
+			ok_to_hrresult((vt.Clear)(self.ptr()))
+		}
+	}
+
+	/// [`IActionCollection::Create`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iactioncollection-create)
+	/// method.
+	#[must_use]
+	fn Create(&self, action_type: co::TASK_ACTION_TYPE) -> HrResult<IAction> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IActionCollectionVT>();
+			ok_to_hrresult((vt.Create)(self.ptr(), action_type.0, &mut ppv_queried))
+				.map(|_| IAction::from(ppv_queried))
+		}
+	}
+
+	/// [`IActionCollection::get_Context`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iactioncollection-get_context)
+	/// method.
+	#[must_use]
+	fn get_Context(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IActionCollectionVT>();
+			ok_to_hrresult((vt.get_Context)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IActionCollection::get_Count`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iactioncollection-get_count)
+	/// method.
+	#[must_use]
+	fn get_Count(&self) -> HrResult<i32> {
+		let mut val = i32::default();
+		unsafe {
+			let vt = self.vt_ref::<IActionCollectionVT>();
+			ok_to_hrresult((vt.get_Count)(self.ptr(), &mut val))
+		}.map(|_| val)
+	}
+
+	/// [`IActionCollection::get_Item`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iactioncollection-get_item)
+	/// method.
+	///
+	/// The index is one-based.
+	#[must_use]
+	fn get_Item(&self, index: i32) -> HrResult<IAction> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IActionCollectionVT>();
+			ok_to_hrresult((vt.get_Item)(self.ptr(), index, &mut ppv_queried))
+				.map(|_| IAction::from(ppv_queried))
+		}
+	}
+
+	/// [`IActionCollection::get_XmlText`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iactioncollection-get_xmltext)
+	/// method.
+	#[must_use]
+	fn get_XmlText(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IActionCollectionVT>();
+			ok_to_hrresult((vt.get_XmlText)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// Returns an iterator over the [`IAction`](crate::IAction) elements,
+	/// which calls
+	/// [`IActionCollection::get_Count`](crate::prelude::taskschd_IActionCollection::get_Count)
+	/// and
+	/// [`IActionCollection::get_Item`](crate::prelude::taskschd_IActionCollection::get_Item)
+	/// internally.
+	#[must_use]
+	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<IAction>> + '_> {
+		match self.get_Count() {
+			Ok(count) => Box::new((1..=count).map(|i| self.get_Item(i))),
+			Err(e) => Box::new(std::iter::once(Err(e))),
+		}
+	}
+
+	/// [`IActionCollection::put_Context`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iactioncollection-put_context)
+	/// method.
+	fn put_Context(&self, context: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(context)?;
+		unsafe {
+			let vt = self.vt_ref::<IActionCollectionVT>();
+			ok_to_hrresult((vt.put_Context)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IActionCollection::put_XmlText`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iactioncollection-put_xmltext)
+	/// method.
+	fn put_XmlText(&self, xml: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(xml)?;
+		unsafe {
+			let vt = self.vt_ref::<IActionCollectionVT>();
+			ok_to_hrresult((vt.put_XmlText)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IActionCollection::Remove`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iactioncollection-remove)
+	/// method.
+	///
+	/// The index is one-based.
+	fn Remove(&self, index: i32) -> HrResult<()> {
+		let var_index = VARIANT::new_i32(index);
+		unsafe {
+			let vt = self.vt_ref::<IActionCollectionVT>();
+			ok_to_hrresult((vt.Remove)(self.ptr(), std::ptr::read(&var_index))) // shallow copy, the callee doesn't take ownership
+		}
+	}
+}
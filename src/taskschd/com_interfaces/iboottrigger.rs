@@ -0,0 +1,63 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::{HRES, PSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::BSTR;
+use crate::prelude::{oleaut_IDispatch, taskschd_ITrigger};
+use crate::vt::ITriggerVT;
+
+/// [`IBootTrigger`](crate::IBootTrigger) virtual table.
+#[repr(C)]
+pub struct IBootTriggerVT {
+	pub ITriggerVT: ITriggerVT,
+	pub get_Delay: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_Delay: fn(ComPtr, PSTR) -> HRES,
+}
+
+com_interface! { IBootTrigger: "2a9c35da-d357-41f4-bbc1-207ac1b1f3cb";
+	/// [`IBootTrigger`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nn-taskschd-iboottrigger)
+	/// COM interface over [`IBootTriggerVT`](crate::vt::IBootTriggerVT).
+	///
+	/// Trigger of type
+	/// [`co::TASK_TRIGGER_TYPE2::BOOT`](crate::co::TASK_TRIGGER_TYPE2::BOOT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for IBootTrigger {}
+impl taskschd_ITrigger for IBootTrigger {}
+impl taskschd_IBootTrigger for IBootTrigger {}
+
+/// This trait is enabled with the `taskschd` feature, and provides methods for
+/// [`IBootTrigger`](crate::IBootTrigger).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait taskschd_IBootTrigger: taskschd_ITrigger {
+	/// [`IBootTrigger::get_Delay`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iboottrigger-get_delay)
+	/// method.
+	#[must_use]
+	fn get_Delay(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IBootTriggerVT>();
+			ok_to_hrresult((vt.get_Delay)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IBootTrigger::put_Delay`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iboottrigger-put_delay)
+	/// method.
+	fn put_Delay(&self, delay: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(delay)?;
+		unsafe {
+			let vt = self.vt_ref::<IBootTriggerVT>();
+			ok_to_hrresult((vt.put_Delay)(self.ptr(), bstr.0))
+		}
+	}
+}
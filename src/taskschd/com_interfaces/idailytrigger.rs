@@ -0,0 +1,85 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::{HRES, PSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::BSTR;
+use crate::prelude::{oleaut_IDispatch, taskschd_ITrigger};
+use crate::vt::ITriggerVT;
+
+/// [`IDailyTrigger`](crate::IDailyTrigger) virtual table.
+#[repr(C)]
+pub struct IDailyTriggerVT {
+	pub ITriggerVT: ITriggerVT,
+	pub get_DaysInterval: fn(ComPtr, *mut i16) -> HRES,
+	pub put_DaysInterval: fn(ComPtr, i16) -> HRES,
+	pub get_RandomDelay: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_RandomDelay: fn(ComPtr, PSTR) -> HRES,
+}
+
+com_interface! { IDailyTrigger: "126c5cd8-b288-41d5-8dbf-e491446adc5c";
+	/// [`IDailyTrigger`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nn-taskschd-idailytrigger)
+	/// COM interface over [`IDailyTriggerVT`](crate::vt::IDailyTriggerVT).
+	///
+	/// Trigger of type
+	/// [`co::TASK_TRIGGER_TYPE2::DAILY`](crate::co::TASK_TRIGGER_TYPE2::DAILY).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for IDailyTrigger {}
+impl taskschd_ITrigger for IDailyTrigger {}
+impl taskschd_IDailyTrigger for IDailyTrigger {}
+
+/// This trait is enabled with the `taskschd` feature, and provides methods for
+/// [`IDailyTrigger`](crate::IDailyTrigger).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait taskschd_IDailyTrigger: taskschd_ITrigger {
+	/// [`IDailyTrigger::get_DaysInterval`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-idailytrigger-get_daysinterval)
+	/// method.
+	#[must_use]
+	fn get_DaysInterval(&self) -> HrResult<i16> {
+		let mut val = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<IDailyTriggerVT>();
+			ok_to_hrresult((vt.get_DaysInterval)(self.ptr(), &mut val))
+		}.map(|_| val)
+	}
+
+	/// [`IDailyTrigger::get_RandomDelay`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-idailytrigger-get_randomdelay)
+	/// method.
+	#[must_use]
+	fn get_RandomDelay(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IDailyTriggerVT>();
+			ok_to_hrresult((vt.get_RandomDelay)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IDailyTrigger::put_DaysInterval`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-idailytrigger-put_daysinterval)
+	/// method.
+	fn put_DaysInterval(&self, days: i16) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IDailyTriggerVT>();
+			ok_to_hrresult((vt.put_DaysInterval)(self.ptr(), days))
+		}
+	}
+
+	/// [`IDailyTrigger::put_RandomDelay`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-idailytrigger-put_randomdelay)
+	/// method.
+	fn put_RandomDelay(&self, delay: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(delay)?;
+		unsafe {
+			let vt = self.vt_ref::<IDailyTriggerVT>();
+			ok_to_hrresult((vt.put_RandomDelay)(self.ptr(), bstr.0))
+		}
+	}
+}
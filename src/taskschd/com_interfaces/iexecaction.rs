@@ -0,0 +1,109 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::{HRES, PSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::BSTR;
+use crate::prelude::{taskschd_IAction, oleaut_IDispatch};
+use crate::vt::IActionVT;
+
+/// [`IExecAction`](crate::IExecAction) virtual table.
+#[repr(C)]
+pub struct IExecActionVT {
+	pub IActionVT: IActionVT,
+	pub get_Path: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_Path: fn(ComPtr, PSTR) -> HRES,
+	pub get_Arguments: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_Arguments: fn(ComPtr, PSTR) -> HRES,
+	pub get_WorkingDirectory: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_WorkingDirectory: fn(ComPtr, PSTR) -> HRES,
+}
+
+com_interface! { IExecAction: "4c3d624d-fd6b-49a3-b9b7-09cb3cd3f047";
+	/// [`IExecAction`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nn-taskschd-iexecaction)
+	/// COM interface over [`IExecActionVT`](crate::vt::IExecActionVT).
+	///
+	/// Action of type
+	/// [`co::TASK_ACTION_TYPE::EXEC`](crate::co::TASK_ACTION_TYPE::EXEC).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for IExecAction {}
+impl taskschd_IAction for IExecAction {}
+impl taskschd_IExecAction for IExecAction {}
+
+/// This trait is enabled with the `taskschd` feature, and provides methods for
+/// [`IExecAction`](crate::IExecAction).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait taskschd_IExecAction: taskschd_IAction {
+	/// [`IExecAction::get_Arguments`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iexecaction-get_arguments)
+	/// method.
+	#[must_use]
+	fn get_Arguments(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IExecActionVT>();
+			ok_to_hrresult((vt.get_Arguments)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IExecAction::get_Path`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iexecaction-get_path)
+	/// method.
+	#[must_use]
+	fn get_Path(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IExecActionVT>();
+			ok_to_hrresult((vt.get_Path)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IExecAction::get_WorkingDirectory`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iexecaction-get_workingdirectory)
+	/// method.
+	#[must_use]
+	fn get_WorkingDirectory(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IExecActionVT>();
+			ok_to_hrresult((vt.get_WorkingDirectory)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IExecAction::put_Arguments`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iexecaction-put_arguments)
+	/// method.
+	fn put_Arguments(&self, args: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(args)?;
+		unsafe {
+			let vt = self.vt_ref::<IExecActionVT>();
+			ok_to_hrresult((vt.put_Arguments)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IExecAction::put_Path`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iexecaction-put_path)
+	/// method.
+	fn put_Path(&self, path: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(path)?;
+		unsafe {
+			let vt = self.vt_ref::<IExecActionVT>();
+			ok_to_hrresult((vt.put_Path)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IExecAction::put_WorkingDirectory`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iexecaction-put_workingdirectory)
+	/// method.
+	fn put_WorkingDirectory(&self, dir: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(dir)?;
+		unsafe {
+			let vt = self.vt_ref::<IExecActionVT>();
+			ok_to_hrresult((vt.put_WorkingDirectory)(self.ptr(), bstr.0))
+		}
+	}
+}
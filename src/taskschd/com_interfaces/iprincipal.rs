@@ -0,0 +1,173 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::BSTR;
+use crate::prelude::oleaut_IDispatch;
+use crate::vt::IDispatchVT;
+
+/// [`IPrincipal`](crate::IPrincipal) virtual table.
+#[repr(C)]
+pub struct IPrincipalVT {
+	pub IDispatchVT: IDispatchVT,
+	pub get_Id: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_Id: fn(ComPtr, PSTR) -> HRES,
+	pub get_DisplayName: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_DisplayName: fn(ComPtr, PSTR) -> HRES,
+	pub get_UserId: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_UserId: fn(ComPtr, PSTR) -> HRES,
+	pub get_LogonType: fn(ComPtr, *mut u32) -> HRES,
+	pub put_LogonType: fn(ComPtr, u32) -> HRES,
+	pub get_GroupId: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_GroupId: fn(ComPtr, PSTR) -> HRES,
+	pub get_RunLevel: fn(ComPtr, *mut u32) -> HRES,
+	pub put_RunLevel: fn(ComPtr, u32) -> HRES,
+}
+
+com_interface! { IPrincipal: "d98d51e5-c9b4-496a-a9c1-18980261cf0f";
+	/// [`IPrincipal`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nn-taskschd-iprincipal)
+	/// COM interface over [`IPrincipalVT`](crate::vt::IPrincipalVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for IPrincipal {}
+impl taskschd_IPrincipal for IPrincipal {}
+
+/// This trait is enabled with the `taskschd` feature, and provides methods for
+/// [`IPrincipal`](crate::IPrincipal).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait taskschd_IPrincipal: oleaut_IDispatch {
+	/// [`IPrincipal::get_DisplayName`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iprincipal-get_displayname)
+	/// method.
+	#[must_use]
+	fn get_DisplayName(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IPrincipalVT>();
+			ok_to_hrresult((vt.get_DisplayName)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IPrincipal::get_GroupId`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iprincipal-get_groupid)
+	/// method.
+	#[must_use]
+	fn get_GroupId(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IPrincipalVT>();
+			ok_to_hrresult((vt.get_GroupId)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IPrincipal::get_Id`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iprincipal-get_id)
+	/// method.
+	#[must_use]
+	fn get_Id(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IPrincipalVT>();
+			ok_to_hrresult((vt.get_Id)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IPrincipal::get_LogonType`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iprincipal-get_logontype)
+	/// method.
+	#[must_use]
+	fn get_LogonType(&self) -> HrResult<co::TASK_LOGON_TYPE> {
+		let mut val = co::TASK_LOGON_TYPE::default();
+		unsafe {
+			let vt = self.vt_ref::<IPrincipalVT>();
+			ok_to_hrresult((vt.get_LogonType)(self.ptr(), &mut val.0))
+		}.map(|_| val)
+	}
+
+	/// [`IPrincipal::get_RunLevel`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iprincipal-get_runlevel)
+	/// method.
+	#[must_use]
+	fn get_RunLevel(&self) -> HrResult<co::TASK_RUNLEVEL> {
+		let mut val = co::TASK_RUNLEVEL::default();
+		unsafe {
+			let vt = self.vt_ref::<IPrincipalVT>();
+			ok_to_hrresult((vt.get_RunLevel)(self.ptr(), &mut val.0))
+		}.map(|_| val)
+	}
+
+	/// [`IPrincipal::get_UserId`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iprincipal-get_userid)
+	/// method.
+	#[must_use]
+	fn get_UserId(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IPrincipalVT>();
+			ok_to_hrresult((vt.get_UserId)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IPrincipal::put_DisplayName`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iprincipal-put_displayname)
+	/// method.
+	fn put_DisplayName(&self, name: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(name)?;
+		unsafe {
+			let vt = self.vt_ref::<IPrincipalVT>();
+			ok_to_hrresult((vt.put_DisplayName)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IPrincipal::put_GroupId`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iprincipal-put_groupid)
+	/// method.
+	fn put_GroupId(&self, group_id: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(group_id)?;
+		unsafe {
+			let vt = self.vt_ref::<IPrincipalVT>();
+			ok_to_hrresult((vt.put_GroupId)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IPrincipal::put_Id`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iprincipal-put_id)
+	/// method.
+	fn put_Id(&self, id: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(id)?;
+		unsafe {
+			let vt = self.vt_ref::<IPrincipalVT>();
+			ok_to_hrresult((vt.put_Id)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IPrincipal::put_LogonType`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iprincipal-put_logontype)
+	/// method.
+	fn put_LogonType(&self, logon_type: co::TASK_LOGON_TYPE) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IPrincipalVT>();
+			ok_to_hrresult((vt.put_LogonType)(self.ptr(), logon_type.0))
+		}
+	}
+
+	/// [`IPrincipal::put_RunLevel`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iprincipal-put_runlevel)
+	/// method.
+	fn put_RunLevel(&self, run_level: co::TASK_RUNLEVEL) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IPrincipalVT>();
+			ok_to_hrresult((vt.put_RunLevel)(self.ptr(), run_level.0))
+		}
+	}
+
+	/// [`IPrincipal::put_UserId`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iprincipal-put_userid)
+	/// method.
+	fn put_UserId(&self, user_id: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(user_id)?;
+		unsafe {
+			let vt = self.vt_ref::<IPrincipalVT>();
+			ok_to_hrresult((vt.put_UserId)(self.ptr(), bstr.0))
+		}
+	}
+}
@@ -0,0 +1,82 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::VARIANT;
+use crate::prelude::{oleaut_IDispatch, oleaut_Variant};
+use crate::taskschd::decl::IRegisteredTask;
+use crate::vt::IDispatchVT;
+
+/// [`IRegisteredTaskCollection`](crate::IRegisteredTaskCollection) virtual table.
+#[repr(C)]
+pub struct IRegisteredTaskCollectionVT {
+	pub IDispatchVT: IDispatchVT,
+	pub get_Count: fn(ComPtr, *mut i32) -> HRES,
+	pub get_Item: fn(ComPtr, VARIANT, *mut ComPtr) -> HRES,
+	pub get__NewEnum: fn(ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IRegisteredTaskCollection: "86627eb4-42a7-41e4-a4d9-ac33a72f2d52";
+	/// [`IRegisteredTaskCollection`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nn-taskschd-iregisteredtaskcollection)
+	/// COM interface over
+	/// [`IRegisteredTaskCollectionVT`](crate::vt::IRegisteredTaskCollectionVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for IRegisteredTaskCollection {}
+impl taskschd_IRegisteredTaskCollection for IRegisteredTaskCollection {}
+
+/// This trait is enabled with the `taskschd` feature, and provides methods for
+/// [`IRegisteredTaskCollection`](crate::IRegisteredTaskCollection).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait taskschd_IRegisteredTaskCollection: oleaut_IDispatch {
+	/// [`IRegisteredTaskCollection::get_Count`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregisteredtaskcollection-get_count)
+	/// method.
+	#[must_use]
+	fn get_Count(&self) -> HrResult<i32> {
+		let mut val = i32::default();
+		unsafe {
+			let vt = self.vt_ref::<IRegisteredTaskCollectionVT>();
+			ok_to_hrresult((vt.get_Count)(self.ptr(), &mut val))
+		}.map(|_| val)
+	}
+
+	/// [`IRegisteredTaskCollection::get_Item`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregisteredtaskcollection-get_item)
+	/// method.
+	///
+	/// The index is one-based.
+	#[must_use]
+	fn get_Item(&self, index: i32) -> HrResult<IRegisteredTask> {
+		let var_index = VARIANT::new_i32(index);
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IRegisteredTaskCollectionVT>();
+			ok_to_hrresult(
+				(vt.get_Item)(self.ptr(), std::ptr::read(&var_index), &mut ppv_queried), // shallow copy, the callee doesn't take ownership
+			).map(|_| IRegisteredTask::from(ppv_queried))
+		}
+	}
+
+	/// Returns an iterator over the [`IRegisteredTask`](crate::IRegisteredTask) elements,
+	/// which calls
+	/// [`IRegisteredTaskCollection::get_Count`](crate::prelude::taskschd_IRegisteredTaskCollection::get_Count)
+	/// and
+	/// [`IRegisteredTaskCollection::get_Item`](crate::prelude::taskschd_IRegisteredTaskCollection::get_Item)
+	/// internally.
+	#[must_use]
+	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<IRegisteredTask>> + '_> {
+		match self.get_Count() {
+			Ok(count) => Box::new((1..=count).map(|i| self.get_Item(i))),
+			Err(e) => Box::new(std::iter::once(Err(e))),
+		}
+	}
+}
@@ -0,0 +1,223 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::{HRES, PSTR, PVOID};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::{BSTR, VARIANT};
+use crate::prelude::oleaut_IDispatch;
+use crate::vt::IDispatchVT;
+
+/// [`IRegistrationInfo`](crate::IRegistrationInfo) virtual table.
+#[repr(C)]
+pub struct IRegistrationInfoVT {
+	pub IDispatchVT: IDispatchVT,
+	pub get_Description: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_Description: fn(ComPtr, PSTR) -> HRES,
+	pub get_Author: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_Author: fn(ComPtr, PSTR) -> HRES,
+	pub get_Version: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_Version: fn(ComPtr, PSTR) -> HRES,
+	pub get_Date: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_Date: fn(ComPtr, PSTR) -> HRES,
+	pub get_Documentation: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_Documentation: fn(ComPtr, PSTR) -> HRES,
+	pub get_XmlText: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_XmlText: fn(ComPtr, PSTR) -> HRES,
+	pub get_URI: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_URI: fn(ComPtr, PSTR) -> HRES,
+	pub get_SecurityDescriptor: fn(ComPtr, PVOID) -> HRES,
+	pub put_SecurityDescriptor: fn(ComPtr, VARIANT) -> HRES,
+	pub get_Source: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_Source: fn(ComPtr, PSTR) -> HRES,
+}
+
+com_interface! { IRegistrationInfo: "416d8b73-cb41-4ea1-805c-9be9a5ac4a74";
+	/// [`IRegistrationInfo`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nn-taskschd-iregistrationinfo)
+	/// COM interface over
+	/// [`IRegistrationInfoVT`](crate::vt::IRegistrationInfoVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for IRegistrationInfo {}
+impl taskschd_IRegistrationInfo for IRegistrationInfo {}
+
+/// This trait is enabled with the `taskschd` feature, and provides methods for
+/// [`IRegistrationInfo`](crate::IRegistrationInfo).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait taskschd_IRegistrationInfo: oleaut_IDispatch {
+	/// [`IRegistrationInfo::get_Author`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-get_author)
+	/// method.
+	#[must_use]
+	fn get_Author(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.get_Author)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IRegistrationInfo::get_Date`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-get_date)
+	/// method.
+	#[must_use]
+	fn get_Date(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.get_Date)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IRegistrationInfo::get_Description`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-get_description)
+	/// method.
+	#[must_use]
+	fn get_Description(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.get_Description)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IRegistrationInfo::get_Documentation`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-get_documentation)
+	/// method.
+	#[must_use]
+	fn get_Documentation(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.get_Documentation)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IRegistrationInfo::get_Source`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-get_source)
+	/// method.
+	#[must_use]
+	fn get_Source(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.get_Source)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IRegistrationInfo::get_URI`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-get_uri)
+	/// method.
+	#[must_use]
+	fn get_URI(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.get_URI)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IRegistrationInfo::get_Version`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-get_version)
+	/// method.
+	#[must_use]
+	fn get_Version(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.get_Version)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IRegistrationInfo::get_XmlText`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-get_xmltext)
+	/// method.
+	#[must_use]
+	fn get_XmlText(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.get_XmlText)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IRegistrationInfo::put_Author`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-put_author)
+	/// method.
+	fn put_Author(&self, author: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(author)?;
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.put_Author)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IRegistrationInfo::put_Date`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-put_date)
+	/// method.
+	fn put_Date(&self, date: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(date)?;
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.put_Date)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IRegistrationInfo::put_Description`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-put_description)
+	/// method.
+	fn put_Description(&self, description: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(description)?;
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.put_Description)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IRegistrationInfo::put_Documentation`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-put_documentation)
+	/// method.
+	fn put_Documentation(&self, documentation: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(documentation)?;
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.put_Documentation)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IRegistrationInfo::put_Source`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-put_source)
+	/// method.
+	fn put_Source(&self, source: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(source)?;
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.put_Source)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IRegistrationInfo::put_URI`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-put_uri)
+	/// method.
+	fn put_URI(&self, uri: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(uri)?;
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.put_URI)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IRegistrationInfo::put_Version`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-put_version)
+	/// method.
+	fn put_Version(&self, version: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(version)?;
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.put_Version)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`IRegistrationInfo::put_XmlText`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-iregistrationinfo-put_xmltext)
+	/// method.
+	fn put_XmlText(&self, xml: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(xml)?;
+		unsafe {
+			let vt = self.vt_ref::<IRegistrationInfoVT>();
+			ok_to_hrresult((vt.put_XmlText)(self.ptr(), bstr.0))
+		}
+	}
+}
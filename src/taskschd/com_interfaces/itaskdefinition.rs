@@ -0,0 +1,203 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::{HRES, PSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::BSTR;
+use crate::prelude::{
+	taskschd_IActionCollection, oleaut_IDispatch, taskschd_IPrincipal, taskschd_IRegistrationInfo, taskschd_ITaskSettings, taskschd_ITriggerCollection,
+};
+use crate::taskschd::decl::{
+	IActionCollection, IPrincipal, IRegistrationInfo, ITaskSettings,
+	ITriggerCollection,
+};
+use crate::vt::IDispatchVT;
+
+/// [`ITaskDefinition`](crate::ITaskDefinition) virtual table.
+#[repr(C)]
+pub struct ITaskDefinitionVT {
+	pub IDispatchVT: IDispatchVT,
+	pub get_RegistrationInfo: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub put_RegistrationInfo: fn(ComPtr, ComPtr) -> HRES,
+	pub get_Triggers: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub put_Triggers: fn(ComPtr, ComPtr) -> HRES,
+	pub get_Settings: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub put_Settings: fn(ComPtr, ComPtr) -> HRES,
+	pub get_Data: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_Data: fn(ComPtr, PSTR) -> HRES,
+	pub get_Principal: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub put_Principal: fn(ComPtr, ComPtr) -> HRES,
+	pub get_Actions: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub put_Actions: fn(ComPtr, ComPtr) -> HRES,
+	pub get_XmlText: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_XmlText: fn(ComPtr, PSTR) -> HRES,
+}
+
+com_interface! { ITaskDefinition: "f5bc8fc5-536d-4f77-b852-fbc1356fdeb6";
+	/// [`ITaskDefinition`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nn-taskschd-itaskdefinition)
+	/// COM interface over [`ITaskDefinitionVT`](crate::vt::ITaskDefinitionVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for ITaskDefinition {}
+impl taskschd_ITaskDefinition for ITaskDefinition {}
+
+/// This trait is enabled with the `taskschd` feature, and provides methods for
+/// [`ITaskDefinition`](crate::ITaskDefinition).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait taskschd_ITaskDefinition: oleaut_IDispatch {
+	/// [`ITaskDefinition::get_Actions`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-get_actions)
+	/// method.
+	#[must_use]
+	fn get_Actions(&self) -> HrResult<IActionCollection> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.get_Actions)(self.ptr(), &mut ppv_queried))
+				.map(|_| IActionCollection::from(ppv_queried))
+		}
+	}
+
+	/// [`ITaskDefinition::get_Data`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-get_data)
+	/// method.
+	#[must_use]
+	fn get_Data(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.get_Data)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`ITaskDefinition::get_Principal`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-get_principal)
+	/// method.
+	#[must_use]
+	fn get_Principal(&self) -> HrResult<IPrincipal> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.get_Principal)(self.ptr(), &mut ppv_queried))
+				.map(|_| IPrincipal::from(ppv_queried))
+		}
+	}
+
+	/// [`ITaskDefinition::get_RegistrationInfo`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-get_registrationinfo)
+	/// method.
+	#[must_use]
+	fn get_RegistrationInfo(&self) -> HrResult<IRegistrationInfo> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.get_RegistrationInfo)(self.ptr(), &mut ppv_queried))
+				.map(|_| IRegistrationInfo::from(ppv_queried))
+		}
+	}
+
+	/// [`ITaskDefinition::get_Settings`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-get_settings)
+	/// method.
+	#[must_use]
+	fn get_Settings(&self) -> HrResult<ITaskSettings> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.get_Settings)(self.ptr(), &mut ppv_queried))
+				.map(|_| ITaskSettings::from(ppv_queried))
+		}
+	}
+
+	/// [`ITaskDefinition::get_Triggers`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-get_triggers)
+	/// method.
+	#[must_use]
+	fn get_Triggers(&self) -> HrResult<ITriggerCollection> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.get_Triggers)(self.ptr(), &mut ppv_queried))
+				.map(|_| ITriggerCollection::from(ppv_queried))
+		}
+	}
+
+	/// [`ITaskDefinition::get_XmlText`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-get_xmltext)
+	/// method.
+	#[must_use]
+	fn get_XmlText(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.get_XmlText)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`ITaskDefinition::put_Actions`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-put_actions)
+	/// method.
+	fn put_Actions(&self, actions: &impl taskschd_IActionCollection) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.put_Actions)(self.ptr(), actions.ptr()))
+		}
+	}
+
+	/// [`ITaskDefinition::put_Data`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-put_data)
+	/// method.
+	fn put_Data(&self, data: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(data)?;
+		unsafe {
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.put_Data)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`ITaskDefinition::put_Principal`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-put_principal)
+	/// method.
+	fn put_Principal(&self, principal: &impl taskschd_IPrincipal) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.put_Principal)(self.ptr(), principal.ptr()))
+		}
+	}
+
+	/// [`ITaskDefinition::put_RegistrationInfo`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-put_registrationinfo)
+	/// method.
+	fn put_RegistrationInfo(&self, info: &impl taskschd_IRegistrationInfo) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.put_RegistrationInfo)(self.ptr(), info.ptr()))
+		}
+	}
+
+	/// [`ITaskDefinition::put_Settings`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-put_settings)
+	/// method.
+	fn put_Settings(&self, settings: &impl taskschd_ITaskSettings) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.put_Settings)(self.ptr(), settings.ptr()))
+		}
+	}
+
+	/// [`ITaskDefinition::put_Triggers`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-put_triggers)
+	/// method.
+	fn put_Triggers(&self, triggers: &impl taskschd_ITriggerCollection) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.put_Triggers)(self.ptr(), triggers.ptr()))
+		}
+	}
+
+	/// [`ITaskDefinition::put_XmlText`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskdefinition-put_xmltext)
+	/// method.
+	fn put_XmlText(&self, xml: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(xml)?;
+		unsafe {
+			let vt = self.vt_ref::<ITaskDefinitionVT>();
+			ok_to_hrresult((vt.put_XmlText)(self.ptr(), bstr.0))
+		}
+	}
+}
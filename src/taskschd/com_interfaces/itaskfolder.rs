@@ -0,0 +1,248 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::{BSTR, VARIANT};
+use crate::prelude::{oleaut_IDispatch, taskschd_ITaskDefinition, oleaut_Variant};
+use crate::taskschd::decl::{
+	IRegisteredTask, IRegisteredTaskCollection, ITaskFolderCollection,
+};
+use crate::vt::IDispatchVT;
+
+/// [`ITaskFolder`](crate::ITaskFolder) virtual table.
+#[repr(C)]
+pub struct ITaskFolderVT {
+	pub IDispatchVT: IDispatchVT,
+	pub get_Name: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_Path: fn(ComPtr, *mut PSTR) -> HRES,
+	pub GetFolder: fn(ComPtr, PSTR, *mut ComPtr) -> HRES,
+	pub GetFolders: fn(ComPtr, i32, *mut ComPtr) -> HRES,
+	pub CreateFolder: fn(ComPtr, PSTR, VARIANT, *mut ComPtr) -> HRES,
+	pub DeleteFolder: fn(ComPtr, PSTR, i32) -> HRES,
+	pub GetTask: fn(ComPtr, PSTR, *mut ComPtr) -> HRES,
+	pub GetTasks: fn(ComPtr, i32, *mut ComPtr) -> HRES,
+	pub DeleteTask: fn(ComPtr, PSTR, i32) -> HRES,
+	pub RegisterTask: fn(ComPtr, PSTR, PSTR, i32, VARIANT, VARIANT, u32, VARIANT, *mut ComPtr) -> HRES,
+	pub RegisterTaskDefinition: fn(ComPtr, PSTR, ComPtr, i32, VARIANT, VARIANT, u32, VARIANT, *mut ComPtr) -> HRES,
+	pub GetSecurityDescriptor: fn(ComPtr, i32, *mut PSTR) -> HRES,
+	pub SetSecurityDescriptor: fn(ComPtr, PSTR, i32) -> HRES,
+}
+
+com_interface! { ITaskFolder: "8cfac062-a080-4c15-9a88-aa7c2af80dfc";
+	/// [`ITaskFolder`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nn-taskschd-itaskfolder)
+	/// COM interface over [`ITaskFolderVT`](crate::vt::ITaskFolderVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for ITaskFolder {}
+impl taskschd_ITaskFolder for ITaskFolder {}
+
+/// This trait is enabled with the `taskschd` feature, and provides methods for
+/// [`ITaskFolder`](crate::ITaskFolder).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait taskschd_ITaskFolder: oleaut_IDispatch {
+	/// [`ITaskFolder::CreateFolder`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskfolder-createfolder)
+	/// method.
+	fn CreateFolder(&self, sub_folder_name: &str) -> HrResult<ITaskFolder> {
+		let bstr = BSTR::SysAllocString(sub_folder_name)?;
+		let sddl = VARIANT::default();
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskFolderVT>();
+			ok_to_hrresult(
+				(vt.CreateFolder)(
+					self.ptr(),
+					bstr.0,
+					std::ptr::read(&sddl), // shallow copy, the callee doesn't take ownership
+					&mut ppv_queried,
+				),
+			).map(|_| ITaskFolder::from(ppv_queried))
+		}
+	}
+
+	/// [`ITaskFolder::DeleteFolder`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskfolder-deletefolder)
+	/// method.
+	fn DeleteFolder(&self, sub_folder_name: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(sub_folder_name)?;
+		unsafe {
+			let vt = self.vt_ref::<ITaskFolderVT>();
+			ok_to_hrresult((vt.DeleteFolder)(self.ptr(), bstr.0, 0)) // flags reserved
+		}
+	}
+
+	/// [`ITaskFolder::DeleteTask`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskfolder-deletetask)
+	/// method.
+	fn DeleteTask(&self, name: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(name)?;
+		unsafe {
+			let vt = self.vt_ref::<ITaskFolderVT>();
+			ok_to_hrresult((vt.DeleteTask)(self.ptr(), bstr.0, 0)) // flags reserved
+		}
+	}
+
+	/// [`ITaskFolder::get_Name`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskfolder-get_name)
+	/// method.
+	#[must_use]
+	fn get_Name(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskFolderVT>();
+			ok_to_hrresult((vt.get_Name)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`ITaskFolder::get_Path`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskfolder-get_path)
+	/// method.
+	#[must_use]
+	fn get_Path(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskFolderVT>();
+			ok_to_hrresult((vt.get_Path)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`ITaskFolder::GetFolder`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskfolder-getfolder)
+	/// method.
+	#[must_use]
+	fn GetFolder(&self, path: &str) -> HrResult<ITaskFolder> {
+		let bstr = BSTR::SysAllocString(path)?;
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskFolderVT>();
+			ok_to_hrresult((vt.GetFolder)(self.ptr(), bstr.0, &mut ppv_queried))
+				.map(|_| ITaskFolder::from(ppv_queried))
+		}
+	}
+
+	/// [`ITaskFolder::GetFolders`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskfolder-getfolders)
+	/// method.
+	#[must_use]
+	fn GetFolders(&self) -> HrResult<ITaskFolderCollection> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskFolderVT>();
+			ok_to_hrresult((vt.GetFolders)(self.ptr(), 0, &mut ppv_queried)) // flags reserved
+				.map(|_| ITaskFolderCollection::from(ppv_queried))
+		}
+	}
+
+	/// [`ITaskFolder::GetTask`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskfolder-gettask)
+	/// method.
+	#[must_use]
+	fn GetTask(&self, path: &str) -> HrResult<IRegisteredTask> {
+		let bstr = BSTR::SysAllocString(path)?;
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskFolderVT>();
+			ok_to_hrresult((vt.GetTask)(self.ptr(), bstr.0, &mut ppv_queried))
+				.map(|_| IRegisteredTask::from(ppv_queried))
+		}
+	}
+
+	/// [`ITaskFolder::GetTasks`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskfolder-gettasks)
+	/// method.
+	#[must_use]
+	fn GetTasks(&self,
+		flags: co::TASK_ENUM,
+	) -> HrResult<IRegisteredTaskCollection>
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskFolderVT>();
+			ok_to_hrresult((vt.GetTasks)(self.ptr(), flags.0 as _, &mut ppv_queried))
+				.map(|_| IRegisteredTaskCollection::from(ppv_queried))
+		}
+	}
+
+	/// [`ITaskFolder::RegisterTask`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskfolder-registertask)
+	/// method.
+	///
+	/// Registers a task from its XML definition.
+	fn RegisterTask(&self,
+		path: &str,
+		xml_text: &str,
+		flags: co::TASK_CREATION,
+		user_id: Option<&str>,
+		password: Option<&str>,
+		logon_type: co::TASK_LOGON_TYPE,
+	) -> HrResult<IRegisteredTask>
+	{
+		let bstr_path = BSTR::SysAllocString(path)?;
+		let bstr_xml = BSTR::SysAllocString(xml_text)?;
+		let to_var = |s: Option<&str>| s.map_or_else(
+			|| Ok(VARIANT::default()), VARIANT::new_bstr);
+		let (user_id, password) = (to_var(user_id)?, to_var(password)?);
+		let sddl = VARIANT::default();
+
+		unsafe {
+			// The VARIANTs are passed by value, but the callee doesn't take
+			// ownership, so shallow copies are passed and the originals are
+			// cleared here.
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskFolderVT>();
+			ok_to_hrresult(
+				(vt.RegisterTask)(
+					self.ptr(),
+					bstr_path.0,
+					bstr_xml.0,
+					flags.0 as _,
+					std::ptr::read(&user_id),
+					std::ptr::read(&password),
+					logon_type.0,
+					std::ptr::read(&sddl),
+					&mut ppv_queried,
+				),
+			).map(|_| IRegisteredTask::from(ppv_queried))
+		}
+	}
+
+	/// [`ITaskFolder::RegisterTaskDefinition`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskfolder-registertaskdefinition)
+	/// method.
+	fn RegisterTaskDefinition(&self,
+		path: &str,
+		definition: &impl taskschd_ITaskDefinition,
+		flags: co::TASK_CREATION,
+		user_id: Option<&str>,
+		password: Option<&str>,
+		logon_type: co::TASK_LOGON_TYPE,
+	) -> HrResult<IRegisteredTask>
+	{
+		let bstr_path = BSTR::SysAllocString(path)?;
+		let to_var = |s: Option<&str>| s.map_or_else(
+			|| Ok(VARIANT::default()), VARIANT::new_bstr);
+		let (user_id, password) = (to_var(user_id)?, to_var(password)?);
+		let sddl = VARIANT::default();
+
+		unsafe {
+			// The VARIANTs are passed by value, but the callee doesn't take
+			// ownership, so shallow copies are passed and the originals are
+			// cleared here.
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskFolderVT>();
+			ok_to_hrresult(
+				(vt.RegisterTaskDefinition)(
+					self.ptr(),
+					bstr_path.0,
+					definition.ptr(),
+					flags.0 as _,
+					std::ptr::read(&user_id),
+					std::ptr::read(&password),
+					logon_type.0,
+					std::ptr::read(&sddl),
+					&mut ppv_queried,
+				),
+			).map(|_| IRegisteredTask::from(ppv_queried))
+		}
+	}
+}
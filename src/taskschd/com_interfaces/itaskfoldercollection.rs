@@ -0,0 +1,82 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::VARIANT;
+use crate::prelude::{oleaut_IDispatch, oleaut_Variant};
+use crate::taskschd::decl::ITaskFolder;
+use crate::vt::IDispatchVT;
+
+/// [`ITaskFolderCollection`](crate::ITaskFolderCollection) virtual table.
+#[repr(C)]
+pub struct ITaskFolderCollectionVT {
+	pub IDispatchVT: IDispatchVT,
+	pub get_Count: fn(ComPtr, *mut i32) -> HRES,
+	pub get_Item: fn(ComPtr, VARIANT, *mut ComPtr) -> HRES,
+	pub get__NewEnum: fn(ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { ITaskFolderCollection: "79184a66-8664-423f-97f1-637356a5d812";
+	/// [`ITaskFolderCollection`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nn-taskschd-itaskfoldercollection)
+	/// COM interface over
+	/// [`ITaskFolderCollectionVT`](crate::vt::ITaskFolderCollectionVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for ITaskFolderCollection {}
+impl taskschd_ITaskFolderCollection for ITaskFolderCollection {}
+
+/// This trait is enabled with the `taskschd` feature, and provides methods for
+/// [`ITaskFolderCollection`](crate::ITaskFolderCollection).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait taskschd_ITaskFolderCollection: oleaut_IDispatch {
+	/// [`ITaskFolderCollection::get_Count`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskfoldercollection-get_count)
+	/// method.
+	#[must_use]
+	fn get_Count(&self) -> HrResult<i32> {
+		let mut val = i32::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskFolderCollectionVT>();
+			ok_to_hrresult((vt.get_Count)(self.ptr(), &mut val))
+		}.map(|_| val)
+	}
+
+	/// [`ITaskFolderCollection::get_Item`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskfoldercollection-get_item)
+	/// method.
+	///
+	/// The index is one-based.
+	#[must_use]
+	fn get_Item(&self, index: i32) -> HrResult<ITaskFolder> {
+		let var_index = VARIANT::new_i32(index);
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskFolderCollectionVT>();
+			ok_to_hrresult(
+				(vt.get_Item)(self.ptr(), std::ptr::read(&var_index), &mut ppv_queried), // shallow copy, the callee doesn't take ownership
+			).map(|_| ITaskFolder::from(ppv_queried))
+		}
+	}
+
+	/// Returns an iterator over the [`ITaskFolder`](crate::ITaskFolder) elements,
+	/// which calls
+	/// [`ITaskFolderCollection::get_Count`](crate::prelude::taskschd_ITaskFolderCollection::get_Count)
+	/// and
+	/// [`ITaskFolderCollection::get_Item`](crate::prelude::taskschd_ITaskFolderCollection::get_Item)
+	/// internally.
+	#[must_use]
+	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<ITaskFolder>> + '_> {
+		match self.get_Count() {
+			Ok(count) => Box::new((1..=count).map(|i| self.get_Item(i))),
+			Err(e) => Box::new(std::iter::once(Err(e))),
+		}
+	}
+}
@@ -0,0 +1,201 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::{HRES, PSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::{BSTR, VARIANT};
+use crate::prelude::{oleaut_IDispatch, oleaut_Variant};
+use crate::taskschd::decl::{ITaskDefinition, ITaskFolder};
+use crate::vt::IDispatchVT;
+
+/// [`ITaskService`](crate::ITaskService) virtual table.
+#[repr(C)]
+pub struct ITaskServiceVT {
+	pub IDispatchVT: IDispatchVT,
+	pub GetFolder: fn(ComPtr, PSTR, *mut ComPtr) -> HRES,
+	pub GetRunningTasks: fn(ComPtr, i32, *mut ComPtr) -> HRES,
+	pub NewTask: fn(ComPtr, u32, *mut ComPtr) -> HRES,
+	pub Connect: fn(ComPtr, VARIANT, VARIANT, VARIANT, VARIANT) -> HRES,
+	pub get_Connected: fn(ComPtr, *mut i16) -> HRES,
+	pub get_TargetServer: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_ConnectedUser: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_ConnectedDomain: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_HighestVersion: fn(ComPtr, *mut u32) -> HRES,
+}
+
+com_interface! { ITaskService: "2faba4c7-4da9-4013-9697-20cc3fd40f85";
+	/// [`ITaskService`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nn-taskschd-itaskservice)
+	/// COM interface over [`ITaskServiceVT`](crate::vt::ITaskServiceVT).
+	///
+	/// Entry point of the Task Scheduler 2.0 API.
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// Registering a task which runs Notepad every day at 9 AM:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, CoCreateInstance, IExecAction, ITaskService};
+	///
+	/// let service = CoCreateInstance::<ITaskService>(
+	///     &co::CLSID::TaskScheduler,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// service.Connect(None, None, None, None)?;
+	///
+	/// let task = service.NewTask()?;
+	/// task.get_RegistrationInfo()?.put_Description("Opens Notepad")?;
+	///
+	/// let trigger = task.get_Triggers()?.Create(co::TASK_TRIGGER_TYPE2::DAILY)?;
+	/// trigger.put_StartBoundary("2024-01-01T09:00:00")?;
+	///
+	/// let action = task.get_Actions()?
+	///     .Create(co::TASK_ACTION_TYPE::EXEC)?
+	///     .QueryInterface::<IExecAction>()?;
+	/// action.put_Path("C:\\Windows\\notepad.exe")?;
+	///
+	/// service.GetFolder("\\")?.RegisterTaskDefinition(
+	///     "My daily task",
+	///     &task,
+	///     co::TASK_CREATION::CREATE_OR_UPDATE,
+	///     None,
+	///     None,
+	///     co::TASK_LOGON_TYPE::INTERACTIVE_TOKEN,
+	/// )?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl oleaut_IDispatch for ITaskService {}
+impl taskschd_ITaskService for ITaskService {}
+
+/// This trait is enabled with the `taskschd` feature, and provides methods for
+/// [`ITaskService`](crate::ITaskService).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait taskschd_ITaskService: oleaut_IDispatch {
+	/// [`ITaskService::Connect`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskservice-connect)
+	/// method.
+	///
+	/// Passing `None` to all arguments connects to the local machine, with the
+	/// current user.
+	fn Connect(&self,
+		server_name: Option<&str>,
+		user: Option<&str>,
+		domain: Option<&str>,
+		password: Option<&str>,
+	) -> HrResult<()>
+	{
+		let to_var = |s: Option<&str>| s.map_or_else(
+			|| Ok(VARIANT::default()), VARIANT::new_bstr);
+		let (server_name, user, domain, password) =
+			(to_var(server_name)?, to_var(user)?, to_var(domain)?, to_var(password)?);
+
+		unsafe {
+			// The VARIANTs are passed by value, but the callee doesn't take
+			// ownership, so shallow copies are passed and the originals are
+			// cleared here.
+			let vt = self.vt_ref::<ITaskServiceVT>();
+			ok_to_hrresult(
+				(vt.Connect)(
+					self.ptr(),
+					std::ptr::read(&server_name),
+					std::ptr::read(&user),
+					std::ptr::read(&domain),
+					std::ptr::read(&password),
+				),
+			)
+		}
+	}
+
+	/// [`ITaskService::get_Connected`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskservice-get_connected)
+	/// method.
+	#[must_use]
+	fn get_Connected(&self) -> HrResult<bool> {
+		let mut val = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskServiceVT>();
+			ok_to_hrresult((vt.get_Connected)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`ITaskService::get_ConnectedDomain`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskservice-get_connecteddomain)
+	/// method.
+	#[must_use]
+	fn get_ConnectedDomain(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskServiceVT>();
+			ok_to_hrresult((vt.get_ConnectedDomain)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`ITaskService::get_ConnectedUser`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskservice-get_connecteduser)
+	/// method.
+	#[must_use]
+	fn get_ConnectedUser(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskServiceVT>();
+			ok_to_hrresult((vt.get_ConnectedUser)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`ITaskService::get_HighestVersion`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskservice-get_highestversion)
+	/// method.
+	#[must_use]
+	fn get_HighestVersion(&self) -> HrResult<u32> {
+		let mut val = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskServiceVT>();
+			ok_to_hrresult((vt.get_HighestVersion)(self.ptr(), &mut val))
+		}.map(|_| val)
+	}
+
+	/// [`ITaskService::get_TargetServer`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskservice-get_targetserver)
+	/// method.
+	#[must_use]
+	fn get_TargetServer(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskServiceVT>();
+			ok_to_hrresult((vt.get_TargetServer)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`ITaskService::GetFolder`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskservice-getfolder)
+	/// method.
+	///
+	/// The root folder is `"\\"`.
+	#[must_use]
+	fn GetFolder(&self, path: &str) -> HrResult<ITaskFolder> {
+		let bstr = BSTR::SysAllocString(path)?;
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskServiceVT>();
+			ok_to_hrresult((vt.GetFolder)(self.ptr(), bstr.0, &mut ppv_queried))
+				.map(|_| ITaskFolder::from(ppv_queried))
+		}
+	}
+
+	/// [`ITaskService::NewTask`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itaskservice-newtask)
+	/// method.
+	#[must_use]
+	fn NewTask(&self) -> HrResult<ITaskDefinition> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<ITaskServiceVT>();
+			ok_to_hrresult((vt.NewTask)(self.ptr(), 0, &mut ppv_queried)) // flags reserved
+				.map(|_| ITaskDefinition::from(ppv_queried))
+		}
+	}
+}
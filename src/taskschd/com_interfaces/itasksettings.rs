@@ -0,0 +1,441 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::BSTR;
+use crate::prelude::oleaut_IDispatch;
+use crate::vt::IDispatchVT;
+
+/// [`ITaskSettings`](crate::ITaskSettings) virtual table.
+#[repr(C)]
+pub struct ITaskSettingsVT {
+	pub IDispatchVT: IDispatchVT,
+	pub get_AllowDemandStart: fn(ComPtr, *mut i16) -> HRES,
+	pub put_AllowDemandStart: fn(ComPtr, i16) -> HRES,
+	pub get_RestartInterval: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_RestartInterval: fn(ComPtr, PSTR) -> HRES,
+	pub get_RestartCount: fn(ComPtr, *mut i32) -> HRES,
+	pub put_RestartCount: fn(ComPtr, i32) -> HRES,
+	pub get_MultipleInstances: fn(ComPtr, *mut u32) -> HRES,
+	pub put_MultipleInstances: fn(ComPtr, u32) -> HRES,
+	pub get_StopIfGoingOnBatteries: fn(ComPtr, *mut i16) -> HRES,
+	pub put_StopIfGoingOnBatteries: fn(ComPtr, i16) -> HRES,
+	pub get_DisallowStartIfOnBatteries: fn(ComPtr, *mut i16) -> HRES,
+	pub put_DisallowStartIfOnBatteries: fn(ComPtr, i16) -> HRES,
+	pub get_AllowHardTerminate: fn(ComPtr, *mut i16) -> HRES,
+	pub put_AllowHardTerminate: fn(ComPtr, i16) -> HRES,
+	pub get_StartWhenAvailable: fn(ComPtr, *mut i16) -> HRES,
+	pub put_StartWhenAvailable: fn(ComPtr, i16) -> HRES,
+	pub get_XmlText: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_XmlText: fn(ComPtr, PSTR) -> HRES,
+	pub get_RunOnlyIfNetworkAvailable: fn(ComPtr, *mut i16) -> HRES,
+	pub put_RunOnlyIfNetworkAvailable: fn(ComPtr, i16) -> HRES,
+	pub get_ExecutionTimeLimit: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_ExecutionTimeLimit: fn(ComPtr, PSTR) -> HRES,
+	pub get_Enabled: fn(ComPtr, *mut i16) -> HRES,
+	pub put_Enabled: fn(ComPtr, i16) -> HRES,
+	pub get_DeleteExpiredTaskAfter: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_DeleteExpiredTaskAfter: fn(ComPtr, PSTR) -> HRES,
+	pub get_Priority: fn(ComPtr, *mut i32) -> HRES,
+	pub put_Priority: fn(ComPtr, i32) -> HRES,
+	pub get_Compatibility: fn(ComPtr, *mut u32) -> HRES,
+	pub put_Compatibility: fn(ComPtr, u32) -> HRES,
+	pub get_Hidden: fn(ComPtr, *mut i16) -> HRES,
+	pub put_Hidden: fn(ComPtr, i16) -> HRES,
+	pub get_IdleSettings: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub put_IdleSettings: fn(ComPtr, ComPtr) -> HRES,
+	pub get_RunOnlyIfIdle: fn(ComPtr, *mut i16) -> HRES,
+	pub put_RunOnlyIfIdle: fn(ComPtr, i16) -> HRES,
+	pub get_WakeToRun: fn(ComPtr, *mut i16) -> HRES,
+	pub put_WakeToRun: fn(ComPtr, i16) -> HRES,
+	pub get_NetworkSettings: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub put_NetworkSettings: fn(ComPtr, ComPtr) -> HRES,
+}
+
+com_interface! { ITaskSettings: "8fd4711d-2d02-4c8c-87e3-eff699de127e";
+	/// [`ITaskSettings`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nn-taskschd-itasksettings)
+	/// COM interface over [`ITaskSettingsVT`](crate::vt::ITaskSettingsVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for ITaskSettings {}
+impl taskschd_ITaskSettings for ITaskSettings {}
+
+/// This trait is enabled with the `taskschd` feature, and provides methods for
+/// [`ITaskSettings`](crate::ITaskSettings).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait taskschd_ITaskSettings: oleaut_IDispatch {
+	/// [`ITaskSettings::get_AllowDemandStart`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_allowdemandstart)
+	/// method.
+	#[must_use]
+	fn get_AllowDemandStart(&self) -> HrResult<bool> {
+		let mut val = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_AllowDemandStart)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`ITaskSettings::get_AllowHardTerminate`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_allowhardterminate)
+	/// method.
+	#[must_use]
+	fn get_AllowHardTerminate(&self) -> HrResult<bool> {
+		let mut val = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_AllowHardTerminate)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`ITaskSettings::get_Compatibility`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_compatibility)
+	/// method.
+	#[must_use]
+	fn get_Compatibility(&self) -> HrResult<co::TASK_COMPATIBILITY> {
+		let mut val = co::TASK_COMPATIBILITY::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_Compatibility)(self.ptr(), &mut val.0))
+		}.map(|_| val)
+	}
+
+	/// [`ITaskSettings::get_DeleteExpiredTaskAfter`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_deleteexpiredtaskafter)
+	/// method.
+	#[must_use]
+	fn get_DeleteExpiredTaskAfter(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_DeleteExpiredTaskAfter)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`ITaskSettings::get_DisallowStartIfOnBatteries`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_disallowstartifonbatteries)
+	/// method.
+	#[must_use]
+	fn get_DisallowStartIfOnBatteries(&self) -> HrResult<bool> {
+		let mut val = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_DisallowStartIfOnBatteries)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`ITaskSettings::get_Enabled`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_enabled)
+	/// method.
+	#[must_use]
+	fn get_Enabled(&self) -> HrResult<bool> {
+		let mut val = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_Enabled)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`ITaskSettings::get_ExecutionTimeLimit`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_executiontimelimit)
+	/// method.
+	#[must_use]
+	fn get_ExecutionTimeLimit(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_ExecutionTimeLimit)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`ITaskSettings::get_Hidden`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_hidden)
+	/// method.
+	#[must_use]
+	fn get_Hidden(&self) -> HrResult<bool> {
+		let mut val = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_Hidden)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`ITaskSettings::get_MultipleInstances`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_multipleinstances)
+	/// method.
+	#[must_use]
+	fn get_MultipleInstances(&self) -> HrResult<co::TASK_INSTANCES_POLICY> {
+		let mut val = co::TASK_INSTANCES_POLICY::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_MultipleInstances)(self.ptr(), &mut val.0))
+		}.map(|_| val)
+	}
+
+	/// [`ITaskSettings::get_Priority`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_priority)
+	/// method.
+	#[must_use]
+	fn get_Priority(&self) -> HrResult<i32> {
+		let mut val = i32::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_Priority)(self.ptr(), &mut val))
+		}.map(|_| val)
+	}
+
+	/// [`ITaskSettings::get_RestartCount`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_restartcount)
+	/// method.
+	#[must_use]
+	fn get_RestartCount(&self) -> HrResult<i32> {
+		let mut val = i32::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_RestartCount)(self.ptr(), &mut val))
+		}.map(|_| val)
+	}
+
+	/// [`ITaskSettings::get_RestartInterval`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_restartinterval)
+	/// method.
+	#[must_use]
+	fn get_RestartInterval(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_RestartInterval)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`ITaskSettings::get_RunOnlyIfIdle`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_runonlyifidle)
+	/// method.
+	#[must_use]
+	fn get_RunOnlyIfIdle(&self) -> HrResult<bool> {
+		let mut val = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_RunOnlyIfIdle)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`ITaskSettings::get_RunOnlyIfNetworkAvailable`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_runonlyifnetworkavailable)
+	/// method.
+	#[must_use]
+	fn get_RunOnlyIfNetworkAvailable(&self) -> HrResult<bool> {
+		let mut val = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_RunOnlyIfNetworkAvailable)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`ITaskSettings::get_StartWhenAvailable`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_startwhenavailable)
+	/// method.
+	#[must_use]
+	fn get_StartWhenAvailable(&self) -> HrResult<bool> {
+		let mut val = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_StartWhenAvailable)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`ITaskSettings::get_StopIfGoingOnBatteries`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_stopifgoingonbatteries)
+	/// method.
+	#[must_use]
+	fn get_StopIfGoingOnBatteries(&self) -> HrResult<bool> {
+		let mut val = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_StopIfGoingOnBatteries)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`ITaskSettings::get_WakeToRun`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_waketorun)
+	/// method.
+	#[must_use]
+	fn get_WakeToRun(&self) -> HrResult<bool> {
+		let mut val = i16::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_WakeToRun)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`ITaskSettings::get_XmlText`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-get_xmltext)
+	/// method.
+	#[must_use]
+	fn get_XmlText(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.get_XmlText)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`ITaskSettings::put_AllowDemandStart`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_allowdemandstart)
+	/// method.
+	fn put_AllowDemandStart(&self, allow: bool) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_AllowDemandStart)(self.ptr(), if allow { -1 } else { 0 }))
+		}
+	}
+
+	/// [`ITaskSettings::put_AllowHardTerminate`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_allowhardterminate)
+	/// method.
+	fn put_AllowHardTerminate(&self, allow: bool) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_AllowHardTerminate)(self.ptr(), if allow { -1 } else { 0 }))
+		}
+	}
+
+	/// [`ITaskSettings::put_Compatibility`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_compatibility)
+	/// method.
+	fn put_Compatibility(&self, compatibility: co::TASK_COMPATIBILITY) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_Compatibility)(self.ptr(), compatibility.0))
+		}
+	}
+
+	/// [`ITaskSettings::put_DeleteExpiredTaskAfter`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_deleteexpiredtaskafter)
+	/// method.
+	fn put_DeleteExpiredTaskAfter(&self, expiration_delay: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(expiration_delay)?;
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_DeleteExpiredTaskAfter)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`ITaskSettings::put_DisallowStartIfOnBatteries`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_disallowstartifonbatteries)
+	/// method.
+	fn put_DisallowStartIfOnBatteries(&self, disallow: bool) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_DisallowStartIfOnBatteries)(self.ptr(), if disallow { -1 } else { 0 }))
+		}
+	}
+
+	/// [`ITaskSettings::put_Enabled`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_enabled)
+	/// method.
+	fn put_Enabled(&self, enabled: bool) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_Enabled)(self.ptr(), if enabled { -1 } else { 0 }))
+		}
+	}
+
+	/// [`ITaskSettings::put_ExecutionTimeLimit`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_executiontimelimit)
+	/// method.
+	fn put_ExecutionTimeLimit(&self, limit: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(limit)?;
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_ExecutionTimeLimit)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`ITaskSettings::put_Hidden`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_hidden)
+	/// method.
+	fn put_Hidden(&self, hidden: bool) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_Hidden)(self.ptr(), if hidden { -1 } else { 0 }))
+		}
+	}
+
+	/// [`ITaskSettings::put_MultipleInstances`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_multipleinstances)
+	/// method.
+	fn put_MultipleInstances(&self, policy: co::TASK_INSTANCES_POLICY) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_MultipleInstances)(self.ptr(), policy.0))
+		}
+	}
+
+	/// [`ITaskSettings::put_Priority`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_priority)
+	/// method.
+	fn put_Priority(&self, priority: i32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_Priority)(self.ptr(), priority))
+		}
+	}
+
+	/// [`ITaskSettings::put_RestartCount`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_restartcount)
+	/// method.
+	fn put_RestartCount(&self, count: i32) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_RestartCount)(self.ptr(), count))
+		}
+	}
+
+	/// [`ITaskSettings::put_RestartInterval`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_restartinterval)
+	/// method.
+	fn put_RestartInterval(&self, interval: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(interval)?;
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_RestartInterval)(self.ptr(), bstr.0))
+		}
+	}
+
+	/// [`ITaskSettings::put_RunOnlyIfIdle`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_runonlyifidle)
+	/// method.
+	fn put_RunOnlyIfIdle(&self, run: bool) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_RunOnlyIfIdle)(self.ptr(), if run { -1 } else { 0 }))
+		}
+	}
+
+	/// [`ITaskSettings::put_RunOnlyIfNetworkAvailable`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_runonlyifnetworkavailable)
+	/// method.
+	fn put_RunOnlyIfNetworkAvailable(&self, run: bool) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_RunOnlyIfNetworkAvailable)(self.ptr(), if run { -1 } else { 0 }))
+		}
+	}
+
+	/// [`ITaskSettings::put_StartWhenAvailable`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_startwhenavailable)
+	/// method.
+	fn put_StartWhenAvailable(&self, start: bool) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_StartWhenAvailable)(self.ptr(), if start { -1 } else { 0 }))
+		}
+	}
+
+	/// [`ITaskSettings::put_StopIfGoingOnBatteries`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_stopifgoingonbatteries)
+	/// method.
+	fn put_StopIfGoingOnBatteries(&self, stop: bool) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_StopIfGoingOnBatteries)(self.ptr(), if stop { -1 } else { 0 }))
+		}
+	}
+
+	/// [`ITaskSettings::put_WakeToRun`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_waketorun)
+	/// method.
+	fn put_WakeToRun(&self, wake: bool) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_WakeToRun)(self.ptr(), if wake { -1 } else { 0 }))
+		}
+	}
+
+	/// [`ITaskSettings::put_XmlText`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itasksettings-put_xmltext)
+	/// method.
+	fn put_XmlText(&self, xml: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(xml)?;
+		unsafe {
+			let vt = self.vt_ref::<ITaskSettingsVT>();
+			ok_to_hrresult((vt.put_XmlText)(self.ptr(), bstr.0))
+		}
+	}
+}
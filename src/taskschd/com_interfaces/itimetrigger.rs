@@ -0,0 +1,63 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::{HRES, PSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::BSTR;
+use crate::prelude::{oleaut_IDispatch, taskschd_ITrigger};
+use crate::vt::ITriggerVT;
+
+/// [`ITimeTrigger`](crate::ITimeTrigger) virtual table.
+#[repr(C)]
+pub struct ITimeTriggerVT {
+	pub ITriggerVT: ITriggerVT,
+	pub get_RandomDelay: fn(ComPtr, *mut PSTR) -> HRES,
+	pub put_RandomDelay: fn(ComPtr, PSTR) -> HRES,
+}
+
+com_interface! { ITimeTrigger: "b45747e0-eba7-4276-9f29-85c5bb300006";
+	/// [`ITimeTrigger`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nn-taskschd-itimetrigger)
+	/// COM interface over [`ITimeTriggerVT`](crate::vt::ITimeTriggerVT).
+	///
+	/// Trigger of type
+	/// [`co::TASK_TRIGGER_TYPE2::TIME`](crate::co::TASK_TRIGGER_TYPE2::TIME).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl oleaut_IDispatch for ITimeTrigger {}
+impl taskschd_ITrigger for ITimeTrigger {}
+impl taskschd_ITimeTrigger for ITimeTrigger {}
+
+/// This trait is enabled with the `taskschd` feature, and provides methods for
+/// [`ITimeTrigger`](crate::ITimeTrigger).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait taskschd_ITimeTrigger: taskschd_ITrigger {
+	/// [`ITimeTrigger::get_RandomDelay`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itimetrigger-get_randomdelay)
+	/// method.
+	#[must_use]
+	fn get_RandomDelay(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<ITimeTriggerVT>();
+			ok_to_hrresult((vt.get_RandomDelay)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`ITimeTrigger::put_RandomDelay`](https://learn.microsoft.com/en-us/windows/win32/api/taskschd/nf-taskschd-itimetrigger-put_randomdelay)
+	/// method.
+	fn put_RandomDelay(&self, delay: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(delay)?;
+		unsafe {
+			let vt = self.vt_ref::<ITimeTriggerVT>();
+			ok_to_hrresult((vt.put_RandomDelay)(self.ptr(), bstr.0))
+		}
+	}
+}
@@ -0,0 +1,81 @@
+mod iaction;
+mod iactioncollection;
+mod iboottrigger;
+mod idailytrigger;
+mod iexecaction;
+mod ilogontrigger;
+mod iprincipal;
+mod iregisteredtask;
+mod iregisteredtaskcollection;
+mod iregistrationinfo;
+mod itaskdefinition;
+mod itaskfolder;
+mod itaskfoldercollection;
+mod itaskservice;
+mod itasksettings;
+mod itimetrigger;
+mod itrigger;
+mod itriggercollection;
+
+pub mod decl {
+	pub use super::iaction::IAction;
+	pub use super::iactioncollection::IActionCollection;
+	pub use super::iboottrigger::IBootTrigger;
+	pub use super::idailytrigger::IDailyTrigger;
+	pub use super::iexecaction::IExecAction;
+	pub use super::ilogontrigger::ILogonTrigger;
+	pub use super::iprincipal::IPrincipal;
+	pub use super::iregisteredtask::IRegisteredTask;
+	pub use super::iregisteredtaskcollection::IRegisteredTaskCollection;
+	pub use super::iregistrationinfo::IRegistrationInfo;
+	pub use super::itaskdefinition::ITaskDefinition;
+	pub use super::itaskfolder::ITaskFolder;
+	pub use super::itaskfoldercollection::ITaskFolderCollection;
+	pub use super::itaskservice::ITaskService;
+	pub use super::itasksettings::ITaskSettings;
+	pub use super::itimetrigger::ITimeTrigger;
+	pub use super::itrigger::ITrigger;
+	pub use super::itriggercollection::ITriggerCollection;
+}
+
+pub mod traits {
+	pub use super::iaction::taskschd_IAction;
+	pub use super::iactioncollection::taskschd_IActionCollection;
+	pub use super::iboottrigger::taskschd_IBootTrigger;
+	pub use super::idailytrigger::taskschd_IDailyTrigger;
+	pub use super::iexecaction::taskschd_IExecAction;
+	pub use super::ilogontrigger::taskschd_ILogonTrigger;
+	pub use super::iprincipal::taskschd_IPrincipal;
+	pub use super::iregisteredtask::taskschd_IRegisteredTask;
+	pub use super::iregisteredtaskcollection::taskschd_IRegisteredTaskCollection;
+	pub use super::iregistrationinfo::taskschd_IRegistrationInfo;
+	pub use super::itaskdefinition::taskschd_ITaskDefinition;
+	pub use super::itaskfolder::taskschd_ITaskFolder;
+	pub use super::itaskfoldercollection::taskschd_ITaskFolderCollection;
+	pub use super::itaskservice::taskschd_ITaskService;
+	pub use super::itasksettings::taskschd_ITaskSettings;
+	pub use super::itimetrigger::taskschd_ITimeTrigger;
+	pub use super::itrigger::taskschd_ITrigger;
+	pub use super::itriggercollection::taskschd_ITriggerCollection;
+}
+
+pub mod vt {
+	pub use super::iaction::IActionVT;
+	pub use super::iactioncollection::IActionCollectionVT;
+	pub use super::iboottrigger::IBootTriggerVT;
+	pub use super::idailytrigger::IDailyTriggerVT;
+	pub use super::iexecaction::IExecActionVT;
+	pub use super::ilogontrigger::ILogonTriggerVT;
+	pub use super::iprincipal::IPrincipalVT;
+	pub use super::iregisteredtask::IRegisteredTaskVT;
+	pub use super::iregisteredtaskcollection::IRegisteredTaskCollectionVT;
+	pub use super::iregistrationinfo::IRegistrationInfoVT;
+	pub use super::itaskdefinition::ITaskDefinitionVT;
+	pub use super::itaskfolder::ITaskFolderVT;
+	pub use super::itaskfoldercollection::ITaskFolderCollectionVT;
+	pub use super::itaskservice::ITaskServiceVT;
+	pub use super::itasksettings::ITaskSettingsVT;
+	pub use super::itimetrigger::ITimeTriggerVT;
+	pub use super::itrigger::ITriggerVT;
+	pub use super::itriggercollection::ITriggerCollectionVT;
+}
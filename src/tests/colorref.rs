@@ -0,0 +1,28 @@
+//! Construction and hexadecimal notation of COLORREF.
+
+use crate::{co, COLORREF};
+
+#[test]
+fn components() {
+	let c = COLORREF::rgb(0x12, 0x34, 0x56);
+	assert_eq!(u32::from(c), 0x0056_3412); // stored as 0x00bbggrr
+	assert_eq!((c.GetRValue(), c.GetGValue(), c.GetBValue()), (0x12, 0x34, 0x56));
+	assert_eq!(COLORREF::from(0x0056_3412), c);
+}
+
+#[test]
+fn hex_round_trip() {
+	let c = COLORREF::rgb(0xff, 0x80, 0x00);
+	assert_eq!(c.to_hex(), "#ff8000");
+	assert_eq!(COLORREF::from_hex("#ff8000"), Ok(c));
+	assert_eq!(COLORREF::from_hex("FF8000"), Ok(c));
+	assert_eq!(COLORREF::from_hex("#f80"), Ok(COLORREF::rgb(0xff, 0x88, 0x00)));
+}
+
+#[test]
+fn hex_invalid() {
+	assert_eq!(COLORREF::from_hex(""), Err(co::ERROR::INVALID_DATA));
+	assert_eq!(COLORREF::from_hex("#ff80"), Err(co::ERROR::INVALID_DATA));
+	assert_eq!(COLORREF::from_hex("#gg8000"), Err(co::ERROR::INVALID_DATA));
+	assert_eq!(COLORREF::from_hex("#+f8000"), Err(co::ERROR::INVALID_DATA));
+}
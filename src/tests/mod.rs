@@ -0,0 +1,5 @@
+//! Unit tests of the code which does not depend on a live Windows session.
+
+#[cfg(feature = "user")] mod colorref;
+#[cfg(feature = "user")] mod msg_packing;
+mod w_string;
@@ -0,0 +1,221 @@
+//! Packing and unpacking of window message parameters, which must match the
+//! encoding documented for each message.
+
+use crate::prelude::*;
+use crate::{co, AccelMenuCtrl, AccelMenuCtrlData, HWND, MAKEDWORD, POINT, SIZE};
+use crate::msg::{wm, WndMsg};
+
+/// Packs two signed coordinates like MAKELPARAM does.
+fn lparam_coords(x: i16, y: i16) -> isize {
+	MAKEDWORD(x as _, y as _) as _
+}
+
+/// Builds the parameters of a message, as received by the window procedure.
+fn raw(msg_id: co::WM, wparam: usize, lparam: isize) -> WndMsg {
+	WndMsg { msg_id, wparam, lparam }
+}
+
+fn fake_hwnd() -> HWND {
+	unsafe { HWND::from_ptr(0x1234 as _) }
+}
+
+#[test]
+fn activate() {
+	let mut m = wm::Activate {
+		event: co::WA::CLICKACTIVE,
+		is_minimized: true,
+		hwnd: fake_hwnd(),
+	};
+	let p = m.as_generic_wm();
+	assert_eq!(p.msg_id, co::WM::ACTIVATE);
+	assert_eq!(p.wparam, 0x0001_0002);
+	assert_eq!(p.lparam, 0x1234);
+
+	let m = wm::Activate::from_generic_wm(p);
+	assert_eq!(m.event, co::WA::CLICKACTIVE);
+	assert!(m.is_minimized);
+	assert_eq!(m.hwnd, fake_hwnd());
+}
+
+#[test]
+fn command_accel_menu_ctrl() {
+	let p = wm::Command { event: AccelMenuCtrl::Accel(40) }.as_generic_wm();
+	assert_eq!(p.wparam, 0x0001_0028);
+	match wm::Command::from_generic_wm(p).event {
+		AccelMenuCtrl::Accel(id) => assert_eq!(id, 40),
+		_ => panic!("Expected accelerator."),
+	}
+
+	let p = wm::Command { event: AccelMenuCtrl::Menu(41) }.as_generic_wm();
+	assert_eq!(p.wparam, 0x0000_0029);
+	match wm::Command::from_generic_wm(p).event {
+		AccelMenuCtrl::Menu(id) => assert_eq!(id, 41),
+		_ => panic!("Expected menu."),
+	}
+
+	let p = wm::Command {
+		event: AccelMenuCtrl::Ctrl(AccelMenuCtrlData {
+			notif_code: co::EN::CHANGE.into(),
+			ctrl_id: 1001,
+			ctrl_hwnd: fake_hwnd(),
+		}),
+	}.as_generic_wm();
+	assert_eq!(p.wparam, MAKEDWORD(1001, u16::from(co::EN::CHANGE)) as usize);
+	assert_eq!(p.lparam, 0x1234);
+	match wm::Command::from_generic_wm(p).event {
+		AccelMenuCtrl::Ctrl(data) => {
+			assert_eq!(data.notif_code, co::EN::CHANGE.into());
+			assert_eq!(data.ctrl_id, 1001);
+			assert_eq!(data.ctrl_hwnd, fake_hwnd());
+		},
+		_ => panic!("Expected control."),
+	}
+}
+
+#[test]
+fn context_menu_negative_coords() {
+	// Screen coordinates are negative on monitors at left or above the
+	// primary one.
+	let p = raw(co::WM::CONTEXTMENU, 0x1234, lparam_coords(-300, -20));
+	let m = wm::ContextMenu::from_generic_wm(p);
+	assert_eq!(m.hwnd, fake_hwnd());
+	assert!(m.cursor_pos == POINT::new(-300, -20));
+
+	let mut m = wm::ContextMenu { hwnd: fake_hwnd(), cursor_pos: POINT::new(-1, 5) };
+	assert_eq!(m.as_generic_wm().lparam, lparam_coords(-1, 5));
+}
+
+#[test]
+fn mouse_coords() {
+	let p = raw(co::WM::MOUSEMOVE, u16::from(co::VK::LBUTTON) as _, lparam_coords(150, 90));
+	let m = wm::MouseMove::from_generic_wm(p);
+	assert_eq!(m.vkey_code, co::VK::LBUTTON);
+	assert!(m.coords == POINT::new(150, 90));
+
+	// While the mouse is captured, coordinates go negative outside the window.
+	let p = raw(co::WM::LBUTTONUP, 0, lparam_coords(-8, -32768));
+	assert!(wm::LButtonUp::from_generic_wm(p).coords == POINT::new(-8, -32768));
+}
+
+#[test]
+fn move_and_nc_hit_test() {
+	let p = raw(co::WM::MOVE, 0, lparam_coords(-1920, 0));
+	assert!(wm::Move::from_generic_wm(p).coords == POINT::new(-1920, 0));
+
+	let p = raw(co::WM::NCHITTEST, 0, lparam_coords(32767, -5));
+	assert!(wm::NcHitTest::from_generic_wm(p).cursor_pos == POINT::new(32767, -5));
+}
+
+#[test]
+fn size() {
+	let mut m = wm::Size {
+		request: co::SIZE_R::MAXIMIZED,
+		client_area: SIZE::new(1920, 1017),
+	};
+	let p = m.as_generic_wm();
+	assert_eq!(p.wparam, u8::from(co::SIZE_R::MAXIMIZED) as usize);
+	assert_eq!(p.lparam, MAKEDWORD(1920, 1017) as isize);
+
+	let m = wm::Size::from_generic_wm(p);
+	assert_eq!(m.request, co::SIZE_R::MAXIMIZED);
+	assert!(m.client_area == SIZE::new(1920, 1017));
+
+	// Sizes are unsigned, and may be larger than 32767.
+	let p = raw(co::WM::SIZE, 0, MAKEDWORD(40000, 65535) as _);
+	assert!(wm::Size::from_generic_wm(p).client_area == SIZE::new(40000, 65535));
+}
+
+#[test]
+fn h_scroll() {
+	let mut m = wm::HScroll {
+		scroll_box_pos: 77,
+		request: co::SB_REQ::THUMBTRACK,
+		hcontrol: None,
+	};
+	let p = m.as_generic_wm();
+	assert_eq!(p.wparam, MAKEDWORD(u16::from(co::SB_REQ::THUMBTRACK), 77) as usize);
+	assert_eq!(p.lparam, 0);
+
+	let m = wm::HScroll::from_generic_wm(p);
+	assert_eq!(m.scroll_box_pos, 77);
+	assert_eq!(m.request, co::SB_REQ::THUMBTRACK);
+	assert!(m.hcontrol.is_none());
+
+	let p = raw(co::WM::HSCROLL, 0, 0x1234);
+	assert_eq!(wm::HScroll::from_generic_wm(p).hcontrol, Some(fake_hwnd()));
+}
+
+#[test]
+fn key_down_flags() {
+	let mut m = wm::KeyDown {
+		vkey_code: co::VK::CHAR_A,
+		repeat_count: 3,
+		scan_code: 0x1e,
+		is_extended_key: true,
+		has_alt_key: false,
+		key_was_previously_down: true,
+		key_is_being_released: false,
+	};
+	let p = m.as_generic_wm();
+	assert_eq!(p.wparam, u16::from(co::VK::CHAR_A) as usize);
+	assert_eq!(p.lparam, 0x411e_0003); // bits 24 and 30 set
+
+	let m = wm::KeyDown::from_generic_wm(p);
+	assert_eq!(m.vkey_code, co::VK::CHAR_A);
+	assert_eq!(m.repeat_count, 3);
+	assert_eq!(m.scan_code, 0x1e);
+	assert!(m.is_extended_key);
+	assert!(!m.has_alt_key);
+	assert!(m.key_was_previously_down);
+	assert!(!m.key_is_being_released);
+}
+
+#[test]
+fn char_released_with_alt() {
+	let p = raw(co::WM::CHAR, 'x' as _, 0xa02d_0001_u32 as _);
+	let m = wm::Char::from_generic_wm(p);
+	assert_eq!(m.char_code, 'x' as u16);
+	assert_eq!(m.repeat_count, 1);
+	assert_eq!(m.scan_code, 0x2d);
+	assert!(!m.is_extended_key);
+	assert!(m.has_alt_key);
+	assert!(!m.key_was_previously_down);
+	assert!(m.key_is_being_released);
+}
+
+#[test]
+fn dpi_changed() {
+	let rc = crate::RECT { left: -10, top: 20, right: 1000, bottom: 800 };
+	let mut m = wm::DpiChanged { new_dpi_x: 144, new_dpi_y: 120, suggested_rect: &rc };
+	let p = m.as_generic_wm();
+	assert_eq!(p.wparam, MAKEDWORD(144, 120) as usize);
+
+	let m = wm::DpiChanged::from_generic_wm(p);
+	assert_eq!(m.new_dpi_x, 144);
+	assert_eq!(m.new_dpi_y, 120);
+	assert!(*m.suggested_rect == rc);
+}
+
+#[test]
+fn show_window_and_set_icon() {
+	let p = raw(co::WM::SHOWWINDOW, 1, u8::from(co::SW_S::PARENTOPENING) as _);
+	let m = wm::ShowWindow::from_generic_wm(p);
+	assert!(m.being_shown);
+	assert_eq!(m.status, co::SW_S::PARENTOPENING);
+
+	let mut m = wm::SetIcon {
+		size: co::ICON_SZ::SMALL,
+		hicon: unsafe { crate::HICON::from_ptr(0x5678 as _) },
+	};
+	let p = m.as_generic_wm();
+	assert_eq!(p.wparam, u8::from(co::ICON_SZ::SMALL) as usize);
+	assert_eq!(p.lparam, 0x5678);
+	assert!(m.convert_ret(0).is_none());
+}
+
+#[test]
+fn point_dword_round_trip() {
+	for pt in [POINT::new(0, 0), POINT::new(-1, -1), POINT::new(32767, -32768), POINT::new(-500, 12)] {
+		assert!(POINT::from(u32::from(pt)) == pt);
+	}
+}
@@ -0,0 +1,63 @@
+//! Conversions of the UTF-16 strings.
+
+use crate::{Encoding, WString};
+
+#[test]
+fn from_str_to_string() {
+	let w = WString::from_str("Hello, world");
+	assert_eq!(w.to_string(), "Hello, world");
+	assert_eq!(w.str_len(), 12);
+	assert_eq!(w.as_slice().last(), Some(&0)); // null-terminated
+}
+
+#[test]
+fn long_string_on_heap() {
+	let s = "abcdefghij".repeat(50); // longer than the stack buffer
+	let w = WString::from_str(&s);
+	assert_eq!(w.to_string(), s);
+	assert_eq!(w.str_len(), 500);
+}
+
+#[test]
+fn non_bmp_chars() {
+	let s = "caf\u{e9} \u{1f600}"; // surrogate pair at the end
+	let w = WString::from_str(s);
+	assert_eq!(w.str_len(), 7);
+	assert_eq!(w.to_string(), s);
+}
+
+#[test]
+fn empty_and_none() {
+	assert_eq!(WString::from_str("").to_string(), "");
+	assert_eq!(WString::from_opt_str(None).to_string(), "");
+	assert_eq!(WString::default().to_string(), "");
+}
+
+#[test]
+fn from_wchars_slice_stops_at_null() {
+	let w = WString::from_wchars_slice(&[0x41, 0x42, 0x00, 0x43]);
+	assert_eq!(w.to_string(), "AB");
+}
+
+#[test]
+fn parse_utf16_with_bom() {
+	let le = [0xff, 0xfe, b'h', 0x00, b'i', 0x00];
+	assert!(Encoding::guess(&le) == (Encoding::Utf16le, 2));
+	assert_eq!(WString::parse(&le).unwrap().to_string(), "hi");
+
+	let be = [0xfe, 0xff, 0x00, b'h', 0x00, b'i', 0x00]; // odd byte is discarded
+	assert!(Encoding::guess(&be) == (Encoding::Utf16be, 2));
+	assert_eq!(WString::parse(&be).unwrap().to_string(), "hi");
+}
+
+#[test]
+fn parse_utf8_with_bom() {
+	let data = [0xef, 0xbb, 0xbf, 0xc3, 0xa9, b'!'];
+	assert!(Encoding::guess(&data) == (Encoding::Utf8, 3));
+	assert_eq!(WString::parse(&data).unwrap().to_string(), "\u{e9}!");
+}
+
+#[test]
+fn parse_empty() {
+	assert_eq!(WString::parse(&[]).unwrap().to_string(), "");
+}
@@ -0,0 +1,166 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+use crate::co::CLSID;
+
+const_guid_values! { CLSID;
+	CUIAutomation "ff48dba4-60ef-4201-aa87-54103eef594e"
+}
+
+const_bitflag! { TREESCOPE: i32;
+	/// [`TreeScope`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationcore/ne-uiautomationcore-treescope)
+	/// enumeration (`i32`).
+	=>
+	=>
+	NONE 0
+	ELEMENT 0x1
+	CHILDREN 0x2
+	DESCENDANTS 0x4
+	PARENT 0x8
+	ANCESTORS 0x10
+	SUBTREE 0x7
+}
+
+const_ordinary! { UIA_CONTROLTYPEID: i32;
+	/// UI Automation
+	/// [control type identifiers](https://learn.microsoft.com/en-us/windows/win32/winauto/uiauto-controltype-ids)
+	/// (`i32`).
+	=>
+	=>
+	BUTTON 50000
+	CALENDAR 50001
+	CHECKBOX 50002
+	COMBOBOX 50003
+	EDIT 50004
+	HYPERLINK 50005
+	IMAGE 50006
+	LISTITEM 50007
+	LIST 50008
+	MENU 50009
+	MENUBAR 50010
+	MENUITEM 50011
+	PROGRESSBAR 50012
+	RADIOBUTTON 50013
+	SCROLLBAR 50014
+	SLIDER 50015
+	SPINNER 50016
+	STATUSBAR 50017
+	TAB 50018
+	TABITEM 50019
+	TEXT 50020
+	TOOLBAR 50021
+	TOOLTIP 50022
+	TREE 50023
+	TREEITEM 50024
+	CUSTOM 50025
+	GROUP 50026
+	THUMB 50027
+	DATAGRID 50028
+	DATAITEM 50029
+	DOCUMENT 50030
+	SPLITBUTTON 50031
+	WINDOW 50032
+	PANE 50033
+	HEADER 50034
+	HEADERITEM 50035
+	TABLE 50036
+	TITLEBAR 50037
+	SEPARATOR 50038
+}
+
+const_ordinary! { UIA_EVENTID: i32;
+	/// UI Automation
+	/// [event identifiers](https://learn.microsoft.com/en-us/windows/win32/winauto/uiauto-event-ids)
+	/// (`i32`).
+	=>
+	=>
+	TOOLTIP_OPENED 20000
+	TOOLTIP_CLOSED 20001
+	STRUCTURE_CHANGED 20002
+	MENU_OPENED 20003
+	AUTOMATION_PROPERTY_CHANGED 20004
+	AUTOMATION_FOCUS_CHANGED 20005
+	ASYNC_CONTENT_LOADED 20006
+	MENU_CLOSED 20007
+	LAYOUT_INVALIDATED 20008
+	INVOKE_INVOKED 20009
+	SELECTIONITEM_ELEMENT_ADDED_TO_SELECTION 20010
+	SELECTIONITEM_ELEMENT_REMOVED_FROM_SELECTION 20011
+	SELECTIONITEM_ELEMENT_SELECTED 20012
+	SELECTION_INVALIDATED 20013
+	TEXT_TEXT_SELECTION_CHANGED 20014
+	TEXT_TEXT_CHANGED 20015
+	WINDOW_WINDOW_OPENED 20016
+	WINDOW_WINDOW_CLOSED 20017
+	MENU_MODE_START 20018
+	MENU_MODE_END 20019
+	INPUT_REACHED_TARGET 20020
+	INPUT_REACHED_OTHER_ELEMENT 20021
+	INPUT_DISCARDED 20022
+}
+
+const_ordinary! { UIA_PATTERNID: i32;
+	/// UI Automation
+	/// [control pattern identifiers](https://learn.microsoft.com/en-us/windows/win32/winauto/uiauto-controlpattern-ids)
+	/// (`i32`).
+	=>
+	=>
+	INVOKE 10000
+	SELECTION 10001
+	VALUE 10002
+	RANGE_VALUE 10003
+	SCROLL 10004
+	EXPAND_COLLAPSE 10005
+	GRID 10006
+	GRID_ITEM 10007
+	MULTIPLE_VIEW 10008
+	WINDOW 10009
+	SELECTION_ITEM 10010
+	DOCK 10011
+	TABLE 10012
+	TABLE_ITEM 10013
+	TEXT 10014
+	TOGGLE 10015
+	TRANSFORM 10016
+	SCROLL_ITEM 10017
+	LEGACY_IACCESSIBLE 10018
+}
+
+const_ordinary! { UIA_PROPERTYID: i32;
+	/// UI Automation
+	/// [property identifiers](https://learn.microsoft.com/en-us/windows/win32/winauto/uiauto-automation-element-propids)
+	/// (`i32`).
+	=>
+	=>
+	RUNTIME_ID 30000
+	BOUNDING_RECTANGLE 30001
+	PROCESS_ID 30002
+	CONTROL_TYPE 30003
+	LOCALIZED_CONTROL_TYPE 30004
+	NAME 30005
+	ACCELERATOR_KEY 30006
+	ACCESS_KEY 30007
+	HAS_KEYBOARD_FOCUS 30008
+	IS_KEYBOARD_FOCUSABLE 30009
+	IS_ENABLED 30010
+	AUTOMATION_ID 30011
+	CLASS_NAME 30012
+	HELP_TEXT 30013
+	CLICKABLE_POINT 30014
+	CULTURE 30015
+	IS_CONTROL_ELEMENT 30016
+	IS_CONTENT_ELEMENT 30017
+	LABELED_BY 30018
+	IS_PASSWORD 30019
+	NATIVE_WINDOW_HANDLE 30020
+	ITEM_TYPE 30021
+	IS_OFFSCREEN 30022
+	ORIENTATION 30023
+	FRAMEWORK_ID 30024
+	IS_REQUIRED_FOR_FORM 30025
+	ITEM_STATUS 30026
+	VALUE_VALUE 30045
+	VALUE_IS_READ_ONLY 30046
+	SELECTION_CAN_SELECT_MULTIPLE 30060
+	SELECTION_IS_SELECTION_REQUIRED 30061
+	SELECTIONITEM_IS_SELECTED 30079
+}
@@ -0,0 +1,491 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{BOOL, HANDLE, HRES, PSTR, PVOID};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::{BSTR, VARIANT};
+use crate::prelude::{
+	Handle, ole_IUnknown, uiautomation_IUIAutomationCondition,
+	uiautomation_IUIAutomationElement, uiautomation_IUIAutomationEventHandler,
+	uiautomation_IUIAutomationFocusChangedEventHandler,
+};
+use crate::uiautomation::decl::{IUIAutomationCondition, IUIAutomationElement};
+use crate::user::decl::{HWND, POINT, RECT};
+use crate::vt::IUnknownVT;
+
+/// [`IUIAutomation`](crate::IUIAutomation) virtual table.
+#[repr(C)]
+pub struct IUIAutomationVT {
+	pub IUnknownVT: IUnknownVT,
+	pub CompareElements: fn(ComPtr, ComPtr, ComPtr, *mut BOOL) -> HRES,
+	pub CompareRuntimeIds: fn(ComPtr, PVOID, PVOID, *mut BOOL) -> HRES,
+	pub GetRootElement: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub ElementFromHandle: fn(ComPtr, HANDLE, *mut ComPtr) -> HRES,
+	pub ElementFromPoint: fn(ComPtr, u64, *mut ComPtr) -> HRES,
+	pub GetFocusedElement: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub GetRootElementBuildCache: fn(ComPtr, ComPtr, *mut ComPtr) -> HRES,
+	pub ElementFromHandleBuildCache: fn(ComPtr, HANDLE, ComPtr, *mut ComPtr) -> HRES,
+	pub ElementFromPointBuildCache: fn(ComPtr, u64, ComPtr, *mut ComPtr) -> HRES,
+	pub GetFocusedElementBuildCache: fn(ComPtr, ComPtr, *mut ComPtr) -> HRES,
+	pub CreateTreeWalker: fn(ComPtr, ComPtr, *mut ComPtr) -> HRES,
+	pub get_ControlViewWalker: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_ContentViewWalker: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_RawViewWalker: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_RawViewCondition: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_ControlViewCondition: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_ContentViewCondition: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub CreateCacheRequest: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub CreateTrueCondition: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub CreateFalseCondition: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub CreatePropertyCondition: fn(ComPtr, i32, VARIANT, *mut ComPtr) -> HRES,
+	pub CreatePropertyConditionEx: fn(ComPtr, i32, VARIANT, i32, *mut ComPtr) -> HRES,
+	pub CreateAndCondition: fn(ComPtr, ComPtr, ComPtr, *mut ComPtr) -> HRES,
+	pub CreateAndConditionFromArray: fn(ComPtr, PVOID, *mut ComPtr) -> HRES,
+	pub CreateAndConditionFromNativeArray: fn(ComPtr, *const ComPtr, i32, *mut ComPtr) -> HRES,
+	pub CreateOrCondition: fn(ComPtr, ComPtr, ComPtr, *mut ComPtr) -> HRES,
+	pub CreateOrConditionFromArray: fn(ComPtr, PVOID, *mut ComPtr) -> HRES,
+	pub CreateOrConditionFromNativeArray: fn(ComPtr, *const ComPtr, i32, *mut ComPtr) -> HRES,
+	pub CreateNotCondition: fn(ComPtr, ComPtr, *mut ComPtr) -> HRES,
+	pub AddAutomationEventHandler: fn(ComPtr, i32, ComPtr, i32, ComPtr, ComPtr) -> HRES,
+	pub RemoveAutomationEventHandler: fn(ComPtr, i32, ComPtr, ComPtr) -> HRES,
+	pub AddPropertyChangedEventHandlerNativeArray: fn(ComPtr, ComPtr, i32, ComPtr, ComPtr, *const i32, i32) -> HRES,
+	pub AddPropertyChangedEventHandler: fn(ComPtr, ComPtr, i32, ComPtr, ComPtr, PVOID) -> HRES,
+	pub RemovePropertyChangedEventHandler: fn(ComPtr, ComPtr, ComPtr) -> HRES,
+	pub AddStructureChangedEventHandler: fn(ComPtr, ComPtr, i32, ComPtr, ComPtr) -> HRES,
+	pub RemoveStructureChangedEventHandler: fn(ComPtr, ComPtr, ComPtr) -> HRES,
+	pub AddFocusChangedEventHandler: fn(ComPtr, ComPtr, ComPtr) -> HRES,
+	pub RemoveFocusChangedEventHandler: fn(ComPtr, ComPtr) -> HRES,
+	pub RemoveAllEventHandlers: fn(ComPtr) -> HRES,
+	pub IntNativeArrayToSafeArray: fn(ComPtr, *const i32, i32, *mut PVOID) -> HRES,
+	pub IntSafeArrayToNativeArray: fn(ComPtr, PVOID, *mut *mut i32, *mut i32) -> HRES,
+	pub RectToVariant: fn(ComPtr, RECT, PVOID) -> HRES,
+	pub VariantToRect: fn(ComPtr, VARIANT, *mut RECT) -> HRES,
+	pub SafeArrayToRectNativeArray: fn(ComPtr, PVOID, *mut *mut RECT, *mut i32) -> HRES,
+	pub CreateProxyFactoryEntry: fn(ComPtr, ComPtr, *mut ComPtr) -> HRES,
+	pub get_ProxyFactoryMapping: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub GetPropertyProgrammaticName: fn(ComPtr, i32, *mut PSTR) -> HRES,
+	pub GetPatternProgrammaticName: fn(ComPtr, i32, *mut PSTR) -> HRES,
+	pub PollForPotentialSupportedPatterns: fn(ComPtr, ComPtr, *mut PVOID, *mut PVOID) -> HRES,
+	pub PollForPotentialSupportedProperties: fn(ComPtr, ComPtr, *mut PVOID, *mut PVOID) -> HRES,
+	pub CheckNotSupported: fn(ComPtr, VARIANT, *mut BOOL) -> HRES,
+	pub get_ReservedNotSupportedValue: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_ReservedMixedAttributeValue: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub ElementFromIAccessible: fn(ComPtr, ComPtr, i32, *mut ComPtr) -> HRES,
+	pub ElementFromIAccessibleBuildCache: fn(ComPtr, ComPtr, i32, ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IUIAutomation: "30cbe57d-d9d0-452a-ab13-7ac5ac4825ee";
+	/// [`IUIAutomation`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nn-uiautomationclient-iuiautomation)
+	/// COM interface over [`IUIAutomationVT`](crate::vt::IUIAutomationVT).
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{self as w, co};
+	///
+	/// let _com_guard = w::CoInitializeEx(
+	///     co::COINIT::MULTITHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+	///
+	/// let uia = w::CoCreateInstance::<w::IUIAutomation>(
+	///     &co::CLSID::CUIAutomation,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	///
+	/// let focused = uia.GetFocusedElement()?;
+	/// println!("Focused: {} ({})",
+	///     focused.get_CurrentName()?,
+	///     focused.get_CurrentLocalizedControlType()?);
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl uiautomation_IUIAutomation for IUIAutomation {}
+
+/// This trait is enabled with the `uiautomation` feature, and provides methods for
+/// [`IUIAutomation`](crate::IUIAutomation).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait uiautomation_IUIAutomation: ole_IUnknown {
+	/// [`IUIAutomation::AddAutomationEventHandler`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-addautomationeventhandler)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Watching for windows being opened:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IUIAutomation, UIAutomationEventHandler};
+	///
+	/// let uia: IUIAutomation; // initialized somewhere
+	/// # let uia = IUIAutomation::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// let handler = UIAutomationEventHandler::new()
+	///     .handle_automation_event(|sender, _event_id| {
+	///         println!("Window opened: {}", sender.get_CurrentName()?);
+	///         Ok(())
+	///     })
+	///     .build();
+	///
+	/// uia.AddAutomationEventHandler(
+	///     co::UIA_EVENTID::WINDOW_WINDOW_OPENED,
+	///     &uia.GetRootElement()?,
+	///     co::TREESCOPE::SUBTREE,
+	///     &handler,
+	/// )?;
+	///
+	/// // ...
+	///
+	/// uia.RemoveAllEventHandlers()?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	fn AddAutomationEventHandler(&self,
+		event_id: co::UIA_EVENTID,
+		element: &impl uiautomation_IUIAutomationElement,
+		scope: co::TREESCOPE,
+		handler: &impl uiautomation_IUIAutomationEventHandler,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult(
+				(vt.AddAutomationEventHandler)(
+					self.ptr(),
+					event_id.0,
+					element.ptr(),
+					scope.0,
+					ComPtr::null(),
+					handler.ptr(),
+				),
+			)
+		}
+	}
+
+	/// [`IUIAutomation::AddFocusChangedEventHandler`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-addfocuschangedeventhandler)
+	/// method.
+	fn AddFocusChangedEventHandler(&self,
+		handler: &impl uiautomation_IUIAutomationFocusChangedEventHandler,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult(
+				(vt.AddFocusChangedEventHandler)(
+					self.ptr(),
+					ComPtr::null(),
+					handler.ptr(),
+				),
+			)
+		}
+	}
+
+	/// [`IUIAutomation::CompareElements`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-compareelements)
+	/// method.
+	#[must_use]
+	fn CompareElements(&self,
+		el1: &impl uiautomation_IUIAutomationElement,
+		el2: &impl uiautomation_IUIAutomationElement,
+	) -> HrResult<bool>
+	{
+		let mut are_same: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult(
+				(vt.CompareElements)(self.ptr(), el1.ptr(), el2.ptr(), &mut are_same),
+			)
+		}.map(|_| are_same != 0)
+	}
+
+	/// [`IUIAutomation::CreateAndCondition`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-createandcondition)
+	/// method.
+	#[must_use]
+	fn CreateAndCondition(&self,
+		condition1: &impl uiautomation_IUIAutomationCondition,
+		condition2: &impl uiautomation_IUIAutomationCondition,
+	) -> HrResult<IUIAutomationCondition>
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult(
+				(vt.CreateAndCondition)(
+					self.ptr(),
+					condition1.ptr(),
+					condition2.ptr(),
+					&mut ppv_queried,
+				),
+			).map(|_| IUIAutomationCondition::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomation::CreateFalseCondition`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-createfalsecondition)
+	/// method.
+	#[must_use]
+	fn CreateFalseCondition(&self) -> HrResult<IUIAutomationCondition> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult((vt.CreateFalseCondition)(self.ptr(), &mut ppv_queried))
+				.map(|_| IUIAutomationCondition::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomation::CreateNotCondition`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-createnotcondition)
+	/// method.
+	#[must_use]
+	fn CreateNotCondition(&self,
+		condition: &impl uiautomation_IUIAutomationCondition,
+	) -> HrResult<IUIAutomationCondition>
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult(
+				(vt.CreateNotCondition)(self.ptr(), condition.ptr(), &mut ppv_queried),
+			).map(|_| IUIAutomationCondition::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomation::CreateOrCondition`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-createorcondition)
+	/// method.
+	#[must_use]
+	fn CreateOrCondition(&self,
+		condition1: &impl uiautomation_IUIAutomationCondition,
+		condition2: &impl uiautomation_IUIAutomationCondition,
+	) -> HrResult<IUIAutomationCondition>
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult(
+				(vt.CreateOrCondition)(
+					self.ptr(),
+					condition1.ptr(),
+					condition2.ptr(),
+					&mut ppv_queried,
+				),
+			).map(|_| IUIAutomationCondition::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomation::CreatePropertyCondition`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-createpropertycondition)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IUIAutomation, VARIANT};
+	///
+	/// let uia: IUIAutomation; // initialized somewhere
+	/// # let uia = IUIAutomation::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// let cond = uia.CreatePropertyCondition(
+	///     co::UIA_PROPERTYID::AUTOMATION_ID,
+	///     &VARIANT::new_bstr("OkButton")?,
+	/// )?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn CreatePropertyCondition(&self,
+		property_id: co::UIA_PROPERTYID,
+		value: &VARIANT,
+	) -> HrResult<IUIAutomationCondition>
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult(
+				(vt.CreatePropertyCondition)(
+					self.ptr(),
+					property_id.0,
+					std::ptr::read(value), // shallow copy, the callee doesn't take ownership
+					&mut ppv_queried,
+				),
+			).map(|_| IUIAutomationCondition::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomation::CreateTrueCondition`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-createtruecondition)
+	/// method.
+	#[must_use]
+	fn CreateTrueCondition(&self) -> HrResult<IUIAutomationCondition> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult((vt.CreateTrueCondition)(self.ptr(), &mut ppv_queried))
+				.map(|_| IUIAutomationCondition::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomation::ElementFromHandle`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-elementfromhandle)
+	/// method.
+	#[must_use]
+	fn ElementFromHandle(&self, hwnd: &HWND) -> HrResult<IUIAutomationElement> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult(
+				(vt.ElementFromHandle)(self.ptr(), hwnd.as_ptr(), &mut ppv_queried),
+			).map(|_| IUIAutomationElement::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomation::ElementFromPoint`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-elementfrompoint)
+	/// method.
+	///
+	/// The point is in screen coordinates.
+	#[must_use]
+	fn ElementFromPoint(&self, pt: POINT) -> HrResult<IUIAutomationElement> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult(
+				(vt.ElementFromPoint)(
+					self.ptr(),
+					(pt.x as u32 as u64) | ((pt.y as u32 as u64) << 32), // POINT passed by value
+					&mut ppv_queried,
+				),
+			).map(|_| IUIAutomationElement::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomation::get_ContentViewCondition`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-get_contentviewcondition)
+	/// method.
+	#[must_use]
+	fn get_ContentViewCondition(&self) -> HrResult<IUIAutomationCondition> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult((vt.get_ContentViewCondition)(self.ptr(), &mut ppv_queried))
+				.map(|_| IUIAutomationCondition::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomation::get_ControlViewCondition`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-get_controlviewcondition)
+	/// method.
+	#[must_use]
+	fn get_ControlViewCondition(&self) -> HrResult<IUIAutomationCondition> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult((vt.get_ControlViewCondition)(self.ptr(), &mut ppv_queried))
+				.map(|_| IUIAutomationCondition::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomation::get_RawViewCondition`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-get_rawviewcondition)
+	/// method.
+	#[must_use]
+	fn get_RawViewCondition(&self) -> HrResult<IUIAutomationCondition> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult((vt.get_RawViewCondition)(self.ptr(), &mut ppv_queried))
+				.map(|_| IUIAutomationCondition::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomation::GetFocusedElement`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-getfocusedelement)
+	/// method.
+	#[must_use]
+	fn GetFocusedElement(&self) -> HrResult<IUIAutomationElement> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult((vt.GetFocusedElement)(self.ptr(), &mut ppv_queried))
+				.map(|_| IUIAutomationElement::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomation::GetPatternProgrammaticName`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-getpatternprogrammaticname)
+	/// method.
+	#[must_use]
+	fn GetPatternProgrammaticName(&self, pattern_id: co::UIA_PATTERNID) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult((vt.GetPatternProgrammaticName)(self.ptr(), pattern_id.0, &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomation::GetPropertyProgrammaticName`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-getpropertyprogrammaticname)
+	/// method.
+	#[must_use]
+	fn GetPropertyProgrammaticName(&self, property_id: co::UIA_PROPERTYID) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult((vt.GetPropertyProgrammaticName)(self.ptr(), property_id.0, &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomation::GetRootElement`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-getrootelement)
+	/// method.
+	///
+	/// The root element is the desktop.
+	#[must_use]
+	fn GetRootElement(&self) -> HrResult<IUIAutomationElement> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult((vt.GetRootElement)(self.ptr(), &mut ppv_queried))
+				.map(|_| IUIAutomationElement::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomation::RemoveAllEventHandlers`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-removealleventhandlers)
+	/// method.
+	fn RemoveAllEventHandlers(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult((vt.RemoveAllEventHandlers)(self.ptr()))
+		}
+	}
+
+	/// [`IUIAutomation::RemoveAutomationEventHandler`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-removeautomationeventhandler)
+	/// method.
+	fn RemoveAutomationEventHandler(&self,
+		event_id: co::UIA_EVENTID,
+		element: &impl uiautomation_IUIAutomationElement,
+		handler: &impl uiautomation_IUIAutomationEventHandler,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult(
+				(vt.RemoveAutomationEventHandler)(
+					self.ptr(),
+					event_id.0,
+					element.ptr(),
+					handler.ptr(),
+				),
+			)
+		}
+	}
+
+	/// [`IUIAutomation::RemoveFocusChangedEventHandler`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomation-removefocuschangedeventhandler)
+	/// method.
+	fn RemoveFocusChangedEventHandler(&self,
+		handler: &impl uiautomation_IUIAutomationFocusChangedEventHandler,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationVT>();
+			ok_to_hrresult(
+				(vt.RemoveFocusChangedEventHandler)(self.ptr(), handler.ptr()),
+			)
+		}
+	}
+}
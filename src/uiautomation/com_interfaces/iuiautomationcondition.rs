@@ -0,0 +1,35 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::ole::decl::ComPtr;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IUIAutomationCondition`](crate::IUIAutomationCondition) virtual table.
+#[repr(C)]
+pub struct IUIAutomationConditionVT {
+	pub IUnknownVT: IUnknownVT,
+}
+
+com_interface! { IUIAutomationCondition: "352ffba8-0973-437c-a61f-f64cafd81df9";
+	/// [`IUIAutomationCondition`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nn-uiautomationclient-iuiautomationcondition)
+	/// COM interface over [`IUIAutomationConditionVT`](crate::vt::IUIAutomationConditionVT).
+	///
+	/// Created with methods like
+	/// [`IUIAutomation::CreatePropertyCondition`](crate::prelude::uiautomation_IUIAutomation::CreatePropertyCondition).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl uiautomation_IUIAutomationCondition for IUIAutomationCondition {}
+
+/// This trait is enabled with the `uiautomation` feature, and provides methods for
+/// [`IUIAutomationCondition`](crate::IUIAutomationCondition).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait uiautomation_IUIAutomationCondition: ole_IUnknown {}
@@ -0,0 +1,572 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{BOOL, HANDLE, HRES, PCVOID, PSTR, PVOID};
+use crate::kernel::privs::ptr_to_option_handle;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::{BSTR, VARIANT};
+use crate::prelude::{ole_IUnknown, uiautomation_IUIAutomationCondition};
+use crate::uiautomation::decl::IUIAutomationElementArray;
+use crate::user::decl::{HWND, POINT, RECT};
+use crate::vt::IUnknownVT;
+
+/// [`IUIAutomationElement`](crate::IUIAutomationElement) virtual table.
+#[repr(C)]
+pub struct IUIAutomationElementVT {
+	pub IUnknownVT: IUnknownVT,
+	pub SetFocus: fn(ComPtr) -> HRES,
+	pub GetRuntimeId: fn(ComPtr, *mut PVOID) -> HRES,
+	pub FindFirst: fn(ComPtr, i32, ComPtr, *mut ComPtr) -> HRES,
+	pub FindAll: fn(ComPtr, i32, ComPtr, *mut ComPtr) -> HRES,
+	pub FindFirstBuildCache: fn(ComPtr, i32, ComPtr, ComPtr, *mut ComPtr) -> HRES,
+	pub FindAllBuildCache: fn(ComPtr, i32, ComPtr, ComPtr, *mut ComPtr) -> HRES,
+	pub BuildUpdatedCache: fn(ComPtr, ComPtr, *mut ComPtr) -> HRES,
+	pub GetCurrentPropertyValue: fn(ComPtr, i32, PVOID) -> HRES,
+	pub GetCurrentPropertyValueEx: fn(ComPtr, i32, BOOL, PVOID) -> HRES,
+	pub GetCachedPropertyValue: fn(ComPtr, i32, PVOID) -> HRES,
+	pub GetCachedPropertyValueEx: fn(ComPtr, i32, BOOL, PVOID) -> HRES,
+	pub GetCurrentPatternAs: fn(ComPtr, i32, PCVOID, *mut ComPtr) -> HRES,
+	pub GetCachedPatternAs: fn(ComPtr, i32, PCVOID, *mut ComPtr) -> HRES,
+	pub GetCurrentPattern: fn(ComPtr, i32, *mut ComPtr) -> HRES,
+	pub GetCachedPattern: fn(ComPtr, i32, *mut ComPtr) -> HRES,
+	pub GetCachedParent: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub GetCachedChildren: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_CurrentProcessId: fn(ComPtr, *mut i32) -> HRES,
+	pub get_CurrentControlType: fn(ComPtr, *mut i32) -> HRES,
+	pub get_CurrentLocalizedControlType: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CurrentName: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CurrentAcceleratorKey: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CurrentAccessKey: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CurrentHasKeyboardFocus: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CurrentIsKeyboardFocusable: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CurrentIsEnabled: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CurrentAutomationId: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CurrentClassName: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CurrentHelpText: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CurrentCulture: fn(ComPtr, *mut i32) -> HRES,
+	pub get_CurrentIsControlElement: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CurrentIsContentElement: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CurrentIsPassword: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CurrentNativeWindowHandle: fn(ComPtr, *mut HANDLE) -> HRES,
+	pub get_CurrentItemType: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CurrentIsOffscreen: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CurrentOrientation: fn(ComPtr, *mut i32) -> HRES,
+	pub get_CurrentFrameworkId: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CurrentIsRequiredForForm: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CurrentItemStatus: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CurrentBoundingRectangle: fn(ComPtr, *mut RECT) -> HRES,
+	pub get_CurrentLabeledBy: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_CurrentAriaRole: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CurrentAriaProperties: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CurrentIsDataValidForForm: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CurrentControllerFor: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_CurrentDescribedBy: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_CurrentFlowsTo: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_CurrentProviderDescription: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedProcessId: fn(ComPtr, *mut i32) -> HRES,
+	pub get_CachedControlType: fn(ComPtr, *mut i32) -> HRES,
+	pub get_CachedLocalizedControlType: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedName: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedAcceleratorKey: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedAccessKey: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedHasKeyboardFocus: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CachedIsKeyboardFocusable: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CachedIsEnabled: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CachedAutomationId: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedClassName: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedHelpText: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedCulture: fn(ComPtr, *mut i32) -> HRES,
+	pub get_CachedIsControlElement: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CachedIsContentElement: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CachedIsPassword: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CachedNativeWindowHandle: fn(ComPtr, *mut HANDLE) -> HRES,
+	pub get_CachedItemType: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedIsOffscreen: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CachedOrientation: fn(ComPtr, *mut i32) -> HRES,
+	pub get_CachedFrameworkId: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedIsRequiredForForm: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CachedItemStatus: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedBoundingRectangle: fn(ComPtr, *mut RECT) -> HRES,
+	pub get_CachedLabeledBy: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_CachedAriaRole: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedAriaProperties: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedIsDataValidForForm: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CachedControllerFor: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_CachedDescribedBy: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_CachedFlowsTo: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_CachedProviderDescription: fn(ComPtr, *mut PSTR) -> HRES,
+	pub GetClickablePoint: fn(ComPtr, *mut POINT, *mut BOOL) -> HRES,
+}
+
+com_interface! { IUIAutomationElement: "d22108aa-8ac5-49a5-837b-37bbb3d7591e";
+	/// [`IUIAutomationElement`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nn-uiautomationclient-iuiautomationelement)
+	/// COM interface over [`IUIAutomationElementVT`](crate::vt::IUIAutomationElementVT).
+	///
+	/// Usually obtained from an [`IUIAutomation`](crate::IUIAutomation)
+	/// object, like with
+	/// [`IUIAutomation::ElementFromHandle`](crate::prelude::uiautomation_IUIAutomation::ElementFromHandle).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl uiautomation_IUIAutomationElement for IUIAutomationElement {}
+
+/// This trait is enabled with the `uiautomation` feature, and provides methods for
+/// [`IUIAutomationElement`](crate::IUIAutomationElement).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait uiautomation_IUIAutomationElement: ole_IUnknown {
+	/// [`IUIAutomationElement::FindAll`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-findall)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Listing the names of all buttons inside a window:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IUIAutomation, IUIAutomationElement, VARIANT};
+	///
+	/// let uia: IUIAutomation; // initialized somewhere
+	/// # let uia = IUIAutomation::from(unsafe { winsafe::ComPtr::null() });
+	/// let window: IUIAutomationElement;
+	/// # let window = IUIAutomationElement::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// let cond = uia.CreatePropertyCondition(
+	///     co::UIA_PROPERTYID::CONTROL_TYPE,
+	///     &VARIANT::new_i32(co::UIA_CONTROLTYPEID::BUTTON.into()),
+	/// )?;
+	///
+	/// for button in window.FindAll(co::TREESCOPE::DESCENDANTS, &cond)?.iter() {
+	///     println!("{}", button?.get_CurrentName()?);
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn FindAll(&self,
+		scope: co::TREESCOPE,
+		condition: &impl uiautomation_IUIAutomationCondition,
+	) -> HrResult<IUIAutomationElementArray>
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult(
+				(vt.FindAll)(self.ptr(), scope.0, condition.ptr(), &mut ppv_queried),
+			).map(|_| IUIAutomationElementArray::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomationElement::FindFirst`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-findfirst)
+	/// method.
+	///
+	/// Returns `None` if no element matches the condition.
+	#[must_use]
+	fn FindFirst(&self,
+		scope: co::TREESCOPE,
+		condition: &impl uiautomation_IUIAutomationCondition,
+	) -> HrResult<Option<IUIAutomationElement>>
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult(
+				(vt.FindFirst)(self.ptr(), scope.0, condition.ptr(), &mut ppv_queried),
+			).map(|_| ppv_queried.as_opt().map(|p| IUIAutomationElement::from(*p)))
+		}
+	}
+
+	/// [`IUIAutomationElement::get_CurrentAcceleratorKey`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentacceleratorkey)
+	/// method.
+	#[must_use]
+	fn get_CurrentAcceleratorKey(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentAcceleratorKey)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationElement::get_CurrentAccessKey`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentaccesskey)
+	/// method.
+	#[must_use]
+	fn get_CurrentAccessKey(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentAccessKey)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationElement::get_CurrentAriaProperties`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentariaproperties)
+	/// method.
+	#[must_use]
+	fn get_CurrentAriaProperties(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentAriaProperties)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationElement::get_CurrentAriaRole`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentariarole)
+	/// method.
+	#[must_use]
+	fn get_CurrentAriaRole(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentAriaRole)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationElement::get_CurrentAutomationId`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentautomationid)
+	/// method.
+	#[must_use]
+	fn get_CurrentAutomationId(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentAutomationId)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationElement::get_CurrentBoundingRectangle`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentboundingrectangle)
+	/// method.
+	#[must_use]
+	fn get_CurrentBoundingRectangle(&self) -> HrResult<RECT> {
+		let mut rc = RECT::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentBoundingRectangle)(self.ptr(), &mut rc))
+		}.map(|_| rc)
+	}
+
+	/// [`IUIAutomationElement::get_CurrentClassName`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentclassname)
+	/// method.
+	#[must_use]
+	fn get_CurrentClassName(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentClassName)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationElement::get_CurrentControlType`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentcontroltype)
+	/// method.
+	#[must_use]
+	fn get_CurrentControlType(&self) -> HrResult<co::UIA_CONTROLTYPEID> {
+		let mut val = co::UIA_CONTROLTYPEID::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentControlType)(self.ptr(), &mut val.0))
+		}.map(|_| val)
+	}
+
+	/// [`IUIAutomationElement::get_CurrentCulture`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentculture)
+	/// method.
+	#[must_use]
+	fn get_CurrentCulture(&self) -> HrResult<i32> {
+		let mut val = i32::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentCulture)(self.ptr(), &mut val))
+		}.map(|_| val)
+	}
+
+	/// [`IUIAutomationElement::get_CurrentFrameworkId`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentframeworkid)
+	/// method.
+	#[must_use]
+	fn get_CurrentFrameworkId(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentFrameworkId)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationElement::get_CurrentHasKeyboardFocus`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currenthaskeyboardfocus)
+	/// method.
+	#[must_use]
+	fn get_CurrentHasKeyboardFocus(&self) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentHasKeyboardFocus)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IUIAutomationElement::get_CurrentHelpText`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currenthelptext)
+	/// method.
+	#[must_use]
+	fn get_CurrentHelpText(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentHelpText)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationElement::get_CurrentIsContentElement`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentiscontentelement)
+	/// method.
+	#[must_use]
+	fn get_CurrentIsContentElement(&self) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentIsContentElement)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IUIAutomationElement::get_CurrentIsControlElement`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentiscontrolelement)
+	/// method.
+	#[must_use]
+	fn get_CurrentIsControlElement(&self) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentIsControlElement)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IUIAutomationElement::get_CurrentIsDataValidForForm`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentisdatavalidforform)
+	/// method.
+	#[must_use]
+	fn get_CurrentIsDataValidForForm(&self) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentIsDataValidForForm)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IUIAutomationElement::get_CurrentIsEnabled`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentisenabled)
+	/// method.
+	#[must_use]
+	fn get_CurrentIsEnabled(&self) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentIsEnabled)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IUIAutomationElement::get_CurrentIsKeyboardFocusable`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentiskeyboardfocusable)
+	/// method.
+	#[must_use]
+	fn get_CurrentIsKeyboardFocusable(&self) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentIsKeyboardFocusable)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IUIAutomationElement::get_CurrentIsOffscreen`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentisoffscreen)
+	/// method.
+	#[must_use]
+	fn get_CurrentIsOffscreen(&self) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentIsOffscreen)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IUIAutomationElement::get_CurrentIsPassword`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentispassword)
+	/// method.
+	#[must_use]
+	fn get_CurrentIsPassword(&self) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentIsPassword)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IUIAutomationElement::get_CurrentIsRequiredForForm`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentisrequiredforform)
+	/// method.
+	#[must_use]
+	fn get_CurrentIsRequiredForForm(&self) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentIsRequiredForForm)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IUIAutomationElement::get_CurrentItemStatus`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentitemstatus)
+	/// method.
+	#[must_use]
+	fn get_CurrentItemStatus(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentItemStatus)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationElement::get_CurrentItemType`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentitemtype)
+	/// method.
+	#[must_use]
+	fn get_CurrentItemType(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentItemType)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationElement::get_CurrentLocalizedControlType`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentlocalizedcontroltype)
+	/// method.
+	#[must_use]
+	fn get_CurrentLocalizedControlType(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentLocalizedControlType)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationElement::get_CurrentName`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentname)
+	/// method.
+	#[must_use]
+	fn get_CurrentName(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentName)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationElement::get_CurrentNativeWindowHandle`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentnativewindowhandle)
+	/// method.
+	///
+	/// Returns `None` if the element has no native window.
+	#[must_use]
+	fn get_CurrentNativeWindowHandle(&self) -> HrResult<Option<HWND>> {
+		let mut hwnd: HANDLE = std::ptr::null_mut();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentNativeWindowHandle)(self.ptr(), &mut hwnd))
+		}.map(|_| ptr_to_option_handle(hwnd))
+	}
+
+	/// [`IUIAutomationElement::get_CurrentProcessId`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentprocessid)
+	/// method.
+	#[must_use]
+	fn get_CurrentProcessId(&self) -> HrResult<u32> {
+		let mut val = u32::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentProcessId)(self.ptr(), &mut val as *mut _ as _))
+		}.map(|_| val)
+	}
+
+	/// [`IUIAutomationElement::get_CurrentProviderDescription`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-get_currentproviderdescription)
+	/// method.
+	#[must_use]
+	fn get_CurrentProviderDescription(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.get_CurrentProviderDescription)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationElement::GetClickablePoint`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-getclickablepoint)
+	/// method.
+	///
+	/// Returns `None` if the element has no clickable point.
+	#[must_use]
+	fn GetClickablePoint(&self) -> HrResult<Option<POINT>> {
+		let mut pt = POINT::default();
+		let mut got_clickable: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult(
+				(vt.GetClickablePoint)(self.ptr(), &mut pt, &mut got_clickable),
+			)
+		}.map(|_| if got_clickable != 0 { Some(pt) } else { None })
+	}
+
+	/// [`IUIAutomationElement::GetCurrentPatternAs`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-getcurrentpatternas)
+	/// method.
+	///
+	/// Returns `None` if the element doesn't support the pattern.
+	///
+	/// # Examples
+	///
+	/// Clicking a button:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IUIAutomationElement, IUIAutomationInvokePattern};
+	///
+	/// let button: IUIAutomationElement; // initialized somewhere
+	/// # let button = IUIAutomationElement::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// if let Some(invoke) = button
+	///     .GetCurrentPatternAs::<IUIAutomationInvokePattern>(co::UIA_PATTERNID::INVOKE)?
+	/// {
+	///     invoke.Invoke()?;
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn GetCurrentPatternAs<T>(&self,
+		pattern_id: co::UIA_PATTERNID,
+	) -> HrResult<Option<T>>
+		where T: ole_IUnknown,
+	{
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult(
+				(vt.GetCurrentPatternAs)(
+					self.ptr(),
+					pattern_id.0,
+					&T::IID as *const _ as _,
+					&mut ppv_queried,
+				),
+			).map(|_| ppv_queried.as_opt().map(|p| T::from(*p)))
+		}
+	}
+
+	/// [`IUIAutomationElement::GetCurrentPropertyValue`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-getcurrentpropertyvalue)
+	/// method.
+	#[must_use]
+	fn GetCurrentPropertyValue(&self,
+		property_id: co::UIA_PROPERTYID,
+	) -> HrResult<VARIANT>
+	{
+		let mut var = VARIANT::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult(
+				(vt.GetCurrentPropertyValue)(
+					self.ptr(),
+					property_id.0,
+					&mut var as *mut _ as _,
+				),
+			)
+		}.map(|_| var)
+	}
+
+	/// [`IUIAutomationElement::SetFocus`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelement-setfocus)
+	/// method.
+	fn SetFocus(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementVT>();
+			ok_to_hrresult((vt.SetFocus)(self.ptr()))
+		}
+	}
+}
@@ -0,0 +1,77 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::uiautomation::decl::IUIAutomationElement;
+use crate::vt::IUnknownVT;
+
+/// [`IUIAutomationElementArray`](crate::IUIAutomationElementArray) virtual table.
+#[repr(C)]
+pub struct IUIAutomationElementArrayVT {
+	pub IUnknownVT: IUnknownVT,
+	pub get_Length: fn(ComPtr, *mut i32) -> HRES,
+	pub GetElement: fn(ComPtr, i32, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IUIAutomationElementArray: "14314595-b4bc-4055-95f2-58f2e42c9855";
+	/// [`IUIAutomationElementArray`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nn-uiautomationclient-iuiautomationelementarray)
+	/// COM interface over [`IUIAutomationElementArrayVT`](crate::vt::IUIAutomationElementArrayVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl uiautomation_IUIAutomationElementArray for IUIAutomationElementArray {}
+
+/// This trait is enabled with the `uiautomation` feature, and provides methods for
+/// [`IUIAutomationElementArray`](crate::IUIAutomationElementArray).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait uiautomation_IUIAutomationElementArray: ole_IUnknown {
+	/// Returns an iterator over the
+	/// [`IUIAutomationElement`](crate::IUIAutomationElement) elements, which
+	/// calls
+	/// [`IUIAutomationElementArray::get_Length`](crate::prelude::uiautomation_IUIAutomationElementArray::get_Length)
+	/// and
+	/// [`IUIAutomationElementArray::GetElement`](crate::prelude::uiautomation_IUIAutomationElementArray::GetElement)
+	/// internally.
+	#[must_use]
+	fn iter(&self) -> Box<dyn Iterator<Item = HrResult<IUIAutomationElement>> + '_> {
+		match self.get_Length() {
+			Ok(count) => Box::new((0..count).map(|i| self.GetElement(i))),
+			Err(e) => Box::new(std::iter::once(Err(e))),
+		}
+	}
+
+	/// [`IUIAutomationElementArray::get_Length`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelementarray-get_length)
+	/// method.
+	#[must_use]
+	fn get_Length(&self) -> HrResult<i32> {
+		let mut val = i32::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationElementArrayVT>();
+			ok_to_hrresult((vt.get_Length)(self.ptr(), &mut val))
+		}.map(|_| val)
+	}
+
+	/// [`IUIAutomationElementArray::GetElement`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationelementarray-getelement)
+	/// method.
+	///
+	/// The index is zero-based.
+	#[must_use]
+	fn GetElement(&self, index: i32) -> HrResult<IUIAutomationElement> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationElementArrayVT>();
+			ok_to_hrresult((vt.GetElement)(self.ptr(), index, &mut ppv_queried))
+				.map(|_| IUIAutomationElement::from(ppv_queried))
+		}
+	}
+}
@@ -0,0 +1,56 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::{ole_IUnknown, uiautomation_IUIAutomationElement};
+use crate::vt::IUnknownVT;
+
+/// [`IUIAutomationEventHandler`](crate::IUIAutomationEventHandler) virtual table.
+#[repr(C)]
+pub struct IUIAutomationEventHandlerVT {
+	pub IUnknownVT: IUnknownVT,
+	pub HandleAutomationEvent: fn(ComPtr, ComPtr, i32) -> HRES,
+}
+
+com_interface! { IUIAutomationEventHandler: "146c3c17-f12e-4e22-8c27-f894b9b79c69";
+	/// [`IUIAutomationEventHandler`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nn-uiautomationclient-iuiautomationeventhandler)
+	/// COM interface over [`IUIAutomationEventHandlerVT`](crate::vt::IUIAutomationEventHandlerVT).
+	///
+	/// To receive UI Automation events, create an implementation with
+	/// [`UIAutomationEventHandler`](crate::UIAutomationEventHandler), then
+	/// pass it to
+	/// [`IUIAutomation::AddAutomationEventHandler`](crate::prelude::uiautomation_IUIAutomation::AddAutomationEventHandler).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl uiautomation_IUIAutomationEventHandler for IUIAutomationEventHandler {}
+
+/// This trait is enabled with the `uiautomation` feature, and provides methods for
+/// [`IUIAutomationEventHandler`](crate::IUIAutomationEventHandler).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait uiautomation_IUIAutomationEventHandler: ole_IUnknown {
+	/// [`IUIAutomationEventHandler::HandleAutomationEvent`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationeventhandler-handleautomationevent)
+	/// method.
+	fn HandleAutomationEvent(&self,
+		sender: &impl uiautomation_IUIAutomationElement,
+		event_id: co::UIA_EVENTID,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationEventHandlerVT>();
+			ok_to_hrresult(
+				(vt.HandleAutomationEvent)(self.ptr(), sender.ptr(), event_id.0),
+			)
+		}
+	}
+}
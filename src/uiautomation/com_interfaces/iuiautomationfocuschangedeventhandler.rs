@@ -0,0 +1,52 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::{ole_IUnknown, uiautomation_IUIAutomationElement};
+use crate::vt::IUnknownVT;
+
+/// [`IUIAutomationFocusChangedEventHandler`](crate::IUIAutomationFocusChangedEventHandler) virtual table.
+#[repr(C)]
+pub struct IUIAutomationFocusChangedEventHandlerVT {
+	pub IUnknownVT: IUnknownVT,
+	pub HandleFocusChangedEvent: fn(ComPtr, ComPtr) -> HRES,
+}
+
+com_interface! { IUIAutomationFocusChangedEventHandler: "c270f6b5-5c69-4290-9745-7a7f97169468";
+	/// [`IUIAutomationFocusChangedEventHandler`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nn-uiautomationclient-iuiautomationfocuschangedeventhandler)
+	/// COM interface over [`IUIAutomationFocusChangedEventHandlerVT`](crate::vt::IUIAutomationFocusChangedEventHandlerVT).
+	///
+	/// To receive focus change events, create an implementation with
+	/// [`UIAutomationFocusChangedEventHandler`](crate::UIAutomationFocusChangedEventHandler),
+	/// then pass it to
+	/// [`IUIAutomation::AddFocusChangedEventHandler`](crate::prelude::uiautomation_IUIAutomation::AddFocusChangedEventHandler).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl uiautomation_IUIAutomationFocusChangedEventHandler for IUIAutomationFocusChangedEventHandler {}
+
+/// This trait is enabled with the `uiautomation` feature, and provides methods for
+/// [`IUIAutomationFocusChangedEventHandler`](crate::IUIAutomationFocusChangedEventHandler).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait uiautomation_IUIAutomationFocusChangedEventHandler: ole_IUnknown {
+	/// [`IUIAutomationFocusChangedEventHandler::HandleFocusChangedEvent`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationfocuschangedeventhandler-handlefocuschangedevent)
+	/// method.
+	fn HandleFocusChangedEvent(&self,
+		sender: &impl uiautomation_IUIAutomationElement,
+	) -> HrResult<()>
+	{
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationFocusChangedEventHandlerVT>();
+			ok_to_hrresult((vt.HandleFocusChangedEvent)(self.ptr(), sender.ptr()))
+		}
+	}
+}
@@ -0,0 +1,48 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IUIAutomationInvokePattern`](crate::IUIAutomationInvokePattern) virtual table.
+#[repr(C)]
+pub struct IUIAutomationInvokePatternVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Invoke: fn(ComPtr) -> HRES,
+}
+
+com_interface! { IUIAutomationInvokePattern: "fb377fbe-8ea6-46d5-9c73-6499642d3059";
+	/// [`IUIAutomationInvokePattern`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nn-uiautomationclient-iuiautomationinvokepattern)
+	/// COM interface over [`IUIAutomationInvokePatternVT`](crate::vt::IUIAutomationInvokePatternVT).
+	///
+	/// Obtained with
+	/// [`IUIAutomationElement::GetCurrentPatternAs`](crate::prelude::uiautomation_IUIAutomationElement::GetCurrentPatternAs),
+	/// passing [`co::UIA_PATTERNID::INVOKE`](crate::co::UIA_PATTERNID::INVOKE).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl uiautomation_IUIAutomationInvokePattern for IUIAutomationInvokePattern {}
+
+/// This trait is enabled with the `uiautomation` feature, and provides methods for
+/// [`IUIAutomationInvokePattern`](crate::IUIAutomationInvokePattern).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait uiautomation_IUIAutomationInvokePattern: ole_IUnknown {
+	/// [`IUIAutomationInvokePattern::Invoke`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationinvokepattern-invoke)
+	/// method.
+	fn Invoke(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationInvokePatternVT>();
+			ok_to_hrresult((vt.Invoke)(self.ptr()))
+		}
+	}
+}
@@ -0,0 +1,97 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::{BOOL, HRES};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::uiautomation::decl::IUIAutomationElement;
+use crate::vt::IUnknownVT;
+
+/// [`IUIAutomationSelectionItemPattern`](crate::IUIAutomationSelectionItemPattern) virtual table.
+#[repr(C)]
+pub struct IUIAutomationSelectionItemPatternVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Select: fn(ComPtr) -> HRES,
+	pub AddToSelection: fn(ComPtr) -> HRES,
+	pub RemoveFromSelection: fn(ComPtr) -> HRES,
+	pub get_CurrentIsSelected: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CurrentSelectionContainer: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_CachedIsSelected: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CachedSelectionContainer: fn(ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IUIAutomationSelectionItemPattern: "a8efa66a-0fda-421a-9194-38021f3578ea";
+	/// [`IUIAutomationSelectionItemPattern`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nn-uiautomationclient-iuiautomationselectionitempattern)
+	/// COM interface over [`IUIAutomationSelectionItemPatternVT`](crate::vt::IUIAutomationSelectionItemPatternVT).
+	///
+	/// Obtained with
+	/// [`IUIAutomationElement::GetCurrentPatternAs`](crate::prelude::uiautomation_IUIAutomationElement::GetCurrentPatternAs),
+	/// passing
+	/// [`co::UIA_PATTERNID::SELECTION_ITEM`](crate::co::UIA_PATTERNID::SELECTION_ITEM).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl uiautomation_IUIAutomationSelectionItemPattern for IUIAutomationSelectionItemPattern {}
+
+/// This trait is enabled with the `uiautomation` feature, and provides methods for
+/// [`IUIAutomationSelectionItemPattern`](crate::IUIAutomationSelectionItemPattern).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait uiautomation_IUIAutomationSelectionItemPattern: ole_IUnknown {
+	/// [`IUIAutomationSelectionItemPattern::AddToSelection`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationselectionitempattern-addtoselection)
+	/// method.
+	fn AddToSelection(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationSelectionItemPatternVT>();
+			ok_to_hrresult((vt.AddToSelection)(self.ptr()))
+		}
+	}
+
+	/// [`IUIAutomationSelectionItemPattern::get_CurrentIsSelected`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationselectionitempattern-get_currentisselected)
+	/// method.
+	#[must_use]
+	fn get_CurrentIsSelected(&self) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationSelectionItemPatternVT>();
+			ok_to_hrresult((vt.get_CurrentIsSelected)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IUIAutomationSelectionItemPattern::get_CurrentSelectionContainer`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationselectionitempattern-get_currentselectioncontainer)
+	/// method.
+	#[must_use]
+	fn get_CurrentSelectionContainer(&self) -> HrResult<IUIAutomationElement> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationSelectionItemPatternVT>();
+			ok_to_hrresult((vt.get_CurrentSelectionContainer)(self.ptr(), &mut ppv_queried))
+				.map(|_| IUIAutomationElement::from(ppv_queried))
+		}
+	}
+
+	/// [`IUIAutomationSelectionItemPattern::RemoveFromSelection`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationselectionitempattern-removefromselection)
+	/// method.
+	fn RemoveFromSelection(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationSelectionItemPatternVT>();
+			ok_to_hrresult((vt.RemoveFromSelection)(self.ptr()))
+		}
+	}
+
+	/// [`IUIAutomationSelectionItemPattern::Select`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationselectionitempattern-select)
+	/// method.
+	fn Select(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationSelectionItemPatternVT>();
+			ok_to_hrresult((vt.Select)(self.ptr()))
+		}
+	}
+}
@@ -0,0 +1,80 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::{BOOL, HRES};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::prelude::ole_IUnknown;
+use crate::uiautomation::decl::IUIAutomationElementArray;
+use crate::vt::IUnknownVT;
+
+/// [`IUIAutomationSelectionPattern`](crate::IUIAutomationSelectionPattern) virtual table.
+#[repr(C)]
+pub struct IUIAutomationSelectionPatternVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetCurrentSelection: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_CurrentCanSelectMultiple: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CurrentIsSelectionRequired: fn(ComPtr, *mut BOOL) -> HRES,
+	pub GetCachedSelection: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub get_CachedCanSelectMultiple: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CachedIsSelectionRequired: fn(ComPtr, *mut BOOL) -> HRES,
+}
+
+com_interface! { IUIAutomationSelectionPattern: "5ed5202e-b2ac-47a6-b638-4b0bf140d78e";
+	/// [`IUIAutomationSelectionPattern`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nn-uiautomationclient-iuiautomationselectionpattern)
+	/// COM interface over [`IUIAutomationSelectionPatternVT`](crate::vt::IUIAutomationSelectionPatternVT).
+	///
+	/// Obtained with
+	/// [`IUIAutomationElement::GetCurrentPatternAs`](crate::prelude::uiautomation_IUIAutomationElement::GetCurrentPatternAs),
+	/// passing
+	/// [`co::UIA_PATTERNID::SELECTION`](crate::co::UIA_PATTERNID::SELECTION).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl uiautomation_IUIAutomationSelectionPattern for IUIAutomationSelectionPattern {}
+
+/// This trait is enabled with the `uiautomation` feature, and provides methods for
+/// [`IUIAutomationSelectionPattern`](crate::IUIAutomationSelectionPattern).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait uiautomation_IUIAutomationSelectionPattern: ole_IUnknown {
+	/// [`IUIAutomationSelectionPattern::get_CurrentCanSelectMultiple`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationselectionpattern-get_currentcanselectmultiple)
+	/// method.
+	#[must_use]
+	fn get_CurrentCanSelectMultiple(&self) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationSelectionPatternVT>();
+			ok_to_hrresult((vt.get_CurrentCanSelectMultiple)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IUIAutomationSelectionPattern::get_CurrentIsSelectionRequired`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationselectionpattern-get_currentisselectionrequired)
+	/// method.
+	#[must_use]
+	fn get_CurrentIsSelectionRequired(&self) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationSelectionPatternVT>();
+			ok_to_hrresult((vt.get_CurrentIsSelectionRequired)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IUIAutomationSelectionPattern::GetCurrentSelection`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationselectionpattern-getcurrentselection)
+	/// method.
+	#[must_use]
+	fn GetCurrentSelection(&self) -> HrResult<IUIAutomationElementArray> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IUIAutomationSelectionPatternVT>();
+			ok_to_hrresult((vt.GetCurrentSelection)(self.ptr(), &mut ppv_queried))
+				.map(|_| IUIAutomationElementArray::from(ppv_queried))
+		}
+	}
+}
@@ -0,0 +1,76 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::kernel::ffi_types::{BOOL, HRES, PSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::BSTR;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IUIAutomationValuePattern`](crate::IUIAutomationValuePattern) virtual table.
+#[repr(C)]
+pub struct IUIAutomationValuePatternVT {
+	pub IUnknownVT: IUnknownVT,
+	pub SetValue: fn(ComPtr, PSTR) -> HRES,
+	pub get_CurrentValue: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CurrentIsReadOnly: fn(ComPtr, *mut BOOL) -> HRES,
+	pub get_CachedValue: fn(ComPtr, *mut PSTR) -> HRES,
+	pub get_CachedIsReadOnly: fn(ComPtr, *mut BOOL) -> HRES,
+}
+
+com_interface! { IUIAutomationValuePattern: "a94cd8b1-0844-4cd6-9d2d-640537ab39e9";
+	/// [`IUIAutomationValuePattern`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nn-uiautomationclient-iuiautomationvaluepattern)
+	/// COM interface over [`IUIAutomationValuePatternVT`](crate::vt::IUIAutomationValuePatternVT).
+	///
+	/// Obtained with
+	/// [`IUIAutomationElement::GetCurrentPatternAs`](crate::prelude::uiautomation_IUIAutomationElement::GetCurrentPatternAs),
+	/// passing [`co::UIA_PATTERNID::VALUE`](crate::co::UIA_PATTERNID::VALUE).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl uiautomation_IUIAutomationValuePattern for IUIAutomationValuePattern {}
+
+/// This trait is enabled with the `uiautomation` feature, and provides methods for
+/// [`IUIAutomationValuePattern`](crate::IUIAutomationValuePattern).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait uiautomation_IUIAutomationValuePattern: ole_IUnknown {
+	/// [`IUIAutomationValuePattern::get_CurrentIsReadOnly`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationvaluepattern-get_currentisreadonly)
+	/// method.
+	#[must_use]
+	fn get_CurrentIsReadOnly(&self) -> HrResult<bool> {
+		let mut val: BOOL = 0;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationValuePatternVT>();
+			ok_to_hrresult((vt.get_CurrentIsReadOnly)(self.ptr(), &mut val))
+		}.map(|_| val != 0)
+	}
+
+	/// [`IUIAutomationValuePattern::get_CurrentValue`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationvaluepattern-get_currentvalue)
+	/// method.
+	#[must_use]
+	fn get_CurrentValue(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationValuePatternVT>();
+			ok_to_hrresult((vt.get_CurrentValue)(self.ptr(), &mut bstr.0))
+		}.map(|_| bstr.to_string())
+	}
+
+	/// [`IUIAutomationValuePattern::SetValue`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationvaluepattern-setvalue)
+	/// method.
+	fn SetValue(&self, val: &str) -> HrResult<()> {
+		let bstr = BSTR::SysAllocString(val)?;
+		unsafe {
+			let vt = self.vt_ref::<IUIAutomationValuePatternVT>();
+			ok_to_hrresult((vt.SetValue)(self.ptr(), bstr.0))
+		}
+	}
+}
@@ -0,0 +1,49 @@
+mod iuiautomation;
+mod iuiautomationcondition;
+mod iuiautomationelement;
+mod iuiautomationelementarray;
+mod iuiautomationeventhandler;
+mod iuiautomationfocuschangedeventhandler;
+mod iuiautomationinvokepattern;
+mod iuiautomationselectionitempattern;
+mod iuiautomationselectionpattern;
+mod iuiautomationvaluepattern;
+
+pub mod decl {
+	pub use super::iuiautomation::IUIAutomation;
+	pub use super::iuiautomationcondition::IUIAutomationCondition;
+	pub use super::iuiautomationelement::IUIAutomationElement;
+	pub use super::iuiautomationelementarray::IUIAutomationElementArray;
+	pub use super::iuiautomationeventhandler::IUIAutomationEventHandler;
+	pub use super::iuiautomationfocuschangedeventhandler::IUIAutomationFocusChangedEventHandler;
+	pub use super::iuiautomationinvokepattern::IUIAutomationInvokePattern;
+	pub use super::iuiautomationselectionitempattern::IUIAutomationSelectionItemPattern;
+	pub use super::iuiautomationselectionpattern::IUIAutomationSelectionPattern;
+	pub use super::iuiautomationvaluepattern::IUIAutomationValuePattern;
+}
+
+pub mod traits {
+	pub use super::iuiautomation::uiautomation_IUIAutomation;
+	pub use super::iuiautomationcondition::uiautomation_IUIAutomationCondition;
+	pub use super::iuiautomationelement::uiautomation_IUIAutomationElement;
+	pub use super::iuiautomationelementarray::uiautomation_IUIAutomationElementArray;
+	pub use super::iuiautomationeventhandler::uiautomation_IUIAutomationEventHandler;
+	pub use super::iuiautomationfocuschangedeventhandler::uiautomation_IUIAutomationFocusChangedEventHandler;
+	pub use super::iuiautomationinvokepattern::uiautomation_IUIAutomationInvokePattern;
+	pub use super::iuiautomationselectionitempattern::uiautomation_IUIAutomationSelectionItemPattern;
+	pub use super::iuiautomationselectionpattern::uiautomation_IUIAutomationSelectionPattern;
+	pub use super::iuiautomationvaluepattern::uiautomation_IUIAutomationValuePattern;
+}
+
+pub mod vt {
+	pub use super::iuiautomation::IUIAutomationVT;
+	pub use super::iuiautomationcondition::IUIAutomationConditionVT;
+	pub use super::iuiautomationelement::IUIAutomationElementVT;
+	pub use super::iuiautomationelementarray::IUIAutomationElementArrayVT;
+	pub use super::iuiautomationeventhandler::IUIAutomationEventHandlerVT;
+	pub use super::iuiautomationfocuschangedeventhandler::IUIAutomationFocusChangedEventHandlerVT;
+	pub use super::iuiautomationinvokepattern::IUIAutomationInvokePatternVT;
+	pub use super::iuiautomationselectionitempattern::IUIAutomationSelectionItemPatternVT;
+	pub use super::iuiautomationselectionpattern::IUIAutomationSelectionPatternVT;
+	pub use super::iuiautomationvaluepattern::IUIAutomationValuePatternVT;
+}
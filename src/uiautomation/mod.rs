@@ -0,0 +1,19 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "uiautomation")))]
+
+pub mod co;
+
+mod com_interfaces;
+mod utilities;
+
+pub mod decl {
+	pub use super::com_interfaces::decl::*;
+	pub use super::utilities::*;
+}
+
+pub mod traits {
+	pub use super::com_interfaces::traits::*;
+}
+
+pub mod vt {
+	pub use super::com_interfaces::vt::*;
+}
@@ -0,0 +1,5 @@
+mod ui_automation_event_handler;
+mod ui_automation_focus_changed_event_handler;
+
+pub use ui_automation_event_handler::UIAutomationEventHandler;
+pub use ui_automation_focus_changed_event_handler::UIAutomationFocusChangedEventHandler;
@@ -0,0 +1,148 @@
+#![allow(non_snake_case)]
+
+use std::mem::ManuallyDrop;
+use std::sync::atomic::{AtomicU32, fence, Ordering};
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PCVOID};
+use crate::ole::decl::{ComPtr, HrResult, IUnknown};
+use crate::ole::privs::catch_com_panic;
+use crate::prelude::ole_IUnknown;
+use crate::uiautomation::decl::{IUIAutomationElement, IUIAutomationEventHandler};
+use crate::vt::{IUIAutomationEventHandlerVT, IUnknownVT};
+
+/// Builder of an
+/// [`IUIAutomationEventHandler`](crate::IUIAutomationEventHandler)
+/// implemented in Rust, which runs the given closure when a UI Automation
+/// event is raised.
+///
+/// The built object is passed to
+/// [`IUIAutomation::AddAutomationEventHandler`](crate::prelude::uiautomation_IUIAutomation::AddAutomationEventHandler).
+/// UI Automation calls the closure from its own threads, so it must be `Send`
+/// and `Sync`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, IUIAutomation, UIAutomationEventHandler};
+///
+/// let uia: IUIAutomation; // initialized somewhere
+/// # let uia = IUIAutomation::from(unsafe { winsafe::ComPtr::null() });
+///
+/// let handler = UIAutomationEventHandler::new()
+///     .handle_automation_event(|sender, event_id| {
+///         println!("{} invoked: {}", sender.get_CurrentName()?, event_id);
+///         Ok(())
+///     })
+///     .build();
+///
+/// uia.AddAutomationEventHandler(
+///     co::UIA_EVENTID::INVOKE_INVOKED,
+///     &uia.GetRootElement()?,
+///     co::TREESCOPE::SUBTREE,
+///     &handler,
+/// )?;
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+#[derive(Default)]
+pub struct UIAutomationEventHandler {
+	handle_automation_event: Option<Box<dyn Fn(&IUIAutomationElement, co::UIA_EVENTID) -> HrResult<()> + Send + Sync>>,
+}
+
+impl UIAutomationEventHandler {
+	/// Creates a new builder, with no closures.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// [`IUIAutomationEventHandler::HandleAutomationEvent`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationeventhandler-handleautomationevent)
+	/// event, called when the registered event is raised by the sender
+	/// element.
+	#[must_use]
+	pub fn handle_automation_event<F>(mut self, func: F) -> Self
+		where F: Fn(&IUIAutomationElement, co::UIA_EVENTID) -> HrResult<()> + Send + Sync + 'static,
+	{
+		self.handle_automation_event = Some(Box::new(func));
+		self
+	}
+
+	/// Creates the
+	/// [`IUIAutomationEventHandler`](crate::IUIAutomationEventHandler)
+	/// object.
+	#[must_use]
+	pub fn build(self) -> IUIAutomationEventHandler {
+		let obj = Box::new(UIAutomationEventHandlerImpl {
+			vt: &UI_AUTOMATION_EVENT_HANDLER_VT,
+			ref_count: AtomicU32::new(1),
+			events: self,
+		});
+		IUIAutomationEventHandler::from(ComPtr(Box::into_raw(obj) as _))
+	}
+}
+
+//------------------------------------------------------------------------------
+
+static UI_AUTOMATION_EVENT_HANDLER_VT: IUIAutomationEventHandlerVT = IUIAutomationEventHandlerVT {
+	IUnknownVT: IUnknownVT {
+		QueryInterface: UIAutomationEventHandlerImpl::QueryInterface,
+		AddRef: UIAutomationEventHandlerImpl::AddRef,
+		Release: UIAutomationEventHandlerImpl::Release,
+	},
+	HandleAutomationEvent: UIAutomationEventHandlerImpl::HandleAutomationEvent,
+};
+
+/// Implementation of the `IUIAutomationEventHandler` COM interface.
+#[repr(C)]
+struct UIAutomationEventHandlerImpl {
+	vt: *const IUIAutomationEventHandlerVT, // must be the first field, as in any COM object
+	ref_count: AtomicU32,
+	events: UIAutomationEventHandler,
+}
+
+impl UIAutomationEventHandlerImpl {
+	fn from_com_ptr<'a>(p: ComPtr) -> &'a Self {
+		unsafe { &*(p.0 as *const Self) }
+	}
+
+	fn QueryInterface(p: ComPtr, riid: PCVOID, ppv: *mut ComPtr) -> HRES {
+		let riid = unsafe { &*(riid as *const co::IID) };
+		if *riid == IUnknown::IID || *riid == IUIAutomationEventHandler::IID {
+			Self::AddRef(p);
+			unsafe { *ppv = p; }
+			co::HRESULT::S_OK.0
+		} else {
+			unsafe { *ppv = ComPtr::null(); }
+			co::HRESULT::E_NOINTERFACE.0
+		}
+	}
+
+	fn AddRef(p: ComPtr) -> u32 {
+		Self::from_com_ptr(p).ref_count.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn Release(p: ComPtr) -> u32 {
+		let count = Self::from_com_ptr(p).ref_count.fetch_sub(1, Ordering::Release) - 1;
+		if count == 0 {
+			fence(Ordering::Acquire); // see the drop of std::sync::Arc
+			let _ = unsafe { Box::from_raw(p.0 as *mut Self) };
+		}
+		count
+	}
+
+	fn HandleAutomationEvent(p: ComPtr, sender: ComPtr, event_id: i32) -> HRES {
+		catch_com_panic(|| {
+			match &Self::from_com_ptr(p).events.handle_automation_event {
+				Some(func) => {
+					let sender = ManuallyDrop::new(IUIAutomationElement::from(sender)); // not owned
+					match func(&sender, co::UIA_EVENTID(event_id)) {
+						Ok(_) => co::HRESULT::S_OK.0,
+						Err(hr) => hr.0,
+					}
+				},
+				None => co::HRESULT::S_OK.0,
+			}
+		})
+	}
+}
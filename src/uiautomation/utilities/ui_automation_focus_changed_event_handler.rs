@@ -0,0 +1,142 @@
+#![allow(non_snake_case)]
+
+use std::mem::ManuallyDrop;
+use std::sync::atomic::{AtomicU32, fence, Ordering};
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PCVOID};
+use crate::ole::decl::{ComPtr, HrResult, IUnknown};
+use crate::ole::privs::catch_com_panic;
+use crate::prelude::ole_IUnknown;
+use crate::uiautomation::decl::{IUIAutomationElement, IUIAutomationFocusChangedEventHandler};
+use crate::vt::{IUIAutomationFocusChangedEventHandlerVT, IUnknownVT};
+
+/// Builder of an
+/// [`IUIAutomationFocusChangedEventHandler`](crate::IUIAutomationFocusChangedEventHandler)
+/// implemented in Rust, which runs the given closure when the keyboard focus
+/// moves to another element.
+///
+/// The built object is passed to
+/// [`IUIAutomation::AddFocusChangedEventHandler`](crate::prelude::uiautomation_IUIAutomation::AddFocusChangedEventHandler).
+/// UI Automation calls the closure from its own threads, so it must be `Send`
+/// and `Sync`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, IUIAutomation, UIAutomationFocusChangedEventHandler};
+///
+/// let uia: IUIAutomation; // initialized somewhere
+/// # let uia = IUIAutomation::from(unsafe { winsafe::ComPtr::null() });
+///
+/// let handler = UIAutomationFocusChangedEventHandler::new()
+///     .handle_focus_changed_event(|sender| {
+///         println!("Focus: {}", sender.get_CurrentName()?);
+///         Ok(())
+///     })
+///     .build();
+///
+/// uia.AddFocusChangedEventHandler(&handler)?;
+/// # Ok::<_, co::HRESULT>(())
+/// ```
+#[derive(Default)]
+pub struct UIAutomationFocusChangedEventHandler {
+	handle_focus_changed_event: Option<Box<dyn Fn(&IUIAutomationElement) -> HrResult<()> + Send + Sync>>,
+}
+
+impl UIAutomationFocusChangedEventHandler {
+	/// Creates a new builder, with no closures.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// [`IUIAutomationFocusChangedEventHandler::HandleFocusChangedEvent`](https://learn.microsoft.com/en-us/windows/win32/api/uiautomationclient/nf-uiautomationclient-iuiautomationeventhandler-handlefocuschangedevent)
+	/// event, called when the sender element receives the keyboard focus.
+	#[must_use]
+	pub fn handle_focus_changed_event<F>(mut self, func: F) -> Self
+		where F: Fn(&IUIAutomationElement) -> HrResult<()> + Send + Sync + 'static,
+	{
+		self.handle_focus_changed_event = Some(Box::new(func));
+		self
+	}
+
+	/// Creates the
+	/// [`IUIAutomationFocusChangedEventHandler`](crate::IUIAutomationFocusChangedEventHandler)
+	/// object.
+	#[must_use]
+	pub fn build(self) -> IUIAutomationFocusChangedEventHandler {
+		let obj = Box::new(UIAutomationFocusChangedEventHandlerImpl {
+			vt: &UI_AUTOMATION_FOCUS_CHANGED_EVENT_HANDLER_VT,
+			ref_count: AtomicU32::new(1),
+			events: self,
+		});
+		IUIAutomationFocusChangedEventHandler::from(ComPtr(Box::into_raw(obj) as _))
+	}
+}
+
+//------------------------------------------------------------------------------
+
+static UI_AUTOMATION_FOCUS_CHANGED_EVENT_HANDLER_VT: IUIAutomationFocusChangedEventHandlerVT = IUIAutomationFocusChangedEventHandlerVT {
+	IUnknownVT: IUnknownVT {
+		QueryInterface: UIAutomationFocusChangedEventHandlerImpl::QueryInterface,
+		AddRef: UIAutomationFocusChangedEventHandlerImpl::AddRef,
+		Release: UIAutomationFocusChangedEventHandlerImpl::Release,
+	},
+	HandleFocusChangedEvent: UIAutomationFocusChangedEventHandlerImpl::HandleFocusChangedEvent,
+};
+
+/// Implementation of the `IUIAutomationFocusChangedEventHandler` COM interface.
+#[repr(C)]
+struct UIAutomationFocusChangedEventHandlerImpl {
+	vt: *const IUIAutomationFocusChangedEventHandlerVT, // must be the first field, as in any COM object
+	ref_count: AtomicU32,
+	events: UIAutomationFocusChangedEventHandler,
+}
+
+impl UIAutomationFocusChangedEventHandlerImpl {
+	fn from_com_ptr<'a>(p: ComPtr) -> &'a Self {
+		unsafe { &*(p.0 as *const Self) }
+	}
+
+	fn QueryInterface(p: ComPtr, riid: PCVOID, ppv: *mut ComPtr) -> HRES {
+		let riid = unsafe { &*(riid as *const co::IID) };
+		if *riid == IUnknown::IID || *riid == IUIAutomationFocusChangedEventHandler::IID {
+			Self::AddRef(p);
+			unsafe { *ppv = p; }
+			co::HRESULT::S_OK.0
+		} else {
+			unsafe { *ppv = ComPtr::null(); }
+			co::HRESULT::E_NOINTERFACE.0
+		}
+	}
+
+	fn AddRef(p: ComPtr) -> u32 {
+		Self::from_com_ptr(p).ref_count.fetch_add(1, Ordering::Relaxed) + 1
+	}
+
+	fn Release(p: ComPtr) -> u32 {
+		let count = Self::from_com_ptr(p).ref_count.fetch_sub(1, Ordering::Release) - 1;
+		if count == 0 {
+			fence(Ordering::Acquire); // see the drop of std::sync::Arc
+			let _ = unsafe { Box::from_raw(p.0 as *mut Self) };
+		}
+		count
+	}
+
+	fn HandleFocusChangedEvent(p: ComPtr, sender: ComPtr) -> HRES {
+		catch_com_panic(|| {
+			match &Self::from_com_ptr(p).events.handle_focus_changed_event {
+				Some(func) => {
+					let sender = ManuallyDrop::new(IUIAutomationElement::from(sender)); // not owned
+					match func(&sender) {
+						Ok(_) => co::HRESULT::S_OK.0,
+						Err(hr) => hr.0,
+					}
+				},
+				None => co::HRESULT::S_OK.0,
+			}
+		})
+	}
+}
@@ -19,6 +19,15 @@ const_bitflag! { ACCELF: u8;
 	ALT 0x10
 }
 
+const_ordinary! { AC_SRC: u8;
+	/// [`BLENDFUNCTION`](crate::BLENDFUNCTION) `BlendOp` and `AlphaFormat`
+	/// (`u8`).
+	=>
+	=>
+	OVER 0x00
+	ALPHA 0x01
+}
+
 const_ordinary! { APPCOMMAND: u16;
 	/// [`wm::AppCommand`](crate::msg::wm::AppCommand) commands (`u16`).
 	=>
@@ -135,6 +144,17 @@ const_ws! { BS: u32;
 	AUTORADIOBUTTON 0x0000_0009
 	PUSHBOX 0x0000_000a
 	OWNERDRAW 0x0000_000b
+	/// Split button, with a drop-down arrow which fires
+	/// [`BCN_DROPDOWN`](https://learn.microsoft.com/en-us/windows/win32/controls/bcn-dropdown).
+	/// Requires comctl.
+	SPLITBUTTON 0x0000_000c
+	/// Default split button. Requires comctl.
+	DEFSPLITBUTTON 0x0000_000d
+	/// Command link button, which displays a note below its text. Requires
+	/// comctl.
+	COMMANDLINK 0x0000_000e
+	/// Default command link button. Requires comctl.
+	DEFCOMMANDLINK 0x0000_000f
 	TYPEMASK 0x0000_000f
 	LEFTTEXT 0x0000_0020
 	TEXT 0x0000_0000
@@ -1030,6 +1050,18 @@ const_ordinary! { DLGID: u16;
 	CONTINUE 11
 }
 
+const_ordinary! { DPI_AWARENESS_CONTEXT: isize;
+	/// [`DPI_AWARENESS_CONTEXT`](https://learn.microsoft.com/en-us/windows/win32/hidpi/dpi-awareness-context)
+	/// handle (`isize`).
+	=>
+	=>
+	UNAWARE -1
+	SYSTEM_AWARE -2
+	PER_MONITOR_AWARE -3
+	PER_MONITOR_AWARE_V2 -4
+	UNAWARE_GDISCALED -5
+}
+
 const_bitflag! { DT: u32;
 	/// [`HDC::DrawText`](crate::prelude::user_Hdc::DrawText) `format` (`u32`).
 	=>
@@ -1165,7 +1197,8 @@ const_cmd! { EN;
 }
 
 const_bitflag! { ENDSESSION: u32;
-	/// [`wm::EndSession`](crate::msg::wm::EndSession) event (`u32`).
+	/// [`wm::EndSession`](crate::msg::wm::EndSession) and
+	/// [`wm::QueryEndSession`](crate::msg::wm::QueryEndSession) event (`u32`).
 	=>
 	=>
 	RESTARTORSHUTDOWN 0
@@ -1417,6 +1450,20 @@ const_ordinary! { HELPW: u32;
 	TCARD_OTHER_CALLER 0x0011
 }
 
+const_bitflag! { HCF: u32;
+	/// [`HIGHCONTRAST`](crate::HIGHCONTRAST) `dwFlags` (`u32`).
+	=>
+	=>
+	HIGHCONTRASTON 0x0000_0001
+	AVAILABLE 0x0000_0002
+	HOTKEYACTIVE 0x0000_0004
+	CONFIRMHOTKEY 0x0000_0008
+	HOTKEYSOUND 0x0000_0010
+	INDICATOR 0x0000_0020
+	HOTKEYAVAILABLE 0x0000_0040
+	OPTION_NOTHEMECHANGE 0x0000_1000
+}
+
 const_ordinary! { HT: u16;
 	/// [`wm::NcHitTest`](crate::msg::wm::NcHitTest),
 	/// [`wm::SetCursor`](crate::msg::wm::SetCursor) `hit_test` (`u16`).
@@ -1978,11 +2025,24 @@ const_ordinary! { MSGF: u8;
 	MENU 2
 }
 
+const_bitflag! { MWMO: u32;
+	/// [`MsgWaitForMultipleObjectsEx`](crate::MsgWaitForMultipleObjectsEx)
+	/// `flags` (`u32`).
+	=>
+	=>
+	WAITALL 0x0001
+	ALERTABLE 0x0002
+	INPUTAVAILABLE 0x0004
+}
+
 const_ordinary! { OBJID: u32;
 	/// [`HWND::GetMenuBarInfo`](crate::prelude::user_Hwnd::GetMenuBarInfo)
+	/// and [`HWND::GetScrollBarInfo`](crate::prelude::user_Hwnd::GetScrollBarInfo)
 	/// `idObject` (`i32`).
 	=>
 	=>
+	HSCROLL 0xffff_fffa
+	VSCROLL 0xffff_fffb
 	CLIENT 0xffff_fffc
 	MENU 0xffff_fffd
 	SYSMENU 0xffff_ffff
@@ -2643,7 +2703,9 @@ const_ws! { SS: u32;
 
 const_bitflag! { STATE_SYSTEM: u32;
 	/// [`DATETIMEPICKERINFO`](crate::DATETIMEPICKERINFO) `stateCheck` and
-	/// `stateButton`, [`TITLEBARINFOEX`](crate::TITLEBARINFOEX) `rgstate`,
+	/// `stateButton`, [`TITLEBARINFO`](crate::TITLEBARINFO) and
+	/// [`TITLEBARINFOEX`](crate::TITLEBARINFOEX) `rgstate`,
+	/// [`SCROLLBARINFO`](crate::SCROLLBARINFO) `rgstate`,
 	/// [`COMBOBOXINFO`](crate::COMBOBOXINFO) `stateButton` (`u32`).
 	=>
 	=>
@@ -2767,6 +2829,17 @@ const_bitflag! { TPM: u32;
 	WORKAREA 0x10000
 }
 
+const_bitflag! { ULW: u32;
+	/// [`UPDATELAYEREDWINDOWINFO`](crate::UPDATELAYEREDWINDOWINFO) `dwFlags`
+	/// (`u32`).
+	=>
+	=>
+	COLORKEY 0x0000_0001
+	ALPHA 0x0000_0002
+	OPAQUE 0x0000_0004
+	EX_NORESIZE 0x0000_0008
+}
+
 const_ordinary! { UOI: i32;
 	/// [`HPROCESS::SetUserObjectInformation`](crate::prelude::user_Hprocess::SetUserObjectInformation)
 	/// `index` (`i32`).
@@ -3174,6 +3247,7 @@ const_ordinary! { WM: u32;
 	SYSCOLORCHANGE 0x0015
 	SHOWWINDOW 0x0018
 	WININICHANGE 0x001a
+	SETTINGCHANGE Self::WININICHANGE.0
 	DEVMODECHANGE 0x001b
 	ACTIVATEAPP 0x001c
 	FONTCHANGE 0x001d
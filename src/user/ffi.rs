@@ -14,6 +14,7 @@ extern_sys! { "user32";
 }
 
 extern_sys! { "user32";
+	AddClipboardFormatListener(HANDLE) -> BOOL
 	AdjustWindowRectEx(PVOID, u32, BOOL, u32) -> BOOL
 	AllowSetForegroundWindow(u32) -> BOOL
 	AnyPopup() -> BOOL
@@ -22,6 +23,7 @@ extern_sys! { "user32";
 	AttachThreadInput(u32, u32, BOOL) -> BOOL
 	BeginDeferWindowPos(i32) -> HANDLE
 	BeginPaint(HANDLE, PVOID) -> HANDLE
+	BlockInput(BOOL) -> BOOL
 	BringWindowToTop(HANDLE) -> BOOL
 	BroadcastSystemMessageW(u32, *mut u32, u32, usize, isize) -> i32
 	CallNextHookEx(HANDLE, i32, usize, isize) -> isize
@@ -36,6 +38,7 @@ extern_sys! { "user32";
 	CloseDesktop(HANDLE) -> BOOL
 	CloseWindow(HANDLE) -> BOOL
 	CopyIcon(HANDLE) -> HANDLE
+	CountClipboardFormats() -> i32
 	CreateAcceleratorTableW(PVOID, i32) -> HANDLE
 	CreateDesktopExW(PCSTR, PCSTR, PCVOID, u32, u32, PVOID, u32, PVOID) -> HANDLE
 	CreateDesktopW(PCSTR, PCSTR, PCVOID, u32, u32, PVOID) -> HANDLE
@@ -44,6 +47,8 @@ extern_sys! { "user32";
 	CreatePopupMenu() -> HANDLE
 	CreateWindowExW(u32, PCSTR, PCSTR, u32, i32, i32, i32, i32, HANDLE, HANDLE, HANDLE, PVOID) -> HANDLE
 	DeferWindowPos(HANDLE, HANDLE, HANDLE, i32, i32, i32, i32, u32) -> HANDLE
+	DefFrameProcW(HANDLE, HANDLE, u32, usize, isize) -> isize
+	DefMDIChildProcW(HANDLE, u32, usize, isize) -> isize
 	DefWindowProcW(HANDLE, u32, usize, isize) -> isize
 	DeleteMenu(HANDLE, u32, u32) -> BOOL
 	DestroyAcceleratorTable(HANDLE) -> BOOL
@@ -51,6 +56,7 @@ extern_sys! { "user32";
 	DestroyIcon(HANDLE) -> BOOL
 	DestroyMenu(HANDLE) -> BOOL
 	DestroyWindow(HANDLE) -> BOOL
+	DisableProcessWindowsGhosting()
 	DialogBoxParamW(HANDLE, PCSTR, HANDLE, PFUNC, isize) -> isize
 	DispatchMessageW(PCVOID) -> isize
 	DrawMenuBar(HANDLE) -> BOOL
@@ -64,6 +70,7 @@ extern_sys! { "user32";
 	EndMenu() -> BOOL
 	EndPaint(HANDLE, PCVOID) -> BOOL
 	EnumChildWindows(HANDLE, PFUNC, isize) -> BOOL
+	EnumClipboardFormats(u32) -> u32
 	EnumDisplayDevicesW(PCSTR, u32, PVOID, u32) -> BOOL
 	EnumDisplayMonitors(HANDLE, PCVOID, PFUNC, isize) -> BOOL
 	EnumDisplaySettingsExW(PCSTR, u32, PVOID, u32) -> BOOL
@@ -82,8 +89,10 @@ extern_sys! { "user32";
 	GetClassNameW(HANDLE, PSTR, i32) -> i32
 	GetClientRect(HANDLE, PVOID) -> BOOL
 	GetClipboardData(u32) -> HANDLE
+	GetClipboardOwner() -> HANDLE
 	GetClipboardSequenceNumber() -> u32
 	GetClipCursor(PVOID) -> BOOL
+	GetComboBoxInfo(HANDLE, PVOID) -> BOOL
 	GetCursorPos(PVOID) -> BOOL
 	GetDC(HANDLE) -> HANDLE
 	GetDesktopWindow() -> HANDLE
@@ -95,6 +104,7 @@ extern_sys! { "user32";
 	GetForegroundWindow() -> HANDLE
 	GetGUIThreadInfo(u32, PVOID) -> BOOL
 	GetLastActivePopup(HANDLE) -> HANDLE
+	GetLastInputInfo(PVOID) -> BOOL
 	GetMenu(HANDLE) -> HANDLE
 	GetMenuBarInfo(HANDLE, i32, i32, PVOID) -> BOOL
 	GetMenuCheckMarkDimensions() -> u32
@@ -111,8 +121,11 @@ extern_sys! { "user32";
 	GetMonitorInfoW(HANDLE, PVOID) -> BOOL
 	GetNextDlgGroupItem(HANDLE, HANDLE, BOOL) -> HANDLE
 	GetNextDlgTabItem(HANDLE, HANDLE, BOOL) -> HANDLE
+	GetOpenClipboardWindow() -> HANDLE
 	GetParent(HANDLE) -> HANDLE
+	GetPropW(HANDLE, PCSTR) -> HANDLE
 	GetQueueStatus(u32) -> u32
+	GetScrollBarInfo(HANDLE, i32, PVOID) -> BOOL
 	GetScrollInfo(HANDLE, i32, PVOID) -> BOOL
 	GetScrollPos(HANDLE, i32) -> i32
 	GetShellWindow() -> HANDLE
@@ -120,8 +133,8 @@ extern_sys! { "user32";
 	GetSysColor(i32) -> u32
 	GetSystemMenu(HANDLE, BOOL) -> HANDLE
 	GetSystemMetrics(i32) -> i32
-	GetSystemMetricsForDpi(i32, u32) -> i32
 	GetThreadDesktop(u32) -> HANDLE
+	GetTitleBarInfo(HANDLE, PVOID) -> BOOL
 	GetTopWindow(HANDLE) -> HANDLE
 	GetUpdateRect(HANDLE, PVOID, BOOL) -> BOOL
 	GetUpdateRgn(HANDLE, HANDLE, BOOL) -> i32
@@ -146,6 +159,7 @@ extern_sys! { "user32";
 	InvalidateRgn(HANDLE, HANDLE, BOOL) -> BOOL
 	InvertRect(HANDLE, PCVOID) -> BOOL
 	IsChild(HANDLE, HANDLE) -> BOOL
+	IsClipboardFormatAvailable(u32) -> BOOL
 	IsDialogMessageW(HANDLE, PVOID) -> BOOL
 	IsGUIThread(BOOL) -> BOOL
 	IsIconic(HANDLE) -> BOOL
@@ -173,6 +187,7 @@ extern_sys! { "user32";
 	MonitorFromRect(PCVOID, u32) -> HANDLE
 	MonitorFromWindow(HANDLE, u32) -> HANDLE
 	MoveWindow(HANDLE, i32, i32, i32, i32, BOOL) -> BOOL
+	MsgWaitForMultipleObjectsEx(u32, *const HANDLE, u32, u32, u32) -> u32
 	OffsetRect(PVOID, i32, i32) -> BOOL
 	OpenClipboard(HANDLE) -> BOOL
 	OpenDesktopW(PCSTR, u32, BOOL, u32) -> HANDLE
@@ -187,10 +202,13 @@ extern_sys! { "user32";
 	RealGetWindowClassW(HANDLE, PSTR, i32) -> u32
 	RedrawWindow(HANDLE, PCVOID, HANDLE, u32) -> BOOL
 	RegisterClassExW(PCVOID) -> u16
+	RegisterClipboardFormatW(PCSTR) -> u32
 	RegisterWindowMessageW(PCSTR) -> u32
 	ReleaseCapture() -> BOOL
 	ReleaseDC(HANDLE, HANDLE) -> i32
+	RemoveClipboardFormatListener(HANDLE) -> BOOL
 	RemoveMenu(HANDLE, u32, u32) -> BOOL
+	RemovePropW(HANDLE, PCSTR) -> HANDLE
 	ScreenToClient(HANDLE, PVOID) -> BOOL
 	ScrollWindowEx(HANDLE, i32, i32, PCVOID, PCVOID, HANDLE, PVOID, u32) -> i32
 	SendInput(u32, PVOID, i32) -> u32
@@ -212,6 +230,7 @@ extern_sys! { "user32";
 	SetMenuItemInfoW(HANDLE, u32, BOOL, PCVOID) -> BOOL
 	SetParent(HANDLE, HANDLE) -> HANDLE
 	SetProcessDPIAware() -> BOOL
+	SetPropW(HANDLE, PCSTR, HANDLE) -> BOOL
 	SetScrollInfo(HANDLE, i32, PCVOID, BOOL) -> i32
 	SetScrollPos(HANDLE, i32, i32, BOOL) -> i32
 	SetScrollRange(HANDLE, i32, i32, i32, BOOL) -> BOOL
@@ -230,6 +249,9 @@ extern_sys! { "user32";
 	ShowOwnedPopups(HANDLE, BOOL) -> BOOL
 	ShowWindow(HANDLE, i32) -> BOOL
 	ShowWindowAsync(HANDLE, i32) -> BOOL
+	ShutdownBlockReasonCreate(HANDLE, PCSTR) -> BOOL
+	ShutdownBlockReasonDestroy(HANDLE) -> BOOL
+	ShutdownBlockReasonQuery(HANDLE, PSTR, *mut u32) -> BOOL
 	SoundSentry() -> BOOL
 	SubtractRect(PVOID, PCVOID, PCVOID) -> BOOL
 	SwapMouseButton(BOOL) -> BOOL
@@ -239,10 +261,12 @@ extern_sys! { "user32";
 	TrackMouseEvent(PVOID) -> BOOL
 	TrackPopupMenu(HANDLE, u32, i32, i32, i32, HANDLE, PCVOID) -> BOOL
 	TranslateAcceleratorW(HANDLE, HANDLE, PVOID) -> i32
+	TranslateMDISysAccel(HANDLE, PVOID) -> BOOL
 	TranslateMessage(PCVOID) -> BOOL
 	UnhookWindowsHookEx(HANDLE) -> BOOL
 	UnionRect(PVOID, PCVOID, PCVOID) -> BOOL
 	UnregisterClassW(PCSTR, HANDLE) -> BOOL
+	UpdateLayeredWindowIndirect(HANDLE, PCVOID) -> BOOL
 	UpdateWindow(HANDLE) -> BOOL
 	ValidateRect(HANDLE, PCVOID) -> BOOL
 	ValidateRgn(HANDLE, HANDLE) -> BOOL
@@ -252,3 +276,14 @@ extern_sys! { "user32";
 	WindowFromPoint(i32, i32) -> HANDLE
 	WinHelpW(HANDLE, PCSTR, u32, usize) -> BOOL
 }
+
+extern_sys_delay! { "user32";
+	GetDpiForSystem() -> u32
+	GetDpiForWindow(HANDLE) -> u32
+	GetSystemMetricsForDpi(i32, u32) -> i32
+	SetProcessDpiAwarenessContext(HANDLE) -> BOOL
+}
+
+extern_sys_delay! { "gdi32";
+	GetDeviceCaps(HANDLE, i32) -> i32 // fallback for GetDpiForSystem
+}
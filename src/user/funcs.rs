@@ -1,14 +1,18 @@
 #![allow(non_snake_case)]
 
 use crate::{co, user};
-use crate::kernel::decl::{GetLastError, HINSTANCE, SysResult, WString};
+use crate::kernel::decl::{
+	GetLastError, HINSTANCE, SetLastError, SysResult, WString,
+};
 use crate::kernel::ffi_types::BOOL;
-use crate::kernel::privs::{bool_to_sysresult, ptr_to_sysresult};
+use crate::kernel::privs::{bool_to_sysresult, INFINITE, ptr_to_sysresult};
 use crate::prelude::{Handle, MsgSend};
 use crate::user::decl::{
 	ATOM, AtomStr, COLORREF, DEVMODE, DISPLAY_DEVICE, GmidxEnum, GUITHREADINFO,
-	HwKbMouse, HWND, INPUT, MSG, POINT, RECT, SIZE, TRACKMOUSEEVENT, WNDCLASSEX,
+	HwKbMouse, HWND, INPUT, LASTINPUTINFO, MSG, POINT, RECT, SIZE, TRACKMOUSEEVENT,
+	WNDCLASSEX,
 };
+use crate::user::guard::DetachThreadInputGuard;
 use crate::user::privs::ASFW_ANY;
 
 /// [`AdjustWindowRectEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-adjustwindowrectex)
@@ -51,14 +55,46 @@ pub fn AnyPopup() -> bool {
 
 /// [`AttachThreadInput`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-attachthreadinput)
 /// function.
+///
+/// In the original C implementation, you must call `AttachThreadInput` again,
+/// with `fAttach` set to `FALSE`, as a cleanup operation.
+///
+/// Here, the cleanup is performed automatically, because `AttachThreadInput`
+/// returns a [`DetachThreadInputGuard`](crate::guard::DetachThreadInputGuard),
+/// which automatically detaches the threads when the guard goes out of scope.
+/// You must, however, keep the guard alive, otherwise the cleanup will be
+/// performed right away.
+///
+/// # Examples
+///
+/// Retrieving the focused window of the foreground application:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{AttachThreadInput, GetCurrentThreadId, HWND};
+///
+/// if let Some(hwnd_fg) = HWND::GetForegroundWindow() {
+///     let (fg_thread_id, _) = hwnd_fg.GetWindowThreadProcessId();
+///     let _detach = AttachThreadInput(GetCurrentThreadId(), fg_thread_id)?;
+///     let hwnd_focus = HWND::GetFocus();
+/// } // threads detached here
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
 pub fn AttachThreadInput(
-	attach_id: u32, attach_to_id: u32, do_attach: bool) -> SysResult<()>
+	attach_id: u32, attach_to_id: u32) -> SysResult<DetachThreadInputGuard>
 {
 	bool_to_sysresult(
-		unsafe {
-			user::ffi::AttachThreadInput(attach_id, attach_to_id, do_attach as _)
-		},
-	)
+		unsafe { user::ffi::AttachThreadInput(attach_id, attach_to_id, 1) },
+	).map(|_| unsafe { DetachThreadInputGuard::new(attach_id, attach_to_id) })
+}
+
+/// [`BlockInput`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-blockinput)
+/// function.
+///
+/// Input is automatically unblocked if the blocking thread exits, or when
+/// the user presses Ctrl+Alt+Del. Requires administrative privileges.
+pub fn BlockInput(block_it: bool) -> SysResult<()> {
+	bool_to_sysresult(unsafe { user::ffi::BlockInput(block_it as _) })
 }
 
 /// [`BroadcastSystemMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-broadcastsystemmessage)
@@ -148,6 +184,25 @@ pub fn ClipCursor(rc: Option<&RECT>) -> SysResult<()> {
 	)
 }
 
+/// [`CountClipboardFormats`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-countclipboardformats)
+/// function.
+pub fn CountClipboardFormats() -> SysResult<u32> {
+	SetLastError(co::ERROR::SUCCESS);
+	match unsafe { user::ffi::CountClipboardFormats() } {
+		0 => match GetLastError() {
+			co::ERROR::SUCCESS => Ok(0), // clipboard is empty
+			err => Err(err),
+		},
+		count => Ok(count as _),
+	}
+}
+
+/// [`DisableProcessWindowsGhosting`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-disableprocesswindowsghosting)
+/// function.
+pub fn DisableProcessWindowsGhosting() {
+	unsafe { user::ffi::DisableProcessWindowsGhosting() }
+}
+
 /// [`DispatchMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-dispatchmessagew)
 /// function.
 ///
@@ -440,6 +495,26 @@ pub fn GetDoubleClickTime() -> u32 {
 	unsafe { user::ffi::GetDoubleClickTime() }
 }
 
+/// [`GetDpiForSystem`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdpiforsystem)
+/// function.
+///
+/// On systems older than Windows 10 version 1607, where this function is not
+/// available, the DPI of the screen device context is returned.
+#[must_use]
+pub fn GetDpiForSystem() -> u32 {
+	match user::ffi::GetDpiForSystem() {
+		Ok(func) => unsafe { func() },
+		Err(_) => unsafe {
+			const LOGPIXELSX: i32 = 88;
+			let hdc = user::ffi::GetDC(std::ptr::null_mut());
+			let dpi = user::ffi::GetDeviceCaps()
+				.map_or(0, |func| func(hdc, LOGPIXELSX));
+			user::ffi::ReleaseDC(std::ptr::null_mut(), hdc);
+			if dpi > 0 { dpi as _ } else { 96 } // USER_DEFAULT_SCREEN_DPI
+		},
+	}
+}
+
 /// [`GetGUIThreadInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getguithreadinfo)
 /// function.
 ///
@@ -468,6 +543,17 @@ pub fn GetGUIThreadInfo(
 	)
 }
 
+/// [`GetLastInputInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getlastinputinfo)
+/// function.
+///
+/// To retrieve the system idle time, see
+/// [`LASTINPUTINFO::idle_duration`](crate::LASTINPUTINFO::idle_duration).
+pub fn GetLastInputInfo(lii: &mut LASTINPUTINFO) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe { user::ffi::GetLastInputInfo(lii as *mut _ as _) },
+	)
+}
+
 /// [`GetMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getmessagew)
 /// function.
 pub fn GetMessage(
@@ -527,9 +613,13 @@ pub fn GetSystemMetrics(index: co::SM) -> i32 {
 
 /// [`GetSystemMetricsForDpi`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getsystemmetricsfordpi)
 /// function.
+///
+/// Returns [`co::ERROR::CALL_NOT_IMPLEMENTED`](crate::co::ERROR::CALL_NOT_IMPLEMENTED)
+/// on systems older than Windows 10 version 1607.
 #[must_use]
 pub fn GetSystemMetricsForDpi(index: co::SM, dpi: u32) -> SysResult<i32> {
-	match unsafe { user::ffi::GetSystemMetricsForDpi(index.0, dpi) } {
+	let func = user::ffi::GetSystemMetricsForDpi()?;
+	match unsafe { func(index.0, dpi) } {
 		0 => match GetLastError() {
 			co::ERROR::SUCCESS => Ok(0), // actual value is zero
 			err => Err(err),
@@ -579,6 +669,13 @@ pub fn IntersectRect(
 	)
 }
 
+/// [`IsClipboardFormatAvailable`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isclipboardformatavailable)
+/// function.
+#[must_use]
+pub fn IsClipboardFormatAvailable(format: co::CF) -> bool {
+	unsafe { user::ffi::IsClipboardFormatAvailable(format.0) != 0 }
+}
+
 /// [`IsGUIThread`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isguithread)
 /// function.
 pub fn IsGUIThread(convert_to_gui_thread: bool) -> SysResult<bool> {
@@ -616,6 +713,38 @@ pub fn LockSetForegroundWindow(lock_code: co::LSFW) -> SysResult<()> {
 	)
 }
 
+/// [`MsgWaitForMultipleObjectsEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-msgwaitformultipleobjectsex)
+/// function.
+///
+/// If `milliseconds` is `None`, waits indefinitely.
+pub fn MsgWaitForMultipleObjectsEx<H>(
+	handles: &[&H],
+	milliseconds: Option<u32>,
+	wake_mask: co::QS,
+	flags: co::MWMO,
+) -> SysResult<co::WAIT>
+	where H: Handle,
+{
+	let raw_handles = handles.iter()
+		.map(|h| h.as_ptr())
+		.collect::<Vec<_>>();
+
+	match unsafe {
+		co::WAIT(
+			user::ffi::MsgWaitForMultipleObjectsEx(
+				raw_handles.len() as _,
+				raw_handles.as_ptr(),
+				milliseconds.unwrap_or(INFINITE),
+				wake_mask.0,
+				flags.0,
+			),
+		)
+	} {
+		co::WAIT::FAILED => Err(GetLastError()),
+		wait => Ok(wait),
+	}
+}
+
 /// [`OffsetRect`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-offsetrect)
 /// function.
 pub fn OffsetRect(rc: &mut RECT, dx: i32, dy: i32) -> SysResult<()> {
@@ -686,6 +815,18 @@ pub unsafe fn RegisterClassEx(wcx: &WNDCLASSEX) -> SysResult<ATOM> {
 	}
 }
 
+/// [`RegisterClipboardFormat`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclipboardformatw)
+/// function.
+#[must_use]
+pub fn RegisterClipboardFormat(format: &str) -> SysResult<co::CF> {
+	match unsafe {
+		user::ffi::RegisterClipboardFormatW(WString::from_str(format).as_ptr())
+	} {
+		0 => Err(GetLastError()),
+		cf => Ok(co::CF(cf)),
+	}
+}
+
 /// [`RegisterWindowMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerwindowmessagew)
 /// function.
 #[must_use]
@@ -698,6 +839,17 @@ pub fn RegisterWindowMessage(s: &str) -> SysResult<u32> {
 	}
 }
 
+/// [`ReleaseCapture`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-releasecapture)
+/// function.
+///
+/// Usually you don't need to call this function, because
+/// [`HWND::SetCapture`](crate::prelude::user_Hwnd::SetCapture) returns a guard
+/// which calls it automatically. It's useful when the capture spans over
+/// different messages.
+pub fn ReleaseCapture() -> SysResult<()> {
+	bool_to_sysresult(unsafe { user::ffi::ReleaseCapture() })
+}
+
 /// [`SendInput`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-sendinput)
 /// function.
 ///
@@ -770,6 +922,12 @@ pub fn SetCaretPos(x: i32, y: i32) -> SysResult<()> {
 /// [`SetClipboardData`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setclipboarddata)
 /// function.
 ///
+/// If `hmem` is null, the clipboard owner performs
+/// [delayed rendering](https://learn.microsoft.com/en-us/windows/win32/dataxchg/clipboard-operations#delayed-rendering):
+/// it will receive [`wm::RenderFormat`](crate::msg::wm::RenderFormat) and
+/// [`wm::RenderAllFormats`](crate::msg::wm::RenderAllFormats) messages when
+/// the data is actually requested.
+///
 /// # Safety
 ///
 /// The `hmem` memory block must be correctly allocated and contain the type
@@ -777,9 +935,18 @@ pub fn SetCaretPos(x: i32, y: i32) -> SysResult<()> {
 pub unsafe fn SetClipboardData(
 	format: co::CF, hmem: *mut u8) -> SysResult<*mut u8>
 {
-	ptr_to_sysresult(
-		user::ffi::SetClipboardData(format.0, hmem as _),
-	).map(|hmem| hmem as *mut _ as _)
+	if hmem.is_null() { // delayed rendering, a null return is not an error
+		SetLastError(co::ERROR::SUCCESS);
+		let ret = user::ffi::SetClipboardData(format.0, std::ptr::null_mut());
+		match GetLastError() {
+			co::ERROR::SUCCESS => Ok(ret as _),
+			err => Err(err),
+		}
+	} else {
+		ptr_to_sysresult(
+			user::ffi::SetClipboardData(format.0, hmem as _),
+		).map(|hmem| hmem as *mut _ as _)
+	}
 }
 
 /// [`SetCursorPos`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcursorpos)
@@ -801,6 +968,20 @@ pub fn SetProcessDPIAware() -> SysResult<()> {
 	bool_to_sysresult(unsafe { user::ffi::SetProcessDPIAware() })
 }
 
+/// [`SetProcessDpiAwarenessContext`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setprocessdpiawarenesscontext)
+/// function.
+///
+/// Requires Windows 10 version 1703 for
+/// [`co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2`](crate::co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2).
+/// Returns [`co::ERROR::CALL_NOT_IMPLEMENTED`](crate::co::ERROR::CALL_NOT_IMPLEMENTED)
+/// on systems older than Windows 10 version 1607.
+pub fn SetProcessDpiAwarenessContext(
+	context: co::DPI_AWARENESS_CONTEXT) -> SysResult<()>
+{
+	let func = user::ffi::SetProcessDpiAwarenessContext()?;
+	bool_to_sysresult(unsafe { func(context.0 as _) })
+}
+
 /// [`ShowCursor`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-showcursor)
 /// function.
 pub fn ShowCursor(show: bool) -> i32 {
@@ -71,6 +71,42 @@ handle_guard! { DestroyIconGuard: HICON;
 	/// when the object goes out of scope.
 }
 
+/// RAII implementation for [`AttachThreadInput`](crate::AttachThreadInput)
+/// which automatically calls
+/// [`AttachThreadInput`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-attachthreadinput)
+/// with `fAttach` set to `FALSE` when the object goes out of scope.
+pub struct DetachThreadInputGuard {
+	attach_id: u32,
+	attach_to_id: u32,
+}
+
+impl Drop for DetachThreadInputGuard {
+	fn drop(&mut self) {
+		unsafe {
+			user::ffi::AttachThreadInput(self.attach_id, self.attach_to_id, 0); // ignore errors
+		}
+	}
+}
+
+impl DetachThreadInputGuard {
+	/// Constructs the guard by taking ownership of the thread IDs.
+	/// 
+	/// # Safety
+	/// 
+	/// Be sure the threads must be detached with
+	/// [`AttachThreadInput`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-attachthreadinput)
+	/// at the end of scope.
+	/// 
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(attach_id: u32, attach_to_id: u32) -> Self {
+		Self { attach_id, attach_to_id }
+	}
+}
+
+//------------------------------------------------------------------------------
+
 handle_guard! { EndDeferWindowPosGuard: HDWP;
 	user::ffi::EndDeferWindowPos;
 	/// RAII implementation for [`HDWP`](crate::HDWP) which automatically calls
@@ -266,3 +302,44 @@ impl<'a, H> ReleaseDCGuard<'a, H>
 		std::mem::replace(&mut self.hdc, HDC::INVALID)
 	}
 }
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for clipboard format listener which automatically
+/// calls
+/// [`RemoveClipboardFormatListener`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-removeclipboardformatlistener)
+/// when the object goes out of scope.
+pub struct RemoveClipboardFormatListenerGuard<'a, H>
+	where H: user_Hwnd,
+{
+	hwnd: &'a H,
+}
+
+impl<'a, H> Drop for RemoveClipboardFormatListenerGuard<'a, H>
+	where H: user_Hwnd,
+{
+	fn drop(&mut self) {
+		if let Some(h) = self.hwnd.as_opt() {
+			unsafe { user::ffi::RemoveClipboardFormatListener(h.as_ptr()); } // ignore errors
+		}
+	}
+}
+
+impl<'a, H> RemoveClipboardFormatListenerGuard<'a, H>
+	where H: user_Hwnd,
+{
+	/// Constructs the guard by taking ownership of the handle.
+	/// 
+	/// # Safety
+	/// 
+	/// Be sure you must call
+	/// [`RemoveClipboardFormatListener`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-removeclipboardformatlistener)
+	/// at the end of scope.
+	/// 
+	/// This method is used internally by the library, and not intended to be
+	/// used externally.
+	#[must_use]
+	pub const unsafe fn new(hwnd: &'a H) -> Self {
+		Self { hwnd }
+	}
+}
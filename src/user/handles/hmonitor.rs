@@ -4,7 +4,7 @@ use crate::{co, user};
 use crate::kernel::decl::SysResult;
 use crate::kernel::privs::bool_to_sysresult;
 use crate::prelude::Handle;
-use crate::user::decl::{MONITORINFOEX, POINT, RECT};
+use crate::user::decl::{GetSystemMetrics, MONITORINFOEX, POINT, RECT, SIZE};
 
 impl_handle! { HMONITOR;
 	/// Handle to a
@@ -22,6 +22,80 @@ impl user_Hmonitor for HMONITOR {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait user_Hmonitor: Handle {
+	/// Returns the work area of the monitor – the area not covered by the
+	/// taskbar and docked toolbars –, retrieved with
+	/// [`HMONITOR::GetMonitorInfo`](crate::prelude::user_Hmonitor::GetMonitorInfo).
+	#[must_use]
+	fn work_area(&self) -> SysResult<RECT> {
+		let mut mi = MONITORINFOEX::default();
+		self.GetMonitorInfo(&mut mi)?;
+		Ok(mi.rcWork)
+	}
+
+	/// Moves the rectangle so it lies entirely within the work area of the
+	/// monitor nearest to it, found with
+	/// [`HMONITOR::MonitorFromRect`](crate::prelude::user_Hmonitor::MonitorFromRect).
+	/// If the rectangle is larger than the work area, it's also shrunk to fit.
+	///
+	/// This is useful to restore a saved window position, which may be out of
+	/// the screen if a monitor was disconnected.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{HMONITOR, RECT};
+	///
+	/// let saved = RECT { left: 3000, top: 200, right: 3800, bottom: 800 };
+	/// let rc = HMONITOR::clamp_rect(saved)?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn clamp_rect(rc: RECT) -> SysResult<RECT> {
+		let rc_work = HMONITOR::MonitorFromRect(rc, co::MONITOR::DEFAULTTONEAREST)
+			.work_area()?;
+
+		let cx = (rc.right - rc.left).min(rc_work.right - rc_work.left);
+		let cy = (rc.bottom - rc.top).min(rc_work.bottom - rc_work.top);
+		let left = rc.left.max(rc_work.left).min(rc_work.right - cx);
+		let top = rc.top.max(rc_work.top).min(rc_work.bottom - cy);
+		Ok(RECT { left, top, right: left + cx, bottom: top + cy })
+	}
+
+	/// Computes the position of a new window of the given size, the `index`-th
+	/// of a cascade starting at the top-left corner of the work area of the
+	/// monitor. Each step is offset by the height of a title bar, and the
+	/// cascade wraps back to the corner when the window would not fit.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HMONITOR, POINT, SIZE};
+	///
+	/// let hmon = HMONITOR::MonitorFromPoint(
+	///     POINT::default(), co::MONITOR::DEFAULTTOPRIMARY);
+	///
+	/// for i in 0..3 {
+	///     let pos = hmon.cascade_pos(i, SIZE::new(640, 480))?;
+	///     println!("Window {} at {}", i, pos);
+	/// }
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn cascade_pos(&self, index: u32, sz: SIZE) -> SysResult<POINT> {
+		let rc_work = self.work_area()?;
+		let step = GetSystemMetrics(co::SM::CYCAPTION)
+			+ GetSystemMetrics(co::SM::CYSIZEFRAME)
+			+ GetSystemMetrics(co::SM::CXPADDEDBORDER);
+
+		let room = ((rc_work.right - rc_work.left) - sz.cx)
+			.min((rc_work.bottom - rc_work.top) - sz.cy);
+		let num_steps = if step > 0 && room > 0 { room / step + 1 } else { 1 };
+		let offset = (index % num_steps as u32) as i32 * step;
+		Ok(POINT::new(rc_work.left + offset, rc_work.top + offset))
+	}
+
 	/// [`GetMonitorInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getmonitorinfow)
 	/// method.
 	///
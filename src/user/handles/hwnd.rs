@@ -4,23 +4,29 @@ use std::marker::PhantomData;
 
 use crate::{co, user};
 use crate::kernel::decl::{
-	GetLastError, HINSTANCE, HIWORD, LOWORD, SetLastError, SysResult, WString,
+	AnyResult, GetCurrentThreadId, GetLastError, GetTickCount64, HINSTANCE,
+	HIWORD, LOWORD, SetLastError, Sleep, SysResult, WString,
 };
 use crate::kernel::ffi_types::BOOL;
 use crate::kernel::privs::{
 	bool_to_sysresult, MAX_PATH, ptr_to_option_handle, ptr_to_sysresult_handle,
 };
-use crate::prelude::{Handle, MsgSend};
+use crate::msg::wm;
+use crate::prelude::{Handle, MsgSend, user_Hmonitor};
 use crate::user::decl::{
-	ALTTABINFO, AtomStr, HACCEL, HMENU, HMONITOR, HRGN, HwndPlace, IdMenu,
-	IdPos, MENUBARINFO, MSG, PAINTSTRUCT, POINT, PtsRc, RECT, SCROLLINFO, SIZE,
-	TIMERPROC, WINDOWINFO, WINDOWPLACEMENT,
+	ALTTABINFO, AtomStr, AttachThreadInput, ClipboardBusyError, COMBOBOXINFO, GetDpiForSystem, GetGUIThreadInfo, GUITHREADINFO, HACCEL, HICON, HMENU, HMONITOR, HRGN,
+	HwKbMouse, HwndPlace, IdMenu, IdPos, KEYBDINPUT, MENUBARINFO, MONITORINFOEX, MSG, PAINTSTRUCT, POINT, PtsRc, RECT, SCROLLBARINFO, SCROLLINFO, SendInput, SIZE,
+	TIMERPROC, TITLEBARINFO, UPDATELAYEREDWINDOWINFO, WINDOWINFO, WINDOWPLACEMENT,
 };
 use crate::user::guard::{
 	CloseClipboardGuard, EndPaintGuard, ReleaseCaptureGuard, ReleaseDCGuard,
+	RemoveClipboardFormatListenerGuard,
 };
 use crate::user::privs::zero_as_none;
 
+/// Window property which holds the state saved by `toggle_fullscreen`.
+const FULLSCREEN_PROP: &str = "WinSafeFullscreen";
+
 impl_handle! { HWND;
 	/// Handle to a
 	/// [window](https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#hwnd).
@@ -46,6 +52,12 @@ pub trait user_Hwnd: Handle {
 	/// [`HWND::GetDC`](crate::prelude::user_Hwnd::GetDC).
 	const DESKTOP: HWND = HWND(std::ptr::null_mut());
 
+	/// Represents the parent of
+	/// [message-only windows](https://learn.microsoft.com/en-us/windows/win32/winmsg/window-features#message-only-windows)
+	/// in [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx)
+	/// and [`HWND::SetParent`](crate::prelude::user_Hwnd::SetParent).
+	const MESSAGE: HWND = HWND(-3isize as _);
+
 	/// [`GetWindowLongPtr`](crate::prelude::user_Hwnd::GetWindowLongPtr)
 	/// wrapper to retrieve the window [`HINSTANCE`](crate::HINSTANCE).
 	#[must_use]
@@ -55,6 +67,376 @@ pub trait user_Hwnd: Handle {
 		}
 	}
 
+	/// Opens the clipboard with
+	/// [`HWND::OpenClipboard`](crate::prelude::user_Hwnd::OpenClipboard), runs
+	/// the closure, then closes the clipboard.
+	///
+	/// Since another window may be holding the clipboard open, the opening is
+	/// retried with an increasing back-off. If the clipboard can't be opened
+	/// within `timeout_ms`, fails with a
+	/// [`ClipboardBusyError`](crate::ClipboardBusyError), which reports the
+	/// process holding the clipboard.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, EmptyClipboard, HWND};
+	///
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	///
+	/// hwnd.with_clipboard(500, || {
+	///     EmptyClipboard()?;
+	///     Ok(())
+	/// })?;
+	/// # Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+	/// ```
+	fn with_clipboard<F, R>(&self, timeout_ms: u32, func: F) -> AnyResult<R>
+		where F: FnOnce() -> AnyResult<R>,
+	{
+		let start = GetTickCount64();
+		let mut backoff_ms = 1;
+		loop {
+			match self.OpenClipboard() {
+				Ok(_hclip) => return func(), // clipboard closed when guard is dropped
+				Err(err) => {
+					let elapsed = GetTickCount64() - start;
+					if elapsed >= timeout_ms as u64 {
+						let process_id = HWND::GetOpenClipboardWindow()
+							.map(|hwnd_open| hwnd_open.GetWindowThreadProcessId().1);
+						return Err(ClipboardBusyError::new(err, process_id).into());
+					}
+					Sleep(backoff_ms.min((timeout_ms as u64 - elapsed) as _));
+					backoff_ms = (backoff_ms * 2).min(100);
+				},
+			}
+		}
+	}
+
+	/// Centers the window on the work area of the monitor where it currently
+	/// is, found with
+	/// [`HWND::MonitorFromWindow`](crate::prelude::user_Hwnd::MonitorFromWindow).
+	/// If the window is larger than the work area, its top-left corner is kept
+	/// visible.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::HWND;
+	///
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	///
+	/// hwnd.center_on_monitor()?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	fn center_on_monitor(&self) -> SysResult<()> {
+		let rc = self.GetWindowRect()?;
+		let rc_work = self.MonitorFromWindow(co::MONITOR::DEFAULTTONEAREST)
+			.work_area()?;
+
+		let cx = rc.right - rc.left;
+		let cy = rc.bottom - rc.top;
+		let x = (rc_work.left + (rc_work.right - rc_work.left - cx) / 2).max(rc_work.left);
+		let y = (rc_work.top + (rc_work.bottom - rc_work.top - cy) / 2).max(rc_work.top);
+
+		self.SetWindowPos(
+			HwndPlace::None,
+			POINT::new(x, y),
+			SIZE::default(),
+			co::SWP::NOSIZE | co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+		)
+	}
+
+	/// Tells whether the window is in fullscreen mode, set by
+	/// [`HWND::toggle_fullscreen`](crate::prelude::user_Hwnd::toggle_fullscreen).
+	#[must_use]
+	fn is_fullscreen(&self) -> bool {
+		let prop_name = WString::from_str(FULLSCREEN_PROP);
+		!unsafe { user::ffi::GetPropW(self.as_ptr(), prop_name.as_ptr()) }.is_null()
+	}
+
+	/// Sets both the big and the small icons of the window, by sending a
+	/// [`wm::SetIcon`](crate::msg::wm::SetIcon) message for each one, returning
+	/// the previous big and small icons, respectively.
+	///
+	/// The big icon is shown in the Alt+Tab dialog, while the small one is shown
+	/// in the title bar and in the taskbar. Pass
+	/// [`HICON::NULL`](crate::HICON::NULL) to remove an icon.
+	///
+	/// The window doesn't take ownership of the icons, so they must be kept
+	/// alive while the window uses them, and the previous ones must be destroyed
+	/// by you, if they were created by you.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{HICON, HWND};
+	///
+	/// let hwnd: HWND; // initialized somewhere
+	/// let big_icon: HICON;
+	/// let small_icon: HICON;
+	/// # let hwnd = HWND::NULL;
+	/// # let big_icon = HICON::NULL;
+	/// # let small_icon = HICON::NULL;
+	///
+	/// let (prev_big, prev_small) = hwnd.set_icon(&big_icon, &small_icon);
+	/// ```
+	fn set_icon(&self, big: &HICON, small: &HICON) -> (Option<HICON>, Option<HICON>) {
+		unsafe {
+			(
+				self.SendMessage(wm::SetIcon {
+					size: co::ICON_SZ::BIG,
+					hicon: big.raw_copy(),
+				}),
+				self.SendMessage(wm::SetIcon {
+					size: co::ICON_SZ::SMALL,
+					hicon: small.raw_copy(),
+				}),
+			)
+		}
+	}
+
+	/// Retrieves the window which has the keyboard focus in the thread that
+	/// owns this window, by calling
+	/// [`GetGUIThreadInfo`](crate::GetGUIThreadInfo).
+	///
+	/// Unlike [`HWND::GetFocus`](crate::prelude::user_Hwnd::GetFocus), which
+	/// only sees the input queue of the calling thread, this works across
+	/// threads and processes, without the need of
+	/// [`AttachThreadInput`](crate::AttachThreadInput).
+	///
+	/// # Examples
+	///
+	/// Retrieving the focused control of the foreground application:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::HWND;
+	///
+	/// if let Some(hwnd_fg) = HWND::GetForegroundWindow() {
+	///     if let Some(hwnd_focus) = hwnd_fg.thread_focus()? {
+	///         println!("Focused: {}", hwnd_focus.GetClassName()?);
+	///     }
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn thread_focus(&self) -> SysResult<Option<HWND>> {
+		let (thread_id, _) = self.GetWindowThreadProcessId();
+		let mut gti = GUITHREADINFO::default();
+		GetGUIThreadInfo(thread_id, &mut gti)?;
+		Ok(if gti.hwndFocus == HWND::NULL {
+			None
+		} else {
+			Some(gti.hwndFocus)
+		})
+	}
+
+	/// Switches a top-level window between its normal state and fullscreen,
+	/// returning whether it's now fullscreen.
+	///
+	/// When entering fullscreen, the window styles and the
+	/// [`WINDOWPLACEMENT`](crate::WINDOWPLACEMENT) are saved, the frame is
+	/// removed, and the window covers the whole monitor where it currently is
+	/// – including the taskbar –, found with
+	/// [`HWND::MonitorFromWindow`](crate::prelude::user_Hwnd::MonitorFromWindow).
+	/// When leaving, styles and placement are restored, so a maximized window
+	/// goes back to maximized.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::HWND;
+	///
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	///
+	/// let is_fullscreen = hwnd.toggle_fullscreen()?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	fn toggle_fullscreen(&self) -> SysResult<bool> {
+		let prop_name = WString::from_str(FULLSCREEN_PROP);
+		let ptr_saved = unsafe {
+			user::ffi::GetPropW(self.as_ptr(), prop_name.as_ptr())
+		} as *mut (isize, WINDOWPLACEMENT);
+
+		if ptr_saved.is_null() { // enter fullscreen
+			let style = self.GetWindowLongPtr(co::GWLP::STYLE);
+			let mut wp = WINDOWPLACEMENT::default();
+			self.GetWindowPlacement(&mut wp)?;
+
+			let mut mi = MONITORINFOEX::default();
+			self.MonitorFromWindow(co::MONITOR::DEFAULTTONEAREST)
+				.GetMonitorInfo(&mut mi)?;
+
+			let ptr_saved = Box::into_raw(Box::new((style, wp)));
+			if let Err(e) = bool_to_sysresult(
+				unsafe {
+					user::ffi::SetPropW(self.as_ptr(), prop_name.as_ptr(), ptr_saved as _)
+				},
+			) {
+				let _ = unsafe { Box::from_raw(ptr_saved) };
+				return Err(e);
+			}
+
+			// A frameless window covering the whole monitor makes the taskbar
+			// go behind it.
+			self.SetWindowLongPtr(co::GWLP::STYLE,
+				style & !(co::WS::OVERLAPPEDWINDOW.0 as isize));
+			self.SetWindowPos(
+				HwndPlace::Place(co::HWND_PLACE::TOP),
+				POINT::new(mi.rcMonitor.left, mi.rcMonitor.top),
+				SIZE::new(
+					mi.rcMonitor.right - mi.rcMonitor.left,
+					mi.rcMonitor.bottom - mi.rcMonitor.top,
+				),
+				co::SWP::NOOWNERZORDER | co::SWP::FRAMECHANGED,
+			)?;
+			Ok(true)
+
+		} else { // leave fullscreen
+			unsafe { user::ffi::RemovePropW(self.as_ptr(), prop_name.as_ptr()); }
+			let (style, wp) = *unsafe { Box::from_raw(ptr_saved) };
+
+			self.SetWindowLongPtr(co::GWLP::STYLE, style);
+			self.SetWindowPlacement(&wp)?;
+			self.SetWindowPos(
+				HwndPlace::None,
+				POINT::default(),
+				SIZE::default(),
+				co::SWP::NOMOVE | co::SWP::NOSIZE | co::SWP::NOZORDER
+					| co::SWP::NOOWNERZORDER | co::SWP::FRAMECHANGED,
+			)?;
+			Ok(false)
+		}
+	}
+
+	/// Brings the window to the foreground and activates it, working around the
+	/// focus-stealing prevention of the system, which makes
+	/// [`HWND::SetForegroundWindow`](crate::prelude::user_Hwnd::SetForegroundWindow)
+	/// fail when called from a process which is not in the foreground – like a
+	/// tray application, or one which just received an IPC request.
+	///
+	/// The following strategies are tried, in order, until one succeeds:
+	///
+	/// 1. a plain `SetForegroundWindow` call;
+	/// 2. attaching the input of the current thread to the thread of the
+	///    current foreground window, with
+	///    [`AttachThreadInput`](crate::AttachThreadInput);
+	/// 3. simulating an Alt key press with [`SendInput`](crate::SendInput),
+	///    which makes the system consider the calling process as having
+	///    received the last input event.
+	///
+	/// A minimized window is restored first. If all strategies fail, returns
+	/// [`co::ERROR::ACCESS_DENIED`](crate::co::ERROR::ACCESS_DENIED), and the
+	/// taskbar button will usually flash instead.
+	///
+	/// If the process which currently owns the foreground is yours, prefer
+	/// calling [`AllowSetForegroundWindow`](crate::AllowSetForegroundWindow)
+	/// in it, passing the process ID of the target window.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::HWND;
+	///
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	///
+	/// hwnd.force_foreground()?;
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	fn force_foreground(&self) -> SysResult<()> {
+		if self.IsIconic() {
+			self.ShowWindow(co::SW::RESTORE);
+		}
+
+		if self.SetForegroundWindow() {
+			return Ok(());
+		}
+
+		let cur_thread_id = GetCurrentThreadId();
+		if let Some(hwnd_fg) = HWND::GetForegroundWindow() {
+			let (fg_thread_id, _) = hwnd_fg.GetWindowThreadProcessId();
+			if fg_thread_id != cur_thread_id {
+				if let Ok(_detach) = AttachThreadInput(cur_thread_id, fg_thread_id) {
+					let ok = self.SetForegroundWindow();
+					self.BringWindowToTop().ok(); // ignore errors
+					if ok {
+						return Ok(());
+					}
+				}
+			}
+		}
+
+		SendInput(&[
+			HwKbMouse::Kb(KEYBDINPUT {
+				wVk: co::VK::MENU,
+				..Default::default()
+			}),
+			HwKbMouse::Kb(KEYBDINPUT {
+				wVk: co::VK::MENU,
+				dwFlags: co::KEYEVENTF::KEYUP,
+				..Default::default()
+			}),
+		])?;
+		if self.SetForegroundWindow() {
+			Ok(())
+		} else {
+			Err(co::ERROR::ACCESS_DENIED)
+		}
+	}
+
+	/// [`AddClipboardFormatListener`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-addclipboardformatlistener)
+	/// method.
+	///
+	/// After this call, the window will receive
+	/// [`WM_CLIPBOARDUPDATE`](crate::prelude::GuiEvents::wm_clipboard_update)
+	/// messages whenever the contents of the clipboard change.
+	///
+	/// In the original C implementation, you must call
+	/// [`RemoveClipboardFormatListener`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-removeclipboardformatlistener)
+	/// as a cleanup operation.
+	///
+	/// Here, the cleanup is performed automatically, because
+	/// `AddClipboardFormatListener` returns a
+	/// [`RemoveClipboardFormatListenerGuard`](crate::guard::RemoveClipboardFormatListenerGuard),
+	/// which automatically calls `RemoveClipboardFormatListener` when the guard
+	/// goes out of scope. You must, however, keep the guard alive, otherwise the
+	/// cleanup will be performed right away.
+	///
+	/// Note that the [`gui`](crate::gui) windows which handle
+	/// [`wm_clipboard_update`](crate::prelude::GuiEvents::wm_clipboard_update)
+	/// are registered automatically.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::HWND;
+	///
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	///
+	/// let _listener = hwnd.AddClipboardFormatListener()?; // keep guard alive
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	fn AddClipboardFormatListener(&self)
+		-> SysResult<RemoveClipboardFormatListenerGuard<'_, Self>>
+	{
+		unsafe {
+			bool_to_sysresult(user::ffi::AddClipboardFormatListener(self.as_ptr()))
+				.map(|_| RemoveClipboardFormatListenerGuard::new(self))
+		}
+	}
+
 	/// [`ArrangeIconicWindows`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-arrangeiconicwindows)
 	/// method.
 	fn ArrangeIconicWindows(&self) -> SysResult<u32> {
@@ -216,6 +598,46 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`DefFrameProc`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-defframeprocw)
+	/// method.
+	///
+	/// Must be called on an MDI frame window, instead of
+	/// [`DefWindowProc`](crate::prelude::user_Hwnd::DefWindowProc).
+	fn DefFrameProc<M>(&self, hwnd_mdi_client: Option<&HWND>, msg: M) -> M::RetType
+		where M: MsgSend,
+	{
+		let mut msg = msg;
+		let wm_any = msg.as_generic_wm();
+		msg.convert_ret(
+			unsafe {
+				user::ffi::DefFrameProcW(
+					self.as_ptr(),
+					hwnd_mdi_client.map_or(std::ptr::null_mut(), |h| h.as_ptr()),
+					wm_any.msg_id.0, wm_any.wparam, wm_any.lparam,
+				)
+			},
+		)
+	}
+
+	/// [`DefMDIChildProc`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-defmdichildprocw)
+	/// method.
+	///
+	/// Must be called on an MDI child window, instead of
+	/// [`DefWindowProc`](crate::prelude::user_Hwnd::DefWindowProc).
+	fn DefMDIChildProc<M>(&self, msg: M) -> M::RetType
+		where M: MsgSend,
+	{
+		let mut msg = msg;
+		let wm_any = msg.as_generic_wm();
+		msg.convert_ret(
+			unsafe {
+				user::ffi::DefMDIChildProcW(
+					self.as_ptr(), wm_any.msg_id.0, wm_any.wparam, wm_any.lparam,
+				)
+			},
+		)
+	}
+
 	/// [`DefWindowProc`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-defwindowprocw)
 	/// method.
 	///
@@ -451,6 +873,39 @@ pub trait user_Hwnd: Handle {
 		).map(|_| rc)
 	}
 
+	/// [`GetClipboardOwner`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclipboardowner)
+	/// static method.
+	#[must_use]
+	fn GetClipboardOwner() -> Option<HWND> {
+		ptr_to_option_handle(unsafe { user::ffi::GetClipboardOwner() })
+	}
+
+	/// [`GetComboBoxInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getcomboboxinfo)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{COMBOBOXINFO, HWND};
+	///
+	/// let hcombo: HWND; // initialized somewhere
+	/// # let hcombo = HWND::NULL;
+	///
+	/// let mut cbi = COMBOBOXINFO::default();
+	/// hcombo.GetComboBoxInfo(&mut cbi)?;
+	///
+	/// println!("Button: {}", cbi.rcButton);
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	fn GetComboBoxInfo(&self, cbi: &mut COMBOBOXINFO) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe {
+				user::ffi::GetComboBoxInfo(self.as_ptr(), cbi as *mut _ as _)
+			},
+		)
+	}
+
 	/// [`GetDC`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdc)
 	/// method.
 	///
@@ -494,6 +949,22 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`GetDpiForWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdpiforwindow)
+	/// method.
+	///
+	/// Returns zero if the window handle is invalid.
+	///
+	/// On systems older than Windows 10 version 1607, where this function is not
+	/// available, returns the result of
+	/// [`GetDpiForSystem`](crate::GetDpiForSystem).
+	#[must_use]
+	fn GetDpiForWindow(&self) -> u32 {
+		match user::ffi::GetDpiForWindow() {
+			Ok(func) => unsafe { func(self.as_ptr()) },
+			Err(_) => GetDpiForSystem(),
+		}
+	}
+
 	/// [`GetFocus`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getfocus)
 	/// static method.
 	#[must_use]
@@ -591,6 +1062,15 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`GetOpenClipboardWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getopenclipboardwindow)
+	/// static method.
+	///
+	/// Returns the window which currently has the clipboard open, if any.
+	#[must_use]
+	fn GetOpenClipboardWindow() -> Option<HWND> {
+		ptr_to_option_handle(unsafe { user::ffi::GetOpenClipboardWindow() })
+	}
+
 	/// [`GetParent`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getparent)
 	/// method.
 	#[must_use]
@@ -598,6 +1078,43 @@ pub trait user_Hwnd: Handle {
 		ptr_to_sysresult_handle(unsafe { user::ffi::GetParent(self.as_ptr()) })
 	}
 
+	/// [`GetScrollBarInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getscrollbarinfo)
+	/// method.
+	///
+	/// For the scroll bars of a window, pass
+	/// [`co::OBJID::HSCROLL`](crate::co::OBJID::HSCROLL) or
+	/// [`co::OBJID::VSCROLL`](crate::co::OBJID::VSCROLL); for a scroll bar
+	/// control, pass [`co::OBJID::CLIENT`](crate::co::OBJID::CLIENT).
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HWND, SCROLLBARINFO};
+	///
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	///
+	/// let mut sbi = SCROLLBARINFO::default();
+	/// hwnd.GetScrollBarInfo(co::OBJID::VSCROLL, &mut sbi)?;
+	///
+	/// println!("Thumb: {} to {}", sbi.xyThumbTop, sbi.xyThumbBottom);
+	/// # Ok::<_, co::ERROR>(())
+	/// ```
+	fn GetScrollBarInfo(&self,
+		obj_id: co::OBJID, sbi: &mut SCROLLBARINFO) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				user::ffi::GetScrollBarInfo(
+					self.as_ptr(),
+					obj_id.0 as _,
+					sbi as *mut _ as _,
+				)
+			},
+		)
+	}
+
 	/// [`GetScrollInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getscrollinfo)
 	/// method.
 	fn GetScrollInfo(&self,
@@ -639,6 +1156,21 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`GetTitleBarInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-gettitlebarinfo)
+	/// method.
+	///
+	/// The elements of `rgstate` are, in order: the title bar itself, a
+	/// reserved one, the minimize, maximize, help and close buttons. To also
+	/// retrieve the button rectangles, send a
+	/// [`wm::GetTitleBarInfoEx`](crate::msg::wm::GetTitleBarInfoEx) message.
+	fn GetTitleBarInfo(&self, tbi: &mut TITLEBARINFO) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe {
+				user::ffi::GetTitleBarInfo(self.as_ptr(), tbi as *mut _ as _)
+			},
+		)
+	}
+
 	/// [`GetTopWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-gettopwindow)
 	/// method.
 	#[must_use]
@@ -1651,6 +2183,54 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`ShutdownBlockReasonCreate`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-shutdownblockreasoncreate)
+	/// method.
+	///
+	/// Tells the system the application must not be terminated, usually while
+	/// handling [`WM_QUERYENDSESSION`](crate::msg::wm::QueryEndSession). The
+	/// reason is displayed to the user, and must be removed with
+	/// [`ShutdownBlockReasonDestroy`](crate::prelude::user_Hwnd::ShutdownBlockReasonDestroy)
+	/// when the critical operation is done.
+	fn ShutdownBlockReasonCreate(&self, reason: &str) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe {
+				user::ffi::ShutdownBlockReasonCreate(
+					self.as_ptr(),
+					WString::from_str(reason).as_ptr(),
+				)
+			},
+		)
+	}
+
+	/// [`ShutdownBlockReasonDestroy`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-shutdownblockreasondestroy)
+	/// method.
+	fn ShutdownBlockReasonDestroy(&self) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe { user::ffi::ShutdownBlockReasonDestroy(self.as_ptr()) },
+		)
+	}
+
+	/// [`ShutdownBlockReasonQuery`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-shutdownblockreasonquery)
+	/// method.
+	#[must_use]
+	fn ShutdownBlockReasonQuery(&self) -> SysResult<String> {
+		let mut len = u32::default();
+		bool_to_sysresult(
+			unsafe {
+				user::ffi::ShutdownBlockReasonQuery(
+					self.as_ptr(), std::ptr::null_mut(), &mut len)
+			},
+		)?;
+
+		let mut buf = WString::new_alloc_buf(len as _);
+		bool_to_sysresult(
+			unsafe {
+				user::ffi::ShutdownBlockReasonQuery(
+					self.as_ptr(), buf.as_mut_ptr(), &mut len)
+			},
+		).map(|_| buf.to_string())
+	}
+
 	/// [`TileWindows`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-tilewindows)
 	/// method.
 	fn TileWindows(&self,
@@ -1692,6 +2272,32 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`TranslateMDISysAccel`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-translatemdisysaccel)
+	/// method.
+	///
+	/// Must be called on the MDI client window. Returns `true` if the message
+	/// was translated.
+	fn TranslateMDISysAccel(&self, msg: &mut MSG) -> bool {
+		unsafe {
+			user::ffi::TranslateMDISysAccel(self.as_ptr(), msg as *mut _ as _) != 0
+		}
+	}
+
+	/// [`UpdateLayeredWindowIndirect`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-updatelayeredwindowindirect)
+	/// method.
+	fn UpdateLayeredWindowIndirect(&self,
+		info: &UPDATELAYEREDWINDOWINFO) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				user::ffi::UpdateLayeredWindowIndirect(
+					self.as_ptr(),
+					info as *const _ as _,
+				)
+			},
+		)
+	}
+
 	/// [`UpdateWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-updatewindow)
 	/// method.
 	fn UpdateWindow(&self) -> SysResult<()> {
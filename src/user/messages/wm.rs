@@ -1,11 +1,12 @@
 use crate::co;
 use crate::kernel::decl::{HIWORD, LOWORD, MAKEDWORD};
+use crate::kernel::ffi_types::BOOL;
 use crate::msg::WndMsg;
 use crate::prelude::{Handle, MsgSend, MsgSendRecv};
 use crate::user::decl::{
-	AccelMenuCtrl, AccelMenuCtrlData, CREATESTRUCT, DELETEITEMSTRUCT, HDC,
-	HELPINFO, HICON, HMENU, HWND, HwndFocus, HwndHmenu, HwndPointId, MINMAXINFO,
-	MSG, NccspRect, POINT, RECT, SIZE, STYLESTRUCT, TIMERPROC, TITLEBARINFOEX,
+	AccelMenuCtrl, AccelMenuCtrlData, CREATESTRUCT, DELETEITEMSTRUCT,
+	DRAWITEMSTRUCT, HDC, HELPINFO, HICON, HMENU, HWND, HwndFocus, HwndHmenu,
+	HwndPointId, MEASUREITEMSTRUCT, MINMAXINFO, MSG, NccspRect, POINT, RECT, SIZE, STYLESTRUCT, TIMERPROC, TITLEBARINFOEX,
 	WINDOWPOS,
 };
 use crate::user::privs::{CB_ERR, FAPPCOMMAND_MASK, LB_ERRSPACE, zero_as_none};
@@ -162,6 +163,10 @@ pub_struct_msg_empty_handleable! { ChildActivate: co::WM::CHILDACTIVATE;
 	/// [`WM_CHILDACTIVATE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-childactivate)
 }
 
+pub_struct_msg_empty_handleable! { ClipboardUpdate: co::WM::CLIPBOARDUPDATE;
+	/// [`WM_CLIPBOARDUPDATE`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-clipboardupdate)
+}
+
 pub_struct_msg_empty_handleable! { Close: co::WM::CLOSE;
 	/// [`WM_CLOSE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-close)
 }
@@ -320,6 +325,80 @@ pub_struct_msg_empty_handleable! { Destroy: co::WM::DESTROY;
 	/// [`WM_DESTROY`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-destroy)
 }
 
+pub_struct_msg_empty_handleable! { DestroyClipboard: co::WM::DESTROYCLIPBOARD;
+	/// [`WM_DESTROYCLIPBOARD`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-destroyclipboard)
+}
+
+/// [`WM_DPICHANGED`](https://learn.microsoft.com/en-us/windows/win32/hidpi/wm-dpichanged)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct DpiChanged<'a> {
+	pub new_dpi_x: u16,
+	pub new_dpi_y: u16,
+	pub suggested_rect: &'a RECT,
+}
+
+unsafe impl<'a> MsgSend for DpiChanged<'a> {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::DPICHANGED,
+			wparam: MAKEDWORD(self.new_dpi_x, self.new_dpi_y) as _,
+			lparam: self.suggested_rect as *const _ as _,
+		}
+	}
+}
+
+unsafe impl<'a> MsgSendRecv for DpiChanged<'a> {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			new_dpi_x: LOWORD(p.wparam as _),
+			new_dpi_y: HIWORD(p.wparam as _),
+			suggested_rect: unsafe { &*(p.lparam as *const _) },
+		}
+	}
+}
+
+/// [`WM_DRAWITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-drawitem)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct DrawItem<'a> {
+	pub control_id: u16,
+	pub drawitemstruct: &'a DRAWITEMSTRUCT,
+}
+
+unsafe impl<'a> MsgSend for DrawItem<'a> {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::DRAWITEM,
+			wparam: self.control_id as _,
+			lparam: self.drawitemstruct as *const _ as _,
+		}
+	}
+}
+
+unsafe impl<'a> MsgSendRecv for DrawItem<'a> {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			control_id: p.wparam as _,
+			drawitemstruct: unsafe { &*(p.lparam as *const _) },
+		}
+	}
+}
+
 /// [`WM_ENABLE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-enable)
 /// message parameters.
 ///
@@ -944,6 +1023,305 @@ pub_struct_msg_button! { MButtonUp: co::WM::MBUTTONUP;
 	/// [`WM_MBUTTONUP`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mbuttonup)
 }
 
+/// [`WM_MDIACTIVATE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-mdiactivate)
+/// message parameters.
+///
+/// When sent to the MDI client window, only `hwnd_activated` is used.
+///
+/// Return type: `()`.
+pub struct MdiActivate {
+	pub hwnd_deactivated: Option<HWND>,
+	pub hwnd_activated: Option<HWND>,
+}
+
+unsafe impl MsgSend for MdiActivate {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDIACTIVATE,
+			wparam: self.hwnd_activated.as_ref().map_or(0, |h| h.as_ptr() as _),
+			lparam: 0,
+		}
+	}
+}
+
+unsafe impl MsgSendRecv for MdiActivate {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			hwnd_deactivated: match p.wparam {
+				0 => None,
+				ptr => Some(unsafe { HWND::from_ptr(ptr as _) }),
+			},
+			hwnd_activated: match p.lparam {
+				0 => None,
+				ptr => Some(unsafe { HWND::from_ptr(ptr as _) }),
+			},
+		}
+	}
+}
+
+/// [`WM_MDICASCADE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-mdicascade)
+/// message parameters.
+///
+/// Return type: `bool`.
+pub struct MdiCascade {
+	pub how: co::MDITILE,
+}
+
+unsafe impl MsgSend for MdiCascade {
+	type RetType = bool;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v != 0
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDICASCADE,
+			wparam: self.how.0 as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`WM_MDIDESTROY`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-mdidestroy)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct MdiDestroy {
+	pub hwnd: HWND,
+}
+
+unsafe impl MsgSend for MdiDestroy {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDIDESTROY,
+			wparam: self.hwnd.as_ptr() as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`WM_MDIGETACTIVE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-mdigetactive)
+/// message, which has no parameters. Instantiate it with
+/// `MdiGetActive::default()`.
+///
+/// Return type: `Option<(HWND, bool)>`, with the active MDI child window and
+/// whether it's maximized.
+#[derive(Default)]
+pub struct MdiGetActive {
+	is_maximized: BOOL, // buffer to receive the value
+}
+
+unsafe impl MsgSend for MdiGetActive {
+	type RetType = Option<(HWND, bool)>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		zero_as_none(v).map(|p| (unsafe { HWND::from_ptr(p as _) }, self.is_maximized != 0))
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDIGETACTIVE,
+			wparam: 0,
+			lparam: &mut self.is_maximized as *mut _ as _,
+		}
+	}
+}
+
+pub_struct_msg_empty! { MdiIconArrange: co::WM::MDIICONARRANGE;
+	/// [`WM_MDIICONARRANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-mdiiconarrange)
+}
+
+/// [`WM_MDIMAXIMIZE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-mdimaximize)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct MdiMaximize {
+	pub hwnd: HWND,
+}
+
+unsafe impl MsgSend for MdiMaximize {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDIMAXIMIZE,
+			wparam: self.hwnd.as_ptr() as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`WM_MDINEXT`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-mdinext)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct MdiNext {
+	pub hwnd: Option<HWND>,
+	pub previous: bool,
+}
+
+unsafe impl MsgSend for MdiNext {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDINEXT,
+			wparam: self.hwnd.as_ref().map_or(0, |h| h.as_ptr() as _),
+			lparam: self.previous as _,
+		}
+	}
+}
+
+/// [`WM_MDIREFRESHMENU`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-mdirefreshmenu)
+/// message, which has no parameters.
+///
+/// Return type: `Option<HMENU>`.
+pub struct MdiRefreshMenu {}
+
+unsafe impl MsgSend for MdiRefreshMenu {
+	type RetType = Option<HMENU>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		zero_as_none(v).map(|p| unsafe { HMENU::from_ptr(p as _) })
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDIREFRESHMENU,
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`WM_MDIRESTORE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-mdirestore)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct MdiRestore {
+	pub hwnd: HWND,
+}
+
+unsafe impl MsgSend for MdiRestore {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDIRESTORE,
+			wparam: self.hwnd.as_ptr() as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`WM_MDISETMENU`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-mdisetmenu)
+/// message parameters.
+///
+/// Return type: `Option<HMENU>`.
+pub struct MdiSetMenu {
+	pub hmenu_frame: Option<HMENU>,
+	pub hmenu_window: Option<HMENU>,
+}
+
+unsafe impl MsgSend for MdiSetMenu {
+	type RetType = Option<HMENU>;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		zero_as_none(v).map(|p| unsafe { HMENU::from_ptr(p as _) })
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDISETMENU,
+			wparam: self.hmenu_frame.as_ref().map_or(0, |h| h.as_ptr() as _),
+			lparam: self.hmenu_window.as_ref().map_or(0, |h| h.as_ptr() as _),
+		}
+	}
+}
+
+/// [`WM_MDITILE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-mditile)
+/// message parameters.
+///
+/// Return type: `bool`.
+pub struct MdiTile {
+	pub how: co::MDITILE,
+}
+
+unsafe impl MsgSend for MdiTile {
+	type RetType = bool;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v != 0
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MDITILE,
+			wparam: self.how.0 as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`WM_MEASUREITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-measureitem)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct MeasureItem<'a> {
+	pub control_id: u16,
+	pub measureitemstruct: &'a mut MEASUREITEMSTRUCT,
+}
+
+unsafe impl<'a> MsgSend for MeasureItem<'a> {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::MEASUREITEM,
+			wparam: self.control_id as _,
+			lparam: self.measureitemstruct as *mut _ as _,
+		}
+	}
+}
+
+unsafe impl<'a> MsgSendRecv for MeasureItem<'a> {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			control_id: p.wparam as _,
+			measureitemstruct: unsafe { &mut *(p.lparam as *mut _) },
+		}
+	}
+}
+
 /// [`WM_MENUCOMMAND`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-menucommand)
 /// message parameters.
 ///
@@ -1318,6 +1696,38 @@ unsafe impl MsgSendRecv for ParentNotify {
 	}
 }
 
+/// [`WM_QUERYENDSESSION`](https://learn.microsoft.com/en-us/windows/win32/shutdown/wm-queryendsession)
+/// message parameters.
+///
+/// Return type: `bool`.
+pub struct QueryEndSession {
+	pub event: co::ENDSESSION,
+}
+
+unsafe impl MsgSend for QueryEndSession {
+	type RetType = bool;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v != 0
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::QUERYENDSESSION,
+			wparam: 0,
+			lparam: self.event.0 as _,
+		}
+	}
+}
+
+unsafe impl MsgSendRecv for QueryEndSession {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			event: co::ENDSESSION(p.lparam as _),
+		}
+	}
+}
+
 /// [`WM_QUERYOPEN`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-queryopen)
 /// message, which has no parameters.
 ///
@@ -1358,6 +1768,42 @@ pub_struct_msg_button! { RButtonUp: co::WM::RBUTTONUP;
 	/// [`WM_RBUTTONUP`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-rbuttonup)
 }
 
+pub_struct_msg_empty_handleable! { RenderAllFormats: co::WM::RENDERALLFORMATS;
+	/// [`WM_RENDERALLFORMATS`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-renderallformats)
+}
+
+/// [`WM_RENDERFORMAT`](https://learn.microsoft.com/en-us/windows/win32/dataxchg/wm-renderformat)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct RenderFormat {
+	pub clipboard_format: co::CF,
+}
+
+unsafe impl MsgSend for RenderFormat {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::RENDERFORMAT,
+			wparam: self.clipboard_format.0 as _,
+			lparam: 0,
+		}
+	}
+}
+
+unsafe impl MsgSendRecv for RenderFormat {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			clipboard_format: co::CF(p.wparam as _),
+		}
+	}
+}
+
 /// [`WM_SETCURSOR`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-setcursor)
 /// message parameters.
 ///
@@ -1511,6 +1957,61 @@ unsafe impl MsgSendRecv for SetText {
 	}
 }
 
+/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+/// message parameters.
+///
+/// Return type: `()`.
+///
+/// # Examples
+///
+/// Broadcasting a change in the environment variables:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{co, HWND, msg::wm, WString};
+///
+/// let section = WString::from_str("Environment");
+///
+/// HWND::BROADCAST.SendMessageTimeout(
+///     wm::SettingChange {
+///         parameter: co::SPI::default(),
+///         section: section.as_ptr(),
+///     },
+///     co::SMTO::ABORTIFHUNG,
+///     5000,
+/// )?;
+/// # Ok::<_, co::ERROR>(())
+/// ```
+pub struct SettingChange {
+	pub parameter: co::SPI,
+	pub section: *const u16, // can't be WString because this message can be received
+}
+
+unsafe impl MsgSend for SettingChange {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::SETTINGCHANGE,
+			wparam: self.parameter.0 as _,
+			lparam: self.section as _,
+		}
+	}
+}
+
+unsafe impl MsgSendRecv for SettingChange {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			parameter: co::SPI(p.wparam as _),
+			section: p.lparam as _,
+		}
+	}
+}
+
 /// [`WM_SHOWWINDOW`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-showwindow)
 /// message parameters.
 ///
@@ -1690,6 +2191,10 @@ pub_struct_msg_char_code! { SysChar: co::WM::SYSCHAR;
 	/// [`WM_SYSCHAR`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-syschar)
 }
 
+pub_struct_msg_empty_handleable! { SysColorChange: co::WM::SYSCOLORCHANGE;
+	/// [`WM_SYSCOLORCHANGE`](https://learn.microsoft.com/en-us/windows/win32/gdi/wm-syscolorchange)
+}
+
 /// [`WM_SYSCOMMAND`](https://learn.microsoft.com/en-us/windows/win32/menurc/wm-syscommand)
 /// message parameters.
 ///
@@ -12,6 +12,7 @@ mod funcs;
 mod handles;
 mod structs;
 mod msg_traits;
+mod utilities;
 
 pub mod decl {
 	pub use super::aliases::*;
@@ -19,6 +20,7 @@ pub mod decl {
 	pub use super::funcs::*;
 	pub use super::handles::decl::*;
 	pub use super::structs::*;
+	pub use super::utilities::*;
 }
 
 pub mod traits {
@@ -8,6 +8,7 @@ pub(crate) const CB_ERR: i32 = -1;
 pub(crate) const CB_ERRSPACE: i32 = -2;
 pub(crate) const CCHDEVICENAME: usize = 32;
 pub(crate) const CCHFORMNAME: usize = 32;
+pub(crate) const CCHILDREN_SCROLLBAR: usize = 5;
 pub(crate) const CCHILDREN_TITLEBAR: usize = 5;
 pub(crate) const DM_SPECVERSION: u16 = 0x0401;
 pub(crate) const FAPPCOMMAND_MASK: u16 = 0xf000;
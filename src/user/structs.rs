@@ -4,7 +4,8 @@ use std::marker::PhantomData;
 
 use crate::co;
 use crate::kernel::decl::{
-	HINSTANCE, HIWORD, LCID, LOBYTE, LOWORD, MAKEDWORD, WString,
+	GetTickCount, HINSTANCE, HIWORD, LCID, LOBYTE, LOWORD, MAKEDWORD, SysResult,
+	WString,
 };
 use crate::kernel::ffi_types::BOOL;
 use crate::prelude::Handle;
@@ -13,7 +14,8 @@ use crate::user::decl::{
 	HwndHmenu, HwndPlace, WNDPROC,
 };
 use crate::user::privs::{
-	CCHDEVICENAME, CCHFORMNAME, CCHILDREN_TITLEBAR, DM_SPECVERSION,
+	CCHDEVICENAME, CCHFORMNAME, CCHILDREN_SCROLLBAR, CCHILDREN_TITLEBAR,
+	DM_SPECVERSION,
 };
 
 /// [`ACCEL`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-accel)
@@ -55,6 +57,27 @@ impl std::fmt::Display for ATOM {
 	}
 }
 
+/// [`BLENDFUNCTION`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-blendfunction)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct BLENDFUNCTION {
+	pub BlendOp: co::AC_SRC,
+	pub BlendFlags: u8,
+	pub SourceConstantAlpha: u8,
+	pub AlphaFormat: co::AC_SRC,
+}
+
+/// [`CLIENTCREATESTRUCT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-clientcreatestruct)
+/// struct.
+#[repr(C)]
+pub struct CLIENTCREATESTRUCT {
+	pub hWindowMenu: HMENU,
+	pub idFirstChild: u32,
+}
+
+impl_default!(CLIENTCREATESTRUCT);
+
 /// [`COLORREF`](https://learn.microsoft.com/en-us/windows/win32/gdi/colorref)
 /// struct.
 #[repr(transparent)]
@@ -67,6 +90,18 @@ impl From<co::CLR> for COLORREF {
 	}
 }
 
+impl From<u32> for COLORREF {
+	fn from(v: u32) -> Self {
+		Self(v)
+	}
+}
+
+impl From<COLORREF> for u32 {
+	fn from(v: COLORREF) -> Self {
+		v.0
+	}
+}
+
 impl std::fmt::Display for COLORREF {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
 		write!(f, "R {}, G {}, B {}",
@@ -87,9 +122,74 @@ impl COLORREF {
 	/// ```
 	#[must_use]
 	pub const fn new(red: u8, green: u8, blue: u8) -> COLORREF {
+		Self::rgb(red, green, blue)
+	}
+
+	/// Creates a new `COLORREF` object with the given color intensities.
+	/// Originally
+	/// [`RGB`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-rgb)
+	/// macro.
+	#[must_use]
+	pub const fn rgb(red: u8, green: u8, blue: u8) -> COLORREF {
 		Self(red as u32 | ((green as u32) << 8) | ((blue as u32) << 16))
 	}
 
+	/// Parses a color in the `#rrggbb` or `#rgb` hexadecimal notation, as used
+	/// in HTML and CSS. The leading `#` is optional.
+	///
+	/// Returns [`co::ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the string is not a valid color.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::COLORREF;
+	///
+	/// let color = COLORREF::from_hex("#ff8000")?;
+	/// assert_eq!(color, COLORREF::rgb(0xff, 0x80, 0x00));
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	pub fn from_hex(hex: &str) -> SysResult<COLORREF> {
+		let digits = hex.strip_prefix('#').unwrap_or(hex);
+		if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let nibble = |i: usize| u8::from_str_radix(&digits[i..i + 1], 16).unwrap();
+
+		match digits.len() {
+			6 => Ok(Self::rgb(
+				nibble(0) << 4 | nibble(1),
+				nibble(2) << 4 | nibble(3),
+				nibble(4) << 4 | nibble(5),
+			)),
+			3 => Ok(Self::rgb( // each digit is repeated: #f80 is #ff8800
+				nibble(0) * 0x11,
+				nibble(1) * 0x11,
+				nibble(2) * 0x11,
+			)),
+			_ => Err(co::ERROR::INVALID_DATA),
+		}
+	}
+
+	/// Returns the color in the `#rrggbb` hexadecimal notation, as used in
+	/// HTML and CSS.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::COLORREF;
+	///
+	/// let color = COLORREF::rgb(0xff, 0x80, 0x00);
+	/// assert_eq!(color.to_hex(), "#ff8000");
+	/// ```
+	#[must_use]
+	pub fn to_hex(self) -> String {
+		format!("#{:02x}{:02x}{:02x}",
+			self.GetRValue(), self.GetGValue(), self.GetBValue())
+	}
+
 	/// Creates an array of `COLORREF` objects with the given color intensities.
 	///
 	/// # Examples
@@ -458,6 +558,20 @@ pub struct DRAWITEMSTRUCT {
 
 impl_default!(DRAWITEMSTRUCT);
 
+/// [`MEASUREITEMSTRUCT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-measureitemstruct)
+/// struct.
+#[repr(C)]
+pub struct MEASUREITEMSTRUCT {
+	pub CtlType: co::ODT,
+	pub CtlID: u32,
+	pub itemID: u32,
+	pub itemWidth: u32,
+	pub itemHeight: u32,
+	pub itemData: usize,
+}
+
+impl_default!(MEASUREITEMSTRUCT);
+
 /// [`MSG`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-msg)
 /// struct.
 #[repr(C)]
@@ -528,6 +642,23 @@ impl HELPINFO {
 	}
 }
 
+/// [`HIGHCONTRAST`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-highcontrastw)
+/// struct.
+#[repr(C)]
+pub struct HIGHCONTRAST<'a> {
+	cbSize: u32,
+	pub dwFlags: co::HCF,
+	lpszDefaultScheme: *mut u16,
+
+	_lpszDefaultScheme: PhantomData<&'a mut u16>,
+}
+
+impl_default_with_size!(HIGHCONTRAST, cbSize, 'a);
+
+impl<'a> HIGHCONTRAST<'a> {
+	pub_fn_string_ptr_get_set!('a, lpszDefaultScheme, set_lpszDefaultScheme);
+}
+
 /// [`INPUT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-input)
 /// struct.
 #[repr(C)]
@@ -588,6 +719,65 @@ pub struct KEYBDINPUT {
 	pub dwExtraInfo: usize,
 }
 
+/// [`LASTINPUTINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-lastinputinfo)
+/// struct.
+#[repr(C)]
+pub struct LASTINPUTINFO {
+	cbSize: u32,
+	pub dwTime: u32,
+}
+
+impl_default_with_size!(LASTINPUTINFO, cbSize);
+
+impl LASTINPUTINFO {
+	/// Returns the time elapsed since the last input event, by comparing
+	/// `dwTime` with [`GetTickCount`](crate::GetTickCount).
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::{GetLastInputInfo, LASTINPUTINFO};
+	///
+	/// let mut lii = LASTINPUTINFO::default();
+	/// GetLastInputInfo(&mut lii)?;
+	///
+	/// if lii.idle_duration().as_secs() > 5 * 60 {
+	///     println!("User is away.");
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn idle_duration(&self) -> std::time::Duration {
+		std::time::Duration::from_millis(
+			GetTickCount().wrapping_sub(self.dwTime) as _) // tick count wraps every 49.7 days
+	}
+}
+
+/// [`MDICREATESTRUCT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mdicreatestructw)
+/// struct.
+#[repr(C)]
+pub struct MDICREATESTRUCT<'a, 'b> {
+	szClass: *mut u16,
+	szTitle: *mut u16,
+	pub hOwner: HINSTANCE,
+	pub x: i32,
+	pub y: i32,
+	pub cx: i32,
+	pub cy: i32,
+	pub style: co::WS,
+	pub lParam: isize,
+
+	_szClass: PhantomData<&'a mut u16>,
+	_szTitle: PhantomData<&'b mut u16>,
+}
+
+impl_default!(MDICREATESTRUCT, 'a, 'b);
+
+impl<'a, 'b> MDICREATESTRUCT<'a, 'b> {
+	pub_fn_string_ptr_get_set!('a, szClass, set_szClass);
+	pub_fn_string_ptr_get_set!('b, szTitle, set_szTitle);
+}
+
 /// [`MENUBARINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-menubarinfo)
 /// struct.
 #[repr(C)]
@@ -758,7 +948,10 @@ impl From<POINT> for u32 {
 
 impl From<u32> for POINT {
 	fn from(v: u32) -> Self {
-		Self::new(LOWORD(v) as _, HIWORD(v) as _)
+		Self::new( // coordinates are signed, like GET_X_LPARAM and GET_Y_LPARAM
+			LOWORD(v) as i16 as _,
+			HIWORD(v) as i16 as _,
+		)
 	}
 }
 
@@ -802,6 +995,21 @@ impl RECT {
 	}
 }
 
+/// [`SCROLLBARINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-scrollbarinfo)
+/// struct.
+#[repr(C)]
+pub struct SCROLLBARINFO {
+	cbSize: u32,
+	pub rcScrollBar: RECT,
+	pub dxyLineButton: i32,
+	pub xyThumbTop: i32,
+	pub xyThumbBottom: i32,
+	reserved: i32,
+	pub rgstate: [co::STATE_SYSTEM; CCHILDREN_SCROLLBAR + 1],
+}
+
+impl_default_with_size!(SCROLLBARINFO, cbSize);
+
 /// [`SCROLLINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-scrollinfo)
 /// struct.
 #[repr(C)]
@@ -896,6 +1104,17 @@ impl STYLESTRUCT {
 	}
 }
 
+/// [`TITLEBARINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-titlebarinfo)
+/// struct.
+#[repr(C)]
+pub struct TITLEBARINFO {
+	cbSize: u32,
+	pub rcTitleBar: RECT,
+	pub rgstate: [co::STATE_SYSTEM; CCHILDREN_TITLEBAR + 1],
+}
+
+impl_default_with_size!(TITLEBARINFO, cbSize);
+
 /// [`TITLEBARINFOEX`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-titlebarinfoex)
 /// struct.
 #[repr(C)]
@@ -920,6 +1139,38 @@ pub struct TRACKMOUSEEVENT {
 
 impl_default_with_size!(TRACKMOUSEEVENT, cbSize);
 
+/// [`UPDATELAYEREDWINDOWINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-updatelayeredwindowinfo)
+/// struct.
+#[repr(C)]
+pub struct UPDATELAYEREDWINDOWINFO<'a, 'b, 'c, 'd, 'e> {
+	cbSize: u32,
+	pub hdcDst: HDC,
+	pptDst: *mut POINT,
+	psize: *mut SIZE,
+	pub hdcSrc: HDC,
+	pptSrc: *mut POINT,
+	pub crKey: COLORREF,
+	pblend: *mut BLENDFUNCTION,
+	pub dwFlags: co::ULW,
+	prcDirty: *mut RECT,
+
+	_pptDst: PhantomData<&'a mut POINT>,
+	_psize: PhantomData<&'b mut SIZE>,
+	_pptSrc: PhantomData<&'c mut POINT>,
+	_pblend: PhantomData<&'d mut BLENDFUNCTION>,
+	_prcDirty: PhantomData<&'e mut RECT>,
+}
+
+impl_default_with_size!(UPDATELAYEREDWINDOWINFO, cbSize, 'a, 'b, 'c, 'd, 'e);
+
+impl<'a, 'b, 'c, 'd, 'e> UPDATELAYEREDWINDOWINFO<'a, 'b, 'c, 'd, 'e> {
+	pub_fn_ptr_get_set!('a, pptDst, set_pptDst, POINT);
+	pub_fn_ptr_get_set!('b, psize, set_psize, SIZE);
+	pub_fn_ptr_get_set!('c, pptSrc, set_pptSrc, POINT);
+	pub_fn_ptr_get_set!('d, pblend, set_pblend, BLENDFUNCTION);
+	pub_fn_ptr_get_set!('e, prcDirty, set_prcDirty, RECT);
+}
+
 /// [`WINDOWINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-windowinfo)
 /// struct.
 #[repr(C)]
@@ -948,7 +1199,6 @@ pub struct WINDOWPLACEMENT {
 	pub ptMinPosition: POINT,
 	pub ptMaxPosition: POINT,
 	pub rcNormalPosition: RECT,
-	pub rcDevice: RECT,
 }
 
 impl_default_with_size!(WINDOWPLACEMENT, length);
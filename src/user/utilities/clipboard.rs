@@ -0,0 +1,278 @@
+use crate::co;
+use crate::kernel::decl::{GetLastError, HGLOBAL, SetLastError, SysResult};
+use crate::prelude::{Handle, kernel_Hglobal, user_Hwnd};
+use crate::user;
+use crate::user::decl::{
+	EmptyClipboard, GetClipboardData, HBITMAP, HWND,
+	IsClipboardFormatAvailable, SetClipboardData,
+};
+use crate::user::guard::CloseClipboardGuard;
+
+/// Size of the
+/// [`DROPFILES`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/ns-shlobj_core-dropfiles)
+/// struct, which precedes the file list in `CF_HDROP` data.
+const DROPFILES_SIZE: usize = 20;
+
+/// Gives typed access to the clipboard, which is kept open while the object
+/// is alive.
+///
+/// The clipboard is opened with
+/// [`HWND::OpenClipboard`](crate::prelude::user_Hwnd::OpenClipboard), and
+/// closed when the object goes out of scope.
+///
+/// # Examples
+///
+/// Writing text and a file list at once:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{Clipboard, HWND};
+///
+/// let hwnd: HWND; // initialized somewhere
+/// # let hwnd = HWND::NULL;
+///
+/// let clip = Clipboard::open(&hwnd)?;
+/// clip.empty()?;
+/// clip.set_text("C:\\Temp\\foo.txt")?;
+/// clip.set_files(&["C:\\Temp\\foo.txt"])?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+///
+/// Reading text:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{Clipboard, HWND};
+///
+/// let clip = Clipboard::open(&HWND::NULL)?;
+/// if let Some(text) = clip.text()? {
+///     println!("{}", text);
+/// }
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub struct Clipboard<'a> {
+	_guard: CloseClipboardGuard<'a>,
+}
+
+impl<'a> Clipboard<'a> {
+	/// Opens the clipboard, associated to the given window. If
+	/// [`HWND::NULL`](crate::prelude::Handle::NULL), the clipboard is
+	/// associated to the current task.
+	///
+	/// Note that the data can only be written if the clipboard is emptied
+	/// first with [`empty`](crate::Clipboard::empty), which also makes the
+	/// window the clipboard owner.
+	#[must_use]
+	pub fn open(hwnd: &'a HWND) -> SysResult<Self> {
+		Ok(Self { _guard: hwnd.OpenClipboard()? })
+	}
+
+	/// Empties the clipboard with
+	/// [`EmptyClipboard`](crate::EmptyClipboard), taking its ownership.
+	pub fn empty(&self) -> SysResult<()> {
+		EmptyClipboard()
+	}
+
+	/// Returns an iterator over the formats currently available in the
+	/// clipboard, by calling
+	/// [`EnumClipboardFormats`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumclipboardformats)
+	/// consecutively.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{Clipboard, HWND};
+	///
+	/// let clip = Clipboard::open(&HWND::NULL)?;
+	/// for format in clip.formats() {
+	///     let format = format?;
+	///     println!("Format: {}", format);
+	/// }
+	/// # Ok::<_, winsafe::co::ERROR>(())
+	/// ```
+	#[must_use]
+	pub fn formats(&self) -> impl Iterator<Item = SysResult<co::CF>> + '_ {
+		let mut current = co::CF::default();
+		let mut done = false;
+		std::iter::from_fn(move || {
+			if done {
+				return None;
+			}
+			SetLastError(co::ERROR::SUCCESS);
+			match unsafe { user::ffi::EnumClipboardFormats(current.0) } {
+				0 => {
+					done = true;
+					match GetLastError() {
+						co::ERROR::SUCCESS => None, // no more formats
+						err => Some(Err(err)),
+					}
+				},
+				format => {
+					current = co::CF(format);
+					Some(Ok(current))
+				},
+			}
+		})
+	}
+
+	/// Tells whether the given format is available, with
+	/// [`IsClipboardFormatAvailable`](crate::IsClipboardFormatAvailable).
+	#[must_use]
+	pub fn has(&self, format: co::CF) -> bool {
+		IsClipboardFormatAvailable(format)
+	}
+
+	/// Retrieves a copy of the raw data of the given format, which must be
+	/// stored in an `HGLOBAL`, like custom formats registered with
+	/// [`RegisterClipboardFormat`](crate::RegisterClipboardFormat).
+	///
+	/// Returns `None` if the format is not available.
+	#[must_use]
+	pub fn data(&self, format: co::CF) -> SysResult<Option<Vec<u8>>> {
+		if !self.has(format) {
+			return Ok(None);
+		}
+		let hglobal = unsafe { HGLOBAL::from_ptr(GetClipboardData(format)? as _) }; // owned by the clipboard
+		let (block, _unlock_guard) = hglobal.GlobalLock()?;
+		Ok(Some(block.to_vec()))
+	}
+
+	/// Places a copy of the raw data in the clipboard, under the given format.
+	///
+	/// The clipboard must have been emptied with
+	/// [`empty`](crate::Clipboard::empty).
+	pub fn set_data(&self, format: co::CF, data: &[u8]) -> SysResult<()> {
+		let mut hglobal = HGLOBAL::GlobalAlloc(co::GMEM::MOVEABLE, data.len())?;
+		{
+			let (block, _unlock_guard) = hglobal.GlobalLock()?;
+			block[..data.len()].copy_from_slice(data);
+		}
+		unsafe { SetClipboardData(format, hglobal.as_ptr() as _)?; }
+		let _ = hglobal.leak(); // now owned by the clipboard
+		Ok(())
+	}
+
+	/// Retrieves the text in `CF_UNICODETEXT` format, or `None` if no text is
+	/// available.
+	#[must_use]
+	pub fn text(&self) -> SysResult<Option<String>> {
+		Ok(self.data(co::CF::UNICODETEXT)?
+			.map(|data| {
+				let chars = data.chunks_exact(2)
+					.map(|ch| u16::from_le_bytes([ch[0], ch[1]]))
+					.take_while(|ch| *ch != 0)
+					.collect::<Vec<_>>();
+				String::from_utf16_lossy(&chars)
+			}))
+	}
+
+	/// Places the text in `CF_UNICODETEXT` format.
+	///
+	/// The clipboard must have been emptied with
+	/// [`empty`](crate::Clipboard::empty).
+	pub fn set_text(&self, text: &str) -> SysResult<()> {
+		let data = text.encode_utf16()
+			.chain(std::iter::once(0))
+			.flat_map(|ch| ch.to_le_bytes())
+			.collect::<Vec<_>>();
+		self.set_data(co::CF::UNICODETEXT, &data)
+	}
+
+	/// Retrieves the file paths in `CF_HDROP` format, usually placed by
+	/// Windows Explorer upon a copy or cut operation. Returns `None` if no
+	/// files are available.
+	#[must_use]
+	pub fn files(&self) -> SysResult<Option<Vec<String>>> {
+		let data = match self.data(co::CF::HDROP)? {
+			Some(data) => data,
+			None => return Ok(None),
+		};
+		if data.len() < DROPFILES_SIZE {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let files_off = u32::from_le_bytes([data[0], data[1], data[2], data[3]]) as usize;
+		let is_wide = u32::from_le_bytes([data[16], data[17], data[18], data[19]]) != 0;
+		let list = data.get(files_off..).ok_or(co::ERROR::INVALID_DATA)?;
+
+		let files = if is_wide {
+			list.chunks_exact(2)
+				.map(|ch| u16::from_le_bytes([ch[0], ch[1]]))
+				.collect::<Vec<_>>()
+				.split(|ch| *ch == 0)
+				.take_while(|path| !path.is_empty()) // list is double null-terminated
+				.map(String::from_utf16_lossy)
+				.collect()
+		} else {
+			list.split(|ch| *ch == 0)
+				.take_while(|path| !path.is_empty())
+				.map(|path| String::from_utf8_lossy(path).into_owned())
+				.collect()
+		};
+		Ok(Some(files))
+	}
+
+	/// Places the file paths in `CF_HDROP` format, which can be pasted in
+	/// Windows Explorer.
+	///
+	/// The clipboard must have been emptied with
+	/// [`empty`](crate::Clipboard::empty).
+	pub fn set_files(&self, paths: &[impl AsRef<str>]) -> SysResult<()> {
+		let mut data = Vec::default();
+		data.extend_from_slice(&(DROPFILES_SIZE as u32).to_le_bytes()); // pFiles
+		data.extend_from_slice(&[0; 12]); // pt, fNC
+		data.extend_from_slice(&1u32.to_le_bytes()); // fWide
+		for path in paths.iter() {
+			path.as_ref().encode_utf16()
+				.chain(std::iter::once(0)) // each path is null-terminated
+				.for_each(|ch| data.extend_from_slice(&ch.to_le_bytes()));
+		}
+		data.extend_from_slice(&[0, 0]); // list is double null-terminated
+		self.set_data(co::CF::HDROP, &data)
+	}
+
+	/// Retrieves the bitmap in `CF_BITMAP` format, or `None` if no bitmap is
+	/// available.
+	///
+	/// The returned handle is owned by the clipboard, and it's valid only
+	/// while the clipboard is open. To keep the bitmap, make a copy of it.
+	#[must_use]
+	pub fn bitmap(&self) -> SysResult<Option<HBITMAP>> {
+		if !self.has(co::CF::BITMAP) {
+			return Ok(None);
+		}
+		Ok(Some(unsafe { HBITMAP::from_ptr(GetClipboardData(co::CF::BITMAP)? as _) }))
+	}
+
+	/// Places the bitmap in `CF_BITMAP` format. The clipboard takes ownership
+	/// of the bitmap, which must not be used nor deleted afterwards.
+	///
+	/// The clipboard must have been emptied with
+	/// [`empty`](crate::Clipboard::empty).
+	pub fn set_bitmap(&self, hbmp: HBITMAP) -> SysResult<()> {
+		unsafe { SetClipboardData(co::CF::BITMAP, hbmp.as_ptr() as _)?; }
+		Ok(())
+	}
+
+	/// Retrieves the device-independent bitmap in `CF_DIB` format – a
+	/// [`BITMAPINFO`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-bitmapinfo)
+	/// followed by the bitmap bits –, or `None` if not available. If the
+	/// clipboard holds a `CF_BITMAP`, the system converts it automatically.
+	#[must_use]
+	pub fn dib(&self) -> SysResult<Option<Vec<u8>>> {
+		self.data(co::CF::DIB)
+	}
+
+	/// Places the device-independent bitmap in `CF_DIB` format, which must
+	/// contain a
+	/// [`BITMAPINFO`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-bitmapinfo)
+	/// followed by the bitmap bits – the contents of a `.bmp` file, without
+	/// the `BITMAPFILEHEADER`.
+	///
+	/// The clipboard must have been emptied with
+	/// [`empty`](crate::Clipboard::empty).
+	pub fn set_dib(&self, dib: &[u8]) -> SysResult<()> {
+		self.set_data(co::CF::DIB, dib)
+	}
+}
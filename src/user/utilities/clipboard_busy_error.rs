@@ -0,0 +1,57 @@
+use crate::co;
+
+/// An error returned by
+/// [`HWND::with_clipboard`](crate::prelude::user_Hwnd::with_clipboard) when
+/// the clipboard could not be opened within the time-out, because another
+/// window kept it open.
+pub struct ClipboardBusyError {
+	error: co::ERROR,
+	process_id: Option<u32>,
+}
+
+impl std::error::Error for ClipboardBusyError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		Some(&self.error)
+	}
+}
+
+impl std::fmt::Debug for ClipboardBusyError {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self.process_id {
+			Some(process_id) => write!(f, "Clipboard open by process {} - {}",
+				process_id, self.error),
+			None => write!(f, "Clipboard busy - {}", self.error),
+		}
+	}
+}
+
+impl std::fmt::Display for ClipboardBusyError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		<Self as std::fmt::Debug>::fmt(self, f) // delegate to Debug trait
+	}
+}
+
+impl ClipboardBusyError {
+	#[must_use]
+	pub(in crate::user) const fn new(
+		error: co::ERROR, process_id: Option<u32>) -> Self
+	{
+		Self { error, process_id }
+	}
+
+	/// The error returned by the last
+	/// [`HWND::OpenClipboard`](crate::prelude::user_Hwnd::OpenClipboard) call.
+	#[must_use]
+	pub const fn error(&self) -> co::ERROR {
+		self.error
+	}
+
+	/// The ID of the process which had the clipboard open when the time-out
+	/// elapsed, retrieved with
+	/// [`HWND::GetOpenClipboardWindow`](crate::prelude::user_Hwnd::GetOpenClipboardWindow).
+	/// Is `None` if the clipboard was not open by a window.
+	#[must_use]
+	pub const fn process_id(&self) -> Option<u32> {
+		self.process_id
+	}
+}
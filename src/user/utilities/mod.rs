@@ -0,0 +1,9 @@
+mod clipboard;
+mod clipboard_busy_error;
+mod persistent_env;
+mod ui_watchdog;
+
+pub use clipboard::Clipboard;
+pub use clipboard_busy_error::ClipboardBusyError;
+pub use persistent_env::{EnvScope, PersistentEnv};
+pub use ui_watchdog::UiWatchdog;
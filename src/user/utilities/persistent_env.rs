@@ -0,0 +1,127 @@
+use crate::co;
+use crate::kernel::decl::{HKEY, RegistryValue, SysResult, WString};
+use crate::kernel::guard::RegCloseKeyGuard;
+use crate::msg::wm;
+use crate::prelude::{kernel_Hkey, user_Hwnd};
+use crate::user::decl::HWND;
+
+/// Scope of the environment variables managed by
+/// [`PersistentEnv`](crate::PersistentEnv).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EnvScope {
+	/// Variables of the current user, stored in `HKEY_CURRENT_USER\Environment`.
+	User,
+	/// Variables of all users, stored in
+	/// `HKEY_LOCAL_MACHINE\SYSTEM\CurrentControlSet\Control\Session Manager\Environment`.
+	/// Writing requires administrative privileges.
+	Machine,
+}
+
+/// Manages the persistent environment variables, which are stored in the
+/// registry and inherited by new processes.
+///
+/// Unlike
+/// [`std::env::set_var`](https://doc.rust-lang.org/std/env/fn.set_var.html),
+/// which affects only the current process, the changes made here survive a
+/// reboot. After
+/// changing the variables, call
+/// [`broadcast_change`](crate::PersistentEnv::broadcast_change), so that
+/// Windows Explorer and other applications reload them.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{EnvScope, PersistentEnv};
+///
+/// let env = PersistentEnv::open(EnvScope::User)?;
+///
+/// let path = env.get("Path")?.unwrap_or_default();
+/// env.set("Path", &format!("{};C:\\Tools", path))?;
+/// env.set("MY_TOOL_HOME", "%USERPROFILE%\\my_tool")?; // saved as REG_EXPAND_SZ
+///
+/// PersistentEnv::broadcast_change(5000)?;
+/// # Ok::<_, winsafe::co::ERROR>(())
+/// ```
+pub struct PersistentEnv {
+	hkey: RegCloseKeyGuard,
+}
+
+impl PersistentEnv {
+	/// Opens the registry key of the environment variables of the given scope.
+	#[must_use]
+	pub fn open(scope: EnvScope) -> SysResult<Self> {
+		let (hkey_root, sub_key) = match scope {
+			EnvScope::User => (HKEY::CURRENT_USER, "Environment"),
+			EnvScope::Machine => (
+				HKEY::LOCAL_MACHINE,
+				"SYSTEM\\CurrentControlSet\\Control\\Session Manager\\Environment",
+			),
+		};
+
+		let hkey = hkey_root.RegOpenKeyEx(
+			Some(sub_key),
+			co::REG_OPTION::default(),
+			co::KEY::READ | co::KEY::WRITE,
+		)?;
+		Ok(Self { hkey })
+	}
+
+	/// Returns the value of the variable, or `None` if it doesn't exist.
+	///
+	/// References to other variables, like `%USERPROFILE%`, are not expanded;
+	/// use [`ExpandEnvironmentStrings`](crate::ExpandEnvironmentStrings) for that.
+	#[must_use]
+	pub fn get(&self, name: &str) -> SysResult<Option<String>> {
+		match self.hkey.RegQueryValueEx(Some(name)) {
+			Ok(RegistryValue::Sz(s))
+				| Ok(RegistryValue::ExpandSz(s)) => Ok(Some(s)),
+			Ok(_) => Err(co::ERROR::INVALID_DATATYPE), // not a string value
+			Err(co::ERROR::FILE_NOT_FOUND) => Ok(None),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Creates or replaces the variable.
+	///
+	/// If the value references other variables, like `%USERPROFILE%`, it's
+	/// saved as `REG_EXPAND_SZ`, otherwise as `REG_SZ`.
+	pub fn set(&self, name: &str, value: &str) -> SysResult<()> {
+		let has_refs = value.matches('%').count() >= 2;
+		self.hkey.RegSetValueEx(
+			Some(name),
+			if has_refs {
+				RegistryValue::ExpandSz(value.to_owned())
+			} else {
+				RegistryValue::Sz(value.to_owned())
+			},
+		)
+	}
+
+	/// Deletes the variable. Does nothing if it doesn't exist.
+	pub fn remove(&self, name: &str) -> SysResult<()> {
+		match self.hkey.RegDeleteValue(Some(name)) {
+			Err(co::ERROR::FILE_NOT_FOUND) => Ok(()),
+			res => res,
+		}
+	}
+
+	/// Broadcasts the
+	/// [`WM_SETTINGCHANGE`](crate::msg::wm::SettingChange) message with the
+	/// `"Environment"` section to all top-level windows, with
+	/// [`SendMessageTimeout`](crate::prelude::user_Hwnd::SendMessageTimeout),
+	/// so the running applications reload the environment variables.
+	///
+	/// The `timeout_ms` is applied to each window; hung windows are skipped.
+	pub fn broadcast_change(timeout_ms: u32) -> SysResult<()> {
+		let section = WString::from_str("Environment");
+		HWND::BROADCAST.SendMessageTimeout(
+			wm::SettingChange {
+				parameter: co::SPI::default(),
+				section: section.as_ptr(),
+			},
+			co::SMTO::ABORTIFHUNG,
+			timeout_ms,
+		)
+	}
+}
@@ -0,0 +1,124 @@
+use std::cell::Cell;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::co;
+use crate::msg::wm;
+use crate::prelude::user_Hwnd;
+use crate::user::decl::{EnumThreadWindows, HWND};
+
+/// Monitors the UI thread from a separate thread, calling a closure when the
+/// UI thread stops pumping messages for a given amount of time – which would
+/// make Windows show the window as "Not Responding".
+///
+/// Periodically, the monitor thread retrieves a top-level window of the UI
+/// thread with [`EnumThreadWindows`](crate::EnumThreadWindows), and sends it
+/// a [`wm::Null`](crate::msg::wm::Null) message with
+/// [`HWND::SendMessageTimeout`](crate::prelude::user_Hwnd::SendMessageTimeout).
+/// If the message is not processed within the timeout, the closure is called,
+/// in the monitor thread, receiving the time elapsed since the UI thread was
+/// last seen responsive. The closure is called once per freeze; it will be
+/// called again only after the UI thread recovers and freezes again.
+///
+/// **Note:** while the UI thread has no top-level window, it cannot be
+/// monitored. Message-only windows are not enumerated by `EnumThreadWindows`,
+/// so a thread which owns only message-only windows is never monitored.
+///
+/// The monitoring stops when the object is dropped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// use winsafe::{GetCurrentThreadId, UiWatchdog};
+///
+/// let watchdog = UiWatchdog::start(GetCurrentThreadId(), 5, |elapsed| {
+///     eprintln!("UI thread frozen for {} ms.", elapsed.as_millis());
+/// });
+///
+/// // run the main loop...
+/// ```
+pub struct UiWatchdog {
+	stop: Arc<AtomicBool>,
+	thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for UiWatchdog {
+	fn drop(&mut self) {
+		self.stop.store(true, Ordering::Relaxed);
+		if let Some(thread) = self.thread.take() {
+			thread.join().ok(); // ignore errors
+		}
+	}
+}
+
+impl UiWatchdog {
+	const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+	/// Starts the monitor thread, which watches the thread with the given ID,
+	/// usually retrieved with
+	/// [`GetCurrentThreadId`](crate::GetCurrentThreadId) from within the UI
+	/// thread.
+	///
+	/// Note that dropping the object may block until the current probe
+	/// finishes, that is, up to `timeout_secs` seconds.
+	#[must_use]
+	pub fn start<F>(ui_thread_id: u32, timeout_secs: u32, on_freeze: F) -> Self
+		where F: Fn(Duration) + Send + 'static,
+	{
+		let stop = Arc::new(AtomicBool::new(false));
+		let stop2 = stop.clone();
+
+		let thread = std::thread::spawn(move || {
+			let mut last_responsive = Instant::now();
+			let mut frozen = false;
+
+			while !stop2.load(Ordering::Relaxed) {
+				let hwnd = match Self::find_window(ui_thread_id) {
+					Some(hwnd) => hwnd,
+					None => {
+						last_responsive = Instant::now(); // nothing to probe
+						frozen = false;
+						std::thread::sleep(Self::POLL_INTERVAL);
+						continue;
+					},
+				};
+
+				let timeout_ms = if frozen {
+					Self::POLL_INTERVAL.as_millis() as u32 // already reported, just wait for recovery
+				} else {
+					timeout_secs.saturating_mul(1000)
+				};
+
+				match hwnd.SendMessageTimeout(
+					wm::Null {}, co::SMTO::NORMAL, timeout_ms)
+				{
+					Ok(_) => {
+						last_responsive = Instant::now();
+						frozen = false;
+						std::thread::sleep(Self::POLL_INTERVAL);
+					},
+					Err(co::ERROR::TIMEOUT) => if !frozen {
+						frozen = true;
+						on_freeze(last_responsive.elapsed());
+					},
+					Err(_) => std::thread::sleep(Self::POLL_INTERVAL), // window destroyed meanwhile, try another
+				}
+			}
+		});
+
+		Self { stop, thread: Some(thread) }
+	}
+
+	/// Returns the first top-level window of the given thread, if any.
+	fn find_window(thread_id: u32) -> Option<HWND> {
+		let found = Cell::new(None);
+		EnumThreadWindows(thread_id, |hwnd: HWND| -> bool {
+			found.set(Some(hwnd));
+			false // stop at the first one
+		}).ok(); // returns an error when stopped, or when there are no windows
+		found.into_inner()
+	}
+}
@@ -1,6 +1,57 @@
-const_ordinary! { STAP: u32;
+const_bitflag! { BDR: u32;
+	/// [`HTHEME::DrawThemeEdge`](crate::prelude::uxtheme_Htheme::DrawThemeEdge)
+	/// `edge` (`u32`).
+	=>
+	=>
+	RAISEDOUTER 0x0001
+	SUNKENOUTER 0x0002
+	RAISEDINNER 0x0004
+	SUNKENINNER 0x0008
+	OUTER Self::RAISEDOUTER.0 | Self::SUNKENOUTER.0
+	INNER Self::RAISEDINNER.0 | Self::SUNKENINNER.0
+	RAISED Self::RAISEDOUTER.0 | Self::RAISEDINNER.0
+	SUNKEN Self::SUNKENOUTER.0 | Self::SUNKENINNER.0
+	/// `EDGE_RAISED`.
+	EDGE_RAISED Self::RAISEDOUTER.0 | Self::RAISEDINNER.0
+	/// `EDGE_SUNKEN`.
+	EDGE_SUNKEN Self::SUNKENOUTER.0 | Self::SUNKENINNER.0
+	/// `EDGE_ETCHED`.
+	EDGE_ETCHED Self::SUNKENOUTER.0 | Self::RAISEDINNER.0
+	/// `EDGE_BUMP`.
+	EDGE_BUMP Self::RAISEDOUTER.0 | Self::SUNKENINNER.0
+}
+
+const_bitflag! { BF: u32;
+	/// [`HTHEME::DrawThemeEdge`](crate::prelude::uxtheme_Htheme::DrawThemeEdge)
+	/// `flags` (`u32`).
+	=>
+	=>
+	LEFT 0x0001
+	TOP 0x0002
+	RIGHT 0x0004
+	BOTTOM 0x0008
+	TOPLEFT Self::TOP.0 | Self::LEFT.0
+	TOPRIGHT Self::TOP.0 | Self::RIGHT.0
+	BOTTOMLEFT Self::BOTTOM.0 | Self::LEFT.0
+	BOTTOMRIGHT Self::BOTTOM.0 | Self::RIGHT.0
+	RECT Self::LEFT.0 | Self::TOP.0 | Self::RIGHT.0 | Self::BOTTOM.0
+	DIAGONAL 0x0010
+	DIAGONAL_ENDTOPRIGHT Self::DIAGONAL.0 | Self::TOP.0 | Self::RIGHT.0
+	DIAGONAL_ENDTOPLEFT Self::DIAGONAL.0 | Self::TOP.0 | Self::LEFT.0
+	DIAGONAL_ENDBOTTOMLEFT Self::DIAGONAL.0 | Self::BOTTOM.0 | Self::LEFT.0
+	DIAGONAL_ENDBOTTOMRIGHT Self::DIAGONAL.0 | Self::BOTTOM.0 | Self::RIGHT.0
+	MIDDLE 0x0800
+	SOFT 0x1000
+	ADJUST 0x2000
+	FLAT 0x4000
+	MONO 0x8000
+}
+
+const_bitflag! { STAP: u32;
 	/// [`HTHEME::GetThemeAppProperties`](crate::prelude::uxtheme_Htheme::GetThemeAppProperties)
-	/// return value (`u32`).
+	/// and
+	/// [`HTHEME::SetThemeAppProperties`](crate::prelude::uxtheme_Htheme::SetThemeAppProperties)
+	/// `flags` (`u32`).
 	=>
 	=>
 	ALLOW_NONCLIENT 1 << 0
@@ -9,6 +60,16 @@ const_ordinary! { STAP: u32;
 	VALIDBITS Self::ALLOW_NONCLIENT.0 | Self::ALLOW_CONTROLS.0 | Self::ALLOW_WEBCONTENT.0
 }
 
+const_ordinary! { THEMESIZE: u32;
+	/// [`THEMESIZE`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/ne-uxtheme-themesize)
+	/// enumeration (`u32`).
+	=>
+	=>
+	MIN 0
+	TRUE 1
+	DRAW 2
+}
+
 const_ordinary! { TMT: i32;
 	/// Theme property
 	/// [identifiers](https://learn.microsoft.com/en-us/windows/win32/controls/property-typedefs)
@@ -271,3 +332,23 @@ const_ordinary! { TMT: i32;
 	ATLASINPUTIMAGE 8001
 	ATLASRECT 8002
 }
+
+const_ordinary! { WTA: u32;
+	/// [`WINDOWTHEMEATTRIBUTETYPE`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/ne-uxtheme-windowthemeattributetype)
+	/// enumeration (`u32`).
+	=>
+	=>
+	NONCLIENT 1
+}
+
+const_bitflag! { WTNCA: u32;
+	/// [`WTA_OPTIONS`](crate::WTA_OPTIONS) flags for
+	/// [`WTA::NONCLIENT`](crate::co::WTA::NONCLIENT) (`u32`).
+	=>
+	=>
+	NODRAWCAPTION 0x0000_0001
+	NODRAWICON 0x0000_0002
+	NOSYSMENU 0x0000_0004
+	NOMIRRORHELP 0x0000_0008
+	VALIDBITS Self::NODRAWCAPTION.0 | Self::NODRAWICON.0 | Self::NOSYSMENU.0 | Self::NOMIRRORHELP.0
+}
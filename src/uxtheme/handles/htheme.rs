@@ -5,7 +5,9 @@ use crate::gdi::guard::DeleteObjectGuard;
 use crate::ole::decl::HrResult;
 use crate::ole::privs::ok_to_hrresult;
 use crate::prelude::Handle;
-use crate::user::decl::{COLORREF, HDC, HRGN, RECT};
+use crate::gdi::decl::LOGFONT;
+use crate::user::decl::{COLORREF, HDC, HRGN, RECT, SIZE};
+use crate::uxtheme::decl::MARGINS;
 
 impl_handle! { HTHEME;
 	/// Handle to a
@@ -42,8 +44,56 @@ pub trait uxtheme_Htheme: Handle {
 		)
 	}
 
+	/// [`DrawThemeEdge`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-drawthemeedge)
+	/// method.
+	///
+	/// Returns the content rectangle, which is only meaningful if
+	/// [`co::BF::ADJUST`](crate::co::BF::ADJUST) is passed.
+	fn DrawThemeEdge(&self,
+		hdc: &HDC,
+		part_state: co::VS,
+		dest_rect: RECT,
+		edge: co::BDR,
+		flags: co::BF,
+	) -> HrResult<RECT>
+	{
+		let mut rc_content = RECT::default();
+		ok_to_hrresult(
+			unsafe {
+				uxtheme::ffi::DrawThemeEdge(
+					self.as_ptr(),
+					hdc.as_ptr(),
+					part_state.part,
+					part_state.state,
+					&dest_rect as *const _ as _,
+					edge.0,
+					flags.0,
+					&mut rc_content as *mut _ as _,
+				)
+			},
+		).map(|_| rc_content)
+	}
+
 	/// [`GetThemeAppProperties`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-getthemeappproperties)
 	/// static method.
+	///
+	/// # Examples
+	///
+	/// Checking whether visual styles are applied to the controls, before
+	/// choosing how to paint a custom control:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HTHEME, IsAppThemed, IsThemeActive};
+	///
+	/// let themed = IsThemeActive()
+	///     && IsAppThemed()
+	///     && HTHEME::GetThemeAppProperties().has(co::STAP::ALLOW_CONTROLS);
+	///
+	/// if !themed {
+	///     // classic or high contrast rendering
+	/// }
+	/// ```
 	#[must_use]
 	fn GetThemeAppProperties() -> co::STAP {
 		co::STAP(unsafe { uxtheme::ffi::GetThemeAppProperties() })
@@ -137,6 +187,121 @@ pub trait uxtheme_Htheme: Handle {
 		).map(|_| color)
 	}
 
+	/// [`GetThemeFont`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-getthemefont)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Creating the font used in the caption of a themed window:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HFONT, HTHEME};
+	///
+	/// let htheme: HTHEME; // initialized somewhere
+	/// # let htheme = HTHEME::NULL;
+	///
+	/// let lf = htheme.GetThemeFont(
+	///     None,
+	///     co::VS::WINDOW_CAPTION_ACTIVE,
+	///     co::TMT::CAPTIONFONT,
+	/// )?;
+	/// let hfont = HFONT::CreateFontIndirect(&lf)
+	///     .map_err(|e| e.to_hresult())?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn GetThemeFont(&self,
+		hdc: Option<&HDC>, part_state: co::VS, prop: co::TMT) -> HrResult<LOGFONT>
+	{
+		let mut lf = LOGFONT::default();
+		ok_to_hrresult(
+			unsafe {
+				uxtheme::ffi::GetThemeFont(
+					self.as_ptr(),
+					hdc.map_or(std::ptr::null_mut(), |h| h.as_ptr()),
+					part_state.part,
+					part_state.state,
+					prop.0,
+					&mut lf as *mut _ as _,
+				)
+			},
+		).map(|_| lf)
+	}
+
+	/// [`GetThemeMargins`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-getthememargins)
+	/// method.
+	#[must_use]
+	fn GetThemeMargins(&self,
+		hdc: Option<&HDC>,
+		part_state: co::VS,
+		prop: co::TMT,
+		rc: Option<RECT>,
+	) -> HrResult<MARGINS>
+	{
+		let mut margins = MARGINS::default();
+		ok_to_hrresult(
+			unsafe {
+				uxtheme::ffi::GetThemeMargins(
+					self.as_ptr(),
+					hdc.map_or(std::ptr::null_mut(), |h| h.as_ptr()),
+					part_state.part,
+					part_state.state,
+					prop.0,
+					rc.as_ref().map_or(std::ptr::null(), |rc| rc as *const _ as _),
+					&mut margins as *mut _ as _,
+				)
+			},
+		).map(|_| margins)
+	}
+
+	/// [`GetThemeMetric`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-getthememetric)
+	/// method.
+	#[must_use]
+	fn GetThemeMetric(&self,
+		hdc: Option<&HDC>, part_state: co::VS, prop: co::TMT) -> HrResult<i32>
+	{
+		let mut val = i32::default();
+		ok_to_hrresult(
+			unsafe {
+				uxtheme::ffi::GetThemeMetric(
+					self.as_ptr(),
+					hdc.map_or(std::ptr::null_mut(), |h| h.as_ptr()),
+					part_state.part,
+					part_state.state,
+					prop.0,
+					&mut val,
+				)
+			},
+		).map(|_| val)
+	}
+
+	/// [`GetThemePartSize`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-getthemepartsize)
+	/// method.
+	#[must_use]
+	fn GetThemePartSize(&self,
+		hdc: Option<&HDC>,
+		part_state: co::VS,
+		rc: Option<RECT>,
+		esize: co::THEMESIZE,
+	) -> HrResult<SIZE>
+	{
+		let mut sz = SIZE::default();
+		ok_to_hrresult(
+			unsafe {
+				uxtheme::ffi::GetThemePartSize(
+					self.as_ptr(),
+					hdc.map_or(std::ptr::null_mut(), |h| h.as_ptr()),
+					part_state.part,
+					part_state.state,
+					rc.as_ref().map_or(std::ptr::null(), |rc| rc as *const _ as _),
+					esize.0,
+					&mut sz as *mut _ as _,
+				)
+			},
+		).map(|_| sz)
+	}
+
 	/// [`IsThemeBackgroundPartiallyTransparent`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-isthemebackgroundpartiallytransparent)
 	/// method.
 	#[must_use]
@@ -158,4 +323,12 @@ pub trait uxtheme_Htheme: Handle {
 				self.as_ptr(), part_state.part, part_state.state) != 0
 		}
 	}
+
+	/// [`SetThemeAppProperties`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-setthemeappproperties)
+	/// static method.
+	///
+	/// Only windows created after this call are affected.
+	fn SetThemeAppProperties(flags: co::STAP) {
+		unsafe { uxtheme::ffi::SetThemeAppProperties(flags.0) }
+	}
 }
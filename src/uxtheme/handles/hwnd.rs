@@ -1,10 +1,13 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use crate::co;
 use crate::kernel::decl::WString;
+use crate::ole::decl::HrResult;
+use crate::ole::privs::ok_to_hrresult;
 use crate::prelude::Handle;
 use crate::user::decl::HWND;
 use crate::uxtheme;
-use crate::uxtheme::decl::HTHEME;
+use crate::uxtheme::decl::{HTHEME, WTA_OPTIONS};
 use crate::uxtheme::guard::CloseThemeDataGuard;
 
 impl uxtheme_Hwnd for HWND {}
@@ -30,4 +33,73 @@ pub trait uxtheme_Hwnd: Handle {
 				.map(|ptr| CloseThemeDataGuard::new(HTHEME::from_ptr(ptr)))
 		}
 	}
+
+	/// [`SetWindowTheme`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-setwindowtheme)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Applying the dark theme to a native control:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::HWND;
+	///
+	/// let hctrl: HWND; // initialized somewhere
+	/// # let hctrl = HWND::NULL;
+	///
+	/// hctrl.SetWindowTheme(Some("DarkMode_Explorer"), None)?;
+	/// # Ok::<_, winsafe::co::HRESULT>(())
+	/// ```
+	fn SetWindowTheme(&self,
+		sub_app_name: Option<&str>, sub_id_list: Option<&str>) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				uxtheme::ffi::SetWindowTheme(
+					self.as_ptr(),
+					WString::from_opt_str(sub_app_name).as_ptr(),
+					WString::from_opt_str(sub_id_list).as_ptr(),
+				)
+			},
+		)
+	}
+
+	/// [`SetWindowThemeAttribute`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-setwindowthemeattribute)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Hiding the caption text and the icon of a window, while keeping the
+	/// caption buttons:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, HWND, WTA_OPTIONS};
+	///
+	/// let hwnd: HWND; // initialized somewhere
+	/// # let hwnd = HWND::NULL;
+	///
+	/// let hide = co::WTNCA::NODRAWCAPTION | co::WTNCA::NODRAWICON;
+	///
+	/// hwnd.SetWindowThemeAttribute(
+	///     co::WTA::NONCLIENT,
+	///     &WTA_OPTIONS { dwFlags: hide, dwMask: hide },
+	/// )?;
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	fn SetWindowThemeAttribute(&self,
+		attr: co::WTA, options: &WTA_OPTIONS) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				uxtheme::ffi::SetWindowThemeAttribute(
+					self.as_ptr(),
+					attr.0,
+					options as *const _ as _,
+					std::mem::size_of::<WTA_OPTIONS>() as _,
+				)
+			},
+		)
+	}
 }
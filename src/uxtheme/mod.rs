@@ -6,10 +6,12 @@ pub mod guard;
 
 mod funcs;
 mod handles;
+mod structs;
 
 pub mod decl {
 	pub use super::funcs::*;
 	pub use super::handles::decl::*;
+	pub use super::structs::*;
 }
 
 pub mod traits {
@@ -0,0 +1,23 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+
+/// [`MARGINS`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/ns-uxtheme-margins)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct MARGINS {
+	pub cxLeftWidth: i32,
+	pub cxRightWidth: i32,
+	pub cyTopHeight: i32,
+	pub cyBottomHeight: i32,
+}
+
+/// [`WTA_OPTIONS`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/ns-uxtheme-wta_options)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Eq, PartialEq)]
+pub struct WTA_OPTIONS {
+	pub dwFlags: co::WTNCA,
+	pub dwMask: co::WTNCA,
+}
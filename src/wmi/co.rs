@@ -0,0 +1,105 @@
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+use crate::co::{CLSID, HRESULT};
+
+const_guid_values! { CLSID;
+	WbemLocator "4590f811-1d3a-11d0-891f-00aa004b2e24"
+}
+
+const_values! { HRESULT;
+	=>
+	WBEM_S_FALSE 1
+	WBEM_S_TIMEDOUT 0x4_0004
+	WBEM_S_NO_MORE_DATA 0x4_0005
+	WBEM_E_FAILED 0x8004_1001
+	WBEM_E_NOT_FOUND 0x8004_1002
+	WBEM_E_ACCESS_DENIED 0x8004_1003
+	WBEM_E_PROVIDER_FAILURE 0x8004_1004
+	WBEM_E_TYPE_MISMATCH 0x8004_1005
+	WBEM_E_OUT_OF_MEMORY 0x8004_1006
+	WBEM_E_INVALID_CONTEXT 0x8004_1007
+	WBEM_E_INVALID_PARAMETER 0x8004_1008
+	WBEM_E_NOT_AVAILABLE 0x8004_1009
+	WBEM_E_CRITICAL_ERROR 0x8004_100a
+	WBEM_E_NOT_SUPPORTED 0x8004_100c
+	WBEM_E_INVALID_NAMESPACE 0x8004_100e
+	WBEM_E_INVALID_OBJECT 0x8004_100f
+	WBEM_E_INVALID_CLASS 0x8004_1010
+	WBEM_E_PROVIDER_NOT_FOUND 0x8004_1011
+	WBEM_E_TRANSPORT_FAILURE 0x8004_1015
+	WBEM_E_INVALID_QUERY 0x8004_1017
+	WBEM_E_INVALID_QUERY_TYPE 0x8004_1018
+	WBEM_E_SHUTTING_DOWN 0x8004_1033
+}
+
+const_ordinary! { CIMTYPE: i32;
+	/// [`CIMTYPE_ENUMERATION`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/ne-wbemcli-cimtype_enumeration)
+	/// enumeration (`i32`).
+	=>
+	=>
+	ILLEGAL 0xfff
+	EMPTY 0
+	SINT8 16
+	UINT8 17
+	SINT16 2
+	UINT16 18
+	SINT32 3
+	UINT32 19
+	SINT64 20
+	UINT64 21
+	REAL32 4
+	REAL64 5
+	BOOLEAN 11
+	STRING 8
+	DATETIME 101
+	REFERENCE 102
+	CHAR16 103
+	OBJECT 13
+	FLAG_ARRAY 0x2000
+}
+
+const_bitflag! { WBEM_CONDITION: i32;
+	/// [`WBEM_CONDITION_FLAG_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/ne-wbemcli-wbem_condition_flag_type)
+	/// enumeration (`i32`).
+	=>
+	=>
+	ALWAYS 0
+	ONLY_IF_TRUE 0x1
+	ONLY_IF_FALSE 0x2
+	ONLY_IF_IDENTICAL 0x3
+	KEYS_ONLY 0x4
+	REFS_ONLY 0x8
+	LOCAL_ONLY 0x10
+	PROPAGATED_ONLY 0x20
+	SYSTEM_ONLY 0x30
+	NONSYSTEM_ONLY 0x40
+}
+
+const_bitflag! { WBEM_FLAG: i32;
+	/// [`WBEM_GENERIC_FLAG_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/ne-wbemcli-wbem_generic_flag_type)
+	/// enumeration (`i32`).
+	=>
+	=>
+	RETURN_WBEM_COMPLETE 0
+	RETURN_IMMEDIATELY 0x10
+	FORWARD_ONLY 0x20
+	NO_ERROR_OBJECT 0x40
+	RETURN_ERROR_OBJECT 0
+	SEND_STATUS 0x80
+	DONT_SEND_STATUS 0
+	ENSURE_LOCATABLE 0x100
+	DIRECT_READ 0x200
+	USE_AMENDED_QUALIFIERS 0x2_0000
+	STRONG_VALIDATION 0x10_0000
+}
+
+const_bitflag! { WBEM_FLAG_CONNECT: i32;
+	/// [`IWbemLocator::ConnectServer`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemlocator-connectserver)
+	/// `lSecurityFlags` (`i32`).
+	=>
+	=>
+	NoValue 0
+	REPOSITORY_ONLY 0x40
+	USE_MAX_WAIT 0x80
+	PROVIDERS 0x100
+}
@@ -0,0 +1,164 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::HRES;
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::{ok_to_hrresult, okfalse_to_hrresult};
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+use crate::wmi::decl::IWbemClassObject;
+
+/// [`IEnumWbemClassObject`](crate::IEnumWbemClassObject) virtual table.
+#[repr(C)]
+pub struct IEnumWbemClassObjectVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Reset: fn(ComPtr) -> HRES,
+	pub Next: fn(ComPtr, i32, u32, *mut ComPtr, *mut u32) -> HRES,
+	pub NextAsync: fn(ComPtr, u32, ComPtr) -> HRES,
+	pub Clone: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub Skip: fn(ComPtr, i32, u32) -> HRES,
+}
+
+com_interface! { IEnumWbemClassObject: "027947e1-d731-11ce-a357-000000000001";
+	/// [`IEnumWbemClassObject`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nn-wbemcli-ienumwbemclassobject)
+	/// COM interface over
+	/// [`IEnumWbemClassObjectVT`](crate::vt::IEnumWbemClassObjectVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl wmi_IEnumWbemClassObject for IEnumWbemClassObject {}
+
+/// This trait is enabled with the `wmi` feature, and provides methods for
+/// [`IEnumWbemClassObject`](crate::IEnumWbemClassObject).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait wmi_IEnumWbemClassObject: ole_IUnknown {
+	/// Returns an iterator over the [`IWbemClassObject`](crate::IWbemClassObject)
+	/// elements which calls
+	/// [`IEnumWbemClassObject::Next`](crate::prelude::wmi_IEnumWbemClassObject::Next)
+	/// internally.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IEnumWbemClassObject};
+	///
+	/// let rows: IEnumWbemClassObject; // initialized somewhere
+	/// # let rows = IEnumWbemClassObject::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// for row in rows.iter() {
+	///     let row = row?;
+	///     println!("{}", row.GetObjectText()?);
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn iter(&self,
+	) -> Box<dyn Iterator<Item = HrResult<IWbemClassObject>> + '_>
+	{
+		Box::new(EnumWbemClassObjectIter::new(self))
+	}
+
+	/// [`IEnumWbemClassObject::Clone`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-ienumwbemclassobject-clone)
+	/// method.
+	#[must_use]
+	fn Clone(&self) -> HrResult<IEnumWbemClassObject> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumWbemClassObjectVT>();
+			ok_to_hrresult((vt.Clone)(self.ptr(), &mut ppv_queried))
+				.map(|_| IEnumWbemClassObject::from(ppv_queried))
+		}
+	}
+
+	/// [`IEnumWbemClassObject::Next`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-ienumwbemclassobject-next)
+	/// method.
+	///
+	/// Blocks until the next object is available.
+	///
+	/// Prefer using
+	/// [`IEnumWbemClassObject::iter`](crate::prelude::wmi_IEnumWbemClassObject::iter),
+	/// which is simpler.
+	#[must_use]
+	fn Next(&self) -> HrResult<Option<IWbemClassObject>> {
+		let mut fetched = u32::default();
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IEnumWbemClassObjectVT>();
+			match ok_to_hrresult(
+				(vt.Next)(
+					self.ptr(),
+					-1, // WBEM_INFINITE
+					1, // retrieve only 1
+					&mut ppv_queried,
+					&mut fetched,
+				),
+			) {
+				Ok(_) => Ok(Some(IWbemClassObject::from(ppv_queried))),
+				Err(hr) => match hr {
+					co::HRESULT::WBEM_S_FALSE => Ok(None), // no item found
+					hr => Err(hr), // actual error
+				},
+			}
+		}
+	}
+
+	/// [`IEnumWbemClassObject::Reset`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-ienumwbemclassobject-reset)
+	/// method.
+	fn Reset(&self) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumWbemClassObjectVT>();
+			ok_to_hrresult((vt.Reset)(self.ptr()))
+		}
+	}
+
+	/// [`IEnumWbemClassObject::Skip`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-ienumwbemclassobject-skip)
+	/// method.
+	///
+	/// Returns `false` if fewer objects than requested were available.
+	fn Skip(&self, count: u32) -> HrResult<bool> {
+		unsafe {
+			let vt = self.vt_ref::<IEnumWbemClassObjectVT>();
+			okfalse_to_hrresult(
+				(vt.Skip)(self.ptr(), -1, count), // WBEM_INFINITE
+			)
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct EnumWbemClassObjectIter<'a, I>
+	where I: wmi_IEnumWbemClassObject,
+{
+	enum_obj: &'a I,
+}
+
+impl<'a, I> Iterator for EnumWbemClassObjectIter<'a, I>
+	where I: wmi_IEnumWbemClassObject,
+{
+	type Item = HrResult<IWbemClassObject>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.enum_obj.Next() {
+			Err(err) => Some(Err(err)),
+			Ok(maybe_obj) => maybe_obj.map(Ok),
+		}
+	}
+}
+
+impl<'a, I> EnumWbemClassObjectIter<'a, I>
+	where I: wmi_IEnumWbemClassObject,
+{
+	fn new(enum_obj: &'a I) -> Self {
+		Self { enum_obj }
+	}
+}
@@ -0,0 +1,275 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::decl::WString;
+use crate::kernel::ffi_types::{HRES, PCSTR, PCVOID, PSTR, PVOID};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::{BSTR, VARIANT};
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+
+/// [`IWbemClassObject`](crate::IWbemClassObject) virtual table.
+#[repr(C)]
+pub struct IWbemClassObjectVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetQualifierSet: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub Get: fn(ComPtr, PCSTR, i32, PVOID, *mut i32, *mut i32) -> HRES,
+	pub Put: fn(ComPtr, PCSTR, i32, PCVOID, i32) -> HRES,
+	pub Delete: fn(ComPtr, PCSTR) -> HRES,
+	pub GetNames: fn(ComPtr, PCSTR, i32, PCVOID, *mut PVOID) -> HRES,
+	pub BeginEnumeration: fn(ComPtr, i32) -> HRES,
+	pub Next: fn(ComPtr, i32, *mut PSTR, PVOID, *mut i32, *mut i32) -> HRES,
+	pub EndEnumeration: fn(ComPtr) -> HRES,
+	pub GetPropertyQualifierSet: fn(ComPtr, PCSTR, *mut ComPtr) -> HRES,
+	pub Clone: fn(ComPtr, *mut ComPtr) -> HRES,
+	pub GetObjectText: fn(ComPtr, i32, *mut PSTR) -> HRES,
+	pub SpawnDerivedClass: fn(ComPtr, i32, *mut ComPtr) -> HRES,
+	pub SpawnInstance: fn(ComPtr, i32, *mut ComPtr) -> HRES,
+	pub CompareTo: fn(ComPtr, i32, ComPtr) -> HRES,
+	pub GetPropertyOrigin: fn(ComPtr, PCSTR, *mut PSTR) -> HRES,
+	pub InheritsFrom: fn(ComPtr, PCSTR) -> HRES,
+	pub GetMethod: fn(ComPtr, PCSTR, i32, *mut ComPtr, *mut ComPtr) -> HRES,
+	pub PutMethod: fn(ComPtr, PCSTR, i32, ComPtr, ComPtr) -> HRES,
+	pub DeleteMethod: fn(ComPtr, PCSTR) -> HRES,
+	pub BeginMethodEnumeration: fn(ComPtr, i32) -> HRES,
+	pub NextMethod: fn(ComPtr, i32, *mut PSTR, *mut ComPtr, *mut ComPtr) -> HRES,
+	pub EndMethodEnumeration: fn(ComPtr) -> HRES,
+	pub GetMethodQualifierSet: fn(ComPtr, PCSTR, *mut ComPtr) -> HRES,
+	pub GetMethodOrigin: fn(ComPtr, PCSTR, *mut PSTR) -> HRES,
+}
+
+com_interface! { IWbemClassObject: "dc12a681-737f-11cf-884d-00aa004b2e24";
+	/// [`IWbemClassObject`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nn-wbemcli-iwbemclassobject)
+	/// COM interface over
+	/// [`IWbemClassObjectVT`](crate::vt::IWbemClassObjectVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl wmi_IWbemClassObject for IWbemClassObject {}
+
+/// This trait is enabled with the `wmi` feature, and provides methods for
+/// [`IWbemClassObject`](crate::IWbemClassObject).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait wmi_IWbemClassObject: ole_IUnknown {
+	/// Returns an iterator over the properties of the object, which calls
+	/// [`IWbemClassObject::BeginEnumeration`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemclassobject-beginenumeration),
+	/// [`IWbemClassObject::Next`](crate::prelude::wmi_IWbemClassObject::Next)
+	/// and
+	/// [`IWbemClassObject::EndEnumeration`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemclassobject-endenumeration)
+	/// internally.
+	///
+	/// Each item is a tuple with the property name, its value and its CIM
+	/// type.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IWbemClassObject};
+	///
+	/// let row: IWbemClassObject; // initialized somewhere
+	/// # let row = IWbemClassObject::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// for prop in row.iter(co::WBEM_CONDITION::NONSYSTEM_ONLY)? {
+	///     let (name, val, _cim_type) = prop?;
+	///     println!("{}: {}", name, val.bstr().unwrap_or_default());
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn iter(&self,
+		flags: co::WBEM_CONDITION,
+	) -> HrResult<Box<dyn Iterator<Item = HrResult<(String, VARIANT, co::CIMTYPE)>> + '_>>
+	{
+		Ok(Box::new(WbemClassObjectIter::new(self, flags)?))
+	}
+
+	/// [`IWbemClassObject::Clone`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemclassobject-clone)
+	/// method.
+	#[must_use]
+	fn Clone(&self) -> HrResult<IWbemClassObject> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IWbemClassObjectVT>();
+			ok_to_hrresult((vt.Clone)(self.ptr(), &mut ppv_queried))
+				.map(|_| IWbemClassObject::from(ppv_queried))
+		}
+	}
+
+	/// [`IWbemClassObject::Delete`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemclassobject-delete)
+	/// method.
+	fn Delete(&self, name: &str) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IWbemClassObjectVT>();
+			ok_to_hrresult(
+				(vt.Delete)(self.ptr(), WString::from_str(name).as_ptr()),
+			)
+		}
+	}
+
+	/// [`IWbemClassObject::Get`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemclassobject-get)
+	/// method.
+	///
+	/// Returns the value of the given property. A property which exists but
+	/// has no value is returned as a `VT_NULL` variant.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IWbemClassObject};
+	///
+	/// let row: IWbemClassObject; // initialized somewhere
+	/// # let row = IWbemClassObject::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// let caption = row.Get("Caption")?
+	///     .bstr()
+	///     .unwrap_or_default();
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn Get(&self, name: &str) -> HrResult<VARIANT> {
+		let mut var = VARIANT::default();
+		unsafe {
+			let vt = self.vt_ref::<IWbemClassObjectVT>();
+			ok_to_hrresult(
+				(vt.Get)(
+					self.ptr(),
+					WString::from_str(name).as_ptr(),
+					0,
+					&mut var as *mut _ as _,
+					std::ptr::null_mut(),
+					std::ptr::null_mut(),
+				),
+			).map(|_| var)
+		}
+	}
+
+	/// [`IWbemClassObject::GetObjectText`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemclassobject-getobjecttext)
+	/// method.
+	///
+	/// Returns the object rendered in MOF syntax.
+	#[must_use]
+	fn GetObjectText(&self) -> HrResult<String> {
+		let mut bstr = BSTR::default();
+		unsafe {
+			let vt = self.vt_ref::<IWbemClassObjectVT>();
+			ok_to_hrresult((vt.GetObjectText)(self.ptr(), 0, &mut bstr.0))
+				.map(|_| bstr.to_string())
+		}
+	}
+
+	/// [`IWbemClassObject::Next`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemclassobject-next)
+	/// method.
+	///
+	/// Prefer using
+	/// [`IWbemClassObject::iter`](crate::prelude::wmi_IWbemClassObject::iter),
+	/// which is simpler.
+	#[must_use]
+	fn Next(&self) -> HrResult<Option<(String, VARIANT, co::CIMTYPE)>> {
+		let mut name = BSTR::default();
+		let mut var = VARIANT::default();
+		let mut cim_type = co::CIMTYPE::default();
+		unsafe {
+			let vt = self.vt_ref::<IWbemClassObjectVT>();
+			match ok_to_hrresult(
+				(vt.Next)(
+					self.ptr(),
+					0,
+					&mut name.0,
+					&mut var as *mut _ as _,
+					&mut cim_type.0,
+					std::ptr::null_mut(),
+				),
+			) {
+				Ok(_) => Ok(Some((name.to_string(), var, cim_type))),
+				Err(hr) => match hr {
+					co::HRESULT::WBEM_S_NO_MORE_DATA => Ok(None), // no more properties
+					hr => Err(hr), // actual error
+				},
+			}
+		}
+	}
+
+	/// [`IWbemClassObject::Put`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemclassobject-put)
+	/// method.
+	fn Put(&self, name: &str, val: &VARIANT) -> HrResult<()> {
+		unsafe {
+			let vt = self.vt_ref::<IWbemClassObjectVT>();
+			ok_to_hrresult(
+				(vt.Put)(
+					self.ptr(),
+					WString::from_str(name).as_ptr(),
+					0,
+					val as *const _ as _,
+					0, // type is inferred from the VARIANT
+				),
+			)
+		}
+	}
+
+	/// [`IWbemClassObject::SpawnInstance`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemclassobject-spawninstance)
+	/// method.
+	#[must_use]
+	fn SpawnInstance(&self) -> HrResult<IWbemClassObject> {
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IWbemClassObjectVT>();
+			ok_to_hrresult((vt.SpawnInstance)(self.ptr(), 0, &mut ppv_queried))
+				.map(|_| IWbemClassObject::from(ppv_queried))
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+struct WbemClassObjectIter<'a, I>
+	where I: wmi_IWbemClassObject,
+{
+	obj: &'a I,
+}
+
+impl<'a, I> Drop for WbemClassObjectIter<'a, I>
+	where I: wmi_IWbemClassObject,
+{
+	fn drop(&mut self) {
+		unsafe {
+			let vt = self.obj.vt_ref::<IWbemClassObjectVT>();
+			(vt.EndEnumeration)(self.obj.ptr()); // ignore errors
+		}
+	}
+}
+
+impl<'a, I> Iterator for WbemClassObjectIter<'a, I>
+	where I: wmi_IWbemClassObject,
+{
+	type Item = HrResult<(String, VARIANT, co::CIMTYPE)>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.obj.Next() {
+			Err(err) => Some(Err(err)),
+			Ok(maybe_prop) => maybe_prop.map(Ok),
+		}
+	}
+}
+
+impl<'a, I> WbemClassObjectIter<'a, I>
+	where I: wmi_IWbemClassObject,
+{
+	fn new(obj: &'a I, flags: co::WBEM_CONDITION) -> HrResult<Self> {
+		unsafe {
+			let vt = obj.vt_ref::<IWbemClassObjectVT>();
+			ok_to_hrresult((vt.BeginEnumeration)(obj.ptr(), flags.0))
+				.map(|_| Self { obj })
+		}
+	}
+}
@@ -0,0 +1,127 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::BSTR;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+use crate::wmi::decl::IWbemServices;
+
+/// [`IWbemLocator`](crate::IWbemLocator) virtual table.
+#[repr(C)]
+pub struct IWbemLocatorVT {
+	pub IUnknownVT: IUnknownVT,
+	pub ConnectServer: fn(ComPtr, PSTR, PSTR, PSTR, PSTR, i32, PSTR, ComPtr, *mut ComPtr) -> HRES,
+}
+
+com_interface! { IWbemLocator: "dc12a687-737f-11cf-884d-00aa004b2e24";
+	/// [`IWbemLocator`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nn-wbemcli-iwbemlocator)
+	/// COM interface over [`IWbemLocatorVT`](crate::vt::IWbemLocatorVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// Querying the operating system name:
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{self as w, co};
+	///
+	/// let _com_guard = w::CoInitializeEx(
+	///     co::COINIT::MULTITHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+	/// w::CoInitializeSecurity(
+	///     None,
+	///     co::RPC_C_AUTHN_LEVEL::DEFAULT,
+	///     co::RPC_C_IMP_LEVEL::IMPERSONATE,
+	///     co::EOAC::NONE,
+	/// )?;
+	///
+	/// let locator = w::CoCreateInstance::<w::IWbemLocator>(
+	///     &co::CLSID::WbemLocator,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// let services = locator.ConnectServer(
+	///     "ROOT\\CIMV2", None, None, None, co::WBEM_FLAG_CONNECT::NoValue, None)?;
+	///
+	/// w::CoSetProxyBlanket(
+	///     &services,
+	///     co::RPC_C_AUTHN::WINNT,
+	///     co::RPC_C_AUTHZ::NONE,
+	///     None,
+	///     co::RPC_C_AUTHN_LEVEL::CALL,
+	///     co::RPC_C_IMP_LEVEL::IMPERSONATE,
+	///     co::EOAC::NONE,
+	/// )?;
+	///
+	/// let rows = services.ExecQuery(
+	///     "SELECT * FROM Win32_OperatingSystem",
+	///     co::WBEM_FLAG::FORWARD_ONLY | co::WBEM_FLAG::RETURN_IMMEDIATELY,
+	/// )?;
+	/// for row in rows.iter() {
+	///     let row = row?;
+	///     let caption = row.Get("Caption")?;
+	///     println!("{}", caption.bstr().unwrap_or_default());
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+}
+
+impl wmi_IWbemLocator for IWbemLocator {}
+
+/// This trait is enabled with the `wmi` feature, and provides methods for
+/// [`IWbemLocator`](crate::IWbemLocator).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait wmi_IWbemLocator: ole_IUnknown {
+	/// [`IWbemLocator::ConnectServer`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemlocator-connectserver)
+	/// method.
+	///
+	/// If `user`, `password`, `locale` or `authority` are `None`, the
+	/// defaults of the current security context are used.
+	#[must_use]
+	fn ConnectServer(&self,
+		network_resource: &str,
+		user: Option<&str>,
+		password: Option<&str>,
+		locale: Option<&str>,
+		security_flags: co::WBEM_FLAG_CONNECT,
+		authority: Option<&str>,
+	) -> HrResult<IWbemServices>
+	{
+		let opt_bstr = |s: Option<&str>| s.map_or(
+			Ok(BSTR::default()), BSTR::SysAllocString); // null BSTR if None
+		let network_resource = BSTR::SysAllocString(network_resource)?;
+		let user = opt_bstr(user)?;
+		let password = opt_bstr(password)?;
+		let locale = opt_bstr(locale)?;
+		let authority = opt_bstr(authority)?;
+
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IWbemLocatorVT>();
+			ok_to_hrresult(
+				(vt.ConnectServer)(
+					self.ptr(),
+					network_resource.0,
+					user.0,
+					password.0,
+					locale.0,
+					security_flags.0,
+					authority.0,
+					ComPtr::null(),
+					&mut ppv_queried,
+				),
+			).map(|_| IWbemServices::from(ppv_queried))
+		}
+	}
+}
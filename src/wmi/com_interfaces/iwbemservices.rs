@@ -0,0 +1,184 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::kernel::ffi_types::{HRES, PSTR};
+use crate::ole::decl::{ComPtr, HrResult};
+use crate::ole::privs::ok_to_hrresult;
+use crate::oleaut::decl::BSTR;
+use crate::prelude::ole_IUnknown;
+use crate::vt::IUnknownVT;
+use crate::wmi::decl::{IEnumWbemClassObject, IWbemClassObject};
+
+/// [`IWbemServices`](crate::IWbemServices) virtual table.
+#[repr(C)]
+pub struct IWbemServicesVT {
+	pub IUnknownVT: IUnknownVT,
+	pub OpenNamespace: fn(ComPtr, PSTR, i32, ComPtr, *mut ComPtr, *mut ComPtr) -> HRES,
+	pub CancelAsyncCall: fn(ComPtr, ComPtr) -> HRES,
+	pub QueryObjectSink: fn(ComPtr, i32, *mut ComPtr) -> HRES,
+	pub GetObject: fn(ComPtr, PSTR, i32, ComPtr, *mut ComPtr, *mut ComPtr) -> HRES,
+	pub GetObjectAsync: fn(ComPtr, PSTR, i32, ComPtr, ComPtr) -> HRES,
+	pub PutClass: fn(ComPtr, ComPtr, i32, ComPtr, *mut ComPtr) -> HRES,
+	pub PutClassAsync: fn(ComPtr, ComPtr, i32, ComPtr, ComPtr) -> HRES,
+	pub DeleteClass: fn(ComPtr, PSTR, i32, ComPtr, *mut ComPtr) -> HRES,
+	pub DeleteClassAsync: fn(ComPtr, PSTR, i32, ComPtr, ComPtr) -> HRES,
+	pub CreateClassEnum: fn(ComPtr, PSTR, i32, ComPtr, *mut ComPtr) -> HRES,
+	pub CreateClassEnumAsync: fn(ComPtr, PSTR, i32, ComPtr, ComPtr) -> HRES,
+	pub PutInstance: fn(ComPtr, ComPtr, i32, ComPtr, *mut ComPtr) -> HRES,
+	pub PutInstanceAsync: fn(ComPtr, ComPtr, i32, ComPtr, ComPtr) -> HRES,
+	pub DeleteInstance: fn(ComPtr, PSTR, i32, ComPtr, *mut ComPtr) -> HRES,
+	pub DeleteInstanceAsync: fn(ComPtr, PSTR, i32, ComPtr, ComPtr) -> HRES,
+	pub CreateInstanceEnum: fn(ComPtr, PSTR, i32, ComPtr, *mut ComPtr) -> HRES,
+	pub CreateInstanceEnumAsync: fn(ComPtr, PSTR, i32, ComPtr, ComPtr) -> HRES,
+	pub ExecQuery: fn(ComPtr, PSTR, PSTR, i32, ComPtr, *mut ComPtr) -> HRES,
+	pub ExecQueryAsync: fn(ComPtr, PSTR, PSTR, i32, ComPtr, ComPtr) -> HRES,
+	pub ExecNotificationQuery: fn(ComPtr, PSTR, PSTR, i32, ComPtr, *mut ComPtr) -> HRES,
+	pub ExecNotificationQueryAsync: fn(ComPtr, PSTR, PSTR, i32, ComPtr, ComPtr) -> HRES,
+	pub ExecMethod: fn(ComPtr, PSTR, PSTR, i32, ComPtr, ComPtr, *mut ComPtr, *mut ComPtr) -> HRES,
+	pub ExecMethodAsync: fn(ComPtr, PSTR, PSTR, i32, ComPtr, ComPtr, ComPtr) -> HRES,
+}
+
+com_interface! { IWbemServices: "9556dc99-828c-11cf-a37e-00aa003240c7";
+	/// [`IWbemServices`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nn-wbemcli-iwbemservices)
+	/// COM interface over [`IWbemServicesVT`](crate::vt::IWbemServicesVT).
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// Usually obtained with
+	/// [`IWbemLocator::ConnectServer`](crate::prelude::wmi_IWbemLocator::ConnectServer).
+}
+
+impl wmi_IWbemServices for IWbemServices {}
+
+/// This trait is enabled with the `wmi` feature, and provides methods for
+/// [`IWbemServices`](crate::IWbemServices).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```rust,no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait wmi_IWbemServices: ole_IUnknown {
+	/// [`IWbemServices::CreateInstanceEnum`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemservices-createinstanceenum)
+	/// method.
+	#[must_use]
+	fn CreateInstanceEnum(&self,
+		filter: &str,
+		flags: co::WBEM_FLAG,
+	) -> HrResult<IEnumWbemClassObject>
+	{
+		let filter = BSTR::SysAllocString(filter)?;
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IWbemServicesVT>();
+			ok_to_hrresult(
+				(vt.CreateInstanceEnum)(
+					self.ptr(),
+					filter.0,
+					flags.0,
+					ComPtr::null(),
+					&mut ppv_queried,
+				),
+			).map(|_| IEnumWbemClassObject::from(ppv_queried))
+		}
+	}
+
+	/// [`IWbemServices::ExecQuery`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemservices-execquery)
+	/// method.
+	///
+	/// The query is always written in WQL, the only language supported by
+	/// WMI.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use winsafe::prelude::*;
+	/// use winsafe::{co, IWbemServices};
+	///
+	/// let services: IWbemServices; // initialized somewhere
+	/// # let services = IWbemServices::from(unsafe { winsafe::ComPtr::null() });
+	///
+	/// let rows = services.ExecQuery(
+	///     "SELECT Name, ProcessId FROM Win32_Process",
+	///     co::WBEM_FLAG::FORWARD_ONLY | co::WBEM_FLAG::RETURN_IMMEDIATELY,
+	/// )?;
+	/// for row in rows.iter() {
+	///     let row = row?;
+	///     println!("{} {}",
+	///         row.Get("ProcessId")?.i32().unwrap_or_default(),
+	///         row.Get("Name")?.bstr().unwrap_or_default());
+	/// }
+	/// # Ok::<_, co::HRESULT>(())
+	/// ```
+	#[must_use]
+	fn ExecQuery(&self,
+		query: &str,
+		flags: co::WBEM_FLAG,
+	) -> HrResult<IEnumWbemClassObject>
+	{
+		let language = BSTR::SysAllocString("WQL")?;
+		let query = BSTR::SysAllocString(query)?;
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IWbemServicesVT>();
+			ok_to_hrresult(
+				(vt.ExecQuery)(
+					self.ptr(),
+					language.0,
+					query.0,
+					flags.0,
+					ComPtr::null(),
+					&mut ppv_queried,
+				),
+			).map(|_| IEnumWbemClassObject::from(ppv_queried))
+		}
+	}
+
+	/// [`IWbemServices::GetObject`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemservices-getobject)
+	/// method.
+	#[must_use]
+	fn GetObject(&self,
+		object_path: &str,
+		flags: co::WBEM_FLAG,
+	) -> HrResult<IWbemClassObject>
+	{
+		let object_path = BSTR::SysAllocString(object_path)?;
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IWbemServicesVT>();
+			ok_to_hrresult(
+				(vt.GetObject)(
+					self.ptr(),
+					object_path.0,
+					flags.0,
+					ComPtr::null(),
+					&mut ppv_queried,
+					std::ptr::null_mut(),
+				),
+			).map(|_| IWbemClassObject::from(ppv_queried))
+		}
+	}
+
+	/// [`IWbemServices::OpenNamespace`](https://learn.microsoft.com/en-us/windows/win32/api/wbemcli/nf-wbemcli-iwbemservices-opennamespace)
+	/// method.
+	#[must_use]
+	fn OpenNamespace(&self, namespace: &str) -> HrResult<IWbemServices> {
+		let namespace = BSTR::SysAllocString(namespace)?;
+		unsafe {
+			let mut ppv_queried = ComPtr::null();
+			let vt = self.vt_ref::<IWbemServicesVT>();
+			ok_to_hrresult(
+				(vt.OpenNamespace)(
+					self.ptr(),
+					namespace.0,
+					0, // synchronous call
+					ComPtr::null(),
+					&mut ppv_queried,
+					std::ptr::null_mut(),
+				),
+			).map(|_| IWbemServices::from(ppv_queried))
+		}
+	}
+}
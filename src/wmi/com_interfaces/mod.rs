@@ -0,0 +1,25 @@
+mod ienumwbemclassobject;
+mod iwbemclassobject;
+mod iwbemlocator;
+mod iwbemservices;
+
+pub mod decl {
+	pub use super::ienumwbemclassobject::IEnumWbemClassObject;
+	pub use super::iwbemclassobject::IWbemClassObject;
+	pub use super::iwbemlocator::IWbemLocator;
+	pub use super::iwbemservices::IWbemServices;
+}
+
+pub mod traits {
+	pub use super::ienumwbemclassobject::wmi_IEnumWbemClassObject;
+	pub use super::iwbemclassobject::wmi_IWbemClassObject;
+	pub use super::iwbemlocator::wmi_IWbemLocator;
+	pub use super::iwbemservices::wmi_IWbemServices;
+}
+
+pub mod vt {
+	pub use super::ienumwbemclassobject::IEnumWbemClassObjectVT;
+	pub use super::iwbemclassobject::IWbemClassObjectVT;
+	pub use super::iwbemlocator::IWbemLocatorVT;
+	pub use super::iwbemservices::IWbemServicesVT;
+}